@@ -32,11 +32,15 @@ const logEmit = vi.hoisted(() => vi.fn());
 const logShutdown = vi.hoisted(() => vi.fn().mockResolvedValue(undefined));
 
 vi.mock("@opentelemetry/api", () => ({
+  context: {
+    active: () => ({}),
+  },
   metrics: {
     getMeter: () => telemetryState.meter,
   },
   trace: {
     getTracer: () => telemetryState.tracer,
+    setSpan: (_ctx: unknown, span: unknown) => ({ parentSpan: span }),
   },
   SpanStatusCode: {
     ERROR: 2,
@@ -224,4 +228,69 @@ describe("diagnostics-otel service", () => {
 
     await service.stop?.();
   });
+
+  test("nests request and tool spans under the run's usage span", async () => {
+    const service = createDiagnosticsOtelService();
+    await service.start({
+      config: {
+        diagnostics: {
+          enabled: true,
+          otel: { enabled: true, endpoint: "http://otel-collector:4318", traces: true },
+        },
+      },
+      logger: { info: vi.fn(), warn: vi.fn(), error: vi.fn(), debug: vi.fn() },
+    });
+
+    emitDiagnosticEvent({
+      type: "model.request",
+      runId: "run-trace",
+      provider: "anthropic",
+      model: "claude-opus-4-6",
+      usage: { input: 100, output: 20, total: 120 },
+      durationMs: 900,
+    });
+    emitDiagnosticEvent({
+      type: "tool.execution",
+      runId: "run-trace",
+      toolName: "exec",
+      toolCallId: "call-1",
+      durationMs: 40,
+      outcome: "error",
+      error: "exit 1",
+    });
+    // Buffered until the run's usage event arrives.
+    expect(telemetryState.tracer.startSpan).not.toHaveBeenCalled();
+    expect(telemetryState.counters.get("openclaw.tool.execution")?.add).toHaveBeenCalledWith(1, {
+      "openclaw.tool": "exec",
+      "openclaw.outcome": "error",
+    });
+
+    emitDiagnosticEvent({
+      type: "model.usage",
+      runId: "run-trace",
+      provider: "anthropic",
+      model: "claude-opus-4-6",
+      usage: { input: 100, output: 20, total: 120 },
+      durationMs: 1500,
+    });
+
+    const calls = telemetryState.tracer.startSpan.mock.calls as unknown as Array<
+      [string, { attributes?: Record<string, unknown> }?, unknown?]
+    >;
+    expect(calls.map((call) => call[0])).toEqual([
+      "openclaw.model.usage",
+      "openclaw.model.request",
+      "openclaw.tool.execution",
+    ]);
+    const usageSpan = telemetryState.tracer.startSpan.mock.results[0]?.value;
+    expect(calls[1]?.[2]).toEqual({ parentSpan: usageSpan });
+    expect(calls[1]?.[1]?.attributes?.["openclaw.tokens.input"]).toBe(100);
+    expect(calls[2]?.[1]?.attributes?.["openclaw.tool"]).toBe("exec");
+    expect(telemetryState.tracer.startSpan.mock.results[2]?.value.setStatus).toHaveBeenCalledWith({
+      code: 2,
+      message: "exit 1",
+    });
+
+    await service.stop?.();
+  });
 });
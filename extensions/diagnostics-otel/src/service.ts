@@ -1,6 +1,6 @@
 import type { SeverityNumber } from "@opentelemetry/api-logs";
 import type { DiagnosticEventPayload, OpenClawPluginService } from "openclaw/plugin-sdk";
-import { metrics, trace, SpanStatusCode } from "@opentelemetry/api";
+import { context, metrics, trace, SpanStatusCode, type Context } from "@opentelemetry/api";
 import { OTLPLogExporter } from "@opentelemetry/exporter-logs-otlp-http";
 import { OTLPMetricExporter } from "@opentelemetry/exporter-metrics-otlp-http";
 import { OTLPTraceExporter } from "@opentelemetry/exporter-trace-otlp-http";
@@ -13,6 +13,18 @@ import { SemanticResourceAttributes } from "@opentelemetry/semantic-conventions"
 import { onDiagnosticEvent, registerLogTransport } from "openclaw/plugin-sdk";
 
 const DEFAULT_SERVICE_NAME = "openclaw";
+/** How long request/tool spans wait for their run's usage event before exporting unparented. */
+const RUN_SPAN_BUFFER_MS = 10 * 60_000;
+const MAX_BUFFERED_RUNS = 200;
+
+type BufferedSpan = {
+  name: string;
+  attributes: Record<string, string | number>;
+  startTime: number;
+  endTime: number;
+  failed: boolean;
+  error?: string;
+};
 
 function normalizeEndpoint(endpoint?: string): string | undefined {
   const trimmed = endpoint?.trim();
@@ -44,6 +56,7 @@ export function createDiagnosticsOtelService(): OpenClawPluginService {
   let logProvider: LoggerProvider | null = null;
   let stopLogTransport: (() => void) | null = null;
   let unsubscribe: (() => void) | null = null;
+  let flushRunSpans: (() => void) | null = null;
 
   return {
     id: "diagnostics-otel",
@@ -204,6 +217,21 @@ export function createDiagnosticsOtelService(): OpenClawPluginService {
         unit: "1",
         description: "Run attempts",
       });
+      const modelRequestDurationHistogram = meter.createHistogram(
+        "openclaw.model.request.duration_ms",
+        {
+          unit: "ms",
+          description: "Provider request duration within a run",
+        },
+      );
+      const toolExecutionCounter = meter.createCounter("openclaw.tool.execution", {
+        unit: "1",
+        description: "Tool executions by tool and outcome",
+      });
+      const toolDurationHistogram = meter.createHistogram("openclaw.tool.duration_ms", {
+        unit: "ms",
+        description: "Tool execution duration",
+      });
 
       if (logsEnabled) {
         const logExporter = new OTLPLogExporter({
@@ -336,6 +364,109 @@ export function createDiagnosticsOtelService(): OpenClawPluginService {
         return span;
       };
 
+      // Request and tool spans wait for their run's usage event so they can nest under it.
+      const pendingRunSpans = new Map<string, { createdAt: number; spans: BufferedSpan[] }>();
+
+      const emitBufferedSpan = (buffered: BufferedSpan, parent?: Context) => {
+        const span = tracer.startSpan(
+          buffered.name,
+          { attributes: buffered.attributes, startTime: buffered.startTime },
+          parent,
+        );
+        if (buffered.failed) {
+          span.setStatus({ code: SpanStatusCode.ERROR, message: buffered.error });
+        }
+        span.end(buffered.endTime);
+      };
+
+      const flushStaleRunSpans = (now: number, force = false) => {
+        for (const [runId, pending] of pendingRunSpans) {
+          const stale =
+            force ||
+            now - pending.createdAt >= RUN_SPAN_BUFFER_MS ||
+            pendingRunSpans.size > MAX_BUFFERED_RUNS;
+          if (!stale) {
+            break;
+          }
+          pendingRunSpans.delete(runId);
+          for (const buffered of pending.spans) {
+            emitBufferedSpan(buffered);
+          }
+        }
+      };
+      flushRunSpans = () => flushStaleRunSpans(Date.now(), true);
+
+      const bufferRunSpan = (runId: string, buffered: BufferedSpan) => {
+        flushStaleRunSpans(buffered.endTime);
+        const pending = pendingRunSpans.get(runId) ?? { createdAt: buffered.endTime, spans: [] };
+        pending.spans.push(buffered);
+        pendingRunSpans.set(runId, pending);
+      };
+
+      const recordModelRequest = (
+        evt: Extract<DiagnosticEventPayload, { type: "model.request" }>,
+      ) => {
+        const attrs = {
+          "openclaw.provider": evt.provider ?? "unknown",
+          "openclaw.model": evt.model ?? "unknown",
+        };
+        if (typeof evt.durationMs === "number") {
+          modelRequestDurationHistogram.record(evt.durationMs, attrs);
+        }
+        if (!tracesEnabled) {
+          return;
+        }
+        const attributes: Record<string, string | number> = {
+          ...attrs,
+          "openclaw.runId": evt.runId,
+          "openclaw.tokens.input": evt.usage.input ?? 0,
+          "openclaw.tokens.output": evt.usage.output ?? 0,
+          "openclaw.tokens.cache_read": evt.usage.cacheRead ?? 0,
+          "openclaw.tokens.cache_write": evt.usage.cacheWrite ?? 0,
+          "openclaw.tokens.total": evt.usage.total ?? 0,
+        };
+        if (evt.stopReason) {
+          attributes["openclaw.stopReason"] = evt.stopReason;
+        }
+        bufferRunSpan(evt.runId, {
+          name: "openclaw.model.request",
+          attributes,
+          startTime: evt.ts - Math.max(0, evt.durationMs ?? 0),
+          endTime: evt.ts,
+          failed: evt.stopReason === "error",
+          error: evt.error,
+        });
+      };
+
+      const recordToolExecution = (
+        evt: Extract<DiagnosticEventPayload, { type: "tool.execution" }>,
+      ) => {
+        const attrs = {
+          "openclaw.tool": evt.toolName,
+          "openclaw.outcome": evt.outcome,
+        };
+        toolExecutionCounter.add(1, attrs);
+        if (typeof evt.durationMs === "number") {
+          toolDurationHistogram.record(evt.durationMs, attrs);
+        }
+        if (!tracesEnabled) {
+          return;
+        }
+        bufferRunSpan(evt.runId, {
+          name: "openclaw.tool.execution",
+          attributes: {
+            ...attrs,
+            "openclaw.runId": evt.runId,
+            "openclaw.toolCallId": evt.toolCallId,
+            "openclaw.durationMs": evt.durationMs ?? 0,
+          },
+          startTime: evt.ts - Math.max(0, evt.durationMs ?? 0),
+          endTime: evt.ts,
+          failed: evt.outcome === "error",
+          error: evt.error,
+        });
+      };
+
       const recordModelUsage = (evt: Extract<DiagnosticEventPayload, { type: "model.usage" }>) => {
         const attrs = {
           "openclaw.channel": evt.channel ?? "unknown",
@@ -395,8 +526,19 @@ export function createDiagnosticsOtelService(): OpenClawPluginService {
           "openclaw.tokens.cache_write": usage.cacheWrite ?? 0,
           "openclaw.tokens.total": usage.total ?? 0,
         };
+        if (evt.runId) {
+          spanAttrs["openclaw.runId"] = evt.runId;
+        }
 
         const span = spanWithDuration("openclaw.model.usage", spanAttrs, evt.durationMs);
+        const children = evt.runId ? pendingRunSpans.get(evt.runId) : undefined;
+        if (evt.runId && children) {
+          pendingRunSpans.delete(evt.runId);
+          const parent = trace.setSpan(context.active(), span);
+          for (const buffered of children.spans) {
+            emitBufferedSpan(buffered, parent);
+          }
+        }
         span.end();
       };
 
@@ -575,6 +717,12 @@ export function createDiagnosticsOtelService(): OpenClawPluginService {
           case "model.usage":
             recordModelUsage(evt);
             return;
+          case "model.request":
+            recordModelRequest(evt);
+            return;
+          case "tool.execution":
+            recordToolExecution(evt);
+            return;
           case "webhook.received":
             recordWebhookReceived(evt);
             return;
@@ -618,6 +766,8 @@ export function createDiagnosticsOtelService(): OpenClawPluginService {
     async stop() {
       unsubscribe?.();
       unsubscribe = null;
+      flushRunSpans?.();
+      flushRunSpans = null;
       stopLogTransport?.();
       stopLogTransport = null;
       if (logProvider) {
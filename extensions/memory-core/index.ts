@@ -26,6 +26,22 @@ const memoryCorePlugin = {
       { names: ["memory_search", "memory_get"] },
     );
 
+    api.registerTool(
+      (ctx) => {
+        const memoryReadTool = api.runtime.tools.createMemoryReadTool({
+          config: ctx.config,
+          agentSessionKey: ctx.sessionKey,
+        });
+        const memoryWriteTool = api.runtime.tools.createMemoryWriteTool({
+          config: ctx.config,
+          agentSessionKey: ctx.sessionKey,
+        });
+        const tools = [memoryReadTool, memoryWriteTool].filter((tool) => tool !== null);
+        return tools.length > 0 ? tools : null;
+      },
+      { names: ["memory_read", "memory_write"] },
+    );
+
     api.registerCli(
       ({ program }) => {
         api.runtime.tools.registerMemoryCli(program);
@@ -4,6 +4,7 @@ import type { OpenClawConfig } from "../config/config.js";
 import {
   resolveAgentConfig,
   resolveAgentDir,
+  resolveAgentIdForWorkspacePath,
   resolveAgentModelFallbacksOverride,
   resolveAgentModelPrimary,
   resolveAgentWorkspaceDir,
@@ -226,3 +227,26 @@ describe("resolveAgentConfig", () => {
     expect(agentDir).toBe(path.join(path.resolve(home), ".openclaw", "agents", "main", "agent"));
   });
 });
+
+describe("resolveAgentIdForWorkspacePath", () => {
+  const root = path.join(path.sep, "srv", "code");
+  const cfg: OpenClawConfig = {
+    agents: {
+      list: [
+        { id: "main", default: true, workspace: root },
+        { id: "api", workspace: path.join(root, "api") },
+      ],
+    },
+  };
+
+  it("picks the innermost workspace containing the directory", () => {
+    expect(resolveAgentIdForWorkspacePath(cfg, path.join(root, "api", "src"))).toBe("api");
+    expect(resolveAgentIdForWorkspacePath(cfg, path.join(root, "api"))).toBe("api");
+    expect(resolveAgentIdForWorkspacePath(cfg, path.join(root, "web"))).toBe("main");
+  });
+
+  it("returns undefined outside every workspace", () => {
+    expect(resolveAgentIdForWorkspacePath(cfg, path.join(path.sep, "tmp"))).toBeUndefined();
+    expect(resolveAgentIdForWorkspacePath(cfg, `${root}-old`)).toBeUndefined();
+  });
+});
@@ -181,6 +181,28 @@ export function resolveAgentWorkspaceDir(cfg: OpenClawConfig, agentId: string) {
   return path.join(stateDir, `workspace-${id}`);
 }
 
+/**
+ * The agent whose workspace contains `dir`, so commands run inside a project default to that
+ * project's agent. Nested workspaces resolve to the innermost one; undefined when none match.
+ */
+export function resolveAgentIdForWorkspacePath(
+  cfg: OpenClawConfig,
+  dir: string,
+): string | undefined {
+  const target = path.resolve(dir);
+  let best: { id: string; depth: number } | undefined;
+  for (const id of listAgentIds(cfg)) {
+    const workspace = path.resolve(resolveAgentWorkspaceDir(cfg, id));
+    const rel = path.relative(workspace, target);
+    const inside =
+      rel === "" || (rel !== ".." && !rel.startsWith(`..${path.sep}`) && !path.isAbsolute(rel));
+    if (inside && (!best || workspace.length > best.depth)) {
+      best = { id, depth: workspace.length };
+    }
+  }
+  return best?.id;
+}
+
 export function resolveAgentDir(cfg: OpenClawConfig, agentId: string) {
   const id = normalizeAgentId(agentId);
   const configured = resolveAgentConfig(cfg, id)?.agentDir?.trim();
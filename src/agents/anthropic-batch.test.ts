@@ -0,0 +1,144 @@
+import { describe, expect, it, vi } from "vitest";
+import {
+  buildBatchRequests,
+  createAnthropicBatchClient,
+  normalizeBatchResult,
+  parseBatchInput,
+  waitForAnthropicBatch,
+} from "./anthropic-batch.js";
+
+describe("parseBatchInput", () => {
+  it("defaults ids, skips blank lines, and builds one request per prompt", () => {
+    const items = parseBatchInput(
+      [
+        '{"id":"a","prompt":"first","maxTokens":64}',
+        "",
+        '{"prompt":"second","system":"be brief"}',
+      ].join("\n"),
+    );
+    expect(items).toEqual([
+      { id: "a", prompt: "first", maxTokens: 64, system: undefined },
+      { id: "line-3", prompt: "second", system: "be brief", maxTokens: undefined },
+    ]);
+    expect(
+      buildBatchRequests(items, { model: "claude-sonnet-4-5", maxTokens: 1024, system: "default" }),
+    ).toEqual([
+      {
+        custom_id: "a",
+        params: {
+          model: "claude-sonnet-4-5",
+          max_tokens: 64,
+          system: "default",
+          messages: [{ role: "user", content: "first" }],
+        },
+      },
+      {
+        custom_id: "line-3",
+        params: {
+          model: "claude-sonnet-4-5",
+          max_tokens: 1024,
+          system: "be brief",
+          messages: [{ role: "user", content: "second" }],
+        },
+      },
+    ]);
+  });
+
+  it("rejects bad lines with their line number", () => {
+    expect(() => parseBatchInput('{"prompt":"x"}\nnot json')).toThrow(/line 2: invalid JSON/);
+    expect(() => parseBatchInput('{"id":"a","prompt":"x"}\n{"id":"a","prompt":"y"}')).toThrow(
+      /line 2: duplicate id "a"/,
+    );
+    expect(() => parseBatchInput('{"id":"has space","prompt":"x"}')).toThrow(/line 1: "id"/);
+    expect(() => parseBatchInput("\n\n")).toThrow(/no prompts/);
+  });
+});
+
+describe("normalizeBatchResult", () => {
+  it("flattens succeeded and errored entries", () => {
+    expect(
+      normalizeBatchResult({
+        custom_id: "a",
+        result: {
+          type: "succeeded",
+          message: {
+            content: [
+              { type: "text", text: "Hello" },
+              { type: "text", text: " there" },
+            ],
+            stop_reason: "end_turn",
+            usage: { input_tokens: 10, output_tokens: 3 },
+          },
+        },
+      }),
+    ).toEqual({
+      id: "a",
+      status: "succeeded",
+      text: "Hello there",
+      stopReason: "end_turn",
+      usage: { input: 10, output: 3, cacheRead: undefined, cacheWrite: undefined },
+    });
+    expect(
+      normalizeBatchResult({
+        custom_id: "b",
+        result: {
+          type: "errored",
+          error: { type: "error", error: { type: "invalid_request_error", message: "bad" } },
+        },
+      }),
+    ).toEqual({ id: "b", status: "errored", error: "bad" });
+  });
+});
+
+describe("createAnthropicBatchClient", () => {
+  it("creates, polls, and downloads results", async () => {
+    const ended = {
+      id: "msgbatch_1",
+      processing_status: "ended",
+      request_counts: { processing: 0, succeeded: 1, errored: 0, canceled: 0, expired: 0 },
+      results_url: "https://example.test/results",
+    };
+    const fetchFn = vi.fn(async (url: string | URL | Request, init?: RequestInit) => {
+      const href = String(url);
+      if (href.endsWith("/v1/messages/batches") && init?.method === "POST") {
+        return Response.json({ ...ended, processing_status: "in_progress" });
+      }
+      if (href.endsWith("/v1/messages/batches/msgbatch_1")) {
+        return Response.json(ended);
+      }
+      if (href === "https://example.test/results") {
+        return new Response(
+          `${JSON.stringify({
+            custom_id: "a",
+            result: { type: "succeeded", message: { content: [{ type: "text", text: "ok" }] } },
+          })}\n`,
+        );
+      }
+      return Response.json({ error: { message: "not found" } }, { status: 404 });
+    });
+    const client = createAnthropicBatchClient({
+      apiKey: "sk-ant-test",
+      fetchFn: fetchFn as typeof fetch,
+    });
+
+    const created = await client.create(
+      buildBatchRequests([{ id: "a", prompt: "hi" }], { model: "m", maxTokens: 16 }),
+    );
+    expect(created.processing_status).toBe("in_progress");
+    const [, init] = fetchFn.mock.calls[0] ?? [];
+    expect(init?.headers).toMatchObject({
+      "x-api-key": "sk-ant-test",
+      "anthropic-version": "2023-06-01",
+    });
+
+    const done = await waitForAnthropicBatch({
+      client,
+      batchId: created.id,
+      intervalMs: 0,
+      sleep: async () => {},
+    });
+    const results = await client.results(done);
+    expect(results).toMatchObject([{ id: "a", status: "succeeded", text: "ok" }]);
+    await expect(client.get("missing")).rejects.toThrow("Anthropic batch API 404: not found");
+  });
+});
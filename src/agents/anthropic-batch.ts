@@ -0,0 +1,276 @@
+export const ANTHROPIC_API_BASE_URL = "https://api.anthropic.com";
+const ANTHROPIC_VERSION = "2023-06-01";
+/** Message Batches are billed at half the regular token price. */
+export const ANTHROPIC_BATCH_DISCOUNT = 0.5;
+export const ANTHROPIC_BATCH_MAX_REQUESTS = 100_000;
+const CUSTOM_ID_RE = /^[a-zA-Z0-9_-]{1,64}$/;
+
+export type BatchInputItem = {
+  id: string;
+  prompt: string;
+  system?: string;
+  maxTokens?: number;
+};
+
+export type AnthropicBatchRequest = {
+  custom_id: string;
+  params: {
+    model: string;
+    max_tokens: number;
+    system?: string;
+    messages: Array<{ role: "user"; content: string }>;
+  };
+};
+
+export type AnthropicBatch = {
+  id: string;
+  processing_status: "in_progress" | "canceling" | "ended";
+  request_counts: {
+    processing: number;
+    succeeded: number;
+    errored: number;
+    canceled: number;
+    expired: number;
+  };
+  created_at?: string;
+  ended_at?: string | null;
+  expires_at?: string;
+  results_url?: string | null;
+};
+
+export type BatchResultLine = {
+  id: string;
+  status: "succeeded" | "errored" | "canceled" | "expired";
+  text?: string;
+  stopReason?: string;
+  usage?: { input: number; output: number; cacheRead?: number; cacheWrite?: number };
+  error?: string;
+};
+
+/**
+ * Parses the `openclaw batch` input: one JSON object per line with `prompt` and optional `id`,
+ * `system`, and `maxTokens`. Blank lines are skipped; ids default to `line-<n>`.
+ */
+export function parseBatchInput(raw: string): BatchInputItem[] {
+  const items: BatchInputItem[] = [];
+  const seen = new Set<string>();
+  for (const [index, line] of raw.split(/\r?\n/).entries()) {
+    if (!line.trim()) {
+      continue;
+    }
+    const where = `line ${index + 1}`;
+    let parsed: unknown;
+    try {
+      parsed = JSON.parse(line);
+    } catch (err) {
+      throw new Error(`${where}: invalid JSON (${String(err)})`, { cause: err });
+    }
+    if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) {
+      throw new Error(`${where}: expected an object like {"id":"a","prompt":"..."}`);
+    }
+    const record = parsed as Record<string, unknown>;
+    if (typeof record.prompt !== "string" || !record.prompt.trim()) {
+      throw new Error(`${where}: "prompt" must be a non-empty string`);
+    }
+    const id =
+      typeof record.id === "string" || typeof record.id === "number"
+        ? String(record.id)
+        : `line-${index + 1}`;
+    if (!CUSTOM_ID_RE.test(id)) {
+      throw new Error(`${where}: "id" must be 1-64 letters, digits, "_" or "-"`);
+    }
+    if (seen.has(id)) {
+      throw new Error(`${where}: duplicate id "${id}"`);
+    }
+    seen.add(id);
+    const maxTokens = record.maxTokens ?? record.max_tokens;
+    if (maxTokens !== undefined && (!Number.isInteger(maxTokens) || Number(maxTokens) <= 0)) {
+      throw new Error(`${where}: "maxTokens" must be a positive integer`);
+    }
+    items.push({
+      id,
+      prompt: record.prompt,
+      system: typeof record.system === "string" && record.system.trim() ? record.system : undefined,
+      maxTokens: maxTokens as number | undefined,
+    });
+  }
+  if (items.length === 0) {
+    throw new Error("The batch input has no prompts");
+  }
+  if (items.length > ANTHROPIC_BATCH_MAX_REQUESTS) {
+    throw new Error(`A batch holds at most ${ANTHROPIC_BATCH_MAX_REQUESTS} prompts`);
+  }
+  return items;
+}
+
+export function buildBatchRequests(
+  items: BatchInputItem[],
+  params: { model: string; maxTokens: number; system?: string },
+): AnthropicBatchRequest[] {
+  return items.map((item) => {
+    const system = item.system ?? params.system;
+    return {
+      custom_id: item.id,
+      params: {
+        model: params.model,
+        max_tokens: item.maxTokens ?? params.maxTokens,
+        ...(system ? { system } : {}),
+        messages: [{ role: "user", content: item.prompt }],
+      },
+    };
+  });
+}
+
+function describeBatchError(error: unknown): string {
+  const record = error as { type?: string; error?: { type?: string; message?: string } };
+  return record?.error?.message ?? record?.error?.type ?? record?.type ?? "request failed";
+}
+
+/** Turns one line of the batch results file into the `openclaw batch` output format. */
+export function normalizeBatchResult(raw: unknown): BatchResultLine | undefined {
+  const entry = raw as {
+    custom_id?: string;
+    result?: {
+      type?: string;
+      message?: {
+        content?: Array<{ type?: string; text?: string }>;
+        stop_reason?: string;
+        usage?: {
+          input_tokens?: number;
+          output_tokens?: number;
+          cache_read_input_tokens?: number;
+          cache_creation_input_tokens?: number;
+        };
+      };
+      error?: unknown;
+    };
+  };
+  if (!entry?.custom_id || !entry.result?.type) {
+    return undefined;
+  }
+  const status = entry.result.type as BatchResultLine["status"];
+  if (status !== "succeeded") {
+    return {
+      id: entry.custom_id,
+      status,
+      error: status === "errored" ? describeBatchError(entry.result.error) : undefined,
+    };
+  }
+  const message = entry.result.message;
+  const usage = message?.usage;
+  return {
+    id: entry.custom_id,
+    status,
+    text: (message?.content ?? [])
+      .filter((block) => block.type === "text" && typeof block.text === "string")
+      .map((block) => block.text)
+      .join(""),
+    stopReason: message?.stop_reason,
+    usage: {
+      input: usage?.input_tokens ?? 0,
+      output: usage?.output_tokens ?? 0,
+      cacheRead: usage?.cache_read_input_tokens || undefined,
+      cacheWrite: usage?.cache_creation_input_tokens || undefined,
+    },
+  };
+}
+
+export type AnthropicBatchClient = {
+  create: (requests: AnthropicBatchRequest[]) => Promise<AnthropicBatch>;
+  get: (batchId: string) => Promise<AnthropicBatch>;
+  cancel: (batchId: string) => Promise<AnthropicBatch>;
+  results: (batch: AnthropicBatch) => Promise<BatchResultLine[]>;
+};
+
+/** Minimal client for the Message Batches API (`/v1/messages/batches`). */
+export function createAnthropicBatchClient(params: {
+  apiKey: string;
+  baseUrl?: string;
+  fetchFn?: typeof fetch;
+}): AnthropicBatchClient {
+  const base = (params.baseUrl?.trim() || ANTHROPIC_API_BASE_URL).replace(/\/+$/, "");
+  const fetchFn = params.fetchFn ?? fetch;
+  const headers = {
+    "x-api-key": params.apiKey,
+    "anthropic-version": ANTHROPIC_VERSION,
+    "content-type": "application/json",
+  };
+
+  const request = async (url: string, init?: RequestInit): Promise<Response> => {
+    const res = await fetchFn(url, { ...init, headers: { ...headers, ...init?.headers } });
+    if (!res.ok) {
+      let detail = "";
+      try {
+        detail = describeBatchError(await res.json());
+      } catch {
+        // keep the status alone
+      }
+      throw new Error(`Anthropic batch API ${res.status}${detail ? `: ${detail}` : ""}`);
+    }
+    return res;
+  };
+
+  return {
+    create: async (requests) =>
+      (await (
+        await request(`${base}/v1/messages/batches`, {
+          method: "POST",
+          body: JSON.stringify({ requests }),
+        })
+      ).json()) as AnthropicBatch,
+    get: async (batchId) =>
+      (await (
+        await request(`${base}/v1/messages/batches/${encodeURIComponent(batchId)}`)
+      ).json()) as AnthropicBatch,
+    cancel: async (batchId) =>
+      (await (
+        await request(`${base}/v1/messages/batches/${encodeURIComponent(batchId)}/cancel`, {
+          method: "POST",
+        })
+      ).json()) as AnthropicBatch,
+    results: async (batch) => {
+      if (batch.processing_status !== "ended") {
+        throw new Error(`Batch ${batch.id} has not finished (${batch.processing_status})`);
+      }
+      const url =
+        batch.results_url ?? `${base}/v1/messages/batches/${encodeURIComponent(batch.id)}/results`;
+      const body = await (await request(url)).text();
+      return body
+        .split(/\r?\n/)
+        .filter((line) => line.trim())
+        .map((line) => normalizeBatchResult(JSON.parse(line)))
+        .filter((line): line is BatchResultLine => Boolean(line));
+    },
+  };
+}
+
+/** Polls until the batch ends (batches can take up to 24 hours). */
+export async function waitForAnthropicBatch(params: {
+  client: AnthropicBatchClient;
+  batchId: string;
+  intervalMs: number;
+  onProgress?: (batch: AnthropicBatch) => void;
+  sleep?: (ms: number) => Promise<void>;
+}): Promise<AnthropicBatch> {
+  const sleep = params.sleep ?? ((ms) => new Promise((resolve) => setTimeout(resolve, ms)));
+  for (;;) {
+    const batch = await params.client.get(params.batchId);
+    params.onProgress?.(batch);
+    if (batch.processing_status === "ended") {
+      return batch;
+    }
+    await sleep(params.intervalMs);
+  }
+}
+
+export function formatBatchCounts(batch: AnthropicBatch): string {
+  const counts = batch.request_counts;
+  const parts = [
+    `${counts.succeeded} succeeded`,
+    counts.processing > 0 ? `${counts.processing} processing` : undefined,
+    counts.errored > 0 ? `${counts.errored} errored` : undefined,
+    counts.canceled > 0 ? `${counts.canceled} canceled` : undefined,
+    counts.expired > 0 ? `${counts.expired} expired` : undefined,
+  ].filter(Boolean);
+  return `${batch.id}: ${batch.processing_status} (${parts.join(", ")})`;
+}
@@ -259,6 +259,11 @@ export function listRunningSessions() {
   return Array.from(runningSessions.values()).filter((s) => s.backgrounded);
 }
 
+/** Every session whose process is still running, including foreground ones. */
+export function listAllRunningSessions() {
+  return Array.from(runningSessions.values());
+}
+
 export function listFinishedSessions() {
   return Array.from(finishedSessions.values());
 }
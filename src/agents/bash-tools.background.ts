@@ -0,0 +1,63 @@
+import type { AgentTool } from "@mariozechner/pi-agent-core";
+import { Type } from "@sinclair/typebox";
+import { createProcessTool } from "./bash-tools.process.js";
+
+export type BackgroundTaskToolDefaults = {
+  scopeKey?: string;
+};
+
+const taskIdDescription = "Task id returned by a backgrounded exec call (its sessionId)";
+
+const bashOutputSchema = Type.Object({
+  taskId: Type.String({ description: taskIdDescription }),
+  timeout: Type.Optional(
+    Type.Number({ description: "Wait up to this many ms for new output or exit (max 120000)" }),
+  ),
+});
+
+const killTaskSchema = Type.Object({
+  taskId: Type.String({ description: taskIdDescription }),
+});
+
+/**
+ * `bash_output` and `kill_task`: single-purpose companions for `exec` runs started with
+ * `background`/`run_in_background`. They map onto `process poll`/`process kill`, so they share
+ * its session registry and per-agent scope.
+ */
+export function createBackgroundTaskTools(
+  defaults?: BackgroundTaskToolDefaults,
+  // oxlint-disable-next-line typescript/no-explicit-any
+): AgentTool<any>[] {
+  const processTool = createProcessTool({ scopeKey: defaults?.scopeKey });
+  return [
+    {
+      name: "bash_output",
+      label: "bash_output",
+      description:
+        "Read new output from a background exec task and report whether it is still running.",
+      parameters: bashOutputSchema,
+      execute: async (toolCallId, args, signal) => {
+        const params = args as { taskId: string; timeout?: number };
+        return processTool.execute(
+          toolCallId,
+          { action: "poll", sessionId: params.taskId, timeout: params.timeout },
+          signal,
+        );
+      },
+    },
+    {
+      name: "kill_task",
+      label: "kill_task",
+      description: "Stop a background exec task.",
+      parameters: killTaskSchema,
+      execute: async (toolCallId, args, signal) => {
+        const params = args as { taskId: string };
+        return processTool.execute(
+          toolCallId,
+          { action: "kill", sessionId: params.taskId },
+          signal,
+        );
+      },
+    },
+  ];
+}
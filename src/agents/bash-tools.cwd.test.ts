@@ -0,0 +1,72 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeAll, describe, expect, it } from "vitest";
+import {
+  getTrackedExecCwd,
+  getTrackedExecCwdWithin,
+  resetTrackedExecCwdsForTests,
+  resolveCdTarget,
+  resolveExecScopeKey,
+  setTrackedExecCwd,
+} from "./bash-tools.cwd.js";
+
+let root = "";
+
+beforeAll(async () => {
+  root = await fs.realpath(await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cwd-")));
+  await fs.mkdir(path.join(root, "pkg", "api"), { recursive: true });
+});
+
+afterEach(() => {
+  resetTrackedExecCwdsForTests();
+});
+
+describe("resolveCdTarget", () => {
+  it("follows top-level cd segments", () => {
+    expect(resolveCdTarget("cd pkg && npm test", root)).toBe(path.join(root, "pkg"));
+    expect(resolveCdTarget("cd pkg; cd api", root)).toBe(path.join(root, "pkg", "api"));
+    expect(resolveCdTarget("cd 'pkg/api'", root)).toBe(path.join(root, "pkg", "api"));
+    expect(resolveCdTarget("cd ..", path.join(root, "pkg"))).toBe(root);
+  });
+
+  it("ignores commands it cannot analyze safely", () => {
+    expect(resolveCdTarget("ls -la", root)).toBeUndefined();
+    expect(resolveCdTarget("cd $HOME", root)).toBeUndefined();
+    expect(resolveCdTarget("cd -", root)).toBeUndefined();
+    expect(resolveCdTarget("(cd pkg && ls)", root)).toBeUndefined();
+    expect(resolveCdTarget("cd pkg | cat", root)).toBeUndefined();
+    expect(resolveCdTarget("cd missing || true", root)).toBeUndefined();
+    expect(resolveCdTarget("cd does-not-exist", root)).toBeUndefined();
+  });
+
+  it.skipIf(process.platform !== "win32")("handles PowerShell locations and backslashes", () => {
+    expect(resolveCdTarget("Set-Location pkg\\api", root)).toBe(path.join(root, "pkg", "api"));
+    expect(resolveCdTarget(`cd -Path "${root}\\pkg"`, "C:\\")).toBe(path.join(root, "pkg"));
+    expect(resolveCdTarget("cd", root)).toBeUndefined();
+  });
+});
+
+describe("tracked exec cwd", () => {
+  it("drops entries whose directory disappeared", async () => {
+    const gone = path.join(root, "gone");
+    await fs.mkdir(gone);
+    setTrackedExecCwd("scope", gone);
+    expect(getTrackedExecCwd("scope")).toBe(gone);
+    await fs.rmdir(gone);
+    expect(getTrackedExecCwd("scope")).toBeUndefined();
+  });
+
+  it("scopes by session key, else agent, and can be limited to roots", () => {
+    expect(resolveExecScopeKey({ sessionKey: "agent:main:main", agentId: "main" })).toBe(
+      "agent:main:main",
+    );
+    expect(resolveExecScopeKey({ agentId: "main" })).toBe("agent:main");
+    expect(resolveExecScopeKey({})).toBeUndefined();
+
+    const api = path.join(root, "pkg", "api");
+    setTrackedExecCwd("scope", api);
+    expect(getTrackedExecCwdWithin("scope", [undefined, path.join(root, "pkg")])).toBe(api);
+    expect(getTrackedExecCwdWithin("scope", [path.join(root, "other")])).toBeUndefined();
+  });
+});
@@ -0,0 +1,166 @@
+import { statSync } from "node:fs";
+import { homedir } from "node:os";
+import path from "node:path";
+import { resolveUserPath } from "../utils.js";
+
+// Tracks the exec working directory per scope so `cd` carries over between calls
+// when no persistent shell is running.
+const trackedCwds = new Map<string, string>();
+
+function isDirectory(dir: string) {
+  try {
+    return statSync(dir).isDirectory();
+  } catch {
+    return false;
+  }
+}
+
+/** Scope of per-session exec state (tracked cwd, processes): the session key, else the agent. */
+export function resolveExecScopeKey(params: {
+  sessionKey?: string;
+  agentId?: string;
+}): string | undefined {
+  return params.sessionKey ?? (params.agentId ? `agent:${params.agentId}` : undefined);
+}
+
+export function getTrackedExecCwd(scopeKey?: string): string | undefined {
+  if (!scopeKey) {
+    return undefined;
+  }
+  const cwd = trackedCwds.get(scopeKey);
+  if (!cwd) {
+    return undefined;
+  }
+  if (!isDirectory(cwd)) {
+    trackedCwds.delete(scopeKey);
+    return undefined;
+  }
+  return cwd;
+}
+
+/**
+ * The tracked cwd, but only when it lies inside one of `roots`. Features that pick a repository
+ * from it (/commit, project memory) must not follow a `cd` into an arbitrary directory.
+ */
+export function getTrackedExecCwdWithin(
+  scopeKey: string | undefined,
+  roots: Array<string | undefined>,
+): string | undefined {
+  const cwd = getTrackedExecCwd(scopeKey);
+  if (!cwd) {
+    return undefined;
+  }
+  const resolved = path.resolve(cwd);
+  const inside = roots.some((root) => {
+    if (!root) {
+      return false;
+    }
+    const rel = path.relative(resolveUserPath(root), resolved);
+    return rel === "" || (!rel.startsWith("..") && !path.isAbsolute(rel));
+  });
+  return inside ? cwd : undefined;
+}
+
+export function setTrackedExecCwd(scopeKey: string | undefined, cwd: string | undefined) {
+  if (!scopeKey || !cwd) {
+    return;
+  }
+  trackedCwds.set(scopeKey, cwd);
+}
+
+export function clearTrackedExecCwd(scopeKey?: string) {
+  if (scopeKey) {
+    trackedCwds.delete(scopeKey);
+  }
+}
+
+// PowerShell (the Windows exec shell) uses backtick escapes, so backslashes are plain separators.
+const isWindows = process.platform === "win32";
+const CD_COMMANDS = isWindows
+  ? new Set(["cd", "chdir", "sl", "set-location", "pushd", "push-location"])
+  : new Set(["cd", "pushd"]);
+const CD_FLAGS = isWindows ? new Set(["-path", "-literalpath"]) : new Set(["--", "-P"]);
+
+function unquote(token: string): string | null {
+  if (token.length >= 2) {
+    const first = token[0];
+    const last = token[token.length - 1];
+    if ((first === "'" || first === '"') && first === last) {
+      const inner = token.slice(1, -1);
+      if (first === '"' && (isWindows ? /[$`]/ : /[$`\\]/).test(inner)) {
+        return null;
+      }
+      return inner;
+    }
+  }
+  const unsafe = isWindows ? /["'$`*?[\]{}()<>|&;~]/ : /["'$`\\*?[\]{}()<>|&;~]/;
+  if (unsafe.test(token.slice(token.startsWith("~") ? 1 : 0))) {
+    return null;
+  }
+  return token;
+}
+
+/**
+ * Resolve the directory a command leaves the shell in, based on top-level `cd` segments.
+ * Returns undefined when the command does not change directories or cannot be analyzed
+ * safely (pipes, subshells, variables, `cd -`).
+ */
+export function resolveCdTarget(command: string, cwd: string): string | undefined {
+  if (/[|()`\n]|\$\(/.test(command.replace(/\|\|/g, ""))) {
+    return undefined;
+  }
+  const segments = command.split(/&&|;/);
+  let current = cwd;
+  let changed = false;
+  for (const raw of segments) {
+    const segment = raw.trim();
+    if (!segment) {
+      continue;
+    }
+    if (segment.includes("||")) {
+      // Conditional cd targets depend on exit status; bail out.
+      if (segment.split(/\s+/).some((token) => CD_COMMANDS.has(token.toLowerCase()))) {
+        return undefined;
+      }
+      continue;
+    }
+    const tokens = segment.split(/\s+/);
+    const commandName = isWindows ? tokens[0].toLowerCase() : tokens[0];
+    if (!CD_COMMANDS.has(commandName)) {
+      continue;
+    }
+    const args = tokens
+      .slice(1)
+      .filter((token) => !CD_FLAGS.has(isWindows ? token.toLowerCase() : token));
+    if (args.length > 1) {
+      return undefined;
+    }
+    if (isWindows && args.length === 0) {
+      // Bare `cd` is a no-op in Windows PowerShell 5.1 but goes home in 7.x.
+      return undefined;
+    }
+    const target = args[0] === undefined ? "~" : unquote(args[0]);
+    if (target === null || target === "-" || target.startsWith("-")) {
+      return undefined;
+    }
+    const expanded =
+      target === "~" || target.startsWith("~/") || (isWindows && target.startsWith("~\\"))
+        ? path.join(homedir(), target.slice(1))
+        : target.startsWith("~")
+          ? null
+          : target;
+    if (expanded === null) {
+      return undefined;
+    }
+    current = path.resolve(current, expanded);
+    changed = true;
+  }
+  if (!changed || !isDirectory(current)) {
+    return undefined;
+  }
+  return current;
+}
+
+export function resetTrackedExecCwdsForTests() {
+  trackedCwds.clear();
+}
@@ -4,7 +4,13 @@ import { afterEach, beforeEach, describe, expect, it } from "vitest";
 import { peekSystemEvents, resetSystemEventsForTest } from "../infra/system-events.js";
 import { sleep } from "../utils.js";
 import { getFinishedSession, resetProcessRegistryForTests } from "./bash-process-registry.js";
-import { createExecTool, createProcessTool, execTool, processTool } from "./bash-tools.js";
+import {
+  createBackgroundTaskTools,
+  createExecTool,
+  createProcessTool,
+  execTool,
+  processTool,
+} from "./bash-tools.js";
 import { buildDockerExecArgs } from "./bash-tools.shared.js";
 import { sanitizeBinaryOutput } from "./shell-utils.js";
 
@@ -130,6 +136,42 @@ describe("exec tool backgrounding", () => {
     expect(sessions.some((s) => s.sessionId === sessionId)).toBe(true);
   });
 
+  it("accepts run_in_background and waits on poll timeout", async () => {
+    const result = await execTool.execute("call1", {
+      command: echoAfterDelay("waited"),
+      run_in_background: true,
+    });
+
+    expect(result.details.status).toBe("running");
+    const sessionId = (result.details as { sessionId: string }).sessionId;
+
+    const poll = await processTool.execute("call2", {
+      action: "poll",
+      sessionId,
+      timeout: isWin ? 8000 : 2000,
+    });
+    const text = poll.content.find((c) => c.type === "text")?.text ?? "";
+    expect(normalizeText(text)).toContain("waited");
+  });
+
+  it("reads and kills background tasks via bash_output and kill_task", async () => {
+    const [bashOutput, killTask] = createBackgroundTaskTools();
+    const result = await execTool.execute("call1", {
+      command: joinCommands(["echo started", longDelayCmd]),
+      run_in_background: true,
+    });
+    const taskId = (result.details as { sessionId: string }).sessionId;
+
+    const output = await bashOutput.execute("call2", { taskId, timeout: isWin ? 8000 : 2000 });
+    expect(output.details.status).toBe("running");
+    const text = output.content.find((c) => c.type === "text")?.text ?? "";
+    expect(normalizeText(text)).toContain("started");
+
+    const killed = await killTask.execute("call3", { taskId });
+    expect(killed.content.find((c) => c.type === "text")?.text).toBe(`Killed session ${taskId}.`);
+    expect(getFinishedSession(taskId)?.exitSignal).toBe("SIGKILL");
+  });
+
   it("derives a session name from the command", async () => {
     const result = await execTool.execute("call1", {
       command: "echo hello",
@@ -5,11 +5,13 @@ import path from "node:path";
 import type { ExecAsk, ExecHost, ExecSecurity } from "../infra/exec-approvals.js";
 import type { ProcessSession, SessionStdin } from "./bash-process-registry.js";
 import type { ExecToolDetails } from "./bash-tools.exec.js";
+import type { ExecOsSandbox } from "./bash-tools.os-sandbox.js";
 import type { BashSandboxConfig } from "./bash-tools.shared.js";
 import { requestHeartbeatNow } from "../infra/heartbeat-wake.js";
 import { enqueueSystemEvent } from "../infra/system-events.js";
 import { logWarn } from "../logger.js";
 import { formatSpawnError, spawnWithFallback } from "../process/spawn-utils.js";
+import { createTerminalSanitizer } from "../terminal/ansi.js";
 import {
   addSession,
   appendOutput,
@@ -17,6 +19,7 @@ import {
   markExited,
   tail,
 } from "./bash-process-registry.js";
+import { wrapArgvWithOsSandbox } from "./bash-tools.os-sandbox.js";
 import {
   buildDockerExecArgs,
   chunkString,
@@ -94,11 +97,17 @@ export const DEFAULT_NOTIFY_TAIL_CHARS = 400;
 export const DEFAULT_APPROVAL_TIMEOUT_MS = 120_000;
 export const DEFAULT_APPROVAL_REQUEST_TIMEOUT_MS = 130_000;
 const DEFAULT_APPROVAL_RUNNING_NOTICE_MS = 10_000;
+const UPDATE_THROTTLE_MS = 250;
 const APPROVAL_SLUG_LENGTH = 8;
 
 export const execSchema = Type.Object({
   command: Type.String({ description: "Shell command to execute" }),
-  workdir: Type.Optional(Type.String({ description: "Working directory (defaults to cwd)" })),
+  workdir: Type.Optional(
+    Type.String({
+      description: "Working directory (defaults to the directory left by the last cd, then cwd)",
+    }),
+  ),
+  cwd: Type.Optional(Type.String({ description: "Alias for workdir" })),
   env: Type.Optional(Type.Record(Type.String(), Type.String())),
   yieldMs: Type.Optional(
     Type.Number({
@@ -106,6 +115,9 @@ export const execSchema = Type.Object({
     }),
   ),
   background: Type.Optional(Type.Boolean({ description: "Run in background immediately" })),
+  run_in_background: Type.Optional(
+    Type.Boolean({ description: "Alias for background (Claude Code style)" }),
+  ),
   timeout: Type.Optional(
     Type.Number({
       description: "Timeout in seconds (optional, kills process on expiry)",
@@ -142,17 +154,23 @@ export const execSchema = Type.Object({
       description: "Node id/name for host=node.",
     }),
   ),
+  restart: Type.Optional(
+    Type.Boolean({
+      description:
+        "Restart the persistent shell before running (when tools.exec.persistentShell is enabled)",
+    }),
+  ),
 });
 
 type PtyExitEvent = { exitCode: number; signal?: number };
 type PtyListener<T> = (event: T) => void;
-type PtyHandle = {
+export type PtyHandle = {
   pid: number;
   write: (data: string | Buffer) => void;
   onData: (listener: PtyListener<string>) => void;
   onExit: (listener: PtyListener<PtyExitEvent>) => void;
 };
-type PtySpawn = (
+export type PtySpawn = (
   file: string,
   args: string[] | string,
   options: {
@@ -345,6 +363,7 @@ export async function runExecProcess(opts: {
   env: Record<string, string>;
   sandbox?: BashSandboxConfig;
   containerWorkdir?: string | null;
+  osSandbox?: ExecOsSandbox | null;
   usePty: boolean;
   warnings: string[];
   maxOutput: number;
@@ -361,6 +380,11 @@ export async function runExecProcess(opts: {
   let pty: PtyHandle | null = null;
   let stdin: SessionStdin | undefined;
   const execCommand = opts.execCommand ?? opts.command;
+  const buildShellArgv = () => {
+    const { shell, args: shellArgs } = getShellConfig();
+    const argv = [shell, ...shellArgs, execCommand];
+    return opts.osSandbox ? wrapArgvWithOsSandbox(argv, opts.osSandbox, opts.workdir) : argv;
+  };
 
   if (opts.sandbox) {
     const { child: spawned } = await spawnWithFallback({
@@ -397,7 +421,7 @@ export async function runExecProcess(opts: {
     child = spawned as ChildProcessWithoutNullStreams;
     stdin = child.stdin;
   } else if (opts.usePty) {
-    const { shell, args: shellArgs } = getShellConfig();
+    const shellArgv = buildShellArgv();
     try {
       const ptyModule = (await import("@lydell/node-pty")) as unknown as {
         spawn?: PtySpawn;
@@ -407,7 +431,7 @@ export async function runExecProcess(opts: {
       if (!spawnPty) {
         throw new Error("PTY support is unavailable (node-pty spawn not found).");
       }
-      pty = spawnPty(shell, [...shellArgs, execCommand], {
+      pty = spawnPty(shellArgv[0], shellArgv.slice(1), {
         cwd: opts.workdir,
         env: opts.env,
         name: process.env.TERM ?? "xterm-256color",
@@ -439,7 +463,7 @@ export async function runExecProcess(opts: {
       logWarn(`exec: PTY spawn failed (${errText}); retrying without PTY for "${opts.command}".`);
       opts.warnings.push(warning);
       const { child: spawned } = await spawnWithFallback({
-        argv: [shell, ...shellArgs, execCommand],
+        argv: shellArgv,
         options: {
           cwd: opts.workdir,
           env: opts.env,
@@ -464,9 +488,8 @@ export async function runExecProcess(opts: {
       stdin = child.stdin;
     }
   } else {
-    const { shell, args: shellArgs } = getShellConfig();
     const { child: spawned } = await spawnWithFallback({
-      argv: [shell, ...shellArgs, execCommand],
+      argv: buildShellArgv(),
       options: {
         cwd: opts.workdir,
         env: opts.env,
@@ -570,10 +593,14 @@ export async function runExecProcess(opts: {
     }, opts.timeoutSec * 1000);
   }
 
-  const emitUpdate = () => {
+  let lastUpdateAt = 0;
+  let updateTimer: NodeJS.Timeout | null = null;
+  const flushUpdate = () => {
     if (!opts.onUpdate) {
       return;
     }
+    updateTimer = null;
+    lastUpdateAt = Date.now();
     const tailText = session.tail || session.aggregated;
     const warningText = opts.warnings.length ? `${opts.warnings.join("\n")}\n\n` : "";
     opts.onUpdate({
@@ -589,22 +616,40 @@ export async function runExecProcess(opts: {
     });
   };
 
-  const handleStdout = (data: string) => {
-    const str = sanitizeBinaryOutput(data.toString());
-    for (const chunk of chunkString(str)) {
-      appendOutput(session, "stdout", chunk);
-      emitUpdate();
+  // Throttle partial results so chatty commands do not flood the event stream.
+  const emitUpdate = () => {
+    if (!opts.onUpdate || updateTimer || session.exited) {
+      return;
     }
+    const waitMs = lastUpdateAt + UPDATE_THROTTLE_MS - Date.now();
+    if (waitMs <= 0) {
+      flushUpdate();
+      return;
+    }
+    updateTimer = setTimeout(flushUpdate, waitMs);
+    updateTimer.unref?.();
   };
 
-  const handleStderr = (data: string) => {
-    const str = sanitizeBinaryOutput(data.toString());
+  // Escape sequences can straddle data events; each stream keeps its own partial state.
+  const stdoutSanitizer = createTerminalSanitizer();
+  const stderrSanitizer = createTerminalSanitizer();
+
+  const appendSanitized = (stream: "stdout" | "stderr", text: string) => {
+    const str = sanitizeBinaryOutput(text);
     for (const chunk of chunkString(str)) {
-      appendOutput(session, "stderr", chunk);
+      appendOutput(session, stream, chunk);
       emitUpdate();
     }
   };
 
+  const handleStdout = (data: string) => {
+    appendSanitized("stdout", stdoutSanitizer.push(data.toString()));
+  };
+
+  const handleStderr = (data: string) => {
+    appendSanitized("stderr", stderrSanitizer.push(data.toString()));
+  };
+
   if (pty) {
     const cursorResponse = buildCursorPositionResponse();
     pty.onData((data) => {
@@ -625,9 +670,15 @@ export async function runExecProcess(opts: {
   const promise = new Promise<ExecProcessOutcome>((resolve) => {
     resolveFn = resolve;
     const handleExit = (code: number | null, exitSignal: NodeJS.Signals | number | null) => {
+      appendSanitized("stdout", stdoutSanitizer.flush());
+      appendSanitized("stderr", stderrSanitizer.flush());
       if (timeoutTimer) {
         clearTimeout(timeoutTimer);
       }
+      if (updateTimer) {
+        clearTimeout(updateTimer);
+        updateTimer = null;
+      }
       if (timeoutFinalizeTimer) {
         clearTimeout(timeoutFinalizeTimer);
       }
@@ -123,3 +123,50 @@ describe("exec host env validation", () => {
     ).rejects.toThrow(/Security Violation: Environment variable 'LD_DEBUG' is forbidden/);
   });
 });
+
+describe("exec env scrubbing", () => {
+  const originalKey = process.env.ANTHROPIC_API_KEY;
+
+  afterEach(() => {
+    if (originalKey === undefined) {
+      delete process.env.ANTHROPIC_API_KEY;
+    } else {
+      process.env.ANTHROPIC_API_KEY = originalKey;
+    }
+  });
+
+  it("scrubs provider keys by default and injects config env", async () => {
+    if (isWin) {
+      return;
+    }
+    process.env.ANTHROPIC_API_KEY = "sk-secret";
+
+    const { createExecTool } = await import("./bash-tools.exec.js");
+    const tool = createExecTool({
+      host: "gateway",
+      security: "full",
+      ask: "off",
+      env: { OPENCLAW_EXEC_INJECTED: "injected" },
+    });
+    const result = await tool.execute("call1", {
+      command: 'echo "${ANTHROPIC_API_KEY:-unset}:$OPENCLAW_EXEC_INJECTED"',
+    });
+
+    expect(normalizeText(result.content.find((c) => c.type === "text")?.text)).toBe(
+      "unset:injected",
+    );
+  });
+
+  it("inherits everything when scrubEnv is empty", async () => {
+    if (isWin) {
+      return;
+    }
+    process.env.ANTHROPIC_API_KEY = "sk-secret";
+
+    const { createExecTool } = await import("./bash-tools.exec.js");
+    const tool = createExecTool({ host: "gateway", security: "full", ask: "off", scrubEnv: [] });
+    const result = await tool.execute("call1", { command: 'echo "$ANTHROPIC_API_KEY"' });
+
+    expect(normalizeText(result.content.find((c) => c.type === "text")?.text)).toBe("sk-secret");
+  });
+});
@@ -0,0 +1,104 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, describe, expect, it } from "vitest";
+import { resetProcessRegistryForTests } from "./bash-process-registry.js";
+import { createExecTool } from "./bash-tools.exec.js";
+import { resetPersistentShellsForTests } from "./bash-tools.persistent-shell.js";
+
+const isWin = process.platform === "win32";
+
+afterEach(() => {
+  resetPersistentShellsForTests();
+  resetProcessRegistryForTests();
+});
+
+describe.skipIf(isWin)("exec persistent shell", () => {
+  const createTool = () =>
+    createExecTool({
+      host: "gateway",
+      security: "full",
+      ask: "off",
+      allowBackground: false,
+      scopeKey: "persistent-test",
+      persistentShell: { enabled: true },
+    });
+
+  it("keeps cwd and exported variables across calls", async () => {
+    const dir = await fs.realpath(await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-shell-")));
+    const tool = createTool();
+
+    await tool.execute("call1", { command: `cd ${dir} && export OPENCLAW_SHELL_TEST=kept` });
+    const result = await tool.execute("call2", { command: 'echo "$PWD:$OPENCLAW_SHELL_TEST"' });
+
+    expect(result.details.status).toBe("completed");
+    expect(result.content?.[0]?.text?.trim()).toBe(`${dir}:kept`);
+    expect((result.details as { cwd?: string }).cwd).toBe(dir);
+  });
+
+  it("applies per-call env to that command only", async () => {
+    const tool = createTool();
+
+    const scoped = await tool.execute("call1", {
+      command: 'echo "value:$OPENCLAW_SHELL_TEST"',
+      env: { OPENCLAW_SHELL_TEST: "scoped" },
+    });
+    expect(scoped.content?.[0]?.text?.trim()).toBe("value:scoped");
+    const result = await tool.execute("call2", {
+      command: 'echo "value:${OPENCLAW_SHELL_TEST:-unset}"',
+    });
+    expect(result.content?.[0]?.text?.trim()).toBe("value:unset");
+  });
+
+  it("runs each command in the directory it was resolved against", async () => {
+    const dir = await fs.realpath(await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-shell-")));
+    const other = await fs.realpath(await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-shell-")));
+    const tool = createExecTool({
+      host: "gateway",
+      security: "full",
+      ask: "off",
+      allowBackground: false,
+      scopeKey: "persistent-untracked",
+      trackCwd: false,
+      persistentShell: { enabled: true },
+    });
+
+    await tool.execute("call1", { command: `cd ${dir}` });
+    const moved = await tool.execute("call2", { command: "pwd", workdir: other });
+    expect(moved.content?.[0]?.text?.trim()).toBe(other);
+    const result = await tool.execute("call3", { command: "pwd" });
+    expect(result.content?.[0]?.text?.trim()).toBe(other);
+    expect((result.details as { cwd?: string }).cwd).toBe(other);
+  });
+
+  it("reports non-zero exits without losing the shell", async () => {
+    const tool = createTool();
+
+    await tool.execute("call1", { command: "OPENCLAW_SHELL_VAR=still-here" });
+    await expect(tool.execute("call2", { command: "echo nope; false" })).rejects.toThrow(
+      /nope[\s\S]*Command exited with code 1/,
+    );
+    const result = await tool.execute("call3", { command: 'echo "$OPENCLAW_SHELL_VAR"' });
+    expect(result.content?.[0]?.text?.trim()).toBe("still-here");
+  });
+
+  it("drops shell state on restart", async () => {
+    const tool = createTool();
+
+    await tool.execute("call1", { command: "export OPENCLAW_SHELL_TEST=gone" });
+    const restarted = await tool.execute("call2", { restart: true });
+    expect(restarted.content?.[0]?.text).toContain("restarted");
+
+    const result = await tool.execute("call3", {
+      command: 'echo "value:${OPENCLAW_SHELL_TEST:-unset}"',
+    });
+    expect(result.content?.[0]?.text?.trim()).toBe("value:unset");
+  });
+
+  it("does not let commands read the control stream", async () => {
+    const tool = createTool();
+
+    const result = await tool.execute("call1", { command: "cat; echo done" });
+    expect(result.content?.[0]?.text?.trim()).toBe("done");
+  });
+});
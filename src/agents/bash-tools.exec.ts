@@ -1,5 +1,6 @@
 import type { AgentTool, AgentToolResult } from "@mariozechner/pi-agent-core";
 import crypto from "node:crypto";
+import type { ExecOsSandboxConfig } from "./bash-tools.os-sandbox.js";
 import type { BashSandboxConfig } from "./bash-tools.shared.js";
 import {
   type ExecAsk,
@@ -26,6 +27,7 @@ import {
 import { logInfo } from "../logger.js";
 import { parseAgentSessionKey, resolveAgentIdFromSessionKey } from "../routing/session-key.js";
 import { markBackgrounded, tail } from "./bash-process-registry.js";
+import { getTrackedExecCwd, resolveCdTarget, setTrackedExecCwd } from "./bash-tools.cwd.js";
 import {
   DEFAULT_APPROVAL_REQUEST_TIMEOUT_MS,
   DEFAULT_APPROVAL_TIMEOUT_MS,
@@ -49,13 +51,22 @@ import {
   type ExecProcessHandle,
   validateHostEnv,
 } from "./bash-tools.exec-runtime.js";
+import { resolveExecOsSandbox } from "./bash-tools.os-sandbox.js";
+import {
+  closePersistentShell,
+  executeInPersistentShell,
+  getPersistentShellCwd,
+  restartPersistentShell,
+} from "./bash-tools.persistent-shell.js";
 import {
   buildSandboxEnv,
   clampWithDefault,
   coerceEnv,
+  DEFAULT_EXEC_SCRUB_ENV,
   readEnvInt,
   resolveSandboxWorkdir,
   resolveWorkdir,
+  scrubEnv,
   truncateMiddle,
 } from "./bash-tools.shared.js";
 import { callGatewayTool } from "./tools/gateway.js";
@@ -80,6 +91,16 @@ export type ExecToolDefaults = {
   messageProvider?: string;
   notifyOnExit?: boolean;
   cwd?: string;
+  persistentShell?: ExecPersistentShellDefaults;
+  trackCwd?: boolean;
+  env?: Record<string, string>;
+  scrubEnv?: string[];
+  osSandbox?: ExecOsSandboxConfig;
+};
+
+export type ExecPersistentShellDefaults = {
+  enabled?: boolean;
+  idleMs?: number;
 };
 
 export type { BashSandboxConfig } from "./bash-tools.shared.js";
@@ -134,9 +155,15 @@ export function createExecTool(
       : 1800;
   const defaultPathPrepend = normalizePathPrepend(defaults?.pathPrepend);
   const safeBins = resolveSafeBins(defaults?.safeBins);
+  const configEnv = defaults?.env ? coerceEnv(defaults.env) : {};
+  const scrubPatterns = defaults?.scrubEnv ?? DEFAULT_EXEC_SCRUB_ENV;
   const notifyOnExit = defaults?.notifyOnExit !== false;
   const notifySessionKey = defaults?.sessionKey?.trim() || undefined;
   const approvalRunningNoticeMs = resolveApprovalRunningNoticeMs(defaults?.approvalRunningNoticeMs);
+  const persistentShellEnabled =
+    defaults?.persistentShell?.enabled === true && process.platform !== "win32";
+  const persistentShellKey = defaults?.scopeKey ?? defaults?.sessionKey ?? "default";
+  const cwdScopeKey = defaults?.trackCwd === false ? undefined : defaults?.scopeKey;
   // Derive agentId only when sessionKey is an agent session key.
   const parsedAgentSession = parseAgentSessionKey(defaults?.sessionKey);
   const agentId =
@@ -153,9 +180,11 @@ export function createExecTool(
       const params = args as {
         command: string;
         workdir?: string;
+        cwd?: string;
         env?: Record<string, string>;
         yieldMs?: number;
         background?: boolean;
+        run_in_background?: boolean;
         timeout?: number;
         pty?: boolean;
         elevated?: boolean;
@@ -163,8 +192,16 @@ export function createExecTool(
         security?: string;
         ask?: string;
         node?: string;
+        restart?: boolean;
       };
 
+      if (params.restart === true && persistentShellEnabled) {
+        if (!params.command?.trim()) {
+          return restartPersistentShell(persistentShellKey);
+        }
+        closePersistentShell(persistentShellKey);
+      }
+
       if (!params.command) {
         throw new Error("Provide a command to start.");
       }
@@ -173,7 +210,8 @@ export function createExecTool(
       const pendingMaxOutput = DEFAULT_PENDING_MAX_OUTPUT;
       const warnings: string[] = [];
       let execCommandOverride: string | undefined;
-      const backgroundRequested = params.background === true;
+      const backgroundRequested =
+        params.background === true || params.run_in_background === true;
       const yieldRequested = typeof params.yieldMs === "number";
       if (!allowBackground && (backgroundRequested || yieldRequested)) {
         warnings.push("Warning: background execution is disabled; running synchronously.");
@@ -275,7 +313,16 @@ export function createExecTool(
       }
 
       const sandbox = host === "sandbox" ? defaults?.sandbox : undefined;
-      const rawWorkdir = params.workdir?.trim() || defaults?.cwd || process.cwd();
+      const requestedWorkdir = params.workdir?.trim() || params.cwd?.trim() || undefined;
+      // Carry `cd` from earlier calls forward (host-local runs only).
+      const trackCwd = Boolean(cwdScopeKey) && !sandbox && host !== "node";
+      const trackedCwd = trackCwd ? getTrackedExecCwd(cwdScopeKey) : undefined;
+      // A persistent shell runs every command in `workdir` (see runInPersistentShell), so
+      // allowlist checks below see the directory the command actually runs in.
+      const shellCwd =
+        persistentShellEnabled && !sandbox ? getPersistentShellCwd(persistentShellKey) : undefined;
+      const rawWorkdir =
+        requestedWorkdir || trackedCwd || shellCwd || defaults?.cwd || process.cwd();
       let workdir = rawWorkdir;
       let containerWorkdir = sandbox?.containerWorkdir;
       if (sandbox) {
@@ -290,7 +337,8 @@ export function createExecTool(
         workdir = resolveWorkdir(rawWorkdir, warnings);
       }
 
-      const baseEnv = coerceEnv(process.env);
+      // Config env is trusted and re-adds anything the scrub list removed on purpose.
+      const baseEnv = { ...scrubEnv(coerceEnv(process.env), scrubPatterns), ...configEnv };
 
       // Logic: Sandbox gets raw env. Host (gateway/node) must pass validation.
       // We validate BEFORE merging to prevent any dangerous vars from entering the stream.
@@ -304,7 +352,7 @@ export function createExecTool(
         ? buildSandboxEnv({
             defaultPath: DEFAULT_PATH,
             paramsEnv: params.env,
-            sandboxEnv: sandbox.env,
+            sandboxEnv: { ...sandbox.env, ...configEnv },
             containerWorkdir: containerWorkdir ?? sandbox.containerWorkdir,
           })
         : mergedEnv;
@@ -318,6 +366,16 @@ export function createExecTool(
       }
       applyPathPrepend(env, defaultPathPrepend);
 
+      const osSandbox =
+        !sandbox && host !== "node"
+          ? resolveExecOsSandbox({
+              config: defaults?.osSandbox,
+              // The writable root is fixed per agent; a per-call workdir must not widen it.
+              workspaceDir: defaults?.cwd ?? process.cwd(),
+              warnings,
+            })
+          : null;
+
       if (host === "node") {
         const approvals = resolveExecApprovals(agentId, { security, ask });
         const hostSecurity = minSecurity(security, approvals.agent.security);
@@ -596,9 +654,11 @@ export function createExecTool(
         };
       }
 
+      let effectiveSecurity = security;
       if (host === "gateway" && !bypassApprovals) {
         const approvals = resolveExecApprovals(agentId, { security, ask });
         const hostSecurity = minSecurity(security, approvals.agent.security);
+        effectiveSecurity = hostSecurity;
         const hostAsk = maxAsk(ask, approvals.agent.ask);
         const askFallback = approvals.agent.askFallback;
         if (hostSecurity === "deny") {
@@ -739,6 +799,7 @@ export function createExecTool(
                 env,
                 sandbox: undefined,
                 containerWorkdir: null,
+                osSandbox,
                 usePty: params.pty === true && !sandbox,
                 warnings,
                 maxOutput,
@@ -866,6 +927,48 @@ export function createExecTool(
         typeof params.timeout === "number" ? params.timeout : defaultTimeoutSec;
       const getWarningText = () => (warnings.length ? `${warnings.join("\n")}\n\n` : "");
       const usePty = params.pty === true && !sandbox;
+
+      // Persistent shells keep cwd/exports/venvs across calls; background and PTY
+      // requests still get a dedicated process so they can be polled via `process`.
+      // Allowlist and approval checks resolve the command against a clean environment, but a
+      // long-lived shell runs it with whatever PATH, functions, and aliases earlier calls left
+      // behind, so only unrestricted (security=full) exec uses one.
+      if (
+        persistentShellEnabled &&
+        effectiveSecurity === "full" &&
+        !sandbox &&
+        !osSandbox &&
+        !backgroundRequested &&
+        !params.pty
+      ) {
+        // The shell outlives this call, so it starts from the base env; per-call `env` applies
+        // to this command only.
+        const shellEnv = { ...baseEnv };
+        if (host === "gateway") {
+          applyShellPath(
+            shellEnv,
+            getShellPathFromLoginShell({
+              env: process.env,
+              timeoutMs: resolveShellEnvFallbackTimeoutMs(process.env),
+            }),
+          );
+        }
+        applyPathPrepend(shellEnv, defaultPathPrepend);
+        return await executeInPersistentShell({
+          key: persistentShellKey,
+          command: execCommandOverride ?? params.command,
+          workdir,
+          idleMs: defaults?.persistentShell?.idleMs,
+          env: shellEnv,
+          commandEnv: params.env,
+          timeoutSec: effectiveTimeout,
+          maxOutput,
+          signal,
+          warningText: getWarningText,
+          onCwd: trackCwd ? (cwd) => setTrackedExecCwd(cwdScopeKey, cwd) : undefined,
+          onUpdate,
+        });
+      }
       const run = await runExecProcess({
         command: params.command,
         execCommand: execCommandOverride,
@@ -873,6 +976,7 @@ export function createExecTool(
         env,
         sandbox,
         containerWorkdir,
+        osSandbox,
         usePty,
         warnings,
         maxOutput,
@@ -961,6 +1065,9 @@ export function createExecTool(
               reject(new Error(outcome.reason ?? "Command failed."));
               return;
             }
+            if (trackCwd) {
+              setTrackedExecCwd(cwdScopeKey, resolveCdTarget(params.command, workdir));
+            }
             resolve({
               content: [
                 {
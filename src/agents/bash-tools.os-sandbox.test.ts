@@ -0,0 +1,114 @@
+import fs from "node:fs";
+import os from "node:os";
+import { describe, expect, it, vi } from "vitest";
+
+vi.mock("./shell-utils.js", () => ({
+  resolveShellFromPath: (name: string) => `/usr/bin/${name}`,
+}));
+
+import {
+  buildSandboxExecProfile,
+  resolveExecOsSandbox,
+  wrapArgvWithOsSandbox,
+  type ExecOsSandbox,
+} from "./bash-tools.os-sandbox.js";
+
+const base: ExecOsSandbox = {
+  backend: "bwrap",
+  workspaceDir: "/work/space",
+  network: true,
+  allowRead: [],
+  allowWrite: [],
+  hidden: ["/home/ada", "/home/ada/.openclaw"],
+};
+
+describe("resolveExecOsSandbox", () => {
+  it("is disabled by default", () => {
+    expect(resolveExecOsSandbox({ workspaceDir: "/work/space" })).toBeNull();
+  });
+
+  it("warns and disables under auto when no backend exists", () => {
+    const warnings: string[] = [];
+    const resolved = resolveExecOsSandbox({
+      config: { backend: "auto" },
+      workspaceDir: "/work/space",
+      platform: "win32",
+      warnings,
+    });
+    expect(resolved).toBeNull();
+    expect(warnings[0]).toContain("found no OS sandbox");
+  });
+
+  it("throws when an explicit backend is unavailable", () => {
+    expect(() =>
+      resolveExecOsSandbox({
+        config: { backend: "sandbox-exec" },
+        workspaceDir: "/work/space",
+        platform: "win32",
+      }),
+    ).toThrow(/not available/);
+  });
+
+  it("turns the network off and hides the home directory by default", () => {
+    const resolved = resolveExecOsSandbox({
+      config: { backend: "bwrap" },
+      workspaceDir: "/work/space",
+      platform: "linux",
+    });
+    expect(resolved?.network).toBe(false);
+    expect(resolved?.hidden).toContain(fs.realpathSync(os.homedir()));
+  });
+});
+
+describe("wrapArgvWithOsSandbox", () => {
+  it("wraps with bwrap and binds the workspace writable", () => {
+    const argv = wrapArgvWithOsSandbox(["sh", "-c", "ls"], base, "/work/space/pkg");
+    expect(argv[0]).toBe("bwrap");
+    expect(argv.join(" ")).toContain("--ro-bind / /");
+    expect(argv.join(" ")).toContain("--bind /work/space /work/space");
+    expect(argv).not.toContain("--unshare-net");
+    expect(argv).toEqual(
+      expect.arrayContaining(["--new-session", "--unshare-pid", "--unshare-ipc"]),
+    );
+    expect(argv.slice(-5)).toEqual(["/work/space/pkg", "--", "sh", "-c", "ls"]);
+  });
+
+  it("masks home and the state dir before exposing the workspace", () => {
+    const joined = (workspaceDir: string) =>
+      wrapArgvWithOsSandbox(["true"], { ...base, workspaceDir }, workspaceDir).join(" ");
+    expect(joined("/home/ada/.openclaw/workspace")).toContain(
+      "--tmpfs /home/ada --tmpfs /home/ada/.openclaw --bind /home/ada/.openclaw/workspace /home/ada/.openclaw/workspace",
+    );
+    // A workspace at $HOME would reveal the state dir, so it is masked again.
+    expect(joined("/home/ada")).toContain(
+      "--bind /home/ada /home/ada --tmpfs /home/ada/.openclaw --new-session",
+    );
+  });
+
+  it("unshares the network and binds allowWrite paths", () => {
+    const argv = wrapArgvWithOsSandbox(
+      ["sh", "-c", "ls"],
+      { ...base, network: false, allowWrite: ["/cache"] },
+      "/work/space",
+    );
+    expect(argv).toContain("--unshare-net");
+    expect(argv.join(" ")).toContain("--bind-try /cache /cache");
+  });
+
+  it("builds a sandbox-exec profile that limits writes and network", () => {
+    const sandbox: ExecOsSandbox = { ...base, backend: "sandbox-exec", network: false };
+    const profile = buildSandboxExecProfile(sandbox);
+    expect(profile).toContain("(deny default)");
+    expect(profile).not.toContain("(allow default)");
+    expect(profile).toContain('(deny file-read-data\n  (subpath "/home/ada")');
+    expect(profile).toContain('(allow file-write*\n  (subpath "/work/space")');
+    expect(profile).not.toContain("network-outbound");
+    expect(buildSandboxExecProfile({ ...sandbox, network: true })).toContain(
+      "(allow network-outbound network-inbound system-socket)",
+    );
+
+    const argv = wrapArgvWithOsSandbox(["sh", "-c", "ls"], sandbox, "/work/space");
+    expect(argv.slice(0, 2)).toEqual(["/usr/bin/sandbox-exec", "-p"]);
+    expect(argv.slice(-3)).toEqual(["sh", "-c", "ls"]);
+  });
+});
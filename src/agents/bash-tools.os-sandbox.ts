@@ -0,0 +1,232 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { resolveStateDir } from "../config/paths.js";
+import { resolveUserPath } from "../utils.js";
+import { resolveShellFromPath } from "./shell-utils.js";
+
+export type ExecOsSandboxBackend = "off" | "auto" | "bwrap" | "sandbox-exec";
+
+export type ExecOsSandboxConfig = {
+  backend?: ExecOsSandboxBackend;
+  network?: boolean;
+  allowRead?: string[];
+  allowWrite?: string[];
+};
+
+export type ExecOsSandbox = {
+  backend: "bwrap" | "sandbox-exec";
+  workspaceDir: string;
+  network: boolean;
+  allowRead: string[];
+  allowWrite: string[];
+  /** Unreadable unless re-exposed: the home directory and the OpenClaw state dir (credentials). */
+  hidden: string[];
+};
+
+const SANDBOX_EXEC_PATH = "/usr/bin/sandbox-exec";
+
+function realpathOrSelf(target: string) {
+  try {
+    return fs.realpathSync(target);
+  } catch {
+    return path.resolve(target);
+  }
+}
+
+function resolvePathList(entries: string[] | undefined) {
+  return (entries ?? [])
+    .map((entry) => entry.trim())
+    .filter(Boolean)
+    .map((entry) => realpathOrSelf(resolveUserPath(entry)));
+}
+
+function isWithin(child: string, parent: string) {
+  const rel = path.relative(parent, child);
+  return Boolean(rel) && !rel.startsWith("..") && !path.isAbsolute(rel);
+}
+
+function detectBackend(platform: NodeJS.Platform): ExecOsSandbox["backend"] | null {
+  if (platform === "linux") {
+    return resolveShellFromPath("bwrap") ? "bwrap" : null;
+  }
+  if (platform === "darwin") {
+    return fs.existsSync(SANDBOX_EXEC_PATH) ? "sandbox-exec" : null;
+  }
+  return null;
+}
+
+/**
+ * Resolve the OS sandbox for gateway exec runs. Returns null when disabled; throws when a
+ * specific backend was requested but is not available on this host.
+ */
+export function resolveExecOsSandbox(params: {
+  config?: ExecOsSandboxConfig;
+  workspaceDir: string;
+  platform?: NodeJS.Platform;
+  warnings?: string[];
+}): ExecOsSandbox | null {
+  const requested = params.config?.backend ?? "off";
+  if (requested === "off") {
+    return null;
+  }
+  const platform = params.platform ?? process.platform;
+  const detected = detectBackend(platform);
+  if (requested === "auto") {
+    if (!detected) {
+      params.warnings?.push(
+        `Warning: tools.exec.sandbox.backend=auto found no OS sandbox on ${platform}; running without it.`,
+      );
+      return null;
+    }
+  } else if (detected !== requested) {
+    throw new Error(
+      `tools.exec.sandbox.backend=${requested} is not available on this host (${platform}).`,
+    );
+  }
+  return {
+    backend: detected ?? (requested as ExecOsSandbox["backend"]),
+    workspaceDir: realpathOrSelf(params.workspaceDir),
+    network: params.config?.network === true,
+    allowRead: resolvePathList(params.config?.allowRead),
+    allowWrite: resolvePathList(params.config?.allowWrite),
+    hidden: [...new Set([realpathOrSelf(os.homedir()), realpathOrSelf(resolveStateDir())])].filter(
+      (dir) => dir !== path.parse(dir).root,
+    ),
+  };
+}
+
+/**
+ * Hidden paths are masked first and the workspace/allow lists are exposed on top, so a workspace
+ * under $HOME stays usable. A hidden path that an exposed path would reveal (e.g. the state dir
+ * when the workspace is $HOME) is masked again afterwards, unless something exposed lives in it.
+ */
+function resolveMasking(sandbox: ExecOsSandbox) {
+  const exposed = [sandbox.workspaceDir, ...sandbox.allowRead, ...sandbox.allowWrite];
+  const remask = sandbox.hidden.filter(
+    (dir) =>
+      exposed.some((entry) => isWithin(dir, entry)) &&
+      !exposed.some((entry) => entry === dir || isWithin(entry, dir)),
+  );
+  return { exposed, remask };
+}
+
+// Mach services a deny-default profile must reach for user/group lookups, logging and TLS.
+const SANDBOX_EXEC_MACH_SERVICES = [
+  "com.apple.system.opendirectoryd.libinfo",
+  "com.apple.system.opendirectoryd.membership",
+  "com.apple.system.notification_center",
+  "com.apple.system.logger",
+  "com.apple.logd",
+  "com.apple.trustd.agent",
+];
+
+// Extra services for DNS resolution and network configuration when network access is enabled.
+const SANDBOX_EXEC_NETWORK_MACH_SERVICES = [
+  "com.apple.SystemConfiguration.DNSConfiguration",
+  "com.apple.SystemConfiguration.configd",
+  "com.apple.networkd",
+  "com.apple.dnssd.service",
+];
+
+function sbplString(value: string) {
+  return `"${value.replace(/\\/g, "\\\\").replace(/"/g, '\\"')}"`;
+}
+
+export function buildSandboxExecProfile(sandbox: ExecOsSandbox) {
+  const { exposed, remask } = resolveMasking(sandbox);
+  const writable = [
+    sandbox.workspaceDir,
+    ...sandbox.allowWrite,
+    realpathOrSelf(os.tmpdir()),
+    "/private/tmp",
+    "/private/var/folders",
+  ];
+  const subpaths = (dirs: string[]) => dirs.map((dir) => `  (subpath ${sbplString(dir)})`);
+  // Start from deny-all and grant only what a shell needs; later rules win, so each file block
+  // narrows or re-opens the one before it.
+  const lines = [
+    "(version 1)",
+    "(deny default)",
+    "(allow process-exec process-fork)",
+    "(allow signal process-info* (target same-sandbox))",
+    "(allow sysctl-read)",
+    "(allow ipc-posix-sem)",
+    "(allow pseudo-tty)",
+    '(allow file-ioctl (literal "/dev/ptmx") (regex #"^/dev/tty"))',
+    "(allow mach-lookup",
+    ...SANDBOX_EXEC_MACH_SERVICES.map((name) => `  (global-name ${sbplString(name)})`),
+    ")",
+    "(allow file-read*)",
+    "(deny file-read-data",
+    ...subpaths(sandbox.hidden),
+    ")",
+    "(allow file-read-data",
+    ...subpaths(exposed),
+    ")",
+    "(allow file-write*",
+    ...subpaths(writable),
+    '  (literal "/dev/null")',
+    '  (regex #"^/dev/tty"))',
+  ];
+  if (remask.length > 0) {
+    lines.push("(deny file-read-data file-write*", ...subpaths(remask), ")");
+  }
+  if (sandbox.network) {
+    lines.push(
+      "(allow network-outbound network-inbound system-socket)",
+      "(allow mach-lookup",
+      ...SANDBOX_EXEC_NETWORK_MACH_SERVICES.map((name) => `  (global-name ${sbplString(name)})`),
+      ")",
+    );
+  }
+  return lines.join("\n");
+}
+
+/**
+ * Wrap a shell argv so the command can read the filesystem except $HOME and the state dir, only
+ * write to the workspace (plus allowWrite and temp dirs), and has no network unless enabled.
+ */
+export function wrapArgvWithOsSandbox(
+  argv: string[],
+  sandbox: ExecOsSandbox,
+  workdir: string,
+): string[] {
+  if (sandbox.backend === "sandbox-exec") {
+    return [SANDBOX_EXEC_PATH, "-p", buildSandboxExecProfile(sandbox), ...argv];
+  }
+  const args = [
+    "bwrap",
+    "--ro-bind",
+    "/",
+    "/",
+    "--dev",
+    "/dev",
+    "--proc",
+    "/proc",
+    "--tmpfs",
+    "/tmp",
+  ];
+  const { remask } = resolveMasking(sandbox);
+  for (const dir of sandbox.hidden) {
+    args.push("--tmpfs", dir);
+  }
+  for (const dir of sandbox.allowRead) {
+    args.push("--ro-bind-try", dir, dir);
+  }
+  args.push("--bind", sandbox.workspaceDir, sandbox.workspaceDir);
+  for (const dir of sandbox.allowWrite) {
+    args.push("--bind-try", dir, dir);
+  }
+  for (const dir of remask) {
+    args.push("--tmpfs", dir);
+  }
+  if (!sandbox.network) {
+    args.push("--unshare-net");
+  }
+  // A new session blocks TIOCSTI injection into the caller's terminal; private pid/ipc namespaces
+  // keep the command from signalling or sharing memory with host processes.
+  args.push("--new-session", "--unshare-pid", "--unshare-ipc");
+  args.push("--die-with-parent", "--chdir", workdir, "--", ...argv);
+  return args;
+}
@@ -0,0 +1,483 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import type { ChildProcessWithoutNullStreams } from "node:child_process";
+import crypto from "node:crypto";
+import type { PtyHandle, PtySpawn } from "./bash-tools.exec-runtime.js";
+import type { ExecToolDetails } from "./bash-tools.exec.js";
+import { logWarn } from "../logger.js";
+import { formatSpawnError, spawnWithFallback } from "../process/spawn-utils.js";
+import { tail, trimWithCap } from "./bash-process-registry.js";
+import { getShellConfig, killProcessTree, sanitizeBinaryOutput } from "./shell-utils.js";
+
+export const DEFAULT_PERSISTENT_SHELL_IDLE_MS = 15 * 60 * 1000;
+const MIN_IDLE_MS = 60 * 1000;
+const SWEEP_INTERVAL_MS = 30_000;
+const INIT_TIMEOUT_MS = 10_000;
+const MARKER_PREFIX = "__OPENCLAW_SHELL_";
+
+export type PersistentShellOutcome = {
+  status: "completed" | "failed";
+  exitCode: number | null;
+  durationMs: number;
+  aggregated: string;
+  cwd?: string;
+  timedOut: boolean;
+  /** True when a fresh shell had to be spawned for this call. */
+  started: boolean;
+  reason?: string;
+};
+
+type PersistentShell = {
+  key: string;
+  pid?: number;
+  mode: "pty" | "pipe";
+  cwd: string;
+  lastUsedAt: number;
+  idleMs: number;
+  exited: boolean;
+  queue: Promise<unknown>;
+  write: (data: string) => void;
+  kill: () => void;
+  listeners: Set<(data: string) => void>;
+  exitListeners: Set<() => void>;
+};
+
+const shells = new Map<string, PersistentShell>();
+let sweeper: NodeJS.Timeout | null = null;
+
+function quoteShellArg(value: string) {
+  return `'${value.replace(/'/g, `'\\''`)}'`;
+}
+
+function normalizeOutput(text: string) {
+  return sanitizeBinaryOutput(text.replace(/\r\n/g, "\n"));
+}
+
+function resolveIdleMs(value?: number) {
+  if (typeof value !== "number" || !Number.isFinite(value)) {
+    return DEFAULT_PERSISTENT_SHELL_IDLE_MS;
+  }
+  return Math.max(MIN_IDLE_MS, Math.floor(value));
+}
+
+function startSweeper() {
+  if (sweeper) {
+    return;
+  }
+  sweeper = setInterval(() => {
+    const now = Date.now();
+    for (const shell of shells.values()) {
+      if (now - shell.lastUsedAt > shell.idleMs) {
+        closePersistentShell(shell.key);
+      }
+    }
+  }, SWEEP_INTERVAL_MS);
+  sweeper.unref?.();
+}
+
+function stopSweeper() {
+  if (!sweeper) {
+    return;
+  }
+  clearInterval(sweeper);
+  sweeper = null;
+}
+
+async function spawnShell(params: {
+  key: string;
+  cwd: string;
+  env: Record<string, string>;
+}): Promise<PersistentShell> {
+  const { shell: shellPath } = getShellConfig();
+  const listeners = new Set<(data: string) => void>();
+  const exitListeners = new Set<() => void>();
+  const emitData = (data: string) => {
+    for (const listener of listeners) {
+      listener(data);
+    }
+  };
+  let shell: PersistentShell | null = null;
+  const onExit = () => {
+    if (shell) {
+      shell.exited = true;
+      if (shells.get(shell.key) === shell) {
+        shells.delete(shell.key);
+      }
+    }
+    for (const listener of exitListeners) {
+      listener();
+    }
+  };
+
+  let pty: PtyHandle | null = null;
+  let child: ChildProcessWithoutNullStreams | null = null;
+  try {
+    const ptyModule = (await import("@lydell/node-pty")) as unknown as {
+      spawn?: PtySpawn;
+      default?: { spawn?: PtySpawn };
+    };
+    const spawnPty = ptyModule.spawn ?? ptyModule.default?.spawn;
+    if (!spawnPty) {
+      throw new Error("PTY support is unavailable (node-pty spawn not found).");
+    }
+    pty = spawnPty(shellPath, [], {
+      cwd: params.cwd,
+      env: params.env,
+      name: process.env.TERM ?? "xterm-256color",
+      cols: 120,
+      rows: 30,
+    });
+    pty.onData((data) => emitData(data.toString()));
+    pty.onExit(() => onExit());
+  } catch (err) {
+    logWarn(`exec: persistent shell PTY spawn failed (${String(err)}); using pipes.`);
+    const { child: spawned } = await spawnWithFallback({
+      argv: [shellPath],
+      options: {
+        cwd: params.cwd,
+        env: params.env,
+        detached: true,
+        stdio: ["pipe", "pipe", "pipe"],
+        windowsHide: true,
+      },
+      fallbacks: [{ label: "no-detach", options: { detached: false } }],
+      onFallback: (fallbackErr, fallback) => {
+        logWarn(
+          `exec: persistent shell spawn failed (${formatSpawnError(fallbackErr)}); retrying with ${fallback.label}.`,
+        );
+      },
+    });
+    child = spawned as ChildProcessWithoutNullStreams;
+    child.stdout.on("data", (data) => emitData(data.toString()));
+    child.stderr.on("data", (data) => emitData(data.toString()));
+    child.once("close", () => onExit());
+    child.once("error", () => onExit());
+  }
+
+  const pid = pty?.pid ?? child?.pid;
+  shell = {
+    key: params.key,
+    pid,
+    mode: pty ? "pty" : "pipe",
+    cwd: params.cwd,
+    lastUsedAt: Date.now(),
+    idleMs: DEFAULT_PERSISTENT_SHELL_IDLE_MS,
+    exited: false,
+    queue: Promise.resolve(),
+    write: (data) => {
+      if (pty) {
+        pty.write(data);
+      } else {
+        child?.stdin.write(data);
+      }
+    },
+    kill: () => {
+      if (pid) {
+        killProcessTree(pid);
+      }
+    },
+    listeners,
+    exitListeners,
+  };
+
+  // Silence prompts/echo so only command output reaches the marker parser.
+  const init =
+    shell.mode === "pty"
+      ? "stty -echo 2>/dev/null; PS1=''; PS2=''; PROMPT_COMMAND=''; unset HISTFILE"
+      : "PS1=''; PS2=''; unset HISTFILE";
+  const ready = await runMarked(shell, init, INIT_TIMEOUT_MS);
+  if (ready.timedOut || ready.exitCode === null) {
+    shell.kill();
+    throw new Error("persistent shell failed to start");
+  }
+  return shell;
+}
+
+function runMarked(
+  shell: PersistentShell,
+  script: string,
+  timeoutMs: number,
+  onOutput?: (visible: string) => void,
+  maxOutput = Number.POSITIVE_INFINITY,
+): Promise<{ output: string; exitCode: number | null; cwd?: string; timedOut: boolean }> {
+  const marker = `${MARKER_PREFIX}${crypto.randomBytes(8).toString("hex")}__`;
+  const pattern = new RegExp(`\\n?${marker} (\\d+) ([^\\n]*)\\n`);
+  return new Promise((resolve) => {
+    let buffer = "";
+    let done = false;
+    let timer: NodeJS.Timeout | null = null;
+    const finish = (result: {
+      output: string;
+      exitCode: number | null;
+      cwd?: string;
+      timedOut: boolean;
+    }) => {
+      if (done) {
+        return;
+      }
+      done = true;
+      if (timer) {
+        clearTimeout(timer);
+      }
+      shell.listeners.delete(onData);
+      shell.exitListeners.delete(onExit);
+      resolve(result);
+    };
+    const onData = (data: string) => {
+      buffer += normalizeOutput(data);
+      const match = pattern.exec(buffer);
+      if (match) {
+        finish({
+          output: trimWithCap(buffer.slice(0, match.index), maxOutput),
+          exitCode: Number.parseInt(match[1], 10),
+          cwd: match[2] || undefined,
+          timedOut: false,
+        });
+        return;
+      }
+      if (onOutput) {
+        onOutput(buffer.split(MARKER_PREFIX)[0] ?? "");
+      }
+    };
+    const onExit = () => {
+      finish({ output: trimWithCap(buffer, maxOutput), exitCode: null, timedOut: false });
+    };
+    shell.listeners.add(onData);
+    shell.exitListeners.add(onExit);
+    if (timeoutMs > 0) {
+      timer = setTimeout(() => {
+        finish({ output: trimWithCap(buffer, maxOutput), exitCode: null, timedOut: true });
+      }, timeoutMs);
+    }
+    // Run the command in a brace group so state (cwd, exports, functions) persists,
+    // with stdin detached so commands cannot swallow the marker line.
+    shell.write(
+      `{ ${script}\n} </dev/null\n__oc_status=$?; printf '\\n%s %s %s\\n' '${marker}' "$__oc_status" "$PWD"\n`,
+    );
+  });
+}
+
+export function hasPersistentShell(key: string) {
+  const shell = shells.get(key);
+  return Boolean(shell && !shell.exited);
+}
+
+export function getPersistentShellCwd(key: string) {
+  const shell = shells.get(key);
+  return shell && !shell.exited ? shell.cwd : undefined;
+}
+
+export function closePersistentShell(key: string) {
+  const shell = shells.get(key);
+  if (!shell) {
+    return false;
+  }
+  shells.delete(key);
+  shell.kill();
+  if (shells.size === 0) {
+    stopSweeper();
+  }
+  return true;
+}
+
+export async function runInPersistentShell(params: {
+  key: string;
+  command: string;
+  /**
+   * Directory the command runs in. Applied with `cd` on every call so the directory the caller
+   * checked (e.g. for allowlist decisions) is the one the command actually sees.
+   */
+  workdir: string;
+  /** Close the shell after this long without calls (default: 15 minutes, min 1 minute). */
+  idleMs?: number;
+  /** Environment the shell starts with when one has to be spawned. */
+  env: Record<string, string>;
+  /** Variables for this command only; it then runs in a subshell so they do not persist. */
+  commandEnv?: Record<string, string>;
+  timeoutSec: number;
+  maxOutput: number;
+  signal?: AbortSignal;
+  onOutput?: (visible: string) => void;
+}): Promise<PersistentShellOutcome> {
+  const startedAt = Date.now();
+  let shell = shells.get(params.key);
+  let started = false;
+  if (!shell || shell.exited) {
+    shell = await spawnShell({ key: params.key, cwd: params.workdir, env: params.env });
+    shells.set(params.key, shell);
+    started = true;
+    startSweeper();
+  }
+  const active = shell;
+  active.idleMs = resolveIdleMs(params.idleMs);
+
+  const exports: string[] = [];
+  for (const [key, value] of Object.entries(params.commandEnv ?? {})) {
+    if (!/^[A-Za-z_][A-Za-z0-9_]*$/.test(key)) {
+      throw new Error(`Invalid environment variable name: ${key}`);
+    }
+    exports.push(`export ${key}=${quoteShellArg(value)}`);
+  }
+  const body =
+    exports.length > 0 ? `(\n${exports.join("\n")}\n${params.command}\n)` : params.command;
+  const script = started ? body : `cd -- ${quoteShellArg(params.workdir)} && {\n${body}\n}`;
+
+  const run = async (): Promise<PersistentShellOutcome> => {
+    active.lastUsedAt = Date.now();
+    const onAbort = () => closePersistentShell(active.key);
+    params.signal?.addEventListener("abort", onAbort, { once: true });
+    try {
+      const result = await runMarked(
+        active,
+        script,
+        params.timeoutSec > 0 ? params.timeoutSec * 1000 : 0,
+        params.onOutput,
+        params.maxOutput,
+      );
+      active.lastUsedAt = Date.now();
+      if (result.cwd) {
+        active.cwd = result.cwd;
+      }
+      const aggregated = result.output.trim();
+      if (result.timedOut) {
+        closePersistentShell(active.key);
+        const reason = `Command timed out after ${params.timeoutSec} seconds (persistent shell restarted)`;
+        return {
+          status: "failed",
+          exitCode: null,
+          durationMs: Date.now() - startedAt,
+          aggregated,
+          cwd: active.cwd,
+          timedOut: true,
+          started,
+          reason: aggregated ? `${aggregated}\n\n${reason}` : reason,
+        };
+      }
+      if (result.exitCode === null) {
+        const reason = params.signal?.aborted
+          ? "Command aborted (persistent shell restarted)"
+          : "Persistent shell exited; a new shell starts on the next call";
+        return {
+          status: "failed",
+          exitCode: null,
+          durationMs: Date.now() - startedAt,
+          aggregated,
+          cwd: active.cwd,
+          timedOut: false,
+          started,
+          reason: aggregated ? `${aggregated}\n\n${reason}` : reason,
+        };
+      }
+      if (result.exitCode !== 0) {
+        const reason = `Command exited with code ${result.exitCode}`;
+        return {
+          status: "failed",
+          exitCode: result.exitCode,
+          durationMs: Date.now() - startedAt,
+          aggregated,
+          cwd: active.cwd,
+          timedOut: false,
+          started,
+          reason: aggregated ? `${aggregated}\n\n${reason}` : reason,
+        };
+      }
+      return {
+        status: "completed",
+        exitCode: 0,
+        durationMs: Date.now() - startedAt,
+        aggregated,
+        cwd: active.cwd,
+        timedOut: false,
+        started,
+      };
+    } finally {
+      params.signal?.removeEventListener("abort", onAbort);
+    }
+  };
+
+  // Serialize calls per shell; parallel tool calls would interleave output otherwise.
+  const next = active.queue.then(run, run);
+  active.queue = next.catch(() => undefined);
+  return next;
+}
+
+/** Result for an `exec` call with `restart: true` and no command. */
+export function restartPersistentShell(key: string): AgentToolResult<ExecToolDetails> {
+  const closed = closePersistentShell(key);
+  return {
+    content: [
+      {
+        type: "text",
+        text: closed
+          ? "Persistent shell restarted."
+          : "No persistent shell was running; a new one starts on the next command.",
+      },
+    ],
+    details: {
+      status: "completed",
+      exitCode: 0,
+      durationMs: 0,
+      aggregated: "",
+    },
+  };
+}
+
+/**
+ * Runs an `exec` call in the persistent shell for `key` and shapes the outcome as the tool result;
+ * `warningText` is prepended to streamed and final output.
+ */
+export async function executeInPersistentShell(
+  params: Omit<Parameters<typeof runInPersistentShell>[0], "onOutput"> & {
+    warningText: () => string;
+    /** Called with the shell's directory after the command, whether or not it failed. */
+    onCwd?: (cwd: string | undefined) => void;
+    onUpdate?: (partialResult: AgentToolResult<ExecToolDetails>) => void;
+  },
+): Promise<AgentToolResult<ExecToolDetails>> {
+  const { warningText, onCwd, onUpdate, ...runParams } = params;
+  const startedAt = Date.now();
+  const outcome = await runInPersistentShell({
+    ...runParams,
+    onOutput: onUpdate
+      ? (visible) =>
+          onUpdate({
+            content: [{ type: "text", text: warningText() + tail(visible, 2000) }],
+            details: {
+              status: "running",
+              sessionId: params.key,
+              startedAt,
+              cwd: params.workdir,
+              tail: tail(visible, 2000),
+            },
+          })
+      : undefined,
+  });
+  onCwd?.(outcome.cwd);
+  if (outcome.status === "failed") {
+    throw new Error(outcome.reason ?? "Command failed.");
+  }
+  return {
+    content: [{ type: "text", text: `${warningText()}${outcome.aggregated || "(no output)"}` }],
+    details: {
+      status: "completed",
+      exitCode: outcome.exitCode ?? 0,
+      durationMs: outcome.durationMs,
+      aggregated: outcome.aggregated,
+      cwd: outcome.cwd,
+    },
+  };
+}
+
+export function closeAllPersistentShells(): number {
+  let closed = 0;
+  for (const key of Array.from(shells.keys())) {
+    if (closePersistentShell(key)) {
+      closed += 1;
+    }
+  }
+  return closed;
+}
+
+export function resetPersistentShellsForTests() {
+  closeAllPersistentShells();
+  stopSweeper();
+}
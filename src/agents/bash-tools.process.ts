@@ -1,6 +1,8 @@
 import type { AgentTool } from "@mariozechner/pi-agent-core";
 import { Type } from "@sinclair/typebox";
+import type { ProcessSession } from "./bash-process-registry.js";
 import { formatDurationCompact } from "../infra/format-time/format-duration.ts";
+import { sleep } from "../utils.js";
 import {
   deleteSession,
   drainSession,
@@ -39,8 +41,32 @@ const processSchema = Type.Object({
   eof: Type.Optional(Type.Boolean({ description: "Close stdin after write" })),
   offset: Type.Optional(Type.Number({ description: "Log offset" })),
   limit: Type.Optional(Type.Number({ description: "Log length" })),
+  timeout: Type.Optional(
+    Type.Number({
+      description: "For poll: wait up to this many ms for new output or exit (max 120000)",
+    }),
+  ),
 });
 
+const MAX_POLL_WAIT_MS = 120_000;
+const POLL_WAIT_INTERVAL_MS = 100;
+
+async function waitForSessionActivity(
+  session: ProcessSession,
+  timeoutMs: number,
+  signal?: AbortSignal,
+) {
+  const deadline = Date.now() + Math.min(Math.max(0, Math.floor(timeoutMs)), MAX_POLL_WAIT_MS);
+  while (
+    !session.exited &&
+    session.pendingStdoutChars + session.pendingStderrChars === 0 &&
+    Date.now() < deadline &&
+    !signal?.aborted
+  ) {
+    await sleep(Math.min(POLL_WAIT_INTERVAL_MS, Math.max(1, deadline - Date.now())));
+  }
+}
+
 export function createProcessTool(
   defaults?: ProcessToolDefaults,
   // oxlint-disable-next-line typescript/no-explicit-any
@@ -58,7 +84,7 @@ export function createProcessTool(
     description:
       "Manage running exec sessions: list, poll, log, write, send-keys, submit, paste, kill.",
     parameters: processSchema,
-    execute: async (_toolCallId, args) => {
+    execute: async (_toolCallId, args, signal) => {
       const params = args as {
         action:
           | "list"
@@ -81,6 +107,7 @@ export function createProcessTool(
         eof?: boolean;
         offset?: number;
         limit?: number;
+        timeout?: number;
       };
 
       if (params.action === "list") {
@@ -193,6 +220,9 @@ export function createProcessTool(
               details: { status: "failed" },
             };
           }
+          if (typeof params.timeout === "number" && params.timeout > 0) {
+            await waitForSessionActivity(scopedSession, params.timeout, signal);
+          }
           const { stdout, stderr } = drainSession(scopedSession);
           const exited = scopedSession.exited;
           const exitCode = scopedSession.exitCode ?? 0;
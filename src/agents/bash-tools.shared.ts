@@ -48,6 +48,47 @@ export function coerceEnv(env?: NodeJS.ProcessEnv | Record<string, string>) {
   return record;
 }
 
+// Credentials the gateway itself runs with; exec children should not inherit them.
+export const DEFAULT_EXEC_SCRUB_ENV = [
+  "*_API_KEY",
+  "*_OAUTH_TOKEN",
+  "ANTHROPIC_AUTH_TOKEN",
+  "OPENCLAW_GATEWAY_TOKEN",
+  "OPENCLAW_GATEWAY_PASSWORD",
+];
+
+function compileEnvPattern(pattern: string): RegExp | null {
+  const trimmed = pattern.trim();
+  if (!trimmed) {
+    return null;
+  }
+  const source = trimmed
+    .split("*")
+    .map((part) => part.replace(/[.+?^${}()|[\]\\]/g, "\\$&"))
+    .join(".*");
+  return new RegExp(`^${source}$`, "i");
+}
+
+/**
+ * Drop env vars whose names match any scrub pattern (`*` wildcards, case-insensitive).
+ * Returns a new record; the input is not modified.
+ */
+export function scrubEnv(env: Record<string, string>, patterns: string[]) {
+  const compiled = patterns
+    .map((pattern) => compileEnvPattern(pattern))
+    .filter((pattern): pattern is RegExp => pattern !== null);
+  if (compiled.length === 0) {
+    return { ...env };
+  }
+  const record: Record<string, string> = {};
+  for (const [key, value] of Object.entries(env)) {
+    if (!compiled.some((pattern) => pattern.test(key))) {
+      record[key] = value;
+    }
+  }
+  return record;
+}
+
 export function buildDockerExecArgs(params: {
   containerName: string;
   command: string;
@@ -1,3 +1,5 @@
+export type { BackgroundTaskToolDefaults } from "./bash-tools.background.js";
+export { createBackgroundTaskTools } from "./bash-tools.background.js";
 export type {
   BashSandboxConfig,
   ExecElevatedDefaults,
@@ -0,0 +1,51 @@
+import { describe, expect, it } from "vitest";
+import { summarizeBench, type BenchRun } from "./bench.js";
+
+describe("summarizeBench", () => {
+  it("averages successful runs per model and keeps model order", () => {
+    const runs: BenchRun[] = [
+      {
+        key: "openai/gpt-5.2",
+        run: 1,
+        text: "a",
+        latencyMs: 2000,
+        ttftMs: 500,
+        usage: { output: 300 },
+        costUsd: 0.01,
+      },
+      {
+        key: "openai/gpt-5.2",
+        run: 2,
+        text: "b",
+        latencyMs: 4000,
+        ttftMs: 1000,
+        usage: { output: 300 },
+        costUsd: 0.02,
+      },
+      { key: "anthropic/claude-opus-4-6", run: 1, error: "timed out", latencyMs: 120_000 },
+    ];
+    const [gpt, opus] = summarizeBench(runs);
+    expect(gpt).toMatchObject({
+      key: "openai/gpt-5.2",
+      runs: 2,
+      ok: 2,
+      avgLatencyMs: 3000,
+      avgTtftMs: 750,
+      avgOutputTokens: 300,
+    });
+    expect(gpt?.totalCostUsd).toBeCloseTo(0.03);
+    // (300 / 1.5s + 300 / 3s) / 2
+    expect(gpt?.tokensPerSecond).toBeCloseTo(150);
+    expect(opus).toMatchObject({ runs: 1, ok: 0 });
+    expect(opus?.avgLatencyMs).toBeUndefined();
+    expect(opus?.totalCostUsd).toBeUndefined();
+  });
+
+  it("leaves cached runs out of the timing averages", () => {
+    const [summary] = summarizeBench([
+      { key: "openai/gpt-5.2", run: 1, text: "a", latencyMs: 2000, usage: { output: 100 } },
+      { key: "openai/gpt-5.2", run: 2, text: "a", latencyMs: 5, cached: true, usage: {} },
+    ]);
+    expect(summary).toMatchObject({ runs: 2, ok: 2, avgLatencyMs: 2000, avgOutputTokens: 100 });
+  });
+});
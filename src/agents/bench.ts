@@ -0,0 +1,196 @@
+import { streamSimple, type TextContent } from "@mariozechner/pi-ai";
+import type { OpenClawConfig } from "../config/config.js";
+import type { CouncilMember } from "./council.js";
+import { estimateUsageCost, resolveModelCostConfig } from "../utils/usage-format.js";
+import { getApiKeyForModel, requireApiKey } from "./model-auth.js";
+import { resolveModel } from "./pi-embedded-runner/model.js";
+import {
+  isCachedResponse,
+  resolveResponseCacheSettings,
+  wrapStreamFnWithResponseCache,
+} from "./response-cache.js";
+import { normalizeUsage, type NormalizedUsage, type UsageLike } from "./usage.js";
+
+const BENCH_MAX_TOKENS = 8192;
+
+export type BenchRun = {
+  key: string;
+  run: number;
+  text?: string;
+  error?: string;
+  /** Request start to final event. */
+  latencyMs: number;
+  /** Request start to the first streamed token (text, thinking, or tool call). */
+  ttftMs?: number;
+  usage?: NormalizedUsage;
+  costUsd?: number;
+  /** Served from the response cache; timings are not meaningful. */
+  cached?: boolean;
+};
+
+export type BenchModelSummary = {
+  key: string;
+  runs: number;
+  ok: number;
+  avgLatencyMs?: number;
+  avgTtftMs?: number;
+  avgOutputTokens?: number;
+  /** Output tokens per second after the first token. */
+  tokensPerSecond?: number;
+  totalCostUsd?: number;
+};
+
+function isTextContentBlock(block: { type: string }): block is TextContent {
+  return block.type === "text";
+}
+
+const FIRST_TOKEN_EVENTS = new Set(["text_delta", "thinking_delta", "toolcall_delta"]);
+
+/** One streamed completion (no tools or session history), timed from request to first token and end. */
+export async function runBenchOnce(params: {
+  cfg: OpenClawConfig;
+  member: CouncilMember;
+  prompt: string;
+  run: number;
+  timeoutMs: number;
+}): Promise<BenchRun> {
+  const { provider, model } = params.member.ref;
+  const startedAt = Date.now();
+  const controller = new AbortController();
+  const timeout = setTimeout(() => controller.abort(), params.timeoutMs);
+  let ttftMs: number | undefined;
+  try {
+    const resolved = resolveModel(provider, model, undefined, params.cfg);
+    if (!resolved.model) {
+      throw new Error(resolved.error ?? `Unknown model: ${params.member.key}`);
+    }
+    const apiKey = requireApiKey(
+      await getApiKeyForModel({ model: resolved.model, cfg: params.cfg }),
+      provider,
+    );
+    const cache = resolveResponseCacheSettings(params.cfg);
+    const streamFn = cache ? wrapStreamFnWithResponseCache(streamSimple, cache) : streamSimple;
+    const stream = await streamFn(
+      resolved.model,
+      { messages: [{ role: "user", content: params.prompt, timestamp: Date.now() }] },
+      {
+        apiKey,
+        maxTokens: Math.min(resolved.model.maxTokens || BENCH_MAX_TOKENS, BENCH_MAX_TOKENS),
+        signal: controller.signal,
+      },
+    );
+    for await (const event of stream) {
+      if (ttftMs === undefined && FIRST_TOKEN_EVENTS.has(event.type)) {
+        ttftMs = Date.now() - startedAt;
+      }
+    }
+    const message = await stream.result();
+    if (message.stopReason === "error" || message.stopReason === "aborted") {
+      throw new Error(message.errorMessage || `request ${message.stopReason}`);
+    }
+    const usage = normalizeUsage(message.usage as UsageLike);
+    const text = message.content
+      .filter(isTextContentBlock)
+      .map((block) => block.text)
+      .join("")
+      .trim();
+    return {
+      key: params.member.key,
+      run: params.run,
+      text,
+      latencyMs: Date.now() - startedAt,
+      ttftMs,
+      usage,
+      costUsd: estimateUsageCost({
+        usage,
+        cost: resolveModelCostConfig({ provider, model, config: params.cfg }),
+      }),
+      cached: isCachedResponse(message) || undefined,
+    };
+  } catch (err) {
+    const error = controller.signal.aborted
+      ? `timed out after ${Math.round(params.timeoutMs / 1000)}s`
+      : err instanceof Error
+        ? err.message
+        : String(err);
+    return {
+      key: params.member.key,
+      run: params.run,
+      error,
+      latencyMs: Date.now() - startedAt,
+      ttftMs,
+    };
+  } finally {
+    clearTimeout(timeout);
+  }
+}
+
+/**
+ * Runs the prompt `runs` times per model. Models and runs go one at a time so one request's
+ * latency is not skewed by another's.
+ */
+export async function runBench(params: {
+  cfg: OpenClawConfig;
+  members: CouncilMember[];
+  prompt: string;
+  runs: number;
+  timeoutMs: number;
+  onRun?: (run: BenchRun) => void | Promise<void>;
+}): Promise<BenchRun[]> {
+  const results: BenchRun[] = [];
+  for (const member of params.members) {
+    for (let run = 1; run <= params.runs; run += 1) {
+      const result = await runBenchOnce({
+        cfg: params.cfg,
+        member,
+        prompt: params.prompt,
+        run,
+        timeoutMs: params.timeoutMs,
+      });
+      results.push(result);
+      await params.onRun?.(result);
+    }
+  }
+  return results;
+}
+
+function average(values: Array<number | undefined>): number | undefined {
+  const present = values.filter((value): value is number => typeof value === "number");
+  if (present.length === 0) {
+    return undefined;
+  }
+  return present.reduce((sum, value) => sum + value, 0) / present.length;
+}
+
+/**
+ * Per-model averages over successful runs, in the order models were given. Cached runs count as
+ * successful but are left out of the timing averages.
+ */
+export function summarizeBench(runs: BenchRun[]): BenchModelSummary[] {
+  const byKey = new Map<string, BenchRun[]>();
+  for (const run of runs) {
+    const list = byKey.get(run.key) ?? [];
+    list.push(run);
+    byKey.set(run.key, list);
+  }
+  return Array.from(byKey, ([key, list]) => {
+    const ok = list.filter((run) => !run.error);
+    const timed = ok.filter((run) => !run.cached);
+    const costs = ok.map((run) => run.costUsd).filter((cost) => typeof cost === "number");
+    const rates = timed.map((run) => {
+      const output = run.usage?.output;
+      const streamingMs = run.latencyMs - (run.ttftMs ?? 0);
+      return output && streamingMs > 0 ? (output / streamingMs) * 1000 : undefined;
+    });
+    return {
+      key,
+      runs: list.length,
+      ok: ok.length,
+      avgLatencyMs: average(timed.map((run) => run.latencyMs)),
+      avgTtftMs: average(timed.map((run) => run.ttftMs)),
+      avgOutputTokens: average(timed.map((run) => run.usage?.output)),
+      tokensPerSecond: average(rates),
+      totalCostUsd: costs.length > 0 ? costs.reduce((sum, cost) => sum + cost, 0) : undefined,
+    };
+  });
+}
@@ -1,13 +1,61 @@
 import type { OpenClawConfig } from "../config/config.js";
 import type { EmbeddedContextFile } from "./pi-embedded-helpers.js";
+import { isSubagentSessionKey } from "../routing/session-key.js";
 import { applyBootstrapHookOverrides } from "./bootstrap-hooks.js";
-import { buildBootstrapContextFiles, resolveBootstrapMaxChars } from "./pi-embedded-helpers.js";
 import {
+  readMemoryFile,
+  resolveProjectMemoryDir,
+  resolveProjectMemoryRoot,
+} from "./memory-file.js";
+import {
+  applyBootstrapTotalBudget,
+  buildBootstrapContextFiles,
+  resolveBootstrapMaxChars,
+  resolveBootstrapTotalMaxChars,
+} from "./pi-embedded-helpers.js";
+import {
+  buildProjectInstructionContextFiles,
+  loadProjectInstructionFiles,
+  type ProjectInstructionFile,
+} from "./project-instructions.js";
+import {
+  DEFAULT_MEMORY_ALT_FILENAME,
+  DEFAULT_MEMORY_FILENAME,
   filterBootstrapFilesForSession,
   loadWorkspaceBootstrapFiles,
   type WorkspaceBootstrapFile,
 } from "./workspace.js";
 
+const MEMORY_FILENAMES = new Set<string>([DEFAULT_MEMORY_FILENAME, DEFAULT_MEMORY_ALT_FILENAME]);
+
+/** MEMORY.md of the project the session is working in, labelled by its own path. */
+async function loadProjectMemoryForRun(params: {
+  workspaceDir: string;
+  config?: OpenClawConfig;
+  sessionKey?: string;
+  sessionId?: string;
+  agentId?: string;
+}): Promise<ProjectInstructionFile | null> {
+  const sessionKey = params.sessionKey ?? params.sessionId;
+  if (sessionKey && isSubagentSessionKey(sessionKey)) {
+    return null;
+  }
+  const projectRoot = resolveProjectMemoryRoot({
+    workspaceDir: params.workspaceDir,
+    config: params.config,
+    sessionKey: params.sessionKey,
+    agentId: params.agentId,
+  });
+  if (!projectRoot) {
+    return null;
+  }
+  const file = await readMemoryFile(resolveProjectMemoryDir(projectRoot)).catch(() => null);
+  if (!file?.text.trim()) {
+    return null;
+  }
+  return { path: file.path, scope: "project", content: file.text.trimEnd() };
+}
+
 export function makeBootstrapWarn(params: {
   sessionLabel: string;
   warn?: (message: string) => void;
@@ -47,15 +95,41 @@ export async function resolveBootstrapContextForRun(params: {
   sessionKey?: string;
   sessionId?: string;
   agentId?: string;
+  /** Leave MEMORY.md and project memory out of the context (`--no-memory`). */
+  disableMemory?: boolean;
   warn?: (message: string) => void;
 }): Promise<{
   bootstrapFiles: WorkspaceBootstrapFile[];
+  instructionFiles: ProjectInstructionFile[];
   contextFiles: EmbeddedContextFile[];
 }> {
-  const bootstrapFiles = await resolveBootstrapFilesForRun(params);
+  const resolvedFiles = await resolveBootstrapFilesForRun(params);
+  const bootstrapFiles = params.disableMemory
+    ? resolvedFiles.filter((file) => !MEMORY_FILENAMES.has(file.name))
+    : resolvedFiles;
+  const maxChars = resolveBootstrapMaxChars(params.config);
   const contextFiles = buildBootstrapContextFiles(bootstrapFiles, {
-    maxChars: resolveBootstrapMaxChars(params.config),
+    maxChars,
     warn: params.warn,
   });
-  return { bootstrapFiles, contextFiles };
+  // Instruction files found walking up from the workspace, labelled by origin path.
+  const instructionFiles = await loadProjectInstructionFiles({
+    cwd: params.workspaceDir,
+    excludePaths: bootstrapFiles.filter((file) => !file.missing).map((file) => file.path),
+  });
+  const projectMemory = params.disableMemory ? null : await loadProjectMemoryForRun(params);
+  if (projectMemory) {
+    instructionFiles.push(projectMemory);
+  }
+  contextFiles.push(
+    ...buildProjectInstructionContextFiles(instructionFiles, { maxChars, warn: params.warn }),
+  );
+  return {
+    bootstrapFiles,
+    instructionFiles,
+    contextFiles: applyBootstrapTotalBudget(contextFiles, {
+      totalMaxChars: resolveBootstrapTotalMaxChars(params.config),
+      warn: params.warn,
+    }),
+  };
 }
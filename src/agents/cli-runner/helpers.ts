@@ -243,6 +243,7 @@ type CliUsage = {
   cacheRead?: number;
   cacheWrite?: number;
   total?: number;
+  reasoning?: number;
 };
 
 export type CliOutput = {
@@ -344,16 +345,20 @@ export function normalizeCliModel(modelId: string, backend: CliBackendConfig): s
 function toUsage(raw: Record<string, unknown>): CliUsage | undefined {
   const pick = (key: string) =>
     typeof raw[key] === "number" && raw[key] > 0 ? raw[key] : undefined;
-  const input = pick("input_tokens") ?? pick("inputTokens");
+  const rawInput = pick("input_tokens") ?? pick("inputTokens");
   const output = pick("output_tokens") ?? pick("outputTokens");
-  const cacheRead =
-    pick("cache_read_input_tokens") ?? pick("cached_input_tokens") ?? pick("cacheRead");
+  // Codex reports `cached_input_tokens` as part of `input_tokens` (OpenAI convention).
+  const codexCached = pick("cached_input_tokens");
+  const input =
+    rawInput && codexCached ? Math.max(0, rawInput - codexCached) || undefined : rawInput;
+  const cacheRead = pick("cache_read_input_tokens") ?? codexCached ?? pick("cacheRead");
   const cacheWrite = pick("cache_write_input_tokens") ?? pick("cacheWrite");
   const total = pick("total_tokens") ?? pick("total");
+  const reasoning = pick("reasoning_output_tokens");
   if (!input && !output && !cacheRead && !cacheWrite && !total) {
     return undefined;
   }
-  return { input, output, cacheRead, cacheWrite, total };
+  return { input, output, cacheRead, cacheWrite, total, ...(reasoning ? { reasoning } : {}) };
 }
 
 function collectText(value: unknown): string {
@@ -0,0 +1,143 @@
+import { execFileSync } from "node:child_process";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+
+vi.mock("./council.js", () => ({
+  askModel: vi.fn(),
+}));
+
+import { parseReviewCommand } from "../auto-reply/reply/commands-review.js";
+import {
+  chunkDiff,
+  formatReviewFindings,
+  parseReviewFindings,
+  runCodeReview,
+  splitDiffByFile,
+} from "./code-review.js";
+import { askModel } from "./council.js";
+
+const fileDiff = (name: string, hunks: string[]) =>
+  [
+    `diff --git a/${name} b/${name}`,
+    `--- a/${name}`,
+    `+++ b/${name}`,
+    ...hunks.map((hunk, i) => `@@ -${i * 10 + 1},1 +${i * 10 + 1},1 @@\n-old\n+${hunk}`),
+    "",
+  ].join("\n");
+
+describe("code review", () => {
+  it("parses /review targets", () => {
+    expect(parseReviewCommand("/review")).toEqual({ target: { kind: "worktree" } });
+    expect(parseReviewCommand("/review --staged")).toEqual({ target: { kind: "staged" } });
+    expect(parseReviewCommand("/review --pr 42")).toEqual({ target: { kind: "pr", number: 42 } });
+    expect(parseReviewCommand("/review --pr=#7")).toEqual({ target: { kind: "pr", number: 7 } });
+    expect(parseReviewCommand("/review main")).toEqual({ target: { kind: "ref", ref: "main" } });
+    expect(parseReviewCommand("/review --pr x")).toHaveProperty("error");
+    expect(parseReviewCommand("/review --output=x")).toHaveProperty("error");
+    expect(parseReviewCommand("/reviews")).toBeNull();
+  });
+
+  it("splits diffs per file and packs chunks under the limit", () => {
+    const diff = fileDiff("a.ts", ["a"]) + fileDiff("b.ts", ["b"]) + fileDiff("c.ts", ["c"]);
+    expect(splitDiffByFile(diff).map((file) => file.path)).toEqual(["a.ts", "b.ts", "c.ts"]);
+    expect(chunkDiff(diff, 10_000)).toHaveLength(1);
+
+    const perFile = fileDiff("a.ts", ["a"]).length;
+    expect(chunkDiff(diff, perFile * 2)).toHaveLength(2);
+
+    const big = fileDiff("big.ts", ["x".repeat(60), "y".repeat(60), "z".repeat(60)]);
+    const pieces = chunkDiff(big, 150);
+    expect(pieces.length).toBeGreaterThan(1);
+    for (const piece of pieces) {
+      expect(piece.startsWith("diff --git a/big.ts b/big.ts\n")).toBe(true);
+      expect(piece.length).toBeLessThanOrEqual(150);
+    }
+  });
+
+  it("reads findings from fenced or chatty replies", () => {
+    const reply =
+      'Here you go:\n```json\n{"findings":[{"file":"a.ts","line":3,"severity":"MAJOR","title":"Off by one"},{"title":"Vague","severity":"weird"},{"severity":"nit"}]}\n```';
+    expect(parseReviewFindings(reply)).toEqual([
+      { file: "a.ts", line: 3, severity: "major", title: "Off by one" },
+      { file: "(general)", severity: "minor", title: "Vague" },
+    ]);
+    expect(() => parseReviewFindings("Looks good to me!")).toThrow("no JSON");
+  });
+
+  it("groups findings by file, most severe first", () => {
+    const text = formatReviewFindings({
+      target: "staged changes",
+      files: 2,
+      chunks: 1,
+      findings: [
+        { file: "b.ts", severity: "nit", title: "Rename x" },
+        { file: "a.ts", line: 9, severity: "minor", title: "Missing await" },
+        { file: "b.ts", line: 2, severity: "critical", title: "SQL injection", detail: "Bind it." },
+      ],
+      errors: [],
+    });
+    expect(text).toBe(
+      [
+        "🔎 Review of staged changes (2 files)",
+        "",
+        "b.ts",
+        "- 🔴 critical L2 SQL injection",
+        "  Bind it.",
+        "- ⚪ nit Rename x",
+        "",
+        "a.ts",
+        "- 🟡 minor L9 Missing await",
+        "",
+        "Findings: 1 critical, 1 minor, 1 nit",
+      ].join("\n"),
+    );
+  });
+
+  describe("runCodeReview", () => {
+    let root: string;
+    const git = (...args: string[]) => execFileSync("git", args, { cwd: root, stdio: "ignore" });
+
+    beforeEach(async () => {
+      root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-review-"));
+      git("init", "-q", "-b", "main");
+      await fs.writeFile(path.join(root, "a.ts"), "export const a = 1;\n");
+      git("add", "a.ts");
+      git("-c", "user.name=T", "-c", "user.email=t@example.com", "commit", "-q", "-m", "init");
+    });
+
+    afterEach(async () => {
+      await fs.rm(root, { recursive: true, force: true });
+    });
+
+    it("reviews uncommitted changes and collects per-chunk failures", async () => {
+      await fs.writeFile(path.join(root, "a.ts"), "export const a = 2;\n");
+      vi.mocked(askModel).mockResolvedValueOnce(
+        '{"findings":[{"file":"a.ts","line":1,"severity":"minor","title":"Magic number"}]}',
+      );
+      const result = await runCodeReview({
+        cfg: {},
+        provider: "anthropic",
+        model: "claude-sonnet",
+        root,
+        target: { kind: "worktree" },
+      });
+      expect(result).toMatchObject({ target: "uncommitted changes", files: 1, chunks: 1 });
+      expect(result.findings).toEqual([
+        { file: "a.ts", line: 1, severity: "minor", title: "Magic number" },
+      ]);
+      expect(vi.mocked(askModel).mock.calls[0]?.[0].prompt).toContain("+export const a = 2;");
+
+      vi.mocked(askModel).mockRejectedValueOnce(new Error("rate limited"));
+      const failed = await runCodeReview({
+        cfg: {},
+        provider: "anthropic",
+        model: "claude-sonnet",
+        root,
+        target: { kind: "worktree" },
+      });
+      expect(failed.errors).toEqual(["Review failed: rate limited"]);
+    });
+  });
+});
@@ -0,0 +1,313 @@
+import type { OpenClawConfig } from "../config/config.js";
+import { runCommandWithTimeout } from "../process/exec.js";
+import { askModel } from "./council.js";
+import { DEFAULT_CONTEXT_TOKENS } from "./defaults.js";
+import { runGit } from "./git-commit.js";
+
+export const REVIEW_SEVERITIES = ["critical", "major", "minor", "nit"] as const;
+export type ReviewSeverity = (typeof REVIEW_SEVERITIES)[number];
+
+export type ReviewTarget =
+  /** Staged and unstaged changes against HEAD. */
+  | { kind: "worktree" }
+  | { kind: "staged" }
+  /** `base..head` as given, otherwise the changes since `ref` diverged from HEAD. */
+  | { kind: "ref"; ref: string }
+  | { kind: "pr"; number: number };
+
+export type ReviewFinding = {
+  file: string;
+  line?: number;
+  severity: ReviewSeverity;
+  title: string;
+  detail?: string;
+};
+
+export type CodeReviewResult = {
+  target: string;
+  files: number;
+  chunks: number;
+  findings: ReviewFinding[];
+  /** Chunks that could not be reviewed (model errors, unparseable replies, over the limit). */
+  errors: string[];
+};
+
+const CHARS_PER_TOKEN_ESTIMATE = 4;
+/** Share of the context window one diff chunk may use; the rest is prompt and reply. */
+const CHUNK_CONTEXT_SHARE = 0.4;
+const MIN_CHUNK_CHARS = 8_000;
+const MAX_CHUNK_CHARS = 160_000;
+const MAX_REVIEW_CHUNKS = 12;
+const REVIEW_TIMEOUT_MS = 180_000;
+const GH_TIMEOUT_MS = 60_000;
+
+const SEVERITY_ICONS: Record<ReviewSeverity, string> = {
+  critical: "🔴",
+  major: "🟠",
+  minor: "🟡",
+  nit: "⚪",
+};
+
+export function describeReviewTarget(target: ReviewTarget): string {
+  switch (target.kind) {
+    case "worktree":
+      return "uncommitted changes";
+    case "staged":
+      return "staged changes";
+    case "ref":
+      return target.ref.includes("..") ? target.ref : `${target.ref}...HEAD`;
+    case "pr":
+      return `PR #${target.number}`;
+  }
+}
+
+export function severityAtLeast(severity: ReviewSeverity, threshold: ReviewSeverity): boolean {
+  return REVIEW_SEVERITIES.indexOf(severity) <= REVIEW_SEVERITIES.indexOf(threshold);
+}
+
+/** Reads the diff to review: local git for worktree/staged/ref targets, `gh pr diff` for PRs. */
+export async function readReviewDiff(root: string, target: ReviewTarget): Promise<string> {
+  const diffArgs = ["diff", "--no-ext-diff", "--no-textconv", "--no-color"];
+  switch (target.kind) {
+    case "worktree":
+      return await runGit(root, [...diffArgs, "HEAD"]);
+    case "staged":
+      return await runGit(root, [...diffArgs, "--cached"]);
+    case "ref":
+      if (target.ref.startsWith("-")) {
+        throw new Error(`invalid ref: ${target.ref}`);
+      }
+      return await runGit(root, [...diffArgs, describeReviewTarget(target), "--"]);
+    case "pr": {
+      const result = await runCommandWithTimeout(
+        ["gh", "pr", "diff", String(target.number), "--color=never"],
+        { timeoutMs: GH_TIMEOUT_MS, cwd: root, env: { GH_PROMPT_DISABLED: "1" }, input: "" },
+      ).catch((err: unknown) => {
+        throw new Error(`gh pr diff failed (is the GitHub CLI installed?): ${String(err)}`);
+      });
+      if (result.code !== 0) {
+        const detail = (result.stderr || result.stdout).trim().split("\n").slice(0, 5).join("\n");
+        throw new Error(`gh pr diff failed${detail ? `: ${detail}` : ""}`);
+      }
+      return result.stdout;
+    }
+  }
+}
+
+/** Splits a unified diff into per-file sections (each starting at its `diff --git` line). */
+export function splitDiffByFile(diff: string): Array<{ path: string; text: string }> {
+  const sections: Array<{ path: string; text: string }> = [];
+  for (const part of diff.split(/^(?=diff --git )/m)) {
+    if (!part.startsWith("diff --git ")) {
+      continue;
+    }
+    const header = part.split("\n", 1)[0] ?? "";
+    // Deleted files only have `--- a/…`; binary and mode-only changes only the header line.
+    const filePath =
+      part.match(/^\+\+\+ b\/(.+)$/m)?.[1] ??
+      part.match(/^--- a\/(.+)$/m)?.[1] ??
+      header.match(/ b\/(.+)$/)?.[1] ??
+      header;
+    sections.push({ path: filePath, text: part.endsWith("\n") ? part : `${part}\n` });
+  }
+  return sections;
+}
+
+/**
+ * Packs per-file diffs into chunks of at most `maxChars`. A file bigger than one chunk is split
+ * at hunk boundaries with its header repeated; a single oversized hunk is truncated.
+ */
+export function chunkDiff(diff: string, maxChars: number): string[] {
+  const pieces: string[] = [];
+  for (const file of splitDiffByFile(diff)) {
+    if (file.text.length <= maxChars) {
+      pieces.push(file.text);
+      continue;
+    }
+    const [header = "", ...hunks] = file.text.split(/^(?=@@ )/m);
+    let current = header;
+    for (const hunk of hunks) {
+      const room = maxChars - header.length;
+      const body = hunk.length > room ? `${hunk.slice(0, room - 40)}\n[hunk truncated]\n` : hunk;
+      if (current.length + body.length > maxChars && current !== header) {
+        pieces.push(current);
+        current = header;
+      }
+      current += body;
+    }
+    pieces.push(current);
+  }
+
+  const chunks: string[] = [];
+  let current = "";
+  for (const piece of pieces) {
+    if (current && current.length + piece.length > maxChars) {
+      chunks.push(current);
+      current = "";
+    }
+    current += piece;
+  }
+  if (current) {
+    chunks.push(current);
+  }
+  return chunks;
+}
+
+export function resolveReviewChunkChars(contextTokens: number | undefined): number {
+  const tokens = contextTokens && contextTokens > 0 ? contextTokens : DEFAULT_CONTEXT_TOKENS;
+  const chars = Math.floor(tokens * CHUNK_CONTEXT_SHARE * CHARS_PER_TOKEN_ESTIMATE);
+  return Math.min(MAX_CHUNK_CHARS, Math.max(MIN_CHUNK_CHARS, chars));
+}
+
+export function buildReviewPrompt(params: {
+  target: string;
+  chunk: string;
+  index: number;
+  total: number;
+}): string {
+  const part = params.total > 1 ? ` (part ${params.index + 1} of ${params.total})` : "";
+  return [
+    `Review this diff of ${params.target}${part} as a senior engineer.`,
+    "Report real problems in the changed lines: bugs, security issues, data loss, races, broken error handling, missing tests for risky logic, and confusing code. Skip praise, summaries, and anything the diff does not touch.",
+    "Severity: critical (must fix: security hole, data loss, crash), major (likely bug or regression), minor (edge case, maintainability), nit (style or naming).",
+    'Reply with JSON only, no code fences: {"findings":[{"file":"path/in/diff","line":42,"severity":"major","title":"one-line problem","detail":"why it matters and how to fix it"}]}',
+    'Use the line number in the new version of the file, or omit "line". Reply {"findings":[]} when there is nothing worth raising.',
+    "",
+    "<diff>",
+    params.chunk.trimEnd(),
+    "</diff>",
+  ].join("\n");
+}
+
+/** Reads the findings out of a model reply; tolerates fences and text around the JSON. */
+export function parseReviewFindings(text: string): ReviewFinding[] {
+  const start = text.indexOf("{");
+  const end = text.lastIndexOf("}");
+  if (start < 0 || end <= start) {
+    throw new Error("review reply had no JSON");
+  }
+  const parsed = JSON.parse(text.slice(start, end + 1)) as { findings?: unknown };
+  if (!Array.isArray(parsed.findings)) {
+    throw new Error("review reply had no findings array");
+  }
+  const findings: ReviewFinding[] = [];
+  for (const raw of parsed.findings) {
+    if (!raw || typeof raw !== "object") {
+      continue;
+    }
+    const entry = raw as Record<string, unknown>;
+    const title = typeof entry.title === "string" ? entry.title.trim() : "";
+    if (!title) {
+      continue;
+    }
+    const severity = REVIEW_SEVERITIES.find(
+      (level) => level === String(entry.severity).toLowerCase(),
+    );
+    const line = Number(entry.line);
+    const detail = typeof entry.detail === "string" ? entry.detail.trim() : "";
+    findings.push({
+      file: typeof entry.file === "string" && entry.file.trim() ? entry.file.trim() : "(general)",
+      ...(Number.isInteger(line) && line > 0 ? { line } : {}),
+      severity: severity ?? "minor",
+      title,
+      ...(detail ? { detail } : {}),
+    });
+  }
+  return findings;
+}
+
+/**
+ * Reviews a diff with one completion per chunk (no tools or session history) and merges the
+ * findings. Chunks past `MAX_REVIEW_CHUNKS` are reported as skipped rather than reviewed.
+ */
+export async function runCodeReview(params: {
+  cfg: OpenClawConfig;
+  provider: string;
+  model: string;
+  root: string;
+  target: ReviewTarget;
+  contextTokens?: number;
+  onProgress?: (update: { completed: number; total: number }) => void;
+}): Promise<CodeReviewResult> {
+  const target = describeReviewTarget(params.target);
+  const diff = await readReviewDiff(params.root, params.target);
+  const files = splitDiffByFile(diff).length;
+  const chunks = chunkDiff(diff, resolveReviewChunkChars(params.contextTokens));
+  const reviewed = chunks.slice(0, MAX_REVIEW_CHUNKS);
+  const errors: string[] = [];
+  if (chunks.length > reviewed.length) {
+    errors.push(
+      `Diff too large: reviewed ${reviewed.length} of ${chunks.length} parts; review the rest by path or ref.`,
+    );
+  }
+
+  const findings: ReviewFinding[] = [];
+  const member = {
+    key: `${params.provider}/${params.model}`,
+    ref: { provider: params.provider, model: params.model },
+  };
+  for (const [index, chunk] of reviewed.entries()) {
+    params.onProgress?.({ completed: index, total: reviewed.length });
+    const controller = new AbortController();
+    const timeout = setTimeout(() => controller.abort(), REVIEW_TIMEOUT_MS);
+    try {
+      const prompt = buildReviewPrompt({ target, chunk, index, total: reviewed.length });
+      const text = await askModel({ cfg: params.cfg, member, prompt, signal: controller.signal });
+      findings.push(...parseReviewFindings(text));
+    } catch (err) {
+      const label = reviewed.length > 1 ? `Part ${index + 1}` : "Review";
+      errors.push(`${label} failed: ${err instanceof Error ? err.message : String(err)}`);
+    } finally {
+      clearTimeout(timeout);
+    }
+  }
+  params.onProgress?.({ completed: reviewed.length, total: reviewed.length });
+  return { target, files, chunks: chunks.length, findings, errors };
+}
+
+/** Findings grouped by file, most severe first. */
+export function formatReviewFindings(result: CodeReviewResult): string {
+  const files = `${result.files} file${result.files === 1 ? "" : "s"}`;
+  const lines = [`🔎 Review of ${result.target} (${files})`];
+  if (result.files === 0) {
+    lines.push("", "No changes to review.");
+  } else if (result.findings.length === 0 && result.errors.length === 0) {
+    lines.push("", "No issues found.");
+  }
+  const rank = (finding: ReviewFinding) => REVIEW_SEVERITIES.indexOf(finding.severity);
+  const byFile = new Map<string, ReviewFinding[]>();
+  for (const finding of result.findings) {
+    byFile.set(finding.file, [...(byFile.get(finding.file) ?? []), finding]);
+  }
+  const grouped = [...byFile.entries()].toSorted(
+    ([a, left], [b, right]) =>
+      Math.min(...left.map(rank)) - Math.min(...right.map(rank)) || a.localeCompare(b),
+  );
+  for (const [file, findings] of grouped) {
+    lines.push("", file);
+    for (const finding of findings.toSorted(
+      (a, b) => rank(a) - rank(b) || (a.line ?? 0) - (b.line ?? 0),
+    )) {
+      const where = finding.line ? `L${finding.line} ` : "";
+      lines.push(
+        `- ${SEVERITY_ICONS[finding.severity]} ${finding.severity} ${where}${finding.title}`,
+      );
+      if (finding.detail) {
+        lines.push(`  ${finding.detail}`);
+      }
+    }
+  }
+  if (result.findings.length > 0) {
+    const counts = REVIEW_SEVERITIES.map((level) => ({
+      level,
+      count: result.findings.filter((finding) => finding.severity === level).length,
+    }))
+      .filter((entry) => entry.count > 0)
+      .map((entry) => `${entry.count} ${entry.level}`);
+    lines.push("", `Findings: ${counts.join(", ")}`);
+  }
+  if (result.errors.length > 0) {
+    lines.push("", ...result.errors.map((error) => `⚠️ ${error}`));
+  }
+  return lines.join("\n");
+}
@@ -151,12 +151,10 @@ describe("pruneHistoryForContextShare", () => {
     expect(pruned.messages.length).toBe(1);
   });
 
-  it("removes orphaned tool_result messages when tool_use is dropped", () => {
-    // Scenario: assistant with tool_use is in chunk 1 (dropped),
-    // tool_result is in chunk 2 (kept) - orphaned tool_result should be removed
-    // to prevent "unexpected tool_use_id" errors from Anthropic's API
+  it("drops tool_result messages together with their tool_use", () => {
+    // Scenario: the assistant with tool_use is large enough to end chunk 1, but the
+    // tool_result must not be split from it - both are dropped and summarized together.
     const messages: AgentMessage[] = [
-      // Chunk 1 (will be dropped) - contains tool_use
       {
         role: "assistant",
         content: [
@@ -165,7 +163,6 @@ describe("pruneHistoryForContextShare", () => {
         ],
         timestamp: 1,
       },
-      // Chunk 2 (will be kept) - contains orphaned tool_result
       {
         role: "toolResult",
         toolCallId: "call_123",
@@ -187,6 +184,47 @@ describe("pruneHistoryForContextShare", () => {
       parts: 2,
     });
 
+    const keptRoles = pruned.messages.map((m) => m.role);
+    expect(keptRoles).toEqual(["user"]);
+    expect(pruned.droppedMessagesList.map((m) => m.timestamp)).toEqual([1, 2]);
+    expect(pruned.droppedMessages).toBe(pruned.droppedMessagesList.length);
+  });
+
+  it("removes tool_results that were already orphaned in the history", () => {
+    const messages: AgentMessage[] = [
+      // Chunk 1 (will be dropped)
+      {
+        role: "user",
+        content: "x".repeat(4000),
+        timestamp: 1,
+      },
+      // Chunk 2 (will be kept) - contains a tool_result without any tool_use
+      {
+        role: "user",
+        content: "x".repeat(500),
+        timestamp: 2,
+      },
+      {
+        role: "toolResult",
+        toolCallId: "call_missing",
+        toolName: "test_tool",
+        content: [{ type: "text", text: "result" }],
+        timestamp: 3,
+      } as AgentMessage,
+      {
+        role: "user",
+        content: "x".repeat(100),
+        timestamp: 4,
+      },
+    ];
+
+    const pruned = pruneHistoryForContextShare({
+      messages,
+      maxContextTokens: 2000,
+      maxHistoryShare: 0.5,
+      parts: 2,
+    });
+
     // The orphaned tool_result should NOT be in kept messages
     // (this is the critical invariant that prevents API errors)
     const keptRoles = pruned.messages.map((m) => m.role);
@@ -195,7 +233,7 @@ describe("pruneHistoryForContextShare", () => {
     // The orphan count should be reflected in droppedMessages
     // (orphaned tool_results are dropped but not added to droppedMessagesList
     // since they lack context for summarization)
-    expect(pruned.droppedMessages).toBeGreaterThan(pruned.droppedMessagesList.length);
+    expect(pruned.droppedMessages).toBe(pruned.droppedMessagesList.length + 1);
   });
 
   it("keeps tool_result when its tool_use is also kept", () => {
@@ -238,11 +276,8 @@ describe("pruneHistoryForContextShare", () => {
     expect(keptRoles).toContain("toolResult");
   });
 
-  it("removes multiple orphaned tool_results from the same dropped tool_use", () => {
-    // Scenario: assistant with multiple tool_use blocks is dropped,
-    // all corresponding tool_results should be removed from kept messages
+  it("keeps multiple tool_results with the tool_use that requested them", () => {
     const messages: AgentMessage[] = [
-      // Chunk 1 (will be dropped) - contains multiple tool_use blocks
       {
         role: "assistant",
         content: [
@@ -252,7 +287,6 @@ describe("pruneHistoryForContextShare", () => {
         ],
         timestamp: 1,
       },
-      // Chunk 2 (will be kept) - contains orphaned tool_results
       {
         role: "toolResult",
         toolCallId: "call_a",
@@ -281,13 +315,9 @@ describe("pruneHistoryForContextShare", () => {
       parts: 2,
     });
 
-    // No orphaned tool_results should be in kept messages
     const keptToolResults = pruned.messages.filter((m) => m.role === "toolResult");
     expect(keptToolResults).toHaveLength(0);
-
-    // The orphan count should reflect both dropped tool_results
-    // droppedMessages = 1 (assistant) + 2 (orphaned tool_results) = 3
-    // droppedMessagesList only has the assistant message
-    expect(pruned.droppedMessages).toBe(pruned.droppedMessagesList.length + 2);
+    expect(pruned.droppedMessagesList.map((m) => m.timestamp)).toEqual([1, 2, 3]);
+    expect(pruned.droppedMessages).toBe(3);
   });
 });
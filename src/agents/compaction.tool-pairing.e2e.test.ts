@@ -0,0 +1,128 @@
+import type { AgentMessage } from "@mariozechner/pi-agent-core";
+import { SessionManager } from "@mariozechner/pi-coding-agent";
+import { describe, expect, it } from "vitest";
+import {
+  chunkMessagesByMaxTokens,
+  pruneHistoryForContextShare,
+  splitMessagesByTokenShare,
+} from "./compaction.js";
+import { sanitizeSessionHistory } from "./pi-embedded-runner/google.js";
+import { resolveTranscriptPolicy } from "./transcript-policy.js";
+
+let timestamp = 0;
+
+function user(size: number): AgentMessage {
+  return { role: "user", content: "u".repeat(size), timestamp: ++timestamp };
+}
+
+function toolTurn(id: string, callCount: number, size: number): AgentMessage[] {
+  const callIds = Array.from({ length: callCount }, (_, i) => `${id}_${i}`);
+  return [
+    {
+      role: "assistant",
+      content: [
+        { type: "text", text: "a".repeat(size) },
+        ...callIds.map((callId) => ({
+          type: "toolCall",
+          id: callId,
+          name: "read",
+          arguments: { path: callId },
+        })),
+      ],
+      api: "anthropic-messages",
+      provider: "anthropic",
+      model: "claude-opus-4-5",
+      stopReason: "toolUse",
+      timestamp: ++timestamp,
+    } as unknown as AgentMessage,
+    ...callIds.map(
+      (callId) =>
+        ({
+          role: "toolResult",
+          toolCallId: callId,
+          toolName: "read",
+          isError: false,
+          content: [{ type: "text", text: "r".repeat(size) }],
+          timestamp: ++timestamp,
+        }) as AgentMessage,
+    ),
+  ];
+}
+
+function buildHistory(): AgentMessage[] {
+  return [
+    user(400),
+    ...toolTurn("call_a", 1, 3000),
+    ...toolTurn("call_b", 3, 2000),
+    user(800),
+    ...toolTurn("call_c", 2, 5000),
+    ...toolTurn("call_d", 1, 600),
+    user(200),
+  ];
+}
+
+function expectPaired(chunk: AgentMessage[]) {
+  expect(chunk[0]?.role).not.toBe("toolResult");
+  const pending = new Set<string>();
+  for (const message of chunk) {
+    if (message.role === "assistant") {
+      expect(pending.size).toBe(0);
+      for (const block of message.content) {
+        if (block.type === "toolCall") {
+          pending.add(block.id);
+        }
+      }
+    } else if (message.role === "toolResult") {
+      expect(pending.delete(message.toolCallId)).toBe(true);
+    } else {
+      expect(pending.size).toBe(0);
+    }
+  }
+  expect(pending.size).toBe(0);
+}
+
+describe("compaction tool_use/tool_result pairing", () => {
+  it("never splits tool results from their tool call", () => {
+    const messages = buildHistory();
+    for (const parts of [2, 3, 4, 8]) {
+      const chunks = splitMessagesByTokenShare(messages, parts);
+      expect(chunks.flat()).toEqual(messages);
+      chunks.forEach(expectPaired);
+    }
+    for (const maxTokens of [100, 500, 1000, 2500]) {
+      const chunks = chunkMessagesByMaxTokens(messages, maxTokens);
+      expect(chunks.flat()).toEqual(messages);
+      chunks.forEach(expectPaired);
+    }
+  });
+
+  it.each([
+    { provider: "anthropic", modelApi: "anthropic-messages", modelId: "claude-opus-4-5" },
+    { provider: "openai", modelApi: "openai-responses", modelId: "gpt-5.2" },
+  ])("keeps pruned history valid for $modelApi", async ({ provider, modelApi, modelId }) => {
+    const messages = buildHistory();
+    for (const maxContextTokens of [1000, 2000, 4000, 8000]) {
+      const pruned = pruneHistoryForContextShare({ messages, maxContextTokens, parts: 2 });
+      expect(pruned.droppedMessages).toBe(pruned.droppedMessagesList.length);
+      expectPaired(pruned.messages);
+
+      const sanitized = await sanitizeSessionHistory({
+        messages: pruned.messages,
+        modelApi,
+        provider,
+        modelId,
+        sessionManager: SessionManager.inMemory(),
+        sessionId: "test",
+        // OpenAI does not repair pairing before sending, so check what strict repair would change.
+        policy: {
+          ...resolveTranscriptPolicy({ provider, modelApi, modelId }),
+          repairToolUseResultPairing: true,
+        },
+      });
+      // Repair must have nothing to fix: no synthetic results, no dropped orphans.
+      expect(sanitized.map((message) => message.timestamp)).toEqual(
+        pruned.messages.map((message) => message.timestamp),
+      );
+    }
+  });
+});
@@ -38,6 +38,19 @@ export function estimateMessagesTokens(messages: AgentMessage[]): number {
   return safe.reduce((sum, message) => sum + estimateTokens(message), 0);
 }
 
+function isToolResultMessage(message: AgentMessage): boolean {
+  return (message as { role?: unknown } | null)?.role === "toolResult";
+}
+
+/**
+ * Chunk boundaries must not separate an assistant tool call from its tool results: strict
+ * providers reject a tool_result whose tool_use is gone (and vice versa), so results always stay
+ * in the same chunk as the turn that requested them.
+ */
+function canSplitBefore(message: AgentMessage): boolean {
+  return !isToolResultMessage(message);
+}
+
 function normalizeParts(parts: number, messageCount: number): number {
   if (!Number.isFinite(parts) || parts <= 1) {
     return 1;
@@ -68,7 +81,8 @@ export function splitMessagesByTokenShare(
     if (
       chunks.length < normalizedParts - 1 &&
       current.length > 0 &&
-      currentTokens + messageTokens > targetTokens
+      currentTokens + messageTokens > targetTokens &&
+      canSplitBefore(message)
     ) {
       chunks.push(current);
       current = [];
@@ -100,7 +114,13 @@ export function chunkMessagesByMaxTokens(
 
   for (const message of messages) {
     const messageTokens = estimateTokens(message);
-    if (currentChunk.length > 0 && currentTokens + messageTokens > maxTokens) {
+    // An oversized message pushes currentTokens past the limit, so the chunk closes at the next
+    // point where a split keeps tool calls and their results together.
+    if (
+      currentChunk.length > 0 &&
+      currentTokens + messageTokens > maxTokens &&
+      canSplitBefore(message)
+    ) {
       chunks.push(currentChunk);
       currentChunk = [];
       currentTokens = 0;
@@ -108,13 +128,6 @@ export function chunkMessagesByMaxTokens(
 
     currentChunk.push(message);
     currentTokens += messageTokens;
-
-    if (messageTokens > maxTokens) {
-      // Split oversized messages to avoid unbounded chunk growth.
-      chunks.push(currentChunk);
-      currentChunk = [];
-      currentTokens = 0;
-    }
   }
 
   if (currentChunk.length > 0) {
@@ -359,10 +372,9 @@ export function pruneHistoryForContextShare(params: {
     const [dropped, ...rest] = chunks;
     const flatRest = rest.flat();
 
-    // After dropping a chunk, repair tool_use/tool_result pairing to handle
-    // orphaned tool_results (whose tool_use was in the dropped chunk).
-    // repairToolUseResultPairing drops orphaned tool_results, preventing
-    // "unexpected tool_use_id" errors from Anthropic's API.
+    // Chunks never split a tool call from its results, but the history itself may already
+    // contain orphaned tool_results (e.g. from older transcripts). repairToolUseResultPairing
+    // drops them, preventing "unexpected tool_use_id" errors from Anthropic's API.
     const repairReport = repairToolUseResultPairing(flatRest);
     const repairedKept = repairReport.messages;
 
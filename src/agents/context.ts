@@ -3,6 +3,7 @@
 
 import { loadConfig } from "../config/config.js";
 import { resolveOpenClawAgentDir } from "./agent-paths.js";
+import { lookupModelMetadata } from "./model-metadata-registry.js";
 import { ensureOpenClawModelsJson } from "./models-config.js";
 
 type ModelEntry = { id: string; contextWindow?: number };
@@ -36,5 +37,5 @@ export function lookupContextTokens(modelId?: string): number | undefined {
   }
   // Best-effort: kick off loading, but don't block.
   void loadPromise;
-  return MODEL_CACHE.get(modelId);
+  return MODEL_CACHE.get(modelId) ?? lookupModelMetadata({ model: modelId })?.contextWindow;
 }
@@ -0,0 +1,149 @@
+import { completeSimple } from "@mariozechner/pi-ai";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import {
+  buildCouncilJudgePrompt,
+  formatCouncilReply,
+  parseCouncilModelList,
+  prepareCouncil,
+  runCouncil,
+} from "./council.js";
+
+vi.mock("@mariozechner/pi-ai", () => ({
+  completeSimple: vi.fn(),
+  getOAuthProviders: () => [],
+  getOAuthApiKey: vi.fn(async () => null),
+}));
+
+vi.mock("./pi-embedded-runner/model.js", () => ({
+  resolveModel: vi.fn((provider: string, modelId: string) => ({
+    model: { provider, id: modelId, name: modelId, api: "openai-completions", maxTokens: 8192 },
+  })),
+}));
+
+vi.mock("./model-auth.js", () => ({
+  getApiKeyForModel: vi.fn(async () => ({ apiKey: "test-key", source: "test", mode: "api-key" })),
+  requireApiKey: vi.fn((auth: { apiKey?: string }) => auth.apiKey ?? ""),
+}));
+
+vi.mock("./model-catalog.js", () => ({
+  loadModelCatalog: vi.fn(async () => [
+    { provider: "anthropic", id: "claude-opus-4-5", name: "Claude Opus 4.5" },
+    { provider: "openai", id: "gpt-5.2", name: "GPT-5.2" },
+    { provider: "google", id: "gemini-3-pro", name: "Gemini 3 Pro" },
+  ]),
+}));
+
+const cfg = {
+  agents: {
+    defaults: {
+      model: { primary: "anthropic/claude-opus-4-5" },
+      models: {
+        "anthropic/claude-opus-4-5": { alias: "opus" },
+        "openai/gpt-5.2": { alias: "gpt" },
+      },
+      council: { models: ["opus", "gpt"], judge: "opus" },
+    },
+  },
+} as OpenClawConfig;
+
+describe("parseCouncilModelList", () => {
+  it("splits comma and space separated lists and dedupes", () => {
+    expect(parseCouncilModelList("gpt, opus gpt")).toEqual(["gpt", "opus"]);
+    expect(parseCouncilModelList(["a/b", "c/d,a/b"])).toEqual(["a/b", "c/d"]);
+    expect(parseCouncilModelList(undefined)).toEqual([]);
+  });
+});
+
+describe("prepareCouncil", () => {
+  it("resolves configured aliases and the judge", async () => {
+    const council = await prepareCouncil({ cfg });
+    expect(council.members.map((member) => member.key)).toEqual([
+      "anthropic/claude-opus-4-5",
+      "openai/gpt-5.2",
+    ]);
+    expect(council.judge?.key).toBe("anthropic/claude-opus-4-5");
+    expect(council.errors).toEqual([]);
+  });
+
+  it("reports models outside the allowlist", async () => {
+    const council = await prepareCouncil({ cfg, models: ["gpt", "google/gemini-3-pro"] });
+    expect(council.members.map((member) => member.key)).toEqual(["openai/gpt-5.2"]);
+    expect(council.errors).toEqual(["model not allowed: google/gemini-3-pro"]);
+  });
+});
+
+describe("runCouncil", () => {
+  beforeEach(() => {
+    vi.mocked(completeSimple).mockReset();
+  });
+
+  it("collects answers, keeps failures, and asks the judge", async () => {
+    vi.mocked(completeSimple).mockImplementation(async (model, context) => {
+      const prompt = String(context.messages[0]?.content ?? "");
+      if (prompt.includes("<answer")) {
+        return { content: [{ type: "text", text: "Synthesized." }], stopReason: "stop" } as never;
+      }
+      if (model.id === "gpt-5.2") {
+        return { content: [], stopReason: "error", errorMessage: "rate limited" } as never;
+      }
+      return { content: [{ type: "text", text: "Yes." }], stopReason: "stop" } as never;
+    });
+    const council = await prepareCouncil({ cfg });
+    const seen: string[] = [];
+    const result = await runCouncil({
+      cfg,
+      prompt: "Is it safe?",
+      members: council.members,
+      judge: council.judge,
+      timeoutMs: 5_000,
+      onAnswer: (answer) => {
+        seen.push(answer.key);
+      },
+    });
+
+    expect(seen.toSorted()).toEqual(["anthropic/claude-opus-4-5", "openai/gpt-5.2"]);
+    expect(result.answers.map(({ key, text, error }) => ({ key, text, error }))).toEqual([
+      { key: "anthropic/claude-opus-4-5", text: "Yes.", error: undefined },
+      { key: "openai/gpt-5.2", text: undefined, error: "rate limited" },
+    ]);
+    expect(result.synthesis?.text).toBe("Synthesized.");
+    const judgePrompt = String(vi.mocked(completeSimple).mock.calls[2]?.[1].messages[0]?.content);
+    expect(judgePrompt).toContain("Is it safe?");
+    expect(judgePrompt).not.toContain("rate limited");
+
+    const reply = formatCouncilReply(result);
+    expect(reply).toContain("### anthropic/claude-opus-4-5");
+    expect(reply).toContain("### openai/gpt-5.2 (failed after");
+    expect(reply).toContain("### Synthesis by anthropic/claude-opus-4-5");
+  });
+
+  it("skips the judge when no model answered", async () => {
+    vi.mocked(completeSimple).mockResolvedValue({
+      content: [],
+      stopReason: "error",
+      errorMessage: "down",
+    } as never);
+    const council = await prepareCouncil({ cfg });
+    const result = await runCouncil({
+      cfg,
+      prompt: "Hi",
+      members: council.members,
+      judge: council.judge,
+      timeoutMs: 5_000,
+    });
+    expect(result.synthesis).toBeUndefined();
+    expect(vi.mocked(completeSimple)).toHaveBeenCalledTimes(2);
+  });
+});
+
+describe("buildCouncilJudgePrompt", () => {
+  it("labels each answer with its model", () => {
+    const prompt = buildCouncilJudgePrompt("Q", [
+      { key: "a/b", text: "one", durationMs: 1 },
+      { key: "c/d", text: "two", durationMs: 1 },
+    ]);
+    expect(prompt).toContain('<answer id="1" model="a/b">\none\n</answer>');
+    expect(prompt).toContain('<answer id="2" model="c/d">\ntwo\n</answer>');
+  });
+});
@@ -0,0 +1,245 @@
+import { completeSimple, type TextContent } from "@mariozechner/pi-ai";
+import type { OpenClawConfig } from "../config/config.js";
+import type { ModelRef } from "./model-selection.js";
+import { getApiKeyForModel, requireApiKey } from "./model-auth.js";
+import { loadModelCatalog } from "./model-catalog.js";
+import { resolveAllowedModelRef, resolveDefaultModelForAgent } from "./model-selection.js";
+import { resolveModel } from "./pi-embedded-runner/model.js";
+
+/** Upper bound on concurrent council members, to keep one prompt from fanning out too far. */
+export const COUNCIL_MAX_MODELS = 6;
+export const DEFAULT_COUNCIL_TIMEOUT_SECONDS = 120;
+const COUNCIL_MAX_TOKENS = 4096;
+
+export type CouncilMember = { key: string; ref: ModelRef };
+
+export type CouncilAnswer = {
+  key: string;
+  text?: string;
+  error?: string;
+  durationMs: number;
+};
+
+export type CouncilResult = {
+  answers: CouncilAnswer[];
+  /** The judge's synthesis, when a judge was requested. */
+  synthesis?: CouncilAnswer;
+};
+
+/** Splits `a,b c` style model lists and drops duplicates. */
+export function parseCouncilModelList(raw: string | string[] | undefined): string[] {
+  const entries = Array.isArray(raw) ? raw : [raw ?? ""];
+  const parts = entries.flatMap((entry) => entry.split(/[\s,]+/));
+  return Array.from(new Set(parts.map((part) => part.trim()).filter(Boolean)));
+}
+
+/** Resolves model refs/aliases against the model allowlist; unusable entries are reported. */
+export async function resolveCouncilMembers(params: {
+  cfg: OpenClawConfig;
+  models: string[];
+  agentId?: string;
+}): Promise<{ members: CouncilMember[]; errors: string[] }> {
+  const defaultRef = resolveDefaultModelForAgent({ cfg: params.cfg, agentId: params.agentId });
+  const catalog = await loadModelCatalog({ config: params.cfg });
+  const members: CouncilMember[] = [];
+  const errors: string[] = [];
+  for (const raw of params.models) {
+    const resolved = resolveAllowedModelRef({
+      cfg: params.cfg,
+      catalog,
+      raw,
+      defaultProvider: defaultRef.provider,
+      defaultModel: defaultRef.model,
+    });
+    if ("error" in resolved) {
+      errors.push(resolved.error);
+      continue;
+    }
+    if (members.some((member) => member.key === resolved.key)) {
+      continue;
+    }
+    if (members.length >= COUNCIL_MAX_MODELS) {
+      errors.push(`too many models (max ${COUNCIL_MAX_MODELS}): ${resolved.key} skipped`);
+      continue;
+    }
+    members.push({ key: resolved.key, ref: resolved.ref });
+  }
+  return { members, errors };
+}
+
+export function resolveCouncilTimeoutMs(cfg: OpenClawConfig, overrideSeconds?: number): number {
+  const seconds =
+    overrideSeconds ??
+    cfg.agents?.defaults?.council?.timeoutSeconds ??
+    DEFAULT_COUNCIL_TIMEOUT_SECONDS;
+  return Math.max(1, seconds) * 1000;
+}
+
+/**
+ * Resolves the council for a request: explicit models/judge win over
+ * `agents.defaults.council`. Errors cover entries that were dropped.
+ */
+export async function prepareCouncil(params: {
+  cfg: OpenClawConfig;
+  models?: string[];
+  judge?: string;
+  agentId?: string;
+}): Promise<{ members: CouncilMember[]; judge?: CouncilMember; errors: string[] }> {
+  const council = params.cfg.agents?.defaults?.council;
+  const models = params.models?.length ? params.models : parseCouncilModelList(council?.models);
+  const { members, errors } = await resolveCouncilMembers({
+    cfg: params.cfg,
+    models,
+    agentId: params.agentId,
+  });
+  const judgeRaw = params.judge?.trim() || council?.judge?.trim();
+  if (!judgeRaw) {
+    return { members, errors };
+  }
+  const judge = await resolveCouncilMembers({
+    cfg: params.cfg,
+    models: [judgeRaw],
+    agentId: params.agentId,
+  });
+  errors.push(...judge.errors.map((error) => `judge: ${error}`));
+  return { members, judge: judge.members[0], errors };
+}
+
+function isTextContentBlock(block: { type: string }): block is TextContent {
+  return block.type === "text";
+}
+
+/** One completion from a single model (no tools or session history); throws on errors and empty replies. */
+export async function askModel(params: {
+  cfg: OpenClawConfig;
+  member: CouncilMember;
+  prompt: string;
+  signal: AbortSignal;
+}): Promise<string> {
+  const { provider, model } = params.member.ref;
+  const resolved = resolveModel(provider, model, undefined, params.cfg);
+  if (!resolved.model) {
+    throw new Error(resolved.error ?? `Unknown model: ${params.member.key}`);
+  }
+  const apiKey = requireApiKey(
+    await getApiKeyForModel({ model: resolved.model, cfg: params.cfg }),
+    provider,
+  );
+  const res = await completeSimple(
+    resolved.model,
+    { messages: [{ role: "user", content: params.prompt, timestamp: Date.now() }] },
+    {
+      apiKey,
+      maxTokens: Math.min(resolved.model.maxTokens || COUNCIL_MAX_TOKENS, COUNCIL_MAX_TOKENS),
+      signal: params.signal,
+    },
+  );
+  if (res.stopReason === "error" || res.stopReason === "aborted") {
+    throw new Error(res.errorMessage || `request ${res.stopReason}`);
+  }
+  const text = res.content
+    .filter(isTextContentBlock)
+    .map((block) => block.text.trim())
+    .filter(Boolean)
+    .join("\n\n")
+    .trim();
+  if (!text) {
+    throw new Error("empty reply");
+  }
+  return text;
+}
+
+async function askMember(params: {
+  cfg: OpenClawConfig;
+  member: CouncilMember;
+  prompt: string;
+  timeoutMs: number;
+}): Promise<CouncilAnswer> {
+  const startedAt = Date.now();
+  const controller = new AbortController();
+  const timeout = setTimeout(() => controller.abort(), params.timeoutMs);
+  try {
+    const text = await askModel({ ...params, signal: controller.signal });
+    return { key: params.member.key, text, durationMs: Date.now() - startedAt };
+  } catch (err) {
+    const error = controller.signal.aborted
+      ? `timed out after ${Math.round(params.timeoutMs / 1000)}s`
+      : err instanceof Error
+        ? err.message
+        : String(err);
+    return { key: params.member.key, error, durationMs: Date.now() - startedAt };
+  } finally {
+    clearTimeout(timeout);
+  }
+}
+
+export function buildCouncilJudgePrompt(prompt: string, answers: CouncilAnswer[]): string {
+  const sections = answers
+    .filter((answer) => answer.text)
+    .map(
+      (answer, index) =>
+        `<answer id="${index + 1}" model="${answer.key}">\n${answer.text}\n</answer>`,
+    );
+  return [
+    "Several models answered the same request independently. Compare their answers: point out where they agree, where they disagree and which claims look wrong, then give the single best answer.",
+    "Reply with the synthesized answer only, without restating each answer.",
+    "",
+    `<request>\n${prompt}\n</request>`,
+    "",
+    ...sections,
+  ].join("\n");
+}
+
+/**
+ * Sends one prompt to every member concurrently (single completion, no tools or session history)
+ * and, when `judge` is set, asks it to synthesize the successful answers.
+ */
+export async function runCouncil(params: {
+  cfg: OpenClawConfig;
+  prompt: string;
+  members: CouncilMember[];
+  judge?: CouncilMember;
+  timeoutMs: number;
+  /** Called as each answer arrives, in completion order. */
+  onAnswer?: (answer: CouncilAnswer) => void | Promise<void>;
+}): Promise<CouncilResult> {
+  const answers = await Promise.all(
+    params.members.map(async (member) => {
+      const answer = await askMember({
+        cfg: params.cfg,
+        member,
+        prompt: params.prompt,
+        timeoutMs: params.timeoutMs,
+      });
+      await params.onAnswer?.(answer);
+      return answer;
+    }),
+  );
+  const successful = answers.filter((answer) => answer.text);
+  if (!params.judge || successful.length === 0) {
+    return { answers };
+  }
+  const synthesis = await askMember({
+    cfg: params.cfg,
+    member: params.judge,
+    prompt: buildCouncilJudgePrompt(params.prompt, successful),
+    timeoutMs: params.timeoutMs,
+  });
+  return { answers, synthesis };
+}
+
+export function formatCouncilAnswer(answer: CouncilAnswer, label = answer.key): string {
+  const seconds = (answer.durationMs / 1000).toFixed(1);
+  if (answer.error) {
+    return `### ${label} (failed after ${seconds}s)\n${answer.error}`;
+  }
+  return `### ${label} (${seconds}s)\n${answer.text ?? ""}`;
+}
+
+export function formatCouncilReply(result: CouncilResult): string {
+  const sections = result.answers.map((answer) => formatCouncilAnswer(answer));
+  if (result.synthesis) {
+    sections.push(formatCouncilAnswer(result.synthesis, `Synthesis by ${result.synthesis.key}`));
+  }
+  return sections.join("\n\n");
+}
@@ -0,0 +1,209 @@
+import path from "node:path";
+import type { OpenClawConfig } from "../config/config.js";
+import { runCommandWithTimeout } from "../process/exec.js";
+import { getTrackedExecCwdWithin, resolveExecScopeKey } from "./bash-tools.cwd.js";
+import { askModel } from "./council.js";
+import { listRepoFilterDrivers, safeGitConfig } from "./git-safe-config.js";
+import { findGitRoot } from "./system-prompt-params.js";
+
+const GIT_TIMEOUT_MS = 30_000;
+/** An approved `git commit` runs the repository's hooks, which can take a while (lint, tests). */
+const COMMIT_TIMEOUT_MS = 180_000;
+const MESSAGE_TIMEOUT_MS = 60_000;
+const MAX_DIFF_CHARS = 24_000;
+const RECENT_SUBJECTS = 10;
+
+const GIT_ENV: NodeJS.ProcessEnv = {
+  GIT_CONFIG_NOSYSTEM: "1",
+  GIT_TERMINAL_PROMPT: "0",
+  GIT_PAGER: "cat",
+  LC_ALL: "C",
+};
+
+export type StagedChanges = {
+  files: string[];
+  stat: string;
+  diff: string;
+  truncated: boolean;
+};
+
+/**
+ * Runs git without a pager, color, or a chance to prompt, and without repository hooks or an
+ * fsmonitor unless `hooks` is set, or repository filter drivers unless `filters` is set; throws
+ * with stderr on failure.
+ */
+export async function runGit(
+  cwd: string,
+  argv: string[],
+  opts: { input?: string; timeoutMs?: number; hooks?: boolean; filters?: boolean } = {},
+): Promise<string> {
+  const filterDrivers = opts.filters ? [] : await listRepoFilterDrivers(cwd);
+  const config = safeGitConfig({ hooks: opts.hooks, filterDrivers });
+  const result = await runCommandWithTimeout(["git", ...config, ...argv], {
+    timeoutMs: opts.timeoutMs ?? GIT_TIMEOUT_MS,
+    cwd,
+    env: GIT_ENV,
+    // Piped stdin instead of the gateway's TTY, so nothing can prompt.
+    input: opts.input ?? "",
+  });
+  if (result.code !== 0) {
+    const detail = (result.stderr || result.stdout).trim().split("\n").slice(0, 8).join("\n");
+    throw new Error(`git ${argv[0]} failed${detail ? `: ${detail}` : ""}`);
+  }
+  return result.stdout;
+}
+
+/**
+ * Repository a session is working in: the repo the agent `cd`'d into (only inside the workspace
+ * or `agents.defaults.repoRoot`), the configured repoRoot, then the workspace. Null when none of
+ * them is inside a git repo.
+ */
+export function resolveSessionGitRoot(params: {
+  workspaceDir: string;
+  config?: OpenClawConfig;
+  sessionKey?: string;
+  agentId?: string;
+}): string | null {
+  const repoRoot = params.config?.agents?.defaults?.repoRoot?.trim();
+  const candidates = [
+    getTrackedExecCwdWithin(resolveExecScopeKey(params), [params.workspaceDir, repoRoot]),
+    repoRoot,
+    params.workspaceDir,
+  ];
+  for (const candidate of candidates) {
+    const root = candidate ? findGitRoot(path.resolve(candidate)) : null;
+    if (root) {
+      return root;
+    }
+  }
+  return null;
+}
+
+/**
+ * `git add` the given paths (relative to the repo root); `["."]` stages everything. Filter drivers
+ * run (e.g. Git LFS configured with `git lfs install --local`) since the owner asked for this.
+ */
+export async function stageGitPaths(root: string, paths: string[]): Promise<void> {
+  if (paths.length > 0) {
+    await runGit(root, ["add", "--", ...paths], { filters: true });
+  }
+}
+
+/** The staged diff, capped for prompting; null when nothing is staged. */
+export async function readStagedChanges(root: string): Promise<StagedChanges | null> {
+  const names = await runGit(root, ["diff", "--cached", "--name-only", "-z"]);
+  const files = names.split("\0").filter(Boolean);
+  if (files.length === 0) {
+    return null;
+  }
+  const stat = (await runGit(root, ["diff", "--cached", "--stat", "--no-ext-diff"])).trimEnd();
+  const full = await runGit(root, ["diff", "--cached", "--no-ext-diff", "--no-textconv"]);
+  const truncated = full.length > MAX_DIFF_CHARS;
+  return { files, stat, diff: truncated ? full.slice(0, MAX_DIFF_CHARS) : full, truncated };
+}
+
+/** Tree id of the index, used to notice staging changes between proposal and approval. */
+export async function readStagedTree(root: string): Promise<string> {
+  return (await runGit(root, ["write-tree"])).trim();
+}
+
+async function readRecentSubjects(root: string): Promise<string[]> {
+  try {
+    const out = await runGit(root, ["log", `-n${RECENT_SUBJECTS}`, "--format=%s"]);
+    return out.split("\n").filter(Boolean);
+  } catch {
+    // A repository without commits yet.
+    return [];
+  }
+}
+
+/** Commits the index with `message` (the repository's hooks run) and returns the new commit. */
+export async function commitStaged(
+  root: string,
+  message: string,
+): Promise<{ hash: string; subject: string }> {
+  await runGit(root, ["commit", "--quiet", "--file=-"], {
+    input: message,
+    timeoutMs: COMMIT_TIMEOUT_MS,
+    hooks: true,
+    filters: true,
+  });
+  const [hash = "", subject = ""] = (await runGit(root, ["log", "-n1", "--format=%h%x1f%s"]))
+    .trim()
+    .split("\x1f");
+  return { hash, subject };
+}
+
+export function buildCommitMessagePrompt(params: {
+  changes: StagedChanges;
+  recentSubjects?: string[];
+}): string {
+  const lines = [
+    "Write a git commit message for the staged changes below, in Conventional Commits format:",
+    "- Subject: `type(scope): summary` where type is one of feat, fix, refactor, perf, docs, test, build, ci, chore, style, revert; the scope is optional.",
+    "- Summary in the imperative mood, lowercase, no trailing period, whole subject under 72 characters.",
+    "- If the change needs explaining, add a blank line and a short body wrapped at 72 columns saying what changed and why. Mark breaking changes with `!` and a `BREAKING CHANGE:` footer.",
+    "Reply with the commit message only: no code fences, quotes, or commentary.",
+  ];
+  if (params.recentSubjects?.length) {
+    lines.push("", "Recent commit subjects in this repository (for scope names):");
+    lines.push(...params.recentSubjects.map((subject) => `- ${subject}`));
+  }
+  lines.push("", "<stat>", params.changes.stat, "</stat>", "", "<diff>", params.changes.diff);
+  if (params.changes.truncated) {
+    lines.push("[diff truncated; the stat above lists every changed file]");
+  }
+  lines.push("</diff>");
+  return lines.join("\n");
+}
+
+/** Strips the fences, quotes, and trailing whitespace models sometimes wrap messages in. */
+export function cleanCommitMessage(raw: string): string {
+  let text = raw.trim();
+  const fenced = text.match(/^```[\w-]*\n([\s\S]*?)\n```$/);
+  if (fenced) {
+    text = fenced[1].trim();
+  }
+  if (/^(["'`]).*\1$/s.test(text)) {
+    text = text.slice(1, -1).trim();
+  }
+  return text
+    .split("\n")
+    .map((line) => line.trimEnd())
+    .join("\n")
+    .replace(/\n{3,}/g, "\n\n");
+}
+
+/** Asks the session's model for a conventional-commit message describing the staged diff. */
+export async function generateCommitMessage(params: {
+  cfg: OpenClawConfig;
+  provider: string;
+  model: string;
+  root: string;
+  changes: StagedChanges;
+}): Promise<string> {
+  const prompt = buildCommitMessagePrompt({
+    changes: params.changes,
+    recentSubjects: await readRecentSubjects(params.root),
+  });
+  const controller = new AbortController();
+  const timeout = setTimeout(() => controller.abort(), MESSAGE_TIMEOUT_MS);
+  try {
+    const text = await askModel({
+      cfg: params.cfg,
+      member: {
+        key: `${params.provider}/${params.model}`,
+        ref: { provider: params.provider, model: params.model },
+      },
+      prompt,
+      signal: controller.signal,
+    });
+    const message = cleanCommitMessage(text);
+    if (!message) {
+      throw new Error("empty commit message");
+    }
+    return message;
+  } finally {
+    clearTimeout(timeout);
+  }
+}
@@ -0,0 +1,71 @@
+import { execFileSync } from "node:child_process";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import { buildGitContext, parseGitContextArgs } from "./git-context.js";
+
+describe("git context", () => {
+  let root: string;
+  const identity = ["-c", "user.name=Ada", "-c", "user.email=ada@example.com"];
+  const git = (...args: string[]) =>
+    execFileSync("git", [...identity, "-c", "commit.gpgsign=false", ...args], {
+      cwd: root,
+      stdio: "ignore",
+    });
+
+  beforeEach(async () => {
+    root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-git-context-"));
+    git("init", "-q", "-b", "main");
+    await fs.writeFile(path.join(root, "a.ts"), "export const a = 1;\n");
+    git("add", "a.ts");
+    git("commit", "-q", "-m", "Add a");
+  });
+
+  afterEach(async () => {
+    await fs.rm(root, { recursive: true, force: true });
+  });
+
+  it("parses /diff-context arguments", () => {
+    expect(parseGitContextArgs("")).toEqual({ diff: true });
+    expect(parseGitContextArgs("staged log 3")).toEqual({ staged: true, log: 3 });
+    expect(parseGitContextArgs("--with-diff log")).toEqual({ diff: true, log: 10 });
+    expect(() => parseGitContextArgs("log 500")).toThrow("from 1 to 100");
+    expect(() => parseGitContextArgs("blame")).toThrow("unknown argument: blame");
+  });
+
+  it("labels the diff, staged changes, and recent commits", async () => {
+    await fs.writeFile(path.join(root, "a.ts"), "export const a = 2;\n");
+    await fs.writeFile(path.join(root, "b.ts"), "export const b = 1;\n");
+    git("add", "b.ts");
+    await fs.writeFile(path.join(root, "notes.md"), "todo\n");
+
+    const context = await buildGitContext(root, { diff: true, staged: true, log: 5 });
+    expect(context.root).toBe(root);
+    expect(context.summary).toEqual(["diff (2 files)", "staged (1 file)", "log (1 commit)"]);
+    expect(context.truncated).toBe(false);
+    expect(context.text).toMatch(/^<git-context repo="[^"]+" branch="main">\n/);
+    expect(context.text).toContain("Uncommitted changes (git diff HEAD):\n```diff\n");
+    expect(context.text).toContain("+export const a = 2;");
+    expect(context.text).toContain("Untracked files: notes.md");
+    expect(context.text).toContain("Staged changes (git diff --cached):");
+    expect(context.text).toMatch(/Recent commits \(git log -n 5\):\n```\n\w+ \S+ Ada: Add a\n```/);
+    expect(context.text.endsWith("\n</git-context>")).toBe(true);
+  });
+
+  it("caps large diffs and rejects directories outside git", async () => {
+    await fs.writeFile(path.join(root, "a.ts"), "x".repeat(5_000));
+    const context = await buildGitContext(root, { diff: true }, { maxChars: 1_000 });
+    expect(context.truncated).toBe(true);
+    expect(context.text).toContain("[truncated]");
+
+    const outside = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-no-git-"));
+    try {
+      await expect(buildGitContext(outside, { diff: true })).rejects.toThrow(
+        "not inside a git repository",
+      );
+    } finally {
+      await fs.rm(outside, { recursive: true, force: true });
+    }
+  });
+});
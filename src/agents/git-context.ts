@@ -0,0 +1,166 @@
+import { shortenHomePath } from "../utils.js";
+import { runGit } from "./git-commit.js";
+import { findGitRoot } from "./system-prompt-params.js";
+
+export const MAX_GIT_CONTEXT_LOG = 100;
+const DEFAULT_MAX_CHARS = 60_000;
+const MAX_UNTRACKED_LISTED = 50;
+
+/** What to attach: uncommitted changes, staged changes, and/or the last `log` commits. */
+export type GitContextRequest = {
+  diff?: boolean;
+  staged?: boolean;
+  log?: number;
+};
+
+export type GitContext = {
+  root: string;
+  text: string;
+  /** One label per attached section, e.g. `diff (3 files)`. */
+  summary: string[];
+  truncated: boolean;
+};
+
+export function hasGitContextRequest(request: GitContextRequest): boolean {
+  return Boolean(request.diff || request.staged || request.log);
+}
+
+export function parseGitContextLogCount(raw: string | undefined, label = "log count"): number {
+  const count = Number(raw);
+  if (!Number.isInteger(count) || count <= 0 || count > MAX_GIT_CONTEXT_LOG) {
+    throw new Error(`${label} must be an integer from 1 to ${MAX_GIT_CONTEXT_LOG}`);
+  }
+  return count;
+}
+
+/** Parses `/diff-context` arguments: `diff`, `staged`, `log [n]` in any combination. */
+export function parseGitContextArgs(args: string): GitContextRequest {
+  const tokens = args.trim().split(/\s+/).filter(Boolean);
+  if (tokens.length === 0) {
+    return { diff: true };
+  }
+  const request: GitContextRequest = {};
+  for (let i = 0; i < tokens.length; i += 1) {
+    const token = tokens[i].toLowerCase().replace(/^--(with-)?/, "");
+    if (token === "diff") {
+      request.diff = true;
+    } else if (token === "staged" || token === "cached") {
+      request.staged = true;
+    } else if (token === "log") {
+      const next = tokens[i + 1];
+      if (next && /^\d+$/.test(next)) {
+        request.log = parseGitContextLogCount(next);
+        i += 1;
+      } else {
+        request.log = 10;
+      }
+    } else {
+      throw new Error(`unknown argument: ${tokens[i]} (use diff, staged, or log [n])`);
+    }
+  }
+  return request;
+}
+
+function fenced(body: string, lang = ""): string {
+  const longestRun = Math.max(0, ...(body.match(/`+/g) ?? []).map((run) => run.length));
+  const fence = "`".repeat(Math.max(3, longestRun + 1));
+  return `${fence}${lang}\n${body.trimEnd()}\n${fence}`;
+}
+
+function plural(count: number, noun: string): string {
+  return `${count} ${noun}${count === 1 ? "" : "s"}`;
+}
+
+function countDiffFiles(diff: string): string {
+  return plural(diff.match(/^diff --git /gm)?.length ?? 0, "file");
+}
+
+/**
+ * Reads the requested git output for the repository containing `cwd` and wraps it in one
+ * labeled `<git-context>` block for a prompt. Throws when `cwd` is not inside a git repository.
+ */
+export async function buildGitContext(
+  cwd: string,
+  request: GitContextRequest,
+  opts: { maxChars?: number } = {},
+): Promise<GitContext> {
+  const root = findGitRoot(cwd);
+  if (!root) {
+    throw new Error(`not inside a git repository: ${shortenHomePath(cwd)}`);
+  }
+  let budget = opts.maxChars ?? DEFAULT_MAX_CHARS;
+  let truncated = false;
+  const cap = (text: string) => {
+    if (text.length <= budget) {
+      budget -= text.length;
+      return text;
+    }
+    truncated = true;
+    const kept = text.slice(0, Math.max(0, budget));
+    budget = 0;
+    return `${kept}\n[truncated]`;
+  };
+
+  const branch = await runGit(root, ["rev-parse", "--abbrev-ref", "HEAD"]).then(
+    (out) => out.trim(),
+    () => "",
+  );
+  const sections: string[] = [];
+  const summary: string[] = [];
+  const diffArgs = ["diff", "--no-ext-diff", "--no-textconv", "--no-color"];
+
+  if (request.diff) {
+    // Against HEAD so staged and unstaged edits both show; a repo without commits has no HEAD.
+    const hasHead = await runGit(root, ["rev-parse", "--verify", "--quiet", "HEAD"]).then(
+      () => true,
+      () => false,
+    );
+    const diff = await runGit(root, hasHead ? [...diffArgs, "HEAD"] : [...diffArgs, "--cached"]);
+    const untracked = (await runGit(root, ["ls-files", "--others", "--exclude-standard", "-z"]))
+      .split("\0")
+      .filter(Boolean);
+    const lines = [
+      `Uncommitted changes (git diff ${hasHead ? "HEAD" : "--cached"}):`,
+      diff.trim() ? fenced(cap(diff), "diff") : "(no changes)",
+    ];
+    if (untracked.length > 0) {
+      const listed = untracked.slice(0, MAX_UNTRACKED_LISTED);
+      const more = untracked.length - listed.length;
+      lines.push(`Untracked files: ${listed.join(", ")}${more > 0 ? ` (and ${more} more)` : ""}`);
+    }
+    sections.push(lines.join("\n"));
+    summary.push(`diff (${countDiffFiles(diff)})`);
+  }
+  if (request.staged) {
+    const diff = await runGit(root, [...diffArgs, "--cached"]);
+    sections.push(
+      [
+        "Staged changes (git diff --cached):",
+        diff.trim() ? fenced(cap(diff), "diff") : "(nothing staged)",
+      ].join("\n"),
+    );
+    summary.push(`staged (${countDiffFiles(diff)})`);
+  }
+  if (request.log) {
+    const log = await runGit(root, ["log", `-n${request.log}`, "--format=%h %as %an: %s"]).catch(
+      () => "",
+    );
+    sections.push(
+      [
+        `Recent commits (git log -n ${request.log}):`,
+        log.trim() ? fenced(cap(log)) : "(no commits)",
+      ].join("\n"),
+    );
+    summary.push(`log (${plural(log.split("\n").filter(Boolean).length, "commit")})`);
+  }
+
+  const attrs = [`repo="${shortenHomePath(root)}"`, branch ? `branch="${branch}"` : ""]
+    .filter(Boolean)
+    .join(" ");
+  return {
+    root,
+    text: `<git-context ${attrs}>\n${sections.join("\n\n")}\n</git-context>`,
+    summary,
+    truncated,
+  };
+}
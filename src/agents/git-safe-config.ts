@@ -0,0 +1,75 @@
+import { runCommandWithTimeout } from "../process/exec.js";
+
+const LIST_FILTERS_TIMEOUT_MS = 10_000;
+
+/**
+ * `-c` overrides for running git on the host in a repository the agent can write to: repository
+ * config cannot launch programs through hooks or an fsmonitor, and output never goes to a pager.
+ * `hooks: true` keeps the repository's hooks for commits the owner explicitly approved.
+ * `filterDrivers` are switched off (see `listRepoFilterDrivers`).
+ */
+export function safeGitConfig(opts: { hooks?: boolean; filterDrivers?: string[] } = {}): string[] {
+  // An empty command makes git pass content through unchanged; `required` would make that fail.
+  const filters = (opts.filterDrivers ?? []).flatMap((name) => [
+    "-c",
+    `filter.${name}.clean=`,
+    "-c",
+    `filter.${name}.smudge=`,
+    "-c",
+    `filter.${name}.process=`,
+    "-c",
+    `filter.${name}.required=false`,
+  ]);
+  return [
+    "-c",
+    "core.fsmonitor=false",
+    ...(opts.hooks ? [] : ["-c", "core.hooksPath=/dev/null"]),
+    "-c",
+    "core.pager=cat",
+    "-c",
+    "color.ui=false",
+    ...filters,
+  ];
+}
+
+/**
+ * Filter drivers (`filter.<name>.clean`, `.smudge`, `.process`) defined in the repository's own
+ * config. `.gitattributes` can route any path through them, so `status`, `diff`, and `add` would
+ * run whatever command the repository config names. Drivers from the user's global or system
+ * config (e.g. Git LFS) are trusted and left alone.
+ */
+export async function listRepoFilterDrivers(cwd: string): Promise<string[]> {
+  const result = await runCommandWithTimeout(
+    [
+      "git",
+      ...safeGitConfig(),
+      "config",
+      "--show-scope",
+      "--name-only",
+      "--get-regexp",
+      "^filter\\.",
+    ],
+    { timeoutMs: LIST_FILTERS_TIMEOUT_MS, cwd, input: "" },
+  );
+  // Exit code 1 means no filter keys are set.
+  if (result.code === 1) {
+    return [];
+  }
+  if (result.code !== 0) {
+    const detail = result.stderr.trim().split("\n")[0];
+    throw new Error(`git config failed${detail ? `: ${detail}` : ""}`);
+  }
+  const names = new Set<string>();
+  for (const line of result.stdout.split("\n")) {
+    const [scope = "", key = ""] = line.split("\t");
+    if (scope === "global" || scope === "system" || !key.startsWith("filter.")) {
+      continue;
+    }
+    // Driver names may contain dots; the variable is whatever follows the last one.
+    const name = key.slice("filter.".length, key.lastIndexOf("."));
+    if (name) {
+      names.add(name);
+    }
+  }
+  return [...names];
+}
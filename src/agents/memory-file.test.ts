@@ -0,0 +1,131 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import { clearTrackedExecCwd, setTrackedExecCwd } from "./bash-tools.cwd.js";
+import {
+  appendMemoryFact,
+  readMemoryFile,
+  removeMemoryFact,
+  resolveMemoryMaxChars,
+  resolveProjectMemoryDir,
+  resolveProjectMemoryRoot,
+} from "./memory-file.js";
+import { createMemoryReadTool, createMemoryWriteTool } from "./tools/memory-tool.js";
+
+describe("memory file facts", () => {
+  let workspaceDir: string;
+
+  beforeEach(async () => {
+    workspaceDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-memory-file-"));
+  });
+
+  afterEach(async () => {
+    await fs.rm(workspaceDir, { recursive: true, force: true });
+  });
+
+  it("appends bullets, skips duplicates, and removes facts", async () => {
+    await fs.writeFile(path.join(workspaceDir, "MEMORY.md"), "# Memory\n- Likes coffee");
+
+    const added = await appendMemoryFact({
+      workspaceDir,
+      fact: "User prefers  tabs",
+      maxChars: 1000,
+    });
+    expect(added.status).toBe("added");
+    const dup = await appendMemoryFact({ workspaceDir, fact: "user prefers tabs", maxChars: 1000 });
+    expect(dup.status).toBe("exists");
+    expect((await readMemoryFile(workspaceDir)).text).toBe(
+      "# Memory\n- Likes coffee\n- User prefers tabs\n",
+    );
+
+    await removeMemoryFact({ workspaceDir, fact: "likes coffee", maxChars: 1000 });
+    expect((await readMemoryFile(workspaceDir)).text).toBe("# Memory\n- User prefers tabs\n");
+    await expect(
+      removeMemoryFact({ workspaceDir, fact: "Memory", maxChars: 1000 }),
+    ).rejects.toThrow(/not found/);
+  });
+
+  it("enforces the size cap", async () => {
+    await appendMemoryFact({ workspaceDir, fact: "Build command is make check", maxChars: 40 });
+    await expect(
+      appendMemoryFact({ workspaceDir, fact: "Tests run with pnpm test", maxChars: 40 }),
+    ).rejects.toThrow(/memory is full/);
+    expect(resolveMemoryMaxChars({ memory: { maxChars: 5000 } })).toBe(5000);
+    expect(resolveMemoryMaxChars({ agents: { defaults: { bootstrapMaxChars: 8000 } } })).toBe(8000);
+  });
+
+  it("only offers the tools in direct sessions", async () => {
+    const config = { agents: { defaults: { workspace: workspaceDir } } };
+    expect(
+      createMemoryWriteTool({ config, agentSessionKey: "agent:main:discord:group:123" }),
+    ).toBeNull();
+    const write = createMemoryWriteTool({ config, agentSessionKey: "agent:main:main" });
+    const read = createMemoryReadTool({ config, agentSessionKey: "agent:main:main" });
+
+    await write?.execute("call", { fact: "Deploys happen on Fridays" });
+    const result = await read?.execute("call", {});
+    expect(result?.details).toMatchObject({
+      text: "- Deploys happen on Fridays\n",
+      maxChars: 20_000,
+    });
+  });
+
+  describe("project namespaces", () => {
+    const sessionKey = "agent:main:main";
+    let stateDir: string;
+    let projectDir: string;
+    let previousStateDir: string | undefined;
+
+    beforeEach(async () => {
+      stateDir = path.join(workspaceDir, "state");
+      projectDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-memory-project-"));
+      await fs.mkdir(path.join(projectDir, ".git"));
+      await fs.mkdir(path.join(projectDir, "src"));
+      previousStateDir = process.env.OPENCLAW_STATE_DIR;
+      process.env.OPENCLAW_STATE_DIR = stateDir;
+    });
+
+    afterEach(async () => {
+      clearTrackedExecCwd(sessionKey);
+      if (previousStateDir === undefined) {
+        delete process.env.OPENCLAW_STATE_DIR;
+      } else {
+        process.env.OPENCLAW_STATE_DIR = previousStateDir;
+      }
+      await fs.rm(projectDir, { recursive: true, force: true });
+    });
+
+    it("keys project memory by the git root of the exec cwd inside repoRoot", () => {
+      const config = { agents: { defaults: { repoRoot: projectDir } } };
+      expect(resolveProjectMemoryRoot({ workspaceDir, sessionKey })).toBeNull();
+      setTrackedExecCwd(sessionKey, path.join(projectDir, "src"));
+      expect(resolveProjectMemoryRoot({ workspaceDir, config, sessionKey })).toBe(projectDir);
+      // A cd outside the workspace and repoRoot does not pick the project.
+      expect(resolveProjectMemoryRoot({ workspaceDir, sessionKey })).toBeNull();
+      setTrackedExecCwd(sessionKey, workspaceDir);
+      expect(resolveProjectMemoryRoot({ workspaceDir, sessionKey })).toBeNull();
+
+      const dir = resolveProjectMemoryDir(projectDir);
+      expect(path.dirname(dir)).toBe(path.join(stateDir, "memory", "projects"));
+      expect(path.basename(dir)).toMatch(/^openclaw-memory-project-.+-[0-9a-f]{10}$/);
+      expect(resolveProjectMemoryDir(`${projectDir}-other`)).not.toBe(dir);
+    });
+
+    it("writes project facts separately from global memory", async () => {
+      const config = { agents: { defaults: { workspace: workspaceDir, repoRoot: projectDir } } };
+      setTrackedExecCwd(sessionKey, projectDir);
+      const write = createMemoryWriteTool({ config, agentSessionKey: sessionKey });
+      const read = createMemoryReadTool({ config, agentSessionKey: sessionKey });
+
+      await write?.execute("call", { fact: "Uses pnpm" });
+      await write?.execute("call", { fact: "User prefers tabs", scope: "global" });
+      const result = await read?.execute("call", {});
+
+      expect(result?.details).toMatchObject({
+        text: "- User prefers tabs\n",
+        project: { root: projectDir, text: "- Uses pnpm\n" },
+      });
+    });
+  });
+});
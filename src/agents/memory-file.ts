@@ -0,0 +1,186 @@
+import { createHash } from "node:crypto";
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveStateDir } from "../config/paths.js";
+import { getTrackedExecCwdWithin, resolveExecScopeKey } from "./bash-tools.cwd.js";
+import { resolveBootstrapMaxChars } from "./pi-embedded-helpers/bootstrap.js";
+import { findGitRoot } from "./system-prompt-params.js";
+import { DEFAULT_MEMORY_ALT_FILENAME, DEFAULT_MEMORY_FILENAME } from "./workspace.js";
+
+export const MAX_MEMORY_FACT_CHARS = 500;
+
+const BULLET_RE = /^\s*[-*]\s+/;
+
+export type MemoryFile = {
+  path: string;
+  text: string;
+  exists: boolean;
+};
+
+/**
+ * Cap for MEMORY.md growth via memory_write. Defaults to the bootstrap injection limit so that
+ * everything the agent stores still fits into the system prompt untruncated.
+ */
+export function resolveMemoryMaxChars(cfg?: OpenClawConfig): number {
+  const raw = cfg?.memory?.maxChars;
+  if (typeof raw === "number" && Number.isFinite(raw) && raw > 0) {
+    return Math.floor(raw);
+  }
+  return resolveBootstrapMaxChars(cfg);
+}
+
+function isInsideDir(target: string, dir: string): boolean {
+  const rel = path.relative(dir, target);
+  return rel === "" || (!rel.startsWith("..") && !path.isAbsolute(rel));
+}
+
+/**
+ * The project a session is working on: the git root (or directory) of the exec cwd the agent
+ * `cd`'d into when it lies inside `agents.defaults.repoRoot`, else repoRoot itself. Null when that
+ * is the agent workspace, whose MEMORY.md already is the global memory.
+ */
+export function resolveProjectMemoryRoot(params: {
+  workspaceDir: string;
+  config?: OpenClawConfig;
+  sessionKey?: string;
+  agentId?: string;
+}): string | null {
+  const repoRoot = params.config?.agents?.defaults?.repoRoot?.trim();
+  const candidate =
+    getTrackedExecCwdWithin(resolveExecScopeKey(params), [params.workspaceDir, repoRoot]) ??
+    repoRoot;
+  if (!candidate) {
+    return null;
+  }
+  const resolved = path.resolve(candidate);
+  const root = findGitRoot(resolved) ?? resolved;
+  const workspaceDir = path.resolve(params.workspaceDir);
+  if (isInsideDir(root, workspaceDir) || isInsideDir(workspaceDir, root)) {
+    return null;
+  }
+  return root;
+}
+
+/** `<name>-<hash>` key for per-project state: readable, and unique per absolute path. */
+export function resolveProjectStateKey(projectRoot: string): string {
+  const resolved = path.resolve(projectRoot);
+  const slug =
+    path
+      .basename(resolved)
+      .toLowerCase()
+      .replace(/[^a-z0-9._-]+/g, "-")
+      .replace(/^-+|-+$/g, "")
+      .slice(0, 40) || "root";
+  const hash = createHash("sha256").update(resolved).digest("hex").slice(0, 10);
+  return `${slug}-${hash}`;
+}
+
+/** `<stateDir>/memory/projects/<name>-<hash>`: one MEMORY.md per project path. */
+export function resolveProjectMemoryDir(
+  projectRoot: string,
+  env: NodeJS.ProcessEnv = process.env,
+): string {
+  return path.join(resolveStateDir(env), "memory", "projects", resolveProjectStateKey(projectRoot));
+}
+
+export async function readMemoryFile(workspaceDir: string): Promise<MemoryFile> {
+  const primaryPath = path.join(workspaceDir, DEFAULT_MEMORY_FILENAME);
+  for (const candidate of [primaryPath, path.join(workspaceDir, DEFAULT_MEMORY_ALT_FILENAME)]) {
+    try {
+      return { path: candidate, text: await fs.readFile(candidate, "utf-8"), exists: true };
+    } catch (err) {
+      if ((err as NodeJS.ErrnoException).code !== "ENOENT") {
+        throw err;
+      }
+    }
+  }
+  return { path: primaryPath, text: "", exists: false };
+}
+
+function normalizeFact(raw: string): string {
+  return raw.replace(/\s+/g, " ").trim();
+}
+
+function factKey(raw: string): string {
+  return normalizeFact(raw.replace(BULLET_RE, "")).toLowerCase();
+}
+
+function hasFact(line: string, key: string): boolean {
+  return BULLET_RE.test(line) && factKey(line) === key;
+}
+
+export type MemoryWriteResult = {
+  path: string;
+  status: "added" | "exists" | "removed";
+  chars: number;
+  maxChars: number;
+};
+
+/** Appends a fact as a markdown bullet, skipping exact duplicates. */
+export async function appendMemoryFact(params: {
+  workspaceDir: string;
+  fact: string;
+  maxChars: number;
+}): Promise<MemoryWriteResult> {
+  const fact = normalizeFact(params.fact);
+  if (!fact) {
+    throw new Error("fact required");
+  }
+  if (fact.length > MAX_MEMORY_FACT_CHARS) {
+    throw new Error(`fact too long (${fact.length} chars; max ${MAX_MEMORY_FACT_CHARS})`);
+  }
+  const file = await readMemoryFile(params.workspaceDir);
+  const key = factKey(fact);
+  if (file.text.split("\n").some((line) => hasFact(line, key))) {
+    return {
+      path: file.path,
+      status: "exists",
+      chars: file.text.length,
+      maxChars: params.maxChars,
+    };
+  }
+  const prefix = file.text && !file.text.endsWith("\n") ? "\n" : "";
+  const next = `${file.text}${prefix}- ${fact}\n`;
+  if (next.length > params.maxChars) {
+    throw new Error(
+      `memory is full (${file.text.length}/${params.maxChars} chars); remove stale facts first`,
+    );
+  }
+  await fs.mkdir(params.workspaceDir, { recursive: true });
+  await fs.writeFile(file.path, next, "utf-8");
+  return { path: file.path, status: "added", chars: next.length, maxChars: params.maxChars };
+}
+
+/** Removes bullet lines matching the fact (whitespace/case-insensitive). */
+export async function removeMemoryFact(params: {
+  workspaceDir: string;
+  fact: string;
+  maxChars: number;
+}): Promise<MemoryWriteResult> {
+  const key = factKey(params.fact);
+  if (!key) {
+    throw new Error("fact required");
+  }
+  const file = await readMemoryFile(params.workspaceDir);
+  const lines = file.text.split("\n");
+  const kept = lines.filter((line) => !hasFact(line, key));
+  if (kept.length === lines.length) {
+    throw new Error("fact not found in memory");
+  }
+  const next = kept.join("\n");
+  await fs.writeFile(file.path, next, "utf-8");
+  return { path: file.path, status: "removed", chars: next.length, maxChars: params.maxChars };
+}
+
+/** Empties MEMORY.md (keeping the file so editors and `/memory edit` still find it). */
+export async function clearMemoryFile(workspaceDir: string): Promise<{
+  path: string;
+  clearedChars: number;
+}> {
+  const file = await readMemoryFile(workspaceDir);
+  if (file.exists && file.text) {
+    await fs.writeFile(file.path, "", "utf-8");
+  }
+  return { path: file.path, clearedChars: file.text.length };
+}
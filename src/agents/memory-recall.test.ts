@@ -0,0 +1,103 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import type { MemorySearchResult } from "../memory/types.js";
+
+const search = vi.fn();
+
+vi.mock("../memory/index.js", () => ({
+  getMemorySearchManager: async () => ({ manager: { search } }),
+}));
+
+import { formatRecalledMemories, recallMemoryForPrompt } from "./memory-recall.js";
+
+function hit(overrides: Partial<MemorySearchResult>): MemorySearchResult {
+  return {
+    path: "MEMORY.md",
+    startLine: 1,
+    endLine: 1,
+    score: 0.8,
+    snippet: "snippet",
+    source: "memory",
+    ...overrides,
+  };
+}
+
+const recallCfg: OpenClawConfig = {
+  agents: { defaults: { memorySearch: { recall: { enabled: true, maxResults: 2 } } } },
+};
+
+describe("memory recall", () => {
+  beforeEach(() => {
+    search.mockReset();
+  });
+
+  it("formats hits with locations and stops at the char budget", () => {
+    const text = formatRecalledMemories(
+      [
+        hit({ snippet: "Deploys go through  staging\nfirst.", startLine: 3, endLine: 4 }),
+        hit({ path: "memory/2026-01-02.md", snippet: "x".repeat(500) }),
+      ],
+      200,
+    );
+
+    expect(text).toBe(
+      [
+        "<relevant-memories>",
+        "Retrieved automatically from memory for this message; entries may be outdated.",
+        "- [MEMORY.md#L3-L4] Deploys go through staging first.",
+        "</relevant-memories>",
+      ].join("\n"),
+    );
+    expect(formatRecalledMemories([], 200)).toBeUndefined();
+  });
+
+  it("is off by default and skips short prompts and commands", async () => {
+    expect(
+      await recallMemoryForPrompt({ cfg: {}, agentId: "main", prompt: "where do deploys go?" }),
+    ).toBeUndefined();
+    expect(
+      await recallMemoryForPrompt({ cfg: recallCfg, agentId: "main", prompt: "thanks" }),
+    ).toBeUndefined();
+    expect(
+      await recallMemoryForPrompt({ cfg: recallCfg, agentId: "main", prompt: "/status verbose" }),
+    ).toBeUndefined();
+    expect(search).not.toHaveBeenCalled();
+  });
+
+  it("injects top hits but not the current session's own transcript", async () => {
+    search.mockResolvedValue([
+      hit({ path: "sessions/sess-1.jsonl", source: "sessions", snippet: "own turn" }),
+      hit({ path: "sessions/sess-0.jsonl", source: "sessions", snippet: "Summary: old work" }),
+      hit({ snippet: "Prefers pnpm" }),
+      hit({ snippet: "third" }),
+    ]);
+
+    const text = await recallMemoryForPrompt({
+      cfg: recallCfg,
+      agentId: "main",
+      prompt: "which package manager should I use?",
+      sessionId: "sess-1",
+    });
+
+    expect(search).toHaveBeenCalledWith("which package manager should I use?", {
+      maxResults: 4,
+      minScore: 0.35,
+      sessionKey: undefined,
+    });
+    expect(text).toContain("- [sessions/sess-0.jsonl#L1] Summary: old work");
+    expect(text).toContain("- [MEMORY.md#L1] Prefers pnpm");
+    expect(text).not.toContain("own turn");
+    expect(text).not.toContain("third");
+  });
+
+  it("gives up when the search is slower than the timeout", async () => {
+    search.mockImplementation(() => new Promise(() => {}));
+    const cfg: OpenClawConfig = {
+      agents: { defaults: { memorySearch: { recall: { enabled: true, timeoutMs: 100 } } } },
+    };
+
+    expect(
+      await recallMemoryForPrompt({ cfg, agentId: "main", prompt: "where do deploys go?" }),
+    ).toBeUndefined();
+  });
+});
@@ -0,0 +1,119 @@
+import path from "node:path";
+import type { OpenClawConfig } from "../config/config.js";
+import type { MemorySearchResult } from "../memory/types.js";
+import { getMemorySearchManager } from "../memory/index.js";
+import { resolveMemorySearchConfig } from "./memory-search.js";
+
+/** Prompts shorter than this ("ok", "thanks") rarely match anything worth injecting. */
+const MIN_RECALL_QUERY_CHARS = 12;
+const MAX_RECALL_QUERY_CHARS = 2000;
+
+function collapseWhitespace(text: string): string {
+  return text.replace(/\s+/g, " ").trim();
+}
+
+function formatLocation(result: MemorySearchResult): string {
+  if (result.citation) {
+    return result.citation;
+  }
+  return result.startLine === result.endLine
+    ? `${result.path}#L${result.startLine}`
+    : `${result.path}#L${result.startLine}-L${result.endLine}`;
+}
+
+/**
+ * Renders recalled snippets as a context block for the user prompt, best hit first, stopping
+ * once `maxChars` is reached (the first snippet is truncated rather than dropped).
+ */
+export function formatRecalledMemories(
+  results: MemorySearchResult[],
+  maxChars: number,
+): string | undefined {
+  const lines: string[] = [];
+  let used = 0;
+  for (const result of results) {
+    const snippet = collapseWhitespace(result.snippet);
+    if (!snippet) {
+      continue;
+    }
+    const line = `- [${formatLocation(result)}] ${snippet}`;
+    if (used + line.length > maxChars) {
+      if (lines.length === 0) {
+        lines.push(`${line.slice(0, Math.max(0, maxChars - 1))}…`);
+      }
+      break;
+    }
+    lines.push(line);
+    used += line.length;
+  }
+  if (lines.length === 0) {
+    return undefined;
+  }
+  return [
+    "<relevant-memories>",
+    "Retrieved automatically from memory for this message; entries may be outdated.",
+    ...lines,
+    "</relevant-memories>",
+  ].join("\n");
+}
+
+/**
+ * Per-prompt retrieval (`memorySearch.recall`): searches the agent's memory index with the
+ * incoming prompt and returns the top hits as a context block, or undefined when recall is
+ * off, nothing scores high enough, or the search does not finish within `recall.timeoutMs`.
+ * Hits from the current session's own transcript are skipped; that history is already in context.
+ */
+export async function recallMemoryForPrompt(params: {
+  cfg?: OpenClawConfig;
+  agentId: string;
+  prompt: string;
+  sessionKey?: string;
+  sessionId?: string;
+}): Promise<string | undefined> {
+  if (!params.cfg) {
+    return undefined;
+  }
+  const resolved = resolveMemorySearchConfig(params.cfg, params.agentId);
+  if (!resolved?.recall.enabled) {
+    return undefined;
+  }
+  const query = params.prompt.trim().slice(0, MAX_RECALL_QUERY_CHARS);
+  if (query.length < MIN_RECALL_QUERY_CHARS || query.startsWith("/")) {
+    return undefined;
+  }
+  const { recall } = resolved;
+  let timer: ReturnType<typeof setTimeout> | undefined;
+  const timeout = new Promise<null>((resolve) => {
+    timer = setTimeout(() => resolve(null), recall.timeoutMs);
+  });
+  const search = (async () => {
+    const { manager } = await getMemorySearchManager({ cfg: params.cfg!, agentId: params.agentId });
+    if (!manager) {
+      return null;
+    }
+    // Over-fetch so that dropping current-session hits still leaves maxResults candidates.
+    return await manager.search(query, {
+      maxResults: recall.maxResults * 2,
+      minScore: recall.minScore,
+      sessionKey: params.sessionKey,
+    });
+  })();
+  try {
+    const results = await Promise.race([search, timeout]);
+    if (!results) {
+      return undefined;
+    }
+    const ownTranscript = params.sessionId ? `${params.sessionId}.jsonl` : undefined;
+    const relevant = results
+      .filter(
+        (result) =>
+          !(result.source === "sessions" && path.basename(result.path) === ownTranscript),
+      )
+      .slice(0, recall.maxResults);
+    return formatRecalledMemories(relevant, recall.maxChars);
+  } finally {
+    clearTimeout(timer);
+    // A timed-out search keeps running; make sure its failure is not reported as unhandled.
+    search.catch(() => {});
+  }
+}
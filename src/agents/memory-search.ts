@@ -64,6 +64,13 @@ export type ResolvedMemorySearchConfig = {
       candidateMultiplier: number;
     };
   };
+  recall: {
+    enabled: boolean;
+    maxResults: number;
+    minScore: number;
+    maxChars: number;
+    timeoutMs: number;
+  };
   cache: {
     enabled: boolean;
     maxEntries?: number;
@@ -85,6 +92,9 @@ const DEFAULT_HYBRID_VECTOR_WEIGHT = 0.7;
 const DEFAULT_HYBRID_TEXT_WEIGHT = 0.3;
 const DEFAULT_HYBRID_CANDIDATE_MULTIPLIER = 4;
 const DEFAULT_CACHE_ENABLED = true;
+const DEFAULT_RECALL_MAX_RESULTS = 3;
+const DEFAULT_RECALL_MAX_CHARS = 2000;
+const DEFAULT_RECALL_TIMEOUT_MS = 1500;
 const DEFAULT_SOURCES: Array<"memory" | "sessions"> = ["memory"];
 
 function normalizeSources(
@@ -237,6 +247,15 @@ function mergeConfig(
       defaults?.query?.hybrid?.candidateMultiplier ??
       DEFAULT_HYBRID_CANDIDATE_MULTIPLIER,
   };
+  const recall = {
+    enabled: overrides?.recall?.enabled ?? defaults?.recall?.enabled ?? false,
+    maxResults:
+      overrides?.recall?.maxResults ?? defaults?.recall?.maxResults ?? DEFAULT_RECALL_MAX_RESULTS,
+    minScore: overrides?.recall?.minScore ?? defaults?.recall?.minScore ?? query.minScore,
+    maxChars: overrides?.recall?.maxChars ?? defaults?.recall?.maxChars ?? DEFAULT_RECALL_MAX_CHARS,
+    timeoutMs:
+      overrides?.recall?.timeoutMs ?? defaults?.recall?.timeoutMs ?? DEFAULT_RECALL_TIMEOUT_MS,
+  };
   const cache = {
     enabled: overrides?.cache?.enabled ?? defaults?.cache?.enabled ?? DEFAULT_CACHE_ENABLED,
     maxEntries: overrides?.cache?.maxEntries ?? defaults?.cache?.maxEntries,
@@ -283,6 +302,13 @@ function mergeConfig(
         candidateMultiplier,
       },
     },
+    recall: {
+      enabled: Boolean(recall.enabled),
+      maxResults: clampInt(recall.maxResults, 1, 20),
+      minScore: clampNumber(recall.minScore, 0, 1),
+      maxChars: clampInt(recall.maxChars, 200, 20_000),
+      timeoutMs: clampInt(recall.timeoutMs, 100, 30_000),
+    },
     cache: {
       enabled: Boolean(cache.enabled),
       maxEntries:
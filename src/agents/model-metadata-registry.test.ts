@@ -0,0 +1,103 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, describe, expect, it } from "vitest";
+import {
+  convertLiteLlmRegistry,
+  diffModelMetadata,
+  lookupModelMetadata,
+  writeModelMetadataRegistry,
+} from "./model-metadata-registry.js";
+
+const meta = { source: "test", fetchedAt: "2026-10-16T00:00:00.000Z" };
+
+const litellm = {
+  sample_spec: { max_tokens: "set to max_output_tokens" },
+  "claude-sonnet-4-5": {
+    litellm_provider: "anthropic",
+    mode: "chat",
+    max_input_tokens: 200_000,
+    max_output_tokens: 64_000,
+    input_cost_per_token: 3e-6,
+    output_cost_per_token: 1.5e-5,
+    cache_read_input_token_cost: 3e-7,
+    cache_creation_input_token_cost: 3.75e-6,
+  },
+  "gemini/gemini-2.5-pro": {
+    litellm_provider: "gemini",
+    mode: "chat",
+    max_input_tokens: 1_048_576,
+    input_cost_per_token: 1.25e-6,
+    output_cost_per_token: 1e-5,
+  },
+  "text-embedding-3-small": {
+    litellm_provider: "openai",
+    mode: "embedding",
+    input_cost_per_token: 2e-8,
+  },
+};
+
+describe("convertLiteLlmRegistry", () => {
+  it("converts per-token prices to per-million and maps provider ids", () => {
+    const registry = convertLiteLlmRegistry(litellm, meta);
+    expect(Object.keys(registry.models)).toEqual([
+      "anthropic/claude-sonnet-4-5",
+      "google/gemini-2.5-pro",
+    ]);
+    expect(registry.models["anthropic/claude-sonnet-4-5"]).toEqual({
+      provider: "anthropic",
+      model: "claude-sonnet-4-5",
+      contextWindow: 200_000,
+      maxTokens: 64_000,
+      cost: { input: 3, output: 15, cacheRead: 0.3, cacheWrite: 3.75 },
+    });
+    // No cache prices listed: cached tokens are priced like input.
+    expect(registry.models["google/gemini-2.5-pro"]?.cost).toEqual({
+      input: 1.25,
+      output: 10,
+      cacheRead: 1.25,
+      cacheWrite: 1.25,
+    });
+  });
+
+  it("diffs against the previous registry", () => {
+    const next = convertLiteLlmRegistry(litellm, meta);
+    const previous = convertLiteLlmRegistry(
+      {
+        "claude-sonnet-4-5": { ...litellm["claude-sonnet-4-5"], input_cost_per_token: 4e-6 },
+        "gpt-4": { litellm_provider: "openai", mode: "chat", max_input_tokens: 8192 },
+      },
+      meta,
+    );
+    expect(diffModelMetadata(previous, next)).toEqual({
+      added: ["google/gemini-2.5-pro"],
+      changed: ["anthropic/claude-sonnet-4-5"],
+      removed: ["openai/gpt-4"],
+    });
+  });
+});
+
+describe("lookupModelMetadata", () => {
+  let stateDir: string | undefined;
+  afterEach(async () => {
+    if (stateDir) {
+      await fs.rm(stateDir, { recursive: true, force: true });
+    }
+  });
+
+  it("reads the cached registry by provider/model or bare model id", async () => {
+    stateDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-model-metadata-"));
+    const env = { OPENCLAW_STATE_DIR: stateDir };
+    expect(lookupModelMetadata({ provider: "anthropic", model: "claude-sonnet-4-5", env })).toBe(
+      undefined,
+    );
+    await writeModelMetadataRegistry(convertLiteLlmRegistry(litellm, meta), env);
+    expect(
+      lookupModelMetadata({ provider: "anthropic", model: "claude-sonnet-4-5", env })?.cost,
+    ).toMatchObject({ input: 3, output: 15 });
+    expect(lookupModelMetadata({ model: "gemini-2.5-pro", env })?.contextWindow).toBe(1_048_576);
+    expect(lookupModelMetadata({ provider: "openai", model: "gemini-2.5-pro", env })).toBe(
+      undefined,
+    );
+  });
+});
@@ -0,0 +1,197 @@
+import fs from "node:fs";
+import path from "node:path";
+import type { ModelCostConfig } from "../utils/usage-format.js";
+import { resolveStateDir } from "../config/paths.js";
+
+/** LiteLLM's community-maintained pricing and context-window table. */
+export const LITELLM_MODEL_REGISTRY_URL =
+  "https://raw.githubusercontent.com/BerriAI/litellm/main/model_prices_and_context_window.json";
+
+// LiteLLM provider names that differ from OpenClaw provider ids.
+const PROVIDER_ALIASES: Record<string, string> = {
+  gemini: "google",
+  bedrock: "amazon-bedrock",
+  bedrock_converse: "amazon-bedrock",
+  together_ai: "together",
+  fireworks_ai: "fireworks",
+};
+const CHAT_MODES = new Set(["chat", "responses"]);
+
+export type ModelMetadataEntry = {
+  provider: string;
+  model: string;
+  contextWindow?: number;
+  maxTokens?: number;
+  /** USD per million tokens, like `models.providers.*.models[].cost`. */
+  cost?: ModelCostConfig;
+};
+
+export type ModelMetadataRegistry = {
+  source: string;
+  fetchedAt: string;
+  /** Keyed by `provider/model`. */
+  models: Record<string, ModelMetadataEntry>;
+};
+
+export function resolveModelMetadataRegistryPath(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "cache", "model-metadata.json");
+}
+
+function positiveNumber(value: unknown): number | undefined {
+  return typeof value === "number" && Number.isFinite(value) && value > 0 ? value : undefined;
+}
+
+function perMillion(value: unknown): number | undefined {
+  return typeof value === "number" && Number.isFinite(value) && value >= 0
+    ? Number((value * 1_000_000).toFixed(6))
+    : undefined;
+}
+
+/**
+ * Converts LiteLLM's `model_prices_and_context_window.json` (per-token USD prices) into registry
+ * entries. Only chat models with a known provider are kept.
+ */
+export function convertLiteLlmRegistry(
+  raw: unknown,
+  meta: { source: string; fetchedAt: string },
+): ModelMetadataRegistry {
+  if (!raw || typeof raw !== "object" || Array.isArray(raw)) {
+    throw new Error("Model registry is not a JSON object");
+  }
+  const models: Record<string, ModelMetadataEntry> = {};
+  for (const [key, value] of Object.entries(raw as Record<string, unknown>)) {
+    if (key === "sample_spec" || !value || typeof value !== "object") {
+      continue;
+    }
+    const spec = value as Record<string, unknown>;
+    const litellmProvider =
+      typeof spec.litellm_provider === "string" ? spec.litellm_provider.trim() : "";
+    if (!litellmProvider || (typeof spec.mode === "string" && !CHAT_MODES.has(spec.mode))) {
+      continue;
+    }
+    const provider = PROVIDER_ALIASES[litellmProvider] ?? litellmProvider;
+    const model = key.startsWith(`${litellmProvider}/`)
+      ? key.slice(litellmProvider.length + 1)
+      : key;
+    const input = perMillion(spec.input_cost_per_token);
+    const output = perMillion(spec.output_cost_per_token);
+    const entry: ModelMetadataEntry = {
+      provider,
+      model,
+      contextWindow: positiveNumber(spec.max_input_tokens) ?? positiveNumber(spec.max_tokens),
+      maxTokens: positiveNumber(spec.max_output_tokens),
+      cost:
+        input !== undefined && output !== undefined
+          ? {
+              input,
+              output,
+              cacheRead: perMillion(spec.cache_read_input_token_cost) ?? input,
+              cacheWrite: perMillion(spec.cache_creation_input_token_cost) ?? input,
+            }
+          : undefined,
+    };
+    if (entry.contextWindow === undefined && entry.cost === undefined) {
+      continue;
+    }
+    models[`${provider}/${model}`] = entry;
+  }
+  return { ...meta, models };
+}
+
+export type ModelMetadataDiff = {
+  added: string[];
+  removed: string[];
+  changed: string[];
+};
+
+export function diffModelMetadata(
+  previous: ModelMetadataRegistry | undefined,
+  next: ModelMetadataRegistry,
+): ModelMetadataDiff {
+  const before = previous?.models ?? {};
+  const added: string[] = [];
+  const changed: string[] = [];
+  for (const [key, entry] of Object.entries(next.models)) {
+    const prior = before[key];
+    if (!prior) {
+      added.push(key);
+    } else if (JSON.stringify(prior) !== JSON.stringify(entry)) {
+      changed.push(key);
+    }
+  }
+  const removed = Object.keys(before).filter((key) => !(key in next.models));
+  return { added, removed, changed };
+}
+
+let cached: { file: string; mtimeMs: number; registry?: ModelMetadataRegistry } | undefined;
+let byModelId: Map<string, ModelMetadataEntry> | undefined;
+
+/** Reads the cached registry written by `openclaw models update` (undefined when missing). */
+export function loadModelMetadataRegistry(
+  env: NodeJS.ProcessEnv = process.env,
+): ModelMetadataRegistry | undefined {
+  const file = resolveModelMetadataRegistryPath(env);
+  let mtimeMs: number;
+  try {
+    mtimeMs = fs.statSync(file).mtimeMs;
+  } catch {
+    return undefined;
+  }
+  if (cached?.file === file && cached.mtimeMs === mtimeMs) {
+    return cached.registry;
+  }
+  let registry: ModelMetadataRegistry | undefined;
+  try {
+    const parsed = JSON.parse(fs.readFileSync(file, "utf8")) as ModelMetadataRegistry;
+    registry = parsed?.models && typeof parsed.models === "object" ? parsed : undefined;
+  } catch {
+    registry = undefined;
+  }
+  cached = { file, mtimeMs, registry };
+  byModelId = undefined;
+  return registry;
+}
+
+export async function writeModelMetadataRegistry(
+  registry: ModelMetadataRegistry,
+  env: NodeJS.ProcessEnv = process.env,
+): Promise<string> {
+  const file = resolveModelMetadataRegistryPath(env);
+  const tmp = `${file}.${process.pid}.tmp`;
+  await fs.promises.mkdir(path.dirname(file), { recursive: true });
+  await fs.promises.writeFile(tmp, `${JSON.stringify(registry)}\n`, "utf8");
+  await fs.promises.rename(tmp, file);
+  return file;
+}
+
+/**
+ * Registry metadata for a model. With a provider the lookup is exact; without one the first
+ * entry with that model id wins (context-window lookups only know the id).
+ */
+export function lookupModelMetadata(params: {
+  provider?: string;
+  model?: string;
+  env?: NodeJS.ProcessEnv;
+}): ModelMetadataEntry | undefined {
+  const model = params.model?.trim();
+  if (!model) {
+    return undefined;
+  }
+  const registry = loadModelMetadataRegistry(params.env);
+  if (!registry) {
+    return undefined;
+  }
+  const provider = params.provider?.trim();
+  if (provider) {
+    return registry.models[`${provider}/${model}`];
+  }
+  if (!byModelId) {
+    byModelId = new Map();
+    for (const entry of Object.values(registry.models)) {
+      if (!byModelId.has(entry.model)) {
+        byModelId.set(entry.model, entry);
+      }
+    }
+  }
+  return byModelId.get(model);
+}
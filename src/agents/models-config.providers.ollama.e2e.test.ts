@@ -2,7 +2,11 @@ import { mkdtempSync } from "node:fs";
 import { tmpdir } from "node:os";
 import { join } from "node:path";
 import { describe, expect, it } from "vitest";
-import { resolveImplicitProviders, resolveOllamaApiBase } from "./models-config.providers.js";
+import {
+  resolveImplicitProviders,
+  resolveOllamaApiBase,
+  resolveOllamaModelInput,
+} from "./models-config.providers.js";
 
 describe("resolveOllamaApiBase", () => {
   it("returns default localhost base when no configured URL is provided", () => {
@@ -24,6 +28,36 @@ describe("resolveOllamaApiBase", () => {
   });
 });
 
+describe("resolveOllamaModelInput", () => {
+  const tag = (name: string, families?: string[]) => ({
+    name,
+    modified_at: "",
+    size: 0,
+    digest: "",
+    details: { family: families?.[0], families },
+  });
+
+  it("marks vision models as image-capable", () => {
+    expect(resolveOllamaModelInput(tag("llava:13b", ["llama", "clip"]))).toEqual([
+      "text",
+      "image",
+    ]);
+    expect(resolveOllamaModelInput(tag("llama3.2-vision:11b", ["mllama"]))).toEqual([
+      "text",
+      "image",
+    ]);
+    expect(resolveOllamaModelInput(tag("my-finetune:latest", ["llama", "clip"]))).toEqual([
+      "text",
+      "image",
+    ]);
+  });
+
+  it("keeps text-only models text-only", () => {
+    expect(resolveOllamaModelInput(tag("llama3.3:latest", ["llama"]))).toEqual(["text"]);
+    expect(resolveOllamaModelInput(tag("qwen2.5-coder:7b"))).toEqual(["text"]);
+  });
+});
+
 describe("Ollama provider", () => {
   it("should not include ollama when no API key is configured", async () => {
     const agentDir = mkdtempSync(join(tmpdir(), "openclaw-test-"));
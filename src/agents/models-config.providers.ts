@@ -123,17 +123,35 @@ const NVIDIA_DEFAULT_COST = {
   cacheWrite: 0,
 };
 
-interface OllamaModel {
+export interface OllamaModel {
   name: string;
   modified_at: string;
   size: number;
   digest: string;
   details?: {
     family?: string;
+    families?: string[] | null;
     parameter_size?: string;
   };
 }
 
+// Vision models ship a projector ("clip") or are multimodal architectures; the name patterns cover
+// tags whose metadata does not say so.
+const OLLAMA_VISION_FAMILIES = new Set(["clip", "mllama", "gemma3", "qwen25vl", "llama4"]);
+const OLLAMA_VISION_NAME_RE =
+  /(llava|vision|moondream|minicpm-v|qwen2(\.5)?-?vl|gemma3|llama4|mistral-small3\.[12])/i;
+
+/** Input modalities for a discovered Ollama model, so images reach vision models. */
+export function resolveOllamaModelInput(model: OllamaModel): Array<"text" | "image"> {
+  const families = [model.details?.family, ...(model.details?.families ?? [])]
+    .filter((family): family is string => typeof family === "string")
+    .map((family) => family.toLowerCase());
+  const vision =
+    families.some((family) => OLLAMA_VISION_FAMILIES.has(family)) ||
+    OLLAMA_VISION_NAME_RE.test(model.name);
+  return vision ? ["text", "image"] : ["text"];
+}
+
 interface OllamaTagsResponse {
   models: OllamaModel[];
 }
@@ -188,7 +206,7 @@ async function discoverOllamaModels(baseUrl?: string): Promise<ModelDefinitionCo
         id: modelId,
         name: modelId,
         reasoning: isReasoning,
-        input: ["text"],
+        input: resolveOllamaModelInput(model),
         cost: OLLAMA_DEFAULT_COST,
         contextWindow: OLLAMA_DEFAULT_CONTEXT_WINDOW,
         maxTokens: OLLAMA_DEFAULT_MAX_TOKENS,
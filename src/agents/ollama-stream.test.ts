@@ -27,6 +27,27 @@ describe("convertToOllamaMessages", () => {
     expect(result).toEqual([{ role: "user", content: "describe this", images: ["base64data"] }]);
   });
 
+  it("strips data URL prefixes and omits user images for text-only models", () => {
+    const messages = [
+      {
+        role: "user",
+        content: [
+          { type: "text", text: "what is this?" },
+          { type: "image", data: "data:image/png;base64,iVBORw0" },
+        ],
+      },
+    ];
+    expect(convertToOllamaMessages(messages)).toEqual([
+      { role: "user", content: "what is this?", images: ["iVBORw0"] },
+    ]);
+    expect(convertToOllamaMessages(messages, undefined, { supportsImages: false })).toEqual([
+      {
+        role: "user",
+        content: "what is this?\n[1 image(s) omitted: model does not accept image input]",
+      },
+    ]);
+  });
+
   it("prepends system message when provided", () => {
     const messages = [{ role: "user", content: "hello" }];
     const result = convertToOllamaMessages(messages, "You are helpful.");
@@ -77,6 +98,29 @@ describe("convertToOllamaMessages", () => {
     expect(result[0]).not.toHaveProperty("tool_name");
   });
 
+  it("forwards image blocks from tool results", () => {
+    const messages = [
+      {
+        role: "toolResult",
+        toolName: "browser",
+        content: [
+          { type: "text", text: "screenshot" },
+          { type: "image", data: "pngdata", mimeType: "image/png" },
+        ],
+      },
+    ];
+    expect(convertToOllamaMessages(messages)).toEqual([
+      { role: "tool", content: "screenshot", images: ["pngdata"], tool_name: "browser" },
+    ]);
+    expect(convertToOllamaMessages(messages, undefined, { supportsImages: false })).toEqual([
+      {
+        role: "tool",
+        content: "screenshot\n[1 image(s) omitted: model does not accept image input]",
+        tool_name: "browser",
+      },
+    ]);
+  });
+
   it("handles empty messages array", () => {
     const result = convertToOllamaMessages([]);
     expect(result).toEqual([]);
@@ -101,6 +145,19 @@ describe("buildAssistantMessage", () => {
     expect(result.stopReason).toBe("stop");
     expect(result.usage.input).toBe(10);
     expect(result.usage.output).toBe(5);
+  });
+
+  it("puts message.thinking into a thinking block before the answer", () => {
+    const response = {
+      model: "deepseek-r1:32b",
+      created_at: "2026-01-01T00:00:00Z",
+      message: { role: "assistant" as const, content: "42", thinking: "6 times 7" },
+      done: true,
+    };
+    expect(buildAssistantMessage(response, modelInfo).content).toEqual([
+      { type: "thinking", thinking: "6 times 7" },
+      { type: "text", text: "42" },
+    ]);
     expect(result.usage.totalTokens).toBe(15);
   });
 
@@ -287,4 +344,42 @@ describe("createOllamaStreamFn", () => {
       globalThis.fetch = originalFetch;
     }
   });
+
+  it("requests thinking for reasoning models and collects streamed thinking", async () => {
+    const originalFetch = globalThis.fetch;
+    const fetchMock = vi.fn(async () => {
+      const payload = [
+        '{"model":"m","created_at":"t","message":{"role":"assistant","content":"","thinking":"let me "},"done":false}',
+        '{"model":"m","created_at":"t","message":{"role":"assistant","content":"","thinking":"think"},"done":false}',
+        '{"model":"m","created_at":"t","message":{"role":"assistant","content":"done"},"done":false}',
+        '{"model":"m","created_at":"t","message":{"role":"assistant","content":""},"done":true}',
+      ].join("\n");
+      return new Response(`${payload}\n`, { status: 200 });
+    });
+    globalThis.fetch = fetchMock as unknown as typeof fetch;
+
+    try {
+      const streamFn = createOllamaStreamFn("http://127.0.0.1:11434");
+      type StreamArgs = Parameters<typeof streamFn>;
+      const stream = streamFn(
+        {
+          id: "deepseek-r1:32b",
+          api: "ollama",
+          provider: "ollama",
+          reasoning: true,
+        } as unknown as StreamArgs[0],
+        { messages: [{ role: "user", content: "hi" }] } as unknown as StreamArgs[1],
+        { reasoning: "low" } as unknown as StreamArgs[2],
+      );
+      const message = await stream.result();
+      expect(message.content).toEqual([
+        { type: "thinking", thinking: "let me think" },
+        { type: "text", text: "done" },
+      ]);
+      const [, requestInit] = fetchMock.mock.calls[0] as unknown as [string, RequestInit];
+      expect(JSON.parse(String(requestInit.body))).toMatchObject({ think: true });
+    } finally {
+      globalThis.fetch = originalFetch;
+    }
+  });
 });
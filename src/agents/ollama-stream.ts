@@ -3,6 +3,7 @@ import type {
   AssistantMessage,
   StopReason,
   TextContent,
+  ThinkingContent,
   ToolCall,
   Tool,
   Usage,
@@ -19,6 +20,8 @@ interface OllamaChatRequest {
   messages: OllamaChatMessage[];
   stream: boolean;
   tools?: OllamaTool[];
+  /** Reasoning models return their thinking in `message.thinking` instead of `<think>` tags. */
+  think?: boolean;
   options?: Record<string, unknown>;
 }
 
@@ -54,6 +57,7 @@ interface OllamaChatResponse {
   message: {
     role: "assistant";
     content: string;
+    thinking?: string;
     tool_calls?: OllamaToolCall[];
   };
   done: boolean;
@@ -91,9 +95,15 @@ function extractOllamaImages(content: unknown): string[] {
   if (!Array.isArray(content)) {
     return [];
   }
+  // Ollama wants bare base64; strip a data URL prefix if one slipped through.
   return (content as InputContentPart[])
     .filter((part): part is { type: "image"; data: string } => part.type === "image")
-    .map((part) => part.data);
+    .map((part) => part.data.replace(/^data:[^;,]+;base64,/, ""))
+    .filter(Boolean);
+}
+
+function omittedImagesNote(count: number): string {
+  return `[${count} image(s) omitted: model does not accept image input]`;
 }
 
 function extractToolCalls(content: unknown): OllamaToolCall[] {
@@ -115,6 +125,7 @@ function extractToolCalls(content: unknown): OllamaToolCall[] {
 export function convertToOllamaMessages(
   messages: Array<{ role: string; content: unknown }>,
   system?: string,
+  options?: { supportsImages?: boolean },
 ): OllamaChatMessage[] {
   const result: OllamaChatMessage[] = [];
 
@@ -126,12 +137,17 @@ export function convertToOllamaMessages(
     const { role } = msg;
 
     if (role === "user") {
-      const text = extractTextContent(msg.content);
+      let text = extractTextContent(msg.content);
       const images = extractOllamaImages(msg.content);
+      const sendImages = images.length > 0 && options?.supportsImages !== false;
+      if (images.length > 0 && !sendImages) {
+        const omitted = omittedImagesNote(images.length);
+        text = text ? `${text}\n${omitted}` : omitted;
+      }
       result.push({
         role: "user",
         content: text,
-        ...(images.length > 0 ? { images } : {}),
+        ...(sendImages ? { images } : {}),
       });
     } else if (role === "assistant") {
       const text = extractTextContent(msg.content);
@@ -144,7 +160,15 @@ export function convertToOllamaMessages(
     } else if (role === "tool" || role === "toolResult") {
       // SDK uses "toolResult" (camelCase) for tool result messages.
       // Ollama API expects "tool" role with tool_name per the native spec.
-      const text = extractTextContent(msg.content);
+      // Image blocks (screenshots, browser snapshots, read of a PNG) ride along on the tool
+      // message; text-only models get a placeholder so the result is not silently empty.
+      let text = extractTextContent(msg.content);
+      const images = extractOllamaImages(msg.content);
+      const sendImages = images.length > 0 && options?.supportsImages !== false;
+      if (images.length > 0 && !sendImages) {
+        const omitted = omittedImagesNote(images.length);
+        text = text ? `${text}\n${omitted}` : omitted;
+      }
       const toolName =
         typeof (msg as { toolName?: unknown }).toolName === "string"
           ? (msg as { toolName?: string }).toolName
@@ -152,6 +176,7 @@ export function convertToOllamaMessages(
       result.push({
         role: "tool",
         content: text,
+        ...(sendImages ? { images } : {}),
         ...(toolName ? { tool_name: toolName } : {}),
       });
     }
@@ -189,8 +214,11 @@ export function buildAssistantMessage(
   response: OllamaChatResponse,
   modelInfo: { api: string; provider: string; id: string },
 ): AssistantMessage {
-  const content: (TextContent | ToolCall)[] = [];
+  const content: (ThinkingContent | TextContent | ToolCall)[] = [];
 
+  if (response.message.thinking?.trim()) {
+    content.push({ type: "thinking", thinking: response.message.thinking });
+  }
   if (response.message.content) {
     content.push({ type: "text", text: response.message.content });
   }
@@ -293,6 +321,7 @@ export function createOllamaStreamFn(baseUrl: string): StreamFn {
         const ollamaMessages = convertToOllamaMessages(
           context.messages ?? [],
           context.systemPrompt,
+          { supportsImages: !Array.isArray(model.input) || model.input.includes("image") },
         );
 
         const ollamaTools = extractOllamaTools(context.tools);
@@ -312,6 +341,8 @@ export function createOllamaStreamFn(baseUrl: string): StreamFn {
           messages: ollamaMessages,
           stream: true,
           ...(ollamaTools.length > 0 ? { tools: ollamaTools } : {}),
+          // Explicit either way: thinking models otherwise think by default, even with /think off.
+          ...(model.reasoning ? { think: Boolean(options?.reasoning) } : {}),
           options: ollamaOptions,
         };
 
@@ -341,6 +372,7 @@ export function createOllamaStreamFn(baseUrl: string): StreamFn {
 
         const reader = response.body.getReader();
         let accumulatedContent = "";
+        let accumulatedThinking = "";
         const accumulatedToolCalls: OllamaToolCall[] = [];
         let finalResponse: OllamaChatResponse | undefined;
 
@@ -348,6 +380,9 @@ export function createOllamaStreamFn(baseUrl: string): StreamFn {
           if (chunk.message?.content) {
             accumulatedContent += chunk.message.content;
           }
+          if (chunk.message?.thinking) {
+            accumulatedThinking += chunk.message.thinking;
+          }
 
           // Ollama sends tool_calls in intermediate (done:false) chunks,
           // NOT in the final done:true chunk. Collect from all chunks.
@@ -366,6 +401,7 @@ export function createOllamaStreamFn(baseUrl: string): StreamFn {
         }
 
         finalResponse.message.content = accumulatedContent;
+        finalResponse.message.thinking = accumulatedThinking || undefined;
         if (accumulatedToolCalls.length > 0) {
           finalResponse.message.tool_calls = accumulatedToolCalls;
         }
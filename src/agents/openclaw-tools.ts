@@ -8,17 +8,35 @@ import { createAgentsListTool } from "./tools/agents-list-tool.js";
 import { createBrowserTool } from "./tools/browser-tool.js";
 import { createCanvasTool } from "./tools/canvas-tool.js";
 import { createCronTool } from "./tools/cron-tool.js";
+import { createExternalTools, refreshExternalTools } from "./tools/external-tools.js";
 import { createGatewayTool } from "./tools/gateway-tool.js";
+import { createGhTool } from "./tools/gh-tool.js";
 import { createImageTool } from "./tools/image-tool.js";
 import { createMessageTool } from "./tools/message-tool.js";
 import { createNodesTool } from "./tools/nodes-tool.js";
+import { createOpenApiTools } from "./tools/openapi-tools.js";
+import { createScreenshotTool } from "./tools/screenshot-tool.js";
 import { createSessionStatusTool } from "./tools/session-status-tool.js";
 import { createSessionsHistoryTool } from "./tools/sessions-history-tool.js";
 import { createSessionsListTool } from "./tools/sessions-list-tool.js";
 import { createSessionsSendTool } from "./tools/sessions-send-tool.js";
 import { createSessionsSpawnTool } from "./tools/sessions-spawn-tool.js";
+import { createTodoWriteTool } from "./tools/todo-tool.js";
 import { createTtsTool } from "./tools/tts-tool.js";
-import { createWebFetchTool, createWebSearchTool } from "./tools/web-tools.js";
+import { createWasmTools, refreshWasmTools } from "./tools/wasm-tools.js";
+import {
+  createHttpRequestTool,
+  createWebFetchTool,
+  createWebSearchTool,
+} from "./tools/web-tools.js";
+
+/**
+ * Re-discovers external and wasm tools off the event loop. Await it before `createOpenClawTools`,
+ * which only serves the definitions found by the last refresh.
+ */
+export async function refreshDiscoveredTools(config?: OpenClawConfig): Promise<void> {
+  await Promise.all([refreshExternalTools(config), refreshWasmTools(config)]);
+}
 
 export function createOpenClawTools(options?: {
   sandboxBrowserBridgeUrl?: string;
@@ -59,6 +77,8 @@ export function createOpenClawTools(options?: {
   requireExplicitMessageTarget?: boolean;
   /** If true, omit the message tool from the tool list. */
   disableMessageTool?: boolean;
+  /** Tools the caller adds alongside these (e.g. coding tools); generated tools never shadow them. */
+  existingToolNames?: Set<string>;
 }): AnyAgentTool[] {
   const imageTool = options?.agentDir?.trim()
     ? createImageTool({
@@ -79,6 +99,11 @@ export function createOpenClawTools(options?: {
     config: options?.config,
     sandboxed: options?.sandboxed,
   });
+  const httpRequestTool = createHttpRequestTool({
+    config: options?.config,
+    sandboxed: options?.sandboxed,
+  });
+  const ghTool = createGhTool({ config: options?.config });
   const messageTool = options?.disableMessageTool
     ? null
     : createMessageTool({
@@ -99,6 +124,8 @@ export function createOpenClawTools(options?: {
       allowHostControl: options?.allowHostBrowserControl,
     }),
     createCanvasTool(),
+    // Captures the gateway host's own screen; never offered to sandboxed sessions.
+    ...(options?.sandboxed ? [] : [createScreenshotTool()]),
     createNodesTool({
       agentSessionKey: options?.agentSessionKey,
       config: options?.config,
@@ -148,8 +175,14 @@ export function createOpenClawTools(options?: {
       agentSessionKey: options?.agentSessionKey,
       config: options?.config,
     }),
+    createTodoWriteTool({
+      agentSessionKey: options?.agentSessionKey,
+      config: options?.config,
+    }),
     ...(webSearchTool ? [webSearchTool] : []),
     ...(webFetchTool ? [webFetchTool] : []),
+    ...(httpRequestTool ? [httpRequestTool] : []),
+    ...(ghTool ? [ghTool] : []),
     ...(imageTool ? [imageTool] : []),
   ];
 
@@ -171,5 +204,28 @@ export function createOpenClawTools(options?: {
     toolAllowlist: options?.pluginToolAllowlist,
   });
 
-  return [...tools, ...pluginTools];
+  // Generated tools are checked against everything already in the final list.
+  const taken = (...lists: AnyAgentTool[][]) =>
+    new Set([...(options?.existingToolNames ?? []), ...lists.flat().map((tool) => tool.name)]);
+
+  const openApiTools = createOpenApiTools({
+    config: options?.config,
+    existingToolNames: taken(tools, pluginTools),
+  });
+
+  const externalTools = createExternalTools({
+    config: options?.config,
+    workspaceDir: options?.workspaceDir,
+    sandboxed: options?.sandboxed,
+    existingToolNames: taken(tools, pluginTools, openApiTools),
+  });
+
+  const wasmTools = createWasmTools({
+    config: options?.config,
+    workspaceDir: options?.workspaceDir,
+    sandboxed: options?.sandboxed,
+    existingToolNames: taken(tools, pluginTools, openApiTools, externalTools),
+  });
+
+  return [...tools, ...pluginTools, ...openApiTools, ...externalTools, ...wasmTools];
 }
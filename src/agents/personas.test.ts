@@ -0,0 +1,69 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import {
+  buildPersonaSystemPrompt,
+  listPersonas,
+  loadPersona,
+  normalizePersonaName,
+  requirePersona,
+} from "./personas.js";
+
+describe("personas", () => {
+  let dir: string;
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-personas-"));
+    await fs.writeFile(
+      path.join(dir, "reviewer.md"),
+      [
+        "---",
+        "description: Strict code reviewer",
+        "model: anthropic/claude-opus-4-6",
+        "temperature: 0.2",
+        "---",
+        "You review diffs. Point out bugs first, style last.",
+        "",
+      ].join("\n"),
+    );
+    await fs.writeFile(path.join(dir, "plain.md"), "Answer in one sentence.\n");
+    await fs.writeFile(path.join(dir, "empty.md"), "---\nmodel: openai/gpt-5.2\n---\n");
+    await fs.writeFile(path.join(dir, "notes.txt"), "not a persona");
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  it("reads front-matter settings and the prompt body", async () => {
+    const persona = await loadPersona("Reviewer", dir);
+    expect(persona).toMatchObject({
+      name: "reviewer",
+      description: "Strict code reviewer",
+      model: "anthropic/claude-opus-4-6",
+      temperature: 0.2,
+      prompt: "You review diffs. Point out bugs first, style last.",
+    });
+    expect(buildPersonaSystemPrompt(persona!)).toBe(
+      "## Persona: reviewer\nYou review diffs. Point out bugs first, style last.",
+    );
+    expect(await loadPersona("plain", dir)).toMatchObject({
+      prompt: "Answer in one sentence.",
+      model: undefined,
+      temperature: undefined,
+    });
+  });
+
+  it("lists usable presets and rejects unknown or unsafe names", async () => {
+    expect((await listPersonas(dir)).map((persona) => persona.name)).toEqual([
+      "plain",
+      "reviewer",
+    ]);
+    expect(normalizePersonaName("../secrets")).toBeNull();
+    expect(await loadPersona("empty", dir)).toBeNull();
+    await expect(requirePersona("writer", dir)).rejects.toThrow(
+      'Unknown persona "writer". Available: plain, reviewer.',
+    );
+  });
+});
@@ -0,0 +1,95 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import { resolveStateDir } from "../config/paths.js";
+import { parseFrontmatterBlock, stripFrontmatterBlock } from "../markdown/frontmatter.js";
+
+/** A named system prompt preset from `~/.openclaw/prompts/<name>.md`. */
+export type PersonaPreset = {
+  name: string;
+  description?: string;
+  /** `provider/model` or alias to run the persona with. */
+  model?: string;
+  temperature?: number;
+  prompt: string;
+  path: string;
+};
+
+const PERSONA_NAME_RE = /^[a-z0-9][a-z0-9_-]{0,63}$/;
+
+export function resolvePersonasDir(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "prompts");
+}
+
+export function normalizePersonaName(raw: string | undefined): string | null {
+  const name = raw?.trim().toLowerCase().replace(/\.md$/, "") ?? "";
+  return PERSONA_NAME_RE.test(name) ? name : null;
+}
+
+export function parsePersonaFile(name: string, content: string, filePath: string): PersonaPreset {
+  const frontmatter = parseFrontmatterBlock(content);
+  const temperature = Number.parseFloat(frontmatter.temperature ?? "");
+  return {
+    name,
+    description: frontmatter.description?.trim() || undefined,
+    model: frontmatter.model?.trim() || undefined,
+    temperature:
+      Number.isFinite(temperature) && temperature >= 0 && temperature <= 2
+        ? temperature
+        : undefined,
+    prompt: stripFrontmatterBlock(content).trim(),
+    path: filePath,
+  };
+}
+
+export async function loadPersona(
+  rawName: string,
+  dir: string = resolvePersonasDir(),
+): Promise<PersonaPreset | null> {
+  const name = normalizePersonaName(rawName);
+  if (!name) {
+    return null;
+  }
+  const filePath = path.join(dir, `${name}.md`);
+  let content: string;
+  try {
+    content = await fs.readFile(filePath, "utf8");
+  } catch {
+    return null;
+  }
+  const persona = parsePersonaFile(name, content, filePath);
+  return persona.prompt ? persona : null;
+}
+
+export async function listPersonas(dir: string = resolvePersonasDir()): Promise<PersonaPreset[]> {
+  let entries: string[];
+  try {
+    entries = await fs.readdir(dir);
+  } catch {
+    return [];
+  }
+  const names = entries
+    .filter((entry) => entry.endsWith(".md"))
+    .map((entry) => normalizePersonaName(entry))
+    .filter((name): name is string => Boolean(name))
+    .toSorted();
+  const personas = await Promise.all(names.map((name) => loadPersona(name, dir)));
+  return personas.filter((persona): persona is PersonaPreset => Boolean(persona));
+}
+
+export function buildPersonaSystemPrompt(persona: PersonaPreset): string {
+  return [`## Persona: ${persona.name}`, persona.prompt].join("\n");
+}
+
+/** Loads a persona or throws with the available names, for explicit selections. */
+export async function requirePersona(
+  rawName: string,
+  dir: string = resolvePersonasDir(),
+): Promise<PersonaPreset> {
+  const persona = await loadPersona(rawName, dir);
+  if (persona) {
+    return persona;
+  }
+  const available = (await listPersonas(dir)).map((entry) => entry.name);
+  const hint = available.length > 0 ? `Available: ${available.join(", ")}.` : `Add one in ${dir}.`;
+  throw new Error(`Unknown persona "${rawName.trim()}". ${hint}`);
+}
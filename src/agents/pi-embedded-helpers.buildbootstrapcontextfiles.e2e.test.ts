@@ -1,5 +1,9 @@
 import { describe, expect, it } from "vitest";
-import { buildBootstrapContextFiles, DEFAULT_BOOTSTRAP_MAX_CHARS } from "./pi-embedded-helpers.js";
+import {
+  applyBootstrapTotalBudget,
+  buildBootstrapContextFiles,
+  DEFAULT_BOOTSTRAP_MAX_CHARS,
+} from "./pi-embedded-helpers.js";
 import { DEFAULT_AGENTS_FILENAME } from "./workspace.js";
 
 const makeFile = (overrides: Partial<WorkspaceBootstrapFile>): WorkspaceBootstrapFile => ({
@@ -51,3 +55,31 @@ describe("buildBootstrapContextFiles", () => {
     expect(result?.content).not.toContain("[...truncated, read AGENTS.md for full content...]");
   });
 });
+
+describe("applyBootstrapTotalBudget", () => {
+  it("keeps files untouched while they fit the total budget", () => {
+    const files = [
+      { path: "AGENTS.md", content: "a".repeat(300) },
+      { path: "TOOLS.md", content: "b".repeat(300) },
+    ];
+    expect(applyBootstrapTotalBudget(files, { totalMaxChars: 600 })).toEqual(files);
+  });
+
+  it("truncates and then omits later files once the budget runs out", () => {
+    const warnings: string[] = [];
+    const [first, second, third] = applyBootstrapTotalBudget(
+      [
+        { path: "AGENTS.md", content: "a".repeat(1_000) },
+        { path: "/repo/CLAUDE.md", content: "b".repeat(2_000) },
+        { path: "/repo/pkg/AGENTS.md", content: "c".repeat(500) },
+      ],
+      { totalMaxChars: 1_500, warn: (message) => warnings.push(message) },
+    );
+    expect(first?.content).toBe("a".repeat(1_000));
+    expect(second?.content).toContain("[...truncated, read /repo/CLAUDE.md for full content...]");
+    expect(second?.content.length).toBeLessThan(2_000);
+    expect(third?.content).toContain("omitted: context budget of 1500 chars reached");
+    expect(third?.content).not.toContain("ccc");
+    expect(warnings).toHaveLength(2);
+  });
+});
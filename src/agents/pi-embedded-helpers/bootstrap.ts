@@ -82,6 +82,8 @@ export function stripThoughtSignatures<T>(
 }
 
 export const DEFAULT_BOOTSTRAP_MAX_CHARS = 20_000;
+export const DEFAULT_BOOTSTRAP_TOTAL_MAX_CHARS = 60_000;
+const BOOTSTRAP_MIN_SECTION_CHARS = 200;
 const BOOTSTRAP_HEAD_RATIO = 0.7;
 const BOOTSTRAP_TAIL_RATIO = 0.2;
 
@@ -100,7 +102,15 @@ export function resolveBootstrapMaxChars(cfg?: OpenClawConfig): number {
   return DEFAULT_BOOTSTRAP_MAX_CHARS;
 }
 
-function trimBootstrapContent(
+export function resolveBootstrapTotalMaxChars(cfg?: OpenClawConfig): number {
+  const raw = cfg?.agents?.defaults?.bootstrapTotalMaxChars;
+  if (typeof raw === "number" && Number.isFinite(raw) && raw > 0) {
+    return Math.floor(raw);
+  }
+  return DEFAULT_BOOTSTRAP_TOTAL_MAX_CHARS;
+}
+
+export function trimBootstrapContent(
   content: string,
   fileName: string,
   maxChars: number,
@@ -190,6 +200,43 @@ export function buildBootstrapContextFiles(
   return result;
 }
 
+/**
+ * Fit injected context files into a shared budget. Files keep their order; once the
+ * budget runs low, later files are truncated (head/tail with a marker) or replaced by
+ * a short pointer telling the model to read the file itself.
+ */
+export function applyBootstrapTotalBudget(
+  files: EmbeddedContextFile[],
+  opts?: { warn?: (message: string) => void; totalMaxChars?: number },
+): EmbeddedContextFile[] {
+  const totalMaxChars = opts?.totalMaxChars ?? DEFAULT_BOOTSTRAP_TOTAL_MAX_CHARS;
+  let remaining = totalMaxChars;
+  const result: EmbeddedContextFile[] = [];
+  for (const file of files) {
+    if (file.content.length <= remaining) {
+      remaining -= file.content.length;
+      result.push(file);
+      continue;
+    }
+    if (remaining < BOOTSTRAP_MIN_SECTION_CHARS) {
+      opts?.warn?.(
+        `context file ${file.path} omitted from injected context (bootstrap total limit ${totalMaxChars} chars reached)`,
+      );
+      const content = `[...omitted: context budget of ${totalMaxChars} chars reached, read ${file.path} for its content...]`;
+      remaining = Math.max(0, remaining - content.length);
+      result.push({ path: file.path, content });
+      continue;
+    }
+    const trimmed = trimBootstrapContent(file.content, file.path, remaining);
+    opts?.warn?.(
+      `context file ${file.path} truncated to ${remaining} chars to fit the bootstrap total limit (${totalMaxChars} chars)`,
+    );
+    remaining = Math.max(0, remaining - trimmed.content.length);
+    result.push({ path: file.path, content: trimmed.content });
+  }
+  return result;
+}
+
 export function sanitizeGoogleTurnOrdering(messages: AgentMessage[]): AgentMessage[] {
   const GOOGLE_TURN_ORDER_BOOTSTRAP_TEXT = "(session bootstrap)";
   const first = messages[0] as { role?: unknown; content?: unknown } | undefined;
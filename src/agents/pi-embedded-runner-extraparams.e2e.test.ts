@@ -2,7 +2,11 @@ import type { StreamFn } from "@mariozechner/pi-agent-core";
 import type { Context, Model, SimpleStreamOptions } from "@mariozechner/pi-ai";
 import { AssistantMessageEventStream } from "@mariozechner/pi-ai";
 import { describe, expect, it } from "vitest";
-import { applyExtraParamsToAgent, resolveExtraParams } from "./pi-embedded-runner.js";
+import {
+  applyExtraParamsToAgent,
+  resolveExtraParams,
+  resolveStopSequences,
+} from "./pi-embedded-runner.js";
 
 describe("resolveExtraParams", () => {
   it("returns undefined with no model config", () => {
@@ -91,4 +95,36 @@ describe("applyExtraParamsToAgent", () => {
       "X-Custom": "1",
     });
   });
+
+  it("writes stop sequences into Anthropic and OpenAI request bodies", () => {
+    const payloads: Array<Record<string, unknown>> = [];
+    const baseStreamFn: StreamFn = (_model, _context, options) => {
+      const payload: Record<string, unknown> = { messages: [] };
+      options?.onPayload?.(payload);
+      payloads.push(payload);
+      return new AssistantMessageEventStream();
+    };
+    const agent = { streamFn: baseStreamFn };
+    const cfg = {
+      agents: {
+        defaults: {
+          models: { "anthropic/claude-sonnet-4-5": { params: { stopSequences: ["</answer>"] } } },
+        },
+      },
+    };
+
+    applyExtraParamsToAgent(agent, cfg, "anthropic", "claude-sonnet-4-5", {
+      stopSequences: ["END", "  ", "END"],
+    });
+    const context: Context = { messages: [] };
+    for (const api of ["anthropic-messages", "openai-completions", "openai-responses"] as const) {
+      void agent.streamFn?.({ api } as Model<typeof api>, context, {});
+    }
+
+    expect(payloads[0]?.stop_sequences).toEqual(["END"]);
+    expect(payloads[1]?.stop).toEqual(["END"]);
+    expect(payloads[2]).toEqual({ messages: [] });
+    expect(resolveStopSequences({ stopSequences: "###" })).toEqual(["###"]);
+    expect(resolveStopSequences({ stopSequences: [] })).toBeUndefined();
+  });
 });
@@ -5,12 +5,16 @@ import { fileURLToPath } from "node:url";
 import type { OpenClawConfig } from "../../config/config.js";
 import { resolveContextWindowInfo } from "../context-window-guard.js";
 import { DEFAULT_CONTEXT_TOKENS } from "../defaults.js";
+import { buildModelAliasIndex, resolveModelRefFromString } from "../model-selection.js";
 import { setCompactionSafeguardRuntime } from "../pi-extensions/compaction-safeguard-runtime.js";
 import { setContextPruningRuntime } from "../pi-extensions/context-pruning/runtime.js";
 import { computeEffectiveSettings } from "../pi-extensions/context-pruning/settings.js";
 import { makeToolPrunablePredicate } from "../pi-extensions/context-pruning/tools.js";
 import { ensurePiCompactionReserveTokens } from "../pi-settings.js";
+import { noteToolResultsDropped } from "../pi-tools.result-cache.js";
 import { isCacheTtlEligibleProvider, readLastCacheTtlTimestamp } from "./cache-ttl.js";
+import { log } from "./logger.js";
+import { resolveModel } from "./model.js";
 
 function resolvePiExtensionPath(id: string): string {
   const self = fileURLToPath(import.meta.url);
@@ -20,7 +24,7 @@ function resolvePiExtensionPath(id: string): string {
   return path.join(dir, "..", "pi-extensions", `${id}.${ext}`);
 }
 
-function resolveContextWindowTokens(params: {
+export function resolveEmbeddedContextWindowTokens(params: {
   cfg: OpenClawConfig | undefined;
   provider: string;
   modelId: string;
@@ -38,6 +42,7 @@ function resolveContextWindowTokens(params: {
 function buildContextPruningExtension(params: {
   cfg: OpenClawConfig | undefined;
   sessionManager: SessionManager;
+  sessionKey?: string;
   provider: string;
   modelId: string;
   model: Model<Api> | undefined;
@@ -57,9 +62,11 @@ function buildContextPruningExtension(params: {
 
   setContextPruningRuntime(params.sessionManager, {
     settings,
-    contextWindowTokens: resolveContextWindowTokens(params),
+    contextWindowTokens: resolveEmbeddedContextWindowTokens(params),
     isToolPrunable: makeToolPrunablePredicate(settings.tools),
     lastCacheTouchAt: readLastCacheTtlTimestamp(params.sessionManager),
+    // Cached-result pointers must not refer to a result that was just pruned.
+    onPrune: () => noteToolResultsDropped(params.sessionKey),
   });
 
   return {
@@ -71,12 +78,46 @@ function resolveCompactionMode(cfg?: OpenClawConfig): "default" | "safeguard" {
   return cfg?.agents?.defaults?.compaction?.mode === "safeguard" ? "safeguard" : "default";
 }
 
+function resolveCompactionSummarizerModel(params: {
+  cfg: OpenClawConfig | undefined;
+  provider: string;
+  agentDir?: string;
+}): Model<Api> | undefined {
+  const raw = params.cfg?.agents?.defaults?.compaction?.model?.trim();
+  if (!raw || !params.cfg) {
+    return undefined;
+  }
+  const resolved = resolveModelRefFromString({
+    raw,
+    defaultProvider: params.provider,
+    aliasIndex: buildModelAliasIndex({ cfg: params.cfg, defaultProvider: params.provider }),
+  });
+  if (!resolved) {
+    return undefined;
+  }
+  const { model, error } = resolveModel(
+    resolved.ref.provider,
+    resolved.ref.model,
+    params.agentDir,
+    params.cfg,
+  );
+  if (!model) {
+    log.warn(
+      `compaction summarizer model ${raw} unavailable (${error ?? "unknown model"}); ` +
+        "using the session model",
+    );
+  }
+  return model;
+}
+
 export function buildEmbeddedExtensionPaths(params: {
   cfg: OpenClawConfig | undefined;
   sessionManager: SessionManager;
+  sessionKey?: string;
   provider: string;
   modelId: string;
   model: Model<Api> | undefined;
+  agentDir?: string;
 }): string[] {
   const paths: string[] = [];
   if (resolveCompactionMode(params.cfg) === "safeguard") {
@@ -91,6 +132,7 @@ export function buildEmbeddedExtensionPaths(params: {
     setCompactionSafeguardRuntime(params.sessionManager, {
       maxHistoryShare: compactionCfg?.maxHistoryShare,
       contextWindowTokens: contextWindowInfo.tokens,
+      summarizerModel: resolveCompactionSummarizerModel(params),
     });
     paths.push(resolvePiExtensionPath("compaction-safeguard"));
   }
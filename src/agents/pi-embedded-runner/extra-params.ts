@@ -25,6 +25,56 @@ export function resolveExtraParams(params: {
   return modelConfig?.params ? { ...modelConfig.params } : undefined;
 }
 
+/** OpenAI accepts at most four stop sequences; Anthropic has no small fixed limit. */
+const OPENAI_MAX_STOP_SEQUENCES = 4;
+
+/**
+ * Resolve `stopSequences` (a string or list of strings) from extra params.
+ */
+export function resolveStopSequences(
+  extraParams: Record<string, unknown> | undefined,
+): string[] | undefined {
+  const raw = extraParams?.stopSequences;
+  const list = typeof raw === "string" ? [raw] : Array.isArray(raw) ? raw : [];
+  // Providers reject empty and whitespace-only sequences.
+  const stops = list.filter(
+    (value): value is string => typeof value === "string" && value.trim() !== "",
+  );
+  return stops.length > 0 ? Array.from(new Set(stops)) : undefined;
+}
+
+/** Stop sequences for a run: a per-run override wins over the model's configured params. */
+export function resolveRunStopSequences(params: {
+  cfg: OpenClawConfig | undefined;
+  provider: string;
+  modelId: string;
+  override?: Record<string, unknown>;
+}): string[] | undefined {
+  return resolveStopSequences(params.override) ?? resolveStopSequences(resolveExtraParams(params));
+}
+
+/**
+ * Write stop sequences into the provider request body. pi-ai has no stop option, so this runs
+ * from the payload hook; APIs without stop support (OpenAI Responses) are left untouched.
+ */
+function applyStopSequencesToPayload(api: string, payload: unknown, stops: string[]): void {
+  if (!payload || typeof payload !== "object") {
+    return;
+  }
+  const body = payload as Record<string, unknown>;
+  if (api === "anthropic-messages") {
+    body.stop_sequences = stops;
+  } else if (api === "bedrock-converse-stream") {
+    const inferenceConfig = (body.inferenceConfig ?? {}) as Record<string, unknown>;
+    body.inferenceConfig = { ...inferenceConfig, stopSequences: stops };
+  } else if (api === "openai-completions") {
+    body.stop = stops.slice(0, OPENAI_MAX_STOP_SEQUENCES);
+  } else if (api.startsWith("google-")) {
+    const config = (body.config ?? {}) as Record<string, unknown>;
+    body.config = { ...config, stopSequences: stops };
+  }
+}
+
 type CacheRetention = "none" | "short" | "long";
 type CacheRetentionStreamOptions = Partial<SimpleStreamOptions> & {
   cacheRetention?: CacheRetention;
@@ -84,18 +134,29 @@ function createStreamFnWithExtraParams(
   if (cacheRetention) {
     streamParams.cacheRetention = cacheRetention;
   }
+  const stopSequences = resolveStopSequences(extraParams);
 
-  if (Object.keys(streamParams).length === 0) {
+  if (Object.keys(streamParams).length === 0 && !stopSequences) {
     return undefined;
   }
 
-  log.debug(`creating streamFn wrapper with params: ${JSON.stringify(streamParams)}`);
+  log.debug(
+    `creating streamFn wrapper with params: ${JSON.stringify({ ...streamParams, stopSequences })}`,
+  );
 
   const underlying = baseStreamFn ?? streamSimple;
   const wrappedStreamFn: StreamFn = (model, context, options) =>
     underlying(model, context, {
       ...streamParams,
       ...options,
+      ...(stopSequences
+        ? {
+            onPayload: (payload: unknown) => {
+              applyStopSequencesToPayload(model.api, payload, stopSequences);
+              options?.onPayload?.(payload);
+            },
+          }
+        : {}),
     });
 
   return wrappedStreamFn;
@@ -44,14 +44,21 @@ import {
   pickFallbackThinkingLevel,
   type FailoverReason,
 } from "../pi-embedded-helpers.js";
+import { resolveCompactionReserveTokens } from "../pi-settings.js";
+import { createAgentRunLimitTracker, resolveAgentRunLimits } from "../pi-tools.run-limits.js";
 import { derivePromptTokens, normalizeUsage, type UsageLike } from "../usage.js";
 import { redactRunIdentifier, resolveRunWorkspaceDir } from "../workspace-run.js";
 import { compactEmbeddedPiSessionDirect } from "./compact.js";
+import { resolveRunStopSequences } from "./extra-params.js";
 import { resolveGlobalLane, resolveSessionLane } from "./lanes.js";
 import { log } from "./logger.js";
 import { resolveModel } from "./model.js";
 import { runEmbeddedAttempt } from "./run/attempt.js";
 import { buildEmbeddedRunPayloads } from "./run/payloads.js";
+import {
+  pruneStaleToolResultsInSession,
+  resolveToolResultPruneSettings,
+} from "./tool-result-pruning.js";
 import {
   truncateOversizedToolResultsInSession,
   sessionLikelyHasOversizedToolResults,
@@ -214,6 +221,12 @@ export async function runEmbeddedPiAgent(
       if (!model) {
         throw new Error(error ?? `Unknown model: ${provider}/${modelId}`);
       }
+      const stopSequences = resolveRunStopSequences({
+        cfg: params.config,
+        provider,
+        modelId,
+        override: params.streamParams,
+      });
 
       const ctxInfo = resolveContextWindowInfo({
         cfg: params.config,
@@ -411,7 +424,31 @@ export async function runEmbeddedPiAgent(
       const MAX_OVERFLOW_COMPACTION_ATTEMPTS = 3;
       let overflowCompactionAttempts = 0;
       let toolResultTruncationAttempted = false;
+      const toolResultPruneSettings = resolveToolResultPruneSettings(params.config);
+      const compactionReserveTokens = resolveCompactionReserveTokens({
+        cfg: params.config,
+        contextWindowTokens: ctxInfo.tokens,
+      });
+      let staleToolResultPruneAttempted = false;
+      if (toolResultPruneSettings) {
+        // Cheap first pass: drop stale tool output before the context grows into a compaction.
+        await pruneStaleToolResultsInSession({
+          sessionFile: params.sessionFile,
+          contextWindowTokens: ctxInfo.tokens,
+          reserveTokens: compactionReserveTokens,
+          settings: toolResultPruneSettings,
+          sessionId: params.sessionId,
+          sessionKey: params.sessionKey,
+        });
+      }
       const usageAccumulator = createUsageAccumulator();
+      const runLimits = resolveAgentRunLimits({
+        config: params.config,
+        overrides: params.runLimits,
+      });
+      const runLimitTracker = runLimits
+        ? createAgentRunLimitTracker({ limits: runLimits, cost: model.cost })
+        : undefined;
       let lastRunPromptUsage: ReturnType<typeof normalizeUsage> | undefined;
       let autoCompactionCount = 0;
       try {
@@ -460,6 +497,13 @@ export async function runEmbeddedPiAgent(
             execOverrides: params.execOverrides,
             bashElevated: params.bashElevated,
             timeoutMs: params.timeoutMs,
+            runLimitTracker,
+            planMode: params.planMode,
+            disabledTools: params.disabledTools,
+            disableMemory: params.disableMemory,
+            structuredOutput: params.structuredOutput,
+            prefill: params.prefill,
+            pinnedContext: params.pinnedContext,
             runId: params.runId,
             abortSignal: params.abortSignal,
             shouldEmitToolResult: params.shouldEmitToolResult,
@@ -537,6 +581,25 @@ export async function runEmbeddedPiAgent(
                 `error=${errorText.slice(0, 200)}`,
             );
             const isCompactionFailure = isCompactionFailureError(errorText);
+            // Before summarizing, swap stale tool results for placeholders (once per run).
+            if (!isCompactionFailure && toolResultPruneSettings && !staleToolResultPruneAttempted) {
+              staleToolResultPruneAttempted = true;
+              const pruneResult = await pruneStaleToolResultsInSession({
+                sessionFile: params.sessionFile,
+                contextWindowTokens: ctxInfo.tokens,
+                reserveTokens: compactionReserveTokens,
+                settings: toolResultPruneSettings,
+                force: true,
+                sessionId: params.sessionId,
+                sessionKey: params.sessionKey,
+              });
+              if (pruneResult.pruned) {
+                log.info(
+                  `[context-overflow-recovery] Pruned ${pruneResult.prunedCount} stale tool result(s); retrying prompt`,
+                );
+                continue;
+              }
+            }
             // Attempt auto-compaction on context overflow (not compaction_failure)
             if (
               !isCompactionFailure &&
@@ -927,11 +990,16 @@ export async function runEmbeddedPiAgent(
             payloads: payloads.length ? payloads : undefined,
             meta: {
               durationMs: Date.now() - started,
+              timings: attempt.timings,
               agentMeta,
               aborted,
               systemPromptReport: attempt.systemPromptReport,
               // Handle client tool calls (OpenResponses hosted tools)
-              stopReason: attempt.clientToolCall ? "tool_calls" : undefined,
+              stopReason: attempt.clientToolCall
+                ? "tool_calls"
+                : stopSequences && lastAssistant?.stopReason === "stop"
+                  ? "stop_sequence"
+                  : undefined,
               pendingToolCalls: attempt.clientToolCall
                 ? [
                     {
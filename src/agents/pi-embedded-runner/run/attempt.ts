@@ -10,6 +10,7 @@ import { resolveChannelCapabilities } from "../../../config/channel-capabilities
 import { getMachineDisplayName } from "../../../infra/machine-name.js";
 import { MAX_IMAGE_BYTES } from "../../../media/constants.js";
 import { getGlobalHookRunner } from "../../../plugins/hook-runner-global.js";
+import { resolvePluginProviderStreamFn } from "../../../plugins/providers.js";
 import { isSubagentSessionKey, normalizeAgentId } from "../../../routing/session-key.js";
 import { resolveSignalReactionLevel } from "../../../signal/reaction-level.js";
 import { resolveTelegramInlineButtonsScope } from "../../../telegram/inline-buttons.js";
@@ -21,6 +22,7 @@ import { isReasoningTagProvider } from "../../../utils/provider-utils.js";
 import { resolveOpenClawAgentDir } from "../../agent-paths.js";
 import { resolveSessionAgentIds } from "../../agent-scope.js";
 import { createAnthropicPayloadLogger } from "../../anthropic-payload-log.js";
+import { getTrackedExecCwd, resolveExecScopeKey } from "../../bash-tools.cwd.js";
 import { makeBootstrapWarn, resolveBootstrapContextForRun } from "../../bootstrap-files.js";
 import { createCacheTrace } from "../../cache-trace.js";
 import {
@@ -32,19 +34,19 @@ import { isTimeoutError } from "../../failover-error.js";
 import { resolveModelAuthMode } from "../../model-auth.js";
 import { resolveDefaultModelForAgent } from "../../model-selection.js";
 import { createOllamaStreamFn, OLLAMA_NATIVE_BASE_URL } from "../../ollama-stream.js";
+import { refreshDiscoveredTools } from "../../openclaw-tools.js";
 import {
   isCloudCodeAssistFormatError,
   resolveBootstrapMaxChars,
+  resolveBootstrapTotalMaxChars,
   validateAnthropicTurns,
   validateGeminiTurns,
 } from "../../pi-embedded-helpers.js";
 import { subscribeEmbeddedPiSession } from "../../pi-embedded-subscribe.js";
-import {
-  ensurePiCompactionReserveTokens,
-  resolveCompactionReserveTokensFloor,
-} from "../../pi-settings.js";
+import { applyPiCompactionSettings } from "../../pi-settings.js";
 import { toClientToolDefinitions } from "../../pi-tool-definition-adapter.js";
 import { createOpenClawCodingTools } from "../../pi-tools.js";
+import { applyPrefillToReplyTexts, supportsNativePrefill } from "../../prefill.js";
 import { resolveSandboxContext } from "../../sandbox.js";
 import { resolveSandboxRuntimeStatus } from "../../sandbox/runtime-status.js";
 import { repairSessionFileIfNeeded } from "../../session-file-repair.js";
@@ -58,13 +60,18 @@ import {
   loadWorkspaceSkillEntries,
   resolveSkillsPromptForRun,
 } from "../../skills.js";
+import { createStructuredOutputTool } from "../../structured-output.js";
 import { buildSystemPromptParams } from "../../system-prompt-params.js";
 import { buildSystemPromptReport } from "../../system-prompt-report.js";
 import { resolveTranscriptPolicy } from "../../transcript-policy.js";
+import { resolveWorkspaceMapForRun } from "../../workspace-map.js";
 import { DEFAULT_BOOTSTRAP_FILENAME } from "../../workspace.js";
 import { isRunnerAbortError } from "../abort.js";
 import { appendCacheTtlTimestamp, isCacheTtlEligibleProvider } from "../cache-ttl.js";
-import { buildEmbeddedExtensionPaths } from "../extensions.js";
+import {
+  buildEmbeddedExtensionPaths,
+  resolveEmbeddedContextWindowTokens,
+} from "../extensions.js";
 import { applyExtraParamsToAgent } from "../extra-params.js";
 import {
   logToolSchemasForGoogle,
@@ -96,6 +103,9 @@ import {
   shouldFlagCompactionTimeout,
 } from "./compaction-timeout.js";
 import { detectAndLoadPromptImages } from "./images.js";
+import { buildAttemptExtraSystemPrompt, prependRecalledMemory } from "./prompt-additions.js";
+import { subscribeRunLimits } from "./run-limits.js";
+import { wrapStreamFnForAttempt } from "./stream-wrappers.js";
 
 export function injectHistoryImagesIntoMessages(
   messages: AgentMessage[],
@@ -259,14 +269,24 @@ export async function runEmbeddedAttempt(
     });
 
     const sessionLabel = params.sessionKey ?? params.sessionId;
-    const { bootstrapFiles: hookAdjustedBootstrapFiles, contextFiles } =
-      await resolveBootstrapContextForRun({
-        workspaceDir: effectiveWorkspace,
-        config: params.config,
-        sessionKey: params.sessionKey,
-        sessionId: params.sessionId,
-        warn: makeBootstrapWarn({ sessionLabel, warn: (message) => log.warn(message) }),
-      });
+    const {
+      bootstrapFiles: hookAdjustedBootstrapFiles,
+      instructionFiles,
+      contextFiles,
+    } = await resolveBootstrapContextForRun({
+      workspaceDir: effectiveWorkspace,
+      config: params.config,
+      sessionKey: params.sessionKey,
+      sessionId: params.sessionId,
+      disableMemory: params.disableMemory,
+      warn: makeBootstrapWarn({ sessionLabel, warn: (message) => log.warn(message) }),
+    });
+    const workspaceMap = isSubagentSessionKey(params.sessionKey)
+      ? undefined
+      : await resolveWorkspaceMapForRun({
+          workspaceDir: effectiveWorkspace,
+          config: params.config,
+        });
     const workspaceNotes = hookAdjustedBootstrapFiles.some(
       (file) => file.name === DEFAULT_BOOTSTRAP_FILENAME && !file.missing,
     )
@@ -277,6 +297,9 @@ export async function runEmbeddedAttempt(
 
     // Check if the model supports native image input
     const modelHasVision = params.model.input?.includes("image") ?? false;
+    if (!params.disableTools) {
+      await refreshDiscoveredTools(params.config);
+    }
     const toolsRaw = params.disableTools
       ? []
       : createOpenClawCodingTools({
@@ -303,6 +326,11 @@ export async function runEmbeddedAttempt(
           workspaceDir: effectiveWorkspace,
           config: params.config,
           abortSignal: runAbortController.signal,
+          runLimits: params.runLimitTracker,
+          planMode: params.planMode,
+          disabledTools: params.disableMemory
+            ? [...(params.disabledTools ?? []), "group:memory"]
+            : params.disabledTools,
           modelProvider: params.model.provider,
           modelId: params.modelId,
           modelAuthMode: resolveModelAuthMode(params.model.provider, params.config),
@@ -315,7 +343,14 @@ export async function runEmbeddedAttempt(
             params.requireExplicitMessageTarget ?? isSubagentSessionKey(params.sessionKey),
           disableMessageTool: params.disableMessageTool,
         });
-    const tools = sanitizeToolsForGoogle({ tools: toolsRaw, provider: params.provider });
+    const structuredOutputTool =
+      params.structuredOutput && params.model.api === "anthropic-messages"
+        ? createStructuredOutputTool(params.structuredOutput)
+        : undefined;
+    const tools = sanitizeToolsForGoogle({
+      tools: structuredOutputTool ? [...toolsRaw, structuredOutputTool] : toolsRaw,
+      provider: params.provider,
+    });
     logToolSchemasForGoogle({ tools, provider: params.provider });
 
     const machineName = await getMachineDisplayName();
@@ -419,11 +454,26 @@ export async function runEmbeddedAttempt(
     });
     const ttsHint = params.config ? buildTtsSystemPromptHint(params.config) : undefined;
 
+    const nativePrefill = supportsNativePrefill({
+      api: params.model.api,
+      reasoning: params.model.reasoning,
+      thinkLevel: params.thinkLevel,
+    });
     const appendPrompt = buildEmbeddedSystemPrompt({
       workspaceDir: effectiveWorkspace,
+      execCwd: sandbox?.enabled
+        ? undefined
+        : getTrackedExecCwd(
+            resolveExecScopeKey({ sessionKey: params.sessionKey, agentId: sessionAgentId }),
+          ),
       defaultThinkLevel: params.thinkLevel,
       reasoningLevel: params.reasoningLevel ?? "off",
-      extraSystemPrompt: params.extraSystemPrompt,
+      extraSystemPrompt: buildAttemptExtraSystemPrompt({
+        extraSystemPrompt: params.extraSystemPrompt,
+        planMode: params.planMode,
+        prefill: params.prefill,
+        nativePrefill,
+      }),
       ownerNumbers: params.ownerNumbers,
       reasoningTagHint,
       heartbeatPrompt: isDefaultAgent
@@ -433,6 +483,7 @@ export async function runEmbeddedAttempt(
       docsPath: docsPath ?? undefined,
       ttsHint,
       workspaceNotes,
+      workspaceMap,
       reactionGuidance,
       promptMode,
       runtimeInfo,
@@ -455,6 +506,7 @@ export async function runEmbeddedAttempt(
       model: params.modelId,
       workspaceDir: effectiveWorkspace,
       bootstrapMaxChars: resolveBootstrapMaxChars(params.config),
+      bootstrapTotalMaxChars: resolveBootstrapTotalMaxChars(params.config),
       sandbox: (() => {
         const runtime = resolveSandboxRuntimeStatus({
           cfg: params.config,
@@ -464,6 +516,7 @@ export async function runEmbeddedAttempt(
       })(),
       systemPrompt: appendPrompt,
       bootstrapFiles: hookAdjustedBootstrapFiles,
+      instructionFiles,
       injectedFiles: contextFiles,
       skillsPrompt,
       tools,
@@ -511,18 +564,26 @@ export async function runEmbeddedAttempt(
       });
 
       const settingsManager = SettingsManager.create(effectiveWorkspace, agentDir);
-      ensurePiCompactionReserveTokens({
+      applyPiCompactionSettings({
         settingsManager,
-        minReserveTokens: resolveCompactionReserveTokensFloor(params.config),
+        cfg: params.config,
+        contextWindowTokens: resolveEmbeddedContextWindowTokens({
+          cfg: params.config,
+          provider: params.provider,
+          modelId: params.modelId,
+          model: params.model,
+        }),
       });
 
       // Call for side effects (sets compaction/pruning runtime state)
       buildEmbeddedExtensionPaths({
         cfg: params.config,
         sessionManager,
+        sessionKey: params.sessionKey ?? params.sessionId,
         provider: params.provider,
         modelId: params.modelId,
         model: params.model,
+        agentDir,
       });
 
       // Get hook runner early so it's available when creating tools
@@ -589,9 +650,13 @@ export async function runEmbeddedAttempt(
         workspaceDir: params.workspaceDir,
       });
 
-      // Ollama native API: bypass SDK's streamSimple and use direct /api/chat calls
-      // for reliable streaming + tool calling support (#11828).
-      if (params.model.api === "ollama") {
+      const pluginStreamFn = resolvePluginProviderStreamFn(params.model.provider);
+      if (pluginStreamFn) {
+        // Provider plugins can bring their own transport (see ProviderPlugin.stream).
+        activeSession.agent.streamFn = pluginStreamFn;
+      } else if (params.model.api === "ollama") {
+        // Ollama native API: bypass SDK's streamSimple and use direct /api/chat calls
+        // for reliable streaming + tool calling support (#11828).
         // Use the resolved model baseUrl first so custom provider aliases work.
         const providerConfig = params.config?.models?.providers?.[params.model.provider];
         const modelBaseUrl =
@@ -612,6 +677,13 @@ export async function runEmbeddedAttempt(
         params.modelId,
         params.streamParams,
       );
+      activeSession.agent.streamFn = wrapStreamFnForAttempt(activeSession.agent.streamFn, {
+        config: params.config,
+        structuredOutput: params.structuredOutput,
+        prefill: params.prefill,
+        nativePrefill,
+        pinnedContext: params.pinnedContext,
+      });
 
       if (cacheTrace) {
         cacheTrace.recordStage("session:loaded", {
@@ -753,6 +825,7 @@ export async function runEmbeddedAttempt(
         getLastToolError,
         getUsageTotals,
         getCompactionCount,
+        getRunTimings,
       } = subscription;
 
       const queueHandle: EmbeddedPiQueueHandle = {
@@ -765,6 +838,16 @@ export async function runEmbeddedAttempt(
       };
       setActiveEmbeddedRun(params.sessionId, queueHandle);
 
+      const unsubscribeRunLimits = params.runLimitTracker
+        ? subscribeRunLimits({
+            session: activeSession,
+            tracker: params.runLimitTracker,
+            abort: (reason) => abortRun(false, reason),
+            runId: params.runId,
+            sessionId: params.sessionId,
+          })
+        : undefined;
+
       let abortWarnTimer: NodeJS.Timeout | undefined;
       const isProbeSession = params.sessionId?.startsWith("probe-") ?? false;
       const abortTimer = setTimeout(
@@ -840,7 +923,14 @@ export async function runEmbeddedAttempt(
         const promptStartedAt = Date.now();
 
         // Run before_agent_start hooks to allow plugins to inject context
-        let effectivePrompt = params.prompt;
+        let effectivePrompt = await prependRecalledMemory({
+          config: params.config,
+          agentId: hookAgentId,
+          prompt: params.prompt,
+          sessionKey: params.sessionKey,
+          sessionId: params.sessionId,
+          disableMemory: params.disableMemory,
+        });
         if (hookRunner?.hasHooks("before_agent_start")) {
           try {
             const hookResult = await hookRunner.runBeforeAgentStart(
@@ -857,7 +947,7 @@ export async function runEmbeddedAttempt(
               },
             );
             if (hookResult?.prependContext) {
-              effectivePrompt = `${hookResult.prependContext}\n\n${params.prompt}`;
+              effectivePrompt = `${hookResult.prependContext}\n\n${effectivePrompt}`;
               log.debug(
                 `hooks: prepended context to prompt (${hookResult.prependContext.length} chars)`,
               );
@@ -1080,6 +1170,7 @@ export async function runEmbeddedAttempt(
             `CRITICAL: unsubscribe failed, possible resource leak: runId=${params.runId} ${String(err)}`,
           );
         }
+        unsubscribeRunLimits?.();
         clearActiveEmbeddedRun(params.sessionId, queueHandle);
         params.abortSignal?.removeEventListener?.("abort", onAbort);
       }
@@ -1104,7 +1195,10 @@ export async function runEmbeddedAttempt(
         sessionIdUsed,
         systemPromptReport,
         messagesSnapshot,
-        assistantTexts,
+        assistantTexts:
+          params.prefill && nativePrefill
+            ? applyPrefillToReplyTexts(assistantTexts, params.prefill)
+            : assistantTexts,
         toolMetas: toolMetasNormalized,
         lastAssistant,
         lastToolError: getLastToolError?.(),
@@ -1116,6 +1210,7 @@ export async function runEmbeddedAttempt(
         ),
         attemptUsage: getUsageTotals(),
         compactionCount: getCompactionCount(),
+        timings: getRunTimings(),
         // Client tool call detected (OpenResponses hosted tools)
         clientToolCall: clientToolCallDetected ?? undefined,
       };
@@ -5,8 +5,10 @@ import type { OpenClawConfig } from "../../../config/config.js";
 import type { enqueueCommand } from "../../../process/command-queue.js";
 import type { InputProvenance } from "../../../sessions/input-provenance.js";
 import type { ExecElevatedDefaults, ExecToolDefaults } from "../../bash-tools.js";
+import type { AssistantPrefill } from "../../prefill.js";
 import type { BlockReplyChunking, ToolResultFormat } from "../../pi-embedded-subscribe.js";
 import type { SkillSnapshot } from "../../skills.js";
+import type { StructuredOutputRequest } from "../../structured-output.js";
 
 // Simplified tool definition for client-provided tools (OpenResponses hosted tools)
 export type ClientToolDefinition = {
@@ -77,6 +79,20 @@ export type RunEmbeddedPiAgentParams = {
   execOverrides?: Pick<ExecToolDefaults, "host" | "security" | "ask" | "node">;
   bashElevated?: ExecElevatedDefaults;
   timeoutMs: number;
+  /** Per-run turn/time/cost limits; overrides `agents.defaults.max*` when set. */
+  runLimits?: { maxTurns?: number; maxDurationSeconds?: number; maxCostUsd?: number };
+  /** Restrict tools to read-only ones and ask the model for a plan instead of changes. */
+  planMode?: boolean;
+  /** Tool names (or `group:*` names) to withhold from this run. */
+  disabledTools?: string[];
+  /** Skip MEMORY.md/project memory injection and the memory tools (`--no-memory`). */
+  disableMemory?: boolean;
+  /** Constrain the final answer to a JSON schema (native provider support where available). */
+  structuredOutput?: StructuredOutputRequest;
+  /** Seed the assistant reply with this text (native on Anthropic, emulated elsewhere). */
+  prefill?: AssistantPrefill;
+  /** Pinned messages (`/pin`) added to the latest user turn of each request, never stored. */
+  pinnedContext?: string;
   runId: string;
   abortSignal?: AbortSignal;
   shouldEmitToolResult?: () => boolean;
@@ -0,0 +1,59 @@
+import type { OpenClawConfig } from "../../../config/config.js";
+import { isSubagentSessionKey } from "../../../routing/session-key.js";
+import { recallMemoryForPrompt } from "../../memory-recall.js";
+import { PLAN_MODE_SYSTEM_PROMPT } from "../../plan-mode.js";
+import { type AssistantPrefill, buildPrefillSystemPrompt } from "../../prefill.js";
+import { log } from "../logger.js";
+
+/** The caller's extra system prompt plus the plan-mode and prompt-based prefill instructions. */
+export function buildAttemptExtraSystemPrompt(params: {
+  extraSystemPrompt?: string;
+  planMode?: boolean;
+  prefill?: AssistantPrefill;
+  nativePrefill: boolean;
+}): string | undefined {
+  return (
+    [
+      params.extraSystemPrompt,
+      params.planMode ? PLAN_MODE_SYSTEM_PROMPT : undefined,
+      params.prefill && !params.nativePrefill
+        ? buildPrefillSystemPrompt(params.prefill)
+        : undefined,
+    ]
+      .filter(Boolean)
+      .join("\n\n") || undefined
+  );
+}
+
+/**
+ * Prepends memories recalled for `prompt`. Subagent runs and `disableMemory` skip recall; recall
+ * failures are logged and the prompt is used as is.
+ */
+export async function prependRecalledMemory(params: {
+  config?: OpenClawConfig;
+  agentId: string;
+  prompt: string;
+  sessionKey?: string;
+  sessionId?: string;
+  disableMemory?: boolean;
+}): Promise<string> {
+  if (params.disableMemory || isSubagentSessionKey(params.sessionKey)) {
+    return params.prompt;
+  }
+  try {
+    const recalled = await recallMemoryForPrompt({
+      cfg: params.config,
+      agentId: params.agentId,
+      prompt: params.prompt,
+      sessionKey: params.sessionKey,
+      sessionId: params.sessionId,
+    });
+    if (recalled) {
+      log.debug(`memory recall: prepended ${recalled.length} chars to prompt`);
+      return `${recalled}\n\n${params.prompt}`;
+    }
+  } catch (recallErr) {
+    log.warn(`memory recall failed: ${String(recallErr)}`);
+  }
+  return params.prompt;
+}
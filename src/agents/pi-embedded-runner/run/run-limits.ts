@@ -0,0 +1,29 @@
+import type { AgentSession } from "@mariozechner/pi-coding-agent";
+import type { AgentRunLimitTracker } from "../../pi-tools.run-limits.js";
+import { log } from "../logger.js";
+
+/**
+ * Counts each finished assistant turn against the run's limits and aborts the run once the model
+ * keeps calling tools past the grace turns. Returns the unsubscribe function.
+ */
+export function subscribeRunLimits(params: {
+  session: Pick<AgentSession, "subscribe">;
+  tracker: AgentRunLimitTracker;
+  abort: (reason: Error) => void;
+  runId: string;
+  sessionId: string;
+}): () => void {
+  return params.session.subscribe((evt) => {
+    if (evt.type !== "message_end" || evt.message.role !== "assistant") {
+      return;
+    }
+    params.tracker.recordTurn(evt.message.usage);
+    // The model was told to wrap up and kept calling tools anyway.
+    if (params.tracker.shouldStop()) {
+      log.warn(
+        `run limit: stopping run after grace turns: runId=${params.runId} sessionId=${params.sessionId}`,
+      );
+      params.abort(new Error(`run stopped: ${params.tracker.exceeded()}`));
+    }
+  });
+}
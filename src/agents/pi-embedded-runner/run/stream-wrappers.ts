@@ -0,0 +1,51 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import type { OpenClawConfig } from "../../../config/config.js";
+import { wrapStreamFnWithPinnedContext } from "../../pinned-context.js";
+import { type AssistantPrefill, wrapStreamFnWithPrefill } from "../../prefill.js";
+import {
+  resolveResponseCacheSettings,
+  wrapStreamFnWithResponseCache,
+} from "../../response-cache.js";
+import {
+  type StructuredOutputRequest,
+  wrapStreamFnWithStructuredOutput,
+} from "../../structured-output.js";
+
+/**
+ * Layers the per-run request options over the model transport: the response schema, native
+ * prefill, then the response cache, so a cache hit skips both. Pinned messages go on top so the
+ * cache key covers them.
+ */
+export function wrapStreamFnForAttempt(
+  streamFn: StreamFn,
+  params: {
+    config?: OpenClawConfig;
+    structuredOutput?: StructuredOutputRequest;
+    prefill?: AssistantPrefill;
+    nativePrefill: boolean;
+    pinnedContext?: string;
+  },
+): StreamFn {
+  let wrapped = streamFn;
+  if (params.structuredOutput) {
+    wrapped = wrapStreamFnWithStructuredOutput(wrapped, params.structuredOutput);
+  }
+  if (params.prefill && params.nativePrefill) {
+    wrapped = wrapStreamFnWithPrefill(wrapped, params.prefill);
+  }
+  const responseCache = resolveResponseCacheSettings(params.config);
+  if (responseCache) {
+    wrapped = wrapStreamFnWithResponseCache(wrapped, {
+      ...responseCache,
+      // Prefill and response schemas change the payload but not the context.
+      salt: JSON.stringify([
+        params.nativePrefill ? params.prefill : undefined,
+        params.structuredOutput?.schema.schema,
+      ]),
+    });
+  }
+  if (params.pinnedContext) {
+    wrapped = wrapStreamFnWithPinnedContext(wrapped, params.pinnedContext);
+  }
+  return wrapped;
+}
@@ -3,7 +3,9 @@ import type { Api, AssistantMessage, Model } from "@mariozechner/pi-ai";
 import type { ThinkLevel } from "../../../auto-reply/thinking.js";
 import type { SessionSystemPromptReport } from "../../../config/sessions/types.js";
 import type { MessagingToolSend } from "../../pi-embedded-messaging.js";
+import type { AgentRunLimitTracker } from "../../pi-tools.run-limits.js";
 import type { AuthStorage, ModelRegistry } from "../../pi-model-discovery.js";
+import type { RunTimings } from "../../run-timings.js";
 import type { NormalizedUsage } from "../../usage.js";
 import type { RunEmbeddedPiAgentParams } from "./params.js";
 
@@ -19,6 +21,8 @@ export type EmbeddedRunAttemptParams = EmbeddedRunAttemptBase & {
   authStorage: AuthStorage;
   modelRegistry: ModelRegistry;
   thinkLevel: ThinkLevel;
+  /** Shared across attempts so retries and compaction don't reset the run's budget. */
+  runLimitTracker?: AgentRunLimitTracker;
 };
 
 export type EmbeddedRunAttemptResult = {
@@ -40,6 +44,7 @@ export type EmbeddedRunAttemptResult = {
   cloudCodeAssistFormatError: boolean;
   attemptUsage?: NormalizedUsage;
   compactionCount?: number;
+  timings?: RunTimings;
   /** Client tool call detected (OpenResponses hosted tools). */
   clientToolCall?: { name: string; params: Record<string, unknown> };
 };
@@ -64,6 +64,10 @@ export function isEmbeddedPiRunStreaming(sessionId: string): boolean {
   return handle.isStreaming();
 }
 
+export function listActiveEmbeddedRunSessionIds(): string[] {
+  return Array.from(ACTIVE_EMBEDDED_RUNS.keys());
+}
+
 export function getActiveEmbeddedRunCount(): number {
   return ACTIVE_EMBEDDED_RUNS.size;
 }
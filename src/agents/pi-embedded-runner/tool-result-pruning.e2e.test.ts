@@ -0,0 +1,163 @@
+import type { AgentMessage } from "@mariozechner/pi-agent-core";
+import { SessionManager } from "@mariozechner/pi-coding-agent";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import {
+  DEFAULT_TOOL_RESULT_PRUNE_SETTINGS,
+  pruneStaleToolResultsInSession,
+  pruneToolResultMessage,
+  resolveToolResultPruneSettings,
+  selectStaleToolResults,
+} from "./tool-result-pruning.js";
+
+function makeToolResult(text: string, toolCallId = "call_1"): AgentMessage {
+  return {
+    role: "toolResult",
+    toolCallId,
+    toolName: "read",
+    content: [{ type: "text", text }],
+    details: { path: "/tmp/file.txt" },
+    isError: false,
+    timestamp: Date.now(),
+  } as AgentMessage;
+}
+
+function makeUserMessage(text: string): AgentMessage {
+  return {
+    role: "user",
+    content: text,
+    timestamp: Date.now(),
+  } as AgentMessage;
+}
+
+function makeAssistantMessage(text: string): AgentMessage {
+  return {
+    role: "assistant",
+    content: [{ type: "text", text }],
+    api: "openai-responses",
+    provider: "openai",
+    model: "mock-1",
+    usage: {
+      input: 1,
+      output: 1,
+      cacheRead: 0,
+      cacheWrite: 0,
+      totalTokens: 2,
+      cost: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0, total: 0 },
+    },
+    stopReason: "stop",
+    timestamp: Date.now(),
+  } as AgentMessage;
+}
+
+const settings = { keepLastAssistants: 2, minChars: 100 };
+
+describe("resolveToolResultPruneSettings", () => {
+  it("defaults to enabled", () => {
+    expect(resolveToolResultPruneSettings(undefined)).toEqual(DEFAULT_TOOL_RESULT_PRUNE_SETTINGS);
+  });
+
+  it("honors overrides and disabling", () => {
+    const cfg = {
+      agents: { defaults: { compaction: { pruneToolResults: { keepLastAssistants: 1 } } } },
+    } as OpenClawConfig;
+    expect(resolveToolResultPruneSettings(cfg)).toEqual({ keepLastAssistants: 1, minChars: 2_000 });
+    const disabled = {
+      agents: { defaults: { compaction: { pruneToolResults: { enabled: false } } } },
+    } as OpenClawConfig;
+    expect(resolveToolResultPruneSettings(disabled)).toBeNull();
+  });
+});
+
+describe("selectStaleToolResults", () => {
+  it("selects large tool results older than the last assistant turns", () => {
+    const messages = [
+      makeUserMessage("hi"),
+      makeAssistantMessage("reading"),
+      makeToolResult("a".repeat(500)),
+      makeToolResult("small"),
+      makeAssistantMessage("reading more"),
+      makeToolResult("b".repeat(500)),
+      makeAssistantMessage("done"),
+    ];
+    expect(selectStaleToolResults(messages, settings)).toEqual([2]);
+  });
+
+  it("keeps everything when there are not enough assistant turns", () => {
+    const messages = [makeAssistantMessage("reading"), makeToolResult("a".repeat(500))];
+    expect(selectStaleToolResults(messages, settings)).toEqual([]);
+  });
+});
+
+describe("pruneToolResultMessage", () => {
+  it("replaces content with a placeholder and drops details", () => {
+    const pruned = pruneToolResultMessage(makeToolResult("a".repeat(500))) as {
+      content: Array<{ type: string; text: string }>;
+      details?: unknown;
+      toolCallId: string;
+    };
+    expect(pruned.toolCallId).toBe("call_1");
+    expect(pruned.details).toBeUndefined();
+    expect(pruned.content[0]?.text).toContain("Old read result pruned to save context (500 chars)");
+  });
+});
+
+describe("pruneStaleToolResultsInSession", () => {
+  it("rewrites stale tool results and keeps the conversation", async () => {
+    const dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-prune-"));
+    const sessionFile = path.join(dir, "session.jsonl");
+    try {
+      const sessionManager = SessionManager.open(sessionFile);
+      for (const message of [
+        makeUserMessage("hi"),
+        makeAssistantMessage("reading"),
+        makeToolResult("a".repeat(500)),
+        makeAssistantMessage("reading more"),
+        makeToolResult("b".repeat(500), "call_2"),
+        makeAssistantMessage("done"),
+      ]) {
+        sessionManager.appendMessage(message as Parameters<typeof sessionManager.appendMessage>[0]);
+      }
+
+      const result = await pruneStaleToolResultsInSession({
+        sessionFile,
+        contextWindowTokens: 200_000,
+        reserveTokens: 20_000,
+        settings,
+        force: true,
+      });
+      expect(result).toMatchObject({ pruned: true, prunedCount: 1 });
+      await expect(fs.access(`${sessionFile}.lock`)).rejects.toThrow();
+
+      const messages = SessionManager.open(sessionFile).buildSessionContext().messages;
+      expect(messages.map((message) => message.role)).toEqual([
+        "user",
+        "assistant",
+        "toolResult",
+        "assistant",
+        "toolResult",
+        "assistant",
+      ]);
+      const texts = messages
+        .filter((message) => message.role === "toolResult")
+        .map((message) => (message.content[0] as { text: string }).text);
+      expect(texts[0]).toContain("pruned to save context");
+      expect(texts[1]).toBe("b".repeat(500));
+    } finally {
+      await fs.rm(dir, { recursive: true, force: true });
+    }
+  });
+
+  it("skips sessions below the threshold unless forced", async () => {
+    const result = await pruneStaleToolResultsInSession({
+      sessionFile: path.join(os.tmpdir(), "openclaw-prune-missing.jsonl"),
+      contextWindowTokens: 200_000,
+      reserveTokens: 20_000,
+      settings,
+    });
+    expect(result).toEqual({ pruned: false, prunedCount: 0, reason: "below threshold" });
+  });
+});
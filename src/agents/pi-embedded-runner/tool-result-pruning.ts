@@ -0,0 +1,190 @@
+import type { AgentMessage } from "@mariozechner/pi-agent-core";
+import { SessionManager } from "@mariozechner/pi-coding-agent";
+import fs from "node:fs/promises";
+import type { OpenClawConfig } from "../../config/config.js";
+import { estimateMessagesTokens } from "../compaction.js";
+import { acquireSessionWriteLock } from "../session-write-lock.js";
+import { log } from "./logger.js";
+import { appendBranchEntries, getToolResultTextLength } from "./tool-result-truncation.js";
+
+/**
+ * Share of the compaction threshold (context window minus reserve) at which stale tool results
+ * are pruned ahead of a run, so the cheap pass usually runs before summarization would.
+ */
+const PRUNE_TRIGGER_SHARE = 0.8;
+
+export type ToolResultPruneSettings = {
+  /** Tool results produced after this many of the latest assistant messages are kept. */
+  keepLastAssistants: number;
+  /** Tool results with at least this much text (or any image) are pruned. */
+  minChars: number;
+};
+
+export const DEFAULT_TOOL_RESULT_PRUNE_SETTINGS: ToolResultPruneSettings = {
+  keepLastAssistants: 3,
+  minChars: 2_000,
+};
+
+export function resolveToolResultPruneSettings(
+  cfg?: OpenClawConfig,
+): ToolResultPruneSettings | null {
+  const raw = cfg?.agents?.defaults?.compaction?.pruneToolResults;
+  if (raw?.enabled === false) {
+    return null;
+  }
+  const settings = { ...DEFAULT_TOOL_RESULT_PRUNE_SETTINGS };
+  if (typeof raw?.keepLastAssistants === "number" && Number.isFinite(raw.keepLastAssistants)) {
+    settings.keepLastAssistants = Math.max(0, Math.floor(raw.keepLastAssistants));
+  }
+  if (typeof raw?.minChars === "number" && Number.isFinite(raw.minChars)) {
+    settings.minChars = Math.max(0, Math.floor(raw.minChars));
+  }
+  return settings;
+}
+
+function hasImageBlock(message: AgentMessage): boolean {
+  const content = (message as { content?: unknown }).content;
+  return (
+    Array.isArray(content) &&
+    content.some((block) => (block as { type?: unknown } | null)?.type === "image")
+  );
+}
+
+/** Indices of large tool results that come before the last `keepLastAssistants` assistant turns. */
+export function selectStaleToolResults(
+  messages: AgentMessage[],
+  settings: ToolResultPruneSettings,
+): number[] {
+  let cutoff = messages.length;
+  let assistants = 0;
+  for (let i = messages.length - 1; i >= 0 && assistants < settings.keepLastAssistants; i--) {
+    if ((messages[i] as { role?: unknown }).role === "assistant") {
+      assistants++;
+      cutoff = i;
+    }
+  }
+  if (assistants < settings.keepLastAssistants) {
+    return [];
+  }
+  const stale: number[] = [];
+  for (let i = 0; i < cutoff; i++) {
+    const message = messages[i];
+    if ((message as { role?: unknown }).role !== "toolResult") {
+      continue;
+    }
+    if (getToolResultTextLength(message) >= settings.minChars || hasImageBlock(message)) {
+      stale.push(i);
+    }
+  }
+  return stale;
+}
+
+/** Replaces a tool result's content with a short placeholder; the tool call itself is kept. */
+export function pruneToolResultMessage(message: AgentMessage): AgentMessage {
+  const toolName = (message as { toolName?: unknown }).toolName;
+  const label = typeof toolName === "string" && toolName ? toolName : "tool";
+  const chars = getToolResultTextLength(message);
+  const images = hasImageBlock(message) ? " and image(s)" : "";
+  const text =
+    `[Old ${label} result pruned to save context (${chars} chars${images}). ` +
+    "Run the tool again if you need this output.]";
+  return {
+    ...message,
+    content: [{ type: "text", text }],
+    details: undefined,
+  } as AgentMessage;
+}
+
+/**
+ * Cheap pass before compaction: replaces large, stale tool results in the session with
+ * placeholders, keeping every user and assistant message intact. Without `force`, it only acts
+ * once the estimated context nears the compaction threshold. Only entries after the latest
+ * compaction are rewritten, under the session write lock.
+ */
+export async function pruneStaleToolResultsInSession(params: {
+  sessionFile: string;
+  contextWindowTokens: number;
+  reserveTokens: number;
+  settings: ToolResultPruneSettings;
+  force?: boolean;
+  sessionId?: string;
+  sessionKey?: string;
+}): Promise<{
+  pruned: boolean;
+  prunedCount: number;
+  tokensBefore?: number;
+  tokensAfter?: number;
+  reason?: string;
+}> {
+  const sessionLabel = params.sessionKey ?? params.sessionId ?? "unknown";
+  const thresholdTokens = Math.floor(
+    Math.max(0, params.contextWindowTokens - params.reserveTokens) * PRUNE_TRIGGER_SHARE,
+  );
+  let sessionLock: Awaited<ReturnType<typeof acquireSessionWriteLock>> | undefined;
+  try {
+    if (!params.force) {
+      // The transcript is at least as large as the context it holds (~4 chars per token).
+      const stat = await fs.stat(params.sessionFile).catch(() => null);
+      if (!stat || stat.size / 4 < thresholdTokens) {
+        return { pruned: false, prunedCount: 0, reason: "below threshold" };
+      }
+    }
+
+    sessionLock = await acquireSessionWriteLock({ sessionFile: params.sessionFile });
+    const sessionManager = SessionManager.open(params.sessionFile);
+    const branch = sessionManager.getBranch();
+    const tokensBefore = estimateMessagesTokens(sessionManager.buildSessionContext().messages);
+    if (!params.force && tokensBefore < thresholdTokens) {
+      return { pruned: false, prunedCount: 0, tokensBefore, reason: "below threshold" };
+    }
+
+    let startIndex = 0;
+    for (let i = branch.length - 1; i >= 0; i--) {
+      if (branch[i].type === "compaction") {
+        startIndex = i + 1;
+        break;
+      }
+    }
+    const candidates: Array<{ index: number; message: AgentMessage }> = [];
+    for (let i = startIndex; i < branch.length; i++) {
+      const entry = branch[i];
+      if (entry.type === "message") {
+        candidates.push({ index: i, message: entry.message });
+      }
+    }
+    const stale = new Set(
+      selectStaleToolResults(
+        candidates.map((candidate) => candidate.message),
+        params.settings,
+      ).map((candidateIndex) => candidates[candidateIndex].index),
+    );
+    if (stale.size === 0) {
+      return { pruned: false, prunedCount: 0, tokensBefore, reason: "no stale tool results" };
+    }
+
+    const firstIndex = Math.min(...stale);
+    const branchFromId = branch[firstIndex].parentId;
+    if (!branchFromId) {
+      sessionManager.resetLeaf();
+    } else {
+      sessionManager.branch(branchFromId);
+    }
+    appendBranchEntries(sessionManager, branch, firstIndex, (message, index) =>
+      stale.has(index) ? pruneToolResultMessage(message) : message,
+    );
+
+    const tokensAfter = estimateMessagesTokens(sessionManager.buildSessionContext().messages);
+    log.info(
+      `[tool-result-pruning] Pruned ${stale.size} stale tool result(s) ` +
+        `(~${tokensBefore} -> ~${tokensAfter} tokens, force=${Boolean(params.force)}) ` +
+        `sessionKey=${sessionLabel}`,
+    );
+    return { pruned: true, prunedCount: stale.size, tokensBefore, tokensAfter };
+  } catch (err) {
+    const reason = err instanceof Error ? err.message : String(err);
+    log.warn(`[tool-result-pruning] Failed to prune: ${reason} sessionKey=${sessionLabel}`);
+    return { pruned: false, prunedCount: 0, reason };
+  } finally {
+    await sessionLock?.release();
+  }
+}
@@ -67,7 +67,7 @@ export function calculateMaxToolResultChars(contextWindowTokens: number): number
 /**
  * Get the total character count of text content blocks in a tool result message.
  */
-function getToolResultTextLength(msg: AgentMessage): number {
+export function getToolResultTextLength(msg: AgentMessage): number {
   if (!msg || (msg as { role?: string }).role !== "toolResult") {
     return 0;
   }
@@ -124,6 +124,61 @@ function truncateToolResultMessage(msg: AgentMessage, maxChars: number): AgentMe
   return { ...msg, content: newContent } as AgentMessage;
 }
 
+type SessionBranch = ReturnType<SessionManager["getBranch"]>;
+
+/**
+ * Re-appends branch entries from `startIndex` onwards after the caller has moved the leaf (via
+ * `branch`/`resetLeaf`), passing each message through `mapMessage`. Entries that reference other
+ * entry ids (branch summaries, labels) are dropped.
+ */
+export function appendBranchEntries(
+  sessionManager: SessionManager,
+  branch: SessionBranch,
+  startIndex: number,
+  mapMessage: (message: AgentMessage, index: number) => AgentMessage,
+): void {
+  for (let i = startIndex; i < branch.length; i++) {
+    const entry = branch[i];
+
+    if (entry.type === "message") {
+      const message = mapMessage(entry.message, i);
+      // appendMessage expects Message | CustomMessage | BashExecutionMessage
+      sessionManager.appendMessage(message as Parameters<typeof sessionManager.appendMessage>[0]);
+    } else if (entry.type === "compaction") {
+      sessionManager.appendCompaction(
+        entry.summary,
+        entry.firstKeptEntryId,
+        entry.tokensBefore,
+        entry.details,
+        entry.fromHook,
+      );
+    } else if (entry.type === "thinking_level_change") {
+      sessionManager.appendThinkingLevelChange(entry.thinkingLevel);
+    } else if (entry.type === "model_change") {
+      sessionManager.appendModelChange(entry.provider, entry.modelId);
+    } else if (entry.type === "custom") {
+      sessionManager.appendCustomEntry(entry.customType, entry.data);
+    } else if (entry.type === "custom_message") {
+      sessionManager.appendCustomMessageEntry(
+        entry.customType,
+        entry.content,
+        entry.display,
+        entry.details,
+      );
+    } else if (entry.type === "branch_summary") {
+      // Branch summaries reference specific entry IDs - skip to avoid inconsistency
+      continue;
+    } else if (entry.type === "label") {
+      // Labels reference specific entry IDs - skip to avoid inconsistency
+      continue;
+    } else if (entry.type === "session_info") {
+      if (entry.name) {
+        sessionManager.appendSessionInfo(entry.name);
+      }
+    }
+  }
+}
+
 /**
  * Find oversized tool result entries in a session and truncate them.
  *
@@ -195,58 +250,20 @@ export async function truncateOversizedToolResultsInSession(params: {
     const oversizedSet = new Set(oversizedIndices);
     let truncatedCount = 0;
 
-    for (let i = firstOversizedIdx; i < branch.length; i++) {
-      const entry = branch[i];
-
-      if (entry.type === "message") {
-        let message = entry.message;
-
-        if (oversizedSet.has(i)) {
-          message = truncateToolResultMessage(message, maxChars);
-          truncatedCount++;
-          const newLength = getToolResultTextLength(message);
-          log.info(
-            `[tool-result-truncation] Truncated tool result: ` +
-              `originalEntry=${entry.id} newChars=${newLength} ` +
-              `sessionKey=${params.sessionKey ?? params.sessionId ?? "unknown"}`,
-          );
-        }
-
-        // appendMessage expects Message | CustomMessage | BashExecutionMessage
-        sessionManager.appendMessage(message as Parameters<typeof sessionManager.appendMessage>[0]);
-      } else if (entry.type === "compaction") {
-        sessionManager.appendCompaction(
-          entry.summary,
-          entry.firstKeptEntryId,
-          entry.tokensBefore,
-          entry.details,
-          entry.fromHook,
-        );
-      } else if (entry.type === "thinking_level_change") {
-        sessionManager.appendThinkingLevelChange(entry.thinkingLevel);
-      } else if (entry.type === "model_change") {
-        sessionManager.appendModelChange(entry.provider, entry.modelId);
-      } else if (entry.type === "custom") {
-        sessionManager.appendCustomEntry(entry.customType, entry.data);
-      } else if (entry.type === "custom_message") {
-        sessionManager.appendCustomMessageEntry(
-          entry.customType,
-          entry.content,
-          entry.display,
-          entry.details,
-        );
-      } else if (entry.type === "branch_summary") {
-        // Branch summaries reference specific entry IDs - skip to avoid inconsistency
-        continue;
-      } else if (entry.type === "label") {
-        // Labels reference specific entry IDs - skip to avoid inconsistency
-        continue;
-      } else if (entry.type === "session_info") {
-        if (entry.name) {
-          sessionManager.appendSessionInfo(entry.name);
-        }
+    appendBranchEntries(sessionManager, branch, firstOversizedIdx, (message, i) => {
+      if (!oversizedSet.has(i)) {
+        return message;
       }
-    }
+      const truncated = truncateToolResultMessage(message, maxChars);
+      truncatedCount++;
+      const newLength = getToolResultTextLength(truncated);
+      log.info(
+        `[tool-result-truncation] Truncated tool result: ` +
+          `originalEntry=${branch[i].id} newChars=${newLength} ` +
+          `sessionKey=${params.sessionKey ?? params.sessionId ?? "unknown"}`,
+      );
+      return truncated;
+    });
 
     log.info(
       `[tool-result-truncation] Truncated ${truncatedCount} tool result(s) in session ` +
@@ -1,5 +1,6 @@
 import type { SessionSystemPromptReport } from "../../config/sessions/types.js";
 import type { MessagingToolSend } from "../pi-embedded-messaging.js";
+import type { RunTimings } from "../run-timings.js";
 
 export type EmbeddedPiAgentMeta = {
   sessionId: string;
@@ -13,6 +14,8 @@ export type EmbeddedPiAgentMeta = {
     cacheRead?: number;
     cacheWrite?: number;
     total?: number;
+    /** Reasoning tokens included in `output`, when the provider reports them. */
+    reasoning?: number;
   };
   /**
    * Usage from the last individual API call (not accumulated across tool-use
@@ -32,6 +35,8 @@ export type EmbeddedPiAgentMeta = {
 
 export type EmbeddedPiRunMeta = {
   durationMs: number;
+  /** Time to first token, model and tool time, and streaming throughput. */
+  timings?: RunTimings;
   agentMeta?: EmbeddedPiAgentMeta;
   aborted?: boolean;
   systemPromptReport?: SessionSystemPromptReport;
@@ -2,11 +2,13 @@ import type { AgentEvent, AgentMessage } from "@mariozechner/pi-agent-core";
 import type { EmbeddedPiSubscribeContext } from "./pi-embedded-subscribe.handlers.types.js";
 import { parseReplyDirectives } from "../auto-reply/reply/reply-directives.js";
 import { emitAgentEvent } from "../infra/agent-events.js";
+import { emitDiagnosticEvent, isDiagnosticsEnabled } from "../infra/diagnostic-events.js";
 import { createInlineCodeState } from "../markdown/code-spans.js";
 import {
   isMessagingToolDuplicateNormalized,
   normalizeTextForComparison,
 } from "./pi-embedded-helpers.js";
+import { handleToolCallArgsStream } from "./pi-embedded-subscribe.handlers.tools.js";
 import { appendRawStream } from "./pi-embedded-subscribe.raw-stream.js";
 import {
   extractAssistantText,
@@ -16,6 +18,7 @@ import {
   formatReasoningMessage,
   promoteThinkingTagsToBlocks,
 } from "./pi-embedded-utils.js";
+import { normalizeUsage, type UsageLike } from "./usage.js";
 
 const stripTrailingDirective = (text: string): string => {
   const openIndex = text.lastIndexOf("[[");
@@ -29,6 +32,32 @@ const stripTrailingDirective = (text: string): string => {
   return text.slice(0, openIndex);
 };
 
+function emitModelRequestDiagnostic(ctx: EmbeddedPiSubscribeContext, message: AgentMessage) {
+  const startedAt = ctx.state.assistantMessageStartedAt;
+  ctx.state.assistantMessageStartedAt = undefined;
+  if (!isDiagnosticsEnabled(ctx.params.config)) {
+    return;
+  }
+  const meta = message as {
+    provider?: string;
+    model?: string;
+    usage?: UsageLike;
+    stopReason?: string;
+    errorMessage?: string;
+  };
+  emitDiagnosticEvent({
+    type: "model.request",
+    runId: ctx.params.runId,
+    sessionKey: ctx.params.sessionKey,
+    provider: meta.provider,
+    model: meta.model,
+    usage: normalizeUsage(meta.usage) ?? {},
+    stopReason: meta.stopReason,
+    durationMs: startedAt !== undefined ? Date.now() - startedAt : undefined,
+    error: meta.stopReason === "error" ? meta.errorMessage : undefined,
+  });
+}
+
 export function handleMessageStart(
   ctx: EmbeddedPiSubscribeContext,
   evt: AgentEvent & { message: AgentMessage },
@@ -44,6 +73,7 @@ export function handleMessageStart(
   // may deliver late text_end updates after message_end, which would otherwise
   // re-trigger block replies.
   ctx.resetAssistantMessageState(ctx.state.assistantTexts.length);
+  ctx.state.assistantMessageStartedAt = Date.now();
   // Use assistant message_start as the earliest "writing" signal for typing.
   void ctx.params.onAssistantMessageStart?.();
 }
@@ -65,7 +95,14 @@ export function handleMessageUpdate(
       ? (assistantEvent as Record<string, unknown>)
       : undefined;
   const evtType = typeof assistantRecord?.type === "string" ? assistantRecord.type : "";
+  if (evtType.endsWith("_delta")) {
+    ctx.state.timings.firstToken();
+  }
 
+  if (assistantRecord && evtType.startsWith("toolcall_")) {
+    handleToolCallArgsStream(ctx, assistantRecord);
+    return;
+  }
   if (evtType !== "text_delta" && evtType !== "text_start" && evtType !== "text_end") {
     return;
   }
@@ -202,6 +239,10 @@ export function handleMessageEnd(
   const assistantMessage = msg;
   ctx.noteLastAssistant(assistantMessage);
   ctx.recordAssistantUsage((assistantMessage as { usage?: unknown }).usage);
+  ctx.state.timings.requestEnd({
+    outputTokens: normalizeUsage((assistantMessage as { usage?: UsageLike }).usage)?.output,
+  });
+  emitModelRequestDiagnostic(ctx, assistantMessage);
   promoteThinkingTagsToBlocks(assistantMessage);
 
   const rawText = extractAssistantText(assistantMessage);
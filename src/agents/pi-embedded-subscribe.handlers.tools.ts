@@ -2,6 +2,7 @@ import type { AgentEvent } from "@mariozechner/pi-agent-core";
 import type { PluginHookAfterToolCallEvent } from "../plugins/types.js";
 import type { EmbeddedPiSubscribeContext } from "./pi-embedded-subscribe.handlers.types.js";
 import { emitAgentEvent } from "../infra/agent-events.js";
+import { emitDiagnosticEvent, isDiagnosticsEnabled } from "../infra/diagnostic-events.js";
 import { getGlobalHookRunner } from "../plugins/hook-runner-global.js";
 import { normalizeTextForComparison } from "./pi-embedded-helpers.js";
 import { isMessagingTool, isMessagingToolSendAction } from "./pi-embedded-messaging.js";
@@ -40,6 +41,87 @@ function extendExecMeta(toolName: string, args: unknown, meta?: string): string
   return meta ? `${meta} · ${suffix}` : suffix;
 }
 
+function isCachedToolResult(result: unknown): boolean {
+  if (!result || typeof result !== "object") {
+    return false;
+  }
+  const details = (result as { details?: unknown }).details;
+  return Boolean(details && typeof details === "object" && "cached" in details && details.cached);
+}
+
+// Streaming args previews are throttled and their long strings cut; the full args follow on start.
+const TOOL_ARGS_PREVIEW_INTERVAL_MS = 150;
+const TOOL_ARGS_PREVIEW_MAX_CHARS = 500;
+
+function truncateToolArgsPreview(args: Record<string, unknown>): Record<string, unknown> {
+  const preview: Record<string, unknown> = {};
+  for (const [key, value] of Object.entries(args)) {
+    preview[key] =
+      typeof value === "string" && value.length > TOOL_ARGS_PREVIEW_MAX_CHARS
+        ? `${value.slice(0, TOOL_ARGS_PREVIEW_MAX_CHARS)}…`
+        : value;
+  }
+  return preview;
+}
+
+/**
+ * Emits `phase: "args"` tool events while the model is still streaming a tool call, so clients
+ * can show what is being composed (e.g. a shell command) before the call runs.
+ */
+export function handleToolCallArgsStream(
+  ctx: EmbeddedPiSubscribeContext,
+  assistantEvent: Record<string, unknown>,
+) {
+  const evtType = assistantEvent.type;
+  if (evtType !== "toolcall_start" && evtType !== "toolcall_delta" && evtType !== "toolcall_end") {
+    return;
+  }
+  const partial = assistantEvent.partial as { content?: unknown } | undefined;
+  const contentIndex = assistantEvent.contentIndex;
+  const block =
+    Array.isArray(partial?.content) && typeof contentIndex === "number"
+      ? (partial.content[contentIndex] as Record<string, unknown> | undefined)
+      : undefined;
+  const endCall = evtType === "toolcall_end" ? assistantEvent.toolCall : undefined;
+  const toolCall =
+    endCall && typeof endCall === "object" ? (endCall as Record<string, unknown>) : block;
+  if (!toolCall || toolCall.type !== "toolCall") {
+    return;
+  }
+  const toolCallId = typeof toolCall.id === "string" ? toolCall.id : "";
+  const rawName = typeof toolCall.name === "string" ? toolCall.name : "";
+  if (!toolCallId || !rawName) {
+    return;
+  }
+
+  const now = Date.now();
+  const lastAt = ctx.state.toolArgsPreviewAt.get(toolCallId);
+  if (
+    evtType === "toolcall_delta" &&
+    lastAt !== undefined &&
+    now - lastAt < TOOL_ARGS_PREVIEW_INTERVAL_MS
+  ) {
+    return;
+  }
+  ctx.state.toolArgsPreviewAt.set(toolCallId, now);
+
+  const args =
+    toolCall.arguments && typeof toolCall.arguments === "object"
+      ? truncateToolArgsPreview(toolCall.arguments as Record<string, unknown>)
+      : {};
+  emitAgentEvent({
+    runId: ctx.params.runId,
+    stream: "tool",
+    data: {
+      phase: "args",
+      name: normalizeToolName(rawName),
+      toolCallId,
+      args,
+      streaming: evtType !== "toolcall_end",
+    },
+  });
+}
+
 export async function handleToolExecutionStart(
   ctx: EmbeddedPiSubscribeContext,
   evt: AgentEvent & { toolName: string; toolCallId: string; args: unknown },
@@ -167,6 +249,10 @@ export async function handleToolExecutionEnd(
   const result = evt.result;
   const isToolError = isError || isToolResultError(result);
   const sanitizedResult = sanitizeToolResult(result);
+  const startData = toolStartData.get(toolCallId);
+  toolStartData.delete(toolCallId);
+  const durationMs = startData?.startTime != null ? Date.now() - startData.startTime : undefined;
+  ctx.state.timings.toolEnd(durationMs);
   const meta = ctx.state.toolMetaById.get(toolCallId);
   ctx.state.toolMetas.push({ toolName, meta });
   ctx.state.toolMetaById.delete(toolCallId);
@@ -226,20 +312,34 @@ export async function handleToolExecutionEnd(
   ctx.log.debug(
     `embedded run tool end: runId=${ctx.params.runId} tool=${toolName} toolCallId=${toolCallId}`,
   );
+  if (isDiagnosticsEnabled(ctx.params.config)) {
+    emitDiagnosticEvent({
+      type: "tool.execution",
+      runId: ctx.params.runId,
+      sessionKey: ctx.params.sessionKey,
+      toolName,
+      toolCallId,
+      durationMs,
+      outcome: isToolError ? "error" : "ok",
+      error: isToolError ? ctx.state.lastToolError?.error : undefined,
+    });
+  }
 
+  // Result-cache hits are surfaced in verbose mode so repeated calls are visible to the user.
+  const cached = isCachedToolResult(result);
+  const outputMeta = cached ? (meta ? `${meta} · cached` : "cached") : meta;
   if (ctx.params.onToolResult && ctx.shouldEmitToolOutput()) {
     const outputText = extractToolResultText(sanitizedResult);
     if (outputText) {
-      ctx.emitToolOutput(toolName, meta, outputText);
+      ctx.emitToolOutput(toolName, outputMeta, outputText);
     }
+  } else if (cached && ctx.params.onToolResult && ctx.shouldEmitToolResult()) {
+    ctx.emitToolSummary(toolName, outputMeta);
   }
 
   // Run after_tool_call plugin hook (fire-and-forget)
   const hookRunnerAfter = ctx.hookRunner ?? getGlobalHookRunner();
   if (hookRunnerAfter?.hasHooks("after_tool_call")) {
-    const startData = toolStartData.get(toolCallId);
-    toolStartData.delete(toolCallId);
-    const durationMs = startData?.startTime != null ? Date.now() - startData.startTime : undefined;
     const toolArgs = startData?.args;
     const hookEvent: PluginHookAfterToolCallEvent = {
       toolName,
@@ -257,7 +357,5 @@ export async function handleToolExecutionEnd(
       .catch((err) => {
         ctx.log.warn(`after_tool_call hook failed: tool=${toolName} error=${String(err)}`);
       });
-  } else {
-    toolStartData.delete(toolCallId);
   }
 }
@@ -50,6 +50,10 @@ export function createEmbeddedPiSessionEventHandler(ctx: EmbeddedPiSubscribeCont
       case "agent_start":
         handleAgentStart(ctx);
         return;
+      case "turn_start":
+        // Each turn is one model request (plus the tool calls it makes).
+        ctx.state.timings.requestStart();
+        return;
       case "auto_compaction_start":
         handleAutoCompactionStart(ctx);
         return;
@@ -9,6 +9,7 @@ import type {
   BlockReplyChunking,
   SubscribeEmbeddedPiSessionParams,
 } from "./pi-embedded-subscribe.types.js";
+import type { RunTimingsTracker } from "./run-timings.js";
 import type { NormalizedUsage } from "./usage.js";
 
 export type EmbeddedSubscribeLogger = {
@@ -27,6 +28,8 @@ export type EmbeddedPiSubscribeState = {
   toolMetas: Array<{ toolName?: string; meta?: string }>;
   toolMetaById: Map<string, string | undefined>;
   toolSummaryById: Set<string>;
+  /** Last time (ms) a streaming args preview was emitted, by tool call id. */
+  toolArgsPreviewAt: Map<string, number>;
   lastToolError?: ToolErrorSummary;
 
   blockReplyBreak: "text_end" | "message_end";
@@ -65,6 +68,9 @@ export type EmbeddedPiSubscribeState = {
   pendingMessagingTexts: Map<string, string>;
   pendingMessagingTargets: Map<string, MessagingToolSend>;
   lastAssistant?: AgentMessage;
+  /** When the current assistant message started streaming, for request timing. */
+  assistantMessageStartedAt?: number;
+  timings: RunTimingsTracker;
 };
 
 export type EmbeddedPiSubscribeContext = {
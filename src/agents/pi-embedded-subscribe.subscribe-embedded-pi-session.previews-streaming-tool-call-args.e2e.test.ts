@@ -0,0 +1,75 @@
+import { describe, expect, it } from "vitest";
+import { onAgentEvent } from "../infra/agent-events.js";
+import { subscribeEmbeddedPiSession } from "./pi-embedded-subscribe.js";
+
+type StubSession = {
+  subscribe: (fn: (evt: unknown) => void) => () => void;
+};
+
+describe("subscribeEmbeddedPiSession", () => {
+  it("previews tool call args while they stream", () => {
+    let handler: ((evt: unknown) => void) | undefined;
+    const session: StubSession = {
+      subscribe: (fn) => {
+        handler = fn;
+        return () => {};
+      },
+    };
+
+    const events: Array<Record<string, unknown>> = [];
+    const stop = onAgentEvent((evt) => {
+      if (evt.runId === "run-args" && evt.stream === "tool") {
+        events.push(evt.data);
+      }
+    });
+
+    subscribeEmbeddedPiSession({
+      session: session as unknown as Parameters<typeof subscribeEmbeddedPiSession>[0]["session"],
+      runId: "run-args",
+    });
+
+    const toolCall = (args: Record<string, unknown>) => ({
+      type: "toolCall",
+      id: "call-1",
+      name: "Bash",
+      arguments: args,
+    });
+    const update = (assistantMessageEvent: Record<string, unknown>) =>
+      handler?.({
+        type: "message_update",
+        message: { role: "assistant", content: [] },
+        assistantMessageEvent,
+      });
+
+    update({ type: "toolcall_start", contentIndex: 0, partial: { content: [toolCall({})] } });
+    // Deltas right after the previous preview are throttled.
+    update({
+      type: "toolcall_delta",
+      contentIndex: 0,
+      delta: '{"command":"rm',
+      partial: { content: [toolCall({ command: "rm" })] },
+    });
+    const longCommand = `echo ${"x".repeat(600)}`;
+    update({
+      type: "toolcall_end",
+      contentIndex: 0,
+      toolCall: toolCall({ command: longCommand }),
+      partial: { content: [toolCall({ command: longCommand })] },
+    });
+
+    stop();
+
+    expect(events).toHaveLength(2);
+    expect(events[0]).toMatchObject({
+      phase: "args",
+      name: "exec",
+      toolCallId: "call-1",
+      args: {},
+      streaming: true,
+    });
+    expect(events[1]).toMatchObject({ phase: "args", toolCallId: "call-1", streaming: false });
+    const command = (events[1]?.args as { command: string }).command;
+    expect(command.length).toBe(501);
+    expect(command.endsWith("…")).toBe(true);
+  });
+});
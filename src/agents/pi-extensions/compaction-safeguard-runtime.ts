@@ -1,6 +1,10 @@
+import type { Api, Model } from "@mariozechner/pi-ai";
+
 export type CompactionSafeguardRuntimeValue = {
   maxHistoryShare?: number;
   contextWindowTokens?: number;
+  /** Model used for summaries instead of the session model (agents.defaults.compaction.model). */
+  summarizerModel?: Model<Api>;
 };
 
 // Session-scoped runtime registry keyed by object identity.
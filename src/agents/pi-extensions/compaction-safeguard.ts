@@ -170,7 +170,18 @@ export default function compactionSafeguardExtension(api: ExtensionAPI): void {
     const toolFailureSection = formatToolFailuresSection(toolFailures);
     const fallbackSummary = `${FALLBACK_SUMMARY}${toolFailureSection}${fileOpsSummary}`;
 
-    const model = ctx.model;
+    const runtime = getCompactionSafeguardRuntime(ctx.sessionManager);
+    const summarizerModel = runtime?.summarizerModel;
+    const summarizerApiKey = summarizerModel
+      ? await ctx.modelRegistry.getApiKey(summarizerModel)
+      : undefined;
+    if (summarizerModel && !summarizerApiKey) {
+      console.warn(
+        `Compaction safeguard: no API key for summarizer model ` +
+          `${summarizerModel.provider}/${summarizerModel.id}; using the session model.`,
+      );
+    }
+    const model = summarizerApiKey ? summarizerModel : ctx.model;
     if (!model) {
       return {
         compaction: {
@@ -182,7 +193,7 @@ export default function compactionSafeguardExtension(api: ExtensionAPI): void {
       };
     }
 
-    const apiKey = await ctx.modelRegistry.getApiKey(model);
+    const apiKey = summarizerApiKey ?? (await ctx.modelRegistry.getApiKey(model));
     if (!apiKey) {
       return {
         compaction: {
@@ -195,9 +206,15 @@ export default function compactionSafeguardExtension(api: ExtensionAPI): void {
     }
 
     try {
-      const runtime = getCompactionSafeguardRuntime(ctx.sessionManager);
-      const modelContextWindow = resolveContextWindowTokens(model);
-      const contextWindowTokens = runtime?.contextWindowTokens ?? modelContextWindow;
+      const contextWindowTokens =
+        runtime?.contextWindowTokens ?? resolveContextWindowTokens(ctx.model ?? model);
+      // Chunks must fit the summarizer, which may have a smaller window than the session model.
+      const summaryWindowTokens = Math.min(contextWindowTokens, resolveContextWindowTokens(model));
+      // Summary output is capped from the reserve; keep it within what the summarizer can emit.
+      const summaryReserveTokens = Math.max(
+        1,
+        Math.min(Math.floor(preparation.settings.reserveTokens), model.maxTokens || Infinity),
+      );
       const turnPrefixMessages = preparation.turnPrefixMessages ?? [];
       let messagesToSummarize = preparation.messagesToSummarize;
 
@@ -239,20 +256,20 @@ export default function compactionSafeguardExtension(api: ExtensionAPI): void {
               try {
                 const droppedChunkRatio = computeAdaptiveChunkRatio(
                   pruned.droppedMessagesList,
-                  contextWindowTokens,
+                  summaryWindowTokens,
                 );
                 const droppedMaxChunkTokens = Math.max(
                   1,
-                  Math.floor(contextWindowTokens * droppedChunkRatio),
+                  Math.floor(summaryWindowTokens * droppedChunkRatio),
                 );
                 droppedSummary = await summarizeInStages({
                   messages: pruned.droppedMessagesList,
                   model,
                   apiKey,
                   signal,
-                  reserveTokens: Math.max(1, Math.floor(preparation.settings.reserveTokens)),
+                  reserveTokens: summaryReserveTokens,
                   maxChunkTokens: droppedMaxChunkTokens,
-                  contextWindow: contextWindowTokens,
+                  contextWindow: summaryWindowTokens,
                   customInstructions,
                   previousSummary: preparation.previousSummary,
                 });
@@ -270,9 +287,8 @@ export default function compactionSafeguardExtension(api: ExtensionAPI): void {
 
       // Use adaptive chunk ratio based on message sizes
       const allMessages = [...messagesToSummarize, ...turnPrefixMessages];
-      const adaptiveRatio = computeAdaptiveChunkRatio(allMessages, contextWindowTokens);
-      const maxChunkTokens = Math.max(1, Math.floor(contextWindowTokens * adaptiveRatio));
-      const reserveTokens = Math.max(1, Math.floor(preparation.settings.reserveTokens));
+      const adaptiveRatio = computeAdaptiveChunkRatio(allMessages, summaryWindowTokens);
+      const maxChunkTokens = Math.max(1, Math.floor(summaryWindowTokens * adaptiveRatio));
 
       // Feed dropped-messages summary as previousSummary so the main summarization
       // incorporates context from pruned messages instead of losing it entirely.
@@ -283,9 +299,9 @@ export default function compactionSafeguardExtension(api: ExtensionAPI): void {
         model,
         apiKey,
         signal,
-        reserveTokens,
+        reserveTokens: summaryReserveTokens,
         maxChunkTokens,
-        contextWindow: contextWindowTokens,
+        contextWindow: summaryWindowTokens,
         customInstructions,
         previousSummary: effectivePreviousSummary,
       });
@@ -297,9 +313,9 @@ export default function compactionSafeguardExtension(api: ExtensionAPI): void {
           model,
           apiKey,
           signal,
-          reserveTokens,
+          reserveTokens: summaryReserveTokens,
           maxChunkTokens,
-          contextWindow: contextWindowTokens,
+          contextWindow: summaryWindowTokens,
           customInstructions: TURN_PREFIX_INSTRUCTIONS,
           previousSummary: undefined,
         });
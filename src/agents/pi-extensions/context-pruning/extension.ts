@@ -31,6 +31,7 @@ export default function contextPruningExtension(api: ExtensionAPI): void {
     if (next === event.messages) {
       return undefined;
     }
+    runtime.onPrune?.();
 
     if (runtime.settings.mode === "cache-ttl") {
       runtime.lastCacheTouchAt = Date.now();
@@ -5,6 +5,8 @@ export type ContextPruningRuntimeValue = {
   contextWindowTokens?: number | null;
   isToolPrunable: (toolName: string) => boolean;
   lastCacheTouchAt?: number | null;
+  /** Called after tool results were pruned from the context sent to the model. */
+  onPrune?: () => void;
 };
 
 // Session-scoped runtime registry keyed by object identity.
@@ -1,7 +1,9 @@
 import { describe, expect, it, vi } from "vitest";
 import {
+  applyPiCompactionSettings,
   DEFAULT_PI_COMPACTION_RESERVE_TOKENS_FLOOR,
   ensurePiCompactionReserveTokens,
+  resolveCompactionReserveTokens,
   resolveCompactionReserveTokensFloor,
 } from "./pi-settings.js";
 
@@ -54,3 +56,42 @@ describe("resolveCompactionReserveTokensFloor", () => {
     ).toBe(0);
   });
 });
+
+describe("resolveCompactionReserveTokens", () => {
+  it("turns a threshold percent into a reserve above the floor", () => {
+    const cfg = { agents: { defaults: { compaction: { thresholdPercent: 60 } } } };
+    expect(resolveCompactionReserveTokens({ cfg, contextWindowTokens: 200_000 })).toBe(80_000);
+    // The floor still wins when it is larger.
+    expect(resolveCompactionReserveTokens({ cfg, contextWindowTokens: 32_000 })).toBe(
+      DEFAULT_PI_COMPACTION_RESERVE_TOKENS_FLOOR,
+    );
+  });
+
+  it("uses the floor without a threshold", () => {
+    expect(resolveCompactionReserveTokens({ contextWindowTokens: 200_000 })).toBe(
+      DEFAULT_PI_COMPACTION_RESERVE_TOKENS_FLOOR,
+    );
+  });
+});
+
+describe("applyPiCompactionSettings", () => {
+  it("applies the threshold reserve and keepRecentTokens", () => {
+    const settingsManager = {
+      getCompactionReserveTokens: () => 16_384,
+      applyOverrides: vi.fn(),
+    };
+
+    const result = applyPiCompactionSettings({
+      settingsManager,
+      cfg: {
+        agents: { defaults: { compaction: { thresholdPercent: 75, keepRecentTokens: 40_000 } } },
+      },
+      contextWindowTokens: 200_000,
+    });
+
+    expect(result).toEqual({ reserveTokens: 50_000, keepRecentTokens: 40_000 });
+    expect(settingsManager.applyOverrides).toHaveBeenLastCalledWith({
+      compaction: { reserveTokens: 50_000, keepRecentTokens: 40_000 },
+    });
+  });
+});
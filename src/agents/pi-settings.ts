@@ -4,7 +4,9 @@ export const DEFAULT_PI_COMPACTION_RESERVE_TOKENS_FLOOR = 20_000;
 
 type PiSettingsManagerLike = {
   getCompactionReserveTokens: () => number;
-  applyOverrides: (overrides: { compaction: { reserveTokens: number } }) => void;
+  applyOverrides: (overrides: {
+    compaction: { reserveTokens: number; keepRecentTokens?: number };
+  }) => void;
 };
 
 export function ensurePiCompactionReserveTokens(params: {
@@ -32,3 +34,63 @@ export function resolveCompactionReserveTokensFloor(cfg?: OpenClawConfig): numbe
   }
   return DEFAULT_PI_COMPACTION_RESERVE_TOKENS_FLOOR;
 }
+
+export function resolveCompactionThresholdPercent(cfg?: OpenClawConfig): number | undefined {
+  const raw = cfg?.agents?.defaults?.compaction?.thresholdPercent;
+  if (typeof raw === "number" && Number.isFinite(raw) && raw > 0 && raw < 100) {
+    return raw;
+  }
+  return undefined;
+}
+
+/**
+ * Pi compacts once context exceeds `contextWindow - reserveTokens`, so a threshold percentage is
+ * expressed as a larger reserve. The reserve never drops below `reserveTokens`.
+ */
+export function applyCompactionThresholdPercent(params: {
+  reserveTokens: number;
+  contextWindowTokens: number;
+  thresholdPercent?: number;
+}): number {
+  if (params.thresholdPercent === undefined) {
+    return params.reserveTokens;
+  }
+  const contextWindow = Math.max(1, Math.floor(params.contextWindowTokens));
+  const thresholdReserve = Math.ceil(contextWindow * (1 - params.thresholdPercent / 100));
+  return Math.max(params.reserveTokens, thresholdReserve);
+}
+
+export function resolveCompactionReserveTokens(params: {
+  cfg?: OpenClawConfig;
+  contextWindowTokens: number;
+}): number {
+  return applyCompactionThresholdPercent({
+    reserveTokens: resolveCompactionReserveTokensFloor(params.cfg),
+    contextWindowTokens: params.contextWindowTokens,
+    thresholdPercent: resolveCompactionThresholdPercent(params.cfg),
+  });
+}
+
+export function resolveCompactionKeepRecentTokens(cfg?: OpenClawConfig): number | undefined {
+  const raw = cfg?.agents?.defaults?.compaction?.keepRecentTokens;
+  if (typeof raw === "number" && Number.isFinite(raw) && raw > 0) {
+    return Math.floor(raw);
+  }
+  return undefined;
+}
+
+export function applyPiCompactionSettings(params: {
+  settingsManager: PiSettingsManagerLike;
+  cfg?: OpenClawConfig;
+  contextWindowTokens: number;
+}): { reserveTokens: number; keepRecentTokens?: number } {
+  const keepRecentTokens = resolveCompactionKeepRecentTokens(params.cfg);
+  const { reserveTokens } = ensurePiCompactionReserveTokens({
+    settingsManager: params.settingsManager,
+    minReserveTokens: resolveCompactionReserveTokens(params),
+  });
+  if (keepRecentTokens !== undefined) {
+    params.settingsManager.applyOverrides({ compaction: { reserveTokens, keepRecentTokens } });
+  }
+  return { reserveTokens, keepRecentTokens };
+}
@@ -0,0 +1,131 @@
+import { describe, expect, it, vi } from "vitest";
+import type { AnyAgentTool } from "./pi-tools.types.js";
+import { findEditMatch, reindentEditText, wrapEditMatchMode } from "./pi-tools.edit-match.js";
+
+const FILE = [
+  "function greet(name) {",
+  "    if (!name) {",
+  "        return 'hi';",
+  "    }",
+  "    return `hello ${name}`;",
+  "}",
+].join("\n");
+
+describe("findEditMatch", () => {
+  it("matches regardless of indentation and trailing spaces", () => {
+    const match = findEditMatch({
+      content: FILE,
+      oldText: "if (!name) {  \n  return 'hi';\n}",
+      mode: "ignore-leading-whitespace",
+    });
+    expect(match).toMatchObject({ startLine: 2, endLine: 4, similarity: 1 });
+    expect(match.text).toBe("    if (!name) {\n        return 'hi';\n    }");
+  });
+
+  it("rejects near misses unless fuzzy", () => {
+    const oldText = "if (!name) {\n  return 'hey';\n}";
+    expect(() =>
+      findEditMatch({ content: FILE, oldText, mode: "ignore-leading-whitespace" }),
+    ).toThrow(/No region matches/);
+    const match = findEditMatch({ content: FILE, oldText, mode: "fuzzy", threshold: 0.8 });
+    expect(match.startLine).toBe(2);
+    expect(match.similarity).toBeGreaterThan(0.8);
+    expect(match.similarity).toBeLessThan(1);
+  });
+
+  it("enforces the similarity threshold", () => {
+    expect(() =>
+      findEditMatch({ content: FILE, oldText: "return `bye ${who}`;", mode: "fuzzy" }),
+    ).toThrow(/at least 90% similar/);
+  });
+
+  it("refuses ambiguous regions", () => {
+    expect(() =>
+      findEditMatch({ content: "  a = 1;\nb\n\ta = 1;", oldText: "a = 1;", mode: "fuzzy" }),
+    ).toThrow(/matches 2 regions equally well \(lines 1-1, 3-3\)/);
+  });
+});
+
+describe("reindentEditText", () => {
+  it("shifts replacement lines to the matched indentation", () => {
+    expect(reindentEditText("if (x) {\n  y();\n}", "if (w) {", "    if (w) {")).toBe(
+      "    if (x) {\n      y();\n    }",
+    );
+  });
+});
+
+describe("wrapEditMatchMode", () => {
+  const createTool = () => {
+    const execute = vi.fn(async () => ({
+      content: [{ type: "text" as const, text: "Successfully replaced text." }],
+      details: { diff: "" },
+    }));
+    const tool = {
+      name: "edit",
+      label: "edit",
+      description: "edit",
+      parameters: { type: "object", properties: { path: { type: "string" } } },
+      execute,
+    } as unknown as AnyAgentTool;
+    return { tool, execute };
+  };
+
+  it("passes exact edits through untouched", async () => {
+    const { tool, execute } = createTool();
+    const readFile = vi.fn(async () => FILE);
+    const wrapped = wrapEditMatchMode(tool, { root: "/ws", readFile });
+    await wrapped.execute("call", { path: "a.js", oldText: "hi", newText: "yo" });
+    expect(readFile).not.toHaveBeenCalled();
+    expect(execute).toHaveBeenCalledWith(
+      "call",
+      { path: "a.js", oldText: "hi", newText: "yo" },
+      undefined,
+      undefined,
+    );
+    expect(Object.keys((wrapped.parameters as { properties: object }).properties)).toEqual([
+      "path",
+      "matchMode",
+      "similarityThreshold",
+    ]);
+  });
+
+  it("resolves oldText to the file region and echoes it back", async () => {
+    const { tool, execute } = createTool();
+    const readFile = vi.fn(async () => Buffer.from(FILE.replace(/\n/g, "\r\n")));
+    const wrapped = wrapEditMatchMode(tool, { root: "/ws", readFile });
+    const result = await wrapped.execute("call", {
+      path: "src/a.js",
+      oldText: "if (!name) {\n    return 'hi';\n}",
+      newText: "if (!name) {\n    return 'hey';\n}",
+      matchMode: "ignore-leading-whitespace",
+    });
+    expect(readFile).toHaveBeenCalledWith("/ws/src/a.js");
+    expect(execute).toHaveBeenCalledWith(
+      "call",
+      {
+        path: "src/a.js",
+        oldText: "    if (!name) {\n        return 'hi';\n    }",
+        newText: "    if (!name) {\n        return 'hey';\n    }",
+      },
+      undefined,
+      undefined,
+    );
+    const texts = result.content.map((block) => (block as { text: string }).text);
+    expect(texts[1]).toBe(
+      "Replaced lines 2-4 (ignore-leading-whitespace):\n" +
+        "    if (!name) {\n        return 'hi';\n    }",
+    );
+    expect(result.details).toMatchObject({
+      diff: "",
+      match: { mode: "ignore-leading-whitespace", startLine: 2, endLine: 4, similarity: 1 },
+    });
+  });
+
+  it("rejects unknown match modes", async () => {
+    const { tool } = createTool();
+    const wrapped = wrapEditMatchMode(tool, { root: "/ws", readFile: async () => FILE });
+    await expect(
+      wrapped.execute("call", { path: "a.js", oldText: "x", newText: "y", matchMode: "loose" }),
+    ).rejects.toThrow(/matchMode must be one of/);
+  });
+});
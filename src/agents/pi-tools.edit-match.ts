@@ -0,0 +1,270 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import { Type } from "@sinclair/typebox";
+import type { AnyAgentTool } from "./pi-tools.types.js";
+import { resolveToCwd } from "./sandbox-paths.js";
+import { optionalStringEnum } from "./schema/typebox.js";
+
+export const EDIT_MATCH_MODES = ["exact", "ignore-leading-whitespace", "fuzzy"] as const;
+export type EditMatchMode = (typeof EDIT_MATCH_MODES)[number];
+
+const DEFAULT_SIMILARITY_THRESHOLD = 0.9;
+const MIN_SIMILARITY_THRESHOLD = 0.5;
+
+export type EditMatch = {
+  /** Exact file text of the matched region (LF line endings). */
+  text: string;
+  /** 1-based, inclusive line range of the region. */
+  startLine: number;
+  endLine: number;
+  similarity: number;
+};
+
+function splitLines(text: string): string[] {
+  const lines = text.split("\n");
+  while (lines.length > 1 && lines[lines.length - 1].trim() === "") {
+    lines.pop();
+  }
+  return lines;
+}
+
+function leadingWhitespace(line: string): string {
+  return line.match(/^[ \t]*/)?.[0] ?? "";
+}
+
+function boundedLevenshtein(a: string, b: string, maxDistance: number): number | null {
+  if (a === b) {
+    return 0;
+  }
+  if (Math.abs(a.length - b.length) > maxDistance) {
+    return null;
+  }
+  if (!a || !b) {
+    return Math.max(a.length, b.length);
+  }
+  let prev = Array.from({ length: b.length + 1 }, (_, idx) => idx);
+  let curr = Array.from({ length: b.length + 1 }, () => 0);
+  for (let i = 1; i <= a.length; i++) {
+    curr[0] = i;
+    let rowMin = i;
+    const aChar = a.charCodeAt(i - 1);
+    for (let j = 1; j <= b.length; j++) {
+      const cost = aChar === b.charCodeAt(j - 1) ? 0 : 1;
+      curr[j] = Math.min(prev[j] + 1, curr[j - 1] + 1, prev[j - 1] + cost);
+      rowMin = Math.min(rowMin, curr[j]);
+    }
+    if (rowMin > maxDistance) {
+      return null;
+    }
+    [prev, curr] = [curr, prev];
+  }
+  const distance = prev[b.length];
+  return distance > maxDistance ? null : distance;
+}
+
+function formatCandidates(matches: EditMatch[]) {
+  return matches
+    .slice(0, 5)
+    .map((match) => `${match.startLine}-${match.endLine}`)
+    .join(", ");
+}
+
+/**
+ * Locate the region of `content` that `oldText` refers to, comparing whole lines.
+ * `ignore-leading-whitespace` requires every line to match once surrounding whitespace is trimmed;
+ * `fuzzy` picks the most similar run of lines at or above `threshold` (0..1, edit-distance based).
+ */
+export function findEditMatch(params: {
+  content: string;
+  oldText: string;
+  mode: Exclude<EditMatchMode, "exact">;
+  threshold?: number;
+}): EditMatch {
+  const fileLines = params.content.split("\n");
+  const wanted = splitLines(params.oldText.replace(/\r\n/g, "\n")).map((line) => line.trim());
+  const span = wanted.length;
+  const wantedChars = wanted.reduce((sum, line) => sum + line.length, 0);
+  if (wantedChars === 0) {
+    throw new Error("oldText must contain non-whitespace characters");
+  }
+  const requested = params.threshold ?? DEFAULT_SIMILARITY_THRESHOLD;
+  const threshold =
+    params.mode === "fuzzy" ? Math.min(1, Math.max(MIN_SIMILARITY_THRESHOLD, requested)) : 1;
+  // Distance budget across the whole window; windows blow through it on the first few lines.
+  const budget = Math.floor((1 - threshold) * wantedChars);
+
+  const matches: EditMatch[] = [];
+  for (let start = 0; start + span <= fileLines.length; start++) {
+    let distance = 0;
+    let windowChars = 0;
+    for (let offset = 0; offset < span; offset++) {
+      const line = fileLines[start + offset].trim();
+      windowChars += line.length;
+      const lineDistance = boundedLevenshtein(wanted[offset], line, budget - distance);
+      if (lineDistance === null) {
+        distance = Number.POSITIVE_INFINITY;
+        break;
+      }
+      distance += lineDistance;
+    }
+    if (!Number.isFinite(distance)) {
+      continue;
+    }
+    const similarity = 1 - distance / Math.max(wantedChars, windowChars);
+    if (similarity < threshold) {
+      continue;
+    }
+    matches.push({
+      text: fileLines.slice(start, start + span).join("\n"),
+      startLine: start + 1,
+      endLine: start + span,
+      similarity,
+    });
+  }
+
+  if (matches.length === 0) {
+    const percent = Math.round(threshold * 100);
+    throw new Error(
+      params.mode === "fuzzy"
+        ? `No region of ${span} line(s) is at least ${percent}% similar to oldText`
+        : "No region matches oldText after ignoring leading/trailing whitespace",
+    );
+  }
+  const best = Math.max(...matches.map((match) => match.similarity));
+  const top = matches.filter((match) => match.similarity === best);
+  if (top.length > 1) {
+    throw new Error(
+      `oldText matches ${top.length} regions equally well (lines ${formatCandidates(top)}); ` +
+        "add more surrounding context",
+    );
+  }
+  return top[0];
+}
+
+/**
+ * Shift `newText` by the indentation difference between the model's oldText and the matched
+ * region so replacements land at the file's real indentation.
+ */
+export function reindentEditText(newText: string, oldText: string, matched: string): string {
+  const firstLine = (text: string) => text.split("\n").find((line) => line.trim()) ?? "";
+  const from = leadingWhitespace(firstLine(oldText));
+  const to = leadingWhitespace(firstLine(matched));
+  if (from === to) {
+    return newText;
+  }
+  return newText
+    .split("\n")
+    .map((line) => {
+      if (!line.trim() || !line.startsWith(from)) {
+        return line;
+      }
+      return to + line.slice(from.length);
+    })
+    .join("\n");
+}
+
+function readMatchMode(value: unknown): EditMatchMode {
+  if (value === undefined || value === null || value === "") {
+    return "exact";
+  }
+  if (typeof value === "string" && (EDIT_MATCH_MODES as readonly string[]).includes(value)) {
+    return value as EditMatchMode;
+  }
+  throw new Error(`matchMode must be one of: ${EDIT_MATCH_MODES.join(", ")}`);
+}
+
+function normalizeFileText(raw: Buffer | string): string {
+  const text = typeof raw === "string" ? raw : raw.toString("utf8");
+  return text.replace(/^\uFEFF/, "").replace(/\r\n/g, "\n");
+}
+
+/**
+ * Add `matchMode` / `similarityThreshold` to an edit tool. Non-exact modes resolve the model's
+ * oldText to the exact file region first, then hand off to the wrapped tool for the replacement
+ * and echo the region back so the model can verify what changed.
+ */
+export function wrapEditMatchMode(
+  tool: AnyAgentTool,
+  params: { root: string; readFile: (absolutePath: string) => Promise<Buffer | string> },
+): AnyAgentTool {
+  const schema = (tool.parameters ?? {}) as Record<string, unknown>;
+  const properties = (schema.properties ?? {}) as Record<string, unknown>;
+  return {
+    ...tool,
+    parameters: {
+      ...schema,
+      properties: {
+        ...properties,
+        matchMode: optionalStringEnum(EDIT_MATCH_MODES, {
+          description:
+            "How oldText is located: exact (default), ignore-leading-whitespace (indentation and trailing spaces may differ), or fuzzy (closest region above similarityThreshold).",
+        }),
+        similarityThreshold: Type.Optional(
+          Type.Number({
+            description: `Minimum similarity (0.5-1) for fuzzy matching (default ${DEFAULT_SIMILARITY_THRESHOLD}).`,
+            minimum: MIN_SIMILARITY_THRESHOLD,
+            maximum: 1,
+          }),
+        ),
+      },
+    },
+    execute: async (toolCallId, args, signal, onUpdate) => {
+      const record =
+        args && typeof args === "object" ? { ...(args as Record<string, unknown>) } : undefined;
+      const mode = readMatchMode(record?.matchMode);
+      const threshold =
+        typeof record?.similarityThreshold === "number" ? record.similarityThreshold : undefined;
+      if (record) {
+        delete record.matchMode;
+        delete record.similarityThreshold;
+      }
+      if (
+        mode === "exact" ||
+        !record ||
+        typeof record.path !== "string" ||
+        typeof record.oldText !== "string"
+      ) {
+        return tool.execute(toolCallId, record ?? args, signal, onUpdate);
+      }
+
+      const oldText = record.oldText;
+      const absolutePath = resolveToCwd(record.path, params.root);
+      const content = normalizeFileText(await params.readFile(absolutePath));
+      if (content.split(oldText.replace(/\r\n/g, "\n")).length === 2) {
+        // Already an exact, unique match; nothing to resolve.
+        return tool.execute(toolCallId, record, signal, onUpdate);
+      }
+      const match = findEditMatch({ content, oldText, mode, threshold });
+      const newText =
+        typeof record.newText === "string"
+          ? reindentEditText(record.newText, oldText, match.text)
+          : record.newText;
+      const result = (await tool.execute(
+        toolCallId,
+        { ...record, oldText: match.text, newText },
+        signal,
+        onUpdate,
+      )) as AgentToolResult<unknown>;
+
+      const similarity = mode === "fuzzy" ? `, similarity ${match.similarity.toFixed(2)}` : "";
+      const echo =
+        `Replaced lines ${match.startLine}-${match.endLine} (${mode}${similarity}):\n` + match.text;
+      const details =
+        result.details && typeof result.details === "object"
+          ? (result.details as Record<string, unknown>)
+          : {};
+      return {
+        ...result,
+        content: [...(result.content ?? []), { type: "text", text: echo }],
+        details: {
+          ...details,
+          match: {
+            mode,
+            startLine: match.startLine,
+            endLine: match.endLine,
+            similarity: match.similarity,
+          },
+        },
+      };
+    },
+  };
+}
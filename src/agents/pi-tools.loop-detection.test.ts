@@ -0,0 +1,56 @@
+import { describe, expect, it, vi } from "vitest";
+import type { AnyAgentTool } from "./tools/common.js";
+import { wrapToolsWithLoopDetection } from "./pi-tools.loop-detection.js";
+
+function fakeTool(name: string, fail = false) {
+  const execute = vi.fn(async () => {
+    if (fail) {
+      throw new Error(`${name} failed`);
+    }
+    return { content: [{ type: "text" as const, text: "ok" }], details: { ok: true } };
+  });
+  const tool = { name, label: name, description: name, parameters: {}, execute };
+  return { tool: tool as unknown as AnyAgentTool, execute };
+}
+
+describe("tool loop detection", () => {
+  it("refuses the call after maxRepeats identical calls in a row", async () => {
+    const exec = fakeTool("exec");
+    const [tool] = wrapToolsWithLoopDetection([exec.tool], { maxRepeats: 2 });
+
+    await tool.execute("1", { command: "ls" });
+    await tool.execute("2", { command: "ls" });
+    const blocked = await tool.execute("3", { command: "ls" });
+    expect(exec.execute).toHaveBeenCalledTimes(2);
+    expect(blocked.details).toMatchObject({ status: "error", loopDetected: true });
+    expect((blocked.content[0] as { text: string }).text).toMatch(/Loop detected/);
+
+    // Different arguments break the streak.
+    await tool.execute("4", { command: "pwd" });
+    await tool.execute("5", { command: "ls" });
+    expect(exec.execute).toHaveBeenCalledTimes(4);
+  });
+
+  it("refuses alternating failing calls but exempts process polling", async () => {
+    const read = fakeTool("read", true);
+    const edit = fakeTool("edit", true);
+    const processTool = fakeTool("process");
+    const [readTool, editTool, pollTool] = wrapToolsWithLoopDetection(
+      [read.tool, edit.tool, processTool.tool],
+      { maxRepeats: 2 },
+    );
+
+    for (const id of ["1", "3"]) {
+      await expect(readTool.execute(id, { path: "a.ts" })).rejects.toThrow(/failed/);
+      await expect(editTool.execute(`${id}b`, { path: "a.ts" })).rejects.toThrow(/failed/);
+    }
+    const blocked = await readTool.execute("5", { path: "a.ts" });
+    expect(blocked.details).toMatchObject({ loopDetected: true });
+    expect(read.execute).toHaveBeenCalledTimes(2);
+
+    for (const id of ["p1", "p2", "p3", "p4"]) {
+      await pollTool.execute(id, { action: "poll", sessionId: "s1" });
+    }
+    expect(processTool.execute).toHaveBeenCalledTimes(4);
+  });
+});
@@ -0,0 +1,113 @@
+import type { AnyAgentTool } from "./tools/common.js";
+import { createSubsystemLogger } from "../logging/subsystem.js";
+import { hashToolArgs } from "./pi-tools.result-cache.js";
+import { normalizeToolName } from "./tool-policy.js";
+
+const log = createSubsystemLogger("agents/tools");
+
+export const DEFAULT_LOOP_MAX_REPEATS = 3;
+const MAX_HISTORY = 20;
+// Polling a background session with identical arguments is expected behavior.
+const EXEMPT_TOOLS = new Set(["process", "bash_output"]);
+
+type CallRecord = {
+  toolName: string;
+  hash: string;
+  failed: boolean;
+};
+
+export type ToolLoopDetectionOptions = {
+  /** Identical consecutive calls allowed before the next one is refused (default: 3). */
+  maxRepeats?: number;
+  sessionKey?: string;
+};
+
+function isFailedResult(result: unknown): boolean {
+  const details = (result as { details?: { status?: unknown } } | undefined)?.details;
+  return details?.status === "error";
+}
+
+export function detectToolLoop(
+  history: CallRecord[],
+  next: { toolName: string; hash: string },
+  maxRepeats: number,
+): string | null {
+  let repeats = 0;
+  for (let i = history.length - 1; i >= 0 && history[i]?.hash === next.hash; i--) {
+    repeats += 1;
+  }
+  if (repeats >= maxRepeats) {
+    return `${next.toolName} was called with identical arguments ${repeats} times in a row`;
+  }
+  const recent = history.slice(-4);
+  const [a1, b1, a2, b2] = recent;
+  if (
+    a1 &&
+    b1 &&
+    a2 &&
+    b2 &&
+    a1.hash === next.hash &&
+    a2.hash === next.hash &&
+    b1.hash === b2.hash &&
+    b1.hash !== next.hash &&
+    recent.every((entry) => entry.failed)
+  ) {
+    return `${a1.toolName} and ${b1.toolName} keep failing in alternation with the same arguments`;
+  }
+  return null;
+}
+
+/**
+ * Refuses tool calls that repeat the previous call verbatim too often, or that bounce between two
+ * failing calls, and returns a notice to the model instead of executing them again.
+ */
+export function wrapToolsWithLoopDetection(
+  tools: AnyAgentTool[],
+  options?: ToolLoopDetectionOptions,
+): AnyAgentTool[] {
+  const maxRepeats = Math.max(1, options?.maxRepeats ?? DEFAULT_LOOP_MAX_REPEATS);
+  const history: CallRecord[] = [];
+  const record = (entry: CallRecord) => {
+    history.push(entry);
+    if (history.length > MAX_HISTORY) {
+      history.shift();
+    }
+  };
+
+  return tools.map((tool) => {
+    const execute = tool.execute;
+    const toolName = normalizeToolName(tool.name);
+    if (!execute || EXEMPT_TOOLS.has(toolName)) {
+      return tool;
+    }
+    return {
+      ...tool,
+      execute: async (toolCallId, params, signal, onUpdate) => {
+        const hash = hashToolArgs(toolName, params);
+        const loop = detectToolLoop(history, { toolName, hash }, maxRepeats);
+        if (loop) {
+          record({ toolName, hash, failed: true });
+          const session = options?.sessionKey ? ` session=${options.sessionKey}` : "";
+          log.warn(`tool loop detected: ${loop}${session}`);
+          return {
+            content: [
+              {
+                type: "text",
+                text: `[system notice] Loop detected: ${loop}. This call was not executed. Do not repeat it; change your approach, or stop and tell the user what is blocking you.`,
+              },
+            ],
+            details: { status: "error", loopDetected: true, reason: loop },
+          };
+        }
+        try {
+          const result = await execute(toolCallId, params, signal, onUpdate);
+          record({ toolName, hash, failed: isFailedResult(result) });
+          return result;
+        } catch (err) {
+          record({ toolName, hash, failed: true });
+          throw err;
+        }
+      },
+    };
+  });
+}
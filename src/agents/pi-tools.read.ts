@@ -1,8 +1,10 @@
 import type { AgentToolResult } from "@mariozechner/pi-agent-core";
 import { createEditTool, createReadTool, createWriteTool } from "@mariozechner/pi-coding-agent";
+import fs from "node:fs/promises";
 import type { AnyAgentTool } from "./pi-tools.types.js";
 import type { SandboxFsBridge } from "./sandbox/fs-bridge.js";
 import { detectMime } from "../media/mime.js";
+import { wrapEditMatchMode } from "./pi-tools.edit-match.js";
 import { assertSandboxPath } from "./sandbox-paths.js";
 import { sanitizeToolResultImages } from "./tool-images.js";
 
@@ -146,6 +148,15 @@ export function normalizeToolParams(params: unknown): Record<string, unknown> |
     normalized.newText = normalized.new_string;
     delete normalized.new_string;
   }
+  // match_mode → matchMode, similarity_threshold → similarityThreshold (edit)
+  if ("match_mode" in normalized && !("matchMode" in normalized)) {
+    normalized.matchMode = normalized.match_mode;
+    delete normalized.match_mode;
+  }
+  if ("similarity_threshold" in normalized && !("similarityThreshold" in normalized)) {
+    normalized.similarityThreshold = normalized.similarity_threshold;
+    delete normalized.similarity_threshold;
+  }
   return normalized;
 }
 
@@ -169,6 +180,8 @@ export function patchToolSchemaForClaudeCompatibility(tool: AnyAgentTool): AnyAg
     { original: "path", alias: "file_path" },
     { original: "oldText", alias: "old_string" },
     { original: "newText", alias: "new_string" },
+    { original: "matchMode", alias: "match_mode" },
+    { original: "similarityThreshold", alias: "similarity_threshold" },
   ];
 
   for (const { original, alias } of aliasPairs) {
@@ -295,12 +308,26 @@ export function createSandboxedEditTool(params: SandboxToolParams) {
   const base = createEditTool(params.root, {
     operations: createSandboxEditOperations(params),
   }) as unknown as AnyAgentTool;
+  const matching = wrapEditMatchMode(base, {
+    root: params.root,
+    readFile: (absolutePath) =>
+      params.bridge.readFile({ filePath: absolutePath, cwd: params.root }),
+  });
   return wrapSandboxPathGuard(
-    wrapToolParamNormalization(base, CLAUDE_PARAM_GROUPS.edit),
+    wrapToolParamNormalization(matching, CLAUDE_PARAM_GROUPS.edit),
     params.root,
   );
 }
 
+export function createOpenClawEditTool(root: string): AnyAgentTool {
+  const base = createEditTool(root) as unknown as AnyAgentTool;
+  const matching = wrapEditMatchMode(base, {
+    root,
+    readFile: (absolutePath) => fs.readFile(absolutePath),
+  });
+  return wrapToolParamNormalization(matching, CLAUDE_PARAM_GROUPS.edit);
+}
+
 export function createOpenClawReadTool(base: AnyAgentTool): AnyAgentTool {
   const patched = patchToolSchemaForClaudeCompatibility(base);
   return {
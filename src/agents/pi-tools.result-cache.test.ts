@@ -0,0 +1,100 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { AnyAgentTool } from "./tools/common.js";
+import {
+  hashToolArgs,
+  noteToolResultsDropped,
+  wrapToolsWithResultCache,
+} from "./pi-tools.result-cache.js";
+
+function fakeTool(name: string) {
+  const execute = vi.fn(async (toolCallId: string) => ({
+    content: [{ type: "text" as const, text: `output of ${toolCallId}` }],
+    details: { ok: true },
+  }));
+  const tool = { name, label: name, description: name, parameters: {}, execute };
+  return { tool: tool as unknown as AnyAgentTool, execute };
+}
+
+function textOf(result: { content: unknown[] }) {
+  return (result.content[0] as { text: string }).text;
+}
+
+describe("tool result cache", () => {
+  let dir: string;
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-result-cache-"));
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  it("answers identical reads with a pointer until the file changes", async () => {
+    const file = path.join(dir, "a.txt");
+    await fs.writeFile(file, "one");
+    const read = fakeTool("read");
+    const [tool] = wrapToolsWithResultCache([read.tool], { cwd: dir });
+
+    await tool.execute("call-1", { path: "a.txt", offset: 1 });
+    const hit = await tool.execute("call-2", { offset: 1, path: "a.txt" });
+    expect(read.execute).toHaveBeenCalledTimes(1);
+    expect(textOf(hit)).toContain("call-1");
+    expect(hit.details).toMatchObject({ cached: true, cachedToolCallId: "call-1" });
+
+    await fs.writeFile(file, "two, longer");
+    const miss = await tool.execute("call-3", { path: "a.txt", offset: 1 });
+    expect(textOf(miss)).toBe("output of call-3");
+    expect(read.execute).toHaveBeenCalledTimes(2);
+  });
+
+  it("drops search results after writes and replays output after compaction", async () => {
+    const grep = fakeTool("grep");
+    const write = fakeTool("write");
+    const [grepTool, writeTool] = wrapToolsWithResultCache([grep.tool, write.tool], {
+      cwd: dir,
+      sessionKey: "agent:main:cache-test",
+    });
+
+    await grepTool.execute("g1", { pattern: "TODO" });
+    noteToolResultsDropped("agent:main:cache-test");
+    const replay = await grepTool.execute("g2", { pattern: "TODO" });
+    expect(textOf(replay)).toBe("output of g1");
+    expect(replay.details).toMatchObject({ cached: true });
+    expect(textOf(await grepTool.execute("g3", { pattern: "TODO" }))).toContain("g2");
+
+    await writeTool.execute("w1", { path: "b.txt", content: "x" });
+    expect(textOf(await grepTool.execute("g4", { pattern: "TODO" }))).toBe("output of g4");
+    expect(grep.execute).toHaveBeenCalledTimes(2);
+  });
+
+  it("treats unknown tools as mutating and skips directory hits while processes run", async () => {
+    const ls = fakeTool("ls");
+    const plugin = fakeTool("deploy_site");
+    let running = false;
+    const [lsTool, pluginTool] = wrapToolsWithResultCache([ls.tool, plugin.tool], {
+      cwd: dir,
+      hasRunningProcesses: () => running,
+    });
+
+    await lsTool.execute("l1", {});
+    expect(textOf(await lsTool.execute("l2", {}))).toContain("l1");
+    await pluginTool.execute("p1", {});
+    expect(textOf(await lsTool.execute("l3", {}))).toBe("output of l3");
+
+    running = true;
+    expect(textOf(await lsTool.execute("l4", {}))).toBe("output of l4");
+    expect(textOf(await lsTool.execute("l5", {}))).toBe("output of l5");
+    expect(ls.execute).toHaveBeenCalledTimes(4);
+  });
+
+  it("hashes arguments independent of key order", () => {
+    expect(hashToolArgs("read", { a: 1, b: [1, { c: 2, d: 3 }] })).toBe(
+      hashToolArgs("Read", { b: [1, { d: 3, c: 2 }], a: 1 }),
+    );
+    expect(hashToolArgs("read", { a: 1 })).not.toBe(hashToolArgs("grep", { a: 1 }));
+  });
+});
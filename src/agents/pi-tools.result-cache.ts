@@ -0,0 +1,212 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import crypto from "node:crypto";
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { SandboxFsBridge } from "./sandbox/fs-bridge.js";
+import type { AnyAgentTool } from "./tools/common.js";
+import { listAllRunningSessions } from "./bash-process-registry.js";
+import { normalizeToolName } from "./tool-policy.js";
+
+const CACHEABLE_TOOLS = new Set(["read", "grep", "find", "ls", "web_fetch"]);
+// Tools known not to touch the workspace. Any other call (including plugin tools) may change
+// files, so directory-wide results (grep/find/ls) are dropped after it.
+const READ_ONLY_TOOLS = new Set([
+  ...CACHEABLE_TOOLS,
+  "web_search",
+  "image",
+  "memory_search",
+  "memory_get",
+  "codebase_search",
+  "session_status",
+  "sessions_list",
+  "sessions_history",
+  "agents_list",
+]);
+const WEB_FETCH_TTL_MS = 5 * 60_000;
+const MAX_ENTRIES = 200;
+
+// Bumped on compaction and context pruning: earlier tool results may no longer be in context.
+const contextEpochBySession = new Map<string, number>();
+
+export function noteToolResultsDropped(sessionKey?: string) {
+  if (!sessionKey) {
+    return;
+  }
+  contextEpochBySession.set(sessionKey, (contextEpochBySession.get(sessionKey) ?? 0) + 1);
+}
+
+/** Forgets a session's epoch once the session is reset or deleted. */
+export function clearToolResultCacheSession(sessionKey?: string) {
+  if (sessionKey) {
+    contextEpochBySession.delete(sessionKey);
+  }
+}
+
+function stableStringify(value: unknown): string {
+  if (value === null || typeof value !== "object") {
+    return JSON.stringify(value) ?? "null";
+  }
+  if (Array.isArray(value)) {
+    return `[${value.map((entry) => stableStringify(entry)).join(",")}]`;
+  }
+  const record = value as Record<string, unknown>;
+  const keys = Object.keys(record)
+    .filter((key) => record[key] !== undefined)
+    .toSorted();
+  const entries = keys.map((key) => `${JSON.stringify(key)}:${stableStringify(record[key])}`);
+  return `{${entries.join(",")}}`;
+}
+
+export function hashToolArgs(toolName: string, args: unknown): string {
+  const digest = crypto.createHash("sha256").update(stableStringify(args ?? {})).digest("hex");
+  return `${normalizeToolName(toolName)}:${digest.slice(0, 32)}`;
+}
+
+type CacheEntry = {
+  toolCallId: string;
+  result: AgentToolResult<unknown>;
+  fingerprint: string;
+  epoch: number;
+  storedAt: number;
+};
+
+export type ToolResultCacheOptions = {
+  sessionKey?: string;
+  /** Host directory used to resolve relative read paths. */
+  cwd: string;
+  sandbox?: { root: string; bridge: SandboxFsBridge };
+  now?: () => number;
+  /** Background processes may change files at any time; defaults to the exec process registry. */
+  hasRunningProcesses?: () => boolean;
+};
+
+function readPathFromArgs(args: unknown): string | undefined {
+  if (!args || typeof args !== "object") {
+    return undefined;
+  }
+  const record = args as Record<string, unknown>;
+  const raw = record.path ?? record.file_path;
+  return typeof raw === "string" && raw.trim() ? raw.trim() : undefined;
+}
+
+function isErrorResult(result: AgentToolResult<unknown>): boolean {
+  const details = result.details as { status?: unknown; error?: unknown } | undefined;
+  return details?.status === "error" || typeof details?.error === "string";
+}
+
+/**
+ * Wraps read/grep/find/ls/web_fetch so that an identical call within the same run returns a short
+ * pointer to the earlier result instead of repeating the output. Reads are keyed by file mtime and
+ * size; directory-wide results are invalidated by any call outside the read-only set and are never
+ * served from cache while a background process is running.
+ */
+export function wrapToolsWithResultCache(
+  tools: AnyAgentTool[],
+  options: ToolResultCacheOptions,
+): AnyAgentTool[] {
+  const entries = new Map<string, CacheEntry>();
+  let mutationGeneration = 0;
+  const now = options.now ?? Date.now;
+  const hasRunningProcesses =
+    options.hasRunningProcesses ?? (() => listAllRunningSessions().length > 0);
+
+  const currentEpoch = () =>
+    options.sessionKey ? (contextEpochBySession.get(options.sessionKey) ?? 0) : 0;
+
+  const fingerprint = async (toolName: string, args: unknown): Promise<string | null> => {
+    if (toolName === "web_fetch") {
+      return "web";
+    }
+    if (toolName !== "read") {
+      return hasRunningProcesses() ? null : `gen:${mutationGeneration}`;
+    }
+    const filePath = readPathFromArgs(args);
+    if (!filePath) {
+      return null;
+    }
+    try {
+      if (options.sandbox) {
+        const stat = await options.sandbox.bridge.stat({
+          filePath,
+          cwd: options.sandbox.root,
+        });
+        return stat ? `file:${stat.mtimeMs}:${stat.size}` : null;
+      }
+      const stat = await fs.stat(path.resolve(options.cwd, filePath));
+      return `file:${stat.mtimeMs}:${stat.size}`;
+    } catch {
+      return null;
+    }
+  };
+
+  return tools.map((tool) => {
+    const execute = tool.execute;
+    const toolName = normalizeToolName(tool.name);
+    if (!execute) {
+      return tool;
+    }
+    if (!READ_ONLY_TOOLS.has(toolName)) {
+      return {
+        ...tool,
+        execute: async (...args: Parameters<typeof execute>) => {
+          mutationGeneration += 1;
+          return await execute(...args);
+        },
+      };
+    }
+    if (!CACHEABLE_TOOLS.has(toolName)) {
+      return tool;
+    }
+    return {
+      ...tool,
+      execute: async (toolCallId, params, signal, onUpdate) => {
+        const key = hashToolArgs(toolName, params);
+        const print = await fingerprint(toolName, params);
+        const cached = entries.get(key);
+        const fresh =
+          cached &&
+          print !== null &&
+          cached.fingerprint === print &&
+          (toolName !== "web_fetch" || now() - cached.storedAt < WEB_FETCH_TTL_MS);
+        if (cached && fresh && cached.epoch === currentEpoch()) {
+          return {
+            content: [
+              {
+                type: "text",
+                text: `[cached] Unchanged since the identical ${toolName} call ${cached.toolCallId}; reuse that result.`,
+              },
+            ],
+            details: { cached: true, cachedToolCallId: cached.toolCallId },
+          };
+        }
+        if (cached && fresh) {
+          // Compacted or pruned since: hand back the stored output in full and point future hits here.
+          entries.set(key, { ...cached, toolCallId, epoch: currentEpoch() });
+          const details = cached.result.details;
+          return details && typeof details === "object"
+            ? { ...cached.result, details: { ...details, cached: true } }
+            : cached.result;
+        }
+
+        const result = await execute(toolCallId, params, signal, onUpdate);
+        if (print !== null && !isErrorResult(result)) {
+          entries.delete(key);
+          entries.set(key, {
+            toolCallId,
+            result,
+            fingerprint: print,
+            epoch: currentEpoch(),
+            storedAt: now(),
+          });
+          if (entries.size > MAX_ENTRIES) {
+            const oldest = entries.keys().next().value;
+            if (oldest !== undefined) {
+              entries.delete(oldest);
+            }
+          }
+        }
+        return result;
+      },
+    };
+  });
+}
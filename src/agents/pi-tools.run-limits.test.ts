@@ -0,0 +1,76 @@
+import { describe, expect, it, vi } from "vitest";
+import type { AnyAgentTool } from "./tools/common.js";
+import {
+  createAgentRunLimitTracker,
+  resolveAgentRunLimits,
+  wrapToolsWithRunLimits,
+} from "./pi-tools.run-limits.js";
+
+function fakeTool(name: string) {
+  const execute = vi.fn(async () => ({
+    content: [{ type: "text" as const, text: "ok" }],
+    details: { ok: true },
+  }));
+  const tool = { name, label: name, description: name, parameters: {}, execute };
+  return { tool: tool as unknown as AnyAgentTool, execute };
+}
+
+describe("run limits", () => {
+  it("resolves config defaults with overrides taking precedence", () => {
+    const config = { agents: { defaults: { maxTurns: 20, maxCostUsd: 1 } } };
+    expect(resolveAgentRunLimits({ config })).toEqual({
+      maxTurns: 20,
+      maxDurationMs: undefined,
+      maxCostUsd: 1,
+    });
+    expect(
+      resolveAgentRunLimits({ config, overrides: { maxTurns: 5, maxDurationSeconds: 90 } }),
+    ).toEqual({ maxTurns: 5, maxDurationMs: 90_000, maxCostUsd: 1 });
+    expect(resolveAgentRunLimits({ config: {} })).toBeUndefined();
+  });
+
+  it("refuses tool calls once the turn limit is reached and asks for a summary", async () => {
+    const tracker = createAgentRunLimitTracker({ limits: { maxTurns: 2 } });
+    const exec = fakeTool("exec");
+    const [tool] = wrapToolsWithRunLimits([exec.tool], tracker);
+
+    tracker.recordTurn();
+    await tool.execute("1", { command: "ls" });
+    expect(exec.execute).toHaveBeenCalledTimes(1);
+
+    tracker.recordTurn();
+    const refused = await tool.execute("2", { command: "ls" });
+    expect(exec.execute).toHaveBeenCalledTimes(1);
+    expect(refused.details).toMatchObject({ status: "error", runLimitReached: true });
+    expect((refused.content[0] as { text: string }).text).toMatch(
+      /turn limit reached \(2\/2 model turns\).*final summary/,
+    );
+
+    // The model gets a couple of turns to answer before the run is stopped.
+    tracker.recordTurn();
+    tracker.recordTurn();
+    expect(tracker.shouldStop()).toBe(false);
+    tracker.recordTurn();
+    expect(tracker.shouldStop()).toBe(true);
+  });
+
+  it("tracks elapsed time and estimated cost", () => {
+    let now = 0;
+    const timed = createAgentRunLimitTracker({
+      limits: { maxDurationMs: 60_000 },
+      now: () => now,
+    });
+    expect(timed.exceeded()).toBeNull();
+    now = 61_000;
+    expect(timed.exceeded()).toBe("time limit reached (60s)");
+
+    const costly = createAgentRunLimitTracker({
+      limits: { maxCostUsd: 0.5 },
+      cost: { input: 3, output: 15, cacheRead: 0, cacheWrite: 0 },
+    });
+    costly.recordTurn({ input: 100_000, output: 5_000 });
+    expect(costly.exceeded()).toBeNull();
+    costly.recordTurn({ input: 100_000, output: 5_000 });
+    expect(costly.exceeded()).toBe("cost limit reached ($0.75 of $0.50)");
+  });
+});
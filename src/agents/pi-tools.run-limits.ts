@@ -0,0 +1,134 @@
+import type { OpenClawConfig } from "../config/config.js";
+import type { AnyAgentTool } from "./tools/common.js";
+import { createSubsystemLogger } from "../logging/subsystem.js";
+import { estimateUsageCost, formatUsd, type ModelCostConfig } from "../utils/usage-format.js";
+import { normalizeUsage, type UsageLike } from "./usage.js";
+
+const log = createSubsystemLogger("agents/tools");
+
+/** Extra model turns allowed after a limit is hit before the run is stopped outright. */
+export const RUN_LIMIT_GRACE_TURNS = 2;
+
+export type AgentRunLimits = {
+  maxTurns?: number;
+  maxDurationMs?: number;
+  maxCostUsd?: number;
+};
+
+export type AgentRunLimitTracker = {
+  /** Records one finished model turn (assistant message) and its usage. */
+  recordTurn: (usage?: UsageLike | null) => void;
+  /** Describes the first limit that has been reached, or null while within limits. */
+  exceeded: () => string | null;
+  /** True once the model kept going for the grace turns after being told to wrap up. */
+  shouldStop: () => boolean;
+};
+
+function positive(value: number | undefined): number | undefined {
+  return typeof value === "number" && Number.isFinite(value) && value > 0 ? value : undefined;
+}
+
+/**
+ * Resolves per-run limits from `agents.defaults.{maxTurns,maxDurationSeconds,maxCostUsd}`, with
+ * explicit overrides (e.g. CLI flags) taking precedence.
+ */
+export function resolveAgentRunLimits(params: {
+  config?: OpenClawConfig;
+  overrides?: { maxTurns?: number; maxDurationSeconds?: number; maxCostUsd?: number };
+}): AgentRunLimits | undefined {
+  const defaults = params.config?.agents?.defaults;
+  const maxTurns = positive(params.overrides?.maxTurns ?? defaults?.maxTurns);
+  const maxDurationSeconds = positive(
+    params.overrides?.maxDurationSeconds ?? defaults?.maxDurationSeconds,
+  );
+  const maxCostUsd = positive(params.overrides?.maxCostUsd ?? defaults?.maxCostUsd);
+  if (!maxTurns && !maxDurationSeconds && !maxCostUsd) {
+    return undefined;
+  }
+  return {
+    maxTurns: maxTurns ? Math.floor(maxTurns) : undefined,
+    maxDurationMs: maxDurationSeconds ? maxDurationSeconds * 1000 : undefined,
+    maxCostUsd,
+  };
+}
+
+export function createAgentRunLimitTracker(params: {
+  limits: AgentRunLimits;
+  cost?: ModelCostConfig;
+  now?: () => number;
+}): AgentRunLimitTracker {
+  const now = params.now ?? Date.now;
+  const startedAt = now();
+  let turns = 0;
+  let costUsd = 0;
+  let exceededAtTurn: number | null = null;
+
+  const exceeded = () => {
+    const { maxTurns, maxDurationMs, maxCostUsd } = params.limits;
+    let reason: string | null = null;
+    if (maxTurns && turns >= maxTurns) {
+      reason = `turn limit reached (${turns}/${maxTurns} model turns)`;
+    } else if (maxDurationMs && now() - startedAt >= maxDurationMs) {
+      reason = `time limit reached (${Math.round(maxDurationMs / 1000)}s)`;
+    } else if (maxCostUsd && costUsd >= maxCostUsd) {
+      reason = `cost limit reached (${formatUsd(costUsd)} of ${formatUsd(maxCostUsd)})`;
+    }
+    if (reason && exceededAtTurn === null) {
+      exceededAtTurn = turns;
+    }
+    return reason;
+  };
+
+  return {
+    recordTurn: (usage) => {
+      turns += 1;
+      costUsd += estimateUsageCost({ usage: normalizeUsage(usage), cost: params.cost }) ?? 0;
+    },
+    exceeded,
+    shouldStop: () => {
+      exceeded();
+      return exceededAtTurn !== null && turns > exceededAtTurn + RUN_LIMIT_GRACE_TURNS;
+    },
+  };
+}
+
+/**
+ * Once a run limit is reached, refuses further tool calls and tells the model to stop and reply
+ * with a final summary, so the turn ends with an answer instead of being cut off.
+ */
+export function wrapToolsWithRunLimits(
+  tools: AnyAgentTool[],
+  tracker: AgentRunLimitTracker,
+  options?: { sessionKey?: string },
+): AnyAgentTool[] {
+  let warned = false;
+  return tools.map((tool) => {
+    const execute = tool.execute;
+    if (!execute) {
+      return tool;
+    }
+    return {
+      ...tool,
+      execute: async (toolCallId, params, signal, onUpdate) => {
+        const reason = tracker.exceeded();
+        if (!reason) {
+          return await execute(toolCallId, params, signal, onUpdate);
+        }
+        if (!warned) {
+          warned = true;
+          const session = options?.sessionKey ? ` session=${options.sessionKey}` : "";
+          log.warn(`run limit: ${reason}${session}`);
+        }
+        return {
+          content: [
+            {
+              type: "text",
+              text: `[system notice] Run ${reason}. This call was not executed and no further tools will run. Reply now with a final summary: what you completed, what remains, and how to continue.`,
+            },
+          ],
+          details: { status: "error", runLimitReached: true, reason },
+        };
+      },
+    };
+  });
+}
@@ -0,0 +1,44 @@
+import type { OpenClawConfig } from "../config/config.js";
+import type { AgentRunLimitTracker } from "./pi-tools.run-limits.js";
+import type { AnyAgentTool } from "./pi-tools.types.js";
+import type { WorkspaceToolSandbox } from "./tools/fs-walk.js";
+import { wrapToolsWithLoopDetection } from "./pi-tools.loop-detection.js";
+import { wrapToolsWithResultCache } from "./pi-tools.result-cache.js";
+import { wrapToolsWithRunLimits } from "./pi-tools.run-limits.js";
+
+/**
+ * Per-run call bookkeeping: the result cache innermost, then loop detection, then run limits, so
+ * a refused or limited call never reaches the cache.
+ */
+export function wrapToolsForRun(
+  tools: AnyAgentTool[],
+  params: {
+    config?: OpenClawConfig;
+    sessionKey?: string;
+    workspaceRoot: string;
+    sandbox?: WorkspaceToolSandbox;
+    runLimits?: AgentRunLimitTracker;
+  },
+): AnyAgentTool[] {
+  const withCache =
+    params.config?.tools?.resultCache?.enabled === false
+      ? tools
+      : wrapToolsWithResultCache(tools, {
+          sessionKey: params.sessionKey,
+          cwd: params.workspaceRoot,
+          sandbox: params.sandbox,
+        });
+  const loopDetection = params.config?.tools?.loopDetection;
+  const withLoopDetection =
+    loopDetection?.enabled === false
+      ? withCache
+      : wrapToolsWithLoopDetection(withCache, {
+          maxRepeats: loopDetection?.maxRepeats,
+          sessionKey: params.sessionKey,
+        });
+  return params.runLimits
+    ? wrapToolsWithRunLimits(withLoopDetection, params.runLimits, {
+        sessionKey: params.sessionKey,
+      })
+    : withLoopDetection;
+}
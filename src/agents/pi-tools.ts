@@ -1,12 +1,12 @@
 import {
   codingTools,
-  createEditTool,
   createReadTool,
   createWriteTool,
   readTool,
 } from "@mariozechner/pi-coding-agent";
 import type { OpenClawConfig } from "../config/config.js";
 import type { ModelAuthMode } from "./model-auth.js";
+import type { AgentRunLimitTracker } from "./pi-tools.run-limits.js";
 import type { AnyAgentTool } from "./pi-tools.types.js";
 import type { SandboxContext } from "./sandbox.js";
 import { logWarn } from "../logger.js";
@@ -15,7 +15,9 @@ import { isSubagentSessionKey } from "../routing/session-key.js";
 import { resolveGatewayMessageChannel } from "../utils/message-channel.js";
 import { resolveAgentConfig } from "./agent-scope.js";
 import { createApplyPatchTool } from "./apply-patch.js";
+import { resolveExecScopeKey } from "./bash-tools.cwd.js";
 import {
+  createBackgroundTaskTools,
   createExecTool,
   createProcessTool,
   type ExecToolDefaults,
@@ -23,8 +25,12 @@ import {
 } from "./bash-tools.js";
 import { listChannelAgentTools } from "./channel-tools.js";
 import { createOpenClawTools } from "./openclaw-tools.js";
+import { filterToolsForPlanMode } from "./plan-mode.js";
 import { wrapToolWithAbortSignal } from "./pi-tools.abort.js";
 import { wrapToolWithBeforeToolCallHook } from "./pi-tools.before-tool-call.js";
+import { wrapToolsForRun } from "./pi-tools.run-wrappers.js";
+import { wrapToolWithInputValidation } from "./pi-tools.validate.js";
+import { createWorkspaceTools } from "./pi-tools.workspace.js";
 import {
   isToolAllowedByPolicies,
   resolveEffectiveToolPolicy,
@@ -34,6 +40,7 @@ import {
 import {
   assertRequiredParams,
   CLAUDE_PARAM_GROUPS,
+  createOpenClawEditTool,
   createOpenClawReadTool,
   createSandboxedEditTool,
   createSandboxedReadTool,
@@ -50,8 +57,11 @@ import {
 import {
   applyOwnerOnlyToolPolicy,
   collectExplicitAllowlist,
+  expandToolGroups,
+  normalizeToolName,
   resolveToolProfilePolicy,
 } from "./tool-policy.js";
+import { createRunCodeTool } from "./tools/run-code-tool.js";
 
 function isOpenAIProvider(provider?: string) {
   const normalized = provider?.trim().toLowerCase();
@@ -104,6 +114,11 @@ function resolveExecConfig(params: { cfg?: OpenClawConfig; agentId?: string }) {
       agentExec?.approvalRunningNoticeMs ?? globalExec?.approvalRunningNoticeMs,
     cleanupMs: agentExec?.cleanupMs ?? globalExec?.cleanupMs,
     notifyOnExit: agentExec?.notifyOnExit ?? globalExec?.notifyOnExit,
+    trackCwd: agentExec?.trackCwd ?? globalExec?.trackCwd,
+    env: agentExec?.env ?? globalExec?.env,
+    scrubEnv: agentExec?.scrubEnv ?? globalExec?.scrubEnv,
+    sandbox: agentExec?.sandbox ?? globalExec?.sandbox,
+    persistentShell: agentExec?.persistentShell ?? globalExec?.persistentShell,
     applyPatch: agentExec?.applyPatch ?? globalExec?.applyPatch,
   };
 }
@@ -128,6 +143,12 @@ export function createOpenClawCodingTools(options?: {
   workspaceDir?: string;
   config?: OpenClawConfig;
   abortSignal?: AbortSignal;
+  /** Turn/time/cost budget for the current run; tools are refused once it is spent. */
+  runLimits?: AgentRunLimitTracker;
+  /** Plan mode: only read-only tools are exposed while the model drafts a plan. */
+  planMode?: boolean;
+  /** Tool names (or `group:*` names) turned off for the session with `/tools disable`. */
+  disabledTools?: string[];
   /**
    * Provider of the currently selected model (used for provider-specific tool quirks).
    * Example: "anthropic", "openai", "google", "openai-codex".
@@ -219,7 +240,7 @@ export function createOpenClawCodingTools(options?: {
   // Prefer sessionKey for process isolation scope to prevent cross-session process visibility/killing.
   // Fallback to agentId if no sessionKey is available (e.g. legacy or global contexts).
   const scopeKey =
-    options?.exec?.scopeKey ?? options?.sessionKey ?? (agentId ? `agent:${agentId}` : undefined);
+    options?.exec?.scopeKey ?? resolveExecScopeKey({ sessionKey: options?.sessionKey, agentId });
   const subagentPolicy =
     isSubagentSessionKey(options?.sessionKey) && options?.sessionKey
       ? resolveSubagentToolPolicy(options.config)
@@ -253,6 +274,9 @@ export function createOpenClawCodingTools(options?: {
   if (sandboxRoot && !sandboxFsBridge) {
     throw new Error("Sandbox filesystem bridge is unavailable.");
   }
+  const workspaceToolSandbox = sandboxRoot
+    ? { root: sandboxRoot, bridge: sandboxFsBridge! }
+    : undefined;
 
   const base = (codingTools as unknown as AnyAgentTool[]).flatMap((tool) => {
     if (tool.name === readTool.name) {
@@ -283,8 +307,8 @@ export function createOpenClawCodingTools(options?: {
       if (sandboxRoot) {
         return [];
       }
-      // Wrap with param normalization for Claude Code compatibility
-      return [wrapToolParamNormalization(createEditTool(workspaceRoot), CLAUDE_PARAM_GROUPS.edit)];
+      // Param normalization for Claude Code compatibility, plus matchMode resolution
+      return [createOpenClawEditTool(workspaceRoot)];
     }
     return [tool];
   });
@@ -308,6 +332,11 @@ export function createOpenClawCodingTools(options?: {
     approvalRunningNoticeMs:
       options?.exec?.approvalRunningNoticeMs ?? execConfig.approvalRunningNoticeMs,
     notifyOnExit: options?.exec?.notifyOnExit ?? execConfig.notifyOnExit,
+    trackCwd: options?.exec?.trackCwd ?? execConfig.trackCwd,
+    env: options?.exec?.env ?? execConfig.env,
+    scrubEnv: options?.exec?.scrubEnv ?? execConfig.scrubEnv,
+    osSandbox: options?.exec?.osSandbox ?? execConfig.sandbox,
+    persistentShell: options?.exec?.persistentShell ?? execConfig.persistentShell,
     sandbox: sandbox
       ? {
           containerName: sandbox.containerName,
@@ -321,6 +350,17 @@ export function createOpenClawCodingTools(options?: {
     cleanupMs: cleanupMsOverride ?? execConfig.cleanupMs,
     scopeKey,
   });
+  const runCodeTool = createRunCodeTool({
+    config: options?.config,
+    workspaceDir: workspaceRoot,
+    sandbox: sandbox
+      ? {
+          containerName: sandbox.containerName,
+          containerWorkdir: sandbox.containerWorkdir,
+          env: sandbox.docker.env,
+        }
+      : undefined,
+  });
   const applyPatchTool =
     !applyPatchEnabled || (sandboxRoot && !allowWorkspaceWrites)
       ? null
@@ -331,7 +371,7 @@ export function createOpenClawCodingTools(options?: {
               ? { root: sandboxRoot, bridge: sandboxFsBridge! }
               : undefined,
         });
-  const tools: AnyAgentTool[] = [
+  const codingTools: AnyAgentTool[] = [
     ...base,
     ...(sandboxRoot
       ? allowWorkspaceWrites
@@ -342,10 +382,23 @@ export function createOpenClawCodingTools(options?: {
         : []
       : []),
     ...(applyPatchTool ? [applyPatchTool as unknown as AnyAgentTool] : []),
+    ...createWorkspaceTools({
+      config: options?.config,
+      workspaceRoot,
+      sandbox: workspaceToolSandbox,
+      allowWorkspaceWrites,
+      sessionKey: options?.sessionKey,
+      agentId,
+    }),
     execTool as unknown as AnyAgentTool,
     processTool as unknown as AnyAgentTool,
+    ...(createBackgroundTaskTools({ scopeKey }) as unknown as AnyAgentTool[]),
+    ...(runCodeTool ? [runCodeTool] : []),
     // Channel docking: include channel-defined agent tools (login, etc.).
     ...listChannelAgentTools({ cfg: options?.config }),
+  ];
+  const tools: AnyAgentTool[] = [
+    ...codingTools,
     ...createOpenClawTools({
       sandboxBrowserBridgeUrl: sandbox?.browser?.bridgeUrl,
       allowHostBrowserControl: sandbox ? sandbox.browserAllowHostControl : true,
@@ -382,6 +435,7 @@ export function createOpenClawCodingTools(options?: {
       requireExplicitMessageTarget: options?.requireExplicitMessageTarget,
       disableMessageTool: options?.disableMessageTool,
       requesterAgentIdOverride: agentId,
+      existingToolNames: new Set(codingTools.map((tool) => tool.name)),
     }),
   ];
   // Security: treat unknown/undefined as unauthorized (opt-in, not opt-out)
@@ -410,8 +464,25 @@ export function createOpenClawCodingTools(options?: {
   });
   // Always normalize tool JSON Schemas before handing them to pi-agent/pi-ai.
   // Without this, some providers (notably OpenAI) will reject root-level union schemas.
-  const normalized = subagentFiltered.map(normalizeToolParameters);
-  const withHooks = normalized.map((tool) =>
+  const modeFiltered = options?.planMode
+    ? filterToolsForPlanMode(subagentFiltered)
+    : subagentFiltered;
+  const disabledTools = new Set(expandToolGroups(options?.disabledTools));
+  const sessionFiltered =
+    disabledTools.size > 0
+      ? modeFiltered.filter((tool) => !disabledTools.has(normalizeToolName(tool.name)))
+      : modeFiltered;
+  const normalized = sessionFiltered.map(normalizeToolParameters);
+  // Reject malformed arguments (missing/wrong-typed fields) before any tool code runs.
+  const validated = normalized.map(wrapToolWithInputValidation);
+  const withRunLimits = wrapToolsForRun(validated, {
+    config: options?.config,
+    sessionKey: options?.sessionKey,
+    workspaceRoot,
+    sandbox: workspaceToolSandbox,
+    runLimits: options?.runLimits,
+  });
+  const withHooks = withRunLimits.map((tool) =>
     wrapToolWithBeforeToolCallHook(tool, {
       agentId,
       sessionKey: options?.sessionKey,
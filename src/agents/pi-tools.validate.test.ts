@@ -0,0 +1,58 @@
+import { Type } from "@sinclair/typebox";
+import { describe, expect, it, vi } from "vitest";
+import type { AnyAgentTool } from "./tools/common.js";
+import { validateToolInput, wrapToolWithInputValidation } from "./pi-tools.validate.js";
+import { stringEnum } from "./schema/typebox.js";
+
+function createTool(parameters: unknown) {
+  const execute = vi.fn(async (_toolCallId: string, params: unknown) => ({
+    content: [{ type: "text" as const, text: "ok" }],
+    details: params,
+  }));
+  const tool = { name: "demo", label: "demo", description: "demo", parameters, execute };
+  return { tool: tool as unknown as AnyAgentTool, execute };
+}
+
+const schema = Type.Object({
+  path: Type.String(),
+  limit: Type.Optional(Type.Number()),
+  mode: Type.Optional(stringEnum(["fast", "full"])),
+  nested: Type.Optional(Type.Object({ depth: Type.Integer() })),
+});
+
+describe("tool input validation", () => {
+  it("reports each offending field precisely", () => {
+    const { tool } = createTool(schema);
+    const result = validateToolInput(tool, {
+      limit: Number.NaN,
+      mode: "slow",
+      nested: { depth: "deep" },
+    });
+    expect(result.ok).toBe(false);
+    const message = result.ok ? "" : result.message;
+    expect(message).toContain("Invalid arguments for demo:");
+    expect(message).toContain("path: required");
+    expect(message).toContain("limit: must be number");
+    expect(message).toContain('mode: must be one of "fast", "full"');
+    expect(message).toContain("nested.depth: must be integer");
+  });
+
+  it("coerces numeric strings and leaves the caller's arguments untouched", async () => {
+    const { tool, execute } = createTool(schema);
+    const wrapped = wrapToolWithInputValidation(tool);
+    const args = { path: "a.txt", limit: "20" };
+
+    await wrapped.execute("call", args);
+    expect(execute.mock.calls[0]?.[1]).toEqual({ path: "a.txt", limit: 20 });
+    expect(args.limit).toBe("20");
+
+    await expect(wrapped.execute("call", {})).rejects.toThrow(/path: required/);
+    expect(execute).toHaveBeenCalledTimes(1);
+  });
+
+  it("skips tools without an object schema", async () => {
+    const { tool, execute } = createTool(undefined);
+    await wrapToolWithInputValidation(tool).execute("call", { anything: true });
+    expect(execute).toHaveBeenCalledTimes(1);
+  });
+});
@@ -0,0 +1,97 @@
+import AjvPkg, { type ErrorObject, type ValidateFunction } from "ajv";
+import type { AnyAgentTool } from "./tools/common.js";
+import { createSubsystemLogger } from "../logging/subsystem.js";
+import { ToolInputError } from "./tools/common.js";
+
+const log = createSubsystemLogger("agents/tools");
+
+// coerceTypes keeps the lenient "5" -> 5 handling tools already do via readNumberParam;
+// strictNumbers rejects NaN/Infinity, which JSON cannot represent anyway.
+const ajv = new (AjvPkg as unknown as new (opts?: object) => import("ajv").default)({
+  allErrors: true,
+  strict: false,
+  strictNumbers: true,
+  coerceTypes: true,
+  validateFormats: false,
+});
+
+// null marks schemas Ajv could not compile; those tools run unvalidated.
+const validatorCache = new WeakMap<object, ValidateFunction | null>();
+const MAX_REPORTED_ERRORS = 5;
+
+function resolveValidator(schema: object, toolName: string): ValidateFunction | null {
+  const cached = validatorCache.get(schema);
+  if (cached !== undefined) {
+    return cached;
+  }
+  let validate: ValidateFunction | null = null;
+  try {
+    validate = ajv.compile(schema);
+  } catch (err) {
+    log.debug(`tool schema not validated: tool=${toolName} error=${String(err)}`);
+  }
+  validatorCache.set(schema, validate);
+  return validate;
+}
+
+export function formatSchemaError(error: ErrorObject): string {
+  const base = error.instancePath.replace(/^\//, "").replace(/\//g, ".");
+  if (error.keyword === "required") {
+    const missing = String((error.params as { missingProperty?: unknown }).missingProperty ?? "");
+    return `${base ? `${base}.` : ""}${missing}: required`;
+  }
+  if (error.keyword === "enum") {
+    const allowed = (error.params as { allowedValues?: unknown[] }).allowedValues ?? [];
+    const values = allowed.map((value) => JSON.stringify(value)).join(", ");
+    return `${base || "<root>"}: must be one of ${values}`;
+  }
+  return `${base || "<root>"}: ${error.message ?? "invalid"}`;
+}
+
+export function formatToolInputErrors(toolName: string, errors: ErrorObject[]): string {
+  const messages = Array.from(new Set(errors.map(formatSchemaError)));
+  const shown = messages.slice(0, MAX_REPORTED_ERRORS);
+  const more = messages.length > shown.length ? ` (+${messages.length - shown.length} more)` : "";
+  return `Invalid arguments for ${toolName}: ${shown.join("; ")}${more}`;
+}
+
+/**
+ * Checks tool arguments against the tool's JSON schema before execution. Returns the (possibly
+ * type-coerced) arguments, or a message naming each offending field.
+ */
+export function validateToolInput(
+  tool: AnyAgentTool,
+  params: unknown,
+): { ok: true; params: unknown } | { ok: false; message: string } {
+  const schema = tool.parameters;
+  if (!schema || typeof schema !== "object") {
+    return { ok: true, params };
+  }
+  const validate = resolveValidator(schema, tool.name);
+  if (!validate) {
+    return { ok: true, params };
+  }
+  // Ajv coerces in place; work on a copy so callers keep the original arguments.
+  const candidate = params === undefined ? {} : structuredClone(params);
+  if (validate(candidate)) {
+    return { ok: true, params: candidate };
+  }
+  return { ok: false, message: formatToolInputErrors(tool.name, validate.errors ?? []) };
+}
+
+export function wrapToolWithInputValidation(tool: AnyAgentTool): AnyAgentTool {
+  const execute = tool.execute;
+  if (!execute) {
+    return tool;
+  }
+  return {
+    ...tool,
+    execute: async (toolCallId, params, signal, onUpdate) => {
+      const checked = validateToolInput(tool, params);
+      if (!checked.ok) {
+        throw new ToolInputError(checked.message);
+      }
+      return await execute(toolCallId, checked.params as typeof params, signal, onUpdate);
+    },
+  };
+}
@@ -0,0 +1,47 @@
+import type { OpenClawConfig } from "../config/config.js";
+import type { AnyAgentTool } from "./pi-tools.types.js";
+import type { WorkspaceToolSandbox } from "./tools/fs-walk.js";
+import { createCodeOutlineTool, isCodeOutlineAvailable } from "./tools/code-outline-tool.js";
+import { createCodebaseSearchTool } from "./tools/codebase-search-tool.js";
+import { createFindTool } from "./tools/find-tool.js";
+import { createGitTool } from "./tools/git-tool.js";
+import { createGrepTool } from "./tools/grep-tool.js";
+import { createLsTool } from "./tools/ls-tool.js";
+import { createNotebookEditTool, createNotebookReadTool } from "./tools/notebook-tool.js";
+
+/**
+ * Search, notebook, outline, git, and codebase-index tools for the workspace. Sandboxed runs go
+ * through the container filesystem bridge and do not get the host-only git and index tools.
+ */
+export function createWorkspaceTools(params: {
+  config?: OpenClawConfig;
+  workspaceRoot: string;
+  sandbox?: WorkspaceToolSandbox;
+  allowWorkspaceWrites: boolean;
+  sessionKey?: string;
+  agentId?: string;
+}): AnyAgentTool[] {
+  const { sandbox, workspaceRoot } = params;
+  const root = sandbox?.root ?? workspaceRoot;
+  const fileToolOptions = { cwd: root, sandbox };
+  const codebaseSearchTool = sandbox
+    ? null
+    : createCodebaseSearchTool({
+        workspaceDir: workspaceRoot,
+        config: params.config,
+        sessionKey: params.sessionKey,
+        agentId: params.agentId,
+      });
+  return [
+    createGrepTool({ root, sandbox }),
+    createFindTool({ root, sandbox }),
+    createLsTool({ root, sandbox }),
+    createNotebookReadTool(fileToolOptions),
+    ...(sandbox && !params.allowWorkspaceWrites ? [] : [createNotebookEditTool(fileToolOptions)]),
+    ...(isCodeOutlineAvailable() ? [createCodeOutlineTool(fileToolOptions)] : []),
+    // git runs on the host, where repo config can launch programs; keep it out of sandboxes.
+    ...(sandbox ? [] : [createGitTool({ root: workspaceRoot })]),
+    // The index lives on the host and covers host paths, so sandboxed runs do not get it either.
+    ...(codebaseSearchTool ? [codebaseSearchTool] : []),
+  ];
+}
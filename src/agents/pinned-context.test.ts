@@ -0,0 +1,46 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import { describe, expect, it } from "vitest";
+import { wrapStreamFnWithPinnedContext } from "./pinned-context.js";
+
+function captureContext(messages: unknown[]) {
+  let seen: { messages: unknown[] } | undefined;
+  const base = ((_model, context) => {
+    seen = context as never;
+    return undefined;
+  }) as unknown as StreamFn;
+  wrapStreamFnWithPinnedContext(base, "PINS")({} as never, { messages } as never, {});
+  return seen?.messages;
+}
+
+describe("pinned context", () => {
+  it("prefixes the latest user turn without touching the stored messages", () => {
+    const older = { role: "user", content: "first" };
+    const latest = { role: "user", content: [{ type: "text", text: "second" }] };
+    const toolResult = { role: "toolResult", content: [{ type: "text", text: "ok" }] };
+    const messages = [older, { role: "assistant", content: [] }, latest, toolResult];
+
+    const sent = captureContext(messages);
+    expect(sent?.[0]).toBe(older);
+    expect(sent?.[2]).toEqual({
+      role: "user",
+      content: [
+        { type: "text", text: "PINS" },
+        { type: "text", text: "second" },
+      ],
+    });
+    expect(sent?.[3]).toBe(toolResult);
+    expect(latest.content).toEqual([{ type: "text", text: "second" }]);
+  });
+
+  it("wraps string content and leaves contexts without a user turn alone", () => {
+    expect(captureContext([{ role: "user", content: "hi" }])?.[0]).toEqual({
+      role: "user",
+      content: [
+        { type: "text", text: "PINS" },
+        { type: "text", text: "hi" },
+      ],
+    });
+    const messages = [{ role: "assistant", content: [] }];
+    expect(captureContext(messages)).toBe(messages);
+  });
+});
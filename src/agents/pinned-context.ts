@@ -0,0 +1,29 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import { streamSimple } from "@mariozechner/pi-ai";
+
+/**
+ * Adds the session's pinned messages to the latest user turn of each model request. The stored
+ * transcript never sees them, so they are not duplicated per turn nor summarized by compaction.
+ */
+export function wrapStreamFnWithPinnedContext(
+  baseStreamFn: StreamFn | undefined,
+  pinnedContext: string,
+): StreamFn {
+  const underlying = baseStreamFn ?? streamSimple;
+  return (model, context, options) => {
+    const messages = context.messages ?? [];
+    const index = messages.findLastIndex((message) => message.role === "user");
+    const target = messages[index];
+    if (!target || target.role !== "user") {
+      return underlying(model, context, options);
+    }
+    const pinned = { type: "text" as const, text: pinnedContext };
+    const content =
+      typeof target.content === "string"
+        ? [pinned, { type: "text" as const, text: target.content }]
+        : [pinned, ...target.content];
+    const next = [...messages];
+    next[index] = { ...target, content };
+    return underlying(model, { ...context, messages: next }, options);
+  };
+}
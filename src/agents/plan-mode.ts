@@ -0,0 +1,47 @@
+import type { AnyAgentTool } from "./tools/common.js";
+import { normalizeToolName } from "./tool-policy.js";
+
+/** Tools that only inspect state; everything else is withheld while a plan is being drafted. */
+export const PLAN_MODE_TOOL_NAMES = new Set([
+  "read",
+  "grep",
+  "find",
+  "ls",
+  "notebook_read",
+  "code_outline",
+  "codebase_search",
+  "git",
+  "web_search",
+  "web_fetch",
+  "memory_search",
+  "memory_get",
+  "memory_read",
+  "agents_list",
+  "sessions_list",
+  "sessions_history",
+  "session_status",
+  "todo_write",
+  "image",
+]);
+
+export const PLAN_MODE_SYSTEM_PROMPT = [
+  "## Plan mode",
+  "You are in plan mode: only read-only tools are available and nothing may be changed yet.",
+  "Investigate as needed, then reply with a concrete, numbered plan: the files or systems you will touch, the changes in each, risks, and how you will verify the result.",
+  "Do not attempt the changes. The user will approve the plan (or ask for revisions) before tools that modify anything are re-enabled.",
+].join("\n");
+
+export function filterToolsForPlanMode(tools: AnyAgentTool[]): AnyAgentTool[] {
+  return tools.filter((tool) => PLAN_MODE_TOOL_NAMES.has(normalizeToolName(tool.name)));
+}
+
+/** Prompt for the run that executes an approved plan; the plan text is restated verbatim. */
+export function buildPlanApprovalPrompt(plan: string, note?: string): string {
+  return [
+    "The user approved your plan. All tools are available again; carry it out now.",
+    `Approved plan:\n${plan.trim()}`,
+    note?.trim() ? `Additional instructions from the user:\n${note.trim()}` : null,
+  ]
+    .filter((part): part is string => Boolean(part))
+    .join("\n\n");
+}
@@ -0,0 +1,61 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import { describe, expect, it } from "vitest";
+import {
+  applyPrefillToReplyTexts,
+  buildPrefillSystemPrompt,
+  resolveContinuePrefill,
+  supportsNativePrefill,
+  wrapStreamFnWithPrefill,
+} from "./prefill.js";
+
+function capturePayloads(wrap: (base: StreamFn) => StreamFn, payloads: unknown[]) {
+  const base = ((_model, _context, options) => {
+    for (const payload of payloads) {
+      options?.onPayload?.(payload);
+    }
+    return undefined;
+  }) as unknown as StreamFn;
+  wrap(base)({ api: "anthropic-messages" } as never, { messages: [] } as never, {});
+}
+
+describe("assistant prefill", () => {
+  it("uses native prefill only on Anthropic without extended thinking", () => {
+    expect(supportsNativePrefill({ api: "anthropic-messages" })).toBe(true);
+    expect(
+      supportsNativePrefill({ api: "anthropic-messages", reasoning: true, thinkLevel: "high" }),
+    ).toBe(false);
+    expect(supportsNativePrefill({ api: "openai-completions" })).toBe(false);
+    expect(buildPrefillSystemPrompt({ text: "```diff", echo: true })).toContain(
+      "Begin your reply with exactly the following text",
+    );
+  });
+
+  it("appends the prefill to the first user turn only, without trailing whitespace", () => {
+    const first = { messages: [{ role: "user", content: "show the diff" }] };
+    const afterTool = {
+      messages: [{ role: "user", content: [{ type: "tool_result", tool_use_id: "t1" }] }],
+    };
+    const again = { messages: [{ role: "user", content: "and again" }] };
+    capturePayloads(
+      (base) => wrapStreamFnWithPrefill(base, { text: "```diff\n", echo: true }),
+      [first, afterTool, again],
+    );
+    expect(first.messages.at(-1)).toEqual({
+      role: "assistant",
+      content: [{ type: "text", text: "```diff" }],
+    });
+    expect(afterTool.messages).toHaveLength(1);
+    expect(again.messages).toHaveLength(1);
+  });
+
+  it("restores echoed prefills in the reply and seeds continuations with the tail", () => {
+    expect(applyPrefillToReplyTexts(["\n-a\n+b"], { text: "```diff", echo: true })).toEqual([
+      "```diff\n-a\n+b",
+    ]);
+    expect(applyPrefillToReplyTexts(["rest"], { text: "start", echo: false })).toEqual(["rest"]);
+    const continued = resolveContinuePrefill(`${"x".repeat(1000)}The end is `);
+    expect(continued.echo).toBe(false);
+    expect(continued.text).toHaveLength(400);
+    expect(continued.text.endsWith("The end is")).toBe(true);
+  });
+});
@@ -0,0 +1,95 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import { streamSimple } from "@mariozechner/pi-ai";
+import type { ThinkLevel } from "../auto-reply/thinking.js";
+import { isPlainUserTurnPayload } from "./structured-output.js";
+
+/** Characters of a cut-off reply used to seed the continuation. */
+export const CONTINUE_PREFILL_CHARS = 400;
+export const CONTINUE_PROMPT =
+  "Continue your previous reply from exactly where it stopped, without repeating anything.";
+
+export type AssistantPrefill = {
+  text: string;
+  /**
+   * Whether the reply should start with the prefill. True for `--prefill`; false when continuing a
+   * reply the user has already seen.
+   */
+  echo: boolean;
+};
+
+/**
+ * Anthropic accepts a trailing assistant message as the start of the reply, except while extended
+ * thinking is on. Everywhere else the prefill is emulated through the system prompt.
+ */
+export function supportsNativePrefill(params: {
+  api: string;
+  reasoning?: boolean;
+  thinkLevel?: ThinkLevel;
+}): boolean {
+  if (params.api !== "anthropic-messages") {
+    return false;
+  }
+  return !params.reasoning || !params.thinkLevel || params.thinkLevel === "off";
+}
+
+export function buildPrefillSystemPrompt(prefill: AssistantPrefill): string {
+  if (prefill.echo) {
+    return [
+      "## Reply prefix",
+      "Begin your reply with exactly the following text (verbatim, nothing before it), then continue naturally from it:",
+      prefill.text,
+    ].join("\n");
+  }
+  return [
+    "## Continuation",
+    "Your previous reply was cut off. It ended with:",
+    prefill.text,
+    "Continue from exactly that point. Do not repeat earlier text and do not add a preamble.",
+  ].join("\n");
+}
+
+/** Adds the prefill as a trailing assistant message to the first request of a run. */
+export function wrapStreamFnWithPrefill(
+  baseStreamFn: StreamFn | undefined,
+  prefill: AssistantPrefill,
+): StreamFn {
+  const underlying = baseStreamFn ?? streamSimple;
+  // The API rejects a final assistant message that ends in whitespace.
+  const text = prefill.text.trimEnd();
+  let used = false;
+  return (model, context, options) =>
+    underlying(model, context, {
+      ...options,
+      onPayload: (payload) => {
+        const body = payload as Record<string, unknown> | undefined;
+        const thinking = body?.thinking as { type?: unknown } | undefined;
+        if (
+          !used &&
+          text &&
+          body &&
+          thinking?.type !== "enabled" &&
+          isPlainUserTurnPayload(body.messages)
+        ) {
+          used = true;
+          const messages = body.messages as unknown[];
+          messages.push({ role: "assistant", content: [{ type: "text", text }] });
+        }
+        options?.onPayload?.(payload);
+      },
+    });
+}
+
+/** Native prefills are not part of the model output, so restore them at the start of the reply. */
+export function applyPrefillToReplyTexts(texts: string[], prefill: AssistantPrefill): string[] {
+  if (!prefill.echo || !prefill.text) {
+    return texts;
+  }
+  const [first = "", ...rest] = texts;
+  return [`${prefill.text.trimEnd()}${first}`, ...rest];
+}
+
+/** Prefill for `/continue`: the tail of the cut-off reply, so the model picks up mid-sentence. */
+export function resolveContinuePrefill(previousReply: string): AssistantPrefill {
+  const trimmed = previousReply.trimEnd();
+  return { text: trimmed.slice(-CONTINUE_PREFILL_CHARS), echo: false };
+}
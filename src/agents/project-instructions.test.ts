@@ -0,0 +1,120 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import { describe, expect, it } from "vitest";
+import { makeTempWorkspace } from "../test-helpers/workspace.js";
+import {
+  buildProjectInstructionContextFiles,
+  loadProjectInstructionFiles,
+} from "./project-instructions.js";
+
+async function setupTree() {
+  const rootDir = await makeTempWorkspace("openclaw-project-instructions-");
+  const stateDir = path.join(rootDir, "state");
+  const repoDir = path.join(rootDir, "repo");
+  const cwd = path.join(repoDir, "packages", "core");
+  await fs.mkdir(stateDir, { recursive: true });
+  await fs.mkdir(cwd, { recursive: true });
+  return { rootDir, stateDir, repoDir, cwd, env: { OPENCLAW_STATE_DIR: stateDir } };
+}
+
+describe("loadProjectInstructionFiles", () => {
+  it("collects global and ancestor instruction files from outermost to innermost", async () => {
+    const { rootDir, stateDir, repoDir, cwd, env } = await setupTree();
+    await fs.writeFile(path.join(stateDir, "CLAUDE.md"), "global", "utf-8");
+    await fs.writeFile(path.join(repoDir, "CLAUDE.md"), "repo claude", "utf-8");
+    await fs.writeFile(path.join(repoDir, ".openclaw.md"), "repo openclaw", "utf-8");
+    await fs.writeFile(path.join(cwd, "AGENTS.md"), "package agents", "utf-8");
+
+    const files = await loadProjectInstructionFiles({ cwd, env, rootDir });
+
+    expect(files.map((file) => [file.scope, file.path, file.content])).toEqual([
+      ["user", path.join(stateDir, "CLAUDE.md"), "global"],
+      ["project", path.join(repoDir, "CLAUDE.md"), "repo claude"],
+      ["project", path.join(repoDir, ".openclaw.md"), "repo openclaw"],
+      ["project", path.join(cwd, "AGENTS.md"), "package agents"],
+    ]);
+  });
+
+  it("skips excluded paths", async () => {
+    const { rootDir, cwd, env } = await setupTree();
+    const agentsPath = path.join(cwd, "AGENTS.md");
+    await fs.writeFile(agentsPath, "already injected", "utf-8");
+
+    const files = await loadProjectInstructionFiles({
+      cwd,
+      env,
+      rootDir,
+      excludePaths: [agentsPath],
+    });
+
+    expect(files).toEqual([]);
+  });
+
+  it("expands @include lines relative to the including file", async () => {
+    const { rootDir, repoDir, cwd, env } = await setupTree();
+    await fs.mkdir(path.join(repoDir, "docs"), { recursive: true });
+    await fs.writeFile(path.join(repoDir, "docs", "style.md"), "use tabs\n@../CLAUDE.md", "utf-8");
+    await fs.writeFile(
+      path.join(repoDir, "CLAUDE.md"),
+      ["# Rules", "@docs/style.md", "@docs/missing.md", "```", "@docs/style.md", "```"].join("\n"),
+      "utf-8",
+    );
+
+    const files = await loadProjectInstructionFiles({ cwd, env, rootDir });
+
+    expect(files).toHaveLength(1);
+    expect(files[0]?.content).toBe(
+      [
+        "# Rules",
+        `<!-- begin include: ${path.join(repoDir, "docs", "style.md")} -->`,
+        "use tabs",
+        "@../CLAUDE.md",
+        `<!-- end include: ${path.join(repoDir, "docs", "style.md")} -->`,
+        "@docs/missing.md",
+        "```",
+        "@docs/style.md",
+        "```",
+      ].join("\n"),
+    );
+  });
+
+  it("stops at the git root and only includes files from the project or state dir", async () => {
+    const { rootDir, stateDir, repoDir, cwd, env } = await setupTree();
+    await fs.mkdir(path.join(repoDir, ".git"));
+    await fs.writeFile(path.join(rootDir, "AGENTS.md"), "outside the repo", "utf-8");
+    await fs.writeFile(path.join(rootDir, "secret.md"), "TOKEN=hunter2", "utf-8");
+    await fs.writeFile(path.join(stateDir, "shared.md"), "shared rules", "utf-8");
+    await fs.symlink(path.join(rootDir, "secret.md"), path.join(repoDir, "linked.md"));
+    const includes = ["@../secret.md", `@${path.join(rootDir, "secret.md")}`, "@linked.md"];
+    await fs.writeFile(
+      path.join(repoDir, "CLAUDE.md"),
+      [...includes, `@${path.join(stateDir, "shared.md")}`].join("\n"),
+      "utf-8",
+    );
+
+    const files = await loadProjectInstructionFiles({ cwd, env });
+
+    expect(files.map((file) => file.path)).toEqual([path.join(repoDir, "CLAUDE.md")]);
+    expect(files[0]?.content).not.toContain("hunter2");
+    expect(files[0]?.content.split("\n").slice(0, 3)).toEqual(includes);
+    expect(files[0]?.content).toContain("shared rules");
+  });
+});
+
+describe("buildProjectInstructionContextFiles", () => {
+  it("labels each file with its origin path and truncates oversized files", () => {
+    const warnings: string[] = [];
+    const files = buildProjectInstructionContextFiles(
+      [
+        { path: "/repo/CLAUDE.md", scope: "project", content: "short" },
+        { path: "/repo/pkg/AGENTS.md", scope: "project", content: "x".repeat(500) },
+      ],
+      { maxChars: 100, warn: (message) => warnings.push(message) },
+    );
+
+    expect(files.map((file) => file.path)).toEqual(["/repo/CLAUDE.md", "/repo/pkg/AGENTS.md"]);
+    expect(files[0]?.content).toBe("short");
+    expect(files[1]?.content).toContain("truncated /repo/pkg/AGENTS.md");
+    expect(warnings).toHaveLength(1);
+  });
+});
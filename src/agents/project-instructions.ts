@@ -0,0 +1,221 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { EmbeddedContextFile } from "./pi-embedded-helpers.js";
+import { resolveStateDir } from "../config/paths.js";
+import { resolveUserPath, shortenHomePath } from "../utils.js";
+import {
+  DEFAULT_BOOTSTRAP_MAX_CHARS,
+  trimBootstrapContent,
+} from "./pi-embedded-helpers/bootstrap.js";
+import { findGitRoot } from "./system-prompt-params.js";
+
+/** Instruction filenames picked up in each directory, in injection order. */
+export const PROJECT_INSTRUCTION_FILENAMES = ["CLAUDE.md", "AGENTS.md", ".openclaw.md"] as const;
+
+/** Global instructions file, resolved under the state dir (`~/.openclaw`). */
+export const USER_INSTRUCTIONS_FILENAME = "CLAUDE.md";
+
+const MAX_INCLUDE_DEPTH = 5;
+const INCLUDE_LINE_RE = /^@(\S+)\s*$/;
+
+export type ProjectInstructionFile = {
+  /** Absolute path of the instruction file. */
+  path: string;
+  /** Where the file came from: the global state dir or a project directory. */
+  scope: "user" | "project";
+  /** File content with `@include` lines expanded. */
+  content: string;
+};
+
+async function readRealPath(filePath: string): Promise<string | null> {
+  try {
+    const stat = await fs.stat(filePath);
+    if (!stat.isFile()) {
+      return null;
+    }
+    return await fs.realpath(filePath);
+  } catch {
+    return null;
+  }
+}
+
+function resolveIncludePath(ref: string, baseDir: string): string {
+  if (ref.startsWith("~")) {
+    return resolveUserPath(ref);
+  }
+  return path.resolve(baseDir, ref);
+}
+
+function isInsideRoots(realPath: string, roots: string[]): boolean {
+  return roots.some((root) => {
+    const rel = path.relative(root, realPath);
+    return !rel.startsWith("..") && !path.isAbsolute(rel);
+  });
+}
+
+async function resolveRealDir(dir: string): Promise<string> {
+  return await fs.realpath(dir).catch(() => path.resolve(dir));
+}
+
+/**
+ * Expand `@path` lines (a line holding only `@` and a path) with the referenced file.
+ * Paths resolve relative to the including file and must resolve (after symlinks) inside one of
+ * `roots`; cycles, missing files, and files outside the roots are left as-is.
+ */
+async function expandIncludes(
+  content: string,
+  filePath: string,
+  roots: string[],
+  stack: Set<string>,
+  depth: number,
+): Promise<string> {
+  const baseDir = path.dirname(filePath);
+  const out: string[] = [];
+  let inFence = false;
+  for (const line of content.split("\n")) {
+    if (line.trimStart().startsWith("```")) {
+      inFence = !inFence;
+    }
+    const match = inFence ? null : INCLUDE_LINE_RE.exec(line.trim());
+    if (!match || depth >= MAX_INCLUDE_DEPTH) {
+      out.push(line);
+      continue;
+    }
+    const includePath = resolveIncludePath(match[1], baseDir);
+    const realPath = await readRealPath(includePath);
+    if (!realPath || stack.has(realPath) || !isInsideRoots(realPath, roots)) {
+      out.push(line);
+      continue;
+    }
+    let included: string;
+    try {
+      included = await fs.readFile(realPath, "utf-8");
+    } catch {
+      out.push(line);
+      continue;
+    }
+    stack.add(realPath);
+    const expanded = await expandIncludes(included, includePath, roots, stack, depth + 1);
+    stack.delete(realPath);
+    out.push(
+      `<!-- begin include: ${shortenHomePath(includePath)} -->`,
+      expanded.trimEnd(),
+      `<!-- end include: ${shortenHomePath(includePath)} -->`,
+    );
+  }
+  return out.join("\n");
+}
+
+async function loadInstructionFile(
+  filePath: string,
+  scope: ProjectInstructionFile["scope"],
+  seen: Set<string>,
+  includeRoots: string[],
+): Promise<ProjectInstructionFile | null> {
+  const realPath = await readRealPath(filePath);
+  if (!realPath || seen.has(realPath)) {
+    return null;
+  }
+  seen.add(realPath);
+  let raw: string;
+  try {
+    raw = await fs.readFile(realPath, "utf-8");
+  } catch {
+    return null;
+  }
+  const content = await expandIncludes(raw, filePath, includeRoots, new Set([realPath]), 0);
+  if (!content.trim()) {
+    return null;
+  }
+  return { path: filePath, scope, content };
+}
+
+function listAncestorDirs(startDir: string, rootDir?: string): string[] {
+  const dirs: string[] = [];
+  const stop = rootDir ? path.resolve(rootDir) : undefined;
+  let current = path.resolve(startDir);
+  while (true) {
+    dirs.push(current);
+    if (stop && current === stop) {
+      break;
+    }
+    const parent = path.dirname(current);
+    if (parent === current) {
+      break;
+    }
+    current = parent;
+  }
+  return dirs;
+}
+
+/**
+ * Discover instruction files for a run: the global `CLAUDE.md` in the state dir first,
+ * then `CLAUDE.md`/`AGENTS.md`/`.openclaw.md` from the project root down to `cwd` itself,
+ * so closer files come later and take precedence. `@` includes may only pull in files
+ * from the project root or the state dir.
+ */
+export async function loadProjectInstructionFiles(params: {
+  cwd: string;
+  env?: NodeJS.ProcessEnv;
+  /** Stop walking up at this directory (inclusive). Defaults to the git root, else `cwd`. */
+  rootDir?: string;
+  /** Paths already injected elsewhere (e.g. workspace bootstrap files); skipped here. */
+  excludePaths?: Iterable<string>;
+}): Promise<ProjectInstructionFile[]> {
+  const seen = new Set<string>();
+  for (const excluded of params.excludePaths ?? []) {
+    const realPath = await readRealPath(excluded);
+    if (realPath) {
+      seen.add(realPath);
+    }
+  }
+
+  const result: ProjectInstructionFile[] = [];
+  const stateDir = resolveStateDir(params.env);
+  const stateRoot = await resolveRealDir(stateDir);
+  const userFile = await loadInstructionFile(
+    path.join(stateDir, USER_INSTRUCTIONS_FILENAME),
+    "user",
+    seen,
+    [stateRoot],
+  );
+  if (userFile) {
+    result.push(userFile);
+  }
+
+  const cwd = resolveUserPath(params.cwd);
+  const rootDir = params.rootDir ?? findGitRoot(cwd) ?? cwd;
+  const includeRoots = [await resolveRealDir(rootDir), stateRoot];
+  const dirs = listAncestorDirs(cwd, rootDir).reverse();
+  for (const dir of dirs) {
+    for (const name of PROJECT_INSTRUCTION_FILENAMES) {
+      const file = await loadInstructionFile(path.join(dir, name), "project", seen, includeRoots);
+      if (file) {
+        result.push(file);
+      }
+    }
+  }
+  return result;
+}
+
+export function buildProjectInstructionContextFiles(
+  files: ProjectInstructionFile[],
+  opts?: { warn?: (message: string) => void; maxChars?: number },
+): EmbeddedContextFile[] {
+  const maxChars = opts?.maxChars ?? DEFAULT_BOOTSTRAP_MAX_CHARS;
+  const result: EmbeddedContextFile[] = [];
+  for (const file of files) {
+    const label = shortenHomePath(file.path);
+    const trimmed = trimBootstrapContent(file.content, label, maxChars);
+    if (!trimmed.content) {
+      continue;
+    }
+    if (trimmed.truncated) {
+      opts?.warn?.(
+        `instruction file ${label} is ${trimmed.originalLength} chars (limit ${trimmed.maxChars}); truncating in injected context`,
+      );
+    }
+    result.push({ path: label, content: trimmed.content });
+  }
+  return result;
+}
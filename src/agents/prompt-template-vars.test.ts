@@ -0,0 +1,48 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import { expandPromptTemplateVars } from "./prompt-template-vars.js";
+
+describe("expandPromptTemplateVars", () => {
+  let root: string;
+
+  beforeEach(() => {
+    root = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-prompt-vars-"));
+  });
+
+  afterEach(() => {
+    fs.rmSync(root, { recursive: true, force: true });
+  });
+
+  it("substitutes cwd, date, model, os, and git branch", () => {
+    fs.mkdirSync(path.join(root, ".git"));
+    fs.writeFileSync(path.join(root, ".git", "HEAD"), "ref: refs/heads/feature/x\n");
+
+    const text = expandPromptTemplateVars(
+      "cwd={{cwd}} date={{ date }} branch={{git_branch}} model={{model}} os={{os}}",
+      {
+        cwd: root,
+        model: "anthropic/claude-opus-4-5",
+        timezone: "UTC",
+        now: new Date("2026-03-04T23:30:00Z"),
+      },
+    );
+
+    expect(text).toBe(
+      `cwd=${root} date=2026-03-04 branch=feature/x model=anthropic/claude-opus-4-5 os=${os.type()} ${os.release()}`,
+    );
+  });
+
+  it("reads env vars but blanks credentials and leaves unknown placeholders", () => {
+    const text = expandPromptTemplateVars(
+      "{{env:TEAM}}|{{env:MISSING}}|{{env:OPENAI_API_KEY}}|{{unknown}}|{{git_branch}}",
+      {
+        cwd: root,
+        env: { TEAM: "platform", OPENAI_API_KEY: "sk-secret" },
+      },
+    );
+
+    expect(text).toBe("platform|||{{unknown}}|");
+  });
+});
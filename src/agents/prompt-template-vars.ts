@@ -0,0 +1,68 @@
+import os from "node:os";
+import { resolveGitBranch } from "../infra/git-commit.js";
+import { DEFAULT_EXEC_SCRUB_ENV, scrubEnv } from "./bash-tools.shared.js";
+
+export type PromptTemplateContext = {
+  /** Directory for `{{cwd}}` and the repo `{{git_branch}}` is read from. */
+  cwd: string;
+  /** `provider/model` for `{{model}}`. */
+  model?: string;
+  /** IANA timezone for `{{date}}`; defaults to the host timezone. */
+  timezone?: string;
+  env?: NodeJS.ProcessEnv;
+  now?: Date;
+};
+
+const TEMPLATE_VAR_RE = /\{\{\s*(cwd|date|git_branch|model|os|env:([A-Za-z_][A-Za-z0-9_]*))\s*\}\}/g;
+
+function formatTemplateDate(now: Date, timezone?: string): string {
+  try {
+    return new Intl.DateTimeFormat("en-CA", {
+      timeZone: timezone,
+      year: "numeric",
+      month: "2-digit",
+      day: "2-digit",
+    }).format(now);
+  } catch {
+    return now.toISOString().slice(0, 10);
+  }
+}
+
+/**
+ * Substitute `{{cwd}}`, `{{date}}`, `{{git_branch}}`, `{{model}}`, `{{os}}`, and `{{env:VAR}}`.
+ * Unknown placeholders are left untouched. Credential-looking env vars (the exec scrub list)
+ * expand to an empty string so a prompt file cannot pull API keys into the model context.
+ */
+export function expandPromptTemplateVars(text: string, ctx: PromptTemplateContext): string {
+  if (!text.includes("{{")) {
+    return text;
+  }
+  const env = ctx.env ?? process.env;
+  let gitBranch: string | null | undefined;
+  return text.replace(TEMPLATE_VAR_RE, (match, key: string, envName?: string) => {
+    if (envName) {
+      const value = env[envName];
+      if (typeof value !== "string") {
+        return "";
+      }
+      return scrubEnv({ [envName]: value }, DEFAULT_EXEC_SCRUB_ENV)[envName] ?? "";
+    }
+    switch (key) {
+      case "cwd":
+        return ctx.cwd;
+      case "date":
+        return formatTemplateDate(ctx.now ?? new Date(), ctx.timezone);
+      case "git_branch":
+        if (gitBranch === undefined) {
+          gitBranch = resolveGitBranch(ctx.cwd);
+        }
+        return gitBranch ?? "";
+      case "model":
+        return ctx.model ?? "";
+      case "os":
+        return `${os.type()} ${os.release()}`;
+      default:
+        return match;
+    }
+  });
+}
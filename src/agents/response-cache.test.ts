@@ -0,0 +1,180 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import { type AssistantMessage, createAssistantMessageEventStream } from "@mariozechner/pi-ai";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, describe, expect, it, vi } from "vitest";
+import {
+  hashResponseRequest,
+  isCachedResponse,
+  pruneResponseCache,
+  resolveResponseCacheSettings,
+  wrapStreamFnWithResponseCache,
+} from "./response-cache.js";
+
+type StreamModel = Parameters<StreamFn>[0];
+
+const model = { provider: "openai", api: "openai-responses", id: "gpt-5.2" } as StreamModel;
+
+function reply(text: string, stopReason: AssistantMessage["stopReason"] = "stop") {
+  return {
+    role: "assistant",
+    content: [{ type: "text", text }],
+    api: "openai-responses",
+    provider: "openai",
+    model: "gpt-5.2",
+    usage: {
+      input: 10,
+      output: 5,
+      cacheRead: 0,
+      cacheWrite: 0,
+      totalTokens: 15,
+      cost: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0, total: 0 },
+    },
+    stopReason,
+    timestamp: 1,
+  } as AssistantMessage;
+}
+
+function fakeStreamFn(message: AssistantMessage) {
+  return vi.fn(() => {
+    const stream = createAssistantMessageEventStream();
+    stream.push({ type: "done", reason: "stop", message });
+    stream.end();
+    return stream;
+  });
+}
+
+describe("hashResponseRequest", () => {
+  it("ignores message timestamps but not content or tools", () => {
+    const base = {
+      provider: "openai",
+      api: "openai-responses",
+      model: "gpt-5.2",
+      context: {
+        systemPrompt: "sys",
+        messages: [{ role: "user" as const, content: "hi", timestamp: 1 }],
+      },
+    };
+    const withMessage = (content: string, timestamp: number) => ({
+      ...base,
+      context: { ...base.context, messages: [{ role: "user" as const, content, timestamp }] },
+    });
+    const later = withMessage("hi", 2);
+    const edited = withMessage("hey", 1);
+    expect(hashResponseRequest(later)).toBe(hashResponseRequest(base));
+    expect(hashResponseRequest(edited)).not.toBe(hashResponseRequest(base));
+    expect(hashResponseRequest({ ...base, options: { temperature: 0.2 } })).not.toBe(
+      hashResponseRequest(base),
+    );
+  });
+});
+
+describe("resolveResponseCacheSettings", () => {
+  it("is off by default and can be enabled from the environment", () => {
+    expect(resolveResponseCacheSettings({}, {})).toBeUndefined();
+    const settings = resolveResponseCacheSettings(
+      { agents: { defaults: { responseCache: { ttlSeconds: 60 } } } },
+      { OPENCLAW_RESPONSE_CACHE: "1", OPENCLAW_STATE_DIR: "/tmp/oc-state" },
+    );
+    expect(settings).toEqual({
+      dir: path.join("/tmp/oc-state", "cache", "responses"),
+      ttlMs: 60_000,
+      maxEntries: 2_000,
+      maxBytes: 200 * 1024 * 1024,
+    });
+  });
+});
+
+const limits = { maxEntries: 100, maxBytes: 1024 * 1024 };
+
+async function storedFiles(dir: string) {
+  return (await fs.readdir(dir)).filter((name) => name.endsWith(".json"));
+}
+
+describe("wrapStreamFnWithResponseCache", () => {
+  let dir: string | undefined;
+  afterEach(async () => {
+    if (dir) {
+      await fs.rm(dir, { recursive: true, force: true });
+    }
+  });
+
+  it("replays identical requests from disk with zero usage", async () => {
+    const cacheDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-response-cache-"));
+    dir = cacheDir;
+    const underlying = fakeStreamFn(reply("cached answer"));
+    const streamFn = wrapStreamFnWithResponseCache(underlying, {
+      dir: cacheDir,
+      ttlMs: 60_000,
+      ...limits,
+    });
+    const context = { messages: [{ role: "user" as const, content: "hi", timestamp: 1 }] };
+
+    const first = await (await streamFn(model, context, {})).result();
+    expect(isCachedResponse(first)).toBe(false);
+    await vi.waitFor(async () => expect(await storedFiles(cacheDir)).toHaveLength(1));
+
+    const second = await (
+      await streamFn(model, { messages: [{ role: "user", content: "hi", timestamp: 2 }] }, {})
+    ).result();
+    expect(underlying).toHaveBeenCalledTimes(1);
+    expect(isCachedResponse(second)).toBe(true);
+    expect(second.content).toEqual([{ type: "text", text: "cached answer" }]);
+    expect(second.usage.output).toBe(0);
+  });
+
+  it("does not store failed replies or serve expired entries", async () => {
+    const cacheDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-response-cache-"));
+    dir = cacheDir;
+    const failing = fakeStreamFn(reply("", "error"));
+    const context = { messages: [{ role: "user" as const, content: "hi", timestamp: 1 }] };
+    const failingFn = wrapStreamFnWithResponseCache(failing, {
+      dir: cacheDir,
+      ttlMs: 60_000,
+      ...limits,
+    });
+    await (await failingFn(model, context, {})).result();
+    expect(await storedFiles(cacheDir)).toEqual([]);
+
+    let now = 1_000;
+    const underlying = fakeStreamFn(reply("fresh"));
+    const streamFn = wrapStreamFnWithResponseCache(
+      underlying,
+      { dir: cacheDir, ttlMs: 10, ...limits },
+      () => now,
+    );
+    await (await streamFn(model, context, {})).result();
+    await vi.waitFor(async () => expect(await storedFiles(cacheDir)).toHaveLength(1));
+    now += 1_000;
+    await (await streamFn(model, context, {})).result();
+    expect(underlying).toHaveBeenCalledTimes(2);
+  });
+});
+
+describe("pruneResponseCache", () => {
+  it("drops expired replies, then the oldest beyond the entry and size limits", async () => {
+    const cacheDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-response-cache-"));
+    try {
+      const now = Date.now();
+      const store = async (name: string, ageMs: number, bytes = 10) => {
+        const file = path.join(cacheDir, `${name}.json`);
+        await fs.writeFile(file, "x".repeat(bytes));
+        const time = new Date(now - ageMs);
+        await fs.utimes(file, time, time);
+      };
+      await store("expired", 120_000);
+      await store("old", 30_000);
+      await store("mid", 20_000);
+      await store("new", 10_000);
+      await pruneResponseCache({ dir: cacheDir, ttlMs: 60_000, ...limits, maxEntries: 2 }, now);
+      expect((await storedFiles(cacheDir)).toSorted()).toEqual(["mid.json", "new.json"]);
+
+      await store("big", 5_000, 100);
+      await pruneResponseCache({ dir: cacheDir, ttlMs: 60_000, ...limits, maxBytes: 110 }, now);
+      expect((await storedFiles(cacheDir)).toSorted()).toEqual(["big.json", "new.json"]);
+    } finally {
+      await fs.rm(cacheDir, { recursive: true, force: true });
+    }
+  });
+});
@@ -0,0 +1,273 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import {
+  type AssistantMessage,
+  createAssistantMessageEventStream,
+  streamSimple,
+} from "@mariozechner/pi-ai";
+import crypto from "node:crypto";
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveStateDir } from "../config/paths.js";
+import { resolveUserPath } from "../utils.js";
+import { parseBooleanValue } from "../utils/boolean.js";
+
+export const DEFAULT_RESPONSE_CACHE_TTL_SECONDS = 24 * 60 * 60;
+export const DEFAULT_RESPONSE_CACHE_MAX_ENTRIES = 2_000;
+export const DEFAULT_RESPONSE_CACHE_MAX_BYTES = 200 * 1024 * 1024;
+// Replies that did not finish cleanly are never stored.
+const CACHEABLE_STOP_REASONS = new Set(["stop", "length", "toolUse"]);
+// Per-request fields that never reach the provider, so they must not affect the key.
+const VOLATILE_MESSAGE_KEYS = new Set([
+  "timestamp",
+  "usage",
+  "api",
+  "provider",
+  "model",
+  "stopReason",
+  "errorMessage",
+  "details",
+]);
+
+export type ResponseCacheSettings = {
+  dir: string;
+  ttlMs: number;
+  maxEntries: number;
+  maxBytes: number;
+  /** Extra key input for payload changes made outside the context (e.g. prefill). */
+  salt?: string;
+};
+
+type StoredResponse = {
+  storedAt: number;
+  provider: string;
+  model: string;
+  message: AssistantMessage;
+};
+
+type StreamContext = Parameters<StreamFn>[1];
+type StreamOptions = Parameters<StreamFn>[2];
+
+const replayedMessages = new WeakSet<object>();
+
+/**
+ * Resolves the response cache from `agents.defaults.responseCache`; `OPENCLAW_RESPONSE_CACHE=1`
+ * turns it on for one command (e.g. `eval` or `bench` reruns). Returns undefined when off.
+ */
+export function resolveResponseCacheSettings(
+  cfg?: OpenClawConfig,
+  env: NodeJS.ProcessEnv = process.env,
+): ResponseCacheSettings | undefined {
+  const config = cfg?.agents?.defaults?.responseCache;
+  const enabled = parseBooleanValue(env.OPENCLAW_RESPONSE_CACHE) ?? config?.enabled ?? false;
+  if (!enabled) {
+    return undefined;
+  }
+  const dir = config?.dir?.trim()
+    ? resolveUserPath(config.dir.trim())
+    : path.join(resolveStateDir(env), "cache", "responses");
+  return {
+    dir,
+    ttlMs: (config?.ttlSeconds ?? DEFAULT_RESPONSE_CACHE_TTL_SECONDS) * 1000,
+    maxEntries: config?.maxEntries ?? DEFAULT_RESPONSE_CACHE_MAX_ENTRIES,
+    maxBytes: (config?.maxMb ?? DEFAULT_RESPONSE_CACHE_MAX_BYTES / (1024 * 1024)) * 1024 * 1024,
+  };
+}
+
+function canonicalize(value: unknown, dropKeys?: Set<string>): unknown {
+  if (Array.isArray(value)) {
+    return value.map((entry) => canonicalize(entry, dropKeys));
+  }
+  if (!value || typeof value !== "object") {
+    return value;
+  }
+  const record = value as Record<string, unknown>;
+  const out: Record<string, unknown> = {};
+  for (const key of Object.keys(record).toSorted()) {
+    if (record[key] === undefined || dropKeys?.has(key)) {
+      continue;
+    }
+    out[key] = canonicalize(record[key]);
+  }
+  return out;
+}
+
+/**
+ * Cache key for one model request: the model, system prompt, messages, tool definitions, and
+ * sampling options. Message timestamps and usage are left out since providers never see them.
+ */
+export function hashResponseRequest(params: {
+  provider: string;
+  api: string;
+  model: string;
+  context: StreamContext;
+  options?: StreamOptions;
+  salt?: string;
+}): string {
+  const { context, options } = params;
+  const payload = {
+    salt: params.salt ?? "",
+    provider: params.provider,
+    api: params.api,
+    model: params.model,
+    systemPrompt: context.systemPrompt ?? "",
+    messages: (context.messages ?? []).map((message) =>
+      canonicalize(message, VOLATILE_MESSAGE_KEYS),
+    ),
+    tools: (context.tools ?? []).map((tool) =>
+      canonicalize({
+        name: tool.name,
+        description: tool.description,
+        parameters: tool.parameters,
+      }),
+    ),
+    options: canonicalize({
+      temperature: options?.temperature,
+      maxTokens: options?.maxTokens,
+      reasoning: options?.reasoning,
+    }),
+  };
+  return crypto.createHash("sha256").update(JSON.stringify(payload)).digest("hex");
+}
+
+/** True for replies served from the response cache instead of the provider. */
+export function isCachedResponse(message: unknown): boolean {
+  return Boolean(message && typeof message === "object" && replayedMessages.has(message));
+}
+
+async function readStoredResponse(
+  settings: ResponseCacheSettings,
+  key: string,
+  now: number,
+): Promise<AssistantMessage | undefined> {
+  const file = path.join(settings.dir, `${key}.json`);
+  let stored: StoredResponse;
+  try {
+    stored = JSON.parse(await fs.readFile(file, "utf8")) as StoredResponse;
+  } catch {
+    return undefined;
+  }
+  if (!stored?.message || now - stored.storedAt > settings.ttlMs) {
+    await fs.rm(file, { force: true }).catch(() => undefined);
+    return undefined;
+  }
+  return stored.message;
+}
+
+/**
+ * Deletes expired replies, then the oldest ones until the directory is within `maxEntries` and
+ * `maxBytes`. Entry age comes from the file mtime, which matches `storedAt`.
+ */
+export async function pruneResponseCache(settings: ResponseCacheSettings, now = Date.now()) {
+  let names: string[];
+  try {
+    names = (await fs.readdir(settings.dir)).filter((name) => name.endsWith(".json"));
+  } catch {
+    return;
+  }
+  const entries: Array<{ file: string; mtimeMs: number; size: number }> = [];
+  for (const name of names) {
+    const file = path.join(settings.dir, name);
+    const stat = await fs.stat(file).catch(() => null);
+    if (!stat?.isFile()) {
+      continue;
+    }
+    if (now - stat.mtimeMs > settings.ttlMs) {
+      await fs.rm(file, { force: true }).catch(() => undefined);
+      continue;
+    }
+    entries.push({ file, mtimeMs: stat.mtimeMs, size: stat.size });
+  }
+  entries.sort((a, b) => a.mtimeMs - b.mtimeMs);
+  let totalBytes = entries.reduce((sum, entry) => sum + entry.size, 0);
+  let count = entries.length;
+  for (const entry of entries) {
+    if (count <= settings.maxEntries && totalBytes <= settings.maxBytes) {
+      break;
+    }
+    await fs.rm(entry.file, { force: true }).catch(() => undefined);
+    count -= 1;
+    totalBytes -= entry.size;
+  }
+}
+
+async function writeStoredResponse(
+  settings: ResponseCacheSettings,
+  key: string,
+  message: AssistantMessage,
+  now: number,
+) {
+  const stored: StoredResponse = {
+    storedAt: now,
+    provider: message.provider,
+    model: message.model,
+    message,
+  };
+  const file = path.join(settings.dir, `${key}.json`);
+  const tmp = `${file}.${process.pid}.tmp`;
+  await fs.mkdir(settings.dir, { recursive: true });
+  await fs.writeFile(tmp, JSON.stringify(stored), "utf8");
+  await fs.rename(tmp, file);
+}
+
+function replayResponse(message: AssistantMessage) {
+  // A replay costs nothing, so usage is zeroed to keep cost and budget accounting honest.
+  const replayed: AssistantMessage = {
+    ...message,
+    timestamp: Date.now(),
+    usage: {
+      input: 0,
+      output: 0,
+      cacheRead: 0,
+      cacheWrite: 0,
+      totalTokens: 0,
+      cost: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0, total: 0 },
+    },
+  };
+  replayedMessages.add(replayed);
+  const stream = createAssistantMessageEventStream();
+  stream.push({
+    type: "done",
+    reason: replayed.stopReason as "stop" | "length" | "toolUse",
+    message: replayed,
+  });
+  stream.end();
+  return stream;
+}
+
+/**
+ * Serves byte-identical model requests from disk. Misses go to the provider and the finished
+ * reply is stored; errors and aborted replies are not.
+ */
+export function wrapStreamFnWithResponseCache(
+  baseStreamFn: StreamFn | undefined,
+  settings: ResponseCacheSettings,
+  now: () => number = Date.now,
+): StreamFn {
+  const underlying = baseStreamFn ?? streamSimple;
+  return async (model, context, options) => {
+    const key = hashResponseRequest({
+      provider: model.provider,
+      api: model.api,
+      model: model.id,
+      context,
+      options,
+      salt: settings.salt,
+    });
+    const hit = await readStoredResponse(settings, key, now());
+    if (hit) {
+      return replayResponse(hit);
+    }
+    const stream = await underlying(model, context, options);
+    void stream
+      .result()
+      .then(async (message) => {
+        if (CACHEABLE_STOP_REASONS.has(message.stopReason)) {
+          await writeStoredResponse(settings, key, message, now());
+          await pruneResponseCache(settings);
+        }
+      })
+      .catch(() => undefined);
+    return stream;
+  };
+}
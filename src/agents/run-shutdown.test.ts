@@ -0,0 +1,69 @@
+import { afterEach, describe, expect, it, vi } from "vitest";
+import type { ProcessSession } from "./bash-process-registry.js";
+import { addSession, resetProcessRegistryForTests } from "./bash-process-registry.js";
+import {
+  clearActiveEmbeddedRun,
+  isEmbeddedPiRunActive,
+  setActiveEmbeddedRun,
+  type EmbeddedPiQueueHandle,
+} from "./pi-embedded-runner/runs.js";
+import { flushAgentRunsForShutdown, resolveSignalExitCode } from "./run-shutdown.js";
+
+const killSession = vi.fn();
+
+vi.mock("./bash-tools.shared.js", () => ({
+  killSession: (session: unknown) => killSession(session),
+}));
+
+function makeSession(id: string, exited: boolean): ProcessSession {
+  return {
+    id,
+    command: "npm run dev",
+    pid: 4242,
+    startedAt: Date.now(),
+    maxOutputChars: 1000,
+    totalOutputChars: 0,
+    pendingStdout: [],
+    pendingStderr: [],
+    pendingStdoutChars: 0,
+    pendingStderrChars: 0,
+    aggregated: "",
+    tail: "",
+    exited,
+    truncated: false,
+    backgrounded: true,
+  };
+}
+
+describe("flushAgentRunsForShutdown", () => {
+  afterEach(() => {
+    resetProcessRegistryForTests();
+    killSession.mockReset();
+  });
+
+  it("aborts active runs, waits for them to settle, and kills running tool processes", async () => {
+    const handle: EmbeddedPiQueueHandle = {
+      queueMessage: async () => {},
+      isStreaming: () => true,
+      isCompacting: () => false,
+      // The real runner persists the partial reply and clears itself asynchronously.
+      abort: () => setTimeout(() => clearActiveEmbeddedRun("sess-shutdown", handle), 10),
+    };
+    setActiveEmbeddedRun("sess-shutdown", handle);
+    addSession(makeSession("dev-server", false));
+    addSession(makeSession("done", true));
+
+    const result = await flushAgentRunsForShutdown(1_000);
+
+    expect(result).toEqual({ abortedRuns: 1, unfinishedRuns: 0, killedProcesses: 1 });
+    expect(isEmbeddedPiRunActive("sess-shutdown")).toBe(false);
+    expect(killSession).toHaveBeenCalledTimes(1);
+    expect(killSession).toHaveBeenCalledWith(expect.objectContaining({ id: "dev-server" }));
+  });
+
+  it("maps signals to 128 + signal number", () => {
+    expect(resolveSignalExitCode("SIGHUP")).toBe(129);
+    expect(resolveSignalExitCode("SIGINT")).toBe(130);
+    expect(resolveSignalExitCode("SIGTERM")).toBe(143);
+  });
+});
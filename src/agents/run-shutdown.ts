@@ -0,0 +1,105 @@
+import os from "node:os";
+import { createSubsystemLogger } from "../logging/subsystem.js";
+import { listAllRunningSessions } from "./bash-process-registry.js";
+import { closeAllPersistentShells } from "./bash-tools.persistent-shell.js";
+import { killSession } from "./bash-tools.shared.js";
+import {
+  abortEmbeddedPiRun,
+  listActiveEmbeddedRunSessionIds,
+  waitForEmbeddedPiRunEnd,
+} from "./pi-embedded-runner/runs.js";
+
+const log = createSubsystemLogger("agents/shutdown");
+
+export const SHUTDOWN_FLUSH_TIMEOUT_MS = 3_000;
+
+export type AgentShutdownResult = {
+  abortedRuns: number;
+  unfinishedRuns: number;
+  killedProcesses: number;
+};
+
+/** Conventional `128 + signal number` exit code, so callers can tell a signal stop from a crash. */
+export function resolveSignalExitCode(signal: NodeJS.Signals): number {
+  const number = os.constants.signals[signal];
+  return typeof number === "number" ? 128 + number : 1;
+}
+
+/**
+ * Aborts every in-flight embedded run and waits for it to settle, so the partial assistant message
+ * is written to the session transcript, then kills exec-tool processes (background jobs and
+ * persistent shells) that would otherwise outlive this process.
+ */
+export async function flushAgentRunsForShutdown(
+  timeoutMs = SHUTDOWN_FLUSH_TIMEOUT_MS,
+): Promise<AgentShutdownResult> {
+  const sessionIds = listActiveEmbeddedRunSessionIds().filter((id) => abortEmbeddedPiRun(id));
+  const settled = await Promise.all(
+    sessionIds.map((sessionId) => waitForEmbeddedPiRunEnd(sessionId, timeoutMs)),
+  );
+  const unfinishedRuns = settled.filter((ended) => !ended).length;
+  if (unfinishedRuns > 0) {
+    log.warn(`shutdown: ${unfinishedRuns} run(s) did not finish within ${timeoutMs}ms`);
+  }
+
+  let killedProcesses = 0;
+  for (const session of listAllRunningSessions()) {
+    if (session.exited) {
+      continue;
+    }
+    killSession(session);
+    killedProcesses += 1;
+  }
+  killedProcesses += closeAllPersistentShells();
+
+  if (sessionIds.length > 0 || killedProcesses > 0) {
+    log.info(
+      `shutdown: aborted ${sessionIds.length} run(s), killed ${killedProcesses} tool process(es)`,
+    );
+  }
+  return { abortedRuns: sessionIds.length, unfinishedRuns, killedProcesses };
+}
+
+/**
+ * Installs SIGTERM/SIGHUP handlers for a foreground agent command: the first signal flushes the
+ * session and exits with {@link resolveSignalExitCode}; a second one exits immediately.
+ */
+export function installAgentShutdownHandlers(params: {
+  exit: (code: number) => void;
+  signals?: NodeJS.Signals[];
+  timeoutMs?: number;
+}): () => void {
+  const signals = params.signals ?? (["SIGTERM", "SIGHUP"] as NodeJS.Signals[]);
+  let stopping = false;
+  const listeners = new Map<NodeJS.Signals, () => void>();
+  const dispose = () => {
+    for (const [signal, listener] of listeners) {
+      process.off(signal, listener);
+    }
+    listeners.clear();
+  };
+  for (const signal of signals) {
+    const listener = () => {
+      const code = resolveSignalExitCode(signal);
+      if (stopping) {
+        params.exit(code);
+        return;
+      }
+      stopping = true;
+      log.info(`received ${signal}; saving session before exit`);
+      void flushAgentRunsForShutdown(params.timeoutMs)
+        .catch((err) => log.error(`shutdown flush failed: ${String(err)}`))
+        .finally(() => {
+          dispose();
+          params.exit(code);
+        });
+    };
+    try {
+      process.on(signal, listener);
+      listeners.set(signal, listener);
+    } catch {
+      // Unsupported signal on this platform.
+    }
+  }
+  return dispose;
+}
@@ -0,0 +1,53 @@
+import { describe, expect, it } from "vitest";
+import { createRunTimingsTracker, formatRunTimings } from "./run-timings.js";
+
+describe("createRunTimingsTracker", () => {
+  it("tracks first-request TTFT, streaming throughput, and tool time", () => {
+    const tracker = createRunTimingsTracker();
+    tracker.requestStart(1000);
+    tracker.firstToken(1500);
+    tracker.firstToken(1600);
+    tracker.requestEnd({ outputTokens: 100, now: 3500 });
+    tracker.toolEnd(400);
+    tracker.toolEnd(undefined);
+    tracker.requestStart(4000);
+    tracker.firstToken(4200);
+    tracker.requestEnd({ outputTokens: 100, now: 6200 });
+
+    const timings = tracker.snapshot();
+    expect(timings).toMatchObject({
+      ttftMs: 500,
+      modelMs: 4700,
+      modelRequests: 2,
+      toolMs: 400,
+      toolCalls: 2,
+    });
+    // 200 tokens over 2s + 2s of streaming
+    expect(timings.outputTokensPerSecond).toBeCloseTo(50);
+  });
+
+  it("ignores message ends without a request start", () => {
+    const tracker = createRunTimingsTracker();
+    tracker.requestEnd({ outputTokens: 10, now: 100 });
+    expect(tracker.snapshot()).toMatchObject({ modelRequests: 0, modelMs: 0 });
+    expect(tracker.snapshot().outputTokensPerSecond).toBeUndefined();
+  });
+});
+
+describe("formatRunTimings", () => {
+  it("skips missing parts", () => {
+    expect(
+      formatRunTimings(
+        {
+          ttftMs: 620,
+          modelMs: 3100,
+          modelRequests: 2,
+          outputTokensPerSecond: 48.27,
+          toolMs: 0,
+          toolCalls: 0,
+        },
+        4210,
+      ),
+    ).toBe("⏱️ 4.21s · TTFT 0.62s · 48.3 tok/s · model 3.10s (2)");
+  });
+});
@@ -0,0 +1,103 @@
+/** Latency and throughput for one agent run, reported in `meta.timings`. */
+export type RunTimings = {
+  /** Request start to first streamed token, for the run's first model request. */
+  ttftMs?: number;
+  /** Time spent in model requests (request start to message end), summed. */
+  modelMs: number;
+  modelRequests: number;
+  /** Output tokens per second while streaming (after the first token), across requests. */
+  outputTokensPerSecond?: number;
+  /** Time spent executing tools, summed. */
+  toolMs: number;
+  toolCalls: number;
+};
+
+export type RunTimingsTracker = {
+  requestStart: (now?: number) => void;
+  firstToken: (now?: number) => void;
+  requestEnd: (params: { outputTokens?: number; now?: number }) => void;
+  toolEnd: (durationMs?: number) => void;
+  snapshot: () => RunTimings;
+};
+
+/**
+ * Collects run timings from agent events: `requestStart` on turn start, `firstToken` on the first
+ * streamed delta, `requestEnd` on the assistant message end, and `toolEnd` per tool result.
+ */
+export function createRunTimingsTracker(): RunTimingsTracker {
+  let requestStartedAt: number | undefined;
+  let firstTokenAt: number | undefined;
+  let ttftMs: number | undefined;
+  let modelMs = 0;
+  let modelRequests = 0;
+  let streamMs = 0;
+  let streamedTokens = 0;
+  let toolMs = 0;
+  let toolCalls = 0;
+
+  return {
+    requestStart: (now = Date.now()) => {
+      requestStartedAt = now;
+      firstTokenAt = undefined;
+    },
+    firstToken: (now = Date.now()) => {
+      if (firstTokenAt !== undefined) {
+        return;
+      }
+      firstTokenAt = now;
+      if (ttftMs === undefined && requestStartedAt !== undefined) {
+        ttftMs = now - requestStartedAt;
+      }
+    },
+    requestEnd: ({ outputTokens, now = Date.now() }) => {
+      if (requestStartedAt === undefined) {
+        return;
+      }
+      modelRequests += 1;
+      modelMs += now - requestStartedAt;
+      if (firstTokenAt !== undefined && outputTokens && now > firstTokenAt) {
+        streamMs += now - firstTokenAt;
+        streamedTokens += outputTokens;
+      }
+      requestStartedAt = undefined;
+      firstTokenAt = undefined;
+    },
+    toolEnd: (durationMs) => {
+      toolCalls += 1;
+      toolMs += durationMs ?? 0;
+    },
+    snapshot: () => ({
+      ttftMs,
+      modelMs,
+      modelRequests,
+      outputTokensPerSecond: streamMs > 0 ? (streamedTokens / streamMs) * 1000 : undefined,
+      toolMs,
+      toolCalls,
+    }),
+  };
+}
+
+function formatSeconds(ms: number): string {
+  return `${(ms / 1000).toFixed(ms < 10_000 ? 2 : 1)}s`;
+}
+
+/** One-line summary, e.g. `⏱️ 4.21s · TTFT 0.62s · 48.3 tok/s · model 3.10s (2) · tools 0.95s (3)`. */
+export function formatRunTimings(timings: RunTimings, totalMs?: number): string {
+  const parts: string[] = [];
+  if (typeof totalMs === "number") {
+    parts.push(formatSeconds(totalMs));
+  }
+  if (timings.ttftMs !== undefined) {
+    parts.push(`TTFT ${formatSeconds(timings.ttftMs)}`);
+  }
+  if (timings.outputTokensPerSecond !== undefined) {
+    parts.push(`${timings.outputTokensPerSecond.toFixed(1)} tok/s`);
+  }
+  if (timings.modelRequests > 0) {
+    parts.push(`model ${formatSeconds(timings.modelMs)} (${timings.modelRequests})`);
+  }
+  if (timings.toolCalls > 0) {
+    parts.push(`tools ${formatSeconds(timings.toolMs)} (${timings.toolCalls})`);
+  }
+  return `⏱️ ${parts.join(" · ")}`;
+}
@@ -61,6 +61,11 @@ describe("sandbox explain helpers", () => {
       "write",
       "edit",
       "apply_patch",
+      "grep",
+      "find",
+      "ls",
+      "notebook_read",
+      "notebook_edit",
       "image",
     ]);
   });
@@ -0,0 +1,37 @@
+import path from "node:path";
+import { describe, expect, it } from "vitest";
+import { resolveSandboxPath } from "./sandbox-paths.js";
+
+describe("resolveSandboxPath", () => {
+  const root = path.resolve("/workspace/project");
+
+  it("resolves paths inside the root and rejects escapes", () => {
+    expect(resolveSandboxPath({ filePath: "src/a.ts", cwd: root, root }).relative).toBe(
+      path.join("src", "a.ts"),
+    );
+    expect(() => resolveSandboxPath({ filePath: "../other", cwd: root, root })).toThrow(
+      /escapes sandbox root/,
+    );
+  });
+
+  it.skipIf(process.platform !== "win32")("rejects other drives and UNC shares", () => {
+    for (const filePath of ["D:\\data\\a.txt", "\\\\server\\share\\a.txt", "//server/share/a"]) {
+      expect(() => resolveSandboxPath({ filePath, cwd: root, root })).toThrow(/escapes/);
+    }
+  });
+
+  it.skipIf(process.platform !== "win32")(
+    "rejects drive-relative, device, stream, and reserved-name paths",
+    () => {
+      const drive = root.slice(0, 2);
+      for (const filePath of [`${drive}notes.txt`, "\\\\?\\C:\\Windows", "\\\\.\\PhysicalDrive0"]) {
+        expect(() => resolveSandboxPath({ filePath, cwd: root, root })).toThrow();
+      }
+      for (const filePath of ["notes.txt:hidden", "logs\\NUL", "con.txt"]) {
+        expect(() => resolveSandboxPath({ filePath, cwd: root, root })).toThrow(
+          /Invalid Windows path segment/,
+        );
+      }
+    },
+  );
+});
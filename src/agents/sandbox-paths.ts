@@ -6,6 +6,12 @@ import { fileURLToPath } from "node:url";
 const UNICODE_SPACES = /[\u00A0\u2000-\u200A\u202F\u205F\u3000]/g;
 const HTTP_URL_RE = /^https?:\/\//i;
 const DATA_URL_RE = /^data:/i;
+// `C:foo` resolves against a hidden per-drive cwd and `\\?\`/`\\.\` paths skip normalization,
+// so neither can be pinned inside a root. UNC shares fall out of `path.relative` as absolute.
+const WIN_DRIVE_RELATIVE_RE = /^[a-z]:(?![\\/])/i;
+const WIN_DEVICE_PATH_RE = /^[\\/]{2}[?.][\\/]/;
+// DOS device names open the device from any directory; `:` in a name addresses an NTFS stream.
+const WIN_RESERVED_NAME_RE = /^(con|prn|aux|nul|com\d|lpt\d)(\..*)?$/i;
 
 function normalizeUnicodeSpaces(str: string): string {
   return str.replace(UNICODE_SPACES, " ");
@@ -13,16 +19,12 @@ function normalizeUnicodeSpaces(str: string): string {
 
 function expandPath(filePath: string): string {
   const normalized = normalizeUnicodeSpaces(filePath);
-  if (normalized === "~") {
-    return os.homedir();
-  }
-  if (normalized.startsWith("~/")) {
-    return os.homedir() + normalized.slice(1);
-  }
-  return normalized;
+  // Windows accepts both separators after `~`; elsewhere `~\` is a literal name.
+  const homePrefix = process.platform === "win32" ? /^~(?=$|[\\/])/ : /^~(?=$|\/)/;
+  return homePrefix.test(normalized) ? os.homedir() + normalized.slice(1) : normalized;
 }
 
-function resolveToCwd(filePath: string, cwd: string): string {
+export function resolveToCwd(filePath: string, cwd: string): string {
   const expanded = expandPath(filePath);
   if (path.isAbsolute(expanded)) {
     return expanded;
@@ -30,6 +32,19 @@ function resolveToCwd(filePath: string, cwd: string): string {
   return path.resolve(cwd, expanded);
 }
 
+function assertWindowsPathAllowed(filePath: string, relative: string) {
+  const expanded = expandPath(filePath);
+  if (WIN_DRIVE_RELATIVE_RE.test(expanded) || WIN_DEVICE_PATH_RE.test(expanded)) {
+    throw new Error(`Unsupported Windows path: ${filePath}`);
+  }
+  const invalid = relative
+    .split(path.sep)
+    .find((part) => part.includes(":") || WIN_RESERVED_NAME_RE.test(part.trimEnd()));
+  if (invalid) {
+    throw new Error(`Invalid Windows path segment (${invalid}): ${filePath}`);
+  }
+}
+
 export function resolveSandboxPath(params: { filePath: string; cwd: string; root: string }): {
   resolved: string;
   relative: string;
@@ -43,6 +58,9 @@ export function resolveSandboxPath(params: { filePath: string; cwd: string; root
   if (relative.startsWith("..") || path.isAbsolute(relative)) {
     throw new Error(`Path escapes sandbox root (${shortPath(rootResolved)}): ${params.filePath}`);
   }
+  if (process.platform === "win32") {
+    assertWindowsPathAllowed(params.filePath, relative);
+  }
   return { resolved, relative };
 }
 
@@ -13,10 +13,17 @@ export const DEFAULT_SANDBOX_MAX_AGE_DAYS = 7;
 export const DEFAULT_TOOL_ALLOW = [
   "exec",
   "process",
+  "run_code",
   "read",
   "write",
   "edit",
   "apply_patch",
+  "grep",
+  "find",
+  "ls",
+  "notebook_read",
+  "notebook_edit",
+  "code_outline",
   "image",
   "sessions_list",
   "sessions_history",
@@ -0,0 +1,91 @@
+import { completeSimple, type TextContent } from "@mariozechner/pi-ai";
+import type { OpenClawConfig } from "../config/config.js";
+import { cleanGeneratedTitle } from "../sessions/session-title.js";
+import { getApiKeyForModel, requireApiKey } from "./model-auth.js";
+import { buildModelAliasIndex, resolveModelRefFromString } from "./model-selection.js";
+import { resolveModel } from "./pi-embedded-runner/model.js";
+
+const TITLE_MAX_TOKENS = 64;
+const TITLE_TIMEOUT_MS = 20_000;
+const TITLE_EXCERPT_CHARS = 2_000;
+
+export function isSessionAutoTitleEnabled(cfg: OpenClawConfig | undefined): boolean {
+  return cfg?.session?.autoTitle?.enabled === true;
+}
+
+export function buildSessionTitlePrompt(params: {
+  userText: string;
+  assistantText?: string;
+}): string {
+  const lines = [
+    "Write a title for this conversation: 3-6 words, sentence case, no quotes, no trailing period.",
+    "Describe the task, not the participants. Reply with the title only.",
+    "",
+    "<user>",
+    params.userText.slice(0, TITLE_EXCERPT_CHARS),
+    "</user>",
+  ];
+  if (params.assistantText?.trim()) {
+    lines.push("<assistant>", params.assistantText.slice(0, TITLE_EXCERPT_CHARS), "</assistant>");
+  }
+  return lines.join("\n");
+}
+
+/**
+ * One small completion that names the session from its first exchange. Uses
+ * `session.autoTitle.model` when set, otherwise the model that ran the session.
+ */
+export async function generateSessionTitle(params: {
+  cfg: OpenClawConfig;
+  provider: string;
+  model: string;
+  agentDir?: string;
+  userText: string;
+  assistantText?: string;
+}): Promise<string | undefined> {
+  const raw = params.cfg.session?.autoTitle?.model?.trim();
+  const ref = raw
+    ? resolveModelRefFromString({
+        raw,
+        defaultProvider: params.provider,
+        aliasIndex: buildModelAliasIndex({ cfg: params.cfg, defaultProvider: params.provider }),
+      })?.ref
+    : undefined;
+  const provider = ref?.provider ?? params.provider;
+  const modelId = ref?.model ?? params.model;
+  const resolved = resolveModel(provider, modelId, params.agentDir, params.cfg);
+  if (!resolved.model) {
+    throw new Error(resolved.error ?? `Unknown model: ${provider}/${modelId}`);
+  }
+  const apiKey = requireApiKey(
+    await getApiKeyForModel({ model: resolved.model, cfg: params.cfg, agentDir: params.agentDir }),
+    provider,
+  );
+  const controller = new AbortController();
+  const timeout = setTimeout(() => controller.abort(), TITLE_TIMEOUT_MS);
+  try {
+    const res = await completeSimple(
+      resolved.model,
+      {
+        messages: [
+          {
+            role: "user",
+            content: buildSessionTitlePrompt(params),
+            timestamp: Date.now(),
+          },
+        ],
+      },
+      { apiKey, maxTokens: TITLE_MAX_TOKENS, signal: controller.signal },
+    );
+    if (res.stopReason === "error" || res.stopReason === "aborted") {
+      throw new Error(res.errorMessage || `request ${res.stopReason}`);
+    }
+    const text = res.content
+      .filter((block): block is TextContent => block.type === "text")
+      .map((block) => block.text)
+      .join("\n");
+    return cleanGeneratedTitle(text);
+  } finally {
+    clearTimeout(timeout);
+  }
+}
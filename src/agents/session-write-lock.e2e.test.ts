@@ -2,7 +2,7 @@ import fs from "node:fs/promises";
 import os from "node:os";
 import path from "node:path";
 import { describe, expect, it } from "vitest";
-import { __testing, acquireSessionWriteLock } from "./session-write-lock.js";
+import { __testing, acquireSessionWriteLock, SessionLockedError } from "./session-write-lock.js";
 
 describe("acquireSessionWriteLock", () => {
   it("reuses locks across symlinked session paths", async () => {
@@ -72,6 +72,54 @@ describe("acquireSessionWriteLock", () => {
     }
   });
 
+  it("reports live owners and takes the lock over when forced", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-lock-"));
+    try {
+      const sessionFile = path.join(root, "session.jsonl");
+      const lockPath = `${sessionFile}.lock`;
+      // The parent process is alive, so the lock is neither stale nor orphaned.
+      await fs.writeFile(
+        lockPath,
+        JSON.stringify({ pid: process.ppid, createdAt: new Date().toISOString() }),
+        "utf8",
+      );
+
+      const error = await acquireSessionWriteLock({ sessionFile, timeoutMs: 100 }).catch(
+        (err: unknown) => err,
+      );
+      expect(error).toBeInstanceOf(SessionLockedError);
+      expect(error).toMatchObject({ ownerPid: process.ppid, lockPath });
+
+      const lock = await acquireSessionWriteLock({ sessionFile, timeoutMs: 100, force: true });
+      const payload = JSON.parse(await fs.readFile(lockPath, "utf8")) as { pid: number };
+      expect(payload.pid).toBe(process.pid);
+      await lock.release();
+    } finally {
+      await fs.rm(root, { recursive: true, force: true });
+    }
+  });
+
+  it("leaves a lock alone once another process has taken it over", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-lock-"));
+    try {
+      const sessionFile = path.join(root, "session.jsonl");
+      const lockPath = `${sessionFile}.lock`;
+      const lock = await acquireSessionWriteLock({ sessionFile, timeoutMs: 100 });
+      // What a `--force` run in another process leaves behind.
+      const takeover = JSON.stringify({
+        pid: process.ppid,
+        createdAt: new Date().toISOString(),
+        token: "other-owner",
+      });
+      await fs.writeFile(lockPath, takeover, "utf8");
+
+      await lock.release();
+      await expect(fs.readFile(lockPath, "utf8")).resolves.toBe(takeover);
+    } finally {
+      await fs.rm(root, { recursive: true, force: true });
+    }
+  });
+
   it("removes held locks on termination signals", async () => {
     const signals = ["SIGINT", "SIGTERM", "SIGQUIT", "SIGABRT"] as const;
     for (const signal of signals) {
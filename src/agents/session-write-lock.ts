@@ -1,3 +1,4 @@
+import { randomUUID } from "node:crypto";
 import fsSync from "node:fs";
 import fs from "node:fs/promises";
 import path from "node:path";
@@ -5,12 +6,15 @@ import path from "node:path";
 type LockFilePayload = {
   pid: number;
   createdAt: string;
+  /** Identifies this acquisition; a holder whose lock was forced away must not remove it. */
+  token?: string;
 };
 
 type HeldLock = {
   count: number;
   handle: fs.FileHandle;
   lockPath: string;
+  token: string;
 };
 
 const CLEANUP_SIGNALS = ["SIGINT", "SIGTERM", "SIGQUIT", "SIGABRT"] as const;
@@ -48,6 +52,22 @@ function resolveCleanupState(): CleanupState {
   return proc[CLEANUP_STATE_KEY];
 }
 
+function parseLockPayload(raw: string): LockFilePayload | null {
+  try {
+    const parsed = JSON.parse(raw) as Partial<LockFilePayload>;
+    if (typeof parsed.pid !== "number") {
+      return null;
+    }
+    if (typeof parsed.createdAt !== "string") {
+      return null;
+    }
+    const token = typeof parsed.token === "string" ? parsed.token : undefined;
+    return { pid: parsed.pid, createdAt: parsed.createdAt, token };
+  } catch {
+    return null;
+  }
+}
+
 function isAlive(pid: number): boolean {
   if (!Number.isFinite(pid) || pid <= 0) {
     return false;
@@ -74,7 +94,10 @@ function releaseAllLocksSync(): void {
       // Ignore errors during cleanup - best effort
     }
     try {
-      fsSync.rmSync(held.lockPath, { force: true });
+      const payload = parseLockPayload(fsSync.readFileSync(held.lockPath, "utf8"));
+      if (payload?.token === held.token) {
+        fsSync.rmSync(held.lockPath, { force: true });
+      }
     } catch {
       // Ignore errors during cleanup - best effort
     }
@@ -125,26 +148,52 @@ function registerCleanupHandlers(): void {
   }
 }
 
+/** Another live process holds the lock and did not release it within the timeout. */
+export class SessionLockedError extends Error {
+  readonly lockPath: string;
+  readonly ownerPid?: number;
+
+  constructor(params: { lockPath: string; ownerPid?: number; timeoutMs: number }) {
+    const owner = params.ownerPid ? `pid=${params.ownerPid}` : "unknown";
+    super(`session file locked (timeout ${params.timeoutMs}ms): ${owner} ${params.lockPath}`);
+    this.name = "SessionLockedError";
+    this.lockPath = params.lockPath;
+    this.ownerPid = params.ownerPid;
+  }
+}
+
 async function readLockPayload(lockPath: string): Promise<LockFilePayload | null> {
   try {
-    const raw = await fs.readFile(lockPath, "utf8");
-    const parsed = JSON.parse(raw) as Partial<LockFilePayload>;
-    if (typeof parsed.pid !== "number") {
-      return null;
-    }
-    if (typeof parsed.createdAt !== "string") {
-      return null;
-    }
-    return { pid: parsed.pid, createdAt: parsed.createdAt };
+    return parseLockPayload(await fs.readFile(lockPath, "utf8"));
   } catch {
     return null;
   }
 }
 
+async function releaseHeldLock(normalizedSessionFile: string): Promise<void> {
+  const current = HELD_LOCKS.get(normalizedSessionFile);
+  if (!current) {
+    return;
+  }
+  current.count -= 1;
+  if (current.count > 0) {
+    return;
+  }
+  HELD_LOCKS.delete(normalizedSessionFile);
+  await current.handle.close();
+  // Another process may have forced the lock away and now owns the file; leave its lock in place.
+  const payload = await readLockPayload(current.lockPath);
+  if (payload?.token === current.token) {
+    await fs.rm(current.lockPath, { force: true });
+  }
+}
+
 export async function acquireSessionWriteLock(params: {
   sessionFile: string;
   timeoutMs?: number;
   staleMs?: number;
+  /** Take the lock over even when a live process holds it. */
+  force?: boolean;
 }): Promise<{
   release: () => Promise<void>;
 }> {
@@ -166,21 +215,7 @@ export async function acquireSessionWriteLock(params: {
   const held = HELD_LOCKS.get(normalizedSessionFile);
   if (held) {
     held.count += 1;
-    return {
-      release: async () => {
-        const current = HELD_LOCKS.get(normalizedSessionFile);
-        if (!current) {
-          return;
-        }
-        current.count -= 1;
-        if (current.count > 0) {
-          return;
-        }
-        HELD_LOCKS.delete(normalizedSessionFile);
-        await current.handle.close();
-        await fs.rm(current.lockPath, { force: true });
-      },
-    };
+    return { release: () => releaseHeldLock(normalizedSessionFile) };
   }
 
   const startedAt = Date.now();
@@ -189,26 +224,15 @@ export async function acquireSessionWriteLock(params: {
     attempt += 1;
     try {
       const handle = await fs.open(lockPath, "wx");
-      await handle.writeFile(
-        JSON.stringify({ pid: process.pid, createdAt: new Date().toISOString() }, null, 2),
-        "utf8",
-      );
-      HELD_LOCKS.set(normalizedSessionFile, { count: 1, handle, lockPath });
-      return {
-        release: async () => {
-          const current = HELD_LOCKS.get(normalizedSessionFile);
-          if (!current) {
-            return;
-          }
-          current.count -= 1;
-          if (current.count > 0) {
-            return;
-          }
-          HELD_LOCKS.delete(normalizedSessionFile);
-          await current.handle.close();
-          await fs.rm(current.lockPath, { force: true });
-        },
+      const token = randomUUID();
+      const payload: LockFilePayload = {
+        pid: process.pid,
+        createdAt: new Date().toISOString(),
+        token,
       };
+      await handle.writeFile(JSON.stringify(payload, null, 2), "utf8");
+      HELD_LOCKS.set(normalizedSessionFile, { count: 1, handle, lockPath, token });
+      return { release: () => releaseHeldLock(normalizedSessionFile) };
     } catch (err) {
       const code = (err as { code?: unknown }).code;
       if (code !== "EEXIST") {
@@ -218,7 +242,7 @@ export async function acquireSessionWriteLock(params: {
       const createdAt = payload?.createdAt ? Date.parse(payload.createdAt) : NaN;
       const stale = !Number.isFinite(createdAt) || Date.now() - createdAt > staleMs;
       const alive = payload?.pid ? isAlive(payload.pid) : false;
-      if (stale || !alive) {
+      if (stale || !alive || params.force) {
         await fs.rm(lockPath, { force: true });
         continue;
       }
@@ -229,8 +253,7 @@ export async function acquireSessionWriteLock(params: {
   }
 
   const payload = await readLockPayload(lockPath);
-  const owner = payload?.pid ? `pid=${payload.pid}` : "unknown";
-  throw new Error(`session file locked (timeout ${timeoutMs}ms): ${owner} ${lockPath}`);
+  throw new SessionLockedError({ lockPath, ownerPid: payload?.pid, timeoutMs });
 }
 
 export const __testing = {
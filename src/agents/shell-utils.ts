@@ -1,6 +1,7 @@
 import { spawn } from "node:child_process";
 import fs from "node:fs";
 import path from "node:path";
+import { sanitizeTerminalText } from "../terminal/ansi.js";
 
 function resolvePowerShellPath(): string {
   const systemRoot = process.env.SystemRoot || process.env.WINDIR;
@@ -49,7 +50,7 @@ export function getShellConfig(): { shell: string; args: string[] } {
   return { shell, args: ["-c"] };
 }
 
-function resolveShellFromPath(name: string): string | undefined {
+export function resolveShellFromPath(name: string): string | undefined {
   const envPath = process.env.PATH ?? "";
   if (!envPath) {
     return undefined;
@@ -129,22 +130,7 @@ export function sanitizeBinaryOutput(text: string): string {
   if (!scrubbed) {
     return scrubbed;
   }
-  const chunks: string[] = [];
-  for (const char of scrubbed) {
-    const code = char.codePointAt(0);
-    if (code == null) {
-      continue;
-    }
-    if (code === 0x09 || code === 0x0a || code === 0x0d) {
-      chunks.push(char);
-      continue;
-    }
-    if (code < 0x20) {
-      continue;
-    }
-    chunks.push(char);
-  }
-  return chunks.join("");
+  return sanitizeTerminalText(scrubbed);
 }
 
 export function killProcessTree(pid: number): void {
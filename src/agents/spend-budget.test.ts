@@ -0,0 +1,59 @@
+import { describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { checkSpendBudget, formatSpendBudgetNotice } from "./spend-budget.js";
+
+const withBudget = (budget: NonNullable<OpenClawConfig["agents"]>["defaults"]) =>
+  ({ agents: { defaults: budget } }) as OpenClawConfig;
+
+describe("checkSpendBudget", () => {
+  const nowMs = new Date(2026, 9, 16, 15, 0).getTime();
+
+  it("returns null without a configured budget", async () => {
+    const loadPeriodCost = vi.fn(async () => 100);
+    expect(await checkSpendBudget({ cfg: {}, agentId: "main", loadPeriodCost })).toBeNull();
+    expect(loadPeriodCost).not.toHaveBeenCalled();
+  });
+
+  it("warns at the threshold and reports the closest limit", async () => {
+    const status = await checkSpendBudget({
+      cfg: withBudget({ budget: { sessionUsd: 5, dailyUsd: 10, monthlyUsd: 100 } }),
+      agentId: "main",
+      sessionId: "sess-1",
+      sessionCostUsd: 1,
+      nowMs,
+      loadPeriodCost: async ({ startMs }) =>
+        startMs === new Date(2026, 9, 16).getTime() ? 8.5 : 20,
+    });
+    expect(status?.level).toBe("warn");
+    expect(status?.checks.map((check) => check.periodKey)).toEqual([
+      "session:sess-1",
+      "daily:2026-10-16",
+      "monthly:2026-10",
+    ]);
+    expect(status?.worst?.scope).toBe("daily");
+    expect(formatSpendBudgetNotice(status)).toBe("⚠️ Daily budget: $8.50 of $10.00 (85%)");
+  });
+
+  it("flags an exceeded session budget and honors warnPercent", async () => {
+    const cfg = withBudget({ budget: { sessionUsd: 2, warnPercent: 50 } });
+    const exceeded = await checkSpendBudget({
+      cfg,
+      agentId: "main",
+      sessionId: "sess-2",
+      sessionCostUsd: 2.4,
+      nowMs,
+    });
+    expect(exceeded?.level).toBe("exceeded");
+    expect(formatSpendBudgetNotice(exceeded)).toMatch(/^🛑 Session budget/);
+
+    const low = await checkSpendBudget({
+      cfg,
+      agentId: "main",
+      sessionId: "sess-2",
+      sessionCostUsd: 0.5,
+      nowMs,
+    });
+    expect(low?.level).toBe("ok");
+    expect(formatSpendBudgetNotice(low)).toBeNull();
+  });
+});
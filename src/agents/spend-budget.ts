@@ -0,0 +1,146 @@
+import type { OpenClawConfig } from "../config/config.js";
+import type { AgentSpendBudgetConfig } from "../config/types.agent-defaults.js";
+import { loadCostUsageSummary } from "../infra/session-cost-usage.js";
+import { formatUsd } from "../utils/usage-format.js";
+
+export const DEFAULT_BUDGET_WARN_PERCENT = 80;
+
+/** Period spend is rescanned from transcripts at most this often per agent. */
+const PERIOD_SPEND_CACHE_MS = 60_000;
+
+export type SpendBudgetScope = "session" | "daily" | "monthly";
+
+export type SpendBudgetCheck = {
+  scope: SpendBudgetScope;
+  limitUsd: number;
+  spentUsd: number;
+  ratio: number;
+  /** Identifies the budget period (day, month, or session id); overrides are tied to it. */
+  periodKey: string;
+};
+
+export type SpendBudgetStatus = {
+  level: "ok" | "warn" | "exceeded";
+  warnPercent: number;
+  checks: SpendBudgetCheck[];
+  /** The check closest to (or furthest past) its limit. */
+  worst?: SpendBudgetCheck;
+};
+
+type PeriodCostLoader = (params: {
+  cfg: OpenClawConfig;
+  agentId: string;
+  startMs: number;
+  endMs: number;
+}) => Promise<number>;
+
+const periodSpendCache = new Map<string, { at: number; costUsd: number }>();
+
+const loadPeriodCostUsd: PeriodCostLoader = async ({ cfg, agentId, startMs, endMs }) => {
+  const key = `${agentId}:${startMs}`;
+  const cached = periodSpendCache.get(key);
+  if (cached && endMs - cached.at < PERIOD_SPEND_CACHE_MS) {
+    return cached.costUsd;
+  }
+  const summary = await loadCostUsageSummary({ startMs, endMs, config: cfg, agentId });
+  periodSpendCache.set(key, { at: endMs, costUsd: summary.totals.totalCost });
+  return summary.totals.totalCost;
+};
+
+function positive(value: number | undefined): number | undefined {
+  return typeof value === "number" && Number.isFinite(value) && value > 0 ? value : undefined;
+}
+
+export function resolveSpendBudget(cfg?: OpenClawConfig): AgentSpendBudgetConfig | undefined {
+  const budget = cfg?.agents?.defaults?.budget;
+  if (!budget) {
+    return undefined;
+  }
+  if (!positive(budget.sessionUsd) && !positive(budget.dailyUsd) && !positive(budget.monthlyUsd)) {
+    return undefined;
+  }
+  return budget;
+}
+
+function formatLocalDate(date: Date): string {
+  return date.toLocaleDateString("en-CA");
+}
+
+/**
+ * Compares spend against `agents.defaults.budget`. Session spend comes from the session entry;
+ * daily and monthly spend from the agent's transcripts, so limits hold across restarts.
+ * Returns null when no budget is configured.
+ */
+export async function checkSpendBudget(params: {
+  cfg: OpenClawConfig;
+  agentId: string;
+  sessionId?: string;
+  sessionCostUsd?: number;
+  nowMs?: number;
+  loadPeriodCost?: PeriodCostLoader;
+}): Promise<SpendBudgetStatus | null> {
+  const budget = resolveSpendBudget(params.cfg);
+  if (!budget) {
+    return null;
+  }
+  const nowMs = params.nowMs ?? Date.now();
+  const now = new Date(nowMs);
+  const loadPeriodCost = params.loadPeriodCost ?? loadPeriodCostUsd;
+  const checks: SpendBudgetCheck[] = [];
+  const push = (scope: SpendBudgetScope, limitUsd: number, spentUsd: number, periodKey: string) =>
+    checks.push({ scope, limitUsd, spentUsd, ratio: spentUsd / limitUsd, periodKey });
+
+  const sessionUsd = positive(budget.sessionUsd);
+  if (sessionUsd && params.sessionId) {
+    push("session", sessionUsd, params.sessionCostUsd ?? 0, `session:${params.sessionId}`);
+  }
+  const dailyUsd = positive(budget.dailyUsd);
+  if (dailyUsd) {
+    const startMs = new Date(now.getFullYear(), now.getMonth(), now.getDate()).getTime();
+    const spent = await loadPeriodCost({
+      cfg: params.cfg,
+      agentId: params.agentId,
+      startMs,
+      endMs: nowMs,
+    });
+    push("daily", dailyUsd, spent, `daily:${formatLocalDate(now)}`);
+  }
+  const monthlyUsd = positive(budget.monthlyUsd);
+  if (monthlyUsd) {
+    const startMs = new Date(now.getFullYear(), now.getMonth(), 1).getTime();
+    const spent = await loadPeriodCost({
+      cfg: params.cfg,
+      agentId: params.agentId,
+      startMs,
+      endMs: nowMs,
+    });
+    push("monthly", monthlyUsd, spent, `monthly:${formatLocalDate(now).slice(0, 7)}`);
+  }
+
+  const warnPercent = positive(budget.warnPercent) ?? DEFAULT_BUDGET_WARN_PERCENT;
+  const worst = checks.toSorted((a, b) => b.ratio - a.ratio)[0];
+  const level = !worst
+    ? "ok"
+    : worst.ratio >= 1
+      ? "exceeded"
+      : worst.ratio * 100 >= warnPercent
+        ? "warn"
+        : "ok";
+  return { level, warnPercent, checks, worst };
+}
+
+export function formatSpendBudgetCheck(check: SpendBudgetCheck): string {
+  const label = { session: "Session", daily: "Daily", monthly: "Monthly" }[check.scope];
+  const percent = Math.round(check.ratio * 100);
+  const spent = formatUsd(check.spentUsd) ?? "$0.00";
+  return `${label} budget: ${spent} of ${formatUsd(check.limitUsd)} (${percent}%)`;
+}
+
+/** One-line notice for the worst check, or null while spend is below the warning threshold. */
+export function formatSpendBudgetNotice(status: SpendBudgetStatus | null): string | null {
+  if (!status?.worst || status.level === "ok") {
+    return null;
+  }
+  const prefix = status.level === "exceeded" ? "🛑" : "⚠️";
+  return `${prefix} ${formatSpendBudgetCheck(status.worst)}`;
+}
@@ -0,0 +1,103 @@
+import { describe, expect, it, vi } from "vitest";
+import {
+  applyStructuredOutputToPayload,
+  compileResponseSchema,
+  createStructuredOutputTool,
+  parseStructuredOutput,
+  runWithStructuredOutput,
+  type StructuredOutputRequest,
+} from "./structured-output.js";
+
+const schema = compileResponseSchema({
+  title: "triage result",
+  type: "object",
+  properties: {
+    severity: { enum: ["low", "high"] },
+    count: { type: "integer" },
+  },
+  required: ["severity", "count"],
+  additionalProperties: false,
+});
+
+function request(forceTool = false): StructuredOutputRequest {
+  return { schema, capture: vi.fn(), forceTool };
+}
+
+describe("structured output", () => {
+  it("parses fenced JSON and reports schema violations without coercing", () => {
+    expect(schema.name).toBe("triage_result");
+    expect(parseStructuredOutput('```json\n{"severity":"low","count":2}\n```', schema)).toEqual({
+      ok: true,
+      value: { severity: "low", count: 2 },
+    });
+    expect(parseStructuredOutput('{"severity":"mid","count":"2"}', schema)).toEqual({
+      ok: false,
+      error: 'severity: must be one of "low", "high"; count: must be integer',
+    });
+    expect(parseStructuredOutput("Sure! Here it is.", schema)).toMatchObject({
+      ok: false,
+      error: expect.stringContaining("not valid JSON"),
+    });
+  });
+
+  it("adds the native constraint for each provider API", () => {
+    const completions: Record<string, unknown> = {};
+    applyStructuredOutputToPayload("openai-completions", completions, request());
+    expect(completions.response_format).toMatchObject({
+      type: "json_schema",
+      json_schema: { name: "triage_result", schema: schema.schema },
+    });
+
+    const responses: Record<string, unknown> = { text: { verbosity: "low" } };
+    applyStructuredOutputToPayload("openai-responses", responses, request());
+    expect(responses.text).toMatchObject({ verbosity: "low", format: { type: "json_schema" } });
+
+    const userTurn = { messages: [{ role: "user", content: "again" }] };
+    const afterTool = {
+      messages: [{ role: "user", content: [{ type: "tool_result", tool_use_id: "t1" }] }],
+    };
+    const thinking = { ...userTurn, thinking: { type: "enabled", budget_tokens: 1024 } };
+    applyStructuredOutputToPayload("anthropic-messages", userTurn, request(true));
+    applyStructuredOutputToPayload("anthropic-messages", afterTool, request(true));
+    applyStructuredOutputToPayload("anthropic-messages", thinking, request(true));
+    expect(userTurn).toHaveProperty("tool_choice", { type: "tool", name: "structured_output" });
+    expect(afterTool).not.toHaveProperty("tool_choice");
+    expect(thinking).not.toHaveProperty("tool_choice");
+  });
+
+  it("captures only valid answers from the tool", async () => {
+    const req = request();
+    const tool = createStructuredOutputTool(req);
+    const rejected = await tool.execute("1", { severity: "low" });
+    expect(rejected.details).toMatchObject({ status: "error" });
+    expect(req.capture).not.toHaveBeenCalled();
+    await tool.execute("2", { severity: "high", count: 1 });
+    expect(req.capture).toHaveBeenCalledWith({ severity: "high", count: 1 });
+  });
+
+  it("re-prompts with the validation error until the reply matches", async () => {
+    const replies = ["not json", '{"severity":"low"}', '{"severity":"low","count":3}'];
+    const run = vi.fn(async (_prompt: string, _request: StructuredOutputRequest) => ({
+      text: replies.shift() ?? "",
+    }));
+    const result = await runWithStructuredOutput({
+      schema,
+      prompt: "triage",
+      run,
+      replyText: (outcome) => outcome.text,
+    });
+    expect(result).toMatchObject({ attempts: 3, output: { ok: true } });
+    expect(run.mock.calls[1][0]).toContain("not valid JSON");
+    expect(run.mock.calls[2][0]).toContain("count: required");
+    expect(run.mock.calls[2][1].forceTool).toBe(true);
+
+    const failing = await runWithStructuredOutput({
+      schema,
+      prompt: "triage",
+      maxRetries: 1,
+      run: async () => ({ text: "{}" }),
+      replyText: (outcome) => outcome.text,
+    });
+    expect(failing).toMatchObject({ attempts: 2, output: { ok: false } });
+  });
+});
@@ -0,0 +1,256 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import { streamSimple } from "@mariozechner/pi-ai";
+import AjvPkg, { type ValidateFunction } from "ajv";
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { AnyAgentTool } from "./tools/common.js";
+import { formatSchemaError } from "./pi-tools.validate.js";
+
+/** Re-prompts after the first answer before giving up on a schema-conforming reply. */
+export const STRUCTURED_OUTPUT_MAX_RETRIES = 2;
+export const STRUCTURED_OUTPUT_TOOL_NAME = "structured_output";
+const MAX_REPORTED_ERRORS = 8;
+
+// Unlike tool input validation, the answer is checked as-is: no type coercion.
+const ajv = new (AjvPkg as unknown as new (opts?: object) => import("ajv").default)({
+  allErrors: true,
+  strict: false,
+  strictNumbers: true,
+  validateFormats: false,
+});
+
+export type ResponseSchema = {
+  /** Name sent to providers that label the schema (letters, digits, `_` and `-`). */
+  name: string;
+  schema: Record<string, unknown>;
+  validate: ValidateFunction;
+};
+
+/** Per-run request for provider-side constraints; `capture` receives answers from the tool. */
+export type StructuredOutputRequest = {
+  schema: ResponseSchema;
+  capture: (value: unknown) => void;
+  /** Force the answer tool on the next plain user turn (used for retries). */
+  forceTool?: boolean;
+};
+
+export type StructuredOutputResult = { ok: true; value: unknown } | { ok: false; error: string };
+
+export function compileResponseSchema(
+  schema: Record<string, unknown>,
+  name = "response",
+): ResponseSchema {
+  let validate: ValidateFunction;
+  try {
+    validate = ajv.compile(schema);
+  } catch (err) {
+    throw new Error(`Invalid response schema: ${String(err)}`, { cause: err });
+  }
+  const title = typeof schema.title === "string" ? schema.title : name;
+  const safeName = title.replace(/[^a-zA-Z0-9_-]+/g, "_").slice(0, 64) || "response";
+  return { name: safeName, schema, validate };
+}
+
+export async function loadResponseSchema(filePath: string): Promise<ResponseSchema> {
+  const resolved = path.resolve(filePath);
+  let raw: string;
+  try {
+    raw = await fs.readFile(resolved, "utf8");
+  } catch (err) {
+    throw new Error(`Cannot read response schema ${resolved}: ${String(err)}`, { cause: err });
+  }
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(raw);
+  } catch (err) {
+    throw new Error(`Response schema ${resolved} is not valid JSON: ${String(err)}`, {
+      cause: err,
+    });
+  }
+  if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) {
+    throw new Error(`Response schema ${resolved} must be a JSON object.`);
+  }
+  return compileResponseSchema(
+    parsed as Record<string, unknown>,
+    path.basename(resolved, path.extname(resolved)),
+  );
+}
+
+function usesAnswerTool(schema: ResponseSchema): boolean {
+  // Tool inputs are always objects, so only object schemas can be answered through the tool.
+  return schema.schema.type === "object";
+}
+
+export function buildStructuredOutputSystemPrompt(schema: ResponseSchema): string {
+  return [
+    "## Structured output",
+    "Your final answer must be a single JSON value that matches this JSON schema:",
+    JSON.stringify(schema.schema, null, 2),
+    usesAnswerTool(schema)
+      ? `If the \`${STRUCTURED_OUTPUT_TOOL_NAME}\` tool is available, submit the answer by calling it with the JSON as its arguments. Otherwise reply with only the JSON: no prose and no code fences.`
+      : "Reply with only the JSON: no prose and no code fences.",
+  ].join("\n");
+}
+
+export function buildStructuredOutputRetryPrompt(error: string): string {
+  return [
+    `Your previous answer did not match the required JSON schema: ${error}`,
+    "Reply again with only the corrected JSON value.",
+  ].join("\n");
+}
+
+export function validateStructuredOutput(
+  value: unknown,
+  schema: ResponseSchema,
+): StructuredOutputResult {
+  if (schema.validate(value)) {
+    return { ok: true, value };
+  }
+  const messages = Array.from(new Set((schema.validate.errors ?? []).map(formatSchemaError)));
+  const shown = messages.slice(0, MAX_REPORTED_ERRORS);
+  const more = messages.length > shown.length ? ` (+${messages.length - shown.length} more)` : "";
+  return { ok: false, error: `${shown.join("; ")}${more}` };
+}
+
+/** Parses a reply as JSON (tolerating a surrounding code fence) and validates it. */
+export function parseStructuredOutput(
+  text: string,
+  schema: ResponseSchema,
+): StructuredOutputResult {
+  const trimmed = text.trim();
+  if (!trimmed) {
+    return { ok: false, error: "the reply was empty" };
+  }
+  const fenced = trimmed.match(/^```[a-zA-Z]*\s*\n([\s\S]*?)\n?```$/);
+  const candidate = fenced ? fenced[1] : trimmed;
+  let value: unknown;
+  try {
+    value = JSON.parse(candidate);
+  } catch (err) {
+    return { ok: false, error: `the reply is not valid JSON (${String(err)})` };
+  }
+  return validateStructuredOutput(value, schema);
+}
+
+/**
+ * Tool the model calls with its final answer on Anthropic models, where a forced tool call is the
+ * native way to get schema-shaped JSON. Invalid answers are bounced back to the model.
+ */
+export function createStructuredOutputTool(request: StructuredOutputRequest): AnyAgentTool {
+  return {
+    name: STRUCTURED_OUTPUT_TOOL_NAME,
+    label: "Structured output",
+    description: "Submit the final answer as JSON matching the required schema.",
+    parameters: request.schema.schema,
+    execute: async (_toolCallId, args) => {
+      const result = validateStructuredOutput(args, request.schema);
+      if (!result.ok) {
+        return {
+          content: [{ type: "text", text: `Answer rejected: ${result.error}. Call again.` }],
+          details: { status: "error", error: result.error },
+        };
+      }
+      request.capture(result.value);
+      return {
+        content: [{ type: "text", text: "Answer recorded. Do not repeat it." }],
+        details: { status: "ok" },
+      };
+    },
+  } as AnyAgentTool;
+}
+
+/** True when a provider payload ends with a user message that is not a tool result. */
+export function isPlainUserTurnPayload(messages: unknown): boolean {
+  if (!Array.isArray(messages) || messages.length === 0) {
+    return false;
+  }
+  const last = messages[messages.length - 1] as { role?: unknown; content?: unknown };
+  if (last?.role !== "user") {
+    return false;
+  }
+  return !(
+    Array.isArray(last.content) &&
+    last.content.some((block) => (block as { type?: unknown })?.type === "tool_result")
+  );
+}
+
+/** Adds the provider's native JSON-schema constraint to outgoing request payloads. */
+export function applyStructuredOutputToPayload(
+  api: string,
+  payload: Record<string, unknown>,
+  request: StructuredOutputRequest,
+): void {
+  const { name, schema } = request.schema;
+  if (api === "openai-completions") {
+    payload.response_format = {
+      type: "json_schema",
+      json_schema: { name, schema, strict: false },
+    };
+    return;
+  }
+  if (api === "openai-responses" || api === "openai-codex-responses") {
+    const text = (payload.text ?? {}) as Record<string, unknown>;
+    payload.text = { ...text, format: { type: "json_schema", name, schema, strict: false } };
+    return;
+  }
+  if (api === "anthropic-messages" && request.forceTool && usesAnswerTool(request.schema)) {
+    // Forcing a tool is rejected while extended thinking is on; the prompt still asks for it.
+    const thinking = payload.thinking as { type?: unknown } | undefined;
+    if (thinking?.type === "enabled") {
+      return;
+    }
+    // Only force on a fresh user turn; forcing after tool results would loop forever.
+    if (isPlainUserTurnPayload(payload.messages)) {
+      payload.tool_choice = { type: "tool", name: STRUCTURED_OUTPUT_TOOL_NAME };
+    }
+  }
+}
+
+export function wrapStreamFnWithStructuredOutput(
+  baseStreamFn: StreamFn | undefined,
+  request: StructuredOutputRequest,
+): StreamFn {
+  const underlying = baseStreamFn ?? streamSimple;
+  return (model, context, options) =>
+    underlying(model, context, {
+      ...options,
+      onPayload: (payload) => {
+        if (payload && typeof payload === "object") {
+          applyStructuredOutputToPayload(model.api, payload as Record<string, unknown>, request);
+        }
+        options?.onPayload?.(payload);
+      },
+    });
+}
+
+/**
+ * Runs a turn and re-prompts with the validation errors until the answer matches the schema or
+ * the retries run out. Answers submitted through the tool win over the reply text.
+ */
+export async function runWithStructuredOutput<T>(params: {
+  schema: ResponseSchema;
+  prompt: string;
+  maxRetries?: number;
+  run: (prompt: string, request: StructuredOutputRequest) => Promise<T>;
+  replyText: (outcome: T) => string;
+}): Promise<{ outcome: T; output: StructuredOutputResult; attempts: number }> {
+  const maxRetries = params.maxRetries ?? STRUCTURED_OUTPUT_MAX_RETRIES;
+  let prompt = params.prompt;
+  for (let attempt = 0; ; attempt++) {
+    let captured: { value: unknown } | undefined;
+    const outcome = await params.run(prompt, {
+      schema: params.schema,
+      capture: (value) => {
+        captured = { value };
+      },
+      forceTool: attempt > 0,
+    });
+    const output = captured
+      ? validateStructuredOutput(captured.value, params.schema)
+      : parseStructuredOutput(params.replyText(outcome), params.schema);
+    if (output.ok || attempt >= maxRetries) {
+      return { outcome, output, attempts: attempt + 1 };
+    }
+    prompt = buildStructuredOutputRetryPrompt(output.error);
+  }
+}
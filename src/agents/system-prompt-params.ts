@@ -93,7 +93,7 @@ function resolveRepoRoot(params: {
   return undefined;
 }
 
-function findGitRoot(startDir: string): string | null {
+export function findGitRoot(startDir: string): string | null {
   let current = path.resolve(startDir);
   for (let i = 0; i < 12; i += 1) {
     const gitPath = path.join(current, ".git");
@@ -1,7 +1,9 @@
 import type { AgentTool } from "@mariozechner/pi-agent-core";
 import type { SessionSystemPromptReport } from "../config/sessions/types.js";
 import type { EmbeddedContextFile } from "./pi-embedded-helpers.js";
+import type { ProjectInstructionFile } from "./project-instructions.js";
 import type { WorkspaceBootstrapFile } from "./workspace.js";
+import { shortenHomePath } from "../utils.js";
 
 function extractBetween(
   input: string,
@@ -37,24 +39,39 @@ function parseSkillBlocks(skillsPrompt: string): Array<{ name: string; blockChar
 
 function buildInjectedWorkspaceFiles(params: {
   bootstrapFiles: WorkspaceBootstrapFile[];
+  instructionFiles?: ProjectInstructionFile[];
   injectedFiles: EmbeddedContextFile[];
   bootstrapMaxChars: number;
 }): SessionSystemPromptReport["injectedWorkspaceFiles"] {
   const injectedByName = new Map(params.injectedFiles.map((f) => [f.path, f.content]));
-  return params.bootstrapFiles.map((file) => {
-    const rawChars = file.missing ? 0 : (file.content ?? "").trimEnd().length;
+  const toEntry = (file: { name: string; path: string; missing: boolean; content?: string }) => {
+    const raw = file.missing ? "" : (file.content ?? "").trimEnd();
     const injected = injectedByName.get(file.name);
     const injectedChars = injected ? injected.length : 0;
-    const truncated = !file.missing && rawChars > params.bootstrapMaxChars;
+    // Per-file cap or the shared total budget may have shortened the injected text.
+    const truncated =
+      !file.missing &&
+      (raw.length > params.bootstrapMaxChars || (injected !== undefined && injected !== raw));
     return {
       name: file.name,
       path: file.path,
       missing: file.missing,
-      rawChars,
+      rawChars: raw.length,
       injectedChars,
       truncated,
     };
-  });
+  };
+  return [
+    ...params.bootstrapFiles.map((file) => toEntry(file)),
+    ...(params.instructionFiles ?? []).map((file) =>
+      toEntry({
+        name: shortenHomePath(file.path),
+        path: file.path,
+        missing: false,
+        content: file.content,
+      }),
+    ),
+  ];
 }
 
 function buildToolsEntries(tools: AgentTool[]): SessionSystemPromptReport["tools"]["entries"] {
@@ -107,9 +124,11 @@ export function buildSystemPromptReport(params: {
   model?: string;
   workspaceDir?: string;
   bootstrapMaxChars: number;
+  bootstrapTotalMaxChars?: number;
   sandbox?: SessionSystemPromptReport["sandbox"];
   systemPrompt: string;
   bootstrapFiles: WorkspaceBootstrapFile[];
+  instructionFiles?: ProjectInstructionFile[];
   injectedFiles: EmbeddedContextFile[];
   skillsPrompt: string;
   tools: AgentTool[];
@@ -136,6 +155,7 @@ export function buildSystemPromptReport(params: {
     model: params.model,
     workspaceDir: params.workspaceDir,
     bootstrapMaxChars: params.bootstrapMaxChars,
+    bootstrapTotalMaxChars: params.bootstrapTotalMaxChars,
     sandbox: params.sandbox,
     systemPrompt: {
       chars: systemPrompt.length,
@@ -144,6 +164,7 @@ export function buildSystemPromptReport(params: {
     },
     injectedWorkspaceFiles: buildInjectedWorkspaceFiles({
       bootstrapFiles: params.bootstrapFiles,
+      instructionFiles: params.instructionFiles,
       injectedFiles: params.injectedFiles,
       bootstrapMaxChars: params.bootstrapMaxChars,
     }),
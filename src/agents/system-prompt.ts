@@ -45,20 +45,30 @@ function buildMemorySection(params: {
   if (params.isMinimal) {
     return [];
   }
-  if (!params.availableTools.has("memory_search") && !params.availableTools.has("memory_get")) {
+  const hasRecall =
+    params.availableTools.has("memory_search") || params.availableTools.has("memory_get");
+  const hasWrite = params.availableTools.has("memory_write");
+  if (!hasRecall && !hasWrite) {
     return [];
   }
-  const lines = [
-    "## Memory Recall",
-    "Before answering anything about prior work, decisions, dates, people, preferences, or todos: run memory_search on MEMORY.md + memory/*.md; then use memory_get to pull only the needed lines. If low confidence after search, say you checked.",
-  ];
-  if (params.citationsMode === "off") {
+  const lines = ["## Memory Recall"];
+  if (hasRecall) {
     lines.push(
-      "Citations are disabled: do not mention file paths or line numbers in replies unless the user explicitly asks.",
+      "Before answering anything about prior work, decisions, dates, people, preferences, or todos: run memory_search on MEMORY.md + memory/*.md; then use memory_get to pull only the needed lines. If low confidence after search, say you checked.",
     );
-  } else {
+    if (params.citationsMode === "off") {
+      lines.push(
+        "Citations are disabled: do not mention file paths or line numbers in replies unless the user explicitly asks.",
+      );
+    } else {
+      lines.push(
+        "Citations: include Source: <path#line> when it helps the user verify memory snippets.",
+      );
+    }
+  }
+  if (hasWrite) {
     lines.push(
-      "Citations: include Source: <path#line> when it helps the user verify memory snippets.",
+      "When you learn a durable fact worth keeping across sessions (a user preference, a project convention, a build command), save it with memory_write; remove facts that turn out to be wrong. Never store secrets.",
     );
   }
   lines.push("");
@@ -163,6 +173,8 @@ function buildDocsSection(params: { docsPath?: string; isMinimal: boolean; readT
 
 export function buildAgentSystemPrompt(params: {
   workspaceDir: string;
+  /** Directory exec currently defaults to (after earlier `cd`), when it differs from workspaceDir. */
+  execCwd?: string;
   defaultThinkLevel?: ThinkLevel;
   reasoningLevel?: ReasoningLevel;
   extraSystemPrompt?: string;
@@ -179,6 +191,8 @@ export function buildAgentSystemPrompt(params: {
   heartbeatPrompt?: string;
   docsPath?: string;
   workspaceNotes?: string[];
+  /** Pre-rendered workspace tree (see workspace-map.ts); omitted when disabled. */
+  workspaceMap?: string;
   ttsHint?: string;
   /** Controls which hardcoded sections to include. Defaults to "full". */
   promptMode?: PromptMode;
@@ -224,13 +238,24 @@ export function buildAgentSystemPrompt(params: {
     grep: "Search file contents for patterns",
     find: "Find files by glob pattern",
     ls: "List directory contents",
+    notebook_read: "Read Jupyter notebook cells",
+    notebook_edit: "Replace, insert, or delete Jupyter notebook cells",
+    code_outline: "List a source file's functions/classes/methods with line ranges",
+    codebase_search: "Find code by meaning in the project's embedding index (use grep for exact names)",
+    git: "Inspect git status/diff/log/show/blame/branches (read-only)",
     exec: "Run shell commands (pty available for TTY-required CLIs)",
     process: "Manage background exec sessions",
+    bash_output: "Read new output from a background exec task",
+    kill_task: "Stop a background exec task",
+    run_code: "Run Python/JavaScript snippets in an isolated interpreter (plots returned as images)",
     web_search: "Search the web (Brave API)",
     web_fetch: "Fetch and extract readable content from a URL",
+    http_request: "Call HTTP APIs with custom methods, headers, and bodies",
+    gh: "List/view GitHub issues and PRs, read PR diffs, comment, and open PRs",
     // Channel docking: add login tools here when a channel needs interactive linking.
     browser: "Control web browser",
     canvas: "Present/eval/snapshot the Canvas",
+    screenshot: "Capture the host screen or focused window as an image",
     nodes: "List/describe/notify/camera/screen on paired nodes",
     cron: "Manage cron jobs and wake events (use for reminders; when scheduling a reminder, write the systemEvent text as something that will read like a reminder when it fires, and mention that it is a reminder depending on the time gap between setting and firing; include recent context in reminder text if appropriate)",
     message: "Send messages and channel actions",
@@ -242,6 +267,7 @@ export function buildAgentSystemPrompt(params: {
     sessions_spawn: "Spawn a sub-agent session",
     session_status:
       "Show a /status-equivalent status card (usage + time + Reasoning/Verbose/Elevated); use for model-use questions (📊 session_status); optional per-session model override",
+    todo_write: "Track multi-step work as a task list (shown to the user via /todos)",
     image: "Analyze an image with the configured image model",
   };
 
@@ -253,12 +279,23 @@ export function buildAgentSystemPrompt(params: {
     "grep",
     "find",
     "ls",
+    "notebook_read",
+    "notebook_edit",
+    "code_outline",
+    "codebase_search",
+    "git",
     "exec",
     "process",
+    "bash_output",
+    "kill_task",
+    "run_code",
     "web_search",
     "web_fetch",
+    "http_request",
+    "gh",
     "browser",
     "canvas",
+    "screenshot",
     "nodes",
     "cron",
     "message",
@@ -268,6 +305,7 @@ export function buildAgentSystemPrompt(params: {
     "sessions_history",
     "sessions_send",
     "session_status",
+    "todo_write",
     "image",
   ];
 
@@ -390,17 +428,24 @@ export function buildAgentSystemPrompt(params: {
           "- grep: search file contents for patterns",
           "- find: find files by glob pattern",
           "- ls: list directory contents",
+          "- notebook_read / notebook_edit: read and edit Jupyter notebook cells",
+          "- code_outline: list a source file's definitions with line ranges",
+          "- codebase_search: find code by meaning in the project's embedding index",
+          "- git: read-only git status/diff/log/show/blame/branches",
           "- apply_patch: apply multi-file patches",
           `- ${execToolName}: run shell commands (supports background via yieldMs/background)`,
           `- ${processToolName}: manage background exec sessions`,
+          "- run_code: run Python/JavaScript snippets in an isolated interpreter",
           "- browser: control OpenClaw's dedicated browser",
           "- canvas: present/eval/snapshot the Canvas",
+          "- screenshot: capture the host screen or focused window",
           "- nodes: list/describe/notify/camera/screen on paired nodes",
           "- cron: manage cron jobs and wake events (use for reminders; when scheduling a reminder, write the systemEvent text as something that will read like a reminder when it fires, and mention that it is a reminder depending on the time gap between setting and firing; include recent context in reminder text if appropriate)",
           "- sessions_list: list sessions",
           "- sessions_history: fetch session history",
           "- sessions_send: send to another session",
           '- session_status: show usage/time/model state and answer "what model are we using?"',
+          "- todo_write: track multi-step work as a task list",
         ].join("\n"),
     "TOOLS.md does not control tool availability; it is user guidance for how to use external tools.",
     "If a task is more complex or takes longer, spawn a sub-agent. It will do the work for you and ping you when it's done. You can always check up on it.",
@@ -452,7 +497,18 @@ export function buildAgentSystemPrompt(params: {
     "## Workspace",
     `Your working directory is: ${params.workspaceDir}`,
     "Treat this directory as the single global workspace for file operations unless explicitly instructed otherwise.",
+    params.execCwd && params.execCwd !== params.workspaceDir
+      ? `Current exec directory (from an earlier cd): ${params.execCwd}`
+      : "",
     ...workspaceNotes,
+    params.workspaceMap
+      ? [
+          "Workspace layout (depth-limited; .gitignore'd paths skipped; use ls/find for more):",
+          "```",
+          params.workspaceMap,
+          "```",
+        ].join("\n")
+      : "",
     "",
     ...docsSection,
     params.sandboxInfo?.enabled ? "## Sandbox" : "",
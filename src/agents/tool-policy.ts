@@ -14,12 +14,26 @@ const TOOL_NAME_ALIASES: Record<string, string> = {
 
 export const TOOL_GROUPS: Record<string, string[]> = {
   // NOTE: Keep canonical (lowercase) tool names here.
-  "group:memory": ["memory_search", "memory_get"],
-  "group:web": ["web_search", "web_fetch"],
+  "group:memory": ["memory_search", "memory_get", "memory_read", "memory_write"],
+  "group:web": ["web_search", "web_fetch", "http_request"],
   // Basic workspace/file tools
-  "group:fs": ["read", "write", "edit", "apply_patch"],
+  "group:fs": [
+    "read",
+    "write",
+    "edit",
+    "apply_patch",
+    "grep",
+    "find",
+    "ls",
+    "notebook_read",
+    "notebook_edit",
+    "code_outline",
+    "codebase_search",
+  ],
   // Host/runtime execution tools
-  "group:runtime": ["exec", "process"],
+  "group:runtime": ["exec", "process", "bash_output", "kill_task"],
+  // Code interpreter (kept out of group:runtime so it can be allowed without shell access)
+  "group:code": ["run_code"],
   // Session management tools
   "group:sessions": [
     "sessions_list",
@@ -27,9 +41,10 @@ export const TOOL_GROUPS: Record<string, string[]> = {
     "sessions_send",
     "sessions_spawn",
     "session_status",
+    "todo_write",
   ],
   // UI helpers
-  "group:ui": ["browser", "canvas"],
+  "group:ui": ["browser", "canvas", "screenshot"],
   // Automation + infra
   "group:automation": ["cron", "gateway"],
   // Messaging surface
@@ -40,6 +55,7 @@ export const TOOL_GROUPS: Record<string, string[]> = {
   "group:openclaw": [
     "browser",
     "canvas",
+    "screenshot",
     "nodes",
     "cron",
     "message",
@@ -50,22 +66,36 @@ export const TOOL_GROUPS: Record<string, string[]> = {
     "sessions_send",
     "sessions_spawn",
     "session_status",
+    "todo_write",
     "memory_search",
     "memory_get",
+    "memory_read",
+    "memory_write",
     "web_search",
     "web_fetch",
+    "http_request",
+    "gh",
     "image",
   ],
 };
 
-const OWNER_ONLY_TOOL_NAMES = new Set<string>(["whatsapp_login"]);
+const OWNER_ONLY_TOOL_NAMES = new Set<string>(["whatsapp_login", "screenshot"]);
 
 const TOOL_PROFILES: Record<ToolProfileId, ToolProfilePolicy> = {
   minimal: {
     allow: ["session_status"],
   },
   coding: {
-    allow: ["group:fs", "group:runtime", "group:sessions", "group:memory", "image"],
+    allow: [
+      "group:fs",
+      "group:runtime",
+      "group:sessions",
+      "group:memory",
+      "run_code",
+      "git",
+      "gh",
+      "image",
+    ],
   },
   messaging: {
     allow: [
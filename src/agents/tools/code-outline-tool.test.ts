@@ -0,0 +1,176 @@
+import type { Node as SyntaxNode } from "web-tree-sitter";
+import { describe, expect, it } from "vitest";
+import {
+  buildCodeOutline,
+  formatCodeOutline,
+  resolveOutlineLanguage,
+} from "./code-outline-tool.js";
+
+type FakeNode = {
+  type: string;
+  text?: string;
+  lines?: [number, number];
+  fields?: Record<string, FakeNode>;
+  children?: FakeNode[];
+};
+
+// Minimal stand-in for tree-sitter nodes: line ranges are 1-based here for readability.
+function toNode(fake: FakeNode): SyntaxNode {
+  const [start, end] = fake.lines ?? [1, 1];
+  const fields = Object.fromEntries(
+    Object.entries(fake.fields ?? {}).map(([key, value]) => [key, toNode(value)]),
+  );
+  return {
+    type: fake.type,
+    text: fake.text ?? "",
+    startPosition: { row: start - 1, column: 0 },
+    endPosition: { row: end - 1, column: 0 },
+    namedChildren: [...Object.values(fields), ...(fake.children ?? []).map(toNode)],
+    childForFieldName: (name: string) => fields[name] ?? null,
+  };
+}
+
+const ident = (text: string): FakeNode => ({ type: "identifier", text });
+
+describe("code_outline", () => {
+  it("maps file extensions to grammars", () => {
+    expect(resolveOutlineLanguage("src/app.tsx")).toBe("tsx");
+    expect(resolveOutlineLanguage("lib/mod.RS")).toBe("rust");
+    expect(resolveOutlineLanguage("notes.md")).toBeUndefined();
+  });
+
+  it("outlines classes, methods, and function-valued variables in TypeScript", () => {
+    const root = toNode({
+      type: "program",
+      lines: [1, 40],
+      children: [
+        {
+          type: "export_statement",
+          children: [
+            {
+              type: "class_declaration",
+              lines: [1, 20],
+              fields: { name: ident("Server") },
+              children: [
+                {
+                  type: "class_body",
+                  children: [
+                    {
+                      type: "method_definition",
+                      lines: [3, 10],
+                      fields: { name: ident("start") },
+                      // Function bodies are skipped, so this nested class is not listed.
+                      children: [
+                        { type: "class_declaration", lines: [4, 5], fields: { name: ident("X") } },
+                      ],
+                    },
+                  ],
+                },
+              ],
+            },
+          ],
+        },
+        {
+          type: "lexical_declaration",
+          children: [
+            {
+              type: "variable_declarator",
+              lines: [22, 24],
+              fields: { name: ident("handler"), value: { type: "arrow_function" } },
+            },
+            {
+              type: "variable_declarator",
+              lines: [25, 25],
+              fields: { name: ident("limit"), value: { type: "number" } },
+            },
+          ],
+        },
+        { type: "interface_declaration", lines: [27, 30], fields: { name: ident("Options") } },
+      ],
+    });
+
+    const entries = buildCodeOutline(root, "typescript");
+    expect(entries).toEqual([
+      { kind: "class", name: "Server", startLine: 1, endLine: 20, depth: 0 },
+      { kind: "method", name: "start", startLine: 3, endLine: 10, depth: 1 },
+      { kind: "function", name: "handler", startLine: 22, endLine: 24, depth: 0 },
+      { kind: "interface", name: "Options", startLine: 27, endLine: 30, depth: 0 },
+    ]);
+    expect(formatCodeOutline(entries.slice(0, 2))).toBe(
+      "class Server (L1-20)\n  method start (L3-10)",
+    );
+    expect(buildCodeOutline(root, "typescript", { maxDepth: 1 }).map((e) => e.name)).toEqual([
+      "Server",
+      "handler",
+      "Options",
+    ]);
+  });
+
+  it("names Rust impls, Go methods, and C functions", () => {
+    const rust = toNode({
+      type: "source_file",
+      children: [
+        {
+          type: "impl_item",
+          lines: [1, 9],
+          fields: { trait: ident("Display"), type: ident("Point") },
+          children: [
+            {
+              type: "declaration_list",
+              children: [{ type: "function_item", lines: [2, 8], fields: { name: ident("fmt") } }],
+            },
+          ],
+        },
+      ],
+    });
+    expect(buildCodeOutline(rust, "rust").map((e) => `${e.kind} ${e.name}`)).toEqual([
+      "impl Display for Point",
+      "method fmt",
+    ]);
+
+    const go = toNode({
+      type: "source_file",
+      children: [
+        {
+          type: "method_declaration",
+          fields: {
+            name: ident("Start"),
+            receiver: {
+              type: "parameter_list",
+              children: [{ type: "parameter_declaration", fields: { type: ident("*Server") } }],
+            },
+          },
+        },
+        {
+          type: "type_declaration",
+          children: [
+            { type: "type_spec", fields: { name: ident("Server"), type: { type: "struct_type" } } },
+          ],
+        },
+      ],
+    });
+    expect(buildCodeOutline(go, "go").map((e) => `${e.kind} ${e.name}`)).toEqual([
+      "method (*Server).Start",
+      "struct Server",
+    ]);
+
+    const c = toNode({
+      type: "translation_unit",
+      children: [
+        {
+          type: "function_definition",
+          fields: {
+            declarator: {
+              type: "pointer_declarator",
+              fields: {
+                declarator: { type: "function_declarator", fields: { declarator: ident("make") } },
+              },
+            },
+          },
+        },
+        { type: "struct_specifier", fields: { name: ident("opaque") } },
+      ],
+    });
+    expect(buildCodeOutline(c, "c").map((e) => `${e.kind} ${e.name}`)).toEqual(["function make"]);
+  });
+});
@@ -0,0 +1,421 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import type { Language, Node as SyntaxNode, Parser } from "web-tree-sitter";
+import { Type } from "@sinclair/typebox";
+import fs from "node:fs/promises";
+import { createRequire } from "node:module";
+import path from "node:path";
+import type { SandboxFsBridge } from "../sandbox/fs-bridge.js";
+import type { AnyAgentTool } from "./common.js";
+import { resolveToCwd } from "../sandbox-paths.js";
+import { readNumberParam, readStringParam, ToolInputError } from "./common.js";
+
+const MAX_OUTLINE_FILE_BYTES = 2 * 1024 * 1024;
+const MAX_OUTLINE_ENTRIES = 500;
+
+export type OutlineKind =
+  | "function"
+  | "method"
+  | "class"
+  | "interface"
+  | "type"
+  | "enum"
+  | "struct"
+  | "trait"
+  | "impl"
+  | "module";
+
+export type OutlineEntry = {
+  kind: OutlineKind;
+  name: string;
+  startLine: number;
+  endLine: number;
+  depth: number;
+};
+
+type LanguageSpec = {
+  /** Grammar file stem in tree-sitter-wasms (`tree-sitter-<grammar>.wasm`). */
+  grammar: string;
+  nodes: Record<string, OutlineKind>;
+};
+
+const JS_NODES: Record<string, OutlineKind> = {
+  function_declaration: "function",
+  generator_function_declaration: "function",
+  class_declaration: "class",
+  method_definition: "method",
+  // Only when initialised with a function expression (see outlineKind).
+  variable_declarator: "function",
+};
+
+const TS_NODES: Record<string, OutlineKind> = {
+  ...JS_NODES,
+  abstract_class_declaration: "class",
+  interface_declaration: "interface",
+  type_alias_declaration: "type",
+  enum_declaration: "enum",
+  internal_module: "module",
+};
+
+const C_NODES: Record<string, OutlineKind> = {
+  function_definition: "function",
+  struct_specifier: "struct",
+  union_specifier: "struct",
+  enum_specifier: "enum",
+};
+
+const LANGUAGES: Record<string, LanguageSpec> = {
+  javascript: { grammar: "javascript", nodes: JS_NODES },
+  typescript: { grammar: "typescript", nodes: TS_NODES },
+  tsx: { grammar: "tsx", nodes: TS_NODES },
+  python: {
+    grammar: "python",
+    nodes: { function_definition: "function", class_definition: "class" },
+  },
+  rust: {
+    grammar: "rust",
+    nodes: {
+      function_item: "function",
+      function_signature_item: "function",
+      struct_item: "struct",
+      union_item: "struct",
+      enum_item: "enum",
+      trait_item: "trait",
+      impl_item: "impl",
+      mod_item: "module",
+      type_item: "type",
+    },
+  },
+  go: {
+    grammar: "go",
+    nodes: { function_declaration: "function", method_declaration: "method", type_spec: "type" },
+  },
+  java: {
+    grammar: "java",
+    nodes: {
+      class_declaration: "class",
+      record_declaration: "class",
+      interface_declaration: "interface",
+      annotation_type_declaration: "interface",
+      enum_declaration: "enum",
+      method_declaration: "method",
+      constructor_declaration: "method",
+    },
+  },
+  c: { grammar: "c", nodes: C_NODES },
+  cpp: {
+    grammar: "cpp",
+    nodes: { ...C_NODES, class_specifier: "class", namespace_definition: "module" },
+  },
+  ruby: {
+    grammar: "ruby",
+    nodes: { class: "class", module: "module", method: "function", singleton_method: "function" },
+  },
+};
+
+const EXTENSION_LANGUAGES: Record<string, string> = {
+  ".js": "javascript",
+  ".jsx": "javascript",
+  ".mjs": "javascript",
+  ".cjs": "javascript",
+  ".ts": "typescript",
+  ".mts": "typescript",
+  ".cts": "typescript",
+  ".tsx": "tsx",
+  ".py": "python",
+  ".pyi": "python",
+  ".rs": "rust",
+  ".go": "go",
+  ".java": "java",
+  ".c": "c",
+  ".h": "c",
+  ".cc": "cpp",
+  ".cpp": "cpp",
+  ".cxx": "cpp",
+  ".hh": "cpp",
+  ".hpp": "cpp",
+  ".hxx": "cpp",
+  ".rb": "ruby",
+};
+
+const FUNCTION_VALUE_TYPES = new Set([
+  "arrow_function",
+  "function",
+  "function_expression",
+  "generator_function",
+]);
+
+const DECLARATOR_NAME_TYPES = new Set([
+  "identifier",
+  "field_identifier",
+  "qualified_identifier",
+  "destructor_name",
+  "operator_name",
+]);
+
+// Functions found inside these are reported as methods.
+const METHOD_OWNER_KINDS = new Set<OutlineKind>([
+  "class",
+  "interface",
+  "struct",
+  "trait",
+  "impl",
+  "enum",
+]);
+
+export function resolveOutlineLanguage(filePath: string): string | undefined {
+  return EXTENSION_LANGUAGES[path.extname(filePath).toLowerCase()];
+}
+
+function outlineKind(node: SyntaxNode, spec: LanguageSpec): OutlineKind | undefined {
+  const kind = spec.nodes[node.type];
+  if (!kind) {
+    return undefined;
+  }
+  switch (node.type) {
+    case "variable_declarator": {
+      const value = node.childForFieldName("value");
+      return value && FUNCTION_VALUE_TYPES.has(value.type) ? "function" : undefined;
+    }
+    case "type_spec": {
+      const type = node.childForFieldName("type")?.type;
+      return type === "struct_type" ? "struct" : type === "interface_type" ? "interface" : "type";
+    }
+    case "struct_specifier":
+    case "union_specifier":
+    case "enum_specifier":
+    case "class_specifier":
+      // Skip bare references such as `struct foo *p;`.
+      return node.childForFieldName("body") ? kind : undefined;
+    default:
+      return kind;
+  }
+}
+
+function declaratorName(node: SyntaxNode): string | undefined {
+  let current = node.childForFieldName("declarator");
+  while (current && !DECLARATOR_NAME_TYPES.has(current.type)) {
+    current = current.childForFieldName("declarator");
+  }
+  return current?.text;
+}
+
+function outlineName(node: SyntaxNode): string {
+  switch (node.type) {
+    case "impl_item": {
+      const type = node.childForFieldName("type")?.text ?? "?";
+      const trait = node.childForFieldName("trait")?.text;
+      return trait ? `${trait} for ${type}` : type;
+    }
+    case "method_declaration": {
+      const name = node.childForFieldName("name")?.text ?? "(anonymous)";
+      // Go: qualify with the receiver type, e.g. (*Server).Start.
+      const receiver = node.childForFieldName("receiver")?.namedChildren[0];
+      const receiverType = receiver?.childForFieldName("type")?.text;
+      return receiverType ? `(${receiverType}).${name}` : name;
+    }
+    case "function_definition": {
+      const name = node.childForFieldName("name")?.text ?? declaratorName(node);
+      return name ?? "(anonymous)";
+    }
+    case "singleton_method":
+      return `self.${node.childForFieldName("name")?.text ?? "(anonymous)"}`;
+    default:
+      return node.childForFieldName("name")?.text ?? "(anonymous)";
+  }
+}
+
+/** Walk a parsed tree and collect definitions. Function bodies are not descended into. */
+export function buildCodeOutline(
+  root: SyntaxNode,
+  language: string,
+  opts?: { maxDepth?: number },
+): OutlineEntry[] {
+  const spec = LANGUAGES[language];
+  if (!spec) {
+    return [];
+  }
+  const entries: OutlineEntry[] = [];
+  const visit = (node: SyntaxNode, depth: number, owner?: OutlineKind) => {
+    if (entries.length >= MAX_OUTLINE_ENTRIES) {
+      return;
+    }
+    const kind = outlineKind(node, spec);
+    if (!kind) {
+      // Plain variables: nothing worth outlining inside their initialisers.
+      if (node.type === "variable_declarator") {
+        return;
+      }
+      for (const child of node.namedChildren) {
+        if (child) {
+          visit(child, depth, owner);
+        }
+      }
+      return;
+    }
+    const resolvedKind =
+      kind === "function" && owner && METHOD_OWNER_KINDS.has(owner) ? "method" : kind;
+    entries.push({
+      kind: resolvedKind,
+      name: outlineName(node).replace(/\s+/g, " "),
+      startLine: node.startPosition.row + 1,
+      endLine: node.endPosition.row + 1,
+      depth,
+    });
+    if (resolvedKind === "function" || resolvedKind === "method") {
+      return;
+    }
+    if (opts?.maxDepth !== undefined && depth + 1 >= opts.maxDepth) {
+      return;
+    }
+    for (const child of node.namedChildren) {
+      if (child) {
+        visit(child, depth + 1, resolvedKind);
+      }
+    }
+  };
+  visit(root, 0);
+  return entries;
+}
+
+export function formatCodeOutline(entries: OutlineEntry[]): string {
+  return entries
+    .map(
+      (entry) =>
+        `${"  ".repeat(entry.depth)}${entry.kind} ${entry.name} (L${entry.startLine}-${entry.endLine})`,
+    )
+    .join("\n");
+}
+
+type TreeSitterModule = typeof import("web-tree-sitter");
+
+const moduleRequire = createRequire(import.meta.url);
+let treeSitterPromise: Promise<{ Parser: typeof Parser; Language: typeof Language }> | null =
+  null;
+const languageCache = new Map<string, Promise<Language>>();
+
+/** True when the optional `web-tree-sitter` dependency is installed. */
+export function isCodeOutlineAvailable(): boolean {
+  try {
+    moduleRequire.resolve("web-tree-sitter");
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+// Lazy-load tree-sitter so sessions that never outline a file don't pay for the WASM runtime.
+async function loadTreeSitter() {
+  if (!treeSitterPromise) {
+    treeSitterPromise = import("web-tree-sitter")
+      .then(async (mod: TreeSitterModule) => {
+        await mod.Parser.init();
+        return { Parser: mod.Parser, Language: mod.Language };
+      })
+      .catch((err) => {
+        treeSitterPromise = null;
+        throw new Error(
+          `Optional dependency web-tree-sitter is required for code_outline: ${String(err)}`,
+        );
+      });
+  }
+  return treeSitterPromise;
+}
+
+function resolveGrammarPath(grammar: string): string {
+  let packageJson: string;
+  try {
+    packageJson = moduleRequire.resolve("tree-sitter-wasms/package.json");
+  } catch {
+    throw new Error("code_outline: tree-sitter grammars not found (install tree-sitter-wasms)");
+  }
+  return path.join(path.dirname(packageJson), "out", `tree-sitter-${grammar}.wasm`);
+}
+
+async function loadLanguage(grammar: string): Promise<Language> {
+  let cached = languageCache.get(grammar);
+  if (!cached) {
+    cached = loadTreeSitter().then(({ Language }) => Language.load(resolveGrammarPath(grammar)));
+    cached.catch(() => languageCache.delete(grammar));
+    languageCache.set(grammar, cached);
+  }
+  return cached;
+}
+
+type CodeOutlineToolOptions = {
+  cwd: string;
+  sandbox?: { root: string; bridge: SandboxFsBridge };
+};
+
+const CodeOutlineSchema = Type.Object({
+  path: Type.String({ description: "Source file to outline." }),
+  maxDepth: Type.Optional(
+    Type.Number({
+      description: "Nesting levels to include (1 = top-level definitions only).",
+      minimum: 1,
+    }),
+  ),
+});
+
+export type CodeOutlineToolDetails = {
+  language: string;
+  lines: number;
+  entries: OutlineEntry[];
+  truncated: boolean;
+};
+
+export function createCodeOutlineTool(options: CodeOutlineToolOptions): AnyAgentTool {
+  const readSource = async (filePath: string): Promise<Buffer> => {
+    if (options.sandbox) {
+      return await options.sandbox.bridge.readFile({ filePath, cwd: options.sandbox.root });
+    }
+    return await fs.readFile(resolveToCwd(filePath, options.cwd));
+  };
+  return {
+    label: "code_outline",
+    name: "code_outline",
+    description:
+      "List the functions, classes, methods, interfaces, and impl blocks of a source file with their line ranges, without reading the whole file. Use it to navigate large files, then read only the line ranges you need.",
+    parameters: CodeOutlineSchema,
+    execute: async (_toolCallId, args): Promise<AgentToolResult<CodeOutlineToolDetails>> => {
+      const params = args as Record<string, unknown>;
+      const filePath = readStringParam(params, "path", { required: true });
+      const maxDepth = readNumberParam(params, "maxDepth", { integer: true });
+      const language = resolveOutlineLanguage(filePath);
+      if (!language) {
+        throw new ToolInputError(
+          `code_outline: unsupported file type ${path.extname(filePath) || "(none)"} (supported: ${Object.keys(EXTENSION_LANGUAGES).join(" ")})`,
+        );
+      }
+      const source = await readSource(filePath);
+      if (source.byteLength > MAX_OUTLINE_FILE_BYTES) {
+        throw new ToolInputError(`code_outline: file too large (${source.byteLength} bytes)`);
+      }
+      const text = source.toString("utf8");
+
+      const { Parser } = await loadTreeSitter();
+      const parser = new Parser();
+      try {
+        parser.setLanguage(await loadLanguage(LANGUAGES[language].grammar));
+        const tree = parser.parse(text);
+        if (!tree) {
+          throw new Error(`code_outline: failed to parse ${filePath}`);
+        }
+        try {
+          const entries = buildCodeOutline(tree.rootNode, language, { maxDepth });
+          const lines = text.split("\n").length;
+          const truncated = entries.length >= MAX_OUTLINE_ENTRIES;
+          const header = `${filePath} (${language}, ${lines} lines, ${entries.length} definitions${truncated ? ", truncated" : ""})`;
+          const body = entries.length > 0 ? formatCodeOutline(entries) : "(no definitions found)";
+          return {
+            content: [{ type: "text", text: `${header}\n${body}` }],
+            details: { language, lines, entries, truncated },
+          };
+        } finally {
+          tree.delete();
+        }
+      } finally {
+        parser.delete();
+      }
+    },
+  };
+}
@@ -0,0 +1,130 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import { Type } from "@sinclair/typebox";
+import path from "node:path";
+import type { OpenClawConfig } from "../../config/config.js";
+import type { AnyAgentTool } from "./common.js";
+import {
+  CodebaseIndex,
+  type CodebaseSearchResult,
+  hasCodebaseIndex,
+} from "../../codebase/codebase-index.js";
+import { shortenHomePath } from "../../utils.js";
+import { resolveSessionAgentId } from "../agent-scope.js";
+import { getTrackedExecCwd, resolveExecScopeKey } from "../bash-tools.cwd.js";
+import { findGitRoot } from "../system-prompt-params.js";
+import { readNumberParam, readStringParam, ToolInputError } from "./common.js";
+
+const DEFAULT_LIMIT = 8;
+const MAX_LIMIT = 30;
+/** Changed files re-embedded before a search; a big checkout is finished by `openclaw index`. */
+const SEARCH_SYNC_MAX_FILES = 200;
+
+const CodebaseSearchSchema = Type.Object({
+  query: Type.String({
+    description:
+      'What the code does, in plain words (e.g. "where webhook deliveries are retried"). Use grep for exact names.',
+  }),
+  path: Type.Optional(
+    Type.String({ description: "Only search files under this directory (relative to the root)." }),
+  ),
+  limit: Type.Optional(
+    Type.Number({ description: `Max results (default ${DEFAULT_LIMIT}, max ${MAX_LIMIT}).` }),
+  ),
+});
+
+/**
+ * Indexed roots to search, most specific first: the repo the agent `cd`'d into, the configured
+ * `agents.defaults.repoRoot`, then the workspace.
+ */
+export function resolveIndexedCodebaseRoots(params: {
+  workspaceDir: string;
+  config?: OpenClawConfig;
+  sessionKey?: string;
+  agentId?: string;
+  env?: NodeJS.ProcessEnv;
+}): string[] {
+  const candidates = [
+    getTrackedExecCwd(resolveExecScopeKey(params)),
+    params.config?.agents?.defaults?.repoRoot?.trim(),
+    params.workspaceDir,
+  ]
+    .filter((value): value is string => Boolean(value))
+    .map((value) => {
+      const resolved = path.resolve(value);
+      return findGitRoot(resolved) ?? resolved;
+    });
+  return Array.from(new Set(candidates)).filter((root) => hasCodebaseIndex(root, params.env));
+}
+
+function formatResults(results: CodebaseSearchResult[]): string {
+  return results
+    .map((result) => {
+      const fence = result.snippet.includes("```") ? "````" : "```";
+      const lang = path.extname(result.path).slice(1);
+      return [
+        `${result.path}:${result.startLine}-${result.endLine} (score ${result.score.toFixed(2)})`,
+        `${fence}${lang}`,
+        result.snippet,
+        fence,
+      ].join("\n");
+    })
+    .join("\n\n");
+}
+
+/** Only offered once `openclaw index` has built an index for one of the candidate roots. */
+export function createCodebaseSearchTool(options: {
+  workspaceDir: string;
+  config?: OpenClawConfig;
+  sessionKey?: string;
+  agentId?: string;
+}): AnyAgentTool | null {
+  const cfg = options.config;
+  if (!cfg || resolveIndexedCodebaseRoots(options).length === 0) {
+    return null;
+  }
+  const agentId = resolveSessionAgentId({ sessionKey: options.sessionKey, config: cfg });
+  return {
+    label: "Codebase Search",
+    name: "codebase_search",
+    description:
+      "Semantic search over the project's indexed source files: finds code by meaning rather than exact text and returns the best-matching chunks with file paths and line ranges. Changed files are re-indexed before searching.",
+    parameters: CodebaseSearchSchema,
+    execute: async (_toolCallId, args): Promise<AgentToolResult<unknown>> => {
+      const params = args as Record<string, unknown>;
+      const query = readStringParam(params, "query", { required: true });
+      const pathPrefix = readStringParam(params, "path");
+      const limit = Math.min(
+        MAX_LIMIT,
+        Math.max(1, readNumberParam(params, "limit", { integer: true }) ?? DEFAULT_LIMIT),
+      );
+      const root = resolveIndexedCodebaseRoots(options)[0];
+      if (!root) {
+        throw new ToolInputError("no codebase index found; run `openclaw index` in the project");
+      }
+      const index = await CodebaseIndex.get({ cfg, agentId, root });
+      let note = "";
+      try {
+        const synced = await index.sync({ maxFiles: SEARCH_SYNC_MAX_FILES });
+        if (synced.pending > 0) {
+          note = `${synced.pending} changed files are not re-indexed yet; run \`openclaw index\` to catch up.`;
+        }
+      } catch (err) {
+        note = `index refresh failed (${String(err)}); results may be stale.`;
+      }
+      const results = await index.search(query, {
+        maxResults: limit,
+        pathPrefix: pathPrefix?.trim() || undefined,
+      });
+      const header = `Root: ${shortenHomePath(root)}${note ? `\n${note}` : ""}`;
+      return {
+        content: [
+          {
+            type: "text",
+            text: `${header}\n\n${results.length > 0 ? formatResults(results) : "No matches."}`,
+          },
+        ],
+        details: { root, results: results.length },
+      };
+    },
+  };
+}
@@ -0,0 +1,83 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import {
+  createExternalTools,
+  parseExternalToolOutput,
+  refreshExternalTools,
+} from "./external-tools.js";
+
+const ECHO_TOOL = `#!/bin/sh
+if [ "$1" = "--schema" ]; then
+  echo '{"name":"echo_json","description":"Echo the request.","parameters":{"type":"object","properties":{"text":{"type":"string"}},"required":["text"]}}'
+  exit 0
+fi
+cat
+`;
+
+const FAILING_TOOL = `#!/bin/sh
+if [ "$1" = "--schema" ]; then
+  echo '{"name":"always_fails"}'
+  exit 0
+fi
+echo "upstream unavailable" >&2
+exit 3
+`;
+
+describe.skipIf(process.platform === "win32")("external tools", () => {
+  let dir: string;
+  let config: OpenClawConfig;
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-external-tools-"));
+    config = { tools: { external: { dir } } } as OpenClawConfig;
+    await fs.writeFile(path.join(dir, "echo"), ECHO_TOOL, { mode: 0o755 });
+    await fs.writeFile(path.join(dir, "fails"), FAILING_TOOL, { mode: 0o755 });
+    await fs.writeFile(path.join(dir, "README.md"), "not a tool");
+    await fs.writeFile(path.join(dir, "broken"), "#!/bin/sh\necho nope\n", { mode: 0o755 });
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  it("discovers executables that declare a schema and runs them over stdin/stdout", async () => {
+    expect(createExternalTools({ config })).toEqual([]);
+    await refreshExternalTools(config);
+    const tools = createExternalTools({ config });
+    expect(tools.map((tool) => tool.name)).toEqual(["echo_json", "always_fails"]);
+
+    const echo = tools[0];
+    expect(echo.description).toBe("Echo the request.");
+    const result = await echo.execute("call-1", { text: "hi" });
+    expect(result.details).toEqual({
+      tool: "echo_json",
+      toolCallId: "call-1",
+      params: { text: "hi" },
+    });
+
+    await expect(tools[1].execute("call-2", {})).rejects.toThrow(
+      /exited with code 3: upstream unavailable/,
+    );
+  });
+
+  it("skips sandboxed sessions, disabled config, and name conflicts", async () => {
+    await refreshExternalTools(config);
+    expect(createExternalTools({ config, sandboxed: true })).toEqual([]);
+    expect(
+      createExternalTools({ config: { tools: { external: { dir, enabled: false } } } }),
+    ).toEqual([]);
+    const tools = createExternalTools({ config, existingToolNames: new Set(["echo_json"]) });
+    expect(tools.map((tool) => tool.name)).toEqual(["always_fails"]);
+  });
+
+  it("interprets content blocks, errors, and plain text output", () => {
+    expect(parseExternalToolOutput('{"content":[{"type":"text","text":"x"}]}').content).toEqual([
+      { type: "text", text: "x" },
+    ]);
+    expect(() => parseExternalToolOutput('{"error":"bad input"}')).toThrow("bad input");
+    expect(parseExternalToolOutput("plain\n").content).toEqual([{ type: "text", text: "plain" }]);
+  });
+});
@@ -0,0 +1,194 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { OpenClawConfig } from "../../config/config.js";
+import type { AnyAgentTool } from "./common.js";
+import { resolveStateDir } from "../../config/paths.js";
+import { createSubsystemLogger } from "../../logging/subsystem.js";
+import { runCommandWithTimeout } from "../../process/exec.js";
+import { isPlainObject, resolveUserPath } from "../../utils.js";
+import { jsonResult } from "./common.js";
+import { type ExternalToolDefinition, loadToolSchema } from "./tool-schema-loader.js";
+
+const log = createSubsystemLogger("agents/tools");
+
+const DEFAULT_TIMEOUT_SECONDS = 60;
+const MAX_OUTPUT_CHARS = 100_000;
+const WINDOWS_EXECUTABLE_EXTS = new Set([".exe", ".cmd", ".bat"]);
+
+// Last discovery per tools dir. Building tools reads this so it never spawns or touches the disk.
+const discoveredTools = new Map<string, ExternalToolDefinition[]>();
+
+export function resolveExternalToolsDir(config?: OpenClawConfig): string {
+  const configured = config?.tools?.external?.dir?.trim();
+  return configured ? resolveUserPath(configured) : path.join(resolveStateDir(), "tools");
+}
+
+async function isExecutableFile(filePath: string, stat: { isFile(): boolean }): Promise<boolean> {
+  if (!stat.isFile()) {
+    return false;
+  }
+  if (process.platform === "win32") {
+    return WINDOWS_EXECUTABLE_EXTS.has(path.extname(filePath).toLowerCase());
+  }
+  try {
+    await fs.access(filePath, fs.constants.X_OK);
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+async function loadExternalTool(executable: string): Promise<ExternalToolDefinition | null> {
+  try {
+    const stat = await fs.stat(executable);
+    if (!(await isExecutableFile(executable, stat))) {
+      return null;
+    }
+    return await loadToolSchema({
+      file: executable,
+      stat,
+      argv: [executable, "--schema"],
+      kind: "external",
+    });
+  } catch (err) {
+    log.warn(`external tool skipped (--schema failed): ${executable} (${String(err)})`);
+    return null;
+  }
+}
+
+export async function discoverExternalTools(dir: string): Promise<ExternalToolDefinition[]> {
+  let entries: string[];
+  try {
+    entries = (await fs.readdir(dir)).toSorted();
+  } catch {
+    return [];
+  }
+  const loaded = await Promise.all(
+    entries
+      .filter((entry) => !entry.startsWith("."))
+      .map((entry) => loadExternalTool(path.join(dir, entry))),
+  );
+  const definitions: ExternalToolDefinition[] = [];
+  const seen = new Set<string>();
+  for (const definition of loaded) {
+    if (!definition) {
+      continue;
+    }
+    if (seen.has(definition.name)) {
+      log.warn(
+        `external tool skipped (duplicate name ${definition.name}): ${definition.executable}`,
+      );
+      continue;
+    }
+    seen.add(definition.name);
+    definitions.push(definition);
+  }
+  return definitions;
+}
+
+/** Re-scans the external tools dir; `createExternalTools` serves the result of the last scan. */
+export async function refreshExternalTools(config?: OpenClawConfig): Promise<void> {
+  if (config?.tools?.external?.enabled === false) {
+    return;
+  }
+  const dir = resolveExternalToolsDir(config);
+  discoveredTools.set(dir, await discoverExternalTools(dir));
+}
+
+function truncate(text: string): string {
+  return text.length > MAX_OUTPUT_CHARS
+    ? `${text.slice(0, MAX_OUTPUT_CHARS)}\n…(truncated ${text.length - MAX_OUTPUT_CHARS} chars)`
+    : text;
+}
+
+/**
+ * Interprets an external tool's stdout. `{"content":[...]}` is passed through as tool content,
+ * `{"error":"..."}` fails the call, other JSON is returned as-is, and plain text becomes a text
+ * result.
+ */
+export function parseExternalToolOutput(stdout: string): ReturnType<typeof jsonResult> {
+  const trimmed = stdout.trim();
+  let parsed: unknown;
+  try {
+    parsed = trimmed ? JSON.parse(trimmed) : undefined;
+  } catch {
+    parsed = undefined;
+  }
+  if (isPlainObject(parsed)) {
+    if (typeof parsed.error === "string" && parsed.error.trim()) {
+      throw new Error(parsed.error.trim());
+    }
+    if (Array.isArray(parsed.content)) {
+      return {
+        content: parsed.content as ReturnType<typeof jsonResult>["content"],
+        details: parsed.details ?? {},
+      };
+    }
+  }
+  if (parsed !== undefined) {
+    return jsonResult(parsed);
+  }
+  return {
+    content: [{ type: "text", text: truncate(trimmed) || "(no output)" }],
+    details: {},
+  };
+}
+
+function createExternalTool(
+  definition: ExternalToolDefinition,
+  options: { timeoutMs: number; workspaceDir?: string },
+): AnyAgentTool {
+  return {
+    label: definition.name,
+    name: definition.name,
+    description: definition.description,
+    parameters: definition.parameters,
+    execute: async (toolCallId, args) => {
+      const result = await runCommandWithTimeout([definition.executable], {
+        timeoutMs: options.timeoutMs,
+        cwd: options.workspaceDir,
+        input: JSON.stringify({ tool: definition.name, toolCallId, params: args ?? {} }),
+      });
+      if (result.killed) {
+        throw new Error(
+          `${definition.name} timed out after ${Math.round(options.timeoutMs / 1000)}s`,
+        );
+      }
+      if (result.code !== 0) {
+        const stderr = result.stderr.trim().slice(0, 2000);
+        throw new Error(
+          `${definition.name} exited with code ${String(result.code)}${stderr ? `: ${stderr}` : ""}`,
+        );
+      }
+      return parseExternalToolOutput(result.stdout);
+    },
+  } as AnyAgentTool;
+}
+
+/**
+ * Wraps the executables found by the last `refreshExternalTools` as agent tools. They run on the
+ * gateway host, so sandboxed sessions never get them.
+ */
+export function createExternalTools(options: {
+  config?: OpenClawConfig;
+  workspaceDir?: string;
+  sandboxed?: boolean;
+  existingToolNames?: Set<string>;
+}): AnyAgentTool[] {
+  const external = options.config?.tools?.external;
+  if (external?.enabled === false || options.sandboxed) {
+    return [];
+  }
+  const timeoutMs = (external?.timeoutSeconds ?? DEFAULT_TIMEOUT_SECONDS) * 1000;
+  const tools: AnyAgentTool[] = [];
+  for (const definition of discoveredTools.get(resolveExternalToolsDir(options.config)) ?? []) {
+    if (options.existingToolNames?.has(definition.name)) {
+      log.warn(
+        `external tool skipped (name conflicts with a built-in or plugin tool): ${definition.name}`,
+      );
+      continue;
+    }
+    tools.push(createExternalTool(definition, { timeoutMs, workspaceDir: options.workspaceDir }));
+  }
+  return tools;
+}
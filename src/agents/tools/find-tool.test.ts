@@ -0,0 +1,80 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterAll, beforeAll, describe, expect, it } from "vitest";
+import { createHostSandboxFsBridge } from "../test-helpers/host-sandbox-fs-bridge.js";
+import { createFindTool } from "./find-tool.js";
+
+let root = "";
+
+async function write(rel: string, mtimeSec?: number) {
+  const target = path.join(root, rel);
+  await fs.mkdir(path.dirname(target), { recursive: true });
+  await fs.writeFile(target, "x");
+  if (mtimeSec !== undefined) {
+    await fs.utimes(target, mtimeSec, mtimeSec);
+  }
+}
+
+async function find(args: Record<string, unknown>, opts?: { sandboxed?: boolean }) {
+  const tool = createFindTool({
+    root,
+    sandbox: opts?.sandboxed ? { root, bridge: createHostSandboxFsBridge(root) } : undefined,
+  });
+  const result = await tool.execute("call", args);
+  const text = (result.content[0] as { text: string }).text;
+  return { text, details: result.details as { count: number; truncated: boolean } };
+}
+
+beforeAll(async () => {
+  root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-find-"));
+  await write(".gitignore");
+  await fs.writeFile(path.join(root, ".gitignore"), "generated/\n");
+  await write("src/old.ts", 1_000);
+  await write("src/new.ts", 3_000);
+  await write("src/lib/mid.ts", 2_000);
+  await write("README.md", 2_500);
+  await write("generated/out.ts", 4_000);
+  await write("node_modules/dep/index.ts", 5_000);
+});
+
+afterAll(async () => {
+  await fs.rm(root, { recursive: true, force: true });
+});
+
+describe("find tool", () => {
+  it("returns matches newest first and skips ignored paths", async () => {
+    const { text } = await find({ pattern: "**/*.ts" });
+    expect(text.split("\n")).toEqual(["src/new.ts", "src/lib/mid.ts", "src/old.ts"]);
+  });
+
+  it("matches bare patterns against file names at any depth", async () => {
+    const { text } = await find({ pattern: "mid.ts" });
+    expect(text).toBe("src/lib/mid.ts");
+  });
+
+  it("includes ignored paths on request", async () => {
+    const { text } = await find({ pattern: "*.ts", includeIgnored: true, limit: 2 });
+    expect(text.split("\n").slice(0, 2)).toEqual(["node_modules/dep/index.ts", "generated/out.ts"]);
+    expect(text).toContain("Showing 2 of 5 matches");
+  });
+
+  it("keeps sandboxed searches inside the root", async () => {
+    const outside = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-find-outside-"));
+    try {
+      await fs.writeFile(path.join(outside, "secret.ts"), "x");
+      await fs.symlink(outside, path.join(root, "linked"));
+      const { text } = await find({ pattern: "**/secret.ts" }, { sandboxed: true });
+      expect(text).toBe("No files found.");
+      await expect(find({ pattern: "*", path: "linked" }, { sandboxed: true })).rejects.toThrow(
+        /Symlink not allowed/,
+      );
+      await expect(find({ pattern: "*", path: "../" }, { sandboxed: true })).rejects.toThrow(
+        /escapes sandbox root/,
+      );
+    } finally {
+      await fs.rm(path.join(root, "linked"), { force: true });
+      await fs.rm(outside, { recursive: true, force: true });
+    }
+  });
+});
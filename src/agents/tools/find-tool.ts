@@ -0,0 +1,129 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import { Type } from "@sinclair/typebox";
+import type { AnyAgentTool } from "./common.js";
+import { readNumberParam, readStringParam, ToolInputError } from "./common.js";
+import {
+  createGlobMatcher,
+  formatWorkspacePath,
+  resolveWorkspaceToolPath,
+  statWorkspaceToolPath,
+  walkTree,
+  type WorkspaceToolSandbox,
+} from "./fs-walk.js";
+
+const DEFAULT_FIND_LIMIT = 100;
+const MAX_FIND_LIMIT = 1000;
+// Stop collecting candidates past this many matches; sorting needs a stat per match.
+const MAX_FIND_CANDIDATES = 20_000;
+const SANDBOX_STAT_BATCH = 16;
+
+const FindToolSchema = Type.Object({
+  pattern: Type.String({
+    description:
+      'Glob to match file paths, e.g. "**/*.ts", "src/**/test_*.py", or "*.md" (no slash matches the file name at any depth).',
+  }),
+  path: Type.Optional(
+    Type.String({ description: "Directory to search (default: workspace root)." }),
+  ),
+  limit: Type.Optional(
+    Type.Number({
+      description: `Maximum paths to return (default ${DEFAULT_FIND_LIMIT}).`,
+      minimum: 1,
+    }),
+  ),
+  includeIgnored: Type.Optional(
+    Type.Boolean({
+      description: "Also search .git, node_modules, and .gitignore'd paths (default false).",
+    }),
+  ),
+});
+
+export type FindToolDetails = {
+  count: number;
+  truncated: boolean;
+};
+
+export function createFindTool(opts: {
+  root: string;
+  sandbox?: WorkspaceToolSandbox;
+}): AnyAgentTool {
+  return {
+    label: "find",
+    name: "find",
+    description:
+      "Find files by glob pattern. Returns workspace-relative paths, most recently modified first. Skips .git, node_modules, and .gitignore'd paths unless includeIgnored=true.",
+    parameters: FindToolSchema,
+    execute: async (
+      _toolCallId,
+      args,
+      signal?: AbortSignal,
+    ): Promise<AgentToolResult<FindToolDetails>> => {
+      const params = args as Record<string, unknown>;
+      const pattern = readStringParam(params, "pattern", { required: true });
+      const limit = Math.min(
+        MAX_FIND_LIMIT,
+        Math.max(1, readNumberParam(params, "limit", { integer: true }) ?? DEFAULT_FIND_LIMIT),
+      );
+      const includeIgnored = params.includeIgnored === true;
+      const target = await resolveWorkspaceToolPath({
+        root: opts.root,
+        input: readStringParam(params, "path"),
+        sandbox: opts.sandbox,
+      });
+      const stat = await statWorkspaceToolPath(target, opts.sandbox);
+      if (stat?.type !== "directory") {
+        throw new ToolInputError(`Not a directory: ${readStringParam(params, "path") ?? target}`);
+      }
+
+      const matches = createGlobMatcher([pattern]);
+      const candidates: string[] = [];
+      let capped = false;
+      for await (const entry of walkTree({
+        root: target,
+        respectGitignore: !includeIgnored,
+        skipDirs: includeIgnored ? [] : undefined,
+        skipSymlinks: Boolean(opts.sandbox),
+        signal,
+      })) {
+        if (!entry.dirent.isFile() || !matches(entry.relPath)) {
+          continue;
+        }
+        if (candidates.length >= MAX_FIND_CANDIDATES) {
+          capped = true;
+          break;
+        }
+        candidates.push(entry.path);
+      }
+
+      // Sandboxed stats each run a container command, so batch them.
+      const batchSize = opts.sandbox ? SANDBOX_STAT_BATCH : candidates.length || 1;
+      const withMtime: Array<{ file: string; mtimeMs: number }> = [];
+      for (let i = 0; i < candidates.length; i += batchSize) {
+        const batch = await Promise.all(
+          candidates.slice(i, i + batchSize).map(async (file) => ({
+            file,
+            mtimeMs: (await statWorkspaceToolPath(file, opts.sandbox))?.mtimeMs ?? 0,
+          })),
+        );
+        withMtime.push(...batch);
+      }
+      withMtime.sort((a, b) => b.mtimeMs - a.mtimeMs || a.file.localeCompare(b.file));
+      const results = withMtime
+        .slice(0, limit)
+        .map(({ file }) => formatWorkspacePath(file, opts.root));
+      const truncated = capped || withMtime.length > limit;
+
+      const details: FindToolDetails = { count: results.length, truncated };
+      if (results.length === 0) {
+        return { content: [{ type: "text", text: "No files found." }], details };
+      }
+      let text = results.join("\n");
+      if (truncated) {
+        text += capped
+          ? `\n\n[Showing ${limit} of more than ${MAX_FIND_CANDIDATES} matches; narrow the pattern or path.]`
+          : `\n\n[Showing ${limit} of ${withMtime.length} matches; narrow the pattern or raise limit.]`;
+      }
+      return { content: [{ type: "text", text }], details };
+    },
+  };
+}
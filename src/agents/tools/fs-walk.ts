@@ -0,0 +1,334 @@
+import type { Dirent } from "node:fs";
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { SandboxFsBridge, SandboxFsStat } from "../sandbox/fs-bridge.js";
+import { resolveUserPath } from "../../utils.js";
+import { assertSandboxPath } from "../sandbox-paths.js";
+
+// Directories skipped by the workspace search tools unless explicitly disabled.
+export const DEFAULT_SKIP_DIRS = [".git", "node_modules"];
+
+export type WalkEntry = {
+  path: string;
+  /** Path relative to the walk root, always `/`-separated. */
+  relPath: string;
+  dirent: Dirent;
+  depth: number;
+};
+
+type IgnoreRule = {
+  base: string;
+  regex: RegExp;
+  negate: boolean;
+  dirOnly: boolean;
+  anchored: boolean;
+};
+
+/** Sandbox workspace for the search tools: host root plus the container filesystem bridge. */
+export type WorkspaceToolSandbox = { root: string; bridge: SandboxFsBridge };
+
+/**
+ * Resolve a tool path argument against the workspace. Sandboxed paths must stay under the sandbox
+ * root and may not pass through a symlink.
+ */
+export async function resolveWorkspaceToolPath(params: {
+  root: string;
+  input?: string;
+  sandbox?: WorkspaceToolSandbox;
+}): Promise<string> {
+  const input = params.input?.trim();
+  if (!input || input === ".") {
+    return params.root;
+  }
+  if (params.sandbox) {
+    const resolved = await assertSandboxPath({
+      filePath: input,
+      cwd: params.root,
+      root: params.sandbox.root,
+    });
+    return resolved.resolved;
+  }
+  return input.startsWith("~") ? resolveUserPath(input) : path.resolve(params.root, input);
+}
+
+/** Stat a resolved tool path; sandboxed paths are checked inside the container. */
+export async function statWorkspaceToolPath(
+  target: string,
+  sandbox?: WorkspaceToolSandbox,
+): Promise<SandboxFsStat | null> {
+  if (sandbox) {
+    return await sandbox.bridge.stat({ filePath: target, cwd: sandbox.root }).catch(() => null);
+  }
+  const stat = await fs.stat(target).catch(() => null);
+  if (!stat) {
+    return null;
+  }
+  const type = stat.isDirectory() ? "directory" : stat.isFile() ? "file" : "other";
+  return { type, size: stat.size, mtimeMs: stat.mtimeMs };
+}
+
+/** Read a file found by the search tools; sandboxed reads go through the container bridge. */
+export async function readWorkspaceToolFile(
+  file: string,
+  sandbox?: WorkspaceToolSandbox,
+  signal?: AbortSignal,
+): Promise<Buffer> {
+  if (sandbox) {
+    return await sandbox.bridge.readFile({ filePath: file, cwd: sandbox.root, signal });
+  }
+  return await fs.readFile(file);
+}
+
+/** Display a path relative to the workspace root (`/`-separated), or absolute when outside it. */
+export function formatWorkspacePath(target: string, root: string) {
+  const rel = path.relative(root, target);
+  return (rel && !rel.startsWith("..") && !path.isAbsolute(rel) ? rel : target)
+    .split(path.sep)
+    .join("/");
+}
+
+/** Human-readable byte count (`512 B`, `4.2 KB`, `18 MB`). */
+export function formatSize(bytes: number) {
+  if (bytes < 1024) {
+    return `${bytes} B`;
+  }
+  const units = ["KB", "MB", "GB", "TB"];
+  let value = bytes / 1024;
+  let unit = 0;
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024;
+    unit += 1;
+  }
+  return `${value >= 10 ? Math.round(value) : value.toFixed(1)} ${units[unit]}`;
+}
+
+function escapeRegex(value: string) {
+  return value.replace(/[.*+?^${}()|[\]\\]/g, "\\$&");
+}
+
+function expandBraces(pattern: string): string[] {
+  const match = /\{([^{}]*)\}/.exec(pattern);
+  if (!match) {
+    return [pattern];
+  }
+  const head = pattern.slice(0, match.index);
+  const tail = pattern.slice(match.index + match[0].length);
+  return match[1].split(",").flatMap((option) => expandBraces(`${head}${option}${tail}`));
+}
+
+function globSource(pattern: string): string {
+  let out = "";
+  let i = 0;
+  while (i < pattern.length) {
+    const ch = pattern[i];
+    if (ch === "*") {
+      if (pattern[i + 1] === "*") {
+        const atSegmentStart = i === 0 || pattern[i - 1] === "/";
+        const next = pattern[i + 2];
+        if (atSegmentStart && next === "/") {
+          out += "(?:.*/)?";
+          i += 3;
+          continue;
+        }
+        if (atSegmentStart && next === undefined) {
+          out += ".*";
+          i += 2;
+          continue;
+        }
+      }
+      out += "[^/]*";
+      i += 1;
+      continue;
+    }
+    if (ch === "?") {
+      out += "[^/]";
+      i += 1;
+      continue;
+    }
+    if (ch === "[") {
+      const end = pattern.indexOf("]", i + 2);
+      if (end !== -1) {
+        const body = pattern.slice(i + 1, end).replace(/^!/, "^").replace(/\\/g, "\\\\");
+        out += `[${body}]`;
+        i = end + 1;
+        continue;
+      }
+    }
+    if (ch === "\\" && i + 1 < pattern.length) {
+      out += escapeRegex(pattern[i + 1]);
+      i += 2;
+      continue;
+    }
+    out += escapeRegex(ch);
+    i += 1;
+  }
+  return out;
+}
+
+/**
+ * Compile a glob (`*`, `**`, `?`, `[...]`, `{a,b}`) to a regex over `/`-separated paths.
+ */
+export function compileGlob(pattern: string, opts?: { ignoreCase?: boolean }): RegExp {
+  const sources = expandBraces(pattern).map((entry) => globSource(entry));
+  return new RegExp(`^(?:${sources.join("|")})$`, opts?.ignoreCase ? "i" : "");
+}
+
+/**
+ * Match a relative path against a glob. Patterns without `/` match the basename at any
+ * depth, like ripgrep's `--glob`.
+ */
+export function createGlobMatcher(patterns: string[], opts?: { ignoreCase?: boolean }) {
+  const compiled = patterns
+    .map((pattern) => pattern.trim())
+    .filter(Boolean)
+    .map((pattern) => ({
+      regex: compileGlob(pattern.replace(/^\.\//, ""), opts),
+      basename: !pattern.includes("/"),
+    }));
+  return (relPath: string) =>
+    compiled.some(({ regex, basename }) =>
+      regex.test(basename ? path.posix.basename(relPath) : relPath),
+    );
+}
+
+export function parseGitignore(content: string, base: string): IgnoreRule[] {
+  const rules: IgnoreRule[] = [];
+  for (const rawLine of content.split(/\r?\n/)) {
+    let line = rawLine.replace(/(?<!\\)\s+$/, "");
+    if (!line || line.startsWith("#")) {
+      continue;
+    }
+    let negate = false;
+    if (line.startsWith("!")) {
+      negate = true;
+      line = line.slice(1);
+    } else if (line.startsWith("\\!") || line.startsWith("\\#")) {
+      line = line.slice(1);
+    }
+    let dirOnly = false;
+    if (line.endsWith("/")) {
+      dirOnly = true;
+      line = line.slice(0, -1);
+    }
+    if (!line) {
+      continue;
+    }
+    const anchored = line.includes("/");
+    line = line.replace(/^\//, "");
+    rules.push({
+      base,
+      regex: new RegExp(`^${globSource(line)}$`),
+      negate,
+      dirOnly,
+      anchored,
+    });
+  }
+  return rules;
+}
+
+function isIgnoredByRules(rules: IgnoreRule[], relPath: string, isDir: boolean): boolean {
+  let ignored = false;
+  for (const rule of rules) {
+    if (rule.dirOnly && !isDir) {
+      continue;
+    }
+    if (rule.base && relPath !== rule.base && !relPath.startsWith(`${rule.base}/`)) {
+      continue;
+    }
+    const local = rule.base ? relPath.slice(rule.base.length + 1) : relPath;
+    const target = rule.anchored ? local : path.posix.basename(local);
+    if (rule.regex.test(target)) {
+      ignored = !rule.negate;
+    }
+  }
+  return ignored;
+}
+
+// With `contained`, ignore files that resolve outside the walk root (e.g. through a symlink) are
+// treated as missing.
+async function readIgnoreFile(file: string, root: string, contained: boolean): Promise<string> {
+  if (contained) {
+    const rel = path.relative(await fs.realpath(root), await fs.realpath(file));
+    if (rel.startsWith("..") || path.isAbsolute(rel)) {
+      return "";
+    }
+  }
+  return await fs.readFile(file, "utf8");
+}
+
+/**
+ * Depth-first walk of a directory tree in name order. Skips DEFAULT_SKIP_DIRS and, when
+ * `respectGitignore` is set, paths excluded by `.gitignore` files along the way (including
+ * the root's `.git/info/exclude`). Symlinked directories are not followed; with `skipSymlinks`,
+ * symlinks are not yielded at all (sandboxed tools must not reach outside the root through one).
+ */
+export async function* walkTree(opts: {
+  root: string;
+  respectGitignore?: boolean;
+  skipDirs?: string[];
+  maxDepth?: number;
+  skipSymlinks?: boolean;
+  signal?: AbortSignal;
+}): AsyncGenerator<WalkEntry> {
+  const skipDirs = new Set(opts.skipDirs ?? DEFAULT_SKIP_DIRS);
+  const respectGitignore = opts.respectGitignore !== false;
+  const skipSymlinks = opts.skipSymlinks === true;
+  let rootRules: IgnoreRule[] = [];
+  if (respectGitignore) {
+    try {
+      const exclude = await readIgnoreFile(
+        path.join(opts.root, ".git", "info", "exclude"),
+        opts.root,
+        skipSymlinks,
+      );
+      rootRules = parseGitignore(exclude, "");
+    } catch {
+      // no repo-local excludes
+    }
+  }
+
+  async function* visit(dir: string, relDir: string, depth: number, inherited: IgnoreRule[]) {
+    if (opts.signal?.aborted) {
+      return;
+    }
+    let entries: Dirent[];
+    try {
+      entries = await fs.readdir(dir, { withFileTypes: true });
+    } catch {
+      return;
+    }
+    let rules: IgnoreRule[] = [];
+    if (respectGitignore) {
+      const content = await readIgnoreFile(
+        path.join(dir, ".gitignore"),
+        opts.root,
+        skipSymlinks,
+      ).catch(() => "");
+      rules = [...inherited, ...parseGitignore(content, relDir)];
+    }
+    entries.sort((a, b) => (a.name < b.name ? -1 : a.name > b.name ? 1 : 0));
+    for (const dirent of entries) {
+      if (opts.signal?.aborted) {
+        return;
+      }
+      if (skipSymlinks && dirent.isSymbolicLink()) {
+        continue;
+      }
+      const isDir = dirent.isDirectory();
+      if (isDir && skipDirs.has(dirent.name)) {
+        continue;
+      }
+      const relPath = relDir ? `${relDir}/${dirent.name}` : dirent.name;
+      if (rules.length > 0 && isIgnoredByRules(rules, relPath, isDir)) {
+        continue;
+      }
+      const fullPath = path.join(dir, dirent.name);
+      yield { path: fullPath, relPath, dirent, depth };
+      if (isDir && (opts.maxDepth === undefined || depth < opts.maxDepth)) {
+        yield* visit(fullPath, relPath, depth + 1, rules);
+      }
+    }
+  }
+
+  yield* visit(opts.root, "", 0, rootRules);
+}
@@ -0,0 +1,178 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import * as ssrf from "../../infra/net/ssrf.js";
+import { createGhTool, readGhKeyringToken, resolveGitHubHost } from "./gh-tool.js";
+
+type GitHubConfig = NonNullable<NonNullable<OpenClawConfig["tools"]>["github"]>;
+
+function createTool(github: GitHubConfig = {}, env: NodeJS.ProcessEnv = {}) {
+  return createGhTool({
+    config: { tools: { github: { enabled: true, keyring: false, ...github } } },
+    env,
+  });
+}
+
+function jsonResponse(body: unknown, status = 200) {
+  return new Response(JSON.stringify(body), {
+    status,
+    headers: { "content-type": "application/json; charset=utf-8" },
+  });
+}
+
+function stubFetch(...responses: Response[]) {
+  const mockFetch = vi.fn();
+  for (const response of responses) {
+    mockFetch.mockResolvedValueOnce(response);
+  }
+  // @ts-expect-error mock fetch
+  global.fetch = mockFetch;
+  return mockFetch;
+}
+
+describe("gh tool", () => {
+  const priorFetch = global.fetch;
+
+  beforeEach(() => {
+    vi.spyOn(ssrf, "resolvePinnedHostnameWithPolicy").mockImplementation(async (hostname) => {
+      const normalized = hostname.trim().toLowerCase().replace(/\.$/, "");
+      const addresses = ["140.82.112.6"];
+      return {
+        hostname: normalized,
+        addresses,
+        lookup: ssrf.createPinnedLookup({ hostname: normalized, addresses }),
+      };
+    });
+  });
+
+  afterEach(() => {
+    // @ts-expect-error restore
+    global.fetch = priorFetch;
+    vi.restoreAllMocks();
+  });
+
+  it("is disabled unless configured", () => {
+    expect(createGhTool({ config: {} })).toBeNull();
+    expect(createTool()?.name).toBe("gh");
+  });
+
+  it("lists issues without pull requests using the env token", async () => {
+    const fetchSpy = stubFetch(
+      jsonResponse([
+        {
+          number: 12,
+          title: "Crash on start",
+          state: "open",
+          html_url: "https://github.com/acme/app/issues/12",
+          user: { login: "ada" },
+          labels: [{ name: "bug" }],
+          comments: 2,
+        },
+        { number: 13, title: "Fix crash", state: "open", html_url: "x", pull_request: {} },
+      ]),
+    );
+
+    const tool = createTool({ defaultRepo: "acme/app" }, { GH_TOKEN: "env-token" });
+    const result = await tool?.execute("call", {
+      action: "list_issues",
+      labels: ["bug"],
+      limit: 5,
+    });
+
+    const [url, init] = fetchSpy.mock.calls[0] as [string, RequestInit];
+    expect(url).toBe(
+      "https://api.github.com/repos/acme/app/issues?state=open&per_page=5&labels=bug",
+    );
+    expect(init.headers).toMatchObject({ Authorization: "Bearer env-token" });
+    expect(result?.details).toMatchObject({
+      count: 1,
+      issues: [{ number: 12, author: "ada", labels: ["bug"], comments: 2 }],
+    });
+  });
+
+  it("returns PR diffs as text and creates PRs against the default branch", async () => {
+    const fetchSpy = stubFetch(
+      new Response("diff --git a/a.txt b/a.txt\n-one\n+two\n", {
+        headers: { "content-type": "text/plain; charset=utf-8" },
+      }),
+      jsonResponse({ default_branch: "main" }),
+      jsonResponse(
+        {
+          number: 42,
+          title: "Add feature",
+          state: "open",
+          html_url: "https://github.com/acme/app/pull/42",
+          head: { ref: "feature" },
+          base: { ref: "main" },
+        },
+        201,
+      ),
+    );
+    const tool = createTool({ token: "cfg-token" }, { GH_TOKEN: "env-token" });
+
+    const diff = await tool?.execute("call", { action: "pr_diff", repo: "acme/app", number: 42 });
+    const diffText = (diff?.content[0] as { text: string }).text;
+    expect(diffText).toContain("+two");
+    expect(diffText).toContain("<<<EXTERNAL_UNTRUSTED_CONTENT>>>");
+    expect((fetchSpy.mock.calls[0]?.[1] as RequestInit).headers).toMatchObject({
+      Accept: "application/vnd.github.diff",
+      Authorization: "Bearer cfg-token",
+    });
+
+    const created = await tool?.execute("call", {
+      action: "create_pr",
+      repo: "acme/app",
+      title: "Add feature",
+      head: "feature",
+    });
+    const [url, init] = fetchSpy.mock.calls[2] as [string, RequestInit];
+    expect(url).toBe("https://api.github.com/repos/acme/app/pulls");
+    expect(JSON.parse(init.body as string)).toEqual({
+      title: "Add feature",
+      head: "feature",
+      base: "main",
+      body: "",
+      draft: false,
+    });
+    expect(created?.details).toMatchObject({ ok: true, number: 42, head: "feature" });
+  });
+
+  it("requires a token for write actions and surfaces API errors", async () => {
+    const tool = createTool({ defaultRepo: "acme/app" });
+    await expect(
+      tool?.execute("call", { action: "comment", number: 1, body: "hi" }),
+    ).rejects.toThrow(/no GitHub token/);
+
+    stubFetch(jsonResponse({ message: "Not Found" }, 404));
+    await expect(tool?.execute("call", { action: "view", number: 999 })).rejects.toThrow(
+      /GitHub API 404: Not Found/,
+    );
+    await expect(tool?.execute("call", { action: "view", repo: "not a repo" })).rejects.toThrow(
+      /owner\/name/,
+    );
+  });
+
+  it("reads and decodes gh keyring entries", async () => {
+    const run = vi.fn(async () => ({
+      stdout: `go-keyring-base64:${Buffer.from("gho_secret").toString("base64")}\n`,
+    }));
+    await expect(readGhKeyringToken({ host: "github.com", platform: "darwin", run })).resolves.toBe(
+      "gho_secret",
+    );
+    expect(run).toHaveBeenCalledWith("security", [
+      "find-generic-password",
+      "-s",
+      "gh:github.com",
+      "-w",
+    ]);
+    await expect(
+      readGhKeyringToken({
+        host: "github.com",
+        platform: "linux",
+        run: async () => {
+          throw new Error("no secret");
+        },
+      }),
+    ).resolves.toBeUndefined();
+    expect(resolveGitHubHost("https://ghe.example.com/api/v3")).toBe("ghe.example.com");
+  });
+});
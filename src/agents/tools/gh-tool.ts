@@ -0,0 +1,423 @@
+import { Type } from "@sinclair/typebox";
+import type { OpenClawConfig } from "../../config/config.js";
+import type { AnyAgentTool } from "./common.js";
+import { fetchWithSsrFGuard } from "../../infra/net/fetch-guard.js";
+import { runExec } from "../../process/exec.js";
+import { wrapExternalContent } from "../../security/external-content.js";
+import { optionalStringEnum, stringEnum } from "../schema/typebox.js";
+import { jsonResult, readNumberParam, readStringParam, ToolInputError } from "./common.js";
+import { truncateText } from "./web-fetch-utils.js";
+import { readResponseTextCapped, resolveTimeoutSeconds } from "./web-shared.js";
+
+const GH_ACTIONS = ["list_issues", "list_prs", "view", "pr_diff", "comment", "create_pr"] as const;
+type GhAction = (typeof GH_ACTIONS)[number];
+
+const GH_STATES = ["open", "closed", "all"] as const;
+
+const DEFAULT_API_BASE_URL = "https://api.github.com";
+const DEFAULT_TIMEOUT_SECONDS = 30;
+const DEFAULT_MAX_CHARS = 30_000;
+const DEFAULT_LIST_LIMIT = 30;
+const MAX_LIST_LIMIT = 100;
+// Bodies past this are cut off while streaming; JSON that hits it cannot be parsed.
+const MAX_RESPONSE_BYTES = 8 * 1024 * 1024;
+const VIEW_COMMENT_LIMIT = 30;
+const KEYRING_TIMEOUT_MS = 5_000;
+const GO_KEYRING_PREFIX = "go-keyring-base64:";
+
+const GhSchema = Type.Object({
+  action: stringEnum(GH_ACTIONS, {
+    description:
+      "list_issues | list_prs | view (issue/PR with comments) | pr_diff | comment | create_pr.",
+  }),
+  repo: Type.Optional(
+    Type.String({
+      description: 'Repository as "owner/name" (default: tools.github.defaultRepo).',
+    }),
+  ),
+  number: Type.Optional(
+    Type.Number({
+      description: "Issue or pull request number (view, pr_diff, comment).",
+      minimum: 1,
+    }),
+  ),
+  state: optionalStringEnum(GH_STATES, { description: "List filter (default: open)." }),
+  labels: Type.Optional(
+    Type.Array(Type.String(), { description: "list_issues: only issues with all these labels." }),
+  ),
+  limit: Type.Optional(
+    Type.Number({ description: "Max items for list actions (default 30, max 100).", minimum: 1 }),
+  ),
+  title: Type.Optional(Type.String({ description: "create_pr: pull request title." })),
+  body: Type.Optional(
+    Type.String({ description: "comment: comment text; create_pr: pull request description." }),
+  ),
+  head: Type.Optional(
+    Type.String({
+      description: 'create_pr: branch with the changes ("branch" or "owner:branch").',
+    }),
+  ),
+  base: Type.Optional(
+    Type.String({ description: "create_pr: target branch (default: the repo default branch)." }),
+  ),
+  draft: Type.Optional(Type.Boolean({ description: "create_pr: open as a draft." })),
+});
+
+type GitHubToolConfig = NonNullable<NonNullable<OpenClawConfig["tools"]>["github"]>;
+
+type ExecRunner = (command: string, args: string[]) => Promise<{ stdout: string }>;
+
+function resolveGitHubConfig(cfg?: OpenClawConfig): GitHubToolConfig | undefined {
+  const github = cfg?.tools?.github;
+  if (!github || typeof github !== "object") {
+    return undefined;
+  }
+  return github;
+}
+
+/** Web host whose gh CLI credentials apply to an API base URL (api.github.com → github.com). */
+export function resolveGitHubHost(apiBaseUrl: string): string {
+  const host = new URL(apiBaseUrl).hostname.toLowerCase();
+  return host === "api.github.com" ? "github.com" : host;
+}
+
+function decodeKeyringSecret(raw: string): string | undefined {
+  const value = raw.trim();
+  if (!value) {
+    return undefined;
+  }
+  if (value.startsWith(GO_KEYRING_PREFIX)) {
+    return Buffer.from(value.slice(GO_KEYRING_PREFIX.length), "base64").toString("utf8").trim();
+  }
+  return value;
+}
+
+/**
+ * Token stored by the gh CLI in the OS keyring (macOS Keychain or Secret Service), if any.
+ * Lookup failures are treated as "no token".
+ */
+export async function readGhKeyringToken(params: {
+  host: string;
+  platform?: NodeJS.Platform;
+  run?: ExecRunner;
+}): Promise<string | undefined> {
+  const platform = params.platform ?? process.platform;
+  const run: ExecRunner =
+    params.run ?? ((command, args) => runExec(command, args, { timeoutMs: KEYRING_TIMEOUT_MS }));
+  const service = `gh:${params.host}`;
+  const lookup =
+    platform === "darwin"
+      ? { command: "security", args: ["find-generic-password", "-s", service, "-w"] }
+      : platform === "linux"
+        ? { command: "secret-tool", args: ["lookup", "service", service] }
+        : null;
+  if (!lookup) {
+    return undefined;
+  }
+  try {
+    const { stdout } = await run(lookup.command, lookup.args);
+    return decodeKeyringSecret(stdout);
+  } catch {
+    return undefined;
+  }
+}
+
+async function resolveToken(params: {
+  github: GitHubToolConfig;
+  host: string;
+  env: NodeJS.ProcessEnv;
+  run?: ExecRunner;
+}): Promise<string | undefined> {
+  const fromConfig = params.github.token?.trim();
+  if (fromConfig) {
+    return fromConfig;
+  }
+  const fromEnv = (params.env.GH_TOKEN ?? params.env.GITHUB_TOKEN)?.trim();
+  if (fromEnv) {
+    return fromEnv;
+  }
+  if (params.github.keyring === false) {
+    return undefined;
+  }
+  return await readGhKeyringToken({ host: params.host, run: params.run });
+}
+
+function readRepo(params: Record<string, unknown>, defaultRepo?: string): string {
+  const repo = (readStringParam(params, "repo") ?? defaultRepo)?.trim();
+  if (!repo) {
+    throw new ToolInputError("repo required (or set tools.github.defaultRepo)");
+  }
+  if (!/^[\w.-]+\/[\w.-]+$/.test(repo)) {
+    throw new ToolInputError('repo must look like "owner/name"');
+  }
+  return repo;
+}
+
+function readIssueNumber(params: Record<string, unknown>): number {
+  const number = readNumberParam(params, "number", { required: true, integer: true });
+  if (number === undefined || number < 1) {
+    throw new ToolInputError("number must be a positive integer");
+  }
+  return number;
+}
+
+function wrapText(text: string | null | undefined): string {
+  const value = text?.trim();
+  return value ? wrapExternalContent(value, { source: "api", includeWarning: false }) : "";
+}
+
+type GitHubUser = { login?: string } | null | undefined;
+type GitHubLabel = { name?: string } | string;
+
+type GitHubIssue = {
+  number: number;
+  title: string;
+  state: string;
+  html_url: string;
+  user?: GitHubUser;
+  labels?: GitHubLabel[];
+  comments?: number;
+  created_at?: string;
+  updated_at?: string;
+  body?: string | null;
+  draft?: boolean;
+  pull_request?: unknown;
+  head?: { ref?: string };
+  base?: { ref?: string };
+};
+
+type GitHubComment = {
+  user?: GitHubUser;
+  created_at?: string;
+  body?: string | null;
+  html_url?: string;
+};
+
+function summarizeIssue(issue: GitHubIssue) {
+  return {
+    number: issue.number,
+    title: issue.title,
+    state: issue.state,
+    author: issue.user?.login,
+    labels: (issue.labels ?? [])
+      .map((label) => (typeof label === "string" ? label : label.name))
+      .filter((name): name is string => Boolean(name)),
+    ...(issue.draft ? { draft: true } : {}),
+    ...(issue.head?.ref ? { head: issue.head.ref, base: issue.base?.ref } : {}),
+    ...(typeof issue.comments === "number" ? { comments: issue.comments } : {}),
+    updatedAt: issue.updated_at,
+    url: issue.html_url,
+  };
+}
+
+export function createGhTool(options?: {
+  config?: OpenClawConfig;
+  env?: NodeJS.ProcessEnv;
+  run?: ExecRunner;
+}): AnyAgentTool | null {
+  const github = resolveGitHubConfig(options?.config);
+  if (github?.enabled !== true) {
+    return null;
+  }
+  const apiBaseUrl = (github.apiBaseUrl?.trim() || DEFAULT_API_BASE_URL).replace(/\/+$/, "");
+  const host = resolveGitHubHost(apiBaseUrl);
+  const apiHost = new URL(apiBaseUrl).hostname;
+  const timeoutSeconds = resolveTimeoutSeconds(github.timeoutSeconds, DEFAULT_TIMEOUT_SECONDS);
+  const maxChars = Math.max(1000, github.maxChars ?? DEFAULT_MAX_CHARS);
+  let tokenPromise: Promise<string | undefined> | undefined;
+
+  const request = async <T>(params: {
+    path: string;
+    method?: "GET" | "POST";
+    body?: unknown;
+    accept?: string;
+  }): Promise<{ data: T; text: string }> => {
+    tokenPromise ??= resolveToken({
+      github,
+      host,
+      env: options?.env ?? process.env,
+      run: options?.run,
+    });
+    const token = await tokenPromise;
+    const method = params.method ?? "GET";
+    if (!token && method !== "GET") {
+      throw new Error(
+        "gh: no GitHub token (set tools.github.token or GH_TOKEN, or run `gh auth login`)",
+      );
+    }
+    const headers: Record<string, string> = {
+      Accept: params.accept ?? "application/vnd.github+json",
+      "User-Agent": "openclaw-gh",
+      "X-GitHub-Api-Version": "2022-11-28",
+      ...(token ? { Authorization: `Bearer ${token}` } : {}),
+      ...(params.body !== undefined ? { "Content-Type": "application/json" } : {}),
+    };
+    const result = await fetchWithSsrFGuard({
+      url: `${apiBaseUrl}${params.path}`,
+      // The token must never be replayed to another host, so redirects are not followed.
+      maxRedirects: 0,
+      timeoutMs: timeoutSeconds * 1000,
+      // Explicitly configured API hosts (e.g. GitHub Enterprise) may live on a private network.
+      policy: { allowedHostnames: [apiHost] },
+      auditContext: "gh",
+      init: {
+        method,
+        headers,
+        ...(params.body !== undefined ? { body: JSON.stringify(params.body) } : {}),
+      },
+    }).catch((err: unknown) => {
+      if (err instanceof Error && /Too many redirects/.test(err.message)) {
+        throw new Error(`gh: ${params.path} redirected (repository renamed or moved?)`, {
+          cause: err,
+        });
+      }
+      throw err;
+    });
+    try {
+      const res = result.response;
+      const { text, truncated } = await readResponseTextCapped(res, MAX_RESPONSE_BYTES);
+      if (!res.ok) {
+        let message = text.trim();
+        try {
+          message = (JSON.parse(text) as { message?: string }).message ?? message;
+        } catch {
+          // Non-JSON error body; keep the raw text.
+        }
+        const hint =
+          res.status === 401 || (res.status === 404 && !token)
+            ? " (check the GitHub token and its scopes)"
+            : "";
+        throw new Error(`gh: GitHub API ${res.status}: ${message || res.statusText}${hint}`);
+      }
+      const isJson = res.headers.get("content-type")?.includes("json") ?? false;
+      if (isJson && truncated) {
+        throw new Error(
+          `gh: ${params.path} returned more than ${MAX_RESPONSE_BYTES} bytes; narrow the request (e.g. a lower limit).`,
+        );
+      }
+      return { data: (isJson && text ? JSON.parse(text) : undefined) as T, text };
+    } finally {
+      await result.release();
+    }
+  };
+
+  return {
+    label: "GitHub",
+    name: "gh",
+    description:
+      "Work with GitHub issues and pull requests over the REST API: list issues/PRs, view an issue or PR with its comments, read a PR diff, post comments, and open pull requests.",
+    parameters: GhSchema,
+    execute: async (_toolCallId, args) => {
+      const params = args as Record<string, unknown>;
+      const action = readStringParam(params, "action", { required: true }) as GhAction;
+      if (!GH_ACTIONS.includes(action)) {
+        throw new ToolInputError(`action must be one of ${GH_ACTIONS.join(", ")}`);
+      }
+      const repo = readRepo(params, github.defaultRepo);
+      const state = readStringParam(params, "state") ?? "open";
+      if (!GH_STATES.includes(state as (typeof GH_STATES)[number])) {
+        throw new ToolInputError("state must be open, closed, or all");
+      }
+      const limit = Math.min(
+        MAX_LIST_LIMIT,
+        Math.max(1, readNumberParam(params, "limit", { integer: true }) ?? DEFAULT_LIST_LIMIT),
+      );
+
+      switch (action) {
+        case "list_issues": {
+          const query = new URLSearchParams({ state, per_page: String(limit) });
+          const labels = Array.isArray(params.labels)
+            ? params.labels.filter((label): label is string => typeof label === "string")
+            : [];
+          if (labels.length > 0) {
+            query.set("labels", labels.join(","));
+          }
+          const { data } = await request<GitHubIssue[]>({
+            path: `/repos/${repo}/issues?${query.toString()}`,
+          });
+          // The issues endpoint also returns pull requests; list_prs covers those.
+          const issues = data.filter((issue) => !issue.pull_request).map(summarizeIssue);
+          return jsonResult({ repo, state, count: issues.length, issues });
+        }
+        case "list_prs": {
+          const query = new URLSearchParams({ state, per_page: String(limit) });
+          const { data } = await request<GitHubIssue[]>({
+            path: `/repos/${repo}/pulls?${query.toString()}`,
+          });
+          const pullRequests = data.map(summarizeIssue);
+          return jsonResult({ repo, state, count: pullRequests.length, pullRequests });
+        }
+        case "view": {
+          const number = readIssueNumber(params);
+          const { data: issue } = await request<GitHubIssue>({
+            path: `/repos/${repo}/issues/${number}`,
+          });
+          const { data: comments } = await request<GitHubComment[]>({
+            path: `/repos/${repo}/issues/${number}/comments?per_page=${VIEW_COMMENT_LIMIT}`,
+          });
+          return jsonResult({
+            repo,
+            ...summarizeIssue(issue),
+            kind: issue.pull_request ? "pull_request" : "issue",
+            createdAt: issue.created_at,
+            body: wrapText(truncateText(issue.body ?? "", maxChars).text),
+            comments: comments.map((comment) => ({
+              author: comment.user?.login,
+              createdAt: comment.created_at,
+              body: wrapText(comment.body),
+            })),
+          });
+        }
+        case "pr_diff": {
+          const number = readIssueNumber(params);
+          const { text } = await request<unknown>({
+            path: `/repos/${repo}/pulls/${number}`,
+            accept: "application/vnd.github.diff",
+          });
+          const truncated = truncateText(text, maxChars);
+          return {
+            content: [{ type: "text", text: wrapText(truncated.text) || "(empty diff)" }],
+            details: { repo, number, length: text.length, truncated: truncated.truncated },
+          };
+        }
+        case "comment": {
+          const number = readIssueNumber(params);
+          const body = readStringParam(params, "body", { required: true });
+          const { data } = await request<GitHubComment>({
+            path: `/repos/${repo}/issues/${number}/comments`,
+            method: "POST",
+            body: { body },
+          });
+          return jsonResult({ repo, number, ok: true, url: data.html_url });
+        }
+        case "create_pr": {
+          const title = readStringParam(params, "title", { required: true });
+          const head = readStringParam(params, "head", { required: true });
+          let base = readStringParam(params, "base");
+          if (!base) {
+            const { data } = await request<{ default_branch?: string }>({
+              path: `/repos/${repo}`,
+            });
+            base = data.default_branch;
+          }
+          if (!base) {
+            throw new ToolInputError("base required (repository default branch unknown)");
+          }
+          const { data } = await request<GitHubIssue>({
+            path: `/repos/${repo}/pulls`,
+            method: "POST",
+            body: {
+              title,
+              head,
+              base,
+              body: readStringParam(params, "body", { allowEmpty: true }) ?? "",
+              draft: params.draft === true,
+            },
+          });
+          return jsonResult({ repo, ok: true, ...summarizeIssue(data) });
+        }
+      }
+      throw new ToolInputError(`Unknown action: ${String(action)}`);
+    },
+  };
+}
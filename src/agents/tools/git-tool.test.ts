@@ -0,0 +1,121 @@
+import { execFileSync } from "node:child_process";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import { createGitTool, parseGitBlame, parseGitStatus } from "./git-tool.js";
+
+let root = "";
+
+function gitSetup(...args: string[]) {
+  const identity = ["-c", "user.name=Test", "-c", "user.email=test@example.com"];
+  execFileSync("git", [...identity, "-c", "commit.gpgsign=false", ...args], {
+    cwd: root,
+    stdio: "ignore",
+  });
+}
+
+async function run(args: Record<string, unknown>) {
+  const result = await createGitTool({ root }).execute("call", args);
+  return {
+    text: (result.content[0] as { text: string }).text,
+    details: result.details as Record<string, unknown>,
+  };
+}
+
+beforeEach(async () => {
+  root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-git-tool-"));
+  gitSetup("init", "-q", "-b", "main");
+  await fs.writeFile(path.join(root, "a.txt"), "one\ntwo\n");
+  gitSetup("add", "a.txt");
+  gitSetup("commit", "-q", "-m", "Add a");
+});
+
+afterEach(async () => {
+  await fs.rm(root, { recursive: true, force: true });
+});
+
+describe("parseGitStatus", () => {
+  it("parses branch tracking, renames, and untracked files", () => {
+    const status = parseGitStatus(
+      [
+        "## main...origin/main [ahead 2, behind 1]",
+        "M  staged.ts",
+        " M edited.ts",
+        "R  new.ts",
+        "old.ts",
+        "UU conflict.ts",
+        "?? notes.md",
+        "",
+      ].join("\0"),
+    );
+    expect(status).toMatchObject({ branch: "main", upstream: "origin/main", ahead: 2, behind: 1 });
+    expect(status.staged.map((entry) => entry.path)).toEqual(["staged.ts", "new.ts"]);
+    expect(status.staged[1]?.origPath).toBe("old.ts");
+    expect(status.unstaged.map((entry) => entry.path)).toEqual(["edited.ts"]);
+    expect(status.conflicted.map((entry) => entry.path)).toEqual(["conflict.ts"]);
+    expect(status.untracked).toEqual(["notes.md"]);
+  });
+});
+
+describe("parseGitBlame", () => {
+  it("reuses commit metadata for repeated hunks", () => {
+    const sha = "a".repeat(40);
+    const lines = parseGitBlame(
+      [
+        `${sha} 1 1 2`,
+        "author Ada",
+        "author-time 1700000000",
+        "\tfirst",
+        `${sha} 2 2`,
+        "\tsecond",
+      ].join("\n"),
+    );
+    expect(lines).toEqual([
+      { line: 1, commit: "aaaaaaaa", author: "Ada", date: "2023-11-14", text: "first" },
+      { line: 2, commit: "aaaaaaaa", author: "Ada", date: "2023-11-14", text: "second" },
+    ]);
+  });
+});
+
+describe("git tool", () => {
+  it("reports status and diffs for working tree changes", async () => {
+    await fs.writeFile(path.join(root, "a.txt"), "one\nTWO\n");
+    await fs.writeFile(path.join(root, "b.txt"), "new\n");
+
+    const status = await run({ action: "status" });
+    expect(status.text).toContain("On branch main (no upstream)");
+    expect(status.details.status).toMatchObject({
+      unstaged: [{ path: "a.txt", index: " ", worktree: "M" }],
+      untracked: ["b.txt"],
+    });
+
+    const diff = await run({ action: "diff", path: "a.txt" });
+    expect(diff.text).toContain("-two\n+TWO");
+  });
+
+  it("does not run filter drivers from the repository config", async () => {
+    const marker = path.join(root, "filter-ran");
+    gitSetup("config", "filter.evil.clean", `touch '${marker}'; cat`);
+    gitSetup("config", "filter.evil.required", "true");
+    await fs.writeFile(path.join(root, ".gitattributes"), "*.txt filter=evil\n");
+    await fs.writeFile(path.join(root, "a.txt"), "one\nTWO\n");
+
+    await run({ action: "status" });
+    const diff = await run({ action: "diff", path: "a.txt" });
+    expect(diff.text).toContain("-two\n+TWO");
+    await expect(fs.access(marker)).rejects.toThrow();
+  });
+
+  it("returns structured log and blame output", async () => {
+    const log = await run({ action: "log" });
+    expect(log.details.commits).toMatchObject([{ author: "Test", subject: "Add a" }]);
+
+    const blame = await run({ action: "blame", path: "a.txt", startLine: 2 });
+    expect(blame.text).toMatch(/^2 [0-9a-f]{8} \(Test \d{4}-\d{2}-\d{2}\) two$/);
+  });
+
+  it("rejects option-like refs", async () => {
+    await expect(run({ action: "show", ref: "--output=/tmp/x" })).rejects.toThrow(/must not start/);
+  });
+});
@@ -0,0 +1,435 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import { Type } from "@sinclair/typebox";
+import path from "node:path";
+import type { AnyAgentTool } from "./common.js";
+import { runCommandWithTimeout } from "../../process/exec.js";
+import { listRepoFilterDrivers, safeGitConfig } from "../git-safe-config.js";
+import { stringEnum } from "../schema/typebox.js";
+import { readNumberParam, readStringParam, ToolInputError } from "./common.js";
+import { resolveWorkspaceToolPath } from "./fs-walk.js";
+
+const GIT_ACTIONS = ["status", "diff", "log", "show", "blame", "branches"] as const;
+type GitAction = (typeof GIT_ACTIONS)[number];
+
+const GIT_TIMEOUT_MS = 30_000;
+const MAX_OUTPUT_CHARS = 30_000;
+const DEFAULT_LOG_LIMIT = 20;
+const MAX_LOG_LIMIT = 200;
+const FIELD = "\x1f";
+const RECORD = "\x1e";
+
+// Read-only invocations must not run repository-configured programs (hooks, fsmonitor, filter
+// and external diff drivers, pagers) or take locks, so the tool is safe to run without approval.
+
+const GIT_ENV: NodeJS.ProcessEnv = {
+  GIT_OPTIONAL_LOCKS: "0",
+  GIT_TERMINAL_PROMPT: "0",
+  GIT_PAGER: "cat",
+  GIT_LITERAL_PATHSPECS: "1",
+  GIT_CONFIG_NOSYSTEM: "1",
+  LC_ALL: "C",
+};
+
+const GitToolSchema = Type.Object({
+  action: stringEnum(GIT_ACTIONS, {
+    description: "Read-only git operation: status, diff, log, show, blame, or branches.",
+  }),
+  repo: Type.Optional(
+    Type.String({ description: "Repository directory (default: workspace root)." }),
+  ),
+  path: Type.Optional(
+    Type.String({ description: "Limit to this file or directory (required for blame)." }),
+  ),
+  ref: Type.Optional(
+    Type.String({
+      description:
+        'Revision: diff base or range ("HEAD~3", "main...HEAD"), log start, show target ("HEAD", "abc123", "HEAD:src/a.ts"), or blame revision.',
+    }),
+  ),
+  staged: Type.Optional(Type.Boolean({ description: "diff: show staged changes." })),
+  stat: Type.Optional(Type.Boolean({ description: "diff/show: summary only (--stat)." })),
+  limit: Type.Optional(
+    Type.Number({ description: `log: max commits (default ${DEFAULT_LOG_LIMIT}).`, minimum: 1 }),
+  ),
+  startLine: Type.Optional(
+    Type.Number({ description: "blame: first line (1-based).", minimum: 1 }),
+  ),
+  endLine: Type.Optional(Type.Number({ description: "blame: last line (inclusive).", minimum: 1 })),
+  remote: Type.Optional(Type.Boolean({ description: "branches: include remote branches." })),
+});
+
+export type GitStatusEntry = {
+  path: string;
+  origPath?: string;
+  index: string;
+  worktree: string;
+};
+
+export type GitStatus = {
+  branch?: string;
+  upstream?: string;
+  ahead: number;
+  behind: number;
+  staged: GitStatusEntry[];
+  unstaged: GitStatusEntry[];
+  untracked: string[];
+  conflicted: GitStatusEntry[];
+};
+
+export type GitLogEntry = {
+  hash: string;
+  shortHash: string;
+  author: string;
+  email: string;
+  date: string;
+  subject: string;
+};
+
+export type GitBlameLine = {
+  line: number;
+  commit: string;
+  author: string;
+  date: string;
+  text: string;
+};
+
+export type GitBranch = {
+  name: string;
+  commit: string;
+  upstream?: string;
+  current: boolean;
+  date: string;
+  subject: string;
+};
+
+/** Parse `git status --porcelain=v1 --branch -z` output. */
+export function parseGitStatus(output: string): GitStatus {
+  const status: GitStatus = {
+    ahead: 0,
+    behind: 0,
+    staged: [],
+    unstaged: [],
+    untracked: [],
+    conflicted: [],
+  };
+  const parts = output.split("\0");
+  for (let i = 0; i < parts.length; i += 1) {
+    const entry = parts[i];
+    if (!entry) {
+      continue;
+    }
+    if (entry.startsWith("## ")) {
+      const header = entry.slice(3);
+      const counts = header.match(/\[(.*)\]$/)?.[1] ?? "";
+      status.ahead = Number(counts.match(/ahead (\d+)/)?.[1] ?? 0);
+      status.behind = Number(counts.match(/behind (\d+)/)?.[1] ?? 0);
+      const names = header.replace(/\s*\[.*\]$/, "");
+      const noCommits = names.match(/^No commits yet on (.+)$/);
+      const [branch, upstream] = (noCommits?.[1] ?? names).split("...");
+      status.branch = branch === "HEAD (no branch)" ? undefined : branch;
+      status.upstream = upstream || undefined;
+      continue;
+    }
+    const index = entry[0] ?? " ";
+    const worktree = entry[1] ?? " ";
+    const file = entry.slice(3);
+    if (index === "?" && worktree === "?") {
+      status.untracked.push(file);
+      continue;
+    }
+    if (index === "!" && worktree === "!") {
+      continue;
+    }
+    const item: GitStatusEntry = { path: file, index, worktree };
+    if (index === "R" || index === "C") {
+      // Renames and copies carry the original path as the next NUL-separated field.
+      item.origPath = parts[i + 1];
+      i += 1;
+    }
+    if (index === "U" || worktree === "U" || (index === "A" && worktree === "A")) {
+      status.conflicted.push(item);
+      continue;
+    }
+    if (index !== " ") {
+      status.staged.push(item);
+    }
+    if (worktree !== " ") {
+      status.unstaged.push(item);
+    }
+  }
+  return status;
+}
+
+export function parseGitLog(output: string): GitLogEntry[] {
+  return output
+    .split(RECORD)
+    .map((record) => record.trim())
+    .filter(Boolean)
+    .map((record) => {
+      const [hash = "", shortHash = "", author = "", email = "", date = "", subject = ""] =
+        record.split(FIELD);
+      return { hash, shortHash, author, email, date, subject };
+    });
+}
+
+/** Parse `git blame --porcelain` output. */
+export function parseGitBlame(output: string): GitBlameLine[] {
+  const commits = new Map<string, { author: string; date: string }>();
+  const lines: GitBlameLine[] = [];
+  let current: { commit: string; line: number } | null = null;
+  for (const raw of output.split("\n")) {
+    if (raw.startsWith("\t")) {
+      if (current) {
+        const info = commits.get(current.commit);
+        lines.push({
+          line: current.line,
+          commit: current.commit.slice(0, 8),
+          author: info?.author ?? "",
+          date: info?.date ?? "",
+          text: raw.slice(1),
+        });
+      }
+      current = null;
+      continue;
+    }
+    const header = raw.match(/^([0-9a-f]{40}) \d+ (\d+)/);
+    if (header) {
+      current = { commit: header[1], line: Number(header[2]) };
+      if (!commits.has(header[1])) {
+        commits.set(header[1], { author: "", date: "" });
+      }
+      continue;
+    }
+    if (!current) {
+      continue;
+    }
+    const info = commits.get(current.commit);
+    if (!info) {
+      continue;
+    }
+    if (raw.startsWith("author ")) {
+      info.author = raw.slice("author ".length);
+    } else if (raw.startsWith("author-time ")) {
+      const seconds = Number(raw.slice("author-time ".length));
+      info.date = Number.isFinite(seconds)
+        ? new Date(seconds * 1000).toISOString().slice(0, 10)
+        : "";
+    }
+  }
+  return lines;
+}
+
+export function parseGitBranches(output: string): GitBranch[] {
+  return output
+    .split("\n")
+    .filter((line) => line.includes(FIELD))
+    .map((line) => {
+      const [name = "", commit = "", upstream = "", head = "", date = "", subject = ""] =
+        line.split(FIELD);
+      return {
+        name,
+        commit,
+        upstream: upstream || undefined,
+        current: head === "*",
+        date: date.slice(0, 10),
+        subject,
+      };
+    })
+    .filter((branch) => !branch.name.endsWith("/HEAD"));
+}
+
+function readRef(params: Record<string, unknown>, options?: { required?: boolean }) {
+  const ref = readStringParam(params, "ref", { required: options?.required });
+  // Refs are passed as positional arguments; never let one be read as an option.
+  if (ref?.startsWith("-")) {
+    throw new ToolInputError("ref must not start with '-'");
+  }
+  return ref;
+}
+
+function truncateOutput(text: string): { text: string; truncated: boolean } {
+  if (text.length <= MAX_OUTPUT_CHARS) {
+    return { text, truncated: false };
+  }
+  const cut = text.slice(0, MAX_OUTPUT_CHARS);
+  const lastNewline = cut.lastIndexOf("\n");
+  return {
+    text: `${lastNewline > 0 ? cut.slice(0, lastNewline) : cut}\n\n[output truncated at ${MAX_OUTPUT_CHARS} chars; narrow with path, ref, or stat]`,
+    truncated: true,
+  };
+}
+
+function formatStatus(status: GitStatus): string {
+  const tracking = [
+    status.upstream ? `tracking ${status.upstream}` : "no upstream",
+    status.ahead ? `ahead ${status.ahead}` : "",
+    status.behind ? `behind ${status.behind}` : "",
+  ]
+    .filter(Boolean)
+    .join(", ");
+  const lines = [`On branch ${status.branch ?? "(detached HEAD)"} (${tracking})`];
+  const section = (title: string, entries: string[]) => {
+    if (entries.length > 0) {
+      lines.push("", `${title}:`, ...entries.map((entry) => `  ${entry}`));
+    }
+  };
+  const describe = (code: string, entry: GitStatusEntry) =>
+    `${code} ${entry.origPath ? `${entry.origPath} -> ${entry.path}` : entry.path}`;
+  section(
+    "Conflicted",
+    status.conflicted.map((entry) => describe(`${entry.index}${entry.worktree}`, entry)),
+  );
+  section("Staged", status.staged.map((entry) => describe(entry.index, entry)));
+  section("Unstaged", status.unstaged.map((entry) => describe(entry.worktree, entry)));
+  section("Untracked", status.untracked);
+  if (lines.length === 1) {
+    lines.push("", "Working tree clean.");
+  }
+  return lines.join("\n");
+}
+
+export function createGitTool(options: { root: string }): AnyAgentTool {
+  const root = options.root;
+  return {
+    label: "Git",
+    name: "git",
+    description:
+      "Inspect a git repository without running shell commands: status, diff, log, show, blame, and branches. Read-only; output is parsed and size-capped.",
+    parameters: GitToolSchema,
+    execute: async (_toolCallId, args): Promise<AgentToolResult<unknown>> => {
+      const params = args as Record<string, unknown>;
+      const action = readStringParam(params, "action", { required: true }) as GitAction;
+      if (!GIT_ACTIONS.includes(action)) {
+        throw new ToolInputError(`action must be one of ${GIT_ACTIONS.join(", ")}`);
+      }
+      const cwd = await resolveWorkspaceToolPath({ root, input: readStringParam(params, "repo") });
+      const pathInput = readStringParam(params, "path");
+      const pathspec = pathInput
+        ? path.relative(cwd, path.resolve(cwd, pathInput)).split(path.sep).join("/") || "."
+        : undefined;
+      const withPath = (argv: string[]) => (pathspec ? [...argv, "--", pathspec] : argv);
+
+      const config = safeGitConfig({ filterDrivers: await listRepoFilterDrivers(cwd) });
+      const git = async (argv: string[]) => {
+        const result = await runCommandWithTimeout(["git", ...config, ...argv], {
+          timeoutMs: GIT_TIMEOUT_MS,
+          cwd,
+          env: GIT_ENV,
+          // Piped (empty) stdin instead of the gateway's TTY.
+          input: "",
+        });
+        if (result.code !== 0) {
+          const detail = (result.stderr || result.stdout).trim().split("\n").slice(0, 5).join("\n");
+          throw new Error(`git ${argv[0]} failed${detail ? `: ${detail}` : ""}`);
+        }
+        return result.stdout;
+      };
+
+      const respond = (text: string, details: Record<string, unknown>) => {
+        const output = truncateOutput(text);
+        return {
+          content: [{ type: "text" as const, text: output.text || "(no output)" }],
+          details: { action, ...details, truncated: output.truncated },
+        };
+      };
+
+      switch (action) {
+        case "status": {
+          const status = parseGitStatus(
+            await git(withPath(["status", "--porcelain=v1", "--branch", "-z"])),
+          );
+          return respond(formatStatus(status), { status });
+        }
+        case "diff": {
+          const ref = readRef(params);
+          const argv = ["diff", "--no-ext-diff", "--no-textconv"];
+          if (params.staged === true) {
+            argv.push("--cached");
+          }
+          if (params.stat === true) {
+            argv.push("--stat");
+          }
+          if (ref) {
+            argv.push(ref);
+          }
+          return respond(await git(withPath(argv)), { ref, staged: params.staged === true });
+        }
+        case "log": {
+          const ref = readRef(params);
+          const limit = Math.min(
+            MAX_LOG_LIMIT,
+            readNumberParam(params, "limit", { integer: true }) ?? DEFAULT_LOG_LIMIT,
+          );
+          const format = ["%H", "%h", "%an", "%ae", "%as", "%s"].join("%x1f");
+          const argv = ["log", `-n${Math.max(1, limit)}`, `--format=${format}%x1e`];
+          if (ref) {
+            argv.push(ref);
+          }
+          const commits = parseGitLog(await git(withPath(argv)));
+          const text = commits
+            .map(
+              (commit) => `${commit.shortHash} ${commit.date} ${commit.author}: ${commit.subject}`,
+            )
+            .join("\n");
+          return respond(text, { commits });
+        }
+        case "show": {
+          const ref = readRef(params, { required: true });
+          const argv = ["show", "--no-ext-diff", "--no-textconv", "--format=fuller"];
+          if (params.stat === true) {
+            argv.push("--stat");
+          }
+          argv.push(ref);
+          return respond(await git(withPath(argv)), { ref });
+        }
+        case "blame": {
+          if (!pathspec) {
+            throw new ToolInputError("path required for blame");
+          }
+          const ref = readRef(params);
+          const start = readNumberParam(params, "startLine", { integer: true });
+          const end = readNumberParam(params, "endLine", { integer: true });
+          const argv = ["blame", "--porcelain"];
+          if (start || end) {
+            argv.push("-L", `${start ?? 1},${end ?? ""}`);
+          }
+          if (ref) {
+            argv.push(ref);
+          }
+          const lines = parseGitBlame(await git([...argv, "--", pathspec]));
+          const width = String(lines.at(-1)?.line ?? 0).length;
+          const text = lines
+            .map(
+              (line) =>
+                `${String(line.line).padStart(width)} ${line.commit} (${line.author} ${line.date}) ${line.text}`,
+            )
+            .join("\n");
+          return respond(text, { path: pathspec, lines: lines.length });
+        }
+        case "branches": {
+          const format = [
+            "%(refname:short)",
+            "%(objectname:short)",
+            "%(upstream:short)",
+            "%(HEAD)",
+            "%(committerdate:iso-strict)",
+            "%(subject)",
+          ].join("%1f");
+          const argv = ["branch", "--list", `--format=${format}`];
+          if (params.remote === true) {
+            argv.push("--all");
+          }
+          const branches = parseGitBranches(await git(argv));
+          const text = branches
+            .map(
+              (branch) =>
+                `${branch.current ? "*" : " "} ${branch.name} ${branch.commit}${branch.upstream ? ` [${branch.upstream}]` : ""} ${branch.date} ${branch.subject}`,
+            )
+            .join("\n");
+          return respond(text, { branches });
+        }
+      }
+      throw new ToolInputError(`Unknown action: ${String(action)}`);
+    },
+  };
+}
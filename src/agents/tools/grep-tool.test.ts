@@ -0,0 +1,129 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterAll, beforeAll, describe, expect, it, vi } from "vitest";
+import { createHostSandboxFsBridge } from "../test-helpers/host-sandbox-fs-bridge.js";
+import { createGrepTool } from "./grep-tool.js";
+
+let root = "";
+
+async function write(rel: string, content: string | Buffer) {
+  const target = path.join(root, rel);
+  await fs.mkdir(path.dirname(target), { recursive: true });
+  await fs.writeFile(target, content);
+}
+
+async function grep(args: Record<string, unknown>, opts?: { sandboxed?: boolean }) {
+  const tool = createGrepTool({
+    root,
+    sandbox: opts?.sandboxed ? { root, bridge: createHostSandboxFsBridge(root) } : undefined,
+  });
+  const result = await tool.execute("call", args);
+  const text = (result.content[0] as { text: string }).text;
+  return { text, details: result.details as { matches: number; truncated: boolean } };
+}
+
+beforeAll(async () => {
+  root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-grep-"));
+  await write(".gitignore", "dist/\n");
+  await write("src/app.ts", "const a = 1;\n// TODO: first\nconst b = 2;\nconst c = 3;\n");
+  await write("src/util.py", "# todo: python\n");
+  await write("dist/app.js", "// TODO: built\n");
+  await write("node_modules/dep/index.js", "// TODO: dep\n");
+  await write("bin/blob", Buffer.from([0x54, 0x4f, 0x44, 0x4f, 0x00, 0x01]));
+});
+
+afterAll(async () => {
+  await fs.rm(root, { recursive: true, force: true });
+});
+
+describe("grep tool", () => {
+  it("searches the workspace and respects .gitignore", async () => {
+    const { text, details } = await grep({ pattern: "TODO" });
+    expect(text).toBe("src/app.ts:2:// TODO: first");
+    expect(details.matches).toBe(1);
+  });
+
+  it("supports case-insensitive search with file type filters", async () => {
+    const { text } = await grep({ pattern: "todo", ignoreCase: true, type: "py" });
+    expect(text).toBe("src/util.py:1:# todo: python");
+  });
+
+  it("prints context lines", async () => {
+    const { text } = await grep({ pattern: "TODO", context: 1, glob: "*.ts" });
+    expect(text.split("\n")).toEqual([
+      "src/app.ts-1-const a = 1;",
+      "src/app.ts:2:// TODO: first",
+      "src/app.ts-3-const b = 2;",
+    ]);
+  });
+
+  it("accepts -A/-B context aliases", async () => {
+    const { text } = await grep({ pattern: "TODO", "-B": 0, "-A": 2, glob: "*.ts" });
+    expect(text.split("\n")).toEqual([
+      "src/app.ts:2:// TODO: first",
+      "src/app.ts-3-const b = 2;",
+      "src/app.ts-4-const c = 3;",
+    ]);
+  });
+
+  it("lists files or counts instead of lines", async () => {
+    const files = await grep({
+      pattern: "todo",
+      ignoreCase: true,
+      outputMode: "files_with_matches",
+    });
+    expect(files.text.split("\n")).toEqual(["src/app.ts", "src/util.py"]);
+    const counts = await grep({ pattern: "const", output_mode: "count" });
+    expect(counts.text).toBe("src/app.ts:3");
+  });
+
+  it("matches across lines in multiline mode", async () => {
+    const { text, details } = await grep({ pattern: "first\\nconst b", multiline: true });
+    expect(text.split("\n")).toEqual([
+      "src/app.ts:2:// TODO: first",
+      "src/app.ts:3:const b = 2;",
+    ]);
+    expect(details.matches).toBe(1);
+  });
+
+  it("stops at the match limit", async () => {
+    const { text, details } = await grep({ pattern: "const", limit: 2 });
+    expect(details).toMatchObject({ matches: 2, truncated: true });
+    expect(text).toContain("Stopped after 2 matches");
+  });
+
+  it("keeps sandboxed searches inside the root", async () => {
+    await expect(grep({ pattern: "x", path: "../" }, { sandboxed: true })).rejects.toThrow(
+      /escapes sandbox root/,
+    );
+  });
+
+  it("reads sandboxed files through the bridge", async () => {
+    const bridge = createHostSandboxFsBridge(root);
+    const readFile = vi.fn(bridge.readFile);
+    const tool = createGrepTool({ root, sandbox: { root, bridge: { ...bridge, readFile } } });
+    await tool.execute("call", { pattern: "python", path: "src" });
+    expect(readFile).toHaveBeenCalledWith(
+      expect.objectContaining({ filePath: path.join(root, "src", "util.py"), cwd: root }),
+    );
+  });
+
+  it("does not follow symlinks out of a sandboxed root", async () => {
+    const outside = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-grep-outside-"));
+    try {
+      await fs.writeFile(path.join(outside, "secret.txt"), "TOKEN=hunter2\n");
+      await fs.symlink(path.join(outside, "secret.txt"), path.join(root, "leak.txt"));
+      await fs.symlink(outside, path.join(root, "leakdir"));
+      const { text } = await grep({ pattern: "hunter2" }, { sandboxed: true });
+      expect(text).toBe("No matches found.");
+      await expect(grep({ pattern: "x", path: "leak.txt" }, { sandboxed: true })).rejects.toThrow(
+        /Symlink not allowed/,
+      );
+    } finally {
+      await fs.rm(path.join(root, "leak.txt"), { force: true });
+      await fs.rm(path.join(root, "leakdir"), { force: true });
+      await fs.rm(outside, { recursive: true, force: true });
+    }
+  });
+});
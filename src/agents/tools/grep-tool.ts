@@ -0,0 +1,350 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import { Type } from "@sinclair/typebox";
+import path from "node:path";
+import type { AnyAgentTool } from "./common.js";
+import { optionalStringEnum } from "../schema/typebox.js";
+import { readNumberParam, readStringParam, ToolInputError } from "./common.js";
+import {
+  createGlobMatcher,
+  formatWorkspacePath,
+  readWorkspaceToolFile,
+  resolveWorkspaceToolPath,
+  statWorkspaceToolPath,
+  walkTree,
+  type WorkspaceToolSandbox,
+} from "./fs-walk.js";
+
+const DEFAULT_MATCH_LIMIT = 100;
+const MAX_MATCH_LIMIT = 2000;
+const MAX_CONTEXT_LINES = 20;
+const MAX_LINE_CHARS = 500;
+const MAX_FILE_BYTES = 10 * 1024 * 1024;
+const BINARY_SNIFF_BYTES = 8000;
+const MAX_OUTPUT_CHARS = 20_000;
+
+const FILE_TYPES: Record<string, string[]> = {
+  c: ["c", "h"],
+  cpp: ["cc", "cpp", "cxx", "h", "hh", "hpp", "hxx"],
+  cs: ["cs"],
+  css: ["css", "scss", "sass", "less"],
+  go: ["go"],
+  html: ["htm", "html"],
+  java: ["java"],
+  js: ["js", "jsx", "mjs", "cjs"],
+  json: ["json", "jsonc", "json5"],
+  kotlin: ["kt", "kts"],
+  md: ["md", "mdx", "markdown"],
+  php: ["php"],
+  py: ["py", "pyi"],
+  rb: ["rb"],
+  rust: ["rs"],
+  sh: ["sh", "bash", "zsh"],
+  swift: ["swift"],
+  toml: ["toml"],
+  ts: ["ts", "tsx", "mts", "cts"],
+  yaml: ["yaml", "yml"],
+};
+
+const GREP_OUTPUT_MODES = ["content", "files_with_matches", "count"] as const;
+type GrepOutputMode = (typeof GREP_OUTPUT_MODES)[number];
+
+const GrepToolSchema = Type.Object({
+  pattern: Type.String({ description: "Regular expression (JavaScript syntax) to search for." }),
+  path: Type.Optional(
+    Type.String({ description: "File or directory to search (default: workspace root)." }),
+  ),
+  glob: Type.Optional(
+    Type.String({ description: 'Only search files matching this glob (e.g. "*.ts", "src/**").' }),
+  ),
+  type: Type.Optional(
+    Type.String({
+      description: `Only search files of this type: ${Object.keys(FILE_TYPES).join(", ")}.`,
+    }),
+  ),
+  outputMode: optionalStringEnum(GREP_OUTPUT_MODES, {
+    description:
+      "content (matching lines, default), files_with_matches (paths only), or count (matches per file).",
+  }),
+  ignoreCase: Type.Optional(Type.Boolean({ description: "Case-insensitive match (-i)." })),
+  literal: Type.Optional(Type.Boolean({ description: "Treat pattern as a literal string." })),
+  multiline: Type.Optional(
+    Type.Boolean({ description: "Let patterns span lines; `.` also matches newlines." }),
+  ),
+  before: Type.Optional(
+    Type.Number({ description: "Lines of context before each match (-B).", minimum: 0 }),
+  ),
+  after: Type.Optional(
+    Type.Number({ description: "Lines of context after each match (-A).", minimum: 0 }),
+  ),
+  context: Type.Optional(
+    Type.Number({ description: "Lines of context before and after each match (-C).", minimum: 0 }),
+  ),
+  limit: Type.Optional(
+    Type.Number({
+      description: `Maximum results: matches in content mode, files otherwise (default ${DEFAULT_MATCH_LIMIT}).`,
+      minimum: 1,
+    }),
+  ),
+});
+
+export type GrepToolDetails = {
+  matches: number;
+  files: number;
+  truncated: boolean;
+};
+
+// Claude Code / ripgrep-style names accepted alongside the schema's camelCase params.
+const GREP_PARAM_ALIASES: Record<string, string> = {
+  "-A": "after",
+  "-B": "before",
+  "-C": "context",
+  "-i": "ignoreCase",
+  output_mode: "outputMode",
+  head_limit: "limit",
+};
+
+function normalizeGrepParams(args: unknown): Record<string, unknown> {
+  const params = { ...(args as Record<string, unknown>) };
+  for (const [alias, key] of Object.entries(GREP_PARAM_ALIASES)) {
+    if (alias in params && !(key in params)) {
+      params[key] = params[alias];
+    }
+  }
+  return params;
+}
+
+function isBinary(buffer: Buffer) {
+  return buffer.subarray(0, BINARY_SNIFF_BYTES).includes(0);
+}
+
+function clampLine(line: string) {
+  return line.length > MAX_LINE_CHARS ? `${line.slice(0, MAX_LINE_CHARS)}…` : line;
+}
+
+function readContextParam(params: Record<string, unknown>, key: string, fallback: number) {
+  const value = readNumberParam(params, key, { integer: true });
+  return Math.min(MAX_CONTEXT_LINES, Math.max(0, value ?? fallback));
+}
+
+/** Returns the (sorted) indexes of lines touched by a match, plus the number of matches. */
+function findMatchLines(text: string, regex: RegExp, multiline: boolean) {
+  const lines = text.split(/\r?\n/);
+  if (!multiline) {
+    const matchLines = lines.flatMap((line, index) => (regex.test(line) ? [index] : []));
+    return { lines, matchLines, count: matchLines.length };
+  }
+  const touched = new Set<number>();
+  let count = 0;
+  let line = 0;
+  let offset = 0;
+  for (const match of text.matchAll(regex)) {
+    const start = match.index ?? 0;
+    for (; offset < start; offset += 1) {
+      if (text[offset] === "\n") {
+        line += 1;
+      }
+    }
+    const body = match[0].replace(/\r?\n$/, "");
+    const span = body.split("\n").length - 1;
+    for (let i = line; i <= line + span; i += 1) {
+      touched.add(i);
+    }
+    count += 1;
+  }
+  return { lines, matchLines: [...touched].toSorted((a, b) => a - b), count };
+}
+
+function renderContent(params: {
+  display: string;
+  lines: string[];
+  matchLines: number[];
+  before: number;
+  after: number;
+  output: string[];
+}) {
+  const { display, lines, matchLines, before, after, output } = params;
+  const isMatch = new Set(matchLines);
+  const withContext = before > 0 || after > 0;
+  let lastPrinted = -1;
+  for (const index of matchLines) {
+    if (index <= lastPrinted) {
+      continue;
+    }
+    const start = Math.max(0, index - before, lastPrinted + 1);
+    if (withContext && lastPrinted >= 0 && start > lastPrinted + 1) {
+      output.push("--");
+    }
+    for (let j = start; j < index; j += 1) {
+      output.push(`${display}-${j + 1}-${clampLine(lines[j])}`);
+    }
+    output.push(`${display}:${index + 1}:${clampLine(lines[index])}`);
+    lastPrinted = index;
+    // Trailing context stops at the next match so it is printed as a match line.
+    const end = Math.min(lines.length - 1, index + after);
+    for (let j = index + 1; j <= end && !isMatch.has(j); j += 1) {
+      output.push(`${display}-${j + 1}-${clampLine(lines[j])}`);
+      lastPrinted = j;
+    }
+  }
+  if (withContext) {
+    output.push("--");
+  }
+}
+
+function capOutput(output: string[]): { text: string; capped: boolean } {
+  let length = 0;
+  for (let i = 0; i < output.length; i += 1) {
+    length += output[i].length + 1;
+    if (length > MAX_OUTPUT_CHARS) {
+      return { text: output.slice(0, i).join("\n"), capped: true };
+    }
+  }
+  return { text: output.join("\n"), capped: false };
+}
+
+export function createGrepTool(opts: {
+  root: string;
+  sandbox?: WorkspaceToolSandbox;
+}): AnyAgentTool {
+  return {
+    label: "grep",
+    name: "grep",
+    description:
+      "Search file contents with a regular expression. Respects .gitignore and skips .git, node_modules, and binary files. Content output lines are path:line:text; context lines use path-line-text. Use outputMode=files_with_matches or count to survey before pulling matching lines.",
+    parameters: GrepToolSchema,
+    execute: async (
+      _toolCallId,
+      args,
+      signal?: AbortSignal,
+    ): Promise<AgentToolResult<GrepToolDetails>> => {
+      const params = normalizeGrepParams(args);
+      const pattern = readStringParam(params, "pattern", { required: true, trim: false });
+      const multiline = params.multiline === true;
+      const flags = `${params.ignoreCase === true ? "i" : ""}${multiline ? "gms" : ""}`;
+      let regex: RegExp;
+      try {
+        regex = new RegExp(
+          params.literal === true ? pattern.replace(/[.*+?^${}()|[\]\\]/g, "\\$&") : pattern,
+          flags,
+        );
+      } catch (err) {
+        throw new ToolInputError(`Invalid regex: ${String(err)}`);
+      }
+      const modeParam = readStringParam(params, "outputMode");
+      if (modeParam && !GREP_OUTPUT_MODES.includes(modeParam as GrepOutputMode)) {
+        throw new ToolInputError(`outputMode must be one of: ${GREP_OUTPUT_MODES.join(", ")}.`);
+      }
+      const outputMode = (modeParam ?? "content") as GrepOutputMode;
+      const typeName = readStringParam(params, "type")?.toLowerCase();
+      const extensions = typeName ? FILE_TYPES[typeName] : undefined;
+      if (typeName && !extensions) {
+        throw new ToolInputError(
+          `Unknown file type "${typeName}". Known types: ${Object.keys(FILE_TYPES).join(", ")}.`,
+        );
+      }
+      const glob = readStringParam(params, "glob");
+      const matchesGlob = glob ? createGlobMatcher([glob]) : undefined;
+      const context = readContextParam(params, "context", 0);
+      const before = readContextParam(params, "before", context);
+      const after = readContextParam(params, "after", context);
+      const limit = Math.min(
+        MAX_MATCH_LIMIT,
+        Math.max(1, readNumberParam(params, "limit", { integer: true }) ?? DEFAULT_MATCH_LIMIT),
+      );
+
+      const target = await resolveWorkspaceToolPath({
+        root: opts.root,
+        input: readStringParam(params, "path"),
+        sandbox: opts.sandbox,
+      });
+      const stat = await statWorkspaceToolPath(target, opts.sandbox);
+      if (!stat) {
+        throw new ToolInputError(`Path not found: ${readStringParam(params, "path") ?? target}`);
+      }
+
+      const files: string[] = [];
+      if (stat.type === "directory") {
+        for await (const entry of walkTree({
+          root: target,
+          skipSymlinks: Boolean(opts.sandbox),
+          signal,
+        })) {
+          if (!entry.dirent.isFile()) {
+            continue;
+          }
+          if (matchesGlob && !matchesGlob(entry.relPath)) {
+            continue;
+          }
+          if (extensions && !extensions.includes(path.extname(entry.relPath).slice(1))) {
+            continue;
+          }
+          files.push(entry.path);
+        }
+      } else {
+        files.push(target);
+      }
+
+      const output: string[] = [];
+      let matchCount = 0;
+      let fileCount = 0;
+      let truncated = false;
+      for (const file of files) {
+        if (signal?.aborted) {
+          break;
+        }
+        const buffer = await readWorkspaceToolFile(file, opts.sandbox, signal).catch(() => null);
+        if (!buffer || buffer.length > MAX_FILE_BYTES || isBinary(buffer)) {
+          continue;
+        }
+        const found = findMatchLines(buffer.toString("utf8"), regex, multiline);
+        if (found.count === 0) {
+          continue;
+        }
+        const resultsSoFar = outputMode === "content" ? matchCount : fileCount;
+        if (resultsSoFar >= limit) {
+          truncated = true;
+          break;
+        }
+        const display = formatWorkspacePath(file, opts.root);
+        fileCount += 1;
+        if (outputMode === "files_with_matches") {
+          matchCount += found.count;
+          output.push(display);
+          continue;
+        }
+        if (outputMode === "count") {
+          matchCount += found.count;
+          output.push(`${display}:${found.count}`);
+          continue;
+        }
+        const remaining = limit - matchCount;
+        const matchLines = found.matchLines.slice(0, remaining);
+        if (found.matchLines.length > remaining) {
+          truncated = true;
+        }
+        matchCount += matchLines.length;
+        renderContent({ display, lines: found.lines, matchLines, before, after, output });
+        if (truncated) {
+          break;
+        }
+      }
+      if (output.at(-1) === "--") {
+        output.pop();
+      }
+
+      const details: GrepToolDetails = { matches: matchCount, files: fileCount, truncated };
+      if (output.length === 0) {
+        return { content: [{ type: "text", text: "No matches found." }], details };
+      }
+      const capped = capOutput(output);
+      let text = capped.text;
+      if (capped.capped) {
+        details.truncated = true;
+        text += `\n\n[Output truncated at ${MAX_OUTPUT_CHARS} chars; narrow the search or use outputMode=files_with_matches.]`;
+      } else if (truncated) {
+        text += `\n\n[Stopped after ${limit} ${outputMode === "content" ? "matches" : "files"}; narrow the pattern/path or raise limit.]`;
+      }
+      return { content: [{ type: "text", text }], details };
+    },
+  };
+}
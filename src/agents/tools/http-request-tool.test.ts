@@ -0,0 +1,167 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import * as ssrf from "../../infra/net/ssrf.js";
+import { createHttpRequestTool, resolveCredentialHeaders } from "./http-request-tool.js";
+
+const lookupMock = vi.fn();
+const resolvePinnedHostnameWithPolicy = ssrf.resolvePinnedHostnameWithPolicy;
+
+function makeHeaders(map: Record<string, string>): { get: (key: string) => string | null } {
+  return {
+    get: (key) => map[key.toLowerCase()] ?? null,
+  };
+}
+
+type HttpConfig = NonNullable<NonNullable<NonNullable<OpenClawConfig["tools"]>["web"]>["http"]>;
+
+function createTool(http: HttpConfig = {}) {
+  return createHttpRequestTool({
+    config: { tools: { web: { http: { enabled: true, ...http } } } },
+  });
+}
+
+describe("http_request tool", () => {
+  const priorFetch = global.fetch;
+
+  beforeEach(() => {
+    lookupMock.mockResolvedValue([{ address: "93.184.216.34", family: 4 }]);
+    vi.spyOn(ssrf, "resolvePinnedHostnameWithPolicy").mockImplementation((hostname, params) =>
+      resolvePinnedHostnameWithPolicy(hostname, { ...params, lookupFn: lookupMock }),
+    );
+  });
+
+  afterEach(() => {
+    // @ts-expect-error restore
+    global.fetch = priorFetch;
+    lookupMock.mockReset();
+    vi.restoreAllMocks();
+  });
+
+  it("is disabled unless configured", () => {
+    expect(createHttpRequestTool({ config: {} })).toBeNull();
+    expect(createTool()?.name).toBe("http_request");
+  });
+
+  it("sends JSON bodies with injected credentials that override model headers", async () => {
+    const fetchSpy = vi.fn().mockResolvedValue({
+      ok: true,
+      status: 201,
+      headers: makeHeaders({ "content-type": "application/json", location: "/issues/7" }),
+      text: async () => '{"number":7}',
+    } as Response);
+    // @ts-expect-error mock fetch
+    global.fetch = fetchSpy;
+
+    const tool = createTool({
+      credentials: { "api.example.com": { Authorization: "Bearer configured" } },
+    });
+    const result = await tool?.execute?.("call", {
+      url: "https://api.example.com/issues",
+      method: "post",
+      headers: { authorization: "Bearer model", "X-Trace": "1" },
+      json: { title: "Bug" },
+    });
+
+    const init = fetchSpy.mock.calls[0]?.[1] as RequestInit;
+    expect(init.method).toBe("POST");
+    expect(init.body).toBe('{"title":"Bug"}');
+    expect(init.headers).toMatchObject({
+      Authorization: "Bearer configured",
+      "Content-Type": "application/json",
+      "X-Trace": "1",
+    });
+    expect(init.headers).not.toHaveProperty("authorization");
+    expect(result?.details).toMatchObject({
+      status: 201,
+      ok: true,
+      credentialsApplied: true,
+      headers: { location: "/issues/7" },
+    });
+    expect((result?.details as { body: string }).body).toContain('"number": 7');
+    expect(JSON.stringify(result?.details)).not.toContain("Bearer configured");
+  });
+
+  it("keeps credentials off plain http and strips terminal escapes", async () => {
+    const fetchSpy = vi.fn().mockResolvedValue({
+      ok: true,
+      status: 200,
+      headers: makeHeaders({ "content-type": "text/plain" }),
+      text: async () => "ok\u001b]0;pwned\u0007\u001b[2J done",
+    } as Response);
+    // @ts-expect-error mock fetch
+    global.fetch = fetchSpy;
+    const tool = createTool({
+      allowPrivateNetwork: true,
+      credentials: {
+        "api.example.com": { Authorization: "Bearer configured" },
+        localhost: { "X-Key": "local" },
+      },
+    });
+
+    await expect(tool?.execute?.("call", { url: "http://api.example.com/" })).rejects.toThrow(
+      /only sent over https/,
+    );
+    expect(fetchSpy).not.toHaveBeenCalled();
+
+    const result = await tool?.execute?.("call", { url: "http://localhost:8080/health" });
+    expect(result?.details).toMatchObject({ credentialsApplied: true });
+    const body = (result?.details as { body: string }).body;
+    expect(body).toContain("ok done");
+    expect(body).not.toContain("\u001b");
+  });
+
+  it("does not follow redirects for requests with a body", async () => {
+    const fetchSpy = vi.fn().mockResolvedValue({
+      ok: false,
+      status: 302,
+      headers: makeHeaders({ location: "https://other.example.com/" }),
+      body: { cancel: vi.fn() },
+    } as unknown as Response);
+    // @ts-expect-error mock fetch
+    global.fetch = fetchSpy;
+
+    await expect(
+      createTool()?.execute?.("call", {
+        url: "https://api.example.com/submit",
+        method: "POST",
+        form: { a: "1" },
+      }),
+    ).rejects.toThrow(/redirects are not followed/);
+    expect(fetchSpy).toHaveBeenCalledTimes(1);
+  });
+
+  it("applies the domain policy and rejects ambiguous bodies", async () => {
+    const fetchSpy = vi.fn();
+    // @ts-expect-error mock fetch
+    global.fetch = fetchSpy;
+    const tool = createTool({ urlDenylist: ["*.corp.example.com"] });
+
+    await expect(
+      tool?.execute?.("call", { url: "https://git.corp.example.com/api" }),
+    ).rejects.toThrow(/denylisted/);
+    await expect(tool?.execute?.("call", { url: "http://169.254.169.254/latest" })).rejects.toThrow(
+      /private|internal/i,
+    );
+    await expect(
+      tool?.execute?.("call", {
+        url: "https://api.example.com/",
+        method: "PUT",
+        json: { a: 1 },
+        body: "x",
+      }),
+    ).rejects.toThrow(/only one of json, form, or body/);
+    expect(fetchSpy).not.toHaveBeenCalled();
+  });
+
+  it("matches credential hosts exactly or by wildcard subdomain", () => {
+    const credentials = {
+      "api.example.com": { Authorization: "a" },
+      "*.internal.test": { "X-Key": "b" },
+    };
+    expect(resolveCredentialHeaders("API.example.com", credentials)).toEqual({
+      Authorization: "a",
+    });
+    expect(resolveCredentialHeaders("svc.internal.test", credentials)).toEqual({ "X-Key": "b" });
+    expect(resolveCredentialHeaders("evil-api.example.com", credentials)).toEqual({});
+  });
+});
@@ -0,0 +1,321 @@
+import { Type } from "@sinclair/typebox";
+import type { OpenClawConfig } from "../../config/config.js";
+import type { AnyAgentTool } from "./common.js";
+import { isLoopbackHost } from "../../gateway/net.js";
+import { fetchWithSsrFGuard } from "../../infra/net/fetch-guard.js";
+import { isHostnameAllowedByPattern, type SsrFPolicy } from "../../infra/net/ssrf.js";
+import { wrapExternalContent } from "../../security/external-content.js";
+import { sanitizeTerminalText } from "../../terminal/ansi.js";
+import { optionalStringEnum } from "../schema/typebox.js";
+import { jsonResult, readNumberParam, readStringParam, ToolInputError } from "./common.js";
+import { truncateText } from "./web-fetch-utils.js";
+import { readResponseTextCapped, resolveTimeoutSeconds } from "./web-shared.js";
+
+const HTTP_METHODS = ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD"] as const;
+type HttpMethod = (typeof HTTP_METHODS)[number];
+
+const DEFAULT_MAX_CHARS = 20_000;
+const DEFAULT_MAX_REDIRECTS = 3;
+const DEFAULT_TIMEOUT_SECONDS = 30;
+const DEFAULT_USER_AGENT = "openclaw-http-request";
+
+// Headers the caller may not set: the runtime owns framing and hop-by-hop behaviour.
+const FORBIDDEN_HEADERS = new Set([
+  "connection",
+  "content-length",
+  "host",
+  "keep-alive",
+  "proxy-authorization",
+  "te",
+  "trailer",
+  "transfer-encoding",
+  "upgrade",
+]);
+
+const RESPONSE_HEADERS = [
+  "content-type",
+  "content-length",
+  "location",
+  "etag",
+  "last-modified",
+  "retry-after",
+  "link",
+  "x-ratelimit-limit",
+  "x-ratelimit-remaining",
+  "x-ratelimit-reset",
+];
+
+const HttpRequestSchema = Type.Object({
+  url: Type.String({ description: "HTTP or HTTPS URL to request." }),
+  method: optionalStringEnum(HTTP_METHODS, { description: "HTTP method (default: GET)." }),
+  headers: Type.Optional(
+    Type.Object(
+      {},
+      {
+        additionalProperties: true,
+        description: "Request headers as a name → value object.",
+      },
+    ),
+  ),
+  json: Type.Optional(
+    Type.Object(
+      {},
+      {
+        additionalProperties: true,
+        description: "JSON request body (sets Content-Type: application/json).",
+      },
+    ),
+  ),
+  form: Type.Optional(
+    Type.Object(
+      {},
+      {
+        additionalProperties: true,
+        description: "Form fields sent as application/x-www-form-urlencoded.",
+      },
+    ),
+  ),
+  body: Type.Optional(
+    Type.String({ description: "Raw request body; set Content-Type in headers when needed." }),
+  ),
+  maxChars: Type.Optional(
+    Type.Number({
+      description: "Maximum response body characters to return (truncates when exceeded).",
+      minimum: 100,
+    }),
+  ),
+});
+
+type HttpRequestConfig = NonNullable<
+  NonNullable<NonNullable<OpenClawConfig["tools"]>["web"]>["http"]
+>;
+
+function resolveHttpConfig(cfg?: OpenClawConfig): HttpRequestConfig | undefined {
+  const http = cfg?.tools?.web?.http;
+  if (!http || typeof http !== "object") {
+    return undefined;
+  }
+  return http;
+}
+
+function readHeaderRecord(value: unknown, label: string): Record<string, string> {
+  if (value === undefined || value === null) {
+    return {};
+  }
+  if (typeof value !== "object" || Array.isArray(value)) {
+    throw new ToolInputError(`${label} must be an object of strings`);
+  }
+  const out: Record<string, string> = {};
+  for (const [key, raw] of Object.entries(value as Record<string, unknown>)) {
+    const name = key.trim();
+    if (!name || raw === undefined || raw === null) {
+      continue;
+    }
+    if (typeof raw === "object") {
+      throw new ToolInputError(`${label}.${name} must be a string`);
+    }
+    out[name] = String(raw);
+  }
+  return out;
+}
+
+/** Headers from `tools.web.http.credentials` whose host pattern matches the request host. */
+export function resolveCredentialHeaders(
+  hostname: string,
+  credentials?: Record<string, Record<string, string>>,
+): Record<string, string> {
+  const host = hostname.trim().toLowerCase().replace(/\.$/, "");
+  const out: Record<string, string> = {};
+  for (const [pattern, headers] of Object.entries(credentials ?? {})) {
+    const normalized = pattern.trim().toLowerCase().replace(/\.$/, "");
+    if (normalized && isHostnameAllowedByPattern(host, normalized)) {
+      Object.assign(out, headers);
+    }
+  }
+  return out;
+}
+
+function buildBody(params: Record<string, unknown>): {
+  body?: string;
+  contentType?: string;
+} {
+  const provided = ["json", "form", "body"].filter((key) => params[key] !== undefined);
+  if (provided.length > 1) {
+    throw new ToolInputError("Provide only one of json, form, or body");
+  }
+  if (params.json !== undefined) {
+    // Models sometimes pass the JSON already serialized; send it as-is instead of re-quoting it.
+    const body = typeof params.json === "string" ? params.json : JSON.stringify(params.json);
+    return { body, contentType: "application/json" };
+  }
+  if (params.form !== undefined) {
+    const fields = readHeaderRecord(params.form, "form");
+    return {
+      body: new URLSearchParams(fields).toString(),
+      contentType: "application/x-www-form-urlencoded",
+    };
+  }
+  if (params.body !== undefined) {
+    if (typeof params.body !== "string") {
+      throw new ToolInputError("body must be a string (use json for objects)");
+    }
+    return { body: params.body };
+  }
+  return {};
+}
+
+function mergeHeaders(...sources: Array<Record<string, string>>): Record<string, string> {
+  // Case-insensitive merge: later sources win, keeping the last spelling of each name.
+  const byName = new Map<string, [string, string]>();
+  for (const source of sources) {
+    for (const [name, value] of Object.entries(source)) {
+      byName.set(name.toLowerCase(), [name, value]);
+    }
+  }
+  return Object.fromEntries(byName.values());
+}
+
+function formatBody(text: string, contentType: string | null): string {
+  if (!contentType?.toLowerCase().includes("json")) {
+    return text;
+  }
+  try {
+    return JSON.stringify(JSON.parse(text), null, 2);
+  } catch {
+    return text;
+  }
+}
+
+export function createHttpRequestTool(options?: {
+  config?: OpenClawConfig;
+  sandboxed?: boolean;
+}): AnyAgentTool | null {
+  const http = resolveHttpConfig(options?.config);
+  if (http?.enabled !== true) {
+    return null;
+  }
+  const policy: SsrFPolicy = {
+    allowPrivateNetwork: http.allowPrivateNetwork === true,
+    hostnameAllowlist: http.urlAllowlist,
+    hostnameDenylist: http.urlDenylist,
+  };
+  const timeoutSeconds = resolveTimeoutSeconds(http.timeoutSeconds, DEFAULT_TIMEOUT_SECONDS);
+  const maxRedirects =
+    typeof http.maxRedirects === "number" && Number.isFinite(http.maxRedirects)
+      ? Math.max(0, Math.floor(http.maxRedirects))
+      : DEFAULT_MAX_REDIRECTS;
+  return {
+    label: "HTTP Request",
+    name: "http_request",
+    description:
+      "Send an HTTP request (GET/POST/PUT/PATCH/DELETE/HEAD) with custom headers and a JSON, form, or raw body; returns status, key headers, and the response body. Credentials for known APIs are added automatically from config.",
+    parameters: HttpRequestSchema,
+    execute: async (_toolCallId, args) => {
+      const params = args as Record<string, unknown>;
+      const url = readStringParam(params, "url", { required: true });
+      const methodRaw = (readStringParam(params, "method") ?? "GET").toUpperCase();
+      if (!HTTP_METHODS.includes(methodRaw as HttpMethod)) {
+        throw new ToolInputError(`method must be one of ${HTTP_METHODS.join(", ")}`);
+      }
+      const method = methodRaw as HttpMethod;
+      let parsedUrl: URL;
+      try {
+        parsedUrl = new URL(url);
+      } catch {
+        throw new ToolInputError("Invalid URL: must be http or https");
+      }
+      if (!["http:", "https:"].includes(parsedUrl.protocol)) {
+        throw new ToolInputError("Invalid URL: must be http or https");
+      }
+
+      const requested = readHeaderRecord(params.headers, "headers");
+      const blocked = Object.keys(requested).filter((name) =>
+        FORBIDDEN_HEADERS.has(name.toLowerCase()),
+      );
+      if (blocked.length > 0) {
+        throw new ToolInputError(`headers not allowed: ${blocked.join(", ")}`);
+      }
+      const { body, contentType } = buildBody(params);
+      if (body !== undefined && (method === "GET" || method === "HEAD")) {
+        throw new ToolInputError(`${method} requests cannot have a body`);
+      }
+      const credentials = resolveCredentialHeaders(parsedUrl.hostname, http.credentials);
+      const injected = Object.keys(credentials).length > 0;
+      if (injected && parsedUrl.protocol !== "https:" && !isLoopbackHost(parsedUrl.hostname)) {
+        throw new ToolInputError(
+          `Configured credentials for ${parsedUrl.hostname} are only sent over https; use an https URL.`,
+        );
+      }
+      const headers = mergeHeaders(
+        { "User-Agent": http.userAgent ?? DEFAULT_USER_AGENT, Accept: "*/*" },
+        contentType ? { "Content-Type": contentType } : {},
+        requested,
+        // Configured credentials win so the model cannot swap in its own token for this host.
+        credentials,
+      );
+
+      // Redirects replay the same headers and body, so only follow them for plain reads that
+      // carry no injected credentials.
+      const followRedirects = !injected && (method === "GET" || method === "HEAD");
+      const start = Date.now();
+      const result = await fetchWithSsrFGuard({
+        url: parsedUrl.toString(),
+        maxRedirects: followRedirects ? maxRedirects : 0,
+        timeoutMs: timeoutSeconds * 1000,
+        policy,
+        auditContext: "http_request",
+        init: { method, headers, ...(body !== undefined ? { body } : {}) },
+      }).catch((err: unknown) => {
+        if (!followRedirects && err instanceof Error && /Too many redirects/.test(err.message)) {
+          throw new Error(
+            `${method} ${parsedUrl.origin}${parsedUrl.pathname} returned a redirect; redirects are not followed for requests with a body or credentials. Request the target URL directly.`,
+            { cause: err },
+          );
+        }
+        throw err;
+      });
+      try {
+        const res = result.response;
+        const maxChars = Math.max(
+          100,
+          readNumberParam(params, "maxChars", { integer: true }) ??
+            http.maxChars ??
+            DEFAULT_MAX_CHARS,
+        );
+        // A character is at most 4 UTF-8 bytes, so this never cuts below maxChars of text.
+        const { text, truncated: cut } =
+          method === "HEAD"
+            ? { text: "", truncated: false }
+            : await readResponseTextCapped(res, maxChars * 4);
+        const truncated = truncateText(
+          sanitizeTerminalText(formatBody(text, res.headers.get("content-type"))),
+          maxChars,
+        );
+        const responseHeaders: Record<string, string> = {};
+        for (const name of RESPONSE_HEADERS) {
+          const value = res.headers.get(name);
+          if (value) {
+            responseHeaders[name] = value;
+          }
+        }
+        return jsonResult({
+          url,
+          finalUrl: result.finalUrl,
+          method,
+          status: res.status,
+          ok: res.ok,
+          headers: responseHeaders,
+          credentialsApplied: injected,
+          body: truncated.text
+            ? wrapExternalContent(truncated.text, { source: "api", includeWarning: false })
+            : "",
+          truncated: truncated.truncated || cut,
+          length: text.length,
+          tookMs: Date.now() - start,
+        });
+      } finally {
+        await result.release();
+      }
+    },
+  };
+}
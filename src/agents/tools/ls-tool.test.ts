@@ -0,0 +1,82 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterAll, beforeAll, describe, expect, it } from "vitest";
+import { createHostSandboxFsBridge } from "../test-helpers/host-sandbox-fs-bridge.js";
+import { createLsTool } from "./ls-tool.js";
+
+let root = "";
+
+async function write(rel: string, content = "x") {
+  const target = path.join(root, rel);
+  await fs.mkdir(path.dirname(target), { recursive: true });
+  await fs.writeFile(target, content);
+}
+
+async function ls(args: Record<string, unknown>, opts?: { sandboxed?: boolean }) {
+  const tool = createLsTool({
+    root,
+    sandbox: opts?.sandboxed ? { root, bridge: createHostSandboxFsBridge(root) } : undefined,
+  });
+  const result = await tool.execute("call", args);
+  const text = (result.content[0] as { text: string }).text;
+  return { text, details: result.details as { entries: number; truncated: boolean } };
+}
+
+beforeAll(async () => {
+  root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-ls-"));
+  await write(".gitignore", "dist/\n");
+  await write("README.md", "a".repeat(2048));
+  await write("src/a.ts");
+  await write("src/lib/b.ts");
+  await write("dist/out.js");
+  await write("node_modules/dep/index.js");
+});
+
+afterAll(async () => {
+  await fs.rm(root, { recursive: true, force: true });
+});
+
+describe("ls tool", () => {
+  it("lists direct children with sizes and entry counts", async () => {
+    const { text, details } = await ls({});
+    expect(text.split("\n")).toEqual([
+      "./",
+      ".gitignore  6 B",
+      "README.md  2.0 KB",
+      "src/  (2 entries)",
+    ]);
+    expect(details).toEqual({ entries: 3, truncated: false });
+  });
+
+  it("descends to the requested depth", async () => {
+    const { text } = await ls({ path: "src", depth: 2 });
+    expect(text.split("\n")).toEqual(["src/", "a.ts  1 B", "lib/  (1 entry)", "  b.ts  1 B"]);
+  });
+
+  it("includes ignored entries on request and stops at the limit", async () => {
+    const { text, details } = await ls({ includeIgnored: true, limit: 2 });
+    expect(text.split("\n").slice(1, 3)).toEqual([".gitignore  6 B", "README.md  2.0 KB"]);
+    expect(details.truncated).toBe(true);
+    expect(text).toContain("Stopped after 2 entries");
+  });
+
+  it("keeps sandboxed listings inside the root", async () => {
+    await expect(ls({ path: "../" }, { sandboxed: true })).rejects.toThrow(/escapes sandbox root/);
+  });
+
+  it("hides symlinks from sandboxed listings", async () => {
+    const outside = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-ls-outside-"));
+    try {
+      await fs.symlink(outside, path.join(root, "linked"));
+      expect((await ls({})).text).toContain(`linked -> ${outside}`);
+      expect((await ls({}, { sandboxed: true })).text).not.toContain("linked");
+      await expect(ls({ path: "linked" }, { sandboxed: true })).rejects.toThrow(
+        /Symlink not allowed/,
+      );
+    } finally {
+      await fs.rm(path.join(root, "linked"), { force: true });
+      await fs.rm(outside, { recursive: true, force: true });
+    }
+  });
+});
@@ -0,0 +1,128 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import { Type } from "@sinclair/typebox";
+import fs from "node:fs/promises";
+import type { AnyAgentTool } from "./common.js";
+import { readNumberParam, readStringParam, ToolInputError } from "./common.js";
+import {
+  formatSize,
+  formatWorkspacePath,
+  resolveWorkspaceToolPath,
+  statWorkspaceToolPath,
+  walkTree,
+  type WorkspaceToolSandbox,
+} from "./fs-walk.js";
+
+const DEFAULT_LS_LIMIT = 200;
+const MAX_LS_LIMIT = 1000;
+const MAX_LS_DEPTH = 5;
+
+const LsToolSchema = Type.Object({
+  path: Type.Optional(
+    Type.String({ description: "Directory to list (default: workspace root)." }),
+  ),
+  depth: Type.Optional(
+    Type.Number({
+      description: `Levels to descend (1 = direct children only, max ${MAX_LS_DEPTH}).`,
+      minimum: 1,
+    }),
+  ),
+  limit: Type.Optional(
+    Type.Number({
+      description: `Maximum entries to return (default ${DEFAULT_LS_LIMIT}).`,
+      minimum: 1,
+    }),
+  ),
+  includeIgnored: Type.Optional(
+    Type.Boolean({
+      description: "Also list .git, node_modules, and .gitignore'd entries (default false).",
+    }),
+  ),
+});
+
+export type LsToolDetails = {
+  entries: number;
+  truncated: boolean;
+};
+
+async function countEntries(dir: string) {
+  const entries = await fs.readdir(dir).catch(() => null);
+  return entries?.length;
+}
+
+export function createLsTool(opts: {
+  root: string;
+  sandbox?: WorkspaceToolSandbox;
+}): AnyAgentTool {
+  return {
+    label: "ls",
+    name: "ls",
+    description:
+      "List a directory with file sizes, types, and per-directory entry counts. Set depth > 1 to descend into subdirectories. Skips .git, node_modules, and .gitignore'd entries unless includeIgnored=true.",
+    parameters: LsToolSchema,
+    execute: async (
+      _toolCallId,
+      args,
+      signal?: AbortSignal,
+    ): Promise<AgentToolResult<LsToolDetails>> => {
+      const params = args as Record<string, unknown>;
+      const depth = Math.min(
+        MAX_LS_DEPTH,
+        Math.max(1, readNumberParam(params, "depth", { integer: true }) ?? 1),
+      );
+      const limit = Math.min(
+        MAX_LS_LIMIT,
+        Math.max(1, readNumberParam(params, "limit", { integer: true }) ?? DEFAULT_LS_LIMIT),
+      );
+      const includeIgnored = params.includeIgnored === true;
+      const target = await resolveWorkspaceToolPath({
+        root: opts.root,
+        input: readStringParam(params, "path"),
+        sandbox: opts.sandbox,
+      });
+      const stat = await statWorkspaceToolPath(target, opts.sandbox);
+      if (stat?.type !== "directory") {
+        throw new ToolInputError(`Not a directory: ${readStringParam(params, "path") ?? target}`);
+      }
+
+      const lines: string[] = [];
+      let truncated = false;
+      for await (const entry of walkTree({
+        root: target,
+        respectGitignore: !includeIgnored,
+        skipDirs: includeIgnored ? [] : undefined,
+        maxDepth: depth - 1,
+        skipSymlinks: Boolean(opts.sandbox),
+        signal,
+      })) {
+        if (lines.length >= limit) {
+          truncated = true;
+          break;
+        }
+        const indent = "  ".repeat(entry.depth);
+        const name = entry.dirent.name;
+        if (entry.dirent.isSymbolicLink()) {
+          const linkTarget = await fs.readlink(entry.path).catch(() => "?");
+          lines.push(`${indent}${name} -> ${linkTarget}`);
+        } else if (entry.dirent.isDirectory()) {
+          const count = await countEntries(entry.path);
+          const suffix =
+            count === undefined ? "" : `  (${count} ${count === 1 ? "entry" : "entries"})`;
+          lines.push(`${indent}${name}/${suffix}`);
+        } else if (entry.dirent.isFile()) {
+          const size = (await statWorkspaceToolPath(entry.path, opts.sandbox))?.size;
+          lines.push(`${indent}${name}${size === undefined ? "" : `  ${formatSize(size)}`}`);
+        } else {
+          lines.push(`${indent}${name}  (special)`);
+        }
+      }
+
+      const header = target === opts.root ? "./" : `${formatWorkspacePath(target, opts.root)}/`;
+      const details: LsToolDetails = { entries: lines.length, truncated };
+      let text = lines.length > 0 ? `${header}\n${lines.join("\n")}` : `${header} (empty)`;
+      if (truncated) {
+        text += `\n\n[Stopped after ${limit} entries; list a subdirectory or raise limit.]`;
+      }
+      return { content: [{ type: "text", text }], details };
+    },
+  };
+}
@@ -6,8 +6,18 @@ import type { AnyAgentTool } from "./common.js";
 import { resolveMemoryBackendConfig } from "../../memory/backend-config.js";
 import { getMemorySearchManager } from "../../memory/index.js";
 import { parseAgentSessionKey } from "../../routing/session-key.js";
-import { resolveSessionAgentId } from "../agent-scope.js";
+import { resolveAgentWorkspaceDir, resolveSessionAgentId } from "../agent-scope.js";
+import {
+  appendMemoryFact,
+  readMemoryFile,
+  removeMemoryFact,
+  resolveMemoryMaxChars,
+  resolveProjectMemoryDir,
+  resolveProjectMemoryRoot,
+} from "../memory-file.js";
 import { resolveMemorySearchConfig } from "../memory-search.js";
+import { resolveSandboxConfigForAgent, resolveSandboxRuntimeStatus } from "../sandbox.js";
+import { optionalStringEnum } from "../schema/typebox.js";
 import { jsonResult, readNumberParam, readStringParam } from "./common.js";
 
 const MemorySearchSchema = Type.Object({
@@ -22,6 +32,19 @@ const MemoryGetSchema = Type.Object({
   lines: Type.Optional(Type.Number()),
 });
 
+const MemoryReadSchema = Type.Object({});
+
+const MemoryWriteSchema = Type.Object({
+  fact: Type.String({ description: "One durable fact, e.g. 'User prefers tabs'." }),
+  action: optionalStringEnum(["add", "remove"] as const, {
+    description: "add (default) appends the fact; remove deletes a previously stored fact.",
+  }),
+  scope: optionalStringEnum(["project", "global"] as const, {
+    description:
+      "project (default when working in a project outside the workspace) or global (workspace MEMORY.md).",
+  }),
+});
+
 export function createMemorySearchTool(options: {
   config?: OpenClawConfig;
   agentSessionKey?: string;
@@ -134,6 +157,122 @@ export function createMemoryGetTool(options: {
   };
 }
 
+// MEMORY.md is private to direct chats (see AGENTS.md), so read/write stay out of group sessions.
+function resolveMemoryFileTarget(options: { config?: OpenClawConfig; agentSessionKey?: string }) {
+  const cfg = options.config;
+  if (!cfg || deriveChatTypeFromSessionKey(options.agentSessionKey) !== "direct") {
+    return null;
+  }
+  const agentId = resolveSessionAgentId({
+    sessionKey: options.agentSessionKey,
+    config: cfg,
+  });
+  const workspaceDir = resolveAgentWorkspaceDir(cfg, agentId);
+  return {
+    cfg,
+    agentId,
+    workspaceDir,
+    // Resolved per call: the project follows the exec cwd as the agent moves between repos.
+    resolveProjectMemoryDir: () => {
+      const root = resolveProjectMemoryRoot({
+        workspaceDir,
+        config: cfg,
+        sessionKey: options.agentSessionKey,
+        agentId,
+      });
+      return root ? { root, dir: resolveProjectMemoryDir(root) } : null;
+    },
+  };
+}
+
+export function createMemoryReadTool(options: {
+  config?: OpenClawConfig;
+  agentSessionKey?: string;
+}): AnyAgentTool | null {
+  const target = resolveMemoryFileTarget(options);
+  if (!target) {
+    return null;
+  }
+  return {
+    label: "Memory Read",
+    name: "memory_read",
+    description:
+      "Read the durable facts stored in MEMORY.md (written via memory_write), with current size and cap. Includes the current project's memory when the session is working in a project outside the workspace.",
+    parameters: MemoryReadSchema,
+    execute: async () => {
+      const maxChars = resolveMemoryMaxChars(target.cfg);
+      const file = await readMemoryFile(target.workspaceDir);
+      const project = target.resolveProjectMemoryDir();
+      const projectFile = project ? await readMemoryFile(project.dir) : null;
+      return jsonResult({
+        path: file.path,
+        text: file.text,
+        chars: file.text.length,
+        maxChars,
+        ...(project && projectFile
+          ? {
+              project: {
+                root: project.root,
+                path: projectFile.path,
+                text: projectFile.text,
+                chars: projectFile.text.length,
+                maxChars,
+              },
+            }
+          : {}),
+      });
+    },
+  };
+}
+
+export function createMemoryWriteTool(options: {
+  config?: OpenClawConfig;
+  agentSessionKey?: string;
+}): AnyAgentTool | null {
+  const target = resolveMemoryFileTarget(options);
+  if (!target) {
+    return null;
+  }
+  // Mirror the memory flush rule: sandboxed sessions only write when the workspace is mounted rw.
+  if (options.agentSessionKey) {
+    const runtime = resolveSandboxRuntimeStatus({
+      cfg: target.cfg,
+      sessionKey: options.agentSessionKey,
+    });
+    if (
+      runtime.sandboxed &&
+      resolveSandboxConfigForAgent(target.cfg, runtime.agentId).workspaceAccess !== "rw"
+    ) {
+      return null;
+    }
+  }
+  return {
+    label: "Memory Write",
+    name: "memory_write",
+    description:
+      "Persist one durable fact across sessions (user preferences, project conventions, build commands) as a bullet in MEMORY.md, or remove a stale one. Keep facts short and stable; do not store secrets or transient task state. While working in a project outside the workspace, facts go to that project's memory by default; use scope=global for facts about the user.",
+    parameters: MemoryWriteSchema,
+    execute: async (_toolCallId, params) => {
+      const fact = readStringParam(params, "fact", { required: true });
+      const action = readStringParam(params, "action") ?? "add";
+      const project =
+        readStringParam(params, "scope") === "global" ? null : target.resolveProjectMemoryDir();
+      const write = action === "remove" ? removeMemoryFact : appendMemoryFact;
+      const result = await write({
+        workspaceDir: project?.dir ?? target.workspaceDir,
+        fact,
+        maxChars: resolveMemoryMaxChars(target.cfg),
+      });
+      return jsonResult({
+        ok: true,
+        scope: project ? "project" : "global",
+        ...(project ? { projectRoot: project.root } : {}),
+        ...result,
+      });
+    },
+  };
+}
+
 function resolveMemoryCitationsMode(cfg: OpenClawConfig): MemoryCitationsMode {
   const mode = cfg.memory?.citations;
   if (mode === "on" || mode === "off" || mode === "auto") {
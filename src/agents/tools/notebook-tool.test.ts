@@ -0,0 +1,90 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import { createNotebookEditTool, createNotebookReadTool } from "./notebook-tool.js";
+
+let root = "";
+
+const NOTEBOOK = {
+  cells: [
+    { cell_type: "markdown", id: "intro", metadata: {}, source: ["# Title\n", "Notes"] },
+    {
+      cell_type: "code",
+      id: "load",
+      execution_count: 3,
+      metadata: {},
+      outputs: [{ output_type: "stream", name: "stdout", text: ["big output\n"] }],
+      source: ["import pandas as pd\n", "df = pd.read_csv('x.csv')"],
+    },
+  ],
+  metadata: { language_info: { name: "python" } },
+  nbformat: 4,
+  nbformat_minor: 5,
+};
+
+async function readNotebook() {
+  return JSON.parse(await fs.readFile(path.join(root, "nb.ipynb"), "utf8")) as typeof NOTEBOOK;
+}
+
+async function edit(args: Record<string, unknown>) {
+  const tool = createNotebookEditTool({ cwd: root });
+  const result = await tool.execute("call", { path: "nb.ipynb", ...args });
+  return (result.content[0] as { text: string }).text;
+}
+
+beforeEach(async () => {
+  root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-notebook-"));
+  await fs.writeFile(path.join(root, "nb.ipynb"), `${JSON.stringify(NOTEBOOK, null, 1)}\n`);
+});
+
+afterEach(async () => {
+  await fs.rm(root, { recursive: true, force: true });
+});
+
+describe("notebook tools", () => {
+  it("reads cells with indices and without outputs", async () => {
+    const tool = createNotebookReadTool({ cwd: root });
+    const result = await tool.execute("call", { path: "nb.ipynb" });
+    const text = (result.content[0] as { text: string }).text;
+    expect(text).toBe(
+      [
+        "nb.ipynb (python, 2 cells)",
+        "[0] markdown\n# Title\nNotes",
+        "[1] code (execution_count 3, 1 output hidden)\n" +
+          "import pandas as pd\ndf = pd.read_csv('x.csv')",
+      ].join("\n\n"),
+    );
+    expect(text).not.toContain("big output");
+  });
+
+  it("replaces a code cell and clears its outputs", async () => {
+    await edit({ index: 1, source: "import polars as pl\n" });
+    const raw = await fs.readFile(path.join(root, "nb.ipynb"), "utf8");
+    expect(raw.startsWith('{\n "cells"')).toBe(true);
+    const cell = (await readNotebook()).cells[1];
+    expect(cell).toMatchObject({
+      id: "load",
+      source: ["import polars as pl\n"],
+      outputs: [],
+      execution_count: null,
+    });
+  });
+
+  it("inserts and deletes cells", async () => {
+    expect(await edit({ action: "insert", index: 2, cellType: "markdown", source: "a\nb" })).toBe(
+      "Inserted markdown cell at index 2. nb.ipynb now has 3 cells.",
+    );
+    let cells = (await readNotebook()).cells;
+    expect(cells[2]).toMatchObject({ cell_type: "markdown", source: ["a\n", "b"] });
+    expect(cells[2].id).toMatch(/^[0-9a-f]{8}$/);
+
+    await edit({ action: "delete", index: 0 });
+    cells = (await readNotebook()).cells;
+    expect(cells.map((cell) => cell.id)).toEqual(["load", expect.any(String)]);
+  });
+
+  it("rejects out-of-range indices", async () => {
+    await expect(edit({ index: 2, source: "x" })).rejects.toThrow(/out of range/);
+  });
+});
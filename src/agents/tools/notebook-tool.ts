@@ -0,0 +1,250 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import { Type } from "@sinclair/typebox";
+import crypto from "node:crypto";
+import fs from "node:fs/promises";
+import type { SandboxFsBridge } from "../sandbox/fs-bridge.js";
+import type { AnyAgentTool } from "./common.js";
+import { resolveToCwd } from "../sandbox-paths.js";
+import { optionalStringEnum } from "../schema/typebox.js";
+import { readNumberParam, readStringParam, ToolInputError } from "./common.js";
+
+const NOTEBOOK_CELL_TYPES = ["code", "markdown", "raw"] as const;
+const NOTEBOOK_EDIT_ACTIONS = ["replace", "insert", "delete"] as const;
+
+type NotebookCellType = (typeof NOTEBOOK_CELL_TYPES)[number];
+
+type NotebookCell = {
+  cell_type: string;
+  source?: string | string[];
+  id?: string;
+  metadata?: Record<string, unknown>;
+  outputs?: unknown[];
+  execution_count?: number | null;
+  [key: string]: unknown;
+};
+
+type Notebook = {
+  cells: NotebookCell[];
+  metadata?: {
+    kernelspec?: { language?: string; display_name?: string };
+    language_info?: { name?: string };
+    [key: string]: unknown;
+  };
+  nbformat?: number;
+  nbformat_minor?: number;
+  [key: string]: unknown;
+};
+
+type NotebookToolOptions = {
+  cwd: string;
+  sandbox?: { root: string; bridge: SandboxFsBridge };
+};
+
+const NotebookReadSchema = Type.Object({
+  path: Type.String({ description: "Path to the .ipynb file." }),
+});
+
+const NotebookEditSchema = Type.Object({
+  path: Type.String({ description: "Path to the .ipynb file." }),
+  action: optionalStringEnum(NOTEBOOK_EDIT_ACTIONS, {
+    description: "replace (default) the cell at index, insert a new cell before it, or delete it.",
+  }),
+  index: Type.Number({
+    description: "0-based cell index as shown by notebook_read (insert accepts the cell count).",
+    minimum: 0,
+  }),
+  source: Type.Optional(
+    Type.String({ description: "New cell source (required for replace and insert)." }),
+  ),
+  cellType: optionalStringEnum(NOTEBOOK_CELL_TYPES, {
+    description: "Cell type for insert (default code) or to convert a replaced cell.",
+  }),
+});
+
+export type NotebookReadToolDetails = {
+  cells: number;
+  language?: string;
+};
+
+export type NotebookEditToolDetails = {
+  action: (typeof NOTEBOOK_EDIT_ACTIONS)[number];
+  index: number;
+  cells: number;
+};
+
+function resolveNotebookFileOps(options: NotebookToolOptions) {
+  if (options.sandbox) {
+    const { root, bridge } = options.sandbox;
+    return {
+      readFile: async (filePath: string) =>
+        (await bridge.readFile({ filePath, cwd: root })).toString("utf8"),
+      writeFile: (filePath: string, content: string) =>
+        bridge.writeFile({ filePath, cwd: root, data: content }),
+    };
+  }
+  return {
+    readFile: (filePath: string) => fs.readFile(resolveToCwd(filePath, options.cwd), "utf8"),
+    writeFile: (filePath: string, content: string) =>
+      fs.writeFile(resolveToCwd(filePath, options.cwd), content, "utf8"),
+  };
+}
+
+function parseNotebook(raw: string, filePath: string): Notebook {
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(raw);
+  } catch {
+    throw new ToolInputError(`Not a Jupyter notebook (invalid JSON): ${filePath}`);
+  }
+  if (!parsed || typeof parsed !== "object" || !Array.isArray((parsed as Notebook).cells)) {
+    throw new ToolInputError(`Not a Jupyter notebook (missing cells): ${filePath}`);
+  }
+  return parsed as Notebook;
+}
+
+function cellSource(cell: NotebookCell): string {
+  return Array.isArray(cell.source) ? cell.source.join("") : (cell.source ?? "");
+}
+
+/** Jupyter stores source as a list of lines, each keeping its trailing newline. */
+function toSourceLines(source: string): string[] {
+  return source ? source.split(/(?<=\n)/) : [];
+}
+
+function notebookLanguage(notebook: Notebook): string | undefined {
+  return notebook.metadata?.language_info?.name ?? notebook.metadata?.kernelspec?.language;
+}
+
+/** Keep the file's existing indentation (Jupyter writes 1 space) so diffs stay small. */
+function serializeNotebook(notebook: Notebook, raw: string): string {
+  const indent = raw.match(/^\{\r?\n( +)"/)?.[1].length ?? 1;
+  return `${JSON.stringify(notebook, null, indent)}\n`;
+}
+
+function formatCellHeader(cell: NotebookCell, index: number): string {
+  const parts: string[] = [];
+  if (cell.cell_type === "code") {
+    if (typeof cell.execution_count === "number") {
+      parts.push(`execution_count ${cell.execution_count}`);
+    }
+    const outputs = Array.isArray(cell.outputs) ? cell.outputs.length : 0;
+    if (outputs > 0) {
+      parts.push(`${outputs} output${outputs === 1 ? "" : "s"} hidden`);
+    }
+  }
+  return `[${index}] ${cell.cell_type}${parts.length > 0 ? ` (${parts.join(", ")})` : ""}`;
+}
+
+function createCell(notebook: Notebook, cellType: NotebookCellType, source: string) {
+  const cell: NotebookCell = { cell_type: cellType, metadata: {}, source: toSourceLines(source) };
+  if (cellType === "code") {
+    cell.execution_count = null;
+    cell.outputs = [];
+  }
+  // Cell ids are required from nbformat 4.5 on.
+  if ((notebook.nbformat ?? 4) > 4 || (notebook.nbformat_minor ?? 0) >= 5) {
+    cell.id = crypto.randomUUID().replace(/-/g, "").slice(0, 8);
+  }
+  return cell;
+}
+
+export function createNotebookReadTool(options: NotebookToolOptions): AnyAgentTool {
+  const fileOps = resolveNotebookFileOps(options);
+  return {
+    label: "notebook_read",
+    name: "notebook_read",
+    description:
+      "Read a Jupyter notebook (.ipynb) as numbered cells with their type and source. Outputs are omitted. Use the cell indices with notebook_edit.",
+    parameters: NotebookReadSchema,
+    execute: async (_toolCallId, args): Promise<AgentToolResult<NotebookReadToolDetails>> => {
+      const params = args as Record<string, unknown>;
+      const filePath = readStringParam(params, "path", { required: true });
+      const notebook = parseNotebook(await fileOps.readFile(filePath), filePath);
+      const language = notebookLanguage(notebook);
+
+      const count = notebook.cells.length;
+      const summary = [language, `${count} cell${count === 1 ? "" : "s"}`].filter(Boolean);
+      const blocks = notebook.cells.map(
+        (cell, index) => `${formatCellHeader(cell, index)}\n${cellSource(cell)}`,
+      );
+      const text = [`${filePath} (${summary.join(", ")})`, ...blocks].join("\n\n");
+      return { content: [{ type: "text", text }], details: { cells: count, language } };
+    },
+  };
+}
+
+export function createNotebookEditTool(options: NotebookToolOptions): AnyAgentTool {
+  const fileOps = resolveNotebookFileOps(options);
+  return {
+    label: "notebook_edit",
+    name: "notebook_edit",
+    description:
+      "Edit a Jupyter notebook (.ipynb) by cell index: replace a cell's source, insert a new cell, or delete a cell. Replacing a code cell clears its outputs.",
+    parameters: NotebookEditSchema,
+    execute: async (_toolCallId, args): Promise<AgentToolResult<NotebookEditToolDetails>> => {
+      const params = args as Record<string, unknown>;
+      const filePath = readStringParam(params, "path", { required: true });
+      const action = readStringParam(params, "action") ?? "replace";
+      if (!(NOTEBOOK_EDIT_ACTIONS as readonly string[]).includes(action)) {
+        throw new ToolInputError(`action must be one of: ${NOTEBOOK_EDIT_ACTIONS.join(", ")}`);
+      }
+      const index = readNumberParam(params, "index", { required: true, integer: true })!;
+      const cellType = readStringParam(params, "cellType") as NotebookCellType | undefined;
+      if (cellType && !NOTEBOOK_CELL_TYPES.includes(cellType)) {
+        throw new ToolInputError(`cellType must be one of: ${NOTEBOOK_CELL_TYPES.join(", ")}`);
+      }
+      const source = readStringParam(params, "source", { trim: false, allowEmpty: true });
+      if (action !== "delete" && source === undefined) {
+        throw new ToolInputError(`source required for ${action}`);
+      }
+
+      const raw = await fileOps.readFile(filePath);
+      const notebook = parseNotebook(raw, filePath);
+      const cells = notebook.cells;
+      const maxIndex = action === "insert" ? cells.length : cells.length - 1;
+      if (index < 0 || index > maxIndex) {
+        throw new ToolInputError(
+          `Cell index ${index} out of range (notebook has ${cells.length} cells)`,
+        );
+      }
+
+      let text: string;
+      if (action === "insert") {
+        const cell = createCell(notebook, cellType ?? "code", source ?? "");
+        cells.splice(index, 0, cell);
+        text = `Inserted ${cell.cell_type} cell at index ${index}.`;
+      } else if (action === "delete") {
+        const [removed] = cells.splice(index, 1);
+        text = `Deleted ${removed.cell_type} cell ${index}.`;
+      } else {
+        const previous = cells[index];
+        if (cellType && cellType !== previous.cell_type) {
+          const cell = createCell(notebook, cellType, source ?? "");
+          cell.metadata = previous.metadata ?? {};
+          if (previous.id) {
+            cell.id = previous.id;
+          }
+          cells[index] = cell;
+        } else {
+          previous.source = toSourceLines(source ?? "");
+          if (previous.cell_type === "code") {
+            previous.outputs = [];
+            previous.execution_count = null;
+          }
+        }
+        text = `Replaced cell ${index} (${cells[index].cell_type}).`;
+      }
+
+      await fileOps.writeFile(filePath, serializeNotebook(notebook, raw));
+      const details: NotebookEditToolDetails = {
+        action: action as NotebookEditToolDetails["action"],
+        index,
+        cells: cells.length,
+      };
+      return {
+        content: [{ type: "text", text: `${text} ${filePath} now has ${cells.length} cells.` }],
+        details,
+      };
+    },
+  };
+}
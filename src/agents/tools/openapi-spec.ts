@@ -0,0 +1,155 @@
+import fs from "node:fs";
+import YAML from "yaml";
+import type { OpenClawConfig } from "../../config/config.js";
+import { fetchWithSsrFGuard } from "../../infra/net/fetch-guard.js";
+import { createSubsystemLogger } from "../../logging/subsystem.js";
+import { isPlainObject, resolveUserPath } from "../../utils.js";
+import { readResponseTextCapped } from "./web-shared.js";
+
+const log = createSubsystemLogger("agents/tools");
+
+const SPEC_FETCH_TIMEOUT_MS = 30_000;
+const REMOTE_SPEC_TTL_MS = 60 * 60_000;
+const MAX_REF_DEPTH = 12;
+const MAX_SPEC_BYTES = 10 * 1024 * 1024;
+
+export type JsonObject = Record<string, unknown>;
+
+type CachedSpec = { doc: JsonObject; loadedAt: number; mtimeMs?: number };
+
+const specCache = new Map<string, CachedSpec>();
+const pendingRemoteLoads = new Map<string, Promise<void>>();
+
+function isRemoteSpec(spec: string): boolean {
+  return /^https?:\/\//i.test(spec.trim());
+}
+
+export function parseOpenApiDocument(text: string): JsonObject {
+  const trimmed = text.trim();
+  const parsed: unknown = trimmed.startsWith("{") ? JSON.parse(trimmed) : YAML.parse(trimmed);
+  if (!isPlainObject(parsed) || !isPlainObject(parsed.paths)) {
+    throw new Error("not an OpenAPI document (missing paths)");
+  }
+  if (typeof parsed.openapi !== "string" || !parsed.openapi.startsWith("3.")) {
+    throw new Error("only OpenAPI 3.x documents are supported");
+  }
+  return parsed;
+}
+
+function loadLocalSpec(spec: string): JsonObject | null {
+  const filePath = resolveUserPath(spec);
+  try {
+    const stat = fs.statSync(filePath);
+    const cached = specCache.get(filePath);
+    if (cached && cached.mtimeMs === stat.mtimeMs) {
+      return cached.doc;
+    }
+    const doc = parseOpenApiDocument(fs.readFileSync(filePath, "utf8"));
+    specCache.set(filePath, { doc, loadedAt: Date.now(), mtimeMs: stat.mtimeMs });
+    return doc;
+  } catch (err) {
+    log.warn(`openapi spec skipped: ${filePath} (${String(err)})`);
+    return null;
+  }
+}
+
+async function loadRemoteSpec(url: string): Promise<void> {
+  const host = new URL(url).hostname;
+  const result = await fetchWithSsrFGuard({
+    url,
+    timeoutMs: SPEC_FETCH_TIMEOUT_MS,
+    // Explicitly configured spec hosts may live on a private network.
+    policy: { allowedHostnames: [host] },
+    auditContext: "openapi-spec",
+  });
+  try {
+    if (!result.response.ok) {
+      throw new Error(`HTTP ${result.response.status}`);
+    }
+    const { text, truncated } = await readResponseTextCapped(result.response, MAX_SPEC_BYTES);
+    if (truncated) {
+      throw new Error(`spec is larger than ${MAX_SPEC_BYTES} bytes`);
+    }
+    const doc = parseOpenApiDocument(text);
+    specCache.set(url, { doc, loadedAt: Date.now() });
+  } finally {
+    await result.release();
+  }
+}
+
+function ensureRemoteSpec(url: string): Promise<void> {
+  const pending = pendingRemoteLoads.get(url);
+  if (pending) {
+    return pending;
+  }
+  const load = loadRemoteSpec(url)
+    .catch((err: unknown) => {
+      log.warn(`openapi spec fetch failed: ${url} (${String(err)})`);
+    })
+    .finally(() => {
+      pendingRemoteLoads.delete(url);
+    });
+  pendingRemoteLoads.set(url, load);
+  return load;
+}
+
+/** Fetches remote specs ahead of the first agent run (called at gateway startup). */
+export async function preloadOpenApiSpecs(config?: OpenClawConfig): Promise<void> {
+  const remote = (config?.tools?.openapi ?? [])
+    .map((source) => source.spec.trim())
+    .filter(isRemoteSpec);
+  await Promise.all(remote.map((url) => ensureRemoteSpec(url)));
+}
+
+/** The parsed spec for a `tools.openapi` source; remote specs are null until first loaded. */
+export function resolveSpec(spec: string): JsonObject | null {
+  if (!isRemoteSpec(spec)) {
+    return loadLocalSpec(spec);
+  }
+  const url = spec.trim();
+  const cached = specCache.get(url);
+  if (!cached) {
+    // Remote specs load in the background; their tools appear from the next run on.
+    void ensureRemoteSpec(url);
+    return null;
+  }
+  if (Date.now() - cached.loadedAt > REMOTE_SPEC_TTL_MS) {
+    void ensureRemoteSpec(url);
+  }
+  return cached.doc;
+}
+
+function lookupRef(doc: JsonObject, ref: string): unknown {
+  if (!ref.startsWith("#/")) {
+    return undefined;
+  }
+  let node: unknown = doc;
+  for (const raw of ref.slice(2).split("/")) {
+    const key = raw.replace(/~1/g, "/").replace(/~0/g, "~");
+    node = isPlainObject(node) ? node[key] : undefined;
+  }
+  return node;
+}
+
+/** Inlines local `$ref`s; cycles and external refs become an unconstrained schema. */
+export function resolveRefs(node: unknown, doc: JsonObject, stack: string[] = []): unknown {
+  if (Array.isArray(node)) {
+    return node.map((item) => resolveRefs(item, doc, stack));
+  }
+  if (!isPlainObject(node)) {
+    return node;
+  }
+  if (typeof node.$ref === "string") {
+    const ref = node.$ref;
+    const target = lookupRef(doc, ref);
+    if (target === undefined || stack.includes(ref) || stack.length >= MAX_REF_DEPTH) {
+      return {};
+    }
+    return resolveRefs(target, doc, [...stack, ref]);
+  }
+  const out: JsonObject = {};
+  for (const [key, value] of Object.entries(node)) {
+    out[key] = resolveRefs(value, doc, stack);
+  }
+  return out;
+}
@@ -0,0 +1,168 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import * as ssrf from "../../infra/net/ssrf.js";
+import { parseOpenApiDocument } from "./openapi-spec.js";
+import {
+  buildOpenApiOperations,
+  buildOpenApiRequest,
+  createOpenApiTools,
+} from "./openapi-tools.js";
+
+const SPEC = `
+openapi: 3.0.3
+info:
+  title: Billing API
+servers:
+  - url: https://{region}.billing.internal/v1
+    variables:
+      region:
+        default: eu
+paths:
+  /invoices/{invoiceId}:
+    parameters:
+      - name: invoiceId
+        in: path
+        schema: { type: string }
+    get:
+      operationId: getInvoice
+      summary: Fetch one invoice
+      parameters:
+        - name: expand
+          in: query
+          schema: { type: array, items: { type: string } }
+  /invoices:
+    post:
+      operationId: createInvoice
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema: { $ref: "#/components/schemas/Invoice" }
+components:
+  schemas:
+    Invoice:
+      type: object
+      required: [amount]
+      properties:
+        amount: { type: number }
+        parent: { $ref: "#/components/schemas/Invoice" }
+`;
+
+const lookupMock = vi.fn();
+const resolvePinnedHostnameWithPolicy = ssrf.resolvePinnedHostnameWithPolicy;
+
+describe("openapi tools", () => {
+  const priorFetch = global.fetch;
+  let dir: string;
+  let config: OpenClawConfig;
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-openapi-"));
+    const spec = path.join(dir, "billing.yaml");
+    await fs.writeFile(spec, SPEC);
+    config = {
+      tools: {
+        openapi: [
+          {
+            spec,
+            baseUrl: "https://eu.billing.internal/v1",
+            auth: { type: "bearer", token: "secret" },
+          },
+        ],
+      },
+    } as OpenClawConfig;
+    lookupMock.mockResolvedValue([{ address: "10.0.0.5", family: 4 }]);
+    vi.spyOn(ssrf, "resolvePinnedHostnameWithPolicy").mockImplementation((hostname, params) =>
+      resolvePinnedHostnameWithPolicy(hostname, { ...params, lookupFn: lookupMock }),
+    );
+  });
+
+  afterEach(async () => {
+    global.fetch = priorFetch;
+    lookupMock.mockReset();
+    vi.restoreAllMocks();
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  it("derives one tool per operation with parameters and inlined schemas", () => {
+    const doc = parseOpenApiDocument(SPEC);
+    const operations = buildOpenApiOperations(doc, { spec: "billing.yaml" });
+    expect(operations.map((op) => op.toolName)).toEqual([
+      "billing_api_getInvoice",
+      "billing_api_createInvoice",
+    ]);
+    expect(operations[0].description).toBe("Fetch one invoice (GET /invoices/{invoiceId})");
+    expect(operations[0].parameters).toMatchObject({
+      properties: { invoiceId: { type: "string" }, expand: { type: "array" } },
+      required: ["invoiceId"],
+    });
+    expect(operations[1].parameters).toMatchObject({
+      properties: {
+        body: { type: "object", required: ["amount"], properties: { parent: { type: "object" } } },
+      },
+      required: ["body"],
+    });
+
+    const request = buildOpenApiRequest(operations[0], "https://api.test", {
+      invoiceId: "in/1",
+      expand: ["lines", "customer"],
+    });
+    expect(request.url.toString()).toBe(
+      "https://api.test/invoices/in%2F1?expand=lines&expand=customer",
+    );
+    expect(() => buildOpenApiRequest(operations[0], "https://api.test", {})).toThrow(
+      /invoiceId required/,
+    );
+  });
+
+  it("calls the API with configured credentials", async () => {
+    const fetchSpy = vi.fn().mockResolvedValue(
+      new Response('{"id":"in_1","amount":5}', {
+        status: 201,
+        headers: { "content-type": "application/json" },
+      }),
+    );
+    global.fetch = fetchSpy;
+
+    const tools = createOpenApiTools({ config });
+    const create = tools.find((tool) => tool.name === "billing_api_createInvoice");
+    const result = await create?.execute("call", { body: { amount: 5 } });
+
+    const [url, init] = fetchSpy.mock.calls[0] as [string, RequestInit];
+    expect(url).toBe("https://eu.billing.internal/v1/invoices");
+    expect(init.method).toBe("POST");
+    expect(init.body).toBe('{"amount":5}');
+    expect((init.headers as Record<string, string>).Authorization).toBe("Bearer secret");
+    expect(result?.details).toMatchObject({ operation: "createInvoice", status: 201, ok: true });
+  });
+
+  it("filters operations and skips names that are already taken", () => {
+    const source = config.tools?.openapi?.[0];
+    const filtered = createOpenApiTools({
+      config: { tools: { openapi: [{ ...source!, name: "bill", operations: ["getInvoice"] }] } },
+    });
+    expect(filtered.map((tool) => tool.name)).toEqual(["bill_getInvoice"]);
+    const deduped = createOpenApiTools({
+      config,
+      existingToolNames: new Set(["billing_api_getInvoice"]),
+    });
+    expect(deduped.map((tool) => tool.name)).toEqual(["billing_api_createInvoice"]);
+  });
+
+  it("only trusts hosts from config", async () => {
+    const source = config.tools?.openapi?.[0];
+    const withoutBaseUrl = { ...source!, baseUrl: undefined };
+    expect(createOpenApiTools({ config: { tools: { openapi: [withoutBaseUrl] } } })).toEqual([]);
+
+    const fetchSpy = vi.fn();
+    global.fetch = fetchSpy;
+    const [fromSpec] = createOpenApiTools({
+      config: { tools: { openapi: [{ ...withoutBaseUrl, auth: undefined }] } },
+    });
+    await expect(fromSpec?.execute("call", { invoiceId: "in_1" })).rejects.toThrow(/Blocked/);
+    expect(fetchSpy).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,376 @@
+import type { OpenClawConfig } from "../../config/config.js";
+import type { AnyAgentTool } from "./common.js";
+import { fetchWithSsrFGuard } from "../../infra/net/fetch-guard.js";
+import { createSubsystemLogger } from "../../logging/subsystem.js";
+import { wrapExternalContent } from "../../security/external-content.js";
+import { isPlainObject } from "../../utils.js";
+import { jsonResult, ToolInputError } from "./common.js";
+import { type JsonObject, resolveRefs, resolveSpec } from "./openapi-spec.js";
+import { truncateText } from "./web-fetch-utils.js";
+import { readResponseTextCapped, resolveTimeoutSeconds } from "./web-shared.js";
+
+const log = createSubsystemLogger("agents/tools");
+
+const DEFAULT_TIMEOUT_SECONDS = 30;
+const DEFAULT_MAX_CHARS = 20_000;
+const MAX_OPERATIONS_PER_SPEC = 128;
+const MAX_DESCRIPTION_CHARS = 1_000;
+const HTTP_METHODS = ["get", "put", "post", "delete", "patch", "head", "options"] as const;
+const PARAM_LOCATIONS = new Set(["path", "query", "header"]);
+
+type OpenApiSourceConfig = NonNullable<NonNullable<OpenClawConfig["tools"]>["openapi"]>[number];
+
+export type OpenApiOperation = {
+  toolName: string;
+  operationId?: string;
+  method: string;
+  path: string;
+  description: string;
+  parameters: JsonObject;
+  params: Array<{ name: string; in: "path" | "query" | "header" }>;
+  bodyKey?: string;
+  bodyContentType?: string;
+};
+
+function sanitizeName(value: string): string {
+  return value
+    .replace(/[^a-zA-Z0-9_-]+/g, "_")
+    .replace(/_+/g, "_")
+    .replace(/^_|_$/g, "");
+}
+
+function resolvePrefix(source: OpenApiSourceConfig, doc: JsonObject): string {
+  const title = isPlainObject(doc.info) && typeof doc.info.title === "string" ? doc.info.title : "";
+  const prefix = sanitizeName(source.name?.trim() || title).toLowerCase();
+  return (prefix || "api").slice(0, 24);
+}
+
+function clip(text: string, max: number): string {
+  return text.length > max ? `${text.slice(0, max - 1)}…` : text;
+}
+
+function pickBodyContentType(content: JsonObject): string | undefined {
+  const types = Object.keys(content);
+  return (
+    types.find((type) => type.toLowerCase().includes("json")) ??
+    types.find((type) => type.toLowerCase() === "application/x-www-form-urlencoded")
+  );
+}
+
+/** Derives one tool definition per operation in the document. */
+export function buildOpenApiOperations(
+  doc: JsonObject,
+  source: OpenApiSourceConfig,
+): OpenApiOperation[] {
+  const prefix = resolvePrefix(source, doc);
+  const allowed = source.operations?.length ? new Set(source.operations) : null;
+  const operations: OpenApiOperation[] = [];
+  const usedNames = new Set<string>();
+
+  for (const [path, rawItem] of Object.entries(doc.paths as JsonObject)) {
+    const pathItem = resolveRefs(rawItem, doc);
+    if (!isPlainObject(pathItem)) {
+      continue;
+    }
+    const sharedParams = Array.isArray(pathItem.parameters) ? pathItem.parameters : [];
+    for (const method of HTTP_METHODS) {
+      const op = pathItem[method];
+      if (!isPlainObject(op)) {
+        continue;
+      }
+      const operationId = typeof op.operationId === "string" ? op.operationId : undefined;
+      if (allowed && (!operationId || !allowed.has(operationId))) {
+        continue;
+      }
+
+      // Operation-level parameters override path-level ones with the same name + location.
+      const byKey = new Map<string, JsonObject>();
+      const opParams = Array.isArray(op.parameters) ? op.parameters : [];
+      for (const param of [...sharedParams, ...opParams]) {
+        if (!isPlainObject(param) || typeof param.name !== "string") {
+          continue;
+        }
+        if (typeof param.in === "string") {
+          byKey.set(`${param.in}:${param.name}`, param);
+        }
+      }
+      const properties: JsonObject = {};
+      const required: string[] = [];
+      const params: OpenApiOperation["params"] = [];
+      for (const param of byKey.values()) {
+        const location = param.in as string;
+        const name = param.name as string;
+        if (!PARAM_LOCATIONS.has(location) || properties[name] !== undefined) {
+          continue;
+        }
+        const schema = isPlainObject(param.schema) ? param.schema : { type: "string" };
+        properties[name] = {
+          ...schema,
+          ...(typeof param.description === "string"
+            ? { description: clip(param.description, 300) }
+            : {}),
+        };
+        if (param.required === true || location === "path") {
+          required.push(name);
+        }
+        params.push({ name, in: location as "path" | "query" | "header" });
+      }
+
+      const requestBody = isPlainObject(op.requestBody) ? op.requestBody : undefined;
+      const content = isPlainObject(requestBody?.content) ? requestBody.content : undefined;
+      const bodyContentType = content ? pickBodyContentType(content) : undefined;
+      let bodyKey: string | undefined;
+      if (content && bodyContentType) {
+        bodyKey = properties.body === undefined ? "body" : "requestBody";
+        const media = content[bodyContentType];
+        properties[bodyKey] = {
+          ...(isPlainObject(media) && isPlainObject(media.schema) ? media.schema : {}),
+          description: "Request body.",
+        };
+        if (requestBody?.required === true) {
+          required.push(bodyKey);
+        }
+      }
+
+      const baseName = sanitizeName(operationId ?? `${method}_${path}`);
+      let toolName = `${prefix}_${baseName}`.slice(0, 64);
+      for (let i = 2; usedNames.has(toolName); i++) {
+        toolName = `${prefix}_${baseName}`.slice(0, 60) + `_${i}`;
+      }
+      usedNames.add(toolName);
+
+      const summary =
+        (typeof op.summary === "string" && op.summary.trim()) ||
+        (typeof op.description === "string" && op.description.trim()) ||
+        "";
+      const endpoint = `${method.toUpperCase()} ${path}`;
+      operations.push({
+        toolName,
+        operationId,
+        method: method.toUpperCase(),
+        path,
+        description: clip(summary ? `${summary} (${endpoint})` : endpoint, MAX_DESCRIPTION_CHARS),
+        parameters: {
+          type: "object",
+          properties,
+          ...(required.length > 0 ? { required } : {}),
+        },
+        params,
+        bodyKey,
+        bodyContentType,
+      });
+      if (operations.length >= MAX_OPERATIONS_PER_SPEC) {
+        log.warn(
+          `openapi spec ${source.spec}: only the first ${MAX_OPERATIONS_PER_SPEC} operations are exposed (use operations to pick)`,
+        );
+        return operations;
+      }
+    }
+  }
+  return operations;
+}
+
+function resolveBaseUrl(doc: JsonObject, source: OpenApiSourceConfig): string | null {
+  const configured = source.baseUrl?.trim();
+  if (configured) {
+    return URL.canParse(configured) ? configured.replace(/\/+$/, "") : null;
+  }
+  const server = Array.isArray(doc.servers) ? doc.servers[0] : undefined;
+  if (!isPlainObject(server) || typeof server.url !== "string") {
+    return null;
+  }
+  const variables = isPlainObject(server.variables) ? server.variables : {};
+  const url = server.url.replace(/\{([^}]+)\}/g, (match, name: string) => {
+    const variable = variables[name];
+    return isPlainObject(variable) && variable.default !== undefined
+      ? String(variable.default)
+      : match;
+  });
+  try {
+    // Relative server URLs resolve against the spec's own location.
+    const base = isRemoteSpec(source.spec) ? new URL(url, source.spec.trim()) : new URL(url);
+    return base.toString().replace(/\/+$/, "");
+  } catch {
+    return null;
+  }
+}
+
+function stringifyParam(value: unknown): string {
+  return typeof value === "object" && value !== null ? JSON.stringify(value) : String(value);
+}
+
+/** Builds the request for one call; auth is applied by the caller. */
+export function buildOpenApiRequest(
+  operation: OpenApiOperation,
+  baseUrl: string,
+  args: JsonObject,
+): { url: URL; headers: Record<string, string>; body?: string } {
+  let path = operation.path;
+  const query = new URLSearchParams();
+  const headers: Record<string, string> = { Accept: "application/json, */*;q=0.5" };
+  for (const param of operation.params) {
+    const value = args[param.name];
+    if (value === undefined || value === null) {
+      if (param.in === "path") {
+        throw new ToolInputError(`${param.name} required`);
+      }
+      continue;
+    }
+    if (param.in === "path") {
+      path = path.replaceAll(`{${param.name}}`, encodeURIComponent(stringifyParam(value)));
+    } else if (param.in === "query") {
+      for (const item of Array.isArray(value) ? value : [value]) {
+        query.append(param.name, stringifyParam(item));
+      }
+    } else {
+      headers[param.name] = stringifyParam(value);
+    }
+  }
+  const url = new URL(`${baseUrl}${path}`);
+  for (const [key, value] of query) {
+    url.searchParams.append(key, value);
+  }
+
+  let body: string | undefined;
+  const payload = operation.bodyKey ? args[operation.bodyKey] : undefined;
+  if (payload !== undefined && operation.bodyContentType) {
+    headers["Content-Type"] = operation.bodyContentType;
+    if (operation.bodyContentType === "application/x-www-form-urlencoded") {
+      const form = new URLSearchParams();
+      for (const [key, value] of Object.entries(isPlainObject(payload) ? payload : {})) {
+        form.append(key, stringifyParam(value));
+      }
+      body = form.toString();
+    } else {
+      body = typeof payload === "string" ? payload : JSON.stringify(payload);
+    }
+  }
+  return { url, headers, body };
+}
+
+function applyAuth(
+  auth: OpenApiSourceConfig["auth"],
+  url: URL,
+  headers: Record<string, string>,
+): void {
+  if (!auth) {
+    return;
+  }
+  if (auth.type === "bearer") {
+    headers.Authorization = `Bearer ${auth.token}`;
+  } else if (auth.type === "basic") {
+    const encoded = Buffer.from(`${auth.username}:${auth.password}`).toString("base64");
+    headers.Authorization = `Basic ${encoded}`;
+  } else if (auth.in === "query") {
+    url.searchParams.set(auth.name, auth.value);
+  } else {
+    headers[auth.name] = auth.value;
+  }
+}
+
+function formatBody(text: string, contentType: string | null): string {
+  if (!contentType?.toLowerCase().includes("json")) {
+    return text;
+  }
+  try {
+    return JSON.stringify(JSON.parse(text), null, 2);
+  } catch {
+    return text;
+  }
+}
+
+function createOperationTool(params: {
+  operation: OpenApiOperation;
+  source: OpenApiSourceConfig;
+  baseUrl: string;
+}): AnyAgentTool {
+  const { operation, source, baseUrl } = params;
+  const timeoutSeconds = resolveTimeoutSeconds(source.timeoutSeconds, DEFAULT_TIMEOUT_SECONDS);
+  // Only a configured baseUrl may point at a private host; a spec's servers entry is untrusted.
+  const policy = source.baseUrl?.trim()
+    ? { allowedHostnames: [new URL(baseUrl).hostname] }
+    : undefined;
+  return {
+    label: operation.operationId ?? operation.toolName,
+    name: operation.toolName,
+    description: operation.description,
+    parameters: operation.parameters,
+    execute: async (_toolCallId, args) => {
+      const request = buildOpenApiRequest(operation, baseUrl, isPlainObject(args) ? args : {});
+      // Configured credentials win over any same-named header parameter.
+      applyAuth(source.auth, request.url, request.headers);
+      const start = Date.now();
+      const result = await fetchWithSsrFGuard({
+        url: request.url.toString(),
+        // Credentials must never be replayed to another host, so redirects are not followed.
+        maxRedirects: 0,
+        timeoutMs: timeoutSeconds * 1000,
+        policy,
+        auditContext: "openapi",
+        init: {
+          method: operation.method,
+          headers: request.headers,
+          ...(request.body !== undefined ? { body: request.body } : {}),
+        },
+      });
+      try {
+        const res = result.response;
+        // A character is at most 4 UTF-8 bytes, so this never cuts below the char limit.
+        const { text, truncated: cut } =
+          operation.method === "HEAD"
+            ? { text: "", truncated: false }
+            : await readResponseTextCapped(res, DEFAULT_MAX_CHARS * 4);
+        const truncated = truncateText(
+          formatBody(text, res.headers.get("content-type")),
+          DEFAULT_MAX_CHARS,
+        );
+        return jsonResult({
+          operation: operation.operationId ?? `${operation.method} ${operation.path}`,
+          status: res.status,
+          ok: res.ok,
+          body: truncated.text
+            ? wrapExternalContent(truncated.text, { source: "api", includeWarning: false })
+            : "",
+          truncated: truncated.truncated || cut,
+          tookMs: Date.now() - start,
+        });
+      } finally {
+        await result.release();
+      }
+    },
+  };
+}
+
+/** Generates tools from every OpenAPI source in `tools.openapi`. */
+export function createOpenApiTools(options: {
+  config?: OpenClawConfig;
+  existingToolNames?: Set<string>;
+}): AnyAgentTool[] {
+  const names = new Set(options.existingToolNames);
+  const tools: AnyAgentTool[] = [];
+  for (const source of options.config?.tools?.openapi ?? []) {
+    const doc = resolveSpec(source.spec);
+    if (!doc) {
+      continue;
+    }
+    if (source.auth && !source.baseUrl?.trim()) {
+      // Credentials must not follow a host chosen by the spec document.
+      log.warn(`openapi spec skipped (auth requires an explicit baseUrl): ${source.spec}`);
+      continue;
+    }
+    const baseUrl = resolveBaseUrl(doc, source);
+    if (!baseUrl) {
+      log.warn(`openapi spec skipped (no usable server URL; set baseUrl): ${source.spec}`);
+      continue;
+    }
+    for (const operation of buildOpenApiOperations(doc, source)) {
+      if (names.has(operation.toolName)) {
+        log.warn(`openapi tool skipped (name conflicts with another tool): ${operation.toolName}`);
+        continue;
+      }
+      names.add(operation.toolName);
+      tools.push(createOperationTool({ operation, source, baseUrl }));
+    }
+  }
+  return tools;
+}
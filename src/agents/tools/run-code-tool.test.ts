@@ -0,0 +1,76 @@
+import { describe, expect, it } from "vitest";
+import { createRunCodeTool, extractPlots, resolveInterpreterArgv } from "./run-code-tool.js";
+
+function createTool(timeoutSeconds = 10) {
+  return createRunCodeTool({
+    config: {
+      tools: { runCode: { enabled: true, timeoutSeconds, sandbox: { backend: "off" } } },
+    },
+    workspaceDir: "/work",
+  });
+}
+
+function textOf(result: { content: Array<{ type: string; text?: string }> }) {
+  return result.content.find((block) => block.type === "text")?.text ?? "";
+}
+
+describe("run_code tool", () => {
+  it("splits runner plot markers out of stdout", () => {
+    const { output, plots } = extractPlots("result 42\n\n\x1eopenclaw-plot:iVBORw0K\n");
+    expect(output).toBe("result 42\n");
+    expect(plots).toEqual(["iVBORw0K"]);
+  });
+
+  it("wraps python with a memory limit but caps the node heap instead", () => {
+    const python = resolveInterpreterArgv({ language: "python", memoryMb: 512, platform: "linux" });
+    expect(python.slice(0, 5)).toEqual([
+      "sh",
+      "-c",
+      'ulimit -v "$1" 2>/dev/null; shift; exec "$@"',
+      "sh",
+      "524288",
+    ]);
+    expect(python.slice(5, 8)).toEqual(["python3", "-u", "-c"]);
+    expect(
+      resolveInterpreterArgv({ language: "javascript", memoryMb: 512, node: "/bin/node" }),
+    ).toEqual(["/bin/node", "--max-old-space-size=512", "-"]);
+  });
+
+  it("runs javascript in a scratch dir with a minimal env", async () => {
+    process.env.OPENCLAW_RUN_CODE_TEST_SECRET = "leak";
+    try {
+      const result = await createTool()?.execute("call", {
+        language: "javascript",
+        code: [
+          "console.log(process.cwd().includes('openclaw-run-code-'));",
+          "console.log(process.env.WORKSPACE, process.env.OPENCLAW_RUN_CODE_TEST_SECRET);",
+          "console.error('warned');",
+          "process.exit(3);",
+        ].join("\n"),
+      });
+      const text = textOf(result!);
+      expect(text).toContain("javascript: exit code 3");
+      expect(text).toContain("stdout:\ntrue\n/work undefined");
+      expect(text).toContain("stderr:\nwarned");
+      expect(text).toContain("warning: code ran without isolation");
+      expect(result?.details).toMatchObject({ exitCode: 3, timedOut: false, isolation: "none" });
+    } finally {
+      delete process.env.OPENCLAW_RUN_CODE_TEST_SECRET;
+    }
+  });
+
+  it("kills runs that exceed the time limit", async () => {
+    const result = await createTool(1)?.execute("call", {
+      language: "javascript",
+      code: "setInterval(() => {}, 1000);",
+      timeoutSeconds: 60,
+    });
+    expect(textOf(result!)).toContain("timed out after 1s");
+    expect(result?.details).toMatchObject({ timedOut: true });
+  });
+
+  it("is off unless enabled", () => {
+    expect(createRunCodeTool({ config: {} })).toBeNull();
+    expect(createRunCodeTool({ config: { tools: { runCode: { enabled: false } } } })).toBeNull();
+  });
+});
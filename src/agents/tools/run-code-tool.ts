@@ -0,0 +1,384 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import { Type } from "@sinclair/typebox";
+import { spawn } from "node:child_process";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import type { OpenClawConfig } from "../../config/config.js";
+import type { AnyAgentTool } from "./common.js";
+import { resolveExecOsSandbox, wrapArgvWithOsSandbox } from "../bash-tools.os-sandbox.js";
+import { coerceEnv } from "../bash-tools.shared.js";
+import { killProcessTree } from "../shell-utils.js";
+import { optionalStringEnum } from "../schema/typebox.js";
+import { sanitizeToolResultImages } from "../tool-images.js";
+import { readNumberParam, readStringParam, ToolInputError } from "./common.js";
+
+const RUN_CODE_LANGUAGES = ["python", "javascript"] as const;
+type RunCodeLanguage = (typeof RUN_CODE_LANGUAGES)[number];
+
+const DEFAULT_TIMEOUT_SECONDS = 30;
+const DEFAULT_MEMORY_MB = 2048;
+const DEFAULT_MAX_OUTPUT_CHARS = 20_000;
+// Hard cap on buffered output so a print loop cannot grow the gateway's memory.
+const MAX_CAPTURE_CHARS = 4 * 1024 * 1024;
+const MAX_PLOTS = 4;
+const PLOT_MARKER = "\x1eopenclaw-plot:";
+const SANDBOX_PATH = "/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin";
+
+// Runs the snippet (read from stdin) as __main__ and, on exit, emits open matplotlib figures as
+// base64 PNG lines so plots come back without touching the filesystem.
+const PYTHON_RUNNER = [
+  "import atexit, base64, io, os, sys",
+  "_src = sys.stdin.read()",
+  "sys.stdin = open(os.devnull)",
+  "def _openclaw_emit_plots():",
+  '    plt = sys.modules.get("matplotlib.pyplot")',
+  "    if plt is None:",
+  "        return",
+  `    for num in plt.get_fignums()[:${MAX_PLOTS}]:`,
+  "        buf = io.BytesIO()",
+  '        plt.figure(num).savefig(buf, format="png", bbox_inches="tight")',
+  // Must match PLOT_MARKER.
+  '        sys.stdout.write("\\n\\x1eopenclaw-plot:" + base64.b64encode(buf.getvalue()).decode() + "\\n")',
+  "    sys.stdout.flush()",
+  "atexit.register(_openclaw_emit_plots)",
+  'exec(compile(_src, "<run_code>", "exec"), {"__name__": "__main__", "__builtins__": __builtins__})',
+].join("\n");
+
+const RunCodeSchema = Type.Object({
+  code: Type.String({ description: "Source code to run." }),
+  language: optionalStringEnum(RUN_CODE_LANGUAGES, {
+    description: "python (default) or javascript (Node.js).",
+  }),
+  timeoutSeconds: Type.Optional(
+    Type.Number({ description: "Time limit in seconds (capped by config).", minimum: 1 }),
+  ),
+});
+
+type RunCodeConfig = NonNullable<NonNullable<OpenClawConfig["tools"]>["runCode"]>;
+
+export type RunCodeSandbox = {
+  containerName: string;
+  containerWorkdir: string;
+  env?: Record<string, string>;
+};
+
+export type RunCodeToolDetails = {
+  language: RunCodeLanguage;
+  exitCode: number | null;
+  timedOut: boolean;
+  durationMs: number;
+  plots: number;
+  isolation: "container" | "bwrap" | "sandbox-exec" | "none";
+  truncated: boolean;
+};
+
+type SpawnedRun = {
+  stdout: string;
+  stderr: string;
+  exitCode: number | null;
+  timedOut: boolean;
+  truncated: boolean;
+};
+
+function clampInt(value: number | undefined, fallback: number, min: number, max?: number) {
+  const base = typeof value === "number" && Number.isFinite(value) ? Math.floor(value) : fallback;
+  return Math.max(min, max === undefined ? base : Math.min(max, base));
+}
+
+/** Split interpreter stdout into plain output and base64 PNG plots emitted by the runner. */
+export function extractPlots(stdout: string): { output: string; plots: string[] } {
+  const plots: string[] = [];
+  const kept: string[] = [];
+  for (const line of stdout.split("\n")) {
+    if (line.startsWith(PLOT_MARKER)) {
+      // The runner writes a newline before each marker; drop it with the marker line.
+      if (kept.length > 0 && kept[kept.length - 1] === "") {
+        kept.pop();
+      }
+      plots.push(line.slice(PLOT_MARKER.length).trim());
+      continue;
+    }
+    kept.push(line);
+  }
+  return { output: kept.join("\n"), plots: plots.filter(Boolean).slice(0, MAX_PLOTS) };
+}
+
+/** Interpreter argv (without isolation wrappers) for a language. */
+export function resolveInterpreterArgv(params: {
+  language: RunCodeLanguage;
+  memoryMb: number;
+  python?: string;
+  node?: string;
+  platform?: NodeJS.Platform;
+}): string[] {
+  const platform = params.platform ?? process.platform;
+  if (params.language === "javascript") {
+    // V8 reserves large virtual ranges, so cap the heap instead of using ulimit -v.
+    return [params.node ?? "node", `--max-old-space-size=${params.memoryMb}`, "-"];
+  }
+  const python = params.python ?? (platform === "win32" ? "python" : "python3");
+  const argv = [python, "-u", "-c", PYTHON_RUNNER];
+  if (platform === "win32") {
+    return argv;
+  }
+  // Best-effort address-space limit; ignored where the shell cannot set it.
+  return [
+    "sh",
+    "-c",
+    'ulimit -v "$1" 2>/dev/null; shift; exec "$@"',
+    "sh",
+    String(params.memoryMb * 1024),
+    ...argv,
+  ];
+}
+
+function buildRunEnv(home: string, extra?: Record<string, string>): Record<string, string> {
+  const env: Record<string, string> = {
+    PATH: process.env.PATH ?? SANDBOX_PATH,
+    HOME: home,
+    TMPDIR: home,
+    LANG: process.env.LANG ?? "C.UTF-8",
+    PYTHONDONTWRITEBYTECODE: "1",
+    PYTHONIOENCODING: "utf-8",
+    MPLBACKEND: "Agg",
+    MPLCONFIGDIR: home,
+    ...extra,
+  };
+  if (process.platform === "win32") {
+    for (const key of ["SystemRoot", "TEMP", "TMP", "USERPROFILE", "PATHEXT"]) {
+      const value = process.env[key];
+      if (value) {
+        env[key] = value;
+      }
+    }
+  }
+  return env;
+}
+
+function spawnWithLimits(params: {
+  argv: string[];
+  cwd?: string;
+  env: Record<string, string>;
+  input: string;
+  timeoutMs: number;
+}): Promise<SpawnedRun> {
+  const [command, ...args] = params.argv;
+  return new Promise((resolve, reject) => {
+    const child = spawn(command ?? "", args, {
+      cwd: params.cwd,
+      env: params.env,
+      stdio: ["pipe", "pipe", "pipe"],
+      detached: process.platform !== "win32",
+    });
+    let stdout = "";
+    let stderr = "";
+    let timedOut = false;
+    let truncated = false;
+    const kill = () => {
+      if (child.pid) {
+        killProcessTree(child.pid);
+      }
+    };
+    const timer = setTimeout(() => {
+      timedOut = true;
+      kill();
+    }, params.timeoutMs);
+    const append = (current: string, chunk: Buffer) => {
+      if (current.length + chunk.length > MAX_CAPTURE_CHARS) {
+        truncated = true;
+        kill();
+        return current;
+      }
+      return current + chunk.toString("utf8");
+    };
+    child.stdout.on("data", (chunk: Buffer) => {
+      stdout = append(stdout, chunk);
+    });
+    child.stderr.on("data", (chunk: Buffer) => {
+      stderr = append(stderr, chunk);
+    });
+    // The interpreter may exit before reading all of stdin.
+    child.stdin.on("error", () => {});
+    child.stdin.end(params.input);
+    child.on("error", (err) => {
+      clearTimeout(timer);
+      reject(err);
+    });
+    child.on("close", (code) => {
+      clearTimeout(timer);
+      resolve({ stdout, stderr, exitCode: code, timedOut, truncated });
+    });
+  });
+}
+
+function clipOutput(text: string, maxChars: number): { text: string; clipped: boolean } {
+  if (text.length <= maxChars) {
+    return { text, clipped: false };
+  }
+  const head = Math.floor(maxChars * 0.3);
+  const tail = maxChars - head;
+  return {
+    text: `${text.slice(0, head)}\n…[${text.length - maxChars} chars omitted]…\n${text.slice(-tail)}`,
+    clipped: true,
+  };
+}
+
+export function createRunCodeTool(options?: {
+  config?: OpenClawConfig;
+  workspaceDir?: string;
+  sandbox?: RunCodeSandbox;
+}): AnyAgentTool | null {
+  const cfg: RunCodeConfig = options?.config?.tools?.runCode ?? {};
+  // Opt-in: snippets run on the gateway host unless the session is sandboxed.
+  if (cfg.enabled !== true) {
+    return null;
+  }
+  const maxTimeoutSeconds = clampInt(cfg.timeoutSeconds, DEFAULT_TIMEOUT_SECONDS, 1);
+  const memoryMb = clampInt(cfg.memoryMb, DEFAULT_MEMORY_MB, 64);
+  const maxOutputChars = clampInt(cfg.maxOutputChars, DEFAULT_MAX_OUTPUT_CHARS, 1000);
+  const network = cfg.sandbox?.network === true;
+  return {
+    label: "Run Code",
+    name: "run_code",
+    description:
+      "Run a Python (default) or JavaScript snippet in an isolated interpreter with time and memory limits and return stdout, stderr, and any matplotlib figures as images. Each call starts fresh in a scratch directory (no state carries over); the workspace path is in the WORKSPACE env var. Network access is off by default. Use for calculations and data analysis; use exec for shell commands.",
+    parameters: RunCodeSchema,
+    execute: async (_toolCallId, args): Promise<AgentToolResult<unknown>> => {
+      const params = args as Record<string, unknown>;
+      const code = readStringParam(params, "code", { required: true, trim: false });
+      const language = (readStringParam(params, "language") ?? "python") as RunCodeLanguage;
+      if (!RUN_CODE_LANGUAGES.includes(language)) {
+        throw new ToolInputError(`language must be one of: ${RUN_CODE_LANGUAGES.join(", ")}`);
+      }
+      const timeoutSeconds = clampInt(
+        readNumberParam(params, "timeoutSeconds", { integer: true }),
+        maxTimeoutSeconds,
+        1,
+        maxTimeoutSeconds,
+      );
+
+      const scratch = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-run-code-"));
+      const started = Date.now();
+      let run: SpawnedRun;
+      let isolation: RunCodeToolDetails["isolation"];
+      try {
+        if (options?.sandbox) {
+          const { containerName, containerWorkdir } = options.sandbox;
+          const inner = resolveInterpreterArgv({ language, memoryMb, platform: "linux" });
+          const envArgs = Object.entries({
+            ...options.sandbox.env,
+            HOME: "/tmp",
+            MPLBACKEND: "Agg",
+            MPLCONFIGDIR: "/tmp",
+            PYTHONDONTWRITEBYTECODE: "1",
+            WORKSPACE: containerWorkdir,
+          }).flatMap(([key, value]) => ["-e", `${key}=${value}`]);
+          const argv = [
+            "docker",
+            "exec",
+            "-i",
+            "-w",
+            containerWorkdir,
+            ...envArgs,
+            containerName,
+            // Killing the docker client does not stop the process inside the container.
+            "timeout",
+            "-s",
+            "KILL",
+            String(timeoutSeconds),
+            ...inner,
+          ];
+          isolation = "container";
+          run = await spawnWithLimits({
+            argv,
+            // The docker client itself needs the gateway env (DOCKER_HOST, contexts, ...).
+            env: coerceEnv(process.env),
+            input: code,
+            timeoutMs: (timeoutSeconds + 5) * 1000,
+          });
+        } else {
+          const backend = cfg.sandbox?.backend ?? "auto";
+          const osSandbox = resolveExecOsSandbox({
+            config: { backend, network },
+            workspaceDir: scratch,
+          });
+          // Without isolation the snippet could read gateway credentials; only run bare when the
+          // operator turned the sandbox off explicitly.
+          if (!osSandbox && backend !== "off") {
+            throw new Error(
+              "run_code: no OS sandbox available on this host (install bubblewrap on Linux, or set tools.runCode.sandbox.backend=off to run without isolation)",
+            );
+          }
+          const inner = resolveInterpreterArgv({
+            language,
+            memoryMb,
+            python: cfg.python,
+            node: process.execPath,
+          });
+          isolation = osSandbox?.backend ?? "none";
+          run = await spawnWithLimits({
+            argv: osSandbox ? wrapArgvWithOsSandbox(inner, osSandbox, scratch) : inner,
+            cwd: scratch,
+            env: buildRunEnv(
+              scratch,
+              options?.workspaceDir ? { WORKSPACE: options.workspaceDir } : undefined,
+            ),
+            input: code,
+            timeoutMs: timeoutSeconds * 1000,
+          });
+        }
+      } catch (err) {
+        if ((err as NodeJS.ErrnoException)?.code === "ENOENT") {
+          throw new Error(
+            `run_code: interpreter not found for ${language} (${String((err as Error).message)})`,
+            { cause: err },
+          );
+        }
+        throw err;
+      } finally {
+        await fs.rm(scratch, { recursive: true, force: true }).catch(() => {});
+      }
+
+      const durationMs = Date.now() - started;
+      const { output, plots } = extractPlots(run.stdout);
+      const stdout = clipOutput(output.trimEnd(), maxOutputChars);
+      const stderr = clipOutput(run.stderr.trimEnd(), maxOutputChars);
+      const status = run.timedOut
+        ? `timed out after ${timeoutSeconds}s`
+        : run.truncated
+          ? "killed: output limit exceeded"
+          : `exit code ${run.exitCode ?? "?"}`;
+      const sections = [`${language}: ${status} (${durationMs}ms)`];
+      if (isolation === "none") {
+        sections.push("warning: code ran without isolation (tools.runCode.sandbox.backend=off)");
+      }
+      sections.push(stdout.text ? `stdout:\n${stdout.text}` : "stdout: (empty)");
+      if (stderr.text) {
+        sections.push(`stderr:\n${stderr.text}`);
+      }
+      if (plots.length > 0) {
+        sections.push(`${plots.length} figure${plots.length === 1 ? "" : "s"} attached`);
+      }
+      const details: RunCodeToolDetails = {
+        language,
+        exitCode: run.exitCode,
+        timedOut: run.timedOut,
+        durationMs,
+        plots: plots.length,
+        isolation,
+        truncated: run.truncated || stdout.clipped || stderr.clipped,
+      };
+      return await sanitizeToolResultImages(
+        {
+          content: [
+            { type: "text", text: sections.join("\n\n") },
+            ...plots.map((data) => ({ type: "image" as const, data, mimeType: "image/png" })),
+          ],
+          details,
+        },
+        "run_code",
+      );
+    },
+  };
+}
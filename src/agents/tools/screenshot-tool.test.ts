@@ -0,0 +1,80 @@
+import fs from "node:fs/promises";
+import { afterEach, describe, expect, it, vi } from "vitest";
+import { createScreenshotTool, resolveScreenshotCommands } from "./screenshot-tool.js";
+
+// 1×1 transparent PNG.
+const PNG_1X1 = Buffer.from(
+  "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=",
+  "base64",
+);
+
+function enoent() {
+  return Object.assign(new Error("spawn ENOENT"), { code: "ENOENT" });
+}
+
+describe("screenshot tool", () => {
+  afterEach(() => {
+    vi.unstubAllEnvs();
+  });
+
+  it("picks platform capture commands", () => {
+    const outPath = "/tmp/shot.png";
+    expect(
+      resolveScreenshotCommands({ platform: "darwin", target: "screen", display: 2, outPath }),
+    ).toEqual([{ command: "screencapture", args: ["-x", "-t", "png", "-D", "2", outPath] }]);
+    expect(
+      resolveScreenshotCommands({ platform: "darwin", target: "window", outPath })[0]?.args,
+    ).toEqual(["-x", "-t", "png", "-R", "{bounds}", outPath]);
+    expect(
+      resolveScreenshotCommands({
+        platform: "linux",
+        target: "screen",
+        outPath,
+        env: { WAYLAND_DISPLAY: "wayland-0" },
+      }).map((cmd) => cmd.command),
+    ).toEqual(["grim", "gnome-screenshot"]);
+    expect(
+      resolveScreenshotCommands({ platform: "linux", target: "window", outPath, env: {} })[0],
+    ).toEqual({ command: "import", args: ["-window", "{window}", outPath] });
+    expect(resolveScreenshotCommands({ platform: "win32", target: "window", outPath })).toEqual(
+      [],
+    );
+  });
+
+  it("falls back past missing tools and returns an image block", async () => {
+    const run = vi.fn(async (command: string, args: string[]) => {
+      if (command === "import") {
+        throw enoent();
+      }
+      await fs.writeFile(args[args.length - 1], PNG_1X1);
+      return { stdout: "" };
+    });
+    const tool = createScreenshotTool({ platform: "linux", run });
+    vi.stubEnv("WAYLAND_DISPLAY", "");
+
+    const result = await tool.execute("call", {});
+
+    expect(run.mock.calls.map(([command]) => command)).toEqual(["import", "gnome-screenshot"]);
+    expect(result.content.find((block) => block.type === "image")).toMatchObject({
+      mimeType: "image/png",
+    });
+    const text = result.content.find((block) => block.type === "text") as { text: string };
+    expect(text.text).toMatch(/^Screenshot of the screen/);
+    expect(result.details).toMatchObject({ target: "screen" });
+    // The capture is returned inline and its temp file removed.
+    await expect(fs.access((result.details as { path: string }).path)).rejects.toThrow();
+  });
+
+  it("reports when no capture tool is installed", async () => {
+    const tool = createScreenshotTool({
+      platform: "linux",
+      run: async () => {
+        throw enoent();
+      },
+    });
+    vi.stubEnv("WAYLAND_DISPLAY", "");
+    await expect(tool.execute("call", { target: "screen" })).rejects.toThrow(
+      /no capture tool found \(tried import, gnome-screenshot, scrot\)/,
+    );
+  });
+});
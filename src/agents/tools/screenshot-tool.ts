@@ -0,0 +1,212 @@
+import { Type } from "@sinclair/typebox";
+import crypto from "node:crypto";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import type { AnyAgentTool } from "./common.js";
+import { getImageMetadata } from "../../media/image-ops.js";
+import { runExec } from "../../process/exec.js";
+import { optionalStringEnum } from "../schema/typebox.js";
+import { imageResultFromFile, readNumberParam, readStringParam, ToolInputError } from "./common.js";
+
+const SCREENSHOT_TARGETS = ["screen", "window"] as const;
+type ScreenshotTarget = (typeof SCREENSHOT_TARGETS)[number];
+
+const CAPTURE_TIMEOUT_MS = 20_000;
+
+const ScreenshotSchema = Type.Object({
+  target: optionalStringEnum(SCREENSHOT_TARGETS, {
+    description: 'What to capture: "screen" (default) or the focused "window".',
+  }),
+  display: Type.Optional(
+    Type.Number({ description: "Display number for multi-monitor setups (1 = main).", minimum: 1 }),
+  ),
+});
+
+export type ScreenshotCommand = {
+  command: string;
+  args: string[];
+};
+
+const FRONT_WINDOW_BOUNDS_SCRIPT = [
+  'tell application "System Events"',
+  "  set frontProc to first application process whose frontmost is true",
+  "  tell front window of frontProc",
+  "    set {x, y} to position",
+  "    set {w, h} to size",
+  "  end tell",
+  "end tell",
+  'return (x as text) & "," & (y as text) & "," & (w as text) & "," & (h as text)',
+].join("\n");
+
+function windowsCaptureScript(outPath: string) {
+  const escaped = outPath.replace(/'/g, "''");
+  return [
+    "Add-Type -AssemblyName System.Windows.Forms,System.Drawing",
+    "$b = [System.Windows.Forms.SystemInformation]::VirtualScreen",
+    "$bmp = New-Object System.Drawing.Bitmap $b.Width, $b.Height",
+    "$g = [System.Drawing.Graphics]::FromImage($bmp)",
+    "$g.CopyFromScreen($b.Left, $b.Top, 0, 0, $bmp.Size)",
+    `$bmp.Save('${escaped}', [System.Drawing.Imaging.ImageFormat]::Png)`,
+    "$g.Dispose(); $bmp.Dispose()",
+  ].join("; ");
+}
+
+/**
+ * Capture commands to try in order for the current platform. Window captures that need a
+ * window id or bounds are resolved at run time (`{window}` / `{bounds}` placeholders).
+ */
+export function resolveScreenshotCommands(params: {
+  platform: NodeJS.Platform;
+  target: ScreenshotTarget;
+  display?: number;
+  outPath: string;
+  env?: NodeJS.ProcessEnv;
+}): ScreenshotCommand[] {
+  const { target, outPath } = params;
+  const env = params.env ?? process.env;
+  switch (params.platform) {
+    case "darwin": {
+      const args = ["-x", "-t", "png"];
+      if (target === "window") {
+        args.push("-R", "{bounds}");
+      } else if (params.display) {
+        args.push("-D", String(params.display));
+      }
+      return [{ command: "screencapture", args: [...args, outPath] }];
+    }
+    case "linux": {
+      if (env.WAYLAND_DISPLAY) {
+        return target === "window"
+          ? [{ command: "gnome-screenshot", args: ["-w", "-f", outPath] }]
+          : [
+              { command: "grim", args: [outPath] },
+              { command: "gnome-screenshot", args: ["-f", outPath] },
+            ];
+      }
+      return target === "window"
+        ? [
+            { command: "import", args: ["-window", "{window}", outPath] },
+            { command: "gnome-screenshot", args: ["-w", "-f", outPath] },
+            { command: "scrot", args: ["-u", "-o", outPath] },
+          ]
+        : [
+            { command: "import", args: ["-window", "root", outPath] },
+            { command: "gnome-screenshot", args: ["-f", outPath] },
+            { command: "scrot", args: ["-o", outPath] },
+          ];
+    }
+    case "win32":
+      return target === "window"
+        ? []
+        : [
+            {
+              command: "powershell",
+              args: ["-NoProfile", "-NonInteractive", "-Command", windowsCaptureScript(outPath)],
+            },
+          ];
+    default:
+      return [];
+  }
+}
+
+type CommandRunner = (command: string, args: string[]) => Promise<{ stdout: string }>;
+
+async function resolvePlaceholders(
+  cmd: ScreenshotCommand,
+  run: CommandRunner,
+): Promise<ScreenshotCommand> {
+  const args: string[] = [];
+  for (const arg of cmd.args) {
+    if (arg === "{bounds}") {
+      const { stdout } = await run("osascript", ["-e", FRONT_WINDOW_BOUNDS_SCRIPT]);
+      args.push(stdout.trim());
+    } else if (arg === "{window}") {
+      const { stdout } = await run("xdotool", ["getactivewindow"]);
+      args.push(stdout.trim());
+    } else {
+      args.push(arg);
+    }
+  }
+  return { command: cmd.command, args };
+}
+
+function isMissingCommand(err: unknown) {
+  return (err as NodeJS.ErrnoException | undefined)?.code === "ENOENT";
+}
+
+export function createScreenshotTool(options?: {
+  platform?: NodeJS.Platform;
+  run?: CommandRunner;
+}): AnyAgentTool {
+  const platform = options?.platform ?? process.platform;
+  const run: CommandRunner =
+    options?.run ??
+    ((command, args) => runExec(command, args, { timeoutMs: CAPTURE_TIMEOUT_MS }));
+  return {
+    label: "Screenshot",
+    name: "screenshot",
+    description:
+      "Capture the screen (or the focused window) of the machine running OpenClaw and return it as an image. Use when the user asks you to look at what is on their screen.",
+    parameters: ScreenshotSchema,
+    execute: async (_toolCallId, args) => {
+      const params = args as Record<string, unknown>;
+      const targetRaw = readStringParam(params, "target") ?? "screen";
+      if (!SCREENSHOT_TARGETS.includes(targetRaw as ScreenshotTarget)) {
+        throw new ToolInputError('target must be "screen" or "window"');
+      }
+      const target = targetRaw as ScreenshotTarget;
+      const display = readNumberParam(params, "display", { integer: true });
+      const outPath = path.join(os.tmpdir(), `openclaw-screenshot-${crypto.randomUUID()}.png`);
+      const commands = resolveScreenshotCommands({ platform, target, display, outPath });
+      if (commands.length === 0) {
+        throw new Error(`screenshot: ${target} capture is not supported on ${platform}`);
+      }
+
+      // The image is returned inline, so the capture file is never needed after this call.
+      try {
+        const missing: string[] = [];
+        let captured = false;
+        for (const candidate of commands) {
+          try {
+            const resolved = await resolvePlaceholders(candidate, run);
+            await run(resolved.command, resolved.args);
+            captured = await fs
+              .stat(outPath)
+              .then((stat) => stat.size > 0)
+              .catch(() => false);
+            if (captured) {
+              break;
+            }
+          } catch (err) {
+            if (!isMissingCommand(err)) {
+              throw new Error(
+                `screenshot: ${candidate.command} failed: ${String((err as Error)?.message ?? err)}`,
+                { cause: err },
+              );
+            }
+            missing.push(candidate.command);
+          }
+        }
+        if (!captured) {
+          throw new Error(
+            missing.length === commands.length
+              ? `screenshot: no capture tool found (tried ${missing.join(", ")})`
+              : "screenshot: capture produced no image (check screen recording permissions)",
+          );
+        }
+
+        const meta = await getImageMetadata(await fs.readFile(outPath)).catch(() => null);
+        const size = meta ? ` (${meta.width}x${meta.height})` : "";
+        return await imageResultFromFile({
+          label: "screenshot",
+          path: outPath,
+          extraText: `Screenshot of the ${target}${size}`,
+          details: { target, ...(display ? { display } : {}), ...(meta ?? {}) },
+        });
+      } finally {
+        await fs.rm(outPath, { force: true });
+      }
+    },
+  };
+}
@@ -0,0 +1,69 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, describe, expect, it } from "vitest";
+import { loadSessionStore } from "../../config/sessions.js";
+import { createTodoWriteTool, formatTodoList } from "./todo-tool.js";
+
+describe("todo_write", () => {
+  let tmpDir: string | undefined;
+
+  afterEach(async () => {
+    if (tmpDir) {
+      await fs.rm(tmpDir, { recursive: true, force: true });
+      tmpDir = undefined;
+    }
+  });
+
+  it("persists the list on the session entry and renders it", async () => {
+    tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-todos-"));
+    const storePath = path.join(tmpDir, "sessions.json");
+    const sessionKey = "agent:main:main";
+    await fs.writeFile(
+      storePath,
+      JSON.stringify({ [sessionKey]: { sessionId: "s1", updatedAt: Date.now() } }),
+    );
+    const tool = createTodoWriteTool({
+      agentSessionKey: sessionKey,
+      config: { session: { store: storePath } },
+    });
+
+    const result = await tool.execute("call", {
+      todos: [
+        { content: "Read the failing test", status: "completed" },
+        { content: "Fix the parser", status: "in_progress", activeForm: "Fixing the parser" },
+        { content: "Run the suite", status: "pending" },
+      ],
+    });
+
+    expect(result.details).toMatchObject({ ok: true, persisted: true, total: 3, completed: 1 });
+    expect((result.content[0] as { text: string }).text).toBe(
+      "📋 Todos (1/3 done)\n✅ Read the failing test\n🔄 Fixing the parser\n⬜ Run the suite",
+    );
+    const entry = loadSessionStore(storePath, { skipCache: true })[sessionKey];
+    expect(entry?.todos?.map((item) => item.status)).toEqual([
+      "completed",
+      "in_progress",
+      "pending",
+    ]);
+  });
+
+  it("rejects invalid lists", async () => {
+    const tool = createTodoWriteTool();
+    await expect(
+      tool.execute("call", {
+        todos: [
+          { content: "a", status: "in_progress" },
+          { content: "b", status: "in_progress" },
+        ],
+      }),
+    ).rejects.toThrow(/only one todo/);
+    await expect(
+      tool.execute("call", { todos: [{ content: "a", status: "done" }] }),
+    ).rejects.toThrow(/status must be one of/);
+    await expect(
+      tool.execute("call", { todos: [{ content: " ", status: "pending" }] }),
+    ).rejects.toThrow(/content required/);
+    expect(formatTodoList(undefined)).toBe("No todos.");
+  });
+});
@@ -0,0 +1,127 @@
+import { Type } from "@sinclair/typebox";
+import type { OpenClawConfig } from "../../config/config.js";
+import type { AnyAgentTool } from "./common.js";
+import { loadConfig } from "../../config/config.js";
+import {
+  resolveStorePath,
+  type SessionTodoItem,
+  type SessionTodoStatus,
+  updateSessionStoreEntry,
+} from "../../config/sessions.js";
+import { resolveAgentIdFromSessionKey } from "../../routing/session-key.js";
+import { stringEnum } from "../schema/typebox.js";
+import { ToolInputError } from "./common.js";
+
+const TODO_STATUSES = ["pending", "in_progress", "completed"] as const;
+const MAX_TODO_ITEMS = 50;
+const MAX_TODO_CHARS = 500;
+
+const TODO_STATUS_ICONS: Record<SessionTodoStatus, string> = {
+  pending: "⬜",
+  in_progress: "🔄",
+  completed: "✅",
+};
+
+const TodoWriteSchema = Type.Object({
+  todos: Type.Array(
+    Type.Object({
+      content: Type.String({ description: "Imperative description, e.g. 'Run the tests'." }),
+      status: stringEnum(TODO_STATUSES),
+      activeForm: Type.Optional(
+        Type.String({
+          description: "Present-tense label shown while in progress, e.g. 'Running tests'.",
+        }),
+      ),
+    }),
+    { description: "The complete task list; replaces the previous list." },
+  ),
+});
+
+export function normalizeTodoItems(raw: unknown): SessionTodoItem[] {
+  if (!Array.isArray(raw)) {
+    throw new ToolInputError("todos must be an array");
+  }
+  if (raw.length > MAX_TODO_ITEMS) {
+    throw new ToolInputError(`todos supports at most ${MAX_TODO_ITEMS} items`);
+  }
+  const todos = raw.map((value, index) => {
+    const item = (value ?? {}) as Record<string, unknown>;
+    const content = typeof item.content === "string" ? item.content.trim() : "";
+    if (!content) {
+      throw new ToolInputError(`todos[${index}].content required`);
+    }
+    const status = item.status;
+    if (!TODO_STATUSES.includes(status as SessionTodoStatus)) {
+      throw new ToolInputError(
+        `todos[${index}].status must be one of ${TODO_STATUSES.join(", ")}`,
+      );
+    }
+    const activeForm = typeof item.activeForm === "string" ? item.activeForm.trim() : "";
+    return {
+      content: content.slice(0, MAX_TODO_CHARS),
+      status: status as SessionTodoStatus,
+      ...(activeForm ? { activeForm: activeForm.slice(0, MAX_TODO_CHARS) } : {}),
+    };
+  });
+  if (todos.filter((item) => item.status === "in_progress").length > 1) {
+    throw new ToolInputError("only one todo can be in_progress at a time");
+  }
+  return todos;
+}
+
+export function formatTodoList(todos: SessionTodoItem[] | undefined): string {
+  if (!todos || todos.length === 0) {
+    return "No todos.";
+  }
+  const done = todos.filter((item) => item.status === "completed").length;
+  const lines = [`📋 Todos (${done}/${todos.length} done)`];
+  for (const item of todos) {
+    const label = item.status === "in_progress" && item.activeForm ? item.activeForm : item.content;
+    lines.push(`${TODO_STATUS_ICONS[item.status]} ${label}`);
+  }
+  return lines.join("\n");
+}
+
+export function createTodoWriteTool(opts?: {
+  agentSessionKey?: string;
+  config?: OpenClawConfig;
+}): AnyAgentTool {
+  return {
+    label: "Todo Write",
+    name: "todo_write",
+    description:
+      "Maintain a structured task list for multi-step work. Send the full list each time (it replaces the previous one); keep exactly one item in_progress while working and mark items completed as soon as they are done. Skip for trivial single-step requests.",
+    parameters: TodoWriteSchema,
+    execute: async (_toolCallId, args) => {
+      const params = args as Record<string, unknown>;
+      const todos = normalizeTodoItems(params.todos);
+
+      // Runs without a session (e.g. one-off CLI invocations) still get the rendered list back.
+      let persisted = false;
+      const sessionKey = opts?.agentSessionKey?.trim();
+      if (sessionKey) {
+        const cfg = opts?.config ?? loadConfig();
+        const storePath = resolveStorePath(cfg.session?.store, {
+          agentId: resolveAgentIdFromSessionKey(sessionKey),
+        });
+        const updated = await updateSessionStoreEntry({
+          storePath,
+          sessionKey,
+          update: async () => ({ todos }),
+        });
+        persisted = Boolean(updated);
+      }
+
+      return {
+        content: [{ type: "text", text: formatTodoList(todos) }],
+        details: {
+          ok: true,
+          persisted,
+          total: todos.length,
+          completed: todos.filter((item) => item.status === "completed").length,
+          todos,
+        },
+      };
+    },
+  };
+}
@@ -0,0 +1,96 @@
+import { createSubsystemLogger } from "../../logging/subsystem.js";
+import { runCommandWithTimeout } from "../../process/exec.js";
+import { isPlainObject } from "../../utils.js";
+
+const log = createSubsystemLogger("agents/tools");
+
+const SCHEMA_TIMEOUT_MS = 10_000;
+const TOOL_NAME_PATTERN = /^[a-zA-Z][a-zA-Z0-9_-]{0,63}$/;
+
+export type ExternalToolDefinition = {
+  name: string;
+  description: string;
+  parameters: Record<string, unknown>;
+  executable: string;
+};
+
+type CachedDefinition = {
+  mtimeMs: number;
+  size: number;
+  definition: Promise<ExternalToolDefinition | null>;
+};
+
+// The schema command is only re-run when the file changes on disk; concurrent loads share one run.
+const definitionCache = new Map<string, CachedDefinition>();
+
+export function parseExternalToolSchema(
+  raw: string,
+  executable: string,
+): ExternalToolDefinition | null {
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(raw.trim());
+  } catch {
+    return null;
+  }
+  if (!isPlainObject(parsed)) {
+    return null;
+  }
+  const name = typeof parsed.name === "string" ? parsed.name.trim() : "";
+  if (!TOOL_NAME_PATTERN.test(name)) {
+    return null;
+  }
+  const description =
+    typeof parsed.description === "string" && parsed.description.trim()
+      ? parsed.description.trim()
+      : `External tool ${name}.`;
+  const parameters =
+    isPlainObject(parsed.parameters) && parsed.parameters.type === "object"
+      ? parsed.parameters
+      : { type: "object", properties: {} };
+  return { name, description, parameters, executable };
+}
+
+async function runSchemaCommand(
+  argv: string[],
+  file: string,
+  kind: string,
+): Promise<ExternalToolDefinition | null> {
+  // Empty stdin so a tool that reads it during `--schema` does not wait on the terminal.
+  const result = await runCommandWithTimeout(argv, { timeoutMs: SCHEMA_TIMEOUT_MS, input: "" });
+  const definition =
+    result.code === 0 && !result.killed ? parseExternalToolSchema(result.stdout, file) : null;
+  if (!definition) {
+    const reason = result.killed ? "timed out" : `exit ${String(result.code)}`;
+    log.warn(`${kind} tool skipped (invalid --schema output): ${file} (${reason})`);
+  }
+  return definition;
+}
+
+/**
+ * Runs `argv` to read the tool definition that `file` declares, caching the result by the file's
+ * mtime and size. Spawn failures (e.g. a missing runtime) reject and are not cached.
+ */
+export async function loadToolSchema(params: {
+  file: string;
+  stat: { mtimeMs: number; size: number };
+  argv: string[];
+  kind: string;
+}): Promise<ExternalToolDefinition | null> {
+  const key = params.argv.join("\0");
+  const cached = definitionCache.get(key);
+  if (cached && cached.mtimeMs === params.stat.mtimeMs && cached.size === params.stat.size) {
+    return await cached.definition;
+  }
+  const definition = runSchemaCommand(params.argv, params.file, params.kind);
+  const entry = { mtimeMs: params.stat.mtimeMs, size: params.stat.size, definition };
+  definitionCache.set(key, entry);
+  try {
+    return await definition;
+  } catch (err) {
+    if (definitionCache.get(key) === entry) {
+      definitionCache.delete(key);
+    }
+    throw err;
+  }
+}
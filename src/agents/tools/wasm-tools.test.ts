@@ -0,0 +1,59 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import { buildWasmRunArgs, createWasmTools, refreshWasmTools } from "./wasm-tools.js";
+
+describe("wasm tools", () => {
+  let dir: string;
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-wasm-tools-"));
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  it("grants only the configured directories and network", () => {
+    expect(buildWasmRunArgs({ module: "/t/tool.wasm", entry: { module: "tool.wasm" } })).toEqual([
+      "run",
+      "/t/tool.wasm",
+    ]);
+    expect(
+      buildWasmRunArgs({
+        module: "/t/tool.wasm",
+        entry: { module: "tool.wasm", workspaceAccess: true, dirs: ["/data"], network: true },
+        workspaceDir: "/home/me/ws",
+      }),
+    ).toEqual([
+      "run",
+      "--dir",
+      "/home/me/ws::/workspace",
+      "--dir",
+      "/data::/data",
+      "-S",
+      "inherit-network=y",
+      "-S",
+      "allow-ip-name-lookup=y",
+      "/t/tool.wasm",
+    ]);
+  });
+
+  it("skips modules when the runtime is unavailable or the session is sandboxed", async () => {
+    const module = path.join(dir, "tool.wasm");
+    await fs.writeFile(module, "\0asm");
+    const config = {
+      tools: {
+        wasm: { runtime: path.join(dir, "missing-wasmtime"), tools: [{ module }] },
+      },
+    } as OpenClawConfig;
+    await refreshWasmTools(config);
+    expect(createWasmTools({ config })).toEqual([]);
+    expect(createWasmTools({ config, sandboxed: true })).toEqual([]);
+    const missing = { tools: { wasm: { tools: [{ module: "/nope.wasm" }] } } } as OpenClawConfig;
+    await refreshWasmTools(missing);
+    expect(createWasmTools({ config: missing })).toEqual([]);
+  });
+});
@@ -0,0 +1,167 @@
+import fs from "node:fs/promises";
+import type { OpenClawConfig } from "../../config/config.js";
+import type { AnyAgentTool } from "./common.js";
+import { createSubsystemLogger } from "../../logging/subsystem.js";
+import { runCommandWithTimeout } from "../../process/exec.js";
+import { resolveUserPath } from "../../utils.js";
+import { parseExternalToolOutput } from "./external-tools.js";
+import { type ExternalToolDefinition, loadToolSchema } from "./tool-schema-loader.js";
+
+const log = createSubsystemLogger("agents/tools");
+
+const DEFAULT_RUNTIME = "wasmtime";
+const DEFAULT_TIMEOUT_SECONDS = 60;
+const WORKSPACE_GUEST_DIR = "/workspace";
+
+type WasmToolConfig = NonNullable<
+  NonNullable<NonNullable<OpenClawConfig["tools"]>["wasm"]>["tools"]
+>[number];
+
+// Definitions from the last refresh, keyed by resolved module path.
+const loadedModules = new Map<string, ExternalToolDefinition | null>();
+let missingRuntimeLogged = false;
+
+/**
+ * Capability flags for one run: only the granted directories are visible to the guest, and
+ * sockets stay disabled unless the tool is granted network access.
+ */
+export function buildWasmRunArgs(params: {
+  module: string;
+  entry: WasmToolConfig;
+  workspaceDir?: string;
+}): string[] {
+  const args = ["run"];
+  if (params.entry.workspaceAccess && params.workspaceDir) {
+    args.push("--dir", `${params.workspaceDir}::${WORKSPACE_GUEST_DIR}`);
+  }
+  for (const dir of params.entry.dirs ?? []) {
+    const resolved = resolveUserPath(dir);
+    args.push("--dir", `${resolved}::${resolved}`);
+  }
+  if (params.entry.network) {
+    args.push("-S", "inherit-network=y", "-S", "allow-ip-name-lookup=y");
+  }
+  args.push(params.module);
+  return args;
+}
+
+async function loadModule(runtime: string, module: string): Promise<ExternalToolDefinition | null> {
+  let stat: { mtimeMs: number; size: number };
+  try {
+    stat = await fs.stat(module);
+  } catch {
+    log.warn(`wasm tool skipped (module not found): ${module}`);
+    return null;
+  }
+  try {
+    // Schema discovery runs with no grants at all.
+    return await loadToolSchema({
+      file: module,
+      stat,
+      argv: [runtime, "run", module, "--schema"],
+      kind: "wasm",
+    });
+  } catch (err) {
+    if ((err as NodeJS.ErrnoException).code === "ENOENT") {
+      if (!missingRuntimeLogged) {
+        missingRuntimeLogged = true;
+        log.warn(`wasm tools skipped: runtime not found (${runtime}); install wasmtime`);
+      }
+    } else {
+      log.warn(`wasm tool skipped (--schema failed): ${module} (${String(err)})`);
+    }
+    return null;
+  }
+}
+
+/** Re-reads the schema of every configured module; `createWasmTools` serves the last result. */
+export async function refreshWasmTools(config?: OpenClawConfig): Promise<void> {
+  const wasm = config?.tools?.wasm;
+  if (wasm?.enabled === false) {
+    return;
+  }
+  const runtime = wasm?.runtime?.trim() || DEFAULT_RUNTIME;
+  const modules = (wasm?.tools ?? []).map((entry) => resolveUserPath(entry.module));
+  const definitions = await Promise.all(modules.map((module) => loadModule(runtime, module)));
+  modules.forEach((module, index) => loadedModules.set(module, definitions[index]));
+}
+
+function createWasmTool(params: {
+  definition: ExternalToolDefinition;
+  entry: WasmToolConfig;
+  runtime: string;
+  timeoutMs: number;
+  workspaceDir?: string;
+}): AnyAgentTool {
+  const { definition, entry, runtime, timeoutMs } = params;
+  const name = entry.name?.trim() || definition.name;
+  return {
+    label: name,
+    name,
+    description: entry.description?.trim() || definition.description,
+    parameters: definition.parameters,
+    execute: async (toolCallId, args) => {
+      const runArgs = buildWasmRunArgs({
+        module: definition.executable,
+        entry,
+        workspaceDir: params.workspaceDir,
+      });
+      const result = await runCommandWithTimeout([runtime, ...runArgs], {
+        timeoutMs,
+        input: JSON.stringify({ tool: name, toolCallId, params: args ?? {} }),
+      });
+      if (result.killed) {
+        throw new Error(`${name} timed out after ${Math.round(timeoutMs / 1000)}s`);
+      }
+      if (result.code !== 0) {
+        const stderr = result.stderr.trim().slice(0, 2000);
+        throw new Error(
+          `${name} exited with code ${String(result.code)}${stderr ? `: ${stderr}` : ""}`,
+        );
+      }
+      return parseExternalToolOutput(result.stdout);
+    },
+  } as AnyAgentTool;
+}
+
+/**
+ * Wraps the WASI modules/components listed in `tools.wasm.tools` (as last read by
+ * `refreshWasmTools`) and runs each call under wasmtime with only the filesystem/network grants
+ * configured for that tool.
+ */
+export function createWasmTools(options: {
+  config?: OpenClawConfig;
+  workspaceDir?: string;
+  sandboxed?: boolean;
+  existingToolNames?: Set<string>;
+}): AnyAgentTool[] {
+  const wasm = options.config?.tools?.wasm;
+  const entries = wasm?.tools ?? [];
+  if (wasm?.enabled === false || entries.length === 0 || options.sandboxed) {
+    return [];
+  }
+  const runtime = wasm?.runtime?.trim() || DEFAULT_RUNTIME;
+  const timeoutMs = (wasm?.timeoutSeconds ?? DEFAULT_TIMEOUT_SECONDS) * 1000;
+  const names = new Set(options.existingToolNames);
+  const tools: AnyAgentTool[] = [];
+  for (const entry of entries) {
+    const definition = loadedModules.get(resolveUserPath(entry.module));
+    if (!definition) {
+      continue;
+    }
+    const tool = createWasmTool({
+      definition,
+      entry,
+      runtime,
+      timeoutMs,
+      workspaceDir: options.workspaceDir,
+    });
+    if (names.has(tool.name)) {
+      log.warn(`wasm tool skipped (name conflicts with another tool): ${tool.name}`);
+      continue;
+    }
+    names.add(tool.name);
+    tools.push(tool);
+  }
+  return tools;
+}
@@ -0,0 +1,102 @@
+// Content-type triage for web_fetch: images go back to the model as image blocks, other binaries
+// are summarized instead of being decoded into megabytes of lossy UTF-8.
+
+export type WebFetchContentKind = "text" | "image" | "binary" | "unknown";
+
+/** Image formats the model providers accept directly; other image types are treated as binary. */
+const MODEL_IMAGE_TYPES = new Set(["image/png", "image/jpeg", "image/gif", "image/webp"]);
+
+const TEXT_TYPE_HINTS = [
+  "json",
+  "xml",
+  "javascript",
+  "ecmascript",
+  "yaml",
+  "toml",
+  "csv",
+  "x-www-form-urlencoded",
+  "graphql",
+];
+
+export function classifyWebFetchContentType(contentType?: string): WebFetchContentKind {
+  const type = contentType?.split(";")[0]?.trim().toLowerCase();
+  if (!type || type === "application/octet-stream" || type === "binary/octet-stream") {
+    return "unknown";
+  }
+  if (type.startsWith("text/") || TEXT_TYPE_HINTS.some((hint) => type.includes(hint))) {
+    return "text";
+  }
+  if (MODEL_IMAGE_TYPES.has(type === "image/jpg" ? "image/jpeg" : type)) {
+    return "image";
+  }
+  return "binary";
+}
+
+/** NUL bytes in the first few KB are a reliable sign the payload is not text. */
+export function looksBinary(buffer: Buffer): boolean {
+  return buffer.subarray(0, 8192).includes(0);
+}
+
+/**
+ * Read at most `maxBytes` of a response body. Unlike the media readers this never throws on
+ * overflow: it cancels the stream and reports `complete: false` so callers can summarize instead.
+ */
+export async function readResponseBytes(
+  res: Response,
+  maxBytes: number,
+): Promise<{ buffer: Buffer; complete: boolean }> {
+  const body = res.body;
+  if (!body || typeof body.getReader !== "function") {
+    const buffer = Buffer.from(await res.arrayBuffer());
+    return buffer.length > maxBytes
+      ? { buffer: buffer.subarray(0, maxBytes), complete: false }
+      : { buffer, complete: true };
+  }
+  const reader = body.getReader();
+  const chunks: Buffer[] = [];
+  let total = 0;
+  let complete = true;
+  try {
+    while (true) {
+      const { done, value } = await reader.read();
+      if (done) {
+        break;
+      }
+      if (!value?.length) {
+        continue;
+      }
+      if (total + value.length > maxBytes) {
+        chunks.push(Buffer.from(value).subarray(0, maxBytes - total));
+        total = maxBytes;
+        complete = false;
+        await reader.cancel().catch(() => {});
+        break;
+      }
+      chunks.push(Buffer.from(value));
+      total += value.length;
+    }
+  } finally {
+    try {
+      reader.releaseLock();
+    } catch {}
+  }
+  return { buffer: Buffer.concat(chunks, total), complete };
+}
+
+/** Filename from a Content-Disposition header, if the server sent one. */
+export function parseContentDispositionFilename(header?: string | null): string | undefined {
+  if (!header) {
+    return undefined;
+  }
+  const encoded = header.match(/filename\*\s*=\s*(?:[\w-]+'[^']*')?([^;]+)/i)?.[1];
+  if (encoded) {
+    try {
+      return decodeURIComponent(encoded.trim().replace(/^"|"$/g, ""));
+    } catch {
+      // Fall through to the plain filename parameter.
+    }
+  }
+  const plain = header.match(/filename\s*=\s*("([^"]*)"|[^;]+)/i);
+  const name = (plain?.[2] ?? plain?.[1])?.trim();
+  return name || undefined;
+}
@@ -0,0 +1,84 @@
+import crypto from "node:crypto";
+import fs from "node:fs/promises";
+import path from "node:path";
+import { resolveStateDir } from "../../config/paths.js";
+
+const MAX_DISK_ENTRIES = 500;
+
+export type WebFetchDiskCacheEntry = {
+  key: string;
+  url: string;
+  /** Validators from the origin response, used to revalidate once the entry expires. */
+  etag?: string;
+  lastModified?: string;
+  storedAt: number;
+  expiresAt: number;
+  payload: Record<string, unknown>;
+};
+
+export function resolveWebFetchCacheDir(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "cache", "web-fetch");
+}
+
+function entryPath(dir: string, key: string): string {
+  return path.join(dir, `${crypto.createHash("sha256").update(key).digest("hex")}.json`);
+}
+
+/**
+ * Read a cached web_fetch payload. Expired entries are still returned when they carry an ETag or
+ * Last-Modified validator so the caller can revalidate; otherwise they are dropped.
+ */
+export async function readWebFetchDiskCache(
+  key: string,
+  opts?: { dir?: string },
+): Promise<WebFetchDiskCacheEntry | null> {
+  const file = entryPath(opts?.dir ?? resolveWebFetchCacheDir(), key);
+  let entry: WebFetchDiskCacheEntry;
+  try {
+    entry = JSON.parse(await fs.readFile(file, "utf8")) as WebFetchDiskCacheEntry;
+  } catch {
+    return null;
+  }
+  if (entry?.key !== key || !entry.payload || typeof entry.expiresAt !== "number") {
+    return null;
+  }
+  if (Date.now() > entry.expiresAt && !entry.etag && !entry.lastModified) {
+    await fs.rm(file, { force: true }).catch(() => {});
+    return null;
+  }
+  return entry;
+}
+
+export async function writeWebFetchDiskCache(
+  entry: WebFetchDiskCacheEntry,
+  opts?: { dir?: string },
+): Promise<void> {
+  const dir = opts?.dir ?? resolveWebFetchCacheDir();
+  const file = entryPath(dir, entry.key);
+  try {
+    await fs.mkdir(dir, { recursive: true });
+    const tmp = `${file}.${process.pid}.${crypto.randomUUID()}.tmp`;
+    await fs.writeFile(tmp, JSON.stringify(entry), "utf8");
+    await fs.rename(tmp, file);
+    await pruneWebFetchDiskCache(dir);
+  } catch {
+    // The disk cache is an optimization; never fail a fetch because it could not be written.
+  }
+}
+
+async function pruneWebFetchDiskCache(dir: string) {
+  const names = (await fs.readdir(dir)).filter((name) => name.endsWith(".json"));
+  if (names.length <= MAX_DISK_ENTRIES) {
+    return;
+  }
+  const stats = await Promise.all(
+    names.map(async (name) => {
+      const file = path.join(dir, name);
+      const stat = await fs.stat(file).catch(() => null);
+      return { file, mtimeMs: stat?.mtimeMs ?? 0 };
+    }),
+  );
+  stats.sort((a, b) => a.mtimeMs - b.mtimeMs);
+  const excess = stats.slice(0, stats.length - MAX_DISK_ENTRIES);
+  await Promise.all(excess.map(({ file }) => fs.rm(file, { force: true }).catch(() => {})));
+}
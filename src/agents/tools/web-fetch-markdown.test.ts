@@ -0,0 +1,85 @@
+import { parseHTML } from "linkedom";
+import { describe, expect, it } from "vitest";
+import { domToMarkdown } from "./web-fetch-markdown.js";
+import { extractReadableContent } from "./web-fetch-utils.js";
+
+function render(html: string) {
+  const { document } = parseHTML(`<!doctype html><html><body>${html}</body></html>`);
+  return domToMarkdown(document.body as unknown as Node, {
+    baseUrl: "https://docs.example.com/guide/",
+  });
+}
+
+describe("domToMarkdown", () => {
+  it("keeps headings, inline formatting, and resolves links", () => {
+    expect(
+      render(
+        `<h2>Install <em>now</em></h2>
+         <p>See <a href="../api">the API</a>, run <code>npm i</code>, and <strong>restart</strong>.</p>`,
+      ),
+    ).toBe(
+      "## Install _now_\n\n" +
+        "See [the API](https://docs.example.com/api), run `npm i`, and **restart**.",
+    );
+  });
+
+  it("renders fenced code blocks with their language and whitespace intact", () => {
+    expect(
+      render(`<pre><code class="language-python">def f():
+    return 1
+
+
+print(f())
+</code></pre>`),
+    ).toBe("```python\ndef f():\n    return 1\n\n\nprint(f())\n```");
+  });
+
+  it("renders nested lists", () => {
+    expect(
+      render(`<ul>
+        <li>Setup</li>
+        <li>Run
+          <ol><li>build</li><li>test</li></ol>
+        </li>
+      </ul>`),
+    ).toBe("- Setup\n- Run\n  1. build\n  2. test");
+  });
+
+  it("renders tables as GFM tables", () => {
+    expect(
+      render(`<table>
+        <thead><tr><th>Option</th><th>Default</th></tr></thead>
+        <tbody><tr><td><code>mode</code></td><td>a|b</td></tr></tbody>
+      </table>`),
+    ).toBe("| Option | Default |\n| --- | --- |\n| `mode` | a\\|b |");
+  });
+
+  it("drops scripts and styles", () => {
+    expect(render("<p>Hi</p><script>alert(1)</script><style>p{}</style>")).toBe("Hi");
+  });
+});
+
+describe("extractReadableContent markdown", () => {
+  it("converts the readable article with code blocks and tables", async () => {
+    const html = `<!doctype html><html><head><title>Docs</title></head><body>
+      <nav><a href="/">Home</a></nav>
+      <article>
+        <h1>Config</h1>
+        <p>Configuration lives in a single file. Every option below is optional and documented
+        with its default so you can start small and add settings when you need them.</p>
+        <pre><code class="language-json">{
+  "port": 8080
+}</code></pre>
+        <table><tr><th>Key</th><th>Type</th></tr><tr><td>port</td><td>number</td></tr></table>
+      </article>
+    </body></html>`;
+    const result = await extractReadableContent({
+      html,
+      url: "https://docs.example.com/config",
+      extractMode: "markdown",
+    });
+    expect(result?.text).toContain('```json\n{\n  "port": 8080\n}\n```');
+    expect(result?.text).toContain("| Key | Type |\n| --- | --- |\n| port | number |");
+    expect(result?.text).not.toContain("Home");
+  });
+});
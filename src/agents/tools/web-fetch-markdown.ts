@@ -0,0 +1,315 @@
+// DOM → Markdown for web_fetch. Works on linkedom (or browser) nodes; keeps headings, links,
+// emphasis, lists, blockquotes, fenced code blocks (with language), and GFM tables.
+
+const TEXT_NODE = 3;
+const ELEMENT_NODE = 1;
+
+// Private-use sentinels survive whitespace cleanup: INDENT becomes a space, CODE wraps the index
+// of a fenced block that is spliced back in last so its whitespace is left alone.
+const INDENT = "\uE000";
+const CODE = "\uE001";
+
+const SKIP_TAGS = new Set([
+  "script",
+  "style",
+  "noscript",
+  "template",
+  "svg",
+  "canvas",
+  "iframe",
+  "object",
+  "button",
+  "input",
+  "select",
+  "textarea",
+  "head",
+]);
+
+const BLOCK_TAGS = new Set([
+  "address",
+  "article",
+  "aside",
+  "body",
+  "center",
+  "details",
+  "div",
+  "fieldset",
+  "figcaption",
+  "figure",
+  "footer",
+  "form",
+  "header",
+  "html",
+  "main",
+  "nav",
+  "p",
+  "section",
+  "summary",
+]);
+
+/** Page chrome dropped when Readability finds no article and the whole page is converted. */
+export const BOILERPLATE_SELECTOR = "script,style,noscript,template,nav,header,footer,aside,form";
+
+type MarkdownContext = {
+  baseUrl?: string;
+  codeBlocks: string[];
+};
+
+function block(text: string): string {
+  const trimmed = text.trim();
+  return trimmed ? `\n\n${trimmed}\n\n` : "";
+}
+
+function singleLine(text: string): string {
+  return text.replace(/\s*\n+\s*/g, " ").trim();
+}
+
+function resolveUrl(href: string, baseUrl?: string): string {
+  try {
+    return new URL(href, baseUrl).toString();
+  } catch {
+    return href;
+  }
+}
+
+function longestRun(text: string, char: string): number {
+  let longest = 0;
+  let current = 0;
+  for (const c of text) {
+    current = c === char ? current + 1 : 0;
+    longest = Math.max(longest, current);
+  }
+  return longest;
+}
+
+function inlineCode(text: string): string {
+  const collapsed = text.replace(/\s+/g, " ");
+  if (!collapsed.trim()) {
+    return "";
+  }
+  const fence = "`".repeat(longestRun(collapsed, "`") + 1);
+  const pad = collapsed.startsWith("`") || collapsed.endsWith("`") ? " " : "";
+  return `${fence}${pad}${collapsed}${pad}${fence}`;
+}
+
+function tagOf(node: Node): string {
+  return (node as Element).tagName?.toLowerCase() ?? "";
+}
+
+function codeLanguage(pre: Element): string {
+  const code = Array.from(pre.childNodes).find((child) => tagOf(child) === "code") as
+    | Element
+    | undefined;
+  const classes = `${pre.getAttribute("class") ?? ""} ${code?.getAttribute("class") ?? ""}`;
+  return classes.match(/(?:^|\s)(?:language|lang)-([\w+#.-]+)/)?.[1] ?? "";
+}
+
+function renderChildren(node: Node, ctx: MarkdownContext): string {
+  let out = "";
+  for (const child of Array.from(node.childNodes)) {
+    out += renderNode(child, ctx);
+  }
+  return out;
+}
+
+function renderList(list: Element, ctx: MarkdownContext): string {
+  const ordered = tagOf(list) === "ol";
+  let counter = Number.parseInt(list.getAttribute("start") ?? "1", 10);
+  if (!Number.isFinite(counter)) {
+    counter = 1;
+  }
+  const items: string[] = [];
+  for (const child of Array.from(list.childNodes)) {
+    if (child.nodeType !== ELEMENT_NODE) {
+      continue;
+    }
+    if (tagOf(child) !== "li") {
+      // Stray content (or a nested list) directly inside the list.
+      const rendered = renderNode(child, ctx).trim();
+      if (rendered) {
+        items.push(rendered);
+      }
+      continue;
+    }
+    const marker = ordered ? `${counter++}. ` : "- ";
+    items.push(renderListItem(child as Element, marker, ctx));
+  }
+  return block(items.join("\n"));
+}
+
+function renderListItem(item: Element, marker: string, ctx: MarkdownContext): string {
+  // Paragraphs inside an item become plain lines so they don't split the list into blocks.
+  const content = renderChildren(item, ctx).trim().replace(/\n{2,}/g, "\n");
+  const indent = INDENT.repeat(marker.length);
+  return content
+    .split("\n")
+    .map((line, idx) => (idx === 0 ? `${marker}${line}` : `${indent}${line}`))
+    .join("\n");
+}
+
+function renderTable(table: Element, ctx: MarkdownContext): string {
+  const rows: Element[] = [];
+  const collectRows = (parent: Element) => {
+    for (const child of Array.from(parent.childNodes)) {
+      const tag = tagOf(child);
+      if (tag === "tr") {
+        rows.push(child as Element);
+      } else if (tag === "thead" || tag === "tbody" || tag === "tfoot") {
+        collectRows(child as Element);
+      }
+    }
+  };
+  collectRows(table);
+
+  const grid = rows.map((row) => {
+    const cells: string[] = [];
+    for (const cell of Array.from(row.childNodes)) {
+      const tag = tagOf(cell);
+      if (tag !== "td" && tag !== "th") {
+        continue;
+      }
+      const text = singleLine(renderChildren(cell, ctx)).replace(/\|/g, "\\|");
+      const span = Number.parseInt((cell as Element).getAttribute("colspan") ?? "1", 10);
+      cells.push(text, ...Array.from({ length: Math.max(0, (span || 1) - 1) }, () => ""));
+    }
+    return cells;
+  });
+  const width = Math.max(0, ...grid.map((cells) => cells.length));
+  if (width === 0) {
+    return "";
+  }
+  const formatRow = (cells: string[]) =>
+    `| ${Array.from({ length: width }, (_, idx) => cells[idx] ?? "").join(" | ")} |`;
+  const [header, ...body] = grid;
+  return block(
+    [
+      formatRow(header),
+      `| ${Array.from({ length: width }, () => "---").join(" | ")} |`,
+      ...body.map(formatRow),
+    ].join("\n"),
+  );
+}
+
+function renderNode(node: Node, ctx: MarkdownContext): string {
+  if (node.nodeType === TEXT_NODE) {
+    return (node.textContent ?? "").replace(/\s+/g, " ");
+  }
+  if (node.nodeType !== ELEMENT_NODE) {
+    return "";
+  }
+  const el = node as Element;
+  const tag = tagOf(el);
+  if (SKIP_TAGS.has(tag)) {
+    return "";
+  }
+  switch (tag) {
+    case "h1":
+    case "h2":
+    case "h3":
+    case "h4":
+    case "h5":
+    case "h6": {
+      const text = singleLine(renderChildren(el, ctx));
+      return text ? block(`${"#".repeat(Number(tag[1]))} ${text}`) : "";
+    }
+    case "br":
+      return "\n";
+    case "hr":
+      return block("---");
+    case "a": {
+      const label = singleLine(renderChildren(el, ctx));
+      const href = el.getAttribute("href")?.trim();
+      if (!label || !href || href.startsWith("#") || /^javascript:/i.test(href)) {
+        return label;
+      }
+      return `[${label}](${resolveUrl(href, ctx.baseUrl)})`;
+    }
+    case "img": {
+      const alt = singleLine(el.getAttribute("alt") ?? "");
+      const src = el.getAttribute("src")?.trim();
+      if (!src || src.startsWith("data:")) {
+        return alt;
+      }
+      return `![${alt}](${resolveUrl(src, ctx.baseUrl)})`;
+    }
+    case "strong":
+    case "b": {
+      const text = renderChildren(el, ctx).trim();
+      return text ? `**${text}**` : "";
+    }
+    case "em":
+    case "i": {
+      const text = renderChildren(el, ctx).trim();
+      return text ? `_${text}_` : "";
+    }
+    case "del":
+    case "s": {
+      const text = renderChildren(el, ctx).trim();
+      return text ? `~~${text}~~` : "";
+    }
+    case "code":
+    case "kbd":
+    case "samp":
+      return inlineCode(el.textContent ?? "");
+    case "pre": {
+      const code = (el.textContent ?? "").replace(/^\n/, "").replace(/\s+$/, "");
+      const fence = "`".repeat(Math.max(3, longestRun(code, "`") + 1));
+      ctx.codeBlocks.push(`${fence}${codeLanguage(el)}\n${code}\n${fence}`);
+      return block(`${CODE}${ctx.codeBlocks.length - 1}${CODE}`);
+    }
+    case "ul":
+    case "ol":
+      return renderList(el, ctx);
+    case "li":
+      return block(renderListItem(el, "- ", ctx));
+    case "blockquote": {
+      const inner = renderChildren(el, ctx).trim().replace(/\n{3,}/g, "\n\n");
+      return block(
+        inner
+          .split("\n")
+          .map((line) => (line.trim() ? `> ${line}` : ">"))
+          .join("\n"),
+      );
+    }
+    case "table":
+      return renderTable(el, ctx);
+    case "dt":
+      return block(`**${singleLine(renderChildren(el, ctx))}**`);
+    case "dd":
+    case "dl":
+      return block(renderChildren(el, ctx));
+    default:
+      return BLOCK_TAGS.has(tag) ? block(renderChildren(el, ctx)) : renderChildren(el, ctx);
+  }
+}
+
+function finalize(markdown: string, codeBlocks: string[]): string {
+  const cleaned = markdown
+    .split("\n")
+    .map((line) =>
+      line
+        .replace(/^(\uE000*)[ \t]+/, "$1")
+        .replace(/[ \t]+$/, "")
+        .replace(/ {2,}/g, " "),
+    )
+    .join("\n")
+    .replaceAll(INDENT, " ")
+    .replace(/\n{3,}/g, "\n\n")
+    .trim();
+  return cleaned.replace(/^([ >]*)\uE001(\d+)\uE001$/gm, (_, prefix: string, idx: string) =>
+    (codeBlocks[Number(idx)] ?? "")
+      .split("\n")
+      .map((line) => `${prefix}${line}`.trimEnd())
+      .join("\n"),
+  );
+}
+
+/** Render a DOM subtree (typically a document body or Readability's article) as Markdown. */
+export function domToMarkdown(root: Node, options?: { baseUrl?: string }): string {
+  const ctx: MarkdownContext = { baseUrl: options?.baseUrl, codeBlocks: [] };
+  const rendered =
+    root.nodeType === ELEMENT_NODE || root.nodeType === TEXT_NODE
+      ? renderNode(root, ctx)
+      : renderChildren(root, ctx);
+  return finalize(rendered, ctx.codeBlocks);
+}
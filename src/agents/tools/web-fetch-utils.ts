@@ -1,3 +1,5 @@
+import { BOILERPLATE_SELECTOR, domToMarkdown } from "./web-fetch-markdown.js";
+
 export type ExtractMode = "markdown" | "text";
 
 let readabilityDepsPromise:
@@ -107,28 +109,69 @@ export function truncateText(
   return { text: value.slice(0, maxChars), truncated: true };
 }
 
+type ParsedDocument = ReturnType<typeof import("linkedom").parseHTML>["document"];
+
+function setBaseUri(document: ParsedDocument, url: string) {
+  try {
+    (document as { baseURI?: string }).baseURI = url;
+  } catch {
+    // Best-effort base URI for relative links.
+  }
+}
+
+/** Whole-page conversion for pages Readability can't identify an article in. */
+function renderPageMarkdown(
+  document: ParsedDocument,
+  url: string,
+): { text: string; title?: string } {
+  const rawTitle = document.querySelector("title")?.textContent ?? "";
+  const title = normalizeWhitespace(rawTitle) || undefined;
+  for (const el of Array.from(document.querySelectorAll(BOILERPLATE_SELECTOR))) {
+    el.remove();
+  }
+  const root =
+    document.querySelector("main") ??
+    document.querySelector("article") ??
+    document.body ??
+    document.documentElement;
+  const text = root ? domToMarkdown(root as unknown as Node, { baseUrl: url }) : "";
+  return { text, title };
+}
+
 export async function extractReadableContent(params: {
   html: string;
   url: string;
   extractMode: ExtractMode;
 }): Promise<{ text: string; title?: string } | null> {
-  const fallback = (): { text: string; title?: string } => {
-    const rendered = htmlToMarkdown(params.html);
+  const toMode = (rendered: { text: string; title?: string }) => {
     if (params.extractMode === "text") {
       const text = markdownToText(rendered.text) || normalizeWhitespace(stripTags(params.html));
       return { text, title: rendered.title };
     }
     return rendered;
   };
+  let deps: Awaited<ReturnType<typeof loadReadabilityDeps>>;
   try {
-    const { Readability, parseHTML } = await loadReadabilityDeps();
-    const { document } = parseHTML(params.html);
+    deps = await loadReadabilityDeps();
+  } catch {
+    return toMode(htmlToMarkdown(params.html));
+  }
+  const { Readability, parseHTML } = deps;
+  const fallback = () => {
     try {
-      (document as { baseURI?: string }).baseURI = params.url;
+      const { document } = parseHTML(params.html);
+      setBaseUri(document, params.url);
+      const rendered = renderPageMarkdown(document, params.url);
+      return toMode(rendered.text ? rendered : htmlToMarkdown(params.html));
     } catch {
-      // Best-effort base URI for relative links.
+      return toMode(htmlToMarkdown(params.html));
     }
-    const reader = new Readability(document, { charThreshold: 0 });
+  };
+  try {
+    const { document } = parseHTML(params.html);
+    setBaseUri(document, params.url);
+    // keepClasses preserves `language-*` hints on code blocks for the Markdown fences.
+    const reader = new Readability(document, { charThreshold: 0, keepClasses: true });
     const parsed = reader.parse();
     if (!parsed?.content) {
       return fallback();
@@ -138,8 +181,9 @@ export async function extractReadableContent(params: {
       const text = normalizeWhitespace(parsed.textContent ?? "");
       return text ? { text, title } : fallback();
     }
-    const rendered = htmlToMarkdown(parsed.content);
-    return { text: rendered.text, title: title ?? rendered.title };
+    const article = parseHTML(`<!doctype html><html><body>${parsed.content}</body></html>`);
+    const text = domToMarkdown(article.document.body as unknown as Node, { baseUrl: params.url });
+    return text ? { text, title } : fallback();
   } catch {
     return fallback();
   }
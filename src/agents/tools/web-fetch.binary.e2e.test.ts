@@ -0,0 +1,102 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import * as ssrf from "../../infra/net/ssrf.js";
+import { classifyWebFetchContentType, parseContentDispositionFilename } from "./web-fetch-binary.js";
+import { createWebFetchTool } from "./web-tools.js";
+
+// 1×1 transparent PNG.
+const PNG_1X1 = Buffer.from(
+  "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=",
+  "base64",
+);
+
+function createTool() {
+  return createWebFetchTool({
+    config: {
+      tools: { web: { fetch: { cacheTtlMinutes: 0, firecrawl: { enabled: false } } } },
+    },
+    sandboxed: false,
+  });
+}
+
+function stubFetch(response: Response) {
+  const mockFetch = vi.fn().mockResolvedValue(response);
+  // @ts-expect-error mock fetch
+  global.fetch = mockFetch;
+  return mockFetch;
+}
+
+describe("web_fetch binary content", () => {
+  const priorFetch = global.fetch;
+
+  beforeEach(() => {
+    vi.spyOn(ssrf, "resolvePinnedHostname").mockImplementation(async (hostname) => {
+      const normalized = hostname.trim().toLowerCase().replace(/\.$/, "");
+      const addresses = ["93.184.216.34"];
+      return {
+        hostname: normalized,
+        addresses,
+        lookup: ssrf.createPinnedLookup({ hostname: normalized, addresses }),
+      };
+    });
+  });
+
+  afterEach(() => {
+    // @ts-expect-error restore
+    global.fetch = priorFetch;
+    vi.restoreAllMocks();
+  });
+
+  it("returns images as image content blocks", async () => {
+    stubFetch(new Response(PNG_1X1, { headers: { "content-type": "image/png" } }));
+
+    const result = await createTool()?.execute?.("call", { url: "https://example.com/dot.png" });
+
+    const image = result?.content.find((block) => block.type === "image") as
+      | { data: string; mimeType: string }
+      | undefined;
+    expect(image?.mimeType).toBe("image/png");
+    expect(image?.data).toBeTruthy();
+    expect(result?.details).toMatchObject({ extractor: "image", bytes: PNG_1X1.length });
+    expect(result?.details).not.toHaveProperty("image");
+  });
+
+  it("summarizes other binaries without decoding them", async () => {
+    const pdf = Buffer.concat([Buffer.from("%PDF-1.7\n"), Buffer.alloc(2048, 0)]);
+    stubFetch(
+      new Response(pdf, {
+        headers: {
+          "content-type": "application/pdf",
+          "content-length": String(pdf.length),
+          "content-disposition": 'attachment; filename="report.pdf"',
+        },
+      }),
+    );
+
+    const result = await createTool()?.execute?.("call", { url: "https://example.com/report" });
+    const details = result?.details as { extractor: string; bytes: number; text: string };
+    expect(details).toMatchObject({ extractor: "binary", bytes: pdf.length });
+    expect(details.text).toContain("application/pdf, 2.0 KB");
+    expect(JSON.stringify(details)).toContain("report.pdf");
+  });
+
+  it("sniffs untyped responses", async () => {
+    stubFetch(new Response(Buffer.from([1, 0, 2, 0, 3])));
+    const binary = await createTool()?.execute?.("call", { url: "https://example.com/blob" });
+    expect(binary?.details).toMatchObject({ extractor: "binary", bytes: 5 });
+
+    stubFetch(new Response(Buffer.from("plain words")));
+    const text = await createTool()?.execute?.("call", { url: "https://example.com/notes" });
+    expect((text?.details as { text: string }).text).toContain("plain words");
+  });
+
+  it("classifies content types and parses download filenames", () => {
+    expect(classifyWebFetchContentType("image/svg+xml")).toBe("text");
+    expect(classifyWebFetchContentType("application/ld+json")).toBe("text");
+    expect(classifyWebFetchContentType("image/jpg")).toBe("image");
+    expect(classifyWebFetchContentType("image/tiff")).toBe("binary");
+    expect(classifyWebFetchContentType(undefined)).toBe("unknown");
+    expect(parseContentDispositionFilename("attachment; filename*=UTF-8''na%C3%AFve.zip")).toBe(
+      "naïve.zip",
+    );
+  });
+});
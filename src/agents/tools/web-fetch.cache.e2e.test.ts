@@ -0,0 +1,104 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import * as ssrf from "../../infra/net/ssrf.js";
+import { readWebFetchDiskCache, writeWebFetchDiskCache } from "./web-fetch-cache.js";
+import { createWebFetchTool } from "./web-tools.js";
+
+function makeHeaders(map: Record<string, string>): { get: (key: string) => string | null } {
+  return {
+    get: (key) => map[key.toLowerCase()] ?? null,
+  };
+}
+
+describe("web_fetch disk cache", () => {
+  const priorFetch = global.fetch;
+  let stateDir = "";
+
+  beforeEach(async () => {
+    stateDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-web-fetch-cache-"));
+    vi.stubEnv("OPENCLAW_STATE_DIR", stateDir);
+    vi.spyOn(ssrf, "resolvePinnedHostname").mockImplementation(async (hostname) => {
+      const normalized = hostname.trim().toLowerCase().replace(/\.$/, "");
+      const addresses = ["93.184.216.34"];
+      return {
+        hostname: normalized,
+        addresses,
+        lookup: ssrf.createPinnedLookup({ hostname: normalized, addresses }),
+      };
+    });
+  });
+
+  afterEach(async () => {
+    // @ts-expect-error restore
+    global.fetch = priorFetch;
+    vi.useRealTimers();
+    vi.unstubAllEnvs();
+    vi.restoreAllMocks();
+    await fs.rm(stateDir, { recursive: true, force: true });
+  });
+
+  it("drops expired entries unless they can be revalidated", async () => {
+    const dir = path.join(stateDir, "entries");
+    const base = { url: "https://example.com/", storedAt: 0, expiresAt: 1, payload: { text: "x" } };
+    await writeWebFetchDiskCache({ ...base, key: "plain" }, { dir });
+    await writeWebFetchDiskCache({ ...base, key: "etag", etag: '"v1"' }, { dir });
+    expect(await readWebFetchDiskCache("plain", { dir })).toBeNull();
+    expect(await readWebFetchDiskCache("etag", { dir })).toMatchObject({ etag: '"v1"' });
+  });
+
+  it("revalidates expired entries with If-None-Match and honors force", async () => {
+    vi.useFakeTimers({ toFake: ["Date"] });
+    const headersSeen: Array<Record<string, string>> = [];
+    const mockFetch = vi.fn((_input: RequestInfo, init?: RequestInit) => {
+      const headers = (init?.headers ?? {}) as Record<string, string>;
+      headersSeen.push(headers);
+      if (headers["If-None-Match"] === '"v1"') {
+        return Promise.resolve({
+          ok: false,
+          status: 304,
+          headers: makeHeaders({}),
+          text: async () => "",
+          url: "https://cache.example.com/page",
+        } as Response);
+      }
+      return Promise.resolve({
+        ok: true,
+        status: 200,
+        headers: makeHeaders({ "content-type": "text/plain", etag: '"v1"' }),
+        text: async () => "cached body",
+        url: "https://cache.example.com/page",
+      } as Response);
+    });
+    // @ts-expect-error mock fetch
+    global.fetch = mockFetch;
+
+    const tool = createWebFetchTool({
+      config: {
+        tools: { web: { fetch: { cacheTtlMinutes: 10, firecrawl: { enabled: false } } } },
+      },
+      sandboxed: false,
+    });
+    const url = "https://cache.example.com/page";
+
+    const first = await tool?.execute?.("call", { url });
+    expect(first?.details).toMatchObject({ status: 200 });
+    expect(mockFetch).toHaveBeenCalledTimes(1);
+
+    vi.setSystemTime(Date.now() + 11 * 60_000);
+    const second = await tool?.execute?.("call", { url });
+    expect(mockFetch).toHaveBeenCalledTimes(2);
+    expect(headersSeen[1]["If-None-Match"]).toBe('"v1"');
+    expect(second?.details).toMatchObject({ cached: true, revalidated: true });
+    expect((second?.details as { text?: string }).text).toContain("cached body");
+
+    const third = await tool?.execute?.("call", { url });
+    expect(mockFetch).toHaveBeenCalledTimes(2);
+    expect(third?.details).toMatchObject({ cached: true });
+
+    await tool?.execute?.("call", { url, force: true });
+    expect(mockFetch).toHaveBeenCalledTimes(3);
+    expect(headersSeen[2]["If-None-Match"]).toBeUndefined();
+  });
+});
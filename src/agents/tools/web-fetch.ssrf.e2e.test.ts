@@ -141,6 +141,78 @@ describe("web_fetch SSRF protection", () => {
     expect(fetchSpy).toHaveBeenCalledTimes(1);
   });
 
+  it("enforces configured domain allow and deny lists before fetching", async () => {
+    const fetchSpy = vi.fn();
+    // @ts-expect-error mock fetch
+    global.fetch = fetchSpy;
+
+    const { createWebFetchTool } = await import("./web-tools.js");
+    const tool = createWebFetchTool({
+      config: {
+        tools: {
+          web: {
+            fetch: {
+              cacheTtlMinutes: 0,
+              firecrawl: { apiKey: "firecrawl-test" },
+              urlAllowlist: ["*.example.com"],
+              urlDenylist: ["admin.example.com"],
+            },
+          },
+        },
+      },
+    });
+
+    await expect(tool?.execute?.("call", { url: "https://admin.example.com/" })).rejects.toThrow(
+      /denylisted/i,
+    );
+    await expect(tool?.execute?.("call", { url: "https://other.test/" })).rejects.toThrow(
+      /not in allowlist/i,
+    );
+    expect(fetchSpy).not.toHaveBeenCalled();
+  });
+
+  it("applies the domain lists to cached pages", async () => {
+    lookupMock.mockResolvedValue([{ address: "93.184.216.34", family: 4 }]);
+    const fetchSpy = vi.fn().mockResolvedValue(textResponse("cached"));
+    // @ts-expect-error mock fetch
+    global.fetch = fetchSpy;
+
+    const { createWebFetchTool } = await import("./web-tools.js");
+    const fetchConfig = { cacheTtlMinutes: 5, diskCache: false, firecrawl: { enabled: false } };
+    const url = "https://cached-policy.example.com/";
+    const open = createWebFetchTool({ config: { tools: { web: { fetch: fetchConfig } } } });
+    await open?.execute?.("call", { url });
+
+    const denied = createWebFetchTool({
+      config: {
+        tools: { web: { fetch: { ...fetchConfig, urlDenylist: ["cached-policy.example.com"] } } },
+      },
+    });
+    await expect(denied?.execute?.("call", { url })).rejects.toThrow(/denylisted/i);
+    expect(fetchSpy).toHaveBeenCalledTimes(1);
+  });
+
+  it("allows private addresses only when allowPrivateNetwork is set", async () => {
+    const fetchSpy = vi.fn().mockResolvedValue(textResponse("internal"));
+    // @ts-expect-error mock fetch
+    global.fetch = fetchSpy;
+
+    const { createWebFetchTool } = await import("./web-tools.js");
+    const tool = createWebFetchTool({
+      config: {
+        tools: {
+          web: {
+            fetch: { cacheTtlMinutes: 0, firecrawl: { enabled: false }, allowPrivateNetwork: true },
+          },
+        },
+      },
+    });
+
+    const result = await tool?.execute?.("call", { url: "http://127.0.0.1/status" });
+    expect(result?.details).toMatchObject({ status: 200 });
+    expect(fetchSpy).toHaveBeenCalledTimes(1);
+  });
+
   it("allows public hosts", async () => {
     lookupMock.mockResolvedValue([{ address: "93.184.216.34", family: 4 }]);
 
@@ -2,12 +2,28 @@ import { Type } from "@sinclair/typebox";
 import type { OpenClawConfig } from "../../config/config.js";
 import type { AnyAgentTool } from "./common.js";
 import { fetchWithSsrFGuard } from "../../infra/net/fetch-guard.js";
-import { SsrFBlockedError } from "../../infra/net/ssrf.js";
+import {
+  assertHostnameAllowedByPolicy,
+  SsrFBlockedError,
+  type SsrFPolicy,
+} from "../../infra/net/ssrf.js";
 import { logDebug } from "../../logger.js";
+import { MAX_IMAGE_BYTES } from "../../media/constants.js";
+import { getImageMetadata } from "../../media/image-ops.js";
+import { detectMime } from "../../media/mime.js";
 import { wrapExternalContent, wrapWebContent } from "../../security/external-content.js";
+import { sanitizeTerminalText } from "../../terminal/ansi.js";
 import { normalizeSecretInput } from "../../utils/normalize-secret-input.js";
 import { stringEnum } from "../schema/typebox.js";
+import { sanitizeToolResultImages } from "../tool-images.js";
 import { jsonResult, readNumberParam, readStringParam } from "./common.js";
+import { formatSize } from "./fs-walk.js";
+import {
+  classifyWebFetchContentType,
+  looksBinary,
+  parseContentDispositionFilename,
+  readResponseBytes,
+} from "./web-fetch-binary.js";
 import {
   extractReadableContent,
   htmlToMarkdown,
@@ -15,6 +31,7 @@ import {
   truncateText,
   type ExtractMode,
 } from "./web-fetch-utils.js";
+import { readWebFetchDiskCache, writeWebFetchDiskCache } from "./web-fetch-cache.js";
 import {
   CacheEntry,
   DEFAULT_CACHE_TTL_MINUTES,
@@ -56,6 +73,9 @@ const WebFetchSchema = Type.Object({
       minimum: 100,
     }),
   ),
+  force: Type.Optional(
+    Type.Boolean({ description: "Skip the response cache and fetch the page again." }),
+  ),
 });
 
 type WebFetchConfig = NonNullable<OpenClawConfig["tools"]>["web"] extends infer Web
@@ -83,6 +103,14 @@ function resolveFetchConfig(cfg?: OpenClawConfig): WebFetchConfig {
   return fetch as WebFetchConfig;
 }
 
+function resolveFetchPolicy(fetch?: WebFetchConfig): SsrFPolicy {
+  return {
+    allowPrivateNetwork: fetch?.allowPrivateNetwork === true,
+    hostnameAllowlist: fetch?.urlAllowlist,
+    hostnameDenylist: fetch?.urlDenylist,
+  };
+}
+
 function resolveFetchEnabled(params: { fetch?: WebFetchConfig; sandboxed?: boolean }): boolean {
   if (typeof params.fetch?.enabled === "boolean") {
     return params.fetch.enabled;
@@ -257,7 +285,8 @@ function wrapWebFetchContent(
     };
   }
   const maxInner = Math.max(0, maxChars - wrapperOverhead);
-  let truncated = truncateText(value, maxInner);
+  const sanitized = sanitizeTerminalText(value);
+  let truncated = truncateText(sanitized, maxInner);
   let wrappedText = includeWarning
     ? wrapWebContent(truncated.text, "web_fetch")
     : wrapExternalContent(truncated.text, { source: "web_fetch", includeWarning: false });
@@ -265,7 +294,7 @@ function wrapWebFetchContent(
   if (wrappedText.length > maxChars) {
     const excess = wrappedText.length - maxChars;
     const adjustedMaxInner = Math.max(0, maxInner - excess);
-    truncated = truncateText(value, adjustedMaxInner);
+    truncated = truncateText(sanitized, adjustedMaxInner);
     wrappedText = includeWarning
       ? wrapWebContent(truncated.text, "web_fetch")
       : wrapExternalContent(truncated.text, { source: "web_fetch", includeWarning: false });
@@ -283,7 +312,10 @@ function wrapWebFetchField(value: string | undefined): string | undefined {
   if (!value) {
     return value;
   }
-  return wrapExternalContent(value, { source: "web_fetch", includeWarning: false });
+  return wrapExternalContent(sanitizeTerminalText(value), {
+    source: "web_fetch",
+    includeWarning: false,
+  });
 }
 
 function normalizeContentType(value: string | null | undefined): string | undefined {
@@ -389,15 +421,10 @@ async function runWebFetch(params: {
   firecrawlProxy: "auto" | "basic" | "stealth";
   firecrawlStoreInCache: boolean;
   firecrawlTimeoutSeconds: number;
+  diskCache: boolean;
+  force: boolean;
+  policy?: SsrFPolicy;
 }): Promise<Record<string, unknown>> {
-  const cacheKey = normalizeCacheKey(
-    `fetch:${params.url}:${params.extractMode}:${params.maxChars}`,
-  );
-  const cached = readCache(FETCH_CACHE, cacheKey);
-  if (cached) {
-    return { ...cached.value, cached: true };
-  }
-
   let parsedUrl: URL;
   try {
     parsedUrl = new URL(params.url);
@@ -407,6 +434,47 @@ async function runWebFetch(params: {
   if (!["http:", "https:"].includes(parsedUrl.protocol)) {
     throw new Error("Invalid URL: must be http or https");
   }
+  // The domain lists apply to cached pages too: an entry may predate a denylist change.
+  assertHostnameAllowedByPolicy(parsedUrl.hostname, params.policy);
+  const assertCachedAllowed = (payload: Record<string, unknown>) => {
+    if (typeof payload.finalUrl === "string" && URL.canParse(payload.finalUrl)) {
+      assertHostnameAllowedByPolicy(new URL(payload.finalUrl).hostname, params.policy);
+    }
+    return { ...payload, cached: true };
+  };
+
+  const cacheKey = normalizeCacheKey(
+    `fetch:${params.url}:${params.extractMode}:${params.maxChars}`,
+  );
+  if (!params.force) {
+    const cached = readCache(FETCH_CACHE, cacheKey);
+    if (cached) {
+      return assertCachedAllowed(cached.value);
+    }
+  }
+  const useDiskCache = params.diskCache && params.cacheTtlMs > 0;
+  const diskEntry = useDiskCache && !params.force ? await readWebFetchDiskCache(cacheKey) : null;
+  if (diskEntry && Date.now() <= diskEntry.expiresAt) {
+    writeCache(FETCH_CACHE, cacheKey, diskEntry.payload, diskEntry.expiresAt - Date.now());
+    return assertCachedAllowed(diskEntry.payload);
+  }
+  const storePayload = async (
+    payload: Record<string, unknown>,
+    validators?: { etag?: string; lastModified?: string },
+  ) => {
+    writeCache(FETCH_CACHE, cacheKey, payload, params.cacheTtlMs);
+    if (useDiskCache) {
+      const now = Date.now();
+      await writeWebFetchDiskCache({
+        key: cacheKey,
+        url: params.url,
+        ...validators,
+        storedAt: now,
+        expiresAt: now + params.cacheTtlMs,
+        payload,
+      });
+    }
+  };
 
   const start = Date.now();
   let res: Response;
@@ -417,11 +485,15 @@ async function runWebFetch(params: {
       url: params.url,
       maxRedirects: params.maxRedirects,
       timeoutMs: params.timeoutSeconds * 1000,
+      policy: params.policy,
       init: {
         headers: {
           Accept: "text/markdown, text/html;q=0.9, */*;q=0.1",
           "User-Agent": params.userAgent,
           "Accept-Language": "en-US,en;q=0.9",
+          // Revalidate an expired disk entry instead of downloading the page again.
+          ...(diskEntry?.etag ? { "If-None-Match": diskEntry.etag } : {}),
+          ...(diskEntry?.lastModified ? { "If-Modified-Since": diskEntry.lastModified } : {}),
         },
       },
     });
@@ -476,13 +548,20 @@ async function runWebFetch(params: {
         text: wrapped.text,
         warning: wrapWebFetchField(firecrawl.warning),
       };
-      writeCache(FETCH_CACHE, cacheKey, payload, params.cacheTtlMs);
+      await storePayload(payload);
       return payload;
     }
     throw error;
   }
 
   try {
+    if (res.status === 304 && diskEntry) {
+      await storePayload(diskEntry.payload, {
+        etag: res.headers.get("etag") ?? diskEntry.etag,
+        lastModified: res.headers.get("last-modified") ?? diskEntry.lastModified,
+      });
+      return { ...diskEntry.payload, cached: true, revalidated: true };
+    }
     if (!res.ok) {
       if (params.firecrawlEnabled && params.firecrawlApiKey) {
         const firecrawl = await fetchFirecrawlContent({
@@ -520,7 +599,7 @@ async function runWebFetch(params: {
           text: wrapped.text,
           warning: wrapWebFetchField(firecrawl.warning),
         };
-        writeCache(FETCH_CACHE, cacheKey, payload, params.cacheTtlMs);
+        await storePayload(payload);
         return payload;
       }
       const rawDetail = await readResponseText(res);
@@ -534,8 +613,84 @@ async function runWebFetch(params: {
     }
 
     const contentType = res.headers.get("content-type") ?? "application/octet-stream";
-    const normalizedContentType = normalizeContentType(contentType) ?? "application/octet-stream";
-    const body = await readResponseText(res);
+    let normalizedContentType = normalizeContentType(contentType) ?? "application/octet-stream";
+    let kind = classifyWebFetchContentType(normalizedContentType);
+    const declaredLength = Number.parseInt(res.headers.get("content-length") ?? "", 10);
+    const declaredBytes = Number.isFinite(declaredLength) ? declaredLength : undefined;
+    const filename = parseContentDispositionFilename(res.headers.get("content-disposition"));
+
+    const binaryPayload = async (bytes: number | undefined, note: string) => {
+      const size = bytes === undefined ? "unknown size" : formatSize(bytes);
+      const payload = {
+        url: params.url, // Keep raw for tool chaining
+        finalUrl, // Keep raw
+        status: res.status,
+        contentType: normalizedContentType, // Protocol metadata, don't wrap
+        extractor: "binary",
+        bytes,
+        filename: wrapWebFetchField(filename),
+        fetchedAt: new Date().toISOString(),
+        tookMs: Date.now() - start,
+        text: `Binary content (${normalizedContentType}, ${size}) was not decoded as text. ${note}`,
+      };
+      await storePayload(payload, {
+        etag: res.headers.get("etag") ?? undefined,
+        lastModified: res.headers.get("last-modified") ?? undefined,
+      });
+      return payload;
+    };
+
+    if (kind === "binary") {
+      void res.body?.cancel().catch(() => {});
+      return await binaryPayload(declaredBytes, "Only metadata is returned for this type.");
+    }
+
+    let body: string;
+    if (kind === "text") {
+      body = await readResponseText(res);
+    } else {
+      // Images and untyped payloads: read a bounded prefix, then sniff what it actually is.
+      const { buffer, complete } = await readResponseBytes(res, MAX_IMAGE_BYTES);
+      if (kind === "unknown") {
+        const sniffed = await detectMime({ buffer: buffer.subarray(0, 4096) });
+        kind = sniffed
+          ? classifyWebFetchContentType(sniffed)
+          : looksBinary(buffer)
+            ? "binary"
+            : "text";
+        if (sniffed && kind !== "text") {
+          normalizedContentType = sniffed;
+        }
+      }
+      if (kind === "image" && complete) {
+        const meta = await getImageMetadata(buffer).catch(() => null);
+        return {
+          url: params.url, // Keep raw for tool chaining
+          finalUrl, // Keep raw
+          status: res.status,
+          contentType: normalizedContentType, // Protocol metadata, don't wrap
+          extractor: "image",
+          bytes: buffer.length,
+          ...(meta ? { width: meta.width, height: meta.height } : {}),
+          fetchedAt: new Date().toISOString(),
+          tookMs: Date.now() - start,
+          // Pulled out by the tool and returned as an image content block; never cached.
+          image: {
+            data: buffer.toString("base64"),
+            mimeType: normalizedContentType === "image/jpg" ? "image/jpeg" : normalizedContentType,
+          },
+        };
+      }
+      if (kind !== "text") {
+        return await binaryPayload(
+          complete ? buffer.length : declaredBytes,
+          kind === "image"
+            ? `The image exceeds the ${formatSize(MAX_IMAGE_BYTES)} web_fetch limit.`
+            : "Only metadata is returned for this type.",
+        );
+      }
+      body = buffer.toString("utf8");
+    }
 
     let title: string | undefined;
     let extractor = "raw";
@@ -607,7 +762,10 @@ async function runWebFetch(params: {
       tookMs: Date.now() - start,
       text: wrapped.text,
     };
-    writeCache(FETCH_CACHE, cacheKey, payload, params.cacheTtlMs);
+    await storePayload(payload, {
+      etag: res.headers.get("etag") ?? undefined,
+      lastModified: res.headers.get("last-modified") ?? undefined,
+    });
     return payload;
   } finally {
     if (release) {
@@ -685,6 +843,7 @@ export function createWebFetchTool(options?: {
     firecrawl?.timeoutSeconds ?? fetch?.timeoutSeconds,
     DEFAULT_TIMEOUT_SECONDS,
   );
+  const policy = resolveFetchPolicy(fetch);
   const userAgent =
     (fetch && "userAgent" in fetch && typeof fetch.userAgent === "string" && fetch.userAgent) ||
     DEFAULT_FETCH_USER_AGENT;
@@ -700,7 +859,7 @@ export function createWebFetchTool(options?: {
       const extractMode = readStringParam(params, "extractMode") === "text" ? "text" : "markdown";
       const maxChars = readNumberParam(params, "maxChars", { integer: true });
       const maxCharsCap = resolveFetchMaxCharsCap(fetch);
-      const result = await runWebFetch({
+      const { image, ...result } = await runWebFetch({
         url,
         extractMode,
         maxChars: resolveMaxChars(
@@ -721,7 +880,24 @@ export function createWebFetchTool(options?: {
         firecrawlProxy: "auto",
         firecrawlStoreInCache: true,
         firecrawlTimeoutSeconds,
+        diskCache: fetch?.diskCache !== false,
+        force: params.force === true,
+        policy,
       });
+      if (image && typeof image === "object") {
+        const { data, mimeType } = image as { data: string; mimeType: string };
+        // sanitizeToolResultImages downscales/recompresses oversized images for the provider.
+        return await sanitizeToolResultImages(
+          {
+            content: [
+              { type: "text", text: JSON.stringify(result, null, 2) },
+              { type: "image", data, mimeType },
+            ],
+            details: result,
+          },
+          "web_fetch",
+        );
+      }
       return jsonResult(result);
     },
   };
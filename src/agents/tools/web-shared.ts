@@ -1,3 +1,5 @@
+import { readResponseBytes } from "./web-fetch-binary.js";
+
 export type CacheEntry<T> = {
   value: T;
   expiresAt: number;
@@ -93,3 +95,19 @@ export async function readResponseText(res: Response): Promise<string> {
     return "";
   }
 }
+
+/**
+ * Read a text body without buffering more than `maxBytes`; the stream is cancelled at the cap and
+ * `truncated` is set.
+ */
+export async function readResponseTextCapped(
+  res: Response,
+  maxBytes: number,
+): Promise<{ text: string; truncated: boolean }> {
+  try {
+    const { buffer, complete } = await readResponseBytes(res, maxBytes);
+    return { text: buffer.toString("utf8"), truncated: !complete };
+  } catch {
+    return { text: "", truncated: false };
+  }
+}
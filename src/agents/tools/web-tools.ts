@@ -1,2 +1,3 @@
 export { createWebFetchTool, extractReadableContent, fetchFirecrawlContent } from "./web-fetch.js";
+export { createHttpRequestTool } from "./http-request-tool.js";
 export { createWebSearchTool } from "./web-search.js";
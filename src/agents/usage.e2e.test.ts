@@ -34,6 +34,33 @@ describe("normalizeUsage", () => {
     });
   });
 
+  it("splits OpenAI cached and reasoning tokens out of prompt/completion counts", () => {
+    expect(
+      normalizeUsage({
+        prompt_tokens: 1000,
+        completion_tokens: 300,
+        total_tokens: 1300,
+        prompt_tokens_details: { cached_tokens: 800 },
+        completion_tokens_details: { reasoning_tokens: 120 },
+      }),
+    ).toEqual({
+      input: 200,
+      output: 300,
+      cacheRead: 800,
+      cacheWrite: undefined,
+      total: 1300,
+      reasoning: 120,
+    });
+    expect(
+      normalizeUsage({
+        input_tokens: 500,
+        output_tokens: 40,
+        input_tokens_details: { cached_tokens: 100 },
+        output_tokens_details: { reasoning_tokens: 0 },
+      }),
+    ).toEqual({ input: 400, output: 40, cacheRead: 100, cacheWrite: undefined, total: undefined });
+  });
+
   it("returns undefined for empty usage objects", () => {
     expect(normalizeUsage({})).toBeUndefined();
   });
@@ -20,6 +20,15 @@ export type UsageLike = {
   total_tokens?: number;
   cache_read?: number;
   cache_write?: number;
+  reasoning?: number;
+  // OpenAI: cached prompt tokens are a subset of prompt/input tokens and reasoning tokens a
+  // subset of completion/output tokens (Chat Completions, Responses API, and Codex CLI).
+  prompt_tokens_details?: { cached_tokens?: number };
+  completion_tokens_details?: { reasoning_tokens?: number };
+  input_tokens_details?: { cached_tokens?: number };
+  output_tokens_details?: { reasoning_tokens?: number };
+  cached_input_tokens?: number;
+  reasoning_output_tokens?: number;
 };
 
 export type NormalizedUsage = {
@@ -28,6 +37,8 @@ export type NormalizedUsage = {
   cacheRead?: number;
   cacheWrite?: number;
   total?: number;
+  /** Reasoning tokens, already counted in `output` (OpenAI reports them separately). */
+  reasoning?: number;
 };
 
 const asFiniteNumber = (value: unknown): number | undefined => {
@@ -54,9 +65,20 @@ export function normalizeUsage(raw?: UsageLike | null): NormalizedUsage | undefi
     return undefined;
   }
 
-  const input = asFiniteNumber(
+  const openAiCached = asFiniteNumber(
+    raw.prompt_tokens_details?.cached_tokens ??
+      raw.input_tokens_details?.cached_tokens ??
+      raw.cached_input_tokens,
+  );
+  const rawInput = asFiniteNumber(
     raw.input ?? raw.inputTokens ?? raw.input_tokens ?? raw.promptTokens ?? raw.prompt_tokens,
   );
+  // OpenAI prompt counts include cached tokens; split them out so `input` stays uncached like
+  // the Anthropic shape and cache reads get their own (cheaper) price.
+  const input =
+    openAiCached !== undefined && rawInput !== undefined && raw.input === undefined
+      ? Math.max(0, rawInput - openAiCached)
+      : rawInput;
   const output = asFiniteNumber(
     raw.output ??
       raw.outputTokens ??
@@ -64,10 +86,17 @@ export function normalizeUsage(raw?: UsageLike | null): NormalizedUsage | undefi
       raw.completionTokens ??
       raw.completion_tokens,
   );
-  const cacheRead = asFiniteNumber(raw.cacheRead ?? raw.cache_read ?? raw.cache_read_input_tokens);
+  const cacheRead =
+    asFiniteNumber(raw.cacheRead ?? raw.cache_read ?? raw.cache_read_input_tokens) ?? openAiCached;
   const cacheWrite = asFiniteNumber(
     raw.cacheWrite ?? raw.cache_write ?? raw.cache_creation_input_tokens,
   );
+  const reasoning = asFiniteNumber(
+    raw.reasoning ??
+      raw.completion_tokens_details?.reasoning_tokens ??
+      raw.output_tokens_details?.reasoning_tokens ??
+      raw.reasoning_output_tokens,
+  );
   const total = asFiniteNumber(raw.total ?? raw.totalTokens ?? raw.total_tokens);
 
   if (
@@ -86,6 +115,7 @@ export function normalizeUsage(raw?: UsageLike | null): NormalizedUsage | undefi
     cacheRead,
     cacheWrite,
     total,
+    ...(reasoning ? { reasoning } : {}),
   };
 }
 
@@ -0,0 +1,61 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import { afterEach, describe, expect, it } from "vitest";
+import { makeTempWorkspace } from "../test-helpers/workspace.js";
+import {
+  __resetWorkspaceMapCacheForTest,
+  buildWorkspaceMap,
+  resolveWorkspaceMapConfig,
+} from "./workspace-map.js";
+
+describe("workspace map", () => {
+  afterEach(() => {
+    __resetWorkspaceMapCacheForTest();
+  });
+
+  it("is off unless enabled and clamps depth", () => {
+    expect(resolveWorkspaceMapConfig({})).toBeNull();
+    expect(
+      resolveWorkspaceMapConfig({ agents: { defaults: { workspaceMap: { enabled: true } } } }),
+    ).toEqual({ maxDepth: 2, maxEntries: 200 });
+    expect(
+      resolveWorkspaceMapConfig({
+        agents: { defaults: { workspaceMap: { enabled: true, maxDepth: 9, maxEntries: 10 } } },
+      }),
+    ).toEqual({ maxDepth: 5, maxEntries: 10 });
+  });
+
+  it("lists a depth-limited, gitignore-aware tree with sizes", async () => {
+    const root = await makeTempWorkspace("openclaw-workspace-map-");
+    await fs.mkdir(path.join(root, "src", "deep"), { recursive: true });
+    await fs.mkdir(path.join(root, "node_modules", "pkg"), { recursive: true });
+    await fs.mkdir(path.join(root, "dist"));
+    await fs.writeFile(path.join(root, ".gitignore"), "dist/\n");
+    await fs.writeFile(path.join(root, "src", "main.ts"), "x".repeat(2048));
+    await fs.writeFile(path.join(root, "src", "deep", "hidden.ts"), "");
+
+    const map = await buildWorkspaceMap(root, { maxDepth: 2, maxEntries: 200 });
+
+    expect(map).toBe([".gitignore  6 B", "src/", "  deep/", "  main.ts  2.0 KB"].join("\n"));
+  });
+
+  it("caps entries and refreshes after files are added", async () => {
+    const root = await makeTempWorkspace("openclaw-workspace-map-");
+    await fs.writeFile(path.join(root, "a.md"), "a");
+    await fs.writeFile(path.join(root, "b.md"), "b");
+    await fs.writeFile(path.join(root, "c.md"), "c");
+    const opts = { maxDepth: 1, maxEntries: 2 };
+
+    expect(await buildWorkspaceMap(root, opts)).toBe(
+      ["a.md  1 B", "b.md  1 B", "… (+1 more entries; use ls or find for the rest)"].join("\n"),
+    );
+
+    await fs.rm(path.join(root, "a.md"));
+    await fs.rm(path.join(root, "b.md"));
+    await fs.writeFile(path.join(root, "0.md"), "new");
+    const stat = await fs.stat(root);
+    await fs.utimes(root, stat.atime, new Date(stat.mtimeMs + 5_000));
+
+    expect(await buildWorkspaceMap(root, opts)).toBe(["0.md  3 B", "c.md  1 B"].join("\n"));
+  });
+});
@@ -0,0 +1,133 @@
+import fs from "node:fs/promises";
+import type { OpenClawConfig } from "../config/config.js";
+import { formatSize, walkTree } from "./tools/fs-walk.js";
+
+export const DEFAULT_WORKSPACE_MAP_DEPTH = 2;
+export const DEFAULT_WORKSPACE_MAP_MAX_ENTRIES = 200;
+const MAX_WORKSPACE_MAP_DEPTH = 5;
+const MAX_CACHED_MAPS = 32;
+
+type CachedWorkspaceMap = {
+  text: string;
+  /** Listed directories' mtimes; a change means entries were added, removed, or renamed. */
+  dirMtimes: Map<string, number>;
+};
+
+const mapCache = new Map<string, CachedWorkspaceMap>();
+
+export type ResolvedWorkspaceMapConfig = {
+  maxDepth: number;
+  maxEntries: number;
+};
+
+export function resolveWorkspaceMapConfig(
+  cfg?: OpenClawConfig,
+): ResolvedWorkspaceMapConfig | null {
+  const raw = cfg?.agents?.defaults?.workspaceMap;
+  if (!raw?.enabled) {
+    return null;
+  }
+  const depth =
+    typeof raw.maxDepth === "number" && raw.maxDepth > 0 ? Math.floor(raw.maxDepth) : 0;
+  const entries =
+    typeof raw.maxEntries === "number" && raw.maxEntries > 0 ? Math.floor(raw.maxEntries) : 0;
+  return {
+    maxDepth: Math.min(MAX_WORKSPACE_MAP_DEPTH, depth || DEFAULT_WORKSPACE_MAP_DEPTH),
+    maxEntries: entries || DEFAULT_WORKSPACE_MAP_MAX_ENTRIES,
+  };
+}
+
+async function readDirMtime(dir: string): Promise<number | undefined> {
+  return (await fs.stat(dir).catch(() => null))?.mtimeMs;
+}
+
+async function isCacheFresh(cached: CachedWorkspaceMap): Promise<boolean> {
+  for (const [dir, mtime] of cached.dirMtimes) {
+    if ((await readDirMtime(dir)) !== mtime) {
+      return false;
+    }
+  }
+  return true;
+}
+
+async function renderWorkspaceMap(
+  root: string,
+  opts: ResolvedWorkspaceMapConfig,
+): Promise<CachedWorkspaceMap> {
+  const dirMtimes = new Map<string, number>();
+  const rootMtime = await readDirMtime(root);
+  if (rootMtime !== undefined) {
+    dirMtimes.set(root, rootMtime);
+  }
+  const lines: string[] = [];
+  let omitted = 0;
+  for await (const entry of walkTree({ root, maxDepth: opts.maxDepth - 1 })) {
+    if (lines.length >= opts.maxEntries) {
+      omitted += 1;
+      continue;
+    }
+    const indent = "  ".repeat(entry.depth);
+    const name = entry.dirent.name;
+    if (entry.dirent.isDirectory()) {
+      const stat = await fs.stat(entry.path).catch(() => null);
+      if (stat) {
+        dirMtimes.set(entry.path, stat.mtimeMs);
+      }
+      lines.push(`${indent}${name}/`);
+    } else if (entry.dirent.isFile()) {
+      const size = (await fs.stat(entry.path).catch(() => null))?.size;
+      lines.push(`${indent}${name}${size === undefined ? "" : `  ${formatSize(size)}`}`);
+    } else if (entry.dirent.isSymbolicLink()) {
+      lines.push(`${indent}${name} -> …`);
+    }
+  }
+  if (omitted > 0) {
+    lines.push(`… (+${omitted} more entries; use ls or find for the rest)`);
+  }
+  return { text: lines.join("\n"), dirMtimes };
+}
+
+/**
+ * Depth-limited, .gitignore-aware tree of the workspace for the system prompt. The result is
+ * cached per workspace and rebuilt once any listed directory's mtime changes, so the prompt
+ * stays byte-stable (and cacheable) until files are added, removed, or renamed.
+ */
+export async function buildWorkspaceMap(
+  root: string,
+  opts: ResolvedWorkspaceMapConfig,
+): Promise<string | undefined> {
+  const key = `${root}\0${opts.maxDepth}\0${opts.maxEntries}`;
+  const cached = mapCache.get(key);
+  if (cached && (await isCacheFresh(cached))) {
+    return cached.text || undefined;
+  }
+  const rendered = await renderWorkspaceMap(root, opts);
+  mapCache.delete(key);
+  mapCache.set(key, rendered);
+  if (mapCache.size > MAX_CACHED_MAPS) {
+    const oldest = mapCache.keys().next().value;
+    if (oldest !== undefined) {
+      mapCache.delete(oldest);
+    }
+  }
+  return rendered.text || undefined;
+}
+
+export async function resolveWorkspaceMapForRun(params: {
+  workspaceDir: string;
+  config?: OpenClawConfig;
+}): Promise<string | undefined> {
+  const opts = resolveWorkspaceMapConfig(params.config);
+  if (!opts) {
+    return undefined;
+  }
+  try {
+    return await buildWorkspaceMap(params.workspaceDir, opts);
+  } catch {
+    return undefined;
+  }
+}
+
+export function __resetWorkspaceMapCacheForTest() {
+  mapCache.clear();
+}
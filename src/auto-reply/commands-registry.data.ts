@@ -1,51 +1,11 @@
-import type {
-  ChatCommandDefinition,
-  CommandCategory,
-  CommandScope,
-} from "./commands-registry.types.js";
+import type { ChatCommandDefinition } from "./commands-registry.types.js";
 import { listChannelDocks } from "../channels/dock.js";
 import { getActivePluginRegistry } from "../plugins/runtime.js";
 import { COMMAND_ARG_FORMATTERS } from "./commands-args.js";
+import { defineChatCommand } from "./commands-registry.define.js";
+import { buildWorkflowChatCommands } from "./commands-registry.workflow.js";
 import { listThinkingLevels } from "./thinking.js";
 
-type DefineChatCommandInput = {
-  key: string;
-  nativeName?: string;
-  description: string;
-  args?: ChatCommandDefinition["args"];
-  argsParsing?: ChatCommandDefinition["argsParsing"];
-  formatArgs?: ChatCommandDefinition["formatArgs"];
-  argsMenu?: ChatCommandDefinition["argsMenu"];
-  acceptsArgs?: boolean;
-  textAlias?: string;
-  textAliases?: string[];
-  scope?: CommandScope;
-  category?: CommandCategory;
-};
-
-function defineChatCommand(command: DefineChatCommandInput): ChatCommandDefinition {
-  const aliases = (command.textAliases ?? (command.textAlias ? [command.textAlias] : []))
-    .map((alias) => alias.trim())
-    .filter(Boolean);
-  const scope =
-    command.scope ?? (command.nativeName ? (aliases.length ? "both" : "native") : "text");
-  const acceptsArgs = command.acceptsArgs ?? Boolean(command.args?.length);
-  const argsParsing = command.argsParsing ?? (command.args?.length ? "positional" : "none");
-  return {
-    key: command.key,
-    nativeName: command.nativeName,
-    description: command.description,
-    acceptsArgs,
-    args: command.args,
-    argsParsing,
-    formatArgs: command.formatArgs,
-    argsMenu: command.argsMenu,
-    textAliases: aliases,
-    scope,
-    category: command.category,
-  };
-}
-
 type ChannelDock = ReturnType<typeof listChannelDocks>[number];
 
 function defineDockCommand(dock: ChannelDock): ChatCommandDefinition {
@@ -246,6 +206,28 @@ function buildChatCommands(): ChatCommandDefinition[] {
       textAlias: "/whoami",
       category: "status",
     }),
+    defineChatCommand({
+      key: "memory",
+      nativeName: "memory",
+      description: "Show, add to, edit, or clear the agent's MEMORY.md.",
+      textAlias: "/memory",
+      category: "status",
+      args: [
+        {
+          name: "action",
+          description: "show | add <fact> | edit | clear [global|project]",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "todos",
+      nativeName: "todos",
+      description: "Show the agent's task list for this session.",
+      textAlias: "/todos",
+      category: "status",
+    }),
     defineChatCommand({
       key: "subagents",
       nativeName: "subagents",
@@ -422,6 +404,7 @@ function buildChatCommands(): ChatCommandDefinition[] {
         },
       ],
     }),
+    ...buildWorkflowChatCommands(),
     defineChatCommand({
       key: "think",
       nativeName: "think",
@@ -0,0 +1,43 @@
+import type {
+  ChatCommandDefinition,
+  CommandCategory,
+  CommandScope,
+} from "./commands-registry.types.js";
+
+type DefineChatCommandInput = {
+  key: string;
+  nativeName?: string;
+  description: string;
+  args?: ChatCommandDefinition["args"];
+  argsParsing?: ChatCommandDefinition["argsParsing"];
+  formatArgs?: ChatCommandDefinition["formatArgs"];
+  argsMenu?: ChatCommandDefinition["argsMenu"];
+  acceptsArgs?: boolean;
+  textAlias?: string;
+  textAliases?: string[];
+  scope?: CommandScope;
+  category?: CommandCategory;
+};
+
+export function defineChatCommand(command: DefineChatCommandInput): ChatCommandDefinition {
+  const aliases = (command.textAliases ?? (command.textAlias ? [command.textAlias] : []))
+    .map((alias) => alias.trim())
+    .filter(Boolean);
+  const scope =
+    command.scope ?? (command.nativeName ? (aliases.length ? "both" : "native") : "text");
+  const acceptsArgs = command.acceptsArgs ?? Boolean(command.args?.length);
+  const argsParsing = command.argsParsing ?? (command.args?.length ? "positional" : "none");
+  return {
+    key: command.key,
+    nativeName: command.nativeName,
+    description: command.description,
+    acceptsArgs,
+    args: command.args,
+    argsParsing,
+    formatArgs: command.formatArgs,
+    argsMenu: command.argsMenu,
+    textAliases: aliases,
+    scope,
+    category: command.category,
+  };
+}
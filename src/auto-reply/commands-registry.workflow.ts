@@ -0,0 +1,193 @@
+import type { ChatCommandDefinition } from "./commands-registry.types.js";
+import { defineChatCommand } from "./commands-registry.define.js";
+
+/** Planning, review, git, and session-management commands. */
+export function buildWorkflowChatCommands(): ChatCommandDefinition[] {
+  return [
+    defineChatCommand({
+      key: "plan",
+      nativeName: "plan",
+      description: "Plan with read-only tools; approve to execute.",
+      textAlias: "/plan",
+      category: "session",
+      args: [
+        {
+          name: "input",
+          description: "Task to plan, approve [notes], or off",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "persona",
+      nativeName: "persona",
+      description: "Select a system prompt preset.",
+      textAlias: "/persona",
+      category: "session",
+      args: [
+        {
+          name: "name",
+          description: "Preset name, list, or off",
+          type: "string",
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "council",
+      nativeName: "council",
+      description: "Ask several models the same prompt.",
+      textAlias: "/council",
+      category: "tools",
+      args: [
+        {
+          name: "prompt",
+          description: "Prompt for every council model",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "commit",
+      nativeName: "commit",
+      description: "Stage changes and commit them with a generated message.",
+      textAlias: "/commit",
+      category: "tools",
+      args: [
+        {
+          name: "paths",
+          description: "Paths to stage (or all), or yes | edit <message> | cancel",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "review",
+      nativeName: "review",
+      description: "Review a diff and list findings by file and severity.",
+      textAlias: "/review",
+      category: "tools",
+      args: [
+        {
+          name: "target",
+          description: "Ref or range, --staged, or --pr <number> (default: uncommitted changes)",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "pin",
+      nativeName: "pin",
+      description: "Pin a message so compaction keeps it verbatim.",
+      textAlias: "/pin",
+      category: "session",
+      args: [
+        {
+          name: "text",
+          description: "Text to pin, or reply | list | remove <n> | clear (default: last message)",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "title",
+      nativeName: "title",
+      description: "Show or set the session title and tags.",
+      textAlias: "/title",
+      category: "session",
+      args: [
+        {
+          name: "title",
+          description: "New title, or clear | tags <tag ...> | tags clear",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "settings",
+      nativeName: "settings",
+      description: "Show the settings stored on this session.",
+      textAlias: "/settings",
+      category: "session",
+    }),
+    defineChatCommand({
+      key: "budget",
+      nativeName: "budget",
+      description: "Show spend budgets, or keep going past an exceeded one.",
+      textAlias: "/budget",
+      category: "session",
+      args: [
+        {
+          name: "action",
+          description: "continue",
+          type: "string",
+          choices: ["continue"],
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "tools",
+      nativeName: "tools",
+      description: "List the session's tools, or turn tools on/off for this session.",
+      textAlias: "/tools",
+      category: "session",
+      args: [
+        {
+          name: "action",
+          description: "enable <tool ...> | disable <tool ...> | reset",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "fork",
+      nativeName: "fork",
+      description: "Branch the conversation; the original is kept as a separate session.",
+      textAlias: "/fork",
+      category: "session",
+      args: [
+        {
+          name: "name",
+          description: "Title for the new branch",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "rewind",
+      nativeName: "rewind",
+      description: "Branch from before the last N turns (default 1).",
+      textAlias: "/rewind",
+      category: "session",
+      args: [
+        {
+          name: "turns",
+          description: "How many turns to go back",
+          type: "number",
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "continue",
+      nativeName: "continue",
+      description: "Continue the last reply where it stopped.",
+      textAlias: "/continue",
+      category: "session",
+      args: [
+        {
+          name: "note",
+          description: "Optional extra instructions",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+  ];
+}
@@ -42,6 +42,7 @@ import { applyReplyTagsToPayload, isRenderablePayload } from "./reply-payloads.j
 export type AgentRunLoopResult =
   | {
       kind: "success";
+      runId: string;
       runResult: Awaited<ReturnType<typeof runEmbeddedPiAgent>>;
       fallbackProvider?: string;
       fallbackModel?: string;
@@ -311,6 +312,11 @@ export async function runAgentTurnWithFallback(params: {
             })(),
             bashElevated: params.followupRun.run.bashElevated,
             timeoutMs: params.followupRun.run.timeoutMs,
+            planMode: params.followupRun.run.planMode,
+            disabledTools: params.followupRun.run.disabledTools,
+            prefill: params.followupRun.run.prefill,
+            pinnedContext: params.followupRun.run.pinnedContext,
+            streamParams: params.followupRun.run.streamParams,
             runId,
             images: params.opts?.images,
             abortSignal: params.opts?.abortSignal,
@@ -620,6 +626,7 @@ export async function runAgentTurnWithFallback(params: {
 
   return {
     kind: "success",
+    runId,
     runResult,
     fallbackProvider,
     fallbackModel,
@@ -72,6 +72,7 @@ export async function runMemoryFlushIfNeeded(params: {
         agentCfgContextTokens: params.agentCfgContextTokens,
       }),
       reserveTokensFloor: memoryFlushSettings.reserveTokensFloor,
+      compactionThresholdPercent: memoryFlushSettings.compactionThresholdPercent,
       softThresholdTokens: memoryFlushSettings.softThresholdTokens,
     });
 
@@ -1,7 +1,7 @@
 import { describe, expect, it } from "vitest";
 import type { OpenClawConfig } from "../../config/config.js";
 import type { TemplateContext } from "../templating.js";
-import { buildThreadingToolContext } from "./agent-runner-utils.js";
+import { buildThreadingToolContext, formatResponseUsageLine } from "./agent-runner-utils.js";
 
 describe("buildThreadingToolContext", () => {
   const cfg = {} as OpenClawConfig;
@@ -104,3 +104,17 @@ describe("buildThreadingToolContext", () => {
     expect(result.currentThreadTs).toBe("123.456");
   });
 });
+
+describe("formatResponseUsageLine", () => {
+  it("shows cache reads and reasoning tokens when reported", () => {
+    expect(
+      formatResponseUsageLine({
+        usage: { input: 200, output: 300, cacheRead: 800, reasoning: 120 },
+        showCost: false,
+      }),
+    ).toBe("Usage: 200 in (+800 cached) / 300 out (120 reasoning)");
+    expect(formatResponseUsageLine({ usage: { input: 12, output: 3 }, showCost: false })).toBe(
+      "Usage: 12 in / 3 out",
+    );
+  });
+});
@@ -106,7 +106,9 @@ export const formatResponseUsageLine = (params: {
       : undefined;
   const costLabel = params.showCost ? formatUsd(cost) : undefined;
   const suffix = costLabel ? ` · est ${costLabel}` : "";
-  return `Usage: ${inputLabel} in / ${outputLabel} out${suffix}`;
+  const cached = usage.cacheRead ? ` (+${formatTokenCount(usage.cacheRead)} cached)` : "";
+  const reasoning = usage.reasoning ? ` (${formatTokenCount(usage.reasoning)} reasoning)` : "";
+  return `Usage: ${inputLabel} in${cached} / ${outputLabel} out${reasoning}${suffix}`;
 };
 
 export const appendUsageLine = (payloads: ReplyPayload[], line: string): ReplyPayload[] => {
@@ -9,6 +9,8 @@ import { DEFAULT_CONTEXT_TOKENS } from "../../agents/defaults.js";
 import { resolveModelAuthMode } from "../../agents/model-auth.js";
 import { isCliProvider } from "../../agents/model-selection.js";
 import { queueEmbeddedPiMessage } from "../../agents/pi-embedded.js";
+import { formatRunTimings } from "../../agents/run-timings.js";
+import { checkSpendBudget, formatSpendBudgetNotice } from "../../agents/spend-budget.js";
 import { hasNonzeroUsage } from "../../agents/usage.js";
 import {
   resolveAgentIdFromSessionKey,
@@ -38,6 +40,7 @@ import { resolveBlockStreamingCoalescing } from "./block-streaming.js";
 import { createFollowupRunner } from "./followup-runner.js";
 import { enqueueFollowupRun, type FollowupRun, type QueueSettings } from "./queue.js";
 import { createReplyToModeFilterForChannel, resolveReplyToMode } from "./reply-threading.js";
+import { scheduleSessionAutoTitle } from "./session-auto-title.js";
 import { incrementRunCompactionCount, persistRunSessionUsage } from "./session-run-accounting.js";
 import { createTypingSignaler } from "./typing-mode.js";
 
@@ -196,6 +199,27 @@ export async function runReplyAgent(params: {
     return undefined;
   }
 
+  const budget = isHeartbeat
+    ? null
+    : await checkSpendBudget({
+        cfg,
+        agentId: followupRun.run.agentId,
+        sessionId: followupRun.run.sessionId,
+        sessionCostUsd: activeSessionEntry?.usageTotals?.costUsd,
+      });
+  const budgetNotice = formatSpendBudgetNotice(budget);
+  const budgetPeriod = budget?.worst?.periodKey;
+  if (budget?.level === "exceeded" && activeSessionEntry?.budgetOverride !== budgetPeriod) {
+    typing.cleanup();
+    return {
+      text: `${budgetNotice}\nSend /budget continue to keep going in this session.`,
+    };
+  }
+  const budgetWarning =
+    budget?.level === "warn" && activeSessionEntry?.budgetWarned !== budgetPeriod
+      ? budgetNotice
+      : null;
+
   await typingSignals.signalRunStart();
 
   activeSessionEntry = await runMemoryFlushIfNeeded({
@@ -334,7 +358,7 @@ export async function runReplyAgent(params: {
       return finalizeWithFollowup(runOutcome.payload, queueKey, runFollowupTurn);
     }
 
-    const { runResult, fallbackProvider, fallbackModel, directlySentBlockKeys } = runOutcome;
+    const { runId, runResult, fallbackProvider, fallbackModel, directlySentBlockKeys } = runOutcome;
     let { didLogHeartbeatStrip, autoCompactionCompleted } = runOutcome;
 
     if (
@@ -395,6 +419,7 @@ export async function runReplyAgent(params: {
       contextTokensUsed,
       systemPromptReport: runResult.meta.systemPromptReport,
       cliSessionId,
+      config: cfg,
     });
 
     // Drain any late tool/block deliveries before deciding there's "nothing to send".
@@ -429,6 +454,24 @@ export async function runReplyAgent(params: {
 
     await signalTypingIfNeeded(replyPayloads, typingSignals);
 
+    if (!isHeartbeat) {
+      void scheduleSessionAutoTitle({
+        cfg,
+        sessionEntry: activeSessionEntry,
+        sessionStore: activeSessionStore,
+        sessionKey,
+        storePath,
+        provider: providerUsed,
+        model: modelUsed,
+        agentDir: followupRun.run.agentDir,
+        userText: sessionCtx.BodyStripped?.trim() || commandBody,
+        assistantText: replyPayloads
+          .map((payload) => payload.text ?? "")
+          .join("\n")
+          .trim(),
+      });
+    }
+
     if (isDiagnosticsEnabled(cfg) && hasNonzeroUsage(usage)) {
       const input = usage.input ?? 0;
       const output = usage.output ?? 0;
@@ -444,6 +487,7 @@ export async function runReplyAgent(params: {
       const costUsd = estimateUsageCost({ usage, cost: costConfig });
       emitDiagnosticEvent({
         type: "model.usage",
+        runId,
         sessionKey,
         sessionId: followupRun.run.sessionId,
         channel: replyToChannel,
@@ -516,6 +560,28 @@ export async function runReplyAgent(params: {
     if (responseUsageLine) {
       finalPayloads = appendUsageLine(finalPayloads, responseUsageLine);
     }
+    const timings = runResult.meta.timings;
+    if (timings && (responseUsageMode === "full" || verboseEnabled)) {
+      finalPayloads = appendUsageLine(
+        finalPayloads,
+        formatRunTimings(timings, runResult.meta.durationMs),
+      );
+    }
+    if (budgetWarning && budgetPeriod) {
+      // Warn once per budget period rather than on every reply.
+      finalPayloads = appendUsageLine(finalPayloads, budgetWarning);
+      if (activeSessionEntry && activeSessionStore && sessionKey) {
+        activeSessionEntry.budgetWarned = budgetPeriod;
+        activeSessionStore[sessionKey] = activeSessionEntry;
+        if (storePath) {
+          await updateSessionStoreEntry({
+            storePath,
+            sessionKey,
+            update: async () => ({ budgetWarned: budgetPeriod }),
+          });
+        }
+      }
+    }
 
     return finalizeWithFollowup(
       finalPayloads.length === 1 ? finalPayloads[0] : finalPayloads,
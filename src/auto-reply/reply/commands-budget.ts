@@ -0,0 +1,97 @@
+import type { CommandHandler } from "./commands-types.js";
+import {
+  checkSpendBudget,
+  formatSpendBudgetCheck,
+  type SpendBudgetStatus,
+} from "../../agents/spend-budget.js";
+import { updateSessionStoreEntry } from "../../config/sessions.js";
+import { logVerbose } from "../../globals.js";
+import { resolveAgentIdFromSessionKey } from "../../routing/session-key.js";
+
+const USAGE = "💰 Usage: /budget | /budget continue";
+
+export function formatBudgetStatus(status: SpendBudgetStatus, override?: string): string {
+  const lines = ["💰 Spend budgets"];
+  for (const check of status.checks) {
+    const flag = check.ratio >= 1 ? (override === check.periodKey ? " (continuing)" : " 🛑") : "";
+    lines.push(`${formatSpendBudgetCheck(check)}${flag}`);
+  }
+  if (status.level === "exceeded" && override !== status.worst?.periodKey) {
+    lines.push("New runs are paused. Send /budget continue to keep going in this session.");
+  } else {
+    lines.push(`Warning at ${status.warnPercent}%.`);
+  }
+  return lines.join("\n");
+}
+
+/** `/budget` shows spend against `agents.defaults.budget`; `/budget continue` confirms going past it. */
+export const handleBudgetCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const normalized = params.command.commandBodyNormalized;
+  if (normalized !== "/budget" && !normalized.startsWith("/budget ")) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /budget from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  const action = normalized.slice("/budget".length).trim().toLowerCase();
+  if (action && action !== "continue") {
+    return { shouldContinue: false, reply: { text: USAGE } };
+  }
+  const entry = params.sessionEntry;
+  const status = await checkSpendBudget({
+    cfg: params.cfg,
+    agentId: params.agentId ?? resolveAgentIdFromSessionKey(params.sessionKey),
+    sessionId: entry?.sessionId,
+    sessionCostUsd: entry?.usageTotals?.costUsd,
+  });
+  if (!status) {
+    return {
+      shouldContinue: false,
+      reply: { text: "💰 No spend budget configured (agents.defaults.budget)." },
+    };
+  }
+  if (!action) {
+    return {
+      shouldContinue: false,
+      reply: { text: formatBudgetStatus(status, entry?.budgetOverride) },
+    };
+  }
+
+  if (!params.command.senderIsOwner) {
+    return {
+      shouldContinue: false,
+      reply: { text: "⚠️ /budget continue is only available to the owner." },
+    };
+  }
+  if (!entry) {
+    return { shouldContinue: false, reply: { text: "⚠️ /budget continue needs an active session." } };
+  }
+  if (status.level !== "exceeded" || !status.worst) {
+    return { shouldContinue: false, reply: { text: "💰 No budget is exceeded right now." } };
+  }
+  // The override covers only the exceeded period; a new day, month, or session asks again.
+  const budgetOverride = status.worst.periodKey;
+  entry.budgetOverride = budgetOverride;
+  if (params.sessionStore?.[params.sessionKey]) {
+    params.sessionStore[params.sessionKey].budgetOverride = budgetOverride;
+  }
+  if (params.storePath) {
+    await updateSessionStoreEntry({
+      storePath: params.storePath,
+      sessionKey: params.sessionKey,
+      update: async () => ({ budgetOverride }),
+    });
+  }
+  return {
+    shouldContinue: false,
+    reply: {
+      text: `💰 Continuing past the limit for this session. ${formatSpendBudgetCheck(status.worst)}`,
+    },
+  };
+};
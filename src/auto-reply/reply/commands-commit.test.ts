@@ -0,0 +1,131 @@
+import { execFileSync } from "node:child_process";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { CommandContext, HandleCommandsParams } from "./commands-types.js";
+
+vi.mock("../../agents/council.js", () => ({
+  askModel: vi.fn(async () => "```\nfeat(auth): add token refresh\n```"),
+}));
+
+import { askModel } from "../../agents/council.js";
+import { cleanCommitMessage } from "../../agents/git-commit.js";
+import {
+  handleCommitCommand,
+  parseCommitCommand,
+  resetPendingCommitsForTests,
+} from "./commands-commit.js";
+
+function makeCommand(body: string, senderIsOwner = true): CommandContext {
+  return {
+    surface: "whatsapp",
+    channel: "whatsapp",
+    ownerList: [],
+    senderIsOwner,
+    isAuthorizedSender: true,
+    rawBodyNormalized: body,
+    commandBodyNormalized: body.split("\n")[0] ?? body,
+  };
+}
+
+describe("/commit", () => {
+  let root: string;
+
+  const git = (...args: string[]) =>
+    execFileSync("git", args, { cwd: root, encoding: "utf8" }).trim();
+
+  beforeEach(async () => {
+    resetPendingCommitsForTests();
+    root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-commit-"));
+    git("init", "-q", "-b", "main");
+    git("config", "user.name", "Test");
+    git("config", "user.email", "test@example.com");
+    git("config", "commit.gpgsign", "false");
+    await fs.writeFile(path.join(root, "a.txt"), "one\n");
+    git("add", "a.txt");
+    git("commit", "-q", "-m", "chore: init");
+  });
+
+  afterEach(async () => {
+    await fs.rm(root, { recursive: true, force: true });
+  });
+
+  const run = async (
+    body: string,
+    opts: { senderIsOwner?: boolean; elevated?: boolean } = {},
+  ) =>
+    (
+      await handleCommitCommand(
+        {
+          ctx: {},
+          command: makeCommand(body, opts.senderIsOwner),
+          elevated: { enabled: true, allowed: opts.elevated ?? true, failures: [] },
+          cfg: {},
+          sessionKey: "agent:main:main",
+          workspaceDir: root,
+          provider: "anthropic",
+          model: "claude-sonnet",
+        } as unknown as HandleCommandsParams,
+        true,
+      )
+    )?.reply?.text;
+
+  it("parses actions", () => {
+    expect(parseCommitCommand("/commit")).toEqual({ action: "propose", paths: [] });
+    expect(parseCommitCommand("/commit all")).toEqual({ action: "propose", paths: ["."] });
+    expect(parseCommitCommand("/commit src docs/a.md")).toEqual({
+      action: "propose",
+      paths: ["src", "docs/a.md"],
+    });
+    expect(parseCommitCommand("/commit yes")).toEqual({ action: "confirm" });
+    expect(parseCommitCommand("/commit cancel")).toEqual({ action: "cancel" });
+    expect(parseCommitCommand("/commit edit fix: x", "/commit edit fix: x\n\nbody")).toEqual({
+      action: "edit",
+      message: "fix: x\n\nbody",
+    });
+    expect(parseCommitCommand("/commits")).toBeNull();
+  });
+
+  it("cleans fenced or quoted messages", () => {
+    expect(cleanCommitMessage('"fix: trim input"')).toBe("fix: trim input");
+    expect(cleanCommitMessage("```text\nfix: a  \n\n\n\nbody\n```")).toBe("fix: a\n\nbody");
+  });
+
+  it("stages paths, proposes a message, and commits on approval", async () => {
+    await fs.writeFile(path.join(root, "a.txt"), "two\n");
+    await fs.writeFile(path.join(root, "b.txt"), "new\n");
+
+    const proposal = await run("/commit a.txt");
+    expect(proposal).toContain("(1 file staged)");
+    expect(proposal).toContain("feat(auth): add token refresh");
+    expect(vi.mocked(askModel).mock.calls[0]?.[0].prompt).toContain("+two");
+    expect(git("log", "-n1", "--format=%s")).toBe("chore: init");
+
+    expect(await run("/commit edit fix(a): bump\n\nExplain why.")).toContain("fix(a): bump");
+    expect(await run("/commit yes")).toMatch(/^✅ Committed \w+: fix\(a\): bump$/);
+    expect(git("log", "-n1", "--format=%B")).toBe("fix(a): bump\n\nExplain why.");
+    expect(git("status", "--porcelain")).toBe("?? b.txt");
+    expect(await run("/commit yes")).toContain("No commit is waiting");
+  });
+
+  it("requires the owner and elevated access", async () => {
+    await fs.writeFile(path.join(root, "a.txt"), "two\n");
+    expect(await run("/commit all", { senderIsOwner: false })).toContain(
+      "only available to the owner",
+    );
+    expect(await run("/commit all", { elevated: false })).toContain("elevated is not available");
+    expect(git("status", "--porcelain")).toBe("M a.txt");
+  });
+
+  it("refuses approval when the staged changes moved", async () => {
+    expect(await run("/commit")).toContain("Nothing is staged");
+
+    await fs.writeFile(path.join(root, "a.txt"), "two\n");
+    await run("/commit all");
+    await fs.writeFile(path.join(root, "a.txt"), "three\n");
+    git("add", "a.txt");
+    expect(await run("/commit yes")).toContain("changed since the message was written");
+    expect(git("log", "-n1", "--format=%s")).toBe("chore: init");
+  });
+});
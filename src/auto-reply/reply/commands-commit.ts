@@ -0,0 +1,200 @@
+import type { CommandHandler } from "./commands-types.js";
+import {
+  commitStaged,
+  generateCommitMessage,
+  readStagedChanges,
+  readStagedTree,
+  resolveSessionGitRoot,
+  stageGitPaths,
+} from "../../agents/git-commit.js";
+import { resolveSandboxRuntimeStatus } from "../../agents/sandbox.js";
+import { logVerbose } from "../../globals.js";
+import { shortenHomePath } from "../../utils.js";
+import { formatElevatedUnavailableMessage } from "./elevated-unavailable.js";
+
+const USAGE =
+  "📦 Usage: /commit [paths… | all] | /commit yes | /commit edit <message> | /commit cancel";
+const PENDING_TTL_MS = 30 * 60_000;
+const CONFIRM_WORDS = new Set(["yes", "y", "ok", "confirm", "approve"]);
+const CANCEL_WORDS = new Set(["cancel", "no", "n", "abort"]);
+
+export type CommitCommand =
+  | { action: "propose"; paths: string[] }
+  | { action: "confirm" | "cancel" }
+  | { action: "edit"; message: string };
+
+type PendingCommit = {
+  root: string;
+  message: string;
+  /** Index tree when the message was proposed; approval is refused if staging changed since. */
+  tree: string;
+  files: number;
+  createdAt: number;
+};
+
+const pendingCommits = new Map<string, PendingCommit>();
+
+export function resetPendingCommitsForTests() {
+  pendingCommits.clear();
+}
+
+export function parseCommitCommand(normalized: string, raw?: string): CommitCommand | null {
+  if (normalized !== "/commit" && !normalized.startsWith("/commit ")) {
+    return null;
+  }
+  const rest = normalized.slice("/commit".length).trim();
+  const [first = "", ...tail] = rest.split(/\s+/);
+  const keyword = first.toLowerCase();
+  if (CONFIRM_WORDS.has(keyword) && tail.length === 0) {
+    return { action: "confirm" };
+  }
+  if (CANCEL_WORDS.has(keyword) && tail.length === 0) {
+    return { action: "cancel" };
+  }
+  if (keyword === "edit") {
+    // The normalized body keeps only the first line; take multi-line messages from the raw text.
+    const rawMatch = raw?.trim().match(/^\/commit(?:@\S+)?\s+edit\b\s*([\s\S]*)$/i);
+    const message = rawMatch ? rawMatch[1] : rest.slice(first.length);
+    return { action: "edit", message: message.trim() };
+  }
+  if (keyword === "all" && tail.length === 0) {
+    return { action: "propose", paths: ["."] };
+  }
+  return { action: "propose", paths: rest ? rest.split(/\s+/) : [] };
+}
+
+function formatProposal(pending: PendingCommit, note?: string): string {
+  const fence = pending.message.includes("```") ? "````" : "```";
+  const files = `${pending.files} file${pending.files === 1 ? "" : "s"}`;
+  const lines = [`📦 Proposed commit in ${shortenHomePath(pending.root)} (${files} staged):`];
+  if (note) {
+    lines.push(note);
+  }
+  lines.push(
+    fence,
+    pending.message,
+    fence,
+    "Reply /commit yes to commit, /commit edit <message> to change it, or /commit cancel.",
+  );
+  return lines.join("\n");
+}
+
+/**
+ * `/commit [paths…]` stages the paths, asks the session's model for a conventional-commit message
+ * from `git diff --cached`, and holds it for approval; `/commit yes` runs `git commit`. Git runs on
+ * the gateway host and the commit runs repository hooks, so like /bash it needs the owner and
+ * elevated access.
+ */
+export const handleCommitCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parseCommitCommand(
+    params.command.commandBodyNormalized,
+    params.command.rawBodyNormalized,
+  );
+  if (!parsed) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /commit from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  const reply = (text: string) => ({ shouldContinue: false, reply: { text } });
+  if (!params.command.senderIsOwner) {
+    return reply("⚠️ /commit is only available to the owner.");
+  }
+  if (!params.elevated.enabled || !params.elevated.allowed) {
+    return reply(
+      formatElevatedUnavailableMessage({
+        runtimeSandboxed: resolveSandboxRuntimeStatus({
+          cfg: params.cfg,
+          sessionKey: params.ctx.SessionKey,
+        }).sandboxed,
+        failures: params.elevated.failures,
+        sessionKey: params.ctx.SessionKey,
+      }),
+    );
+  }
+
+  let pending = pendingCommits.get(params.sessionKey);
+  if (pending && Date.now() - pending.createdAt > PENDING_TTL_MS) {
+    pendingCommits.delete(params.sessionKey);
+    pending = undefined;
+  }
+
+  if (parsed.action === "cancel") {
+    pendingCommits.delete(params.sessionKey);
+    return reply(
+      pending
+        ? "📦 Commit cancelled. Staged changes were left in place."
+        : "📦 No commit is waiting for approval.",
+    );
+  }
+  if (parsed.action === "edit") {
+    if (!pending) {
+      return reply("📦 No commit is waiting for approval. Run /commit first.");
+    }
+    if (!parsed.message) {
+      return reply(formatProposal(pending, "Send the new message after /commit edit."));
+    }
+    pending.message = parsed.message;
+    return reply(formatProposal(pending));
+  }
+  if (parsed.action === "confirm") {
+    if (!pending) {
+      return reply("📦 No commit is waiting for approval. Run /commit first.");
+    }
+    try {
+      if ((await readStagedTree(pending.root)) !== pending.tree) {
+        pendingCommits.delete(params.sessionKey);
+        return reply(
+          "⚠️ The staged changes changed since the message was written. Run /commit again.",
+        );
+      }
+      const commit = await commitStaged(pending.root, pending.message);
+      pendingCommits.delete(params.sessionKey);
+      return reply(`✅ Committed ${commit.hash}: ${commit.subject}`);
+    } catch (err) {
+      return reply(`⚠️ ${err instanceof Error ? err.message : String(err)}`);
+    }
+  }
+
+  const root = resolveSessionGitRoot({
+    workspaceDir: params.workspaceDir,
+    config: params.cfg,
+    sessionKey: params.sessionKey,
+    agentId: params.agentId,
+  });
+  if (!root) {
+    return reply("⚠️ /commit needs the workspace (or agents.defaults.repoRoot) to be a git repo.");
+  }
+  try {
+    await stageGitPaths(root, parsed.paths);
+    const changes = await readStagedChanges(root);
+    if (!changes) {
+      pendingCommits.delete(params.sessionKey);
+      return reply(`📦 Nothing is staged in ${shortenHomePath(root)}.\n${USAGE}`);
+    }
+    const message = await generateCommitMessage({
+      cfg: params.cfg,
+      provider: params.provider,
+      model: params.model,
+      root,
+      changes,
+    });
+    const proposal: PendingCommit = {
+      root,
+      message,
+      tree: await readStagedTree(root),
+      files: changes.files.length,
+      createdAt: Date.now(),
+    };
+    pendingCommits.set(params.sessionKey, proposal);
+    return reply(formatProposal(proposal));
+  } catch (err) {
+    return reply(`⚠️ /commit failed: ${err instanceof Error ? err.message : String(err)}`);
+  }
+};
@@ -0,0 +1,61 @@
+import type { AgentMessage } from "@mariozechner/pi-agent-core";
+import { describe, expect, it } from "vitest";
+import { IMAGE_TOKEN_ESTIMATE, summarizeContextHistory } from "./commands-context-history.js";
+
+describe("summarizeContextHistory", () => {
+  it("splits history into text, tool, image, and summary buckets", () => {
+    const messages = [
+      { role: "compactionSummary", summary: "s".repeat(400), tokensBefore: 1, timestamp: 0 },
+      {
+        role: "user",
+        content: [
+          { type: "text", text: "u".repeat(40) },
+          { type: "image", data: "abc", mimeType: "image/png" },
+        ],
+        timestamp: 1,
+      },
+      {
+        role: "assistant",
+        content: [
+          { type: "thinking", thinking: "t".repeat(80) },
+          { type: "text", text: "a".repeat(20) },
+          { type: "toolCall", id: "call_1", name: "read", arguments: { path: "x" } },
+        ],
+        usage: { input: 200, output: 30, cacheRead: 800, cacheWrite: 0 },
+        timestamp: 2,
+      },
+      {
+        role: "toolResult",
+        toolCallId: "call_1",
+        toolName: "read",
+        content: [{ type: "text", text: "r".repeat(1_000) }],
+        timestamp: 3,
+      },
+    ] as unknown as AgentMessage[];
+
+    const breakdown = summarizeContextHistory(messages);
+
+    expect(breakdown).toMatchObject({
+      messages: 4,
+      summaryChars: 400,
+      userTextChars: 40,
+      assistantTextChars: 20,
+      thinkingChars: 80,
+      toolCallChars: JSON.stringify({ path: "x" }).length,
+      toolResultChars: 1_000,
+      images: 1,
+      lastUsage: { input: 200, output: 30, cacheRead: 800, cacheWrite: 0 },
+    });
+    const chars = 400 + 40 + 20 + 80 + JSON.stringify({ path: "x" }).length + 1_000;
+    expect(breakdown.estimatedTokens).toBe(Math.ceil(chars / 4) + IMAGE_TOKEN_ESTIMATE);
+  });
+
+  it("handles plain string content and empty history", () => {
+    expect(summarizeContextHistory([]).estimatedTokens).toBe(0);
+    const breakdown = summarizeContextHistory([
+      { role: "user", content: "hello", timestamp: 1 } as AgentMessage,
+    ]);
+    expect(breakdown.userTextChars).toBe(5);
+    expect(breakdown.lastUsage).toBeUndefined();
+  });
+});
@@ -0,0 +1,114 @@
+import type { AgentMessage } from "@mariozechner/pi-agent-core";
+import { SessionManager } from "@mariozechner/pi-coding-agent";
+import fs from "node:fs";
+
+/** Rough per-image cost; providers bill images by size, so this is only a ballpark. */
+export const IMAGE_TOKEN_ESTIMATE = 1_200;
+
+export type ContextHistoryBreakdown = {
+  messages: number;
+  userTextChars: number;
+  assistantTextChars: number;
+  thinkingChars: number;
+  toolCallChars: number;
+  toolResultChars: number;
+  summaryChars: number;
+  otherChars: number;
+  images: number;
+  estimatedTokens: number;
+  /** Usage reported for the latest assistant reply (the last request actually sent). */
+  lastUsage?: { input: number; output: number; cacheRead: number; cacheWrite: number };
+};
+
+function textLength(value: unknown): number {
+  return typeof value === "string" ? value.length : 0;
+}
+
+function finiteNumber(value: unknown): number {
+  return typeof value === "number" && Number.isFinite(value) ? value : 0;
+}
+
+export function summarizeContextHistory(messages: AgentMessage[]): ContextHistoryBreakdown {
+  const breakdown: ContextHistoryBreakdown = {
+    messages: messages.length,
+    userTextChars: 0,
+    assistantTextChars: 0,
+    thinkingChars: 0,
+    toolCallChars: 0,
+    toolResultChars: 0,
+    summaryChars: 0,
+    otherChars: 0,
+    images: 0,
+    estimatedTokens: 0,
+  };
+  for (const message of messages) {
+    const record = message as { role?: unknown; content?: unknown; summary?: unknown };
+    const role = typeof record.role === "string" ? record.role : "";
+    if (role === "compactionSummary" || role === "branchSummary") {
+      breakdown.summaryChars += textLength(record.summary);
+      continue;
+    }
+    const blocks =
+      typeof record.content === "string"
+        ? [{ type: "text", text: record.content }]
+        : Array.isArray(record.content)
+          ? (record.content as Array<Record<string, unknown>>)
+          : [];
+    for (const block of blocks) {
+      if (!block || typeof block !== "object") {
+        continue;
+      }
+      if (block.type === "image") {
+        breakdown.images += 1;
+      } else if (block.type === "thinking") {
+        breakdown.thinkingChars += textLength(block.thinking);
+      } else if (block.type === "toolCall") {
+        breakdown.toolCallChars += JSON.stringify(block.arguments ?? {}).length;
+      } else if (block.type === "text") {
+        const chars = textLength(block.text);
+        if (role === "user") {
+          breakdown.userTextChars += chars;
+        } else if (role === "assistant") {
+          breakdown.assistantTextChars += chars;
+        } else if (role === "toolResult") {
+          breakdown.toolResultChars += chars;
+        } else {
+          breakdown.otherChars += chars;
+        }
+      }
+    }
+    if (role === "assistant") {
+      const usage = (message as { usage?: Record<string, unknown> }).usage;
+      if (usage) {
+        breakdown.lastUsage = {
+          input: finiteNumber(usage.input),
+          output: finiteNumber(usage.output),
+          cacheRead: finiteNumber(usage.cacheRead),
+          cacheWrite: finiteNumber(usage.cacheWrite),
+        };
+      }
+    }
+  }
+  const chars =
+    breakdown.userTextChars +
+    breakdown.assistantTextChars +
+    breakdown.thinkingChars +
+    breakdown.toolCallChars +
+    breakdown.toolResultChars +
+    breakdown.summaryChars +
+    breakdown.otherChars;
+  breakdown.estimatedTokens = Math.ceil(chars / 4) + breakdown.images * IMAGE_TOKEN_ESTIMATE;
+  return breakdown;
+}
+
+/** Messages the next request would send: the latest compaction summary plus everything after. */
+export function loadContextHistory(sessionFile: string | undefined): AgentMessage[] | null {
+  if (!sessionFile || !fs.existsSync(sessionFile)) {
+    return null;
+  }
+  try {
+    return SessionManager.open(sessionFile).buildSessionContext().messages;
+  } catch {
+    return null;
+  }
+}
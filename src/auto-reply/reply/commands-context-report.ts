@@ -4,7 +4,11 @@ import type { HandleCommandsParams } from "./commands-types.js";
 import { resolveSessionAgentIds } from "../../agents/agent-scope.js";
 import { resolveBootstrapContextForRun } from "../../agents/bootstrap-files.js";
 import { resolveDefaultModelForAgent } from "../../agents/model-selection.js";
-import { resolveBootstrapMaxChars } from "../../agents/pi-embedded-helpers.js";
+import {
+  resolveBootstrapMaxChars,
+  resolveBootstrapTotalMaxChars,
+} from "../../agents/pi-embedded-helpers.js";
+import { resolveCompactionReserveTokens } from "../../agents/pi-settings.js";
 import { createOpenClawCodingTools } from "../../agents/pi-tools.js";
 import { resolveSandboxRuntimeStatus } from "../../agents/sandbox.js";
 import { buildWorkspaceSkillSnapshot } from "../../agents/skills.js";
@@ -13,8 +17,15 @@ import { buildSystemPromptParams } from "../../agents/system-prompt-params.js";
 import { buildSystemPromptReport } from "../../agents/system-prompt-report.js";
 import { buildAgentSystemPrompt } from "../../agents/system-prompt.js";
 import { buildToolSummaryMap } from "../../agents/tool-summaries.js";
+import { resolveSessionFilePath, resolveSessionFilePathOptions } from "../../config/sessions.js";
 import { getRemoteSkillEligibility } from "../../infra/skills-remote.js";
 import { buildTtsSystemPromptHint } from "../../tts/tts.js";
+import {
+  type ContextHistoryBreakdown,
+  IMAGE_TOKEN_ESTIMATE,
+  loadContextHistory,
+  summarizeContextHistory,
+} from "./commands-context-history.js";
 
 function estimateTokensFromChars(chars: number): number {
   return Math.ceil(Math.max(0, chars) / 4);
@@ -59,7 +70,11 @@ async function resolveContextReport(
 
   const workspaceDir = params.workspaceDir;
   const bootstrapMaxChars = resolveBootstrapMaxChars(params.cfg);
-  const { bootstrapFiles, contextFiles: injectedFiles } = await resolveBootstrapContextForRun({
+  const {
+    bootstrapFiles,
+    instructionFiles,
+    contextFiles: injectedFiles,
+  } = await resolveBootstrapContextForRun({
     workspaceDir,
     config: params.cfg,
     sessionKey: params.sessionKey,
@@ -169,15 +184,117 @@ async function resolveContextReport(
     model: params.model,
     workspaceDir,
     bootstrapMaxChars,
+    bootstrapTotalMaxChars: resolveBootstrapTotalMaxChars(params.cfg),
     sandbox: { mode: sandboxRuntime.mode, sandboxed: sandboxRuntime.sandboxed },
     systemPrompt,
     bootstrapFiles,
+    instructionFiles,
     injectedFiles,
     skillsPrompt,
     tools,
   });
 }
 
+function resolveContextHistory(params: HandleCommandsParams): ContextHistoryBreakdown | null {
+  const entry = params.sessionEntry;
+  if (!entry?.sessionId) {
+    return null;
+  }
+  const sessionFile = resolveSessionFilePath(
+    entry.sessionId,
+    entry,
+    resolveSessionFilePathOptions({ agentId: params.agentId, storePath: params.storePath }),
+  );
+  const messages = loadContextHistory(sessionFile);
+  return messages ? summarizeContextHistory(messages) : null;
+}
+
+function buildContextBudget(params: {
+  report: SessionSystemPromptReport;
+  history: ContextHistoryBreakdown | null;
+  contextTokens: number | null;
+  cfg: HandleCommandsParams["cfg"];
+}) {
+  const systemPromptTokens = estimateTokensFromChars(params.report.systemPrompt.chars);
+  const toolSchemaTokens = estimateTokensFromChars(params.report.tools.schemaChars);
+  const historyTokens = params.history?.estimatedTokens ?? 0;
+  const usedTokens = systemPromptTokens + toolSchemaTokens + historyTokens;
+  const contextTokens =
+    params.contextTokens && params.contextTokens > 0 ? params.contextTokens : null;
+  const compactAtTokens = contextTokens
+    ? Math.max(
+        0,
+        contextTokens -
+          resolveCompactionReserveTokens({ cfg: params.cfg, contextWindowTokens: contextTokens }),
+      )
+    : null;
+  return {
+    systemPromptTokens,
+    toolSchemaTokens,
+    historyTokens,
+    usedTokens,
+    contextTokens,
+    headroomTokens: contextTokens ? Math.max(0, contextTokens - usedTokens) : null,
+    compactAtTokens,
+  };
+}
+
+function formatHistoryLines(
+  history: ContextHistoryBreakdown | null,
+  budget: ReturnType<typeof buildContextBudget>,
+): string[] {
+  const lines: string[] = [];
+  if (!history) {
+    lines.push("History: (no transcript yet)");
+  } else {
+    lines.push(
+      `History (sent with the next request): ${formatInt(history.messages)} messages, ~${formatInt(history.estimatedTokens)} tok`,
+    );
+    const buckets: Array<[string, number]> = [
+      ["Compaction summary", history.summaryChars],
+      ["User text", history.userTextChars],
+      ["Assistant text", history.assistantTextChars],
+      ["Thinking", history.thinkingChars],
+      ["Tool calls", history.toolCallChars],
+      ["Tool results", history.toolResultChars],
+      ["Other", history.otherChars],
+    ];
+    for (const [label, chars] of buckets) {
+      if (chars > 0) {
+        lines.push(`- ${label}: ${formatCharsAndTokens(chars)}`);
+      }
+    }
+    if (history.images > 0) {
+      lines.push(
+        `- Images: ${formatInt(history.images)} (~${formatInt(history.images * IMAGE_TOKEN_ESTIMATE)} tok)`,
+      );
+    }
+    const usage = history.lastUsage;
+    if (usage) {
+      const prompt = usage.input + usage.cacheRead + usage.cacheWrite;
+      const cachedPct = prompt > 0 ? Math.round((usage.cacheRead / prompt) * 100) : 0;
+      lines.push(
+        `Last request: ${formatInt(prompt)} prompt tok (cache read ${formatInt(usage.cacheRead)} = ${cachedPct}%, cache write ${formatInt(usage.cacheWrite)}), ${formatInt(usage.output)} output tok`,
+      );
+    }
+  }
+  const used = `~${formatInt(budget.usedTokens)} tok (system ~${formatInt(budget.systemPromptTokens)} + tools ~${formatInt(budget.toolSchemaTokens)} + history ~${formatInt(budget.historyTokens)})`;
+  if (budget.contextTokens) {
+    lines.push(
+      `Headroom: ${used} of ${formatInt(budget.contextTokens)} → ~${formatInt(budget.headroomTokens ?? 0)} tok left`,
+    );
+    if (budget.compactAtTokens !== null) {
+      const untilCompaction = Math.max(0, budget.compactAtTokens - budget.usedTokens);
+      lines.push(
+        `Auto-compaction at ~${formatInt(budget.compactAtTokens)} tok (~${formatInt(untilCompaction)} tok to go)`,
+      );
+    }
+  } else {
+    lines.push(`Estimated use: ${used}; context window unknown`);
+  }
+  return lines;
+}
+
 export async function buildContextReply(params: HandleCommandsParams): Promise<ReplyPayload> {
   const args = parseContextArgs(params.command.commandBodyNormalized);
   const sub = args.split(/\s+/).filter(Boolean)[0]?.toLowerCase() ?? "";
@@ -192,6 +309,7 @@ export async function buildContextReply(params: HandleCommandsParams): Promise<R
         "Try:",
         "- /context list   (short breakdown)",
         "- /context detail (per-file + per-tool + per-skill + system prompt size)",
+        "  Both include history buckets, cache reads, and headroom until compaction.",
         "- /context json   (same, machine-readable)",
         "",
         "Inline shortcut = a command token inside a normal message (e.g. “hey /status”). It runs immediately (allowlisted senders only) and is stripped before the model sees the remaining text.",
@@ -207,8 +325,16 @@ export async function buildContextReply(params: HandleCommandsParams): Promise<R
     contextTokens: params.contextTokens ?? null,
   } as const;
 
+  const history = resolveContextHistory(params);
+  const budget = buildContextBudget({
+    report,
+    history,
+    contextTokens: session.contextTokens,
+    cfg: params.cfg,
+  });
+
   if (sub === "json") {
-    return { text: JSON.stringify({ report, session }, null, 2) };
+    return { text: JSON.stringify({ report, session, history, budget }, null, 2) };
   }
 
   if (sub !== "list" && sub !== "show" && sub !== "detail" && sub !== "deep") {
@@ -250,7 +376,16 @@ export async function buildContextReply(params: HandleCommandsParams): Promise<R
     typeof report.bootstrapMaxChars === "number"
       ? `${formatInt(report.bootstrapMaxChars)} chars`
       : "? chars";
+  const injectedTotalChars = report.injectedWorkspaceFiles.reduce(
+    (sum, f) => sum + (f.missing ? 0 : f.injectedChars),
+    0,
+  );
+  const bootstrapTotalLine =
+    typeof report.bootstrapTotalMaxChars === "number"
+      ? `Bootstrap max/total: ${formatInt(report.bootstrapTotalMaxChars)} chars (used ${formatCharsAndTokens(injectedTotalChars)})`
+      : `Bootstrap max/total: ? chars (used ${formatCharsAndTokens(injectedTotalChars)})`;
 
+  const historyLines = formatHistoryLines(history, budget);
   const totalsLine =
     session.totalTokens != null
       ? `Session tokens (cached): ${formatInt(session.totalTokens)} total / ctx=${session.contextTokens ?? "?"}`
@@ -280,6 +415,7 @@ export async function buildContextReply(params: HandleCommandsParams): Promise<R
         "🧠 Context breakdown (detailed)",
         `Workspace: ${workspaceLabel}`,
         `Bootstrap max/file: ${bootstrapMaxLabel}`,
+        bootstrapTotalLine,
         sandboxLine,
         systemPromptLine,
         "",
@@ -303,6 +439,7 @@ export async function buildContextReply(params: HandleCommandsParams): Promise<R
         ...(perToolSummary.omitted ? [`… (+${perToolSummary.omitted} more tools)`] : []),
         ...(toolPropsLines.length ? ["", "Tools (param count):", ...toolPropsLines] : []),
         "",
+        ...historyLines,
         totalsLine,
         "",
         "Inline shortcut: a command token inside normal text (e.g. “hey /status”) that runs immediately (allowlisted senders only) and is stripped before the model sees the remaining message.",
@@ -317,6 +454,7 @@ export async function buildContextReply(params: HandleCommandsParams): Promise<R
       "🧠 Context breakdown",
       `Workspace: ${workspaceLabel}`,
       `Bootstrap max/file: ${bootstrapMaxLabel}`,
+      bootstrapTotalLine,
       sandboxLine,
       systemPromptLine,
       "",
@@ -329,6 +467,7 @@ export async function buildContextReply(params: HandleCommandsParams): Promise<R
       toolSchemaLine,
       toolsNamesLine,
       "",
+      ...historyLines,
       totalsLine,
       "",
       "Inline shortcut: a command token inside normal text (e.g. “hey /status”) that runs immediately (allowlisted senders only) and is stripped before the model sees the remaining message.",
@@ -0,0 +1,68 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandContext } from "./commands-types.js";
+import { CONTINUE_PROMPT } from "../../agents/prefill.js";
+import { handleContinueCommand } from "./commands-continue.js";
+
+function makeCommand(body: string): CommandContext {
+  return {
+    surface: "whatsapp",
+    channel: "whatsapp",
+    ownerList: [],
+    senderIsOwner: true,
+    isAuthorizedSender: true,
+    rawBodyNormalized: body,
+    commandBodyNormalized: body,
+  };
+}
+
+describe("/continue", () => {
+  let dir: string;
+  let storePath: string;
+  const entry: SessionEntry = { sessionId: "sess-continue", updatedAt: 0 };
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-continue-"));
+    storePath = path.join(dir, "sessions.json");
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  const run = (body: string) =>
+    handleContinueCommand({ command: makeCommand(body), sessionEntry: entry, storePath });
+
+  it("ignores other commands and needs a previous reply", () => {
+    expect(run("/continued")).toBeNull();
+    expect(run("/continue")).toEqual({
+      kind: "reply",
+      reply: { text: "⚠️ Nothing to continue yet." },
+    });
+  });
+
+  it("seeds the next turn with the tail of the latest reply", async () => {
+    const transcript = [
+      { message: { role: "user", content: [{ type: "text", text: "write the guide" }] } },
+      {
+        message: {
+          role: "assistant",
+          content: [{ type: "text", text: "Step 1: install\nStep 2: conf" }],
+        },
+      },
+    ];
+    await fs.writeFile(
+      path.join(dir, "sess-continue.jsonl"),
+      transcript.map((line) => JSON.stringify(line)).join("\n"),
+    );
+
+    expect(run("/continue keep it short")).toEqual({
+      kind: "continue",
+      body: `${CONTINUE_PROMPT}\n\nkeep it short`,
+      prefill: { text: "Step 1: install\nStep 2: conf", echo: false },
+    });
+  });
+});
@@ -0,0 +1,44 @@
+import type { AssistantPrefill } from "../../agents/prefill.js";
+import type { SessionEntry } from "../../config/sessions.js";
+import type { ReplyPayload } from "../types.js";
+import type { CommandContext } from "./commands-types.js";
+import { CONTINUE_PROMPT, resolveContinuePrefill } from "../../agents/prefill.js";
+import { logVerbose } from "../../globals.js";
+import { readLatestAssistantText } from "./commands-plan.js";
+
+export type ContinueCommandResult =
+  | { kind: "reply"; reply?: ReplyPayload }
+  | { kind: "continue"; body: string; prefill: AssistantPrefill };
+
+/**
+ * `/continue` resumes the latest reply (e.g. one cut off by the output token limit) by seeding the
+ * next assistant turn with its tail.
+ */
+export function handleContinueCommand(params: {
+  command: CommandContext;
+  sessionEntry?: SessionEntry;
+  storePath?: string;
+}): ContinueCommandResult | null {
+  const normalized = params.command.commandBodyNormalized;
+  if (normalized !== "/continue" && !normalized.startsWith("/continue ")) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /continue from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { kind: "reply" };
+  }
+  const previous = params.sessionEntry
+    ? readLatestAssistantText(params.sessionEntry, params.storePath)
+    : undefined;
+  if (!previous) {
+    return { kind: "reply", reply: { text: "⚠️ Nothing to continue yet." } };
+  }
+  const note = normalized.slice("/continue".length).trim();
+  return {
+    kind: "continue",
+    body: note ? `${CONTINUE_PROMPT}\n\n${note}` : CONTINUE_PROMPT,
+    prefill: resolveContinuePrefill(previous),
+  };
+}
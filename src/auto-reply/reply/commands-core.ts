@@ -12,17 +12,26 @@ import { shouldHandleTextCommands } from "../commands-registry.js";
 import { handleAllowlistCommand } from "./commands-allowlist.js";
 import { handleApproveCommand } from "./commands-approve.js";
 import { handleBashCommand } from "./commands-bash.js";
+import { handleBudgetCommand } from "./commands-budget.js";
+import { handleCommitCommand } from "./commands-commit.js";
 import { handleCompactCommand } from "./commands-compact.js";
 import { handleConfigCommand, handleDebugCommand } from "./commands-config.js";
+import { handleCouncilCommand } from "./commands-council.js";
+import { handleForkCommand } from "./commands-fork.js";
 import {
   handleCommandsListCommand,
   handleContextCommand,
   handleHelpCommand,
+  handleMemoryCommand,
   handleStatusCommand,
+  handleTodosCommand,
   handleWhoamiCommand,
 } from "./commands-info.js";
 import { handleModelsCommand } from "./commands-models.js";
+import { handlePersonaCommand } from "./commands-persona.js";
+import { handlePinCommand } from "./commands-pin.js";
 import { handlePluginCommand } from "./commands-plugin.js";
+import { handleReviewCommand } from "./commands-review.js";
 import {
   handleAbortTrigger,
   handleActivationCommand,
@@ -31,7 +40,10 @@ import {
   handleStopCommand,
   handleUsageCommand,
 } from "./commands-session.js";
+import { handleSettingsCommand } from "./commands-settings.js";
 import { handleSubagentsCommand } from "./commands-subagents.js";
+import { handleTitleCommand } from "./commands-title.js";
+import { handleToolsCommand } from "./commands-tools.js";
 import { handleTtsCommands } from "./commands-tts.js";
 import { routeReply } from "./route-reply.js";
 
@@ -55,10 +67,22 @@ export async function handleCommands(params: HandleCommandsParams): Promise<Comm
       handleApproveCommand,
       handleContextCommand,
       handleWhoamiCommand,
+      handleTodosCommand,
+      handleMemoryCommand,
       handleSubagentsCommand,
       handleConfigCommand,
       handleDebugCommand,
       handleModelsCommand,
+      handlePersonaCommand,
+      handleCouncilCommand,
+      handleCommitCommand,
+      handleReviewCommand,
+      handlePinCommand,
+      handleTitleCommand,
+      handleToolsCommand,
+      handleSettingsCommand,
+      handleBudgetCommand,
+      handleForkCommand,
       handleStopCommand,
       handleCompactCommand,
       handleAbortTrigger,
@@ -0,0 +1,71 @@
+import type { CommandHandler } from "./commands-types.js";
+import {
+  COUNCIL_MAX_MODELS,
+  formatCouncilReply,
+  prepareCouncil,
+  resolveCouncilTimeoutMs,
+  runCouncil,
+} from "../../agents/council.js";
+import { logVerbose } from "../../globals.js";
+
+const USAGE = "🏛️ Usage: /council <prompt>";
+
+export function parseCouncilCommand(normalized: string, raw?: string): { prompt: string } | null {
+  if (normalized !== "/council" && !normalized.startsWith("/council ")) {
+    return null;
+  }
+  // The normalized body keeps only the first line; take multi-line prompts from the raw text.
+  const rawMatch = raw?.trim().match(/^\/council(?:@\S+)?\s*:?\s*([\s\S]*)$/i);
+  if (rawMatch) {
+    return { prompt: rawMatch[1].trim() };
+  }
+  return { prompt: normalized.slice("/council".length).trim() };
+}
+
+/**
+ * `/council <prompt>` asks every model in `agents.defaults.council.models` the same prompt
+ * concurrently and replies with the labeled answers, plus a synthesis when a judge is configured.
+ * Members see only the prompt: no tools, session history or system prompt.
+ */
+export const handleCouncilCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parseCouncilCommand(
+    params.command.commandBodyNormalized,
+    params.command.rawBodyNormalized,
+  );
+  if (!parsed) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /council from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  if (!parsed.prompt) {
+    return { shouldContinue: false, reply: { text: USAGE } };
+  }
+
+  const council = await prepareCouncil({ cfg: params.cfg, agentId: params.agentId });
+  const notes = council.errors.map((error) => `⚠️ ${error}`);
+  if (council.members.length < 2) {
+    notes.push(
+      `⚠️ /council needs at least two models in agents.defaults.council.models (max ${COUNCIL_MAX_MODELS}).`,
+    );
+    return { shouldContinue: false, reply: { text: notes.join("\n") } };
+  }
+
+  const result = await runCouncil({
+    cfg: params.cfg,
+    prompt: parsed.prompt,
+    members: council.members,
+    judge: council.judge,
+    timeoutMs: resolveCouncilTimeoutMs(params.cfg),
+  });
+  return {
+    shouldContinue: false,
+    reply: { text: [...notes, formatCouncilReply(result)].join("\n\n") },
+  };
+};
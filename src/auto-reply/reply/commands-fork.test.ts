@@ -0,0 +1,142 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandContext, HandleCommandsParams } from "./commands-types.js";
+import { handleForkCommand, parseForkCommand } from "./commands-fork.js";
+
+function makeCommand(body: string): CommandContext {
+  return {
+    surface: "whatsapp",
+    channel: "whatsapp",
+    ownerList: [],
+    senderIsOwner: true,
+    isAuthorizedSender: true,
+    rawBodyNormalized: body,
+    commandBodyNormalized: body,
+  };
+}
+
+const userMessage = (text: string) => ({ role: "user", content: [{ type: "text", text }] });
+const assistantMessage = (text: string) => ({
+  role: "assistant",
+  content: [{ type: "text", text }],
+  api: "openai-responses",
+  provider: "openai",
+  model: "gpt-5.2",
+  usage: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0, totalTokens: 0 },
+  stopReason: "stop",
+});
+
+async function readTranscriptTexts(file: string): Promise<string[]> {
+  const lines = (await fs.readFile(file, "utf-8")).split("\n").filter(Boolean);
+  return lines
+    .map((line) => JSON.parse(line) as { type: string; message?: { content: [{ text: string }] } })
+    .filter((line) => line.type === "message")
+    .map((line) => line.message?.content[0]?.text ?? "");
+}
+
+describe("/fork and /rewind", () => {
+  let dir: string;
+  let storePath: string;
+  let entry: SessionEntry;
+  let sessionStore: Record<string, SessionEntry>;
+  const sessionKey = "agent:main:telegram:direct:42";
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-fork-"));
+    storePath = path.join(dir, "sessions.json");
+    const sessionFile = path.join(dir, "orig-session.jsonl");
+    const messages = [
+      userMessage("plan the migration"),
+      assistantMessage("Step 1: add the column."),
+      userMessage("use a view instead"),
+      assistantMessage("Creating the view."),
+    ];
+    const lines = [
+      { type: "session", version: 3, id: "orig-session", timestamp: new Date().toISOString() },
+      ...messages.map((message, i) => ({
+        type: "message",
+        id: `m${i}`,
+        parentId: i === 0 ? null : `m${i - 1}`,
+        timestamp: new Date().toISOString(),
+        message: { ...message, timestamp: i },
+      })),
+    ];
+    await fs.writeFile(sessionFile, `${lines.map((line) => JSON.stringify(line)).join("\n")}\n`);
+    entry = {
+      sessionId: "orig-session",
+      sessionFile,
+      updatedAt: 0,
+      title: "Migration",
+      titleSource: "user",
+      claudeCliSessionId: "cli-1",
+    };
+    sessionStore = { [sessionKey]: entry };
+    await fs.writeFile(storePath, JSON.stringify(sessionStore));
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  const run = (body: string) =>
+    handleForkCommand(
+      {
+        command: makeCommand(body),
+        sessionEntry: entry,
+        sessionStore,
+        sessionKey,
+        storePath,
+      } as unknown as HandleCommandsParams,
+      true,
+    );
+
+  const readStore = async () =>
+    JSON.parse(await fs.readFile(storePath, "utf-8")) as Record<string, SessionEntry>;
+
+  it("parses arguments", () => {
+    expect(parseForkCommand("/fork")).toEqual({ action: "fork" });
+    expect(parseForkCommand("/fork try views")).toEqual({ action: "fork", name: "try views" });
+    expect(parseForkCommand("/rewind")).toEqual({ action: "rewind", turns: 1 });
+    expect(parseForkCommand("/rewind 3")).toEqual({ action: "rewind", turns: 3 });
+    expect(parseForkCommand("/rewind two")).toHaveProperty("error");
+    expect(parseForkCommand("/forks")).toBeNull();
+  });
+
+  it("forks onto a copy and keeps the original under a side key", async () => {
+    const result = await run("/fork Views approach");
+    const savedKey = `${sessionKey}:fork:orig-ses`;
+    expect(result?.reply?.text).toBe(
+      `🍴 Forked as "Views approach". The conversation so far is saved as ${savedKey}.`,
+    );
+
+    const store = await readStore();
+    expect(store[savedKey]).toMatchObject({ sessionId: "orig-session", title: "Migration" });
+    const live = store[sessionKey];
+    expect(live.sessionId).not.toBe("orig-session");
+    expect(live).toMatchObject({ title: "Views approach", titleSource: "user" });
+    expect(live.claudeCliSessionId).toBeUndefined();
+    expect(entry.sessionId).toBe(live.sessionId);
+    expect(await readTranscriptTexts(live.sessionFile ?? "")).toHaveLength(4);
+  });
+
+  it("rewinds by dropping the last turns from the copy", async () => {
+    const result = await run("/rewind");
+    expect(result?.reply?.text).toContain("⏪ Rewound 1 turn(s).");
+
+    const live = (await readStore())[sessionKey];
+    expect(live.title).toBe("Migration");
+    expect(live.totalTokensFresh).toBe(false);
+    expect(await readTranscriptTexts(live.sessionFile ?? "")).toEqual([
+      "plan the migration",
+      "Step 1: add the column.",
+    ]);
+    // The original transcript is untouched.
+    expect(await readTranscriptTexts(path.join(dir, "orig-session.jsonl"))).toHaveLength(4);
+
+    const tooFar = await run("/rewind 5");
+    expect(tooFar?.reply?.text).toBe("⚠️ Only 1 turn(s) to rewind.");
+  });
+});
@@ -0,0 +1,120 @@
+import fs from "node:fs";
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandHandler } from "./commands-types.js";
+import { updateSessionStore } from "../../config/sessions.js";
+import { resolveSessionTranscriptCandidates } from "../../gateway/session-utils.fs.js";
+import { logVerbose } from "../../globals.js";
+import { branchSessionTranscript, buildForkedSessionEntry } from "../../sessions/session-fork.js";
+import { parseSessionTitle } from "../../sessions/session-title.js";
+
+const REWIND_USAGE = "⏪ Usage: /rewind [turns] (default 1)";
+
+export function parseForkCommand(
+  normalized: string,
+):
+  | { action: "fork"; name?: string }
+  | { action: "rewind"; turns: number }
+  | { error: string }
+  | null {
+  const match = normalized.match(/^\/(fork|rewind)(?:\s+([\s\S]*))?$/i);
+  if (!match) {
+    return null;
+  }
+  const rest = match[2]?.trim() ?? "";
+  if (match[1].toLowerCase() === "fork") {
+    return rest ? { action: "fork", name: rest } : { action: "fork" };
+  }
+  if (!rest) {
+    return { action: "rewind", turns: 1 };
+  }
+  const turns = Number(rest);
+  return Number.isInteger(turns) && turns > 0
+    ? { action: "rewind", turns }
+    : { error: REWIND_USAGE };
+}
+
+/** Key the pre-fork conversation is kept under; the chat's own key moves to the fork. */
+export function resolveForkSavedKey(sessionKey: string, sessionId: string): string {
+  return `${sessionKey}:fork:${sessionId.slice(0, 8)}`;
+}
+
+/**
+ * Chats are bound to their session key, so a fork swaps transcripts instead of switching keys:
+ * the original entry moves to a side key untouched, and the chat continues on a branched copy.
+ */
+export const handleForkCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parseForkCommand(params.command.commandBodyNormalized);
+  if (!parsed) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /fork from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  if ("error" in parsed) {
+    return { shouldContinue: false, reply: { text: parsed.error } };
+  }
+  const entry = params.sessionEntry;
+  const storePath = params.storePath;
+  if (!entry?.sessionId || !storePath) {
+    return { shouldContinue: false, reply: { text: "⚠️ Nothing to fork yet." } };
+  }
+
+  let title = entry.title;
+  if (parsed.action === "fork" && parsed.name) {
+    const parsedTitle = parseSessionTitle(parsed.name);
+    if (!parsedTitle.ok) {
+      return { shouldContinue: false, reply: { text: `⚠️ ${parsedTitle.error}` } };
+    }
+    title = parsedTitle.title;
+  }
+  const sourceFile = resolveSessionTranscriptCandidates(
+    entry.sessionId,
+    storePath,
+    entry.sessionFile,
+  ).find((candidate) => fs.existsSync(candidate));
+  if (!sourceFile) {
+    return { shouldContinue: false, reply: { text: "⚠️ Nothing to fork yet." } };
+  }
+  const rewindTurns = parsed.action === "rewind" ? parsed.turns : 0;
+  const branch = branchSessionTranscript({ sessionFile: sourceFile, rewindTurns });
+  if (!branch.ok) {
+    return { shouldContinue: false, reply: { text: `⚠️ ${branch.error}` } };
+  }
+
+  const saved: SessionEntry = { ...entry };
+  const savedKey = resolveForkSavedKey(params.sessionKey, saved.sessionId);
+  const fork = buildForkedSessionEntry(saved, {
+    sessionId: branch.sessionId,
+    sessionFile: branch.sessionFile,
+    title,
+    rewound: rewindTurns > 0,
+  });
+  // Update in place: later stages of this turn still hold a reference to the entry.
+  for (const key of Object.keys(entry)) {
+    if (!(key in fork)) {
+      delete (entry as Record<string, unknown>)[key];
+    }
+  }
+  Object.assign(entry, fork);
+  if (params.sessionStore) {
+    params.sessionStore[savedKey] = saved;
+    params.sessionStore[params.sessionKey] = entry;
+  }
+  await updateSessionStore(storePath, (store) => {
+    store[savedKey] = saved;
+    store[params.sessionKey] = entry;
+  });
+
+  const renamed = title && title !== saved.title ? ` as "${title}"` : "";
+  const text =
+    parsed.action === "rewind"
+      ? `⏪ Rewound ${rewindTurns} turn(s). The full conversation is saved as ${savedKey}.`
+      : `🍴 Forked${renamed}. The conversation so far is saved as ${savedKey}.`;
+  return { shouldContinue: false, reply: { text } };
+};
@@ -1,5 +1,13 @@
-import { describe, expect, it } from "vitest";
-import { buildCommandsPaginationKeyboard } from "./commands-info.js";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { CommandContext, HandleCommandsParams } from "./commands-types.js";
+import {
+  buildCommandsPaginationKeyboard,
+  handleMemoryCommand,
+  parseMemoryCommand,
+} from "./commands-info.js";
 
 describe("buildCommandsPaginationKeyboard", () => {
   it("adds agent id to callback data when provided", () => {
@@ -11,3 +19,69 @@ describe("buildCommandsPaginationKeyboard", () => {
     ]);
   });
 });
+
+function makeCommand(body: string): CommandContext {
+  return {
+    surface: "whatsapp",
+    channel: "whatsapp",
+    ownerList: [],
+    senderIsOwner: true,
+    isAuthorizedSender: true,
+    rawBodyNormalized: body,
+    commandBodyNormalized: body,
+  };
+}
+
+describe("/memory", () => {
+  let workspaceDir: string;
+
+  beforeEach(async () => {
+    workspaceDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-memory-cmd-"));
+  });
+
+  afterEach(async () => {
+    await fs.rm(workspaceDir, { recursive: true, force: true });
+  });
+
+  const run = async (body: string) =>
+    (
+      await handleMemoryCommand(
+        {
+          command: makeCommand(body),
+          cfg: {},
+          workspaceDir,
+          sessionKey: "agent:main:main",
+        } as unknown as HandleCommandsParams,
+        true,
+      )
+    )?.reply?.text;
+
+  it("parses subcommands", () => {
+    expect(parseMemoryCommand("/memories")).toBeNull();
+    expect(parseMemoryCommand("/memory")).toEqual({ action: "show" });
+    expect(parseMemoryCommand("/memory add  Uses pnpm")).toEqual({
+      action: "add",
+      fact: "Uses pnpm",
+    });
+    expect(parseMemoryCommand("/memory clear project")).toEqual({
+      action: "clear",
+      scope: "project",
+    });
+    expect(parseMemoryCommand("/memory add")?.action).toBe("invalid");
+    expect(parseMemoryCommand("/memory wipe")?.action).toBe("invalid");
+  });
+
+  it("adds, shows, points at, and clears the memory file", async () => {
+    expect(await run("/memory show")).toBe("🧠 Memory is empty.");
+    expect(await run("/memory add Prefers tabs")).toMatch(/^🧠 Added to memory/);
+    expect(await run("/memory add prefers  tabs")).toBe("🧠 Already in memory.");
+    expect(await run("/memory")).toContain("- Prefers tabs");
+    expect(await run("/memory edit")).toMatch(/🧠 Memory: .*MEMORY\.md/);
+
+    expect(await run("/memory clear")).toBe("🧹 Memory cleared (15 chars removed).");
+    expect(await fs.readFile(path.join(workspaceDir, "MEMORY.md"), "utf-8")).toBe("");
+    expect(await run("/memory clear project")).toBe(
+      "🧠 No project memory is active for this session.",
+    );
+  });
+});
@@ -1,5 +1,17 @@
+import path from "node:path";
 import type { CommandHandler } from "./commands-types.js";
+import {
+  appendMemoryFact,
+  clearMemoryFile,
+  type MemoryFile,
+  readMemoryFile,
+  resolveMemoryMaxChars,
+  resolveProjectMemoryDir,
+  resolveProjectMemoryRoot,
+} from "../../agents/memory-file.js";
+import { formatTodoList } from "../../agents/tools/todo-tool.js";
 import { logVerbose } from "../../globals.js";
+import { shortenHomePath } from "../../utils.js";
 import { listSkillCommandsForAgents } from "../skill-commands.js";
 import {
   buildCommandsMessage,
@@ -168,6 +180,173 @@ export const handleContextCommand: CommandHandler = async (params, allowTextComm
   return { shouldContinue: false, reply: await buildContextReply(params) };
 };
 
+export const handleTodosCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  if (params.command.commandBodyNormalized !== "/todos") {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /todos from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  return { shouldContinue: false, reply: { text: formatTodoList(params.sessionEntry?.todos) } };
+};
+
+const MEMORY_REPLY_MAX_CHARS = 3500;
+
+export type MemoryCommandAction =
+  | { action: "show" }
+  | { action: "add"; fact: string }
+  | { action: "edit" }
+  | { action: "clear"; scope?: "global" | "project" }
+  | { action: "invalid"; message: string };
+
+const MEMORY_USAGE = "Usage: /memory [show | add <fact> | edit | clear [global|project]]";
+
+export function parseMemoryCommand(normalized: string): MemoryCommandAction | null {
+  if (normalized !== "/memory" && !normalized.startsWith("/memory ")) {
+    return null;
+  }
+  const rest = normalized.slice("/memory".length).trim();
+  const [verb = "", ...args] = rest.split(/\s+/);
+  switch (verb.toLowerCase()) {
+    case "":
+    case "show":
+      return { action: "show" };
+    case "add": {
+      const fact = rest.slice(verb.length).trim();
+      return fact ? { action: "add", fact } : { action: "invalid", message: MEMORY_USAGE };
+    }
+    case "edit":
+      return { action: "edit" };
+    case "clear": {
+      const scope = args[0]?.toLowerCase();
+      if (!scope) {
+        return { action: "clear" };
+      }
+      return scope === "global" || scope === "project"
+        ? { action: "clear", scope }
+        : { action: "invalid", message: MEMORY_USAGE };
+    }
+    default:
+      return { action: "invalid", message: MEMORY_USAGE };
+  }
+}
+
+export const handleMemoryCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parseMemoryCommand(params.command.commandBodyNormalized);
+  if (!parsed) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /memory from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  if (params.isGroup) {
+    return { shouldContinue: false, reply: { text: "🧠 Memory is only shown in direct chats." } };
+  }
+  if (parsed.action === "invalid") {
+    return { shouldContinue: false, reply: { text: `⚙️ ${parsed.message}` } };
+  }
+  const maxChars = resolveMemoryMaxChars(params.cfg);
+  const projectRoot = resolveProjectMemoryRoot({
+    workspaceDir: params.workspaceDir,
+    config: params.cfg,
+    sessionKey: params.sessionKey,
+    agentId: params.agentId,
+  });
+  const projectDir = projectRoot ? resolveProjectMemoryDir(projectRoot) : null;
+
+  if (parsed.action === "add") {
+    // Same default as memory_write: facts land in the active project's namespace when there is one.
+    try {
+      const result = await appendMemoryFact({
+        workspaceDir: projectDir ?? params.workspaceDir,
+        fact: parsed.fact,
+        maxChars,
+      });
+      const where = projectRoot ? `project memory (${shortenHomePath(projectRoot)})` : "memory";
+      const text =
+        result.status === "exists"
+          ? `🧠 Already in ${where}.`
+          : `🧠 Added to ${where} (${result.chars}/${maxChars} chars).`;
+      return { shouldContinue: false, reply: { text } };
+    } catch (err) {
+      return {
+        shouldContinue: false,
+        reply: { text: `⚠️ Could not add to memory: ${(err as Error).message}` },
+      };
+    }
+  }
+
+  if (parsed.action === "clear") {
+    const targets: Array<{ label: string; dir: string }> = [];
+    if (parsed.scope !== "project") {
+      targets.push({ label: projectDir ? "Global memory" : "Memory", dir: params.workspaceDir });
+    }
+    if (parsed.scope !== "global" && projectRoot && projectDir) {
+      targets.push({ label: `Project memory (${shortenHomePath(projectRoot)})`, dir: projectDir });
+    }
+    if (targets.length === 0) {
+      return {
+        shouldContinue: false,
+        reply: { text: "🧠 No project memory is active for this session." },
+      };
+    }
+    const lines: string[] = [];
+    for (const target of targets) {
+      const result = await clearMemoryFile(target.dir);
+      lines.push(
+        result.clearedChars > 0
+          ? `🧹 ${target.label} cleared (${result.clearedChars} chars removed).`
+          : `🧠 ${target.label} was already empty.`,
+      );
+    }
+    return { shouldContinue: false, reply: { text: lines.join("\n") } };
+  }
+
+  const file = await readMemoryFile(params.workspaceDir);
+  const projectFile = projectDir ? await readMemoryFile(projectDir) : null;
+
+  if (parsed.action === "edit") {
+    // Chat surfaces cannot spawn an editor; point at the files instead (the TUI opens $EDITOR).
+    const lines = [`🧠 ${projectFile ? "Global memory" : "Memory"}: ${shortenHomePath(file.path)}`];
+    if (projectRoot && projectFile) {
+      lines.push(`🧠 Project memory: ${shortenHomePath(projectFile.path)}`);
+    }
+    lines.push("Edit the file directly, or run /memory edit in the TUI to open it in $EDITOR.");
+    return { shouldContinue: false, reply: { text: lines.join("\n") } };
+  }
+
+  const formatSection = (label: string, entry: MemoryFile) => {
+    const text = entry.text.trim();
+    const hidden = text.length - MEMORY_REPLY_MAX_CHARS;
+    return [
+      `🧠 ${label} (${path.basename(entry.path)}, ${entry.text.length}/${maxChars} chars)`,
+      hidden > 0 ? `${text.slice(0, MEMORY_REPLY_MAX_CHARS)}\n… (${hidden} more chars)` : text,
+    ].join("\n");
+  };
+  const sections = [
+    file.text.trim() ? formatSection(projectFile ? "Global memory" : "Memory", file) : "",
+    projectRoot && projectFile?.text.trim()
+      ? formatSection(`Project memory: ${shortenHomePath(projectRoot)}`, projectFile)
+      : "",
+  ].filter(Boolean);
+  if (sections.length === 0) {
+    return { shouldContinue: false, reply: { text: "🧠 Memory is empty." } };
+  }
+  return { shouldContinue: false, reply: { text: sections.join("\n\n") } };
+};
+
 export const handleWhoamiCommand: CommandHandler = async (params, allowTextCommands) => {
   if (!allowTextCommands) {
     return null;
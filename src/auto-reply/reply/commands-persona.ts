@@ -0,0 +1,127 @@
+import type { CommandHandler } from "./commands-types.js";
+import { DEFAULT_MODEL, DEFAULT_PROVIDER } from "../../agents/defaults.js";
+import { loadModelCatalog } from "../../agents/model-catalog.js";
+import { resolveAllowedModelRef, resolveConfiguredModelRef } from "../../agents/model-selection.js";
+import { listPersonas, loadPersona, resolvePersonasDir } from "../../agents/personas.js";
+import { updateSessionStore } from "../../config/sessions.js";
+import { logVerbose } from "../../globals.js";
+import { applyModelOverrideToSessionEntry } from "../../sessions/model-overrides.js";
+
+const OFF_ACTIONS = new Set(["off", "clear", "none", "reset"]);
+
+export function parsePersonaCommand(normalized: string): { args: string } | null {
+  if (normalized !== "/persona" && !normalized.startsWith("/persona ")) {
+    return null;
+  }
+  return { args: normalized.slice("/persona".length).trim() };
+}
+
+async function formatPersonaList(current: string | undefined): Promise<string> {
+  const personas = await listPersonas();
+  if (personas.length === 0) {
+    return `🎭 No personas yet. Add Markdown files to ${resolvePersonasDir()} (e.g. reviewer.md).`;
+  }
+  const lines = [`🎭 Persona: ${current ?? "none"}`];
+  for (const persona of personas) {
+    const marker = persona.name === current ? "•" : "-";
+    const description = persona.description ? ` — ${persona.description}` : "";
+    lines.push(`${marker} ${persona.name}${description}`);
+  }
+  lines.push("Use /persona <name> or /persona off.");
+  return lines.join("\n");
+}
+
+/**
+ * `/persona <name>` selects a system prompt preset for the session; a preset `model` is applied
+ * like `/model`. The preset prompt and temperature are applied on every run while it is active.
+ */
+export const handlePersonaCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parsePersonaCommand(params.command.commandBodyNormalized);
+  if (!parsed) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /persona from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  const entry = params.sessionEntry;
+  if (!parsed.args || parsed.args.toLowerCase() === "list") {
+    return {
+      shouldContinue: false,
+      reply: { text: await formatPersonaList(entry?.persona) },
+    };
+  }
+  if (!entry) {
+    return { shouldContinue: false, reply: { text: "⚠️ Personas need an active session." } };
+  }
+
+  const persist = async () => {
+    entry.updatedAt = Date.now();
+    if (params.sessionStore) {
+      params.sessionStore[params.sessionKey] = entry;
+    }
+    if (params.storePath) {
+      await updateSessionStore(params.storePath, (store) => {
+        store[params.sessionKey] = entry;
+      });
+    }
+  };
+
+  if (OFF_ACTIONS.has(parsed.args.toLowerCase())) {
+    delete entry.persona;
+    await persist();
+    return { shouldContinue: false, reply: { text: "🎭 Persona cleared." } };
+  }
+
+  const persona = await loadPersona(parsed.args);
+  if (!persona) {
+    return {
+      shouldContinue: false,
+      reply: {
+        text: `⚠️ Unknown persona "${parsed.args}".\n${await formatPersonaList(entry.persona)}`,
+      },
+    };
+  }
+  entry.persona = persona.name;
+
+  let modelNote = "";
+  if (persona.model) {
+    const configured = resolveConfiguredModelRef({
+      cfg: params.cfg,
+      defaultProvider: DEFAULT_PROVIDER,
+      defaultModel: DEFAULT_MODEL,
+    });
+    const resolved = resolveAllowedModelRef({
+      cfg: params.cfg,
+      catalog: await loadModelCatalog({ config: params.cfg }),
+      raw: persona.model,
+      defaultProvider: configured.provider,
+      defaultModel: configured.model,
+    });
+    if ("error" in resolved) {
+      modelNote = ` Model not changed (${resolved.error}).`;
+    } else {
+      applyModelOverrideToSessionEntry({
+        entry,
+        selection: {
+          provider: resolved.ref.provider,
+          model: resolved.ref.model,
+          isDefault:
+            resolved.ref.provider === configured.provider &&
+            resolved.ref.model === configured.model,
+        },
+      });
+      modelNote = ` Model: ${resolved.key}.`;
+    }
+  }
+  await persist();
+  return {
+    shouldContinue: false,
+    reply: { text: `🎭 Persona set to ${persona.name}.${modelNote}` },
+  };
+};
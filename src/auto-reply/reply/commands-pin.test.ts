@@ -0,0 +1,102 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandContext, HandleCommandsParams } from "./commands-types.js";
+import { buildPinnedMessagesContext } from "../../sessions/pinned-messages.js";
+import { handlePinCommand, parsePinCommand } from "./commands-pin.js";
+
+function makeCommand(body: string, raw = body): CommandContext {
+  return {
+    surface: "whatsapp",
+    channel: "whatsapp",
+    ownerList: [],
+    senderIsOwner: true,
+    isAuthorizedSender: true,
+    rawBodyNormalized: raw,
+    commandBodyNormalized: body,
+  };
+}
+
+describe("/pin", () => {
+  let dir: string;
+  let storePath: string;
+  let entry: SessionEntry;
+  let sessionStore: Record<string, SessionEntry>;
+  const sessionKey = "agent:main:main";
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-pin-"));
+    storePath = path.join(dir, "sessions.json");
+    entry = { sessionId: "sess-pin", updatedAt: 0 };
+    sessionStore = { [sessionKey]: entry };
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  const run = (body: string, raw?: string) =>
+    handlePinCommand(
+      {
+        command: makeCommand(body, raw),
+        sessionEntry: entry,
+        sessionStore,
+        sessionKey,
+        storePath,
+      } as unknown as HandleCommandsParams,
+      true,
+    );
+
+  it("parses actions and pinned text", () => {
+    expect(parsePinCommand("/pin")).toEqual({ action: "last" });
+    expect(parsePinCommand("/pin reply")).toEqual({ action: "reply" });
+    expect(parsePinCommand("/pin remove 2")).toEqual({ action: "remove", index: 2 });
+    expect(parsePinCommand("/pin remove two")).toEqual({ action: "usage" });
+    expect(parsePinCommand("/pin list the constraints")).toEqual({
+      action: "text",
+      text: "list the constraints",
+    });
+    expect(parsePinCommand("/pin Never touch prod", "/pin Never touch prod\nUse staging")).toEqual(
+      { action: "text", text: "Never touch prod\nUse staging" },
+    );
+    expect(parsePinCommand("/pinned")).toBeNull();
+  });
+
+  it("pins the last user message and persists it", async () => {
+    const transcript = [
+      { message: { role: "user", content: [{ type: "text", text: "Port the parser to Rust." }] } },
+      { message: { role: "assistant", content: [{ type: "text", text: "On it." }] } },
+    ];
+    await fs.writeFile(
+      path.join(dir, "sess-pin.jsonl"),
+      transcript.map((line) => JSON.stringify(line)).join("\n"),
+    );
+
+    const result = await run("/pin");
+    expect(result?.reply?.text).toBe("📌 Pinned #1: Port the parser to Rust.");
+    expect(entry.pinnedMessages).toMatchObject([{ role: "user", text: "Port the parser to Rust." }]);
+    const stored = JSON.parse(await fs.readFile(storePath, "utf8"));
+    expect(stored[sessionKey].pinnedMessages).toHaveLength(1);
+
+    await run("/pin reply");
+    expect(entry.pinnedMessages?.map((pin) => pin.role)).toEqual(["user", "assistant"]);
+
+    const prompt = buildPinnedMessagesContext(entry.pinnedMessages);
+    expect(prompt).toMatch(/^Pinned messages \(untrusted,/);
+    expect(prompt).toContain('<pinned id="1" role="user">\nPort the parser to Rust.\n</pinned>');
+    expect(prompt).toContain('<pinned id="2" role="assistant">\nOn it.\n</pinned>');
+  });
+
+  it("removes and clears pins", async () => {
+    await run("/pin first");
+    await run("/pin second");
+    expect((await run("/pin remove 1"))?.reply?.text).toBe("📌 Unpinned: first");
+    expect(entry.pinnedMessages?.map((pin) => pin.text)).toEqual(["second"]);
+    expect((await run("/pin remove 5"))?.reply?.text).toContain("No pinned message #5");
+    await run("/pin clear");
+    expect(entry.pinnedMessages).toBeUndefined();
+    expect(buildPinnedMessagesContext(entry.pinnedMessages)).toBe("");
+  });
+});
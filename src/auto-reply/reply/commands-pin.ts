@@ -0,0 +1,182 @@
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandHandler } from "./commands-types.js";
+import { updateSessionStore } from "../../config/sessions.js";
+import { stripEnvelopeFromMessage } from "../../gateway/chat-sanitize.js";
+import { readSessionMessages } from "../../gateway/session-utils.fs.js";
+import { logVerbose } from "../../globals.js";
+import { addPinnedMessage, removePinnedMessage } from "../../sessions/pinned-messages.js";
+import { readLatestAssistantText } from "./commands-plan.js";
+
+const USAGE = "📌 Usage: /pin [text] | /pin reply | /pin list | /pin remove <n> | /pin clear";
+const PREVIEW_CHARS = 80;
+
+export function parsePinCommand(
+  normalized: string,
+  raw?: string,
+):
+  | { action: "last" | "reply" | "list" | "clear" }
+  | { action: "remove"; index: number }
+  | { action: "text"; text: string }
+  | { action: "usage" }
+  | null {
+  if (normalized !== "/pin" && !normalized.startsWith("/pin ")) {
+    return null;
+  }
+  const rest = normalized.slice("/pin".length).trim();
+  if (!rest) {
+    return { action: "last" };
+  }
+  const [first = "", ...tail] = rest.split(/\s+/);
+  const keyword = first.toLowerCase();
+  if (tail.length === 0 && (keyword === "reply" || keyword === "list" || keyword === "clear")) {
+    return { action: keyword };
+  }
+  if (keyword === "remove" || keyword === "rm") {
+    const index = Number.parseInt(tail[0] ?? "", 10);
+    return tail.length === 1 && index > 0 ? { action: "remove", index } : { action: "usage" };
+  }
+  // The normalized body keeps only the first line; take multi-line text from the raw body.
+  const rawMatch = raw?.trim().match(/^\/pin(?:@\S+)?\s*:?\s*([\s\S]*)$/i);
+  return { action: "text", text: rawMatch ? rawMatch[1].trim() : rest };
+}
+
+function extractUserText(message: unknown): string | undefined {
+  const stripped = stripEnvelopeFromMessage(message) as { role?: unknown; content?: unknown };
+  if (stripped?.role !== "user") {
+    return undefined;
+  }
+  if (typeof stripped.content === "string") {
+    return stripped.content.trim() || undefined;
+  }
+  if (!Array.isArray(stripped.content)) {
+    return undefined;
+  }
+  const text = stripped.content
+    .map((block) =>
+      block && typeof block === "object" && (block as { type?: unknown }).type === "text"
+        ? String((block as { text?: unknown }).text ?? "")
+        : "",
+    )
+    .join("\n")
+    .trim();
+  return text || undefined;
+}
+
+/** Text of the most recent user message in the session transcript. */
+function readLatestUserText(entry: SessionEntry, storePath?: string): string | undefined {
+  const messages = readSessionMessages(entry.sessionId, storePath, entry.sessionFile);
+  for (let i = messages.length - 1; i >= 0; i--) {
+    const text = extractUserText(messages[i]);
+    if (text) {
+      return text;
+    }
+  }
+  return undefined;
+}
+
+function preview(text: string): string {
+  const line = text.replace(/\s+/g, " ").trim();
+  return line.length > PREVIEW_CHARS ? `${line.slice(0, PREVIEW_CHARS - 1)}…` : line;
+}
+
+function formatPinList(entry: SessionEntry): string {
+  const pins = entry.pinnedMessages ?? [];
+  if (pins.length === 0) {
+    return `📌 No pinned messages.\n${USAGE}`;
+  }
+  const lines = ["📌 Pinned messages:"];
+  pins.forEach((pin, index) => {
+    lines.push(`${index + 1}. (${pin.role}) ${preview(pin.text)}`);
+  });
+  return lines.join("\n");
+}
+
+/**
+ * `/pin` keeps a message verbatim for the rest of the session: pins are re-sent with every run, so
+ * compaction cannot summarize them away. Without text it pins the last user message.
+ */
+export const handlePinCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parsePinCommand(
+    params.command.commandBodyNormalized,
+    params.command.rawBodyNormalized,
+  );
+  if (!parsed) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(`Ignoring /pin from unauthorized sender: ${params.command.senderId || "<unknown>"}`);
+    return { shouldContinue: false };
+  }
+  const entry = params.sessionEntry;
+  if (!entry) {
+    return { shouldContinue: false, reply: { text: "⚠️ Pinning needs an active session." } };
+  }
+
+  const persist = async () => {
+    entry.updatedAt = Date.now();
+    if (params.sessionStore) {
+      params.sessionStore[params.sessionKey] = entry;
+    }
+    if (params.storePath) {
+      await updateSessionStore(params.storePath, (store) => {
+        store[params.sessionKey] = entry;
+      });
+    }
+  };
+
+  switch (parsed.action) {
+    case "usage":
+      return { shouldContinue: false, reply: { text: USAGE } };
+    case "list":
+      return { shouldContinue: false, reply: { text: formatPinList(entry) } };
+    case "clear": {
+      const count = entry.pinnedMessages?.length ?? 0;
+      delete entry.pinnedMessages;
+      await persist();
+      return { shouldContinue: false, reply: { text: `📌 Unpinned ${count} message(s).` } };
+    }
+    case "remove": {
+      const removed = removePinnedMessage(entry, parsed.index);
+      if (!removed) {
+        return {
+          shouldContinue: false,
+          reply: { text: `⚠️ No pinned message #${parsed.index}.\n${formatPinList(entry)}` },
+        };
+      }
+      await persist();
+      return { shouldContinue: false, reply: { text: `📌 Unpinned: ${preview(removed.text)}` } };
+    }
+    default:
+      break;
+  }
+
+  const role = parsed.action === "reply" ? "assistant" : "user";
+  const text =
+    parsed.action === "text"
+      ? parsed.text
+      : parsed.action === "reply"
+        ? readLatestAssistantText(entry, params.storePath)
+        : readLatestUserText(entry, params.storePath);
+  if (!text) {
+    return {
+      shouldContinue: false,
+      reply: {
+        text:
+          parsed.action === "reply" ? "📌 No reply to pin yet." : "📌 No message to pin yet.",
+      },
+    };
+  }
+  const added = addPinnedMessage(entry, { text, role });
+  if (!added.ok) {
+    return { shouldContinue: false, reply: { text: `⚠️ ${added.error}` } };
+  }
+  await persist();
+  const count = entry.pinnedMessages?.length ?? 0;
+  return {
+    shouldContinue: false,
+    reply: { text: `📌 Pinned #${count}: ${preview(added.pin.text)}` },
+  };
+};
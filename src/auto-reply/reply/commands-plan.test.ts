@@ -0,0 +1,115 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { AnyAgentTool } from "../../agents/tools/common.js";
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandContext } from "./commands-types.js";
+import { filterToolsForPlanMode } from "../../agents/plan-mode.js";
+import { handlePlanCommand, parsePlanCommand } from "./commands-plan.js";
+
+function makeCommand(body: string): CommandContext {
+  return {
+    surface: "whatsapp",
+    channel: "whatsapp",
+    ownerList: [],
+    senderIsOwner: true,
+    isAuthorizedSender: true,
+    rawBodyNormalized: body,
+    commandBodyNormalized: body,
+  };
+}
+
+describe("/plan", () => {
+  let dir: string;
+  let storePath: string;
+  let entry: SessionEntry;
+  let sessionStore: Record<string, SessionEntry>;
+  const sessionKey = "agent:main:main";
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-plan-"));
+    storePath = path.join(dir, "sessions.json");
+    entry = { sessionId: "sess-plan", updatedAt: 0 };
+    sessionStore = { [sessionKey]: entry };
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  const run = (body: string) =>
+    handlePlanCommand({
+      command: makeCommand(body),
+      sessionEntry: entry,
+      sessionStore,
+      sessionKey,
+      storePath,
+    });
+
+  it("parses toggles, approvals, and tasks", () => {
+    expect(parsePlanCommand("/plan")).toEqual({ action: "on", args: "" });
+    expect(parsePlanCommand("/plan off")).toEqual({ action: "off", args: "" });
+    expect(parsePlanCommand("/plan approve skip step 3")).toEqual({
+      action: "approve",
+      args: "skip step 3",
+    });
+    expect(parsePlanCommand("/plan stop the cache from growing")).toEqual({
+      action: "task",
+      args: "stop the cache from growing",
+    });
+    expect(parsePlanCommand("/planet")).toBeNull();
+  });
+
+  it("turns plan mode on for a task and injects the latest plan on approval", async () => {
+    const task = await run("/plan migrate the config loader");
+    expect(task).toEqual({ kind: "continue", body: "migrate the config loader" });
+    expect(entry.planMode).toBe(true);
+    const stored = JSON.parse(await fs.readFile(storePath, "utf8"));
+    expect(stored[sessionKey].planMode).toBe(true);
+
+    const transcript = [
+      { message: { role: "user", content: [{ type: "text", text: "migrate" }] } },
+      {
+        message: {
+          role: "assistant",
+          content: [{ type: "text", text: "1. Update loader.ts\n2. Run tests" }],
+        },
+      },
+    ];
+    await fs.writeFile(
+      path.join(dir, "sess-plan.jsonl"),
+      transcript.map((line) => JSON.stringify(line)).join("\n"),
+    );
+
+    const approved = await run("/plan approve keep the old path working");
+    expect(approved?.kind).toBe("continue");
+    const body = approved?.kind === "continue" ? approved.body : "";
+    expect(body).toContain("Approved plan:\n1. Update loader.ts\n2. Run tests");
+    expect(body).toContain("Additional instructions from the user:\nkeep the old path working");
+    expect(entry.planMode).toBeUndefined();
+  });
+
+  it("refuses to approve outside plan mode", async () => {
+    const result = await run("/plan approve");
+    expect(result).toMatchObject({
+      kind: "reply",
+      reply: { text: expect.stringContaining("Plan mode is off") },
+    });
+  });
+
+  it("keeps only read-only tools in plan mode", () => {
+    const tools = ["read", "write", "exec", "grep", "apply_patch", "web_fetch"].map((name) => ({
+      name,
+      label: name,
+      description: name,
+      parameters: {},
+      execute: async () => ({ content: [], details: {} }),
+    })) as unknown as AnyAgentTool[];
+    expect(filterToolsForPlanMode(tools).map((tool) => tool.name)).toEqual([
+      "read",
+      "grep",
+      "web_fetch",
+    ]);
+  });
+});
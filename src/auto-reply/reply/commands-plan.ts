@@ -0,0 +1,127 @@
+import type { SessionEntry } from "../../config/sessions.js";
+import type { ReplyPayload } from "../types.js";
+import type { CommandContext } from "./commands-types.js";
+import { buildPlanApprovalPrompt } from "../../agents/plan-mode.js";
+import { extractAssistantText } from "../../agents/tools/sessions-helpers.js";
+import { updateSessionStore } from "../../config/sessions.js";
+import { readSessionMessages } from "../../gateway/session-utils.fs.js";
+import { logVerbose } from "../../globals.js";
+
+export type PlanCommandResult =
+  | { kind: "reply"; reply?: ReplyPayload }
+  /** Continue to the agent with this prompt (a task to plan, or an approved plan to execute). */
+  | { kind: "continue"; body: string };
+
+const OFF_ACTIONS = new Set(["off", "cancel", "exit", "stop"]);
+const USAGE = "📝 Usage: /plan [task] | /plan approve [notes] | /plan off";
+
+export function parsePlanCommand(
+  normalized: string,
+): { action: "on" | "off" | "approve" | "task"; args: string } | null {
+  if (normalized !== "/plan" && !normalized.startsWith("/plan ")) {
+    return null;
+  }
+  const rest = normalized.slice("/plan".length).trim();
+  if (!rest || rest.toLowerCase() === "on") {
+    return { action: "on", args: "" };
+  }
+  const [first = "", ...tail] = rest.split(/\s+/);
+  const keyword = first.toLowerCase();
+  if (OFF_ACTIONS.has(keyword) && tail.length === 0) {
+    return { action: "off", args: "" };
+  }
+  if (keyword === "approve") {
+    return { action: "approve", args: rest.slice(first.length).trim() };
+  }
+  return { action: "task", args: rest };
+}
+
+/** Text of the most recent assistant reply in the session transcript. */
+export function readLatestAssistantText(
+  entry: SessionEntry,
+  storePath?: string,
+): string | undefined {
+  const messages = readSessionMessages(entry.sessionId, storePath, entry.sessionFile);
+  for (let i = messages.length - 1; i >= 0; i--) {
+    const text = extractAssistantText(messages[i]);
+    if (text) {
+      return text;
+    }
+  }
+  return undefined;
+}
+
+/**
+ * `/plan` toggles plan mode for the session. While it is on, runs only get read-only tools and are
+ * asked for a plan; `/plan approve` turns it off and re-runs with the latest plan injected.
+ */
+export async function handlePlanCommand(params: {
+  command: CommandContext;
+  sessionEntry?: SessionEntry;
+  sessionStore?: Record<string, SessionEntry>;
+  sessionKey: string;
+  storePath?: string;
+}): Promise<PlanCommandResult | null> {
+  const parsed = parsePlanCommand(params.command.commandBodyNormalized);
+  if (!parsed) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /plan from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { kind: "reply" };
+  }
+  const entry = params.sessionEntry;
+  if (!entry) {
+    return { kind: "reply", reply: { text: "⚠️ Plan mode needs an active session." } };
+  }
+
+  const persist = async (planMode: boolean) => {
+    if (planMode) {
+      entry.planMode = true;
+    } else {
+      delete entry.planMode;
+    }
+    entry.updatedAt = Date.now();
+    if (params.sessionStore) {
+      params.sessionStore[params.sessionKey] = entry;
+    }
+    if (params.storePath) {
+      await updateSessionStore(params.storePath, (store) => {
+        store[params.sessionKey] = entry;
+      });
+    }
+  };
+
+  switch (parsed.action) {
+    case "on":
+      await persist(true);
+      return {
+        kind: "reply",
+        reply: {
+          text: "📝 Plan mode on: read-only tools only. Describe the change and I'll propose a plan; /plan approve to execute it, /plan off to leave.",
+        },
+      };
+    case "off":
+      await persist(false);
+      return { kind: "reply", reply: { text: "📝 Plan mode off. All tools are available." } };
+    case "task":
+      await persist(true);
+      return { kind: "continue", body: parsed.args };
+    case "approve": {
+      if (!entry.planMode) {
+        return { kind: "reply", reply: { text: `📝 Plan mode is off.\n${USAGE}` } };
+      }
+      const plan = readLatestAssistantText(entry, params.storePath);
+      if (!plan) {
+        return {
+          kind: "reply",
+          reply: { text: "📝 No plan to approve yet. Describe the change first." },
+        };
+      }
+      await persist(false);
+      return { kind: "continue", body: buildPlanApprovalPrompt(plan, parsed.args) };
+    }
+  }
+}
@@ -0,0 +1,92 @@
+import type { CommandHandler } from "./commands-types.js";
+import {
+  formatReviewFindings,
+  type ReviewTarget,
+  runCodeReview,
+} from "../../agents/code-review.js";
+import { resolveSessionGitRoot } from "../../agents/git-commit.js";
+import { logVerbose } from "../../globals.js";
+
+const USAGE = "🔎 Usage: /review [ref | --staged | --pr <number>]";
+
+export function parseReviewCommand(
+  normalized: string,
+): { target: ReviewTarget } | { error: string } | null {
+  if (normalized !== "/review" && !normalized.startsWith("/review ")) {
+    return null;
+  }
+  const tokens = normalized.slice("/review".length).trim().split(/\s+/).filter(Boolean);
+  if (tokens.length === 0) {
+    return { target: { kind: "worktree" } };
+  }
+  const [first = "", second] = tokens;
+  if ((first === "--staged" || first === "--cached") && tokens.length === 1) {
+    return { target: { kind: "staged" } };
+  }
+  const prMatch = first.match(/^--pr(?:=(\S+))?$/);
+  if (prMatch) {
+    const raw = prMatch[1] ?? second;
+    const number = Number(raw?.replace(/^#/, ""));
+    if (tokens.length > (prMatch[1] ? 1 : 2) || !Number.isInteger(number) || number <= 0) {
+      return { error: USAGE };
+    }
+    return { target: { kind: "pr", number } };
+  }
+  if (tokens.length > 1 || first.startsWith("-")) {
+    return { error: USAGE };
+  }
+  return { target: { kind: "ref", ref: first } };
+}
+
+/**
+ * `/review` reviews uncommitted changes (or a ref, the index, or a GitHub PR) with the session's
+ * model, chunked to fit its context window, and replies with findings grouped by file.
+ */
+export const handleReviewCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parseReviewCommand(params.command.commandBodyNormalized);
+  if (!parsed) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /review from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  if ("error" in parsed) {
+    return { shouldContinue: false, reply: { text: parsed.error } };
+  }
+  const root = resolveSessionGitRoot({
+    workspaceDir: params.workspaceDir,
+    config: params.cfg,
+    sessionKey: params.sessionKey,
+    agentId: params.agentId,
+  });
+  if (!root) {
+    return {
+      shouldContinue: false,
+      reply: {
+        text: "⚠️ /review needs the workspace (or agents.defaults.repoRoot) to be a git repo.",
+      },
+    };
+  }
+  try {
+    const result = await runCodeReview({
+      cfg: params.cfg,
+      provider: params.provider,
+      model: params.model,
+      root,
+      target: parsed.target,
+      contextTokens: params.contextTokens,
+    });
+    return { shouldContinue: false, reply: { text: formatReviewFindings(result) } };
+  } catch (err) {
+    return {
+      shouldContinue: false,
+      reply: { text: `⚠️ /review failed: ${err instanceof Error ? err.message : String(err)}` },
+    };
+  }
+};
@@ -162,13 +162,21 @@ export const handleUsageCommand: CommandHandler = async (params, allowTextComman
   const rawArgs = normalized === "/usage" ? "" : normalized.slice("/usage".length).trim();
   const requested = rawArgs ? normalizeUsageDisplay(rawArgs) : undefined;
   if (rawArgs.toLowerCase().startsWith("cost")) {
-    const sessionSummary = await loadSessionCostSummary({
-      sessionId: params.sessionEntry?.sessionId,
-      sessionEntry: params.sessionEntry,
-      sessionFile: params.sessionEntry?.sessionFile,
-      config: params.cfg,
-      agentId: params.agentId,
-    });
+    // Prefer the totals persisted on the session entry; older sessions fall back to the transcript.
+    const totals = params.sessionEntry?.usageTotals;
+    const sessionSummary = totals
+      ? {
+          totalCost: totals.costUsd,
+          totalTokens: totals.totalTokens,
+          missingCostEntries: totals.runsWithoutCost,
+        }
+      : await loadSessionCostSummary({
+          sessionId: params.sessionEntry?.sessionId,
+          sessionEntry: params.sessionEntry,
+          sessionFile: params.sessionEntry?.sessionFile,
+          config: params.cfg,
+          agentId: params.agentId,
+        });
     const summary = await loadCostUsageSummary({ days: 30, config: params.cfg });
 
     const sessionCost = formatUsd(sessionSummary?.totalCost);
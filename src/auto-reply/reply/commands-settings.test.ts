@@ -0,0 +1,57 @@
+import { describe, expect, it } from "vitest";
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandContext, HandleCommandsParams } from "./commands-types.js";
+import { handleSettingsCommand } from "./commands-settings.js";
+
+function makeCommand(body: string): CommandContext {
+  return {
+    surface: "whatsapp",
+    channel: "whatsapp",
+    ownerList: [],
+    senderIsOwner: true,
+    isAuthorizedSender: true,
+    rawBodyNormalized: body,
+    commandBodyNormalized: body,
+  };
+}
+
+describe("/settings", () => {
+  const run = async (body: string, entry?: SessionEntry) =>
+    (
+      await handleSettingsCommand(
+        {
+          command: makeCommand(body),
+          sessionEntry: entry,
+          sessionKey: "agent:main:main",
+          provider: "anthropic",
+          model: "claude-opus-4-5",
+          resolvedThinkLevel: "low",
+          resolvedVerboseLevel: "off",
+          resolvedReasoningLevel: "off",
+          isGroup: false,
+        } as unknown as HandleCommandsParams,
+        true,
+      )
+    )?.reply?.text;
+
+  it("shows stored overrides and marks defaults", async () => {
+    const text = await run("/settings", {
+      sessionId: "sess-settings",
+      updatedAt: 0,
+      providerOverride: "openai",
+      modelOverride: "gpt-5.2",
+      thinkingLevel: "high",
+      disabledTools: ["exec"],
+    });
+    expect(text).toContain("Model: openai/gpt-5.2");
+    expect(text).toContain("Thinking: high");
+    expect(text).toContain("Verbose: off (default)");
+    expect(text).toContain("Disabled tools: exec");
+    expect(text).not.toContain("Plan mode");
+  });
+
+  it("ignores other commands and needs a session", async () => {
+    expect(await run("/settingsx")).toBeUndefined();
+    expect(await run("/settings")).toContain("needs an active session");
+  });
+});
@@ -0,0 +1,79 @@
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandHandler, HandleCommandsParams } from "./commands-types.js";
+import { logVerbose } from "../../globals.js";
+
+function formatSetting(label: string, stored: string | undefined, effective: string): string {
+  return stored ? `${label}: ${stored}` : `${label}: ${effective} (default)`;
+}
+
+/** `/settings` text: per-session overrides, with the effective default where none is stored. */
+export function formatSessionSettings(
+  entry: SessionEntry,
+  params: Pick<
+    HandleCommandsParams,
+    | "provider"
+    | "model"
+    | "resolvedThinkLevel"
+    | "resolvedVerboseLevel"
+    | "resolvedReasoningLevel"
+    | "resolvedElevatedLevel"
+    | "isGroup"
+  >,
+): string {
+  const modelOverride = entry.modelOverride
+    ? entry.providerOverride
+      ? `${entry.providerOverride}/${entry.modelOverride}`
+      : entry.modelOverride
+    : undefined;
+  const lines = [
+    "⚙️ Session settings",
+    formatSetting("Model", modelOverride, `${params.provider}/${params.model}`),
+    formatSetting("Thinking", entry.thinkingLevel, params.resolvedThinkLevel ?? "off"),
+    formatSetting("Verbose", entry.verboseLevel, params.resolvedVerboseLevel),
+    formatSetting("Reasoning", entry.reasoningLevel, params.resolvedReasoningLevel),
+    formatSetting("Elevated", entry.elevatedLevel, params.resolvedElevatedLevel ?? "off"),
+    formatSetting("Usage footer", entry.responseUsage, "off"),
+    formatSetting("Queue", entry.queueMode, "config"),
+  ];
+  if (params.isGroup) {
+    lines.push(formatSetting("Activation", entry.groupActivation, "config"));
+  }
+  if (entry.sendPolicy) {
+    lines.push(`Send policy: ${entry.sendPolicy}`);
+  }
+  if (entry.persona) {
+    lines.push(`Persona: ${entry.persona}`);
+  }
+  if (entry.planMode) {
+    lines.push("Plan mode: on");
+  }
+  if (entry.disabledTools?.length) {
+    lines.push(`Disabled tools: ${entry.disabledTools.join(", ")}`);
+  }
+  lines.push(
+    "Stored with the session and restored when you come back to it. Change with /model, /think, /verbose, /reasoning, /elevated, /usage, /queue, /persona, or /tools.",
+  );
+  return lines.join("\n");
+}
+
+/** `/settings` reviews the overrides stored on the session entry. */
+export const handleSettingsCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const normalized = params.command.commandBodyNormalized;
+  if (normalized !== "/settings" && !normalized.startsWith("/settings ")) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /settings from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  const entry = params.sessionEntry;
+  if (!entry) {
+    return { shouldContinue: false, reply: { text: "⚠️ /settings needs an active session." } };
+  }
+  return { shouldContinue: false, reply: { text: formatSessionSettings(entry, params) } };
+};
@@ -0,0 +1,103 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandContext, HandleCommandsParams } from "./commands-types.js";
+import { cleanGeneratedTitle, normalizeSessionTags } from "../../sessions/session-title.js";
+import { handleTitleCommand, parseTitleCommand } from "./commands-title.js";
+
+function makeCommand(body: string): CommandContext {
+  return {
+    surface: "whatsapp",
+    channel: "whatsapp",
+    ownerList: [],
+    senderIsOwner: true,
+    isAuthorizedSender: true,
+    rawBodyNormalized: body,
+    commandBodyNormalized: body,
+  };
+}
+
+describe("/title", () => {
+  let dir: string;
+  let storePath: string;
+  let entry: SessionEntry;
+  let sessionStore: Record<string, SessionEntry>;
+  const sessionKey = "agent:main:main";
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-title-"));
+    storePath = path.join(dir, "sessions.json");
+    entry = { sessionId: "sess-title", updatedAt: 0, title: "Old", titleSource: "auto" };
+    sessionStore = { [sessionKey]: entry };
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  const run = (body: string) =>
+    handleTitleCommand(
+      {
+        command: makeCommand(body),
+        sessionEntry: entry,
+        sessionStore,
+        sessionKey,
+        storePath,
+      } as unknown as HandleCommandsParams,
+      true,
+    );
+
+  it("parses actions", () => {
+    expect(parseTitleCommand("/title")).toEqual({ action: "show" });
+    expect(parseTitleCommand("/title clear")).toEqual({ action: "clear" });
+    expect(parseTitleCommand("/title tags clear")).toEqual({ action: "clear-tags" });
+    expect(parseTitleCommand("/title tags ci auth")).toEqual({
+      action: "tags",
+      tags: ["ci", "auth"],
+    });
+    expect(parseTitleCommand("/title clear the cache")).toEqual({
+      action: "set",
+      title: "clear the cache",
+    });
+    expect(parseTitleCommand("/titles")).toBeNull();
+  });
+
+  it("sets a user title and tags and persists them", async () => {
+    expect((await run("/title"))?.reply?.text).toBe("🏷️ Title: Old (auto)");
+    expect((await run("/title Fix flaky auth test"))?.reply?.text).toBe(
+      "🏷️ Title set: Fix flaky auth test",
+    );
+    expect((await run("/title tags #CI auth ci"))?.reply?.text).toBe("🏷️ Tags: #ci #auth");
+
+    const stored = JSON.parse(await fs.readFile(storePath, "utf8"));
+    expect(stored[sessionKey]).toMatchObject({
+      title: "Fix flaky auth test",
+      titleSource: "user",
+      tags: ["ci", "auth"],
+    });
+
+    await run("/title clear");
+    expect(entry.title).toBeUndefined();
+    expect(entry.titleSource).toBeUndefined();
+    expect(entry.tags).toEqual(["ci", "auth"]);
+  });
+
+  it("rejects overlong titles", async () => {
+    expect((await run(`/title ${"x".repeat(81)}`))?.reply?.text).toContain("too long");
+    expect(entry.title).toBe("Old");
+  });
+});
+
+describe("session title helpers", () => {
+  it("cleans generated titles", () => {
+    expect(cleanGeneratedTitle('"Fix flaky auth test."\n')).toBe("Fix flaky auth test");
+    expect(cleanGeneratedTitle("Title: Port parser to Rust")).toBe("Port parser to Rust");
+    expect(cleanGeneratedTitle("  \n ")).toBeUndefined();
+  });
+
+  it("normalizes tags", () => {
+    expect(normalizeSessionTags(["#Bug Fix", "bug-fix", "", "v2.1"])).toEqual(["bug-fix", "v2.1"]);
+  });
+});
@@ -0,0 +1,109 @@
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandHandler } from "./commands-types.js";
+import { updateSessionStore } from "../../config/sessions.js";
+import { logVerbose } from "../../globals.js";
+import { normalizeSessionTags, parseSessionTitle } from "../../sessions/session-title.js";
+
+const USAGE = "🏷️ Usage: /title [text] | /title clear | /title tags [tag ...] | /title tags clear";
+
+export function parseTitleCommand(
+  normalized: string,
+):
+  | { action: "show" | "clear" | "clear-tags" }
+  | { action: "set"; title: string }
+  | { action: "tags"; tags: string[] }
+  | null {
+  if (normalized !== "/title" && !normalized.startsWith("/title ")) {
+    return null;
+  }
+  const rest = normalized.slice("/title".length).trim();
+  if (!rest) {
+    return { action: "show" };
+  }
+  const [first = "", ...tail] = rest.split(/\s+/);
+  const keyword = first.toLowerCase();
+  if (keyword === "clear" && tail.length === 0) {
+    return { action: "clear" };
+  }
+  if (keyword === "tags" || keyword === "tag") {
+    if (tail.length === 1 && tail[0].toLowerCase() === "clear") {
+      return { action: "clear-tags" };
+    }
+    return tail.length === 0 ? { action: "show" } : { action: "tags", tags: tail };
+  }
+  return { action: "set", title: rest };
+}
+
+function formatTitle(entry: SessionEntry): string {
+  const lines = [
+    entry.title
+      ? `🏷️ Title: ${entry.title}${entry.titleSource === "auto" ? " (auto)" : ""}`
+      : "🏷️ No title set.",
+  ];
+  if (entry.tags?.length) {
+    lines.push(`Tags: ${entry.tags.map((tag) => `#${tag}`).join(" ")}`);
+  }
+  if (!entry.title && !entry.tags?.length) {
+    lines.push(USAGE);
+  }
+  return lines.join("\n");
+}
+
+export const handleTitleCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parseTitleCommand(params.command.commandBodyNormalized);
+  if (!parsed) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /title from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  const entry = params.sessionEntry;
+  if (!entry) {
+    return { shouldContinue: false, reply: { text: "⚠️ Titles need an active session." } };
+  }
+  if (parsed.action === "show") {
+    return { shouldContinue: false, reply: { text: formatTitle(entry) } };
+  }
+
+  let reply: string;
+  if (parsed.action === "set") {
+    const title = parseSessionTitle(parsed.title);
+    if (!title.ok) {
+      return { shouldContinue: false, reply: { text: `⚠️ ${title.error}` } };
+    }
+    entry.title = title.title;
+    entry.titleSource = "user";
+    reply = `🏷️ Title set: ${title.title}`;
+  } else if (parsed.action === "clear") {
+    delete entry.title;
+    delete entry.titleSource;
+    reply = "🏷️ Title cleared.";
+  } else if (parsed.action === "clear-tags") {
+    delete entry.tags;
+    reply = "🏷️ Tags cleared.";
+  } else {
+    const tags = normalizeSessionTags(parsed.tags);
+    if (tags.length === 0) {
+      return { shouldContinue: false, reply: { text: USAGE } };
+    }
+    entry.tags = tags;
+    reply = `🏷️ Tags: ${tags.map((tag) => `#${tag}`).join(" ")}`;
+  }
+
+  entry.updatedAt = Date.now();
+  if (params.sessionStore) {
+    params.sessionStore[params.sessionKey] = entry;
+  }
+  if (params.storePath) {
+    await updateSessionStore(params.storePath, (store) => {
+      store[params.sessionKey] = entry;
+    });
+  }
+  return { shouldContinue: false, reply: { text: reply } };
+};
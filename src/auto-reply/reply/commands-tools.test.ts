@@ -0,0 +1,99 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandContext, HandleCommandsParams } from "./commands-types.js";
+
+vi.mock("../../agents/pi-tools.js", () => ({
+  createOpenClawCodingTools: () =>
+    ["read", "write", "edit", "exec", "process", "web_search"].map((name) => ({ name })),
+}));
+
+import { loadSessionStore } from "../../config/sessions.js";
+import { handleToolsCommand, parseToolsCommand } from "./commands-tools.js";
+
+function makeCommand(body: string): CommandContext {
+  return {
+    surface: "whatsapp",
+    channel: "whatsapp",
+    ownerList: [],
+    senderIsOwner: true,
+    isAuthorizedSender: true,
+    rawBodyNormalized: body,
+    commandBodyNormalized: body,
+  };
+}
+
+describe("/tools", () => {
+  let dir: string;
+  let storePath: string;
+  let entry: SessionEntry;
+  let sessionStore: Record<string, SessionEntry>;
+  const sessionKey = "agent:main:main";
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-tools-"));
+    storePath = path.join(dir, "sessions.json");
+    entry = { sessionId: "sess-tools", updatedAt: 0 };
+    sessionStore = { [sessionKey]: entry };
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  const run = async (body: string) =>
+    (
+      await handleToolsCommand(
+        {
+          command: makeCommand(body),
+          sessionEntry: entry,
+          sessionStore,
+          sessionKey,
+          storePath,
+        } as unknown as HandleCommandsParams,
+        true,
+      )
+    )?.reply?.text;
+
+  it("parses actions", () => {
+    expect(parseToolsCommand("/tools")).toEqual({ action: "list" });
+    expect(parseToolsCommand("/tools disable exec, process")).toEqual({
+      action: "disable",
+      names: ["exec", "process"],
+    });
+    expect(parseToolsCommand("/tools reset")).toEqual({ action: "reset" });
+    expect(parseToolsCommand("/tools enable")).toEqual({ action: "invalid" });
+    expect(parseToolsCommand("/toolsmith")).toBeNull();
+  });
+
+  it("disables and re-enables tools (and groups) for the session", async () => {
+    const disabled = await run("/tools disable group:runtime bogus");
+    expect(disabled).toContain("Disabled for this session: exec, process.");
+    expect(disabled).toContain("Skipped (not available here): bash_output, kill_task, bogus");
+    expect(entry.disabledTools).toEqual(["exec", "process"]);
+    expect(loadSessionStore(storePath, { skipCache: true })[sessionKey]?.disabledTools).toEqual([
+      "exec",
+      "process",
+    ]);
+
+    const list = await run("/tools");
+    expect(list).toContain("Tools enabled (4): edit, read, web_search, write");
+    expect(list).toContain("Disabled for this session: exec, process");
+
+    await run("/tools enable exec");
+    expect(entry.disabledTools).toEqual(["process"]);
+    await run("/tools reset");
+    expect(entry.disabledTools).toBeUndefined();
+
+    expect(await run("/tools disable nope")).toContain("Unknown tool: nope");
+  });
+
+  it("lists only read-only tools as enabled in plan mode", async () => {
+    entry.planMode = true;
+    const list = await run("/tools");
+    expect(list).toContain("Tools enabled (2): read, web_search");
+    expect(list).toContain("Plan mode is on");
+  });
+});
@@ -0,0 +1,161 @@
+import type { SessionEntry } from "../../config/sessions.js";
+import type { CommandHandler, HandleCommandsParams } from "./commands-types.js";
+import { PLAN_MODE_TOOL_NAMES } from "../../agents/plan-mode.js";
+import { createOpenClawCodingTools } from "../../agents/pi-tools.js";
+import { expandToolGroups, normalizeToolName } from "../../agents/tool-policy.js";
+import { updateSessionStore } from "../../config/sessions.js";
+import { logVerbose } from "../../globals.js";
+
+const USAGE =
+  "🧰 Usage: /tools | /tools enable <tool ...> | /tools disable <tool ...> | /tools reset";
+
+export function parseToolsCommand(
+  normalized: string,
+):
+  | { action: "list" | "reset" }
+  | { action: "enable" | "disable"; names: string[] }
+  | { action: "invalid" }
+  | null {
+  if (normalized !== "/tools" && !normalized.startsWith("/tools ")) {
+    return null;
+  }
+  const [first = "", ...rest] = normalized
+    .slice("/tools".length)
+    .split(/[\s,]+/)
+    .filter(Boolean);
+  const keyword = first.toLowerCase();
+  if (!keyword || keyword === "list") {
+    return rest.length === 0 ? { action: "list" } : { action: "invalid" };
+  }
+  if (keyword === "reset" && rest.length === 0) {
+    return { action: "reset" };
+  }
+  if ((keyword === "enable" || keyword === "disable") && rest.length > 0) {
+    return { action: keyword, names: rest };
+  }
+  return { action: "invalid" };
+}
+
+/** Tools the session would get from config policy alone, before `/tools` and plan mode. */
+function listSessionToolNames(params: HandleCommandsParams): string[] {
+  try {
+    const tools = createOpenClawCodingTools({
+      config: params.cfg,
+      workspaceDir: params.workspaceDir,
+      sessionKey: params.sessionKey,
+      messageProvider: params.command.channel,
+      groupId: params.sessionEntry?.groupId ?? undefined,
+      groupChannel: params.sessionEntry?.groupChannel ?? undefined,
+      groupSpace: params.sessionEntry?.space ?? undefined,
+      spawnedBy: params.sessionEntry?.spawnedBy ?? undefined,
+      senderIsOwner: params.command.senderIsOwner,
+      modelProvider: params.provider,
+      modelId: params.model,
+    });
+    return Array.from(new Set(tools.map((tool) => normalizeToolName(tool.name)))).toSorted();
+  } catch {
+    return [];
+  }
+}
+
+function formatToolList(available: string[], entry: SessionEntry): string {
+  const disabled = new Set(entry.disabledTools ?? []);
+  const enabled = available.filter(
+    (name) => !disabled.has(name) && (!entry.planMode || PLAN_MODE_TOOL_NAMES.has(name)),
+  );
+  const lines = [`🧰 Tools enabled (${enabled.length}): ${enabled.join(", ") || "none"}`];
+  if (disabled.size > 0) {
+    lines.push(`Disabled for this session: ${[...disabled].toSorted().join(", ")}`);
+  }
+  if (entry.planMode) {
+    lines.push("Plan mode is on: only read-only tools run until /plan approve or /plan off.");
+  }
+  lines.push(
+    "Tools blocked by config (tools.allow/deny, profiles, sandbox) are not listed.",
+    USAGE,
+  );
+  return lines.join("\n");
+}
+
+/**
+ * `/tools` shows the tools this session's runs get; `/tools enable|disable` adjusts that for the
+ * rest of the session (stored on the session entry, applied from the next turn). Config policy
+ * still decides what can be enabled.
+ */
+export const handleToolsCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parseToolsCommand(params.command.commandBodyNormalized);
+  if (!parsed) {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /tools from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  if (parsed.action === "invalid") {
+    return { shouldContinue: false, reply: { text: USAGE } };
+  }
+  const entry = params.sessionEntry;
+  if (!entry) {
+    return { shouldContinue: false, reply: { text: "⚠️ /tools needs an active session." } };
+  }
+  const available = listSessionToolNames(params);
+  if (parsed.action === "list") {
+    return { shouldContinue: false, reply: { text: formatToolList(available, entry) } };
+  }
+
+  let reply: string;
+  if (parsed.action === "reset") {
+    delete entry.disabledTools;
+    reply = "🧰 All tools allowed by config are enabled again.";
+  } else {
+    const names = expandToolGroups(parsed.names);
+    // A tool disabled earlier can always be re-enabled, even if config no longer offers it.
+    const isKnown = (name: string) =>
+      available.includes(name) ||
+      (parsed.action === "enable" && Boolean(entry.disabledTools?.includes(name)));
+    const unknown = names.filter((name) => !isKnown(name));
+    const known = names.filter(isKnown);
+    if (known.length === 0) {
+      return {
+        shouldContinue: false,
+        reply: {
+          text: `⚠️ Unknown tool: ${unknown.join(", ")}. Available: ${available.join(", ")}`,
+        },
+      };
+    }
+    const disabled = new Set(entry.disabledTools ?? []);
+    for (const name of known) {
+      if (parsed.action === "disable") {
+        disabled.add(name);
+      } else {
+        disabled.delete(name);
+      }
+    }
+    if (disabled.size > 0) {
+      entry.disabledTools = [...disabled].toSorted();
+    } else {
+      delete entry.disabledTools;
+    }
+    const verb = parsed.action === "disable" ? "Disabled" : "Enabled";
+    reply = `🧰 ${verb} for this session: ${known.join(", ")}. Applies from the next message.`;
+    if (unknown.length > 0) {
+      reply += `\nSkipped (not available here): ${unknown.join(", ")}`;
+    }
+  }
+
+  entry.updatedAt = Date.now();
+  if (params.sessionStore) {
+    params.sessionStore[params.sessionKey] = entry;
+  }
+  if (params.storePath) {
+    await updateSessionStore(params.storePath, (store) => {
+      store[params.sessionKey] = entry;
+    });
+  }
+  return { shouldContinue: false, reply: { text: reply } };
+};
@@ -169,6 +169,11 @@ export function createFollowupRunner(params: {
               execOverrides: queued.run.execOverrides,
               bashElevated: queued.run.bashElevated,
               timeoutMs: queued.run.timeoutMs,
+              planMode: queued.run.planMode,
+              disabledTools: queued.run.disabledTools,
+              prefill: queued.run.prefill,
+              pinnedContext: queued.run.pinnedContext,
+              streamParams: queued.run.streamParams,
               runId,
               blockReplyBreak: queued.run.blockReplyBreak,
               onAgentEvent: (evt) => {
@@ -213,6 +218,7 @@ export function createFollowupRunner(params: {
           providerUsed: fallbackProvider,
           contextTokensUsed,
           logLabel: "followup",
+          config: queued.run.config,
         });
       }
 
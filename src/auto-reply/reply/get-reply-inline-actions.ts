@@ -1,3 +1,4 @@
+import type { AssistantPrefill } from "../../agents/prefill.js";
 import type { SkillCommandSpec } from "../../agents/skills.js";
 import type { OpenClawConfig } from "../../config/config.js";
 import type { SessionEntry } from "../../config/sessions.js";
@@ -7,12 +8,14 @@ import type { GetReplyOptions, ReplyPayload } from "../types.js";
 import type { InlineDirectives } from "./directive-handling.js";
 import type { createModelSelectionState } from "./model-selection.js";
 import type { TypingController } from "./typing.js";
-import { createOpenClawTools } from "../../agents/openclaw-tools.js";
+import { createOpenClawTools, refreshDiscoveredTools } from "../../agents/openclaw-tools.js";
 import { getChannelDock } from "../../channels/dock.js";
 import { logVerbose } from "../../globals.js";
 import { resolveGatewayMessageChannel } from "../../utils/message-channel.js";
 import { listSkillCommandsForWorkspace, resolveSkillCommandInvocation } from "../skill-commands.js";
 import { getAbortMemory } from "./abort.js";
+import { handleContinueCommand } from "./commands-continue.js";
+import { handlePlanCommand } from "./commands-plan.js";
 import { buildStatusReply, handleCommands } from "./commands.js";
 import { isDirectiveOnly } from "./directive-handling.js";
 import { extractInlineSimpleCommand } from "./reply-inline.js";
@@ -23,6 +26,8 @@ export type InlineActionResult =
       kind: "continue";
       directives: InlineDirectives;
       abortedLastRun: boolean;
+      /** Seed for the assistant turn (`/continue`). */
+      prefill?: AssistantPrefill;
     };
 
 // oxlint-disable-next-line typescript/no-explicit-any
@@ -171,6 +176,7 @@ export async function handleInlineActions(params: {
         resolveGatewayMessageChannel(ctx.Provider) ??
         undefined;
 
+      await refreshDiscoveredTools(cfg);
       const tools = createOpenClawTools({
         agentSessionKey: sessionKey,
         agentChannel: channel,
@@ -219,6 +225,38 @@ export async function handleInlineActions(params: {
     cleanedBody = rewrittenBody;
   }
 
+  const planResult = allowTextCommands
+    ? await handlePlanCommand({ command, sessionEntry, sessionStore, sessionKey, storePath })
+    : null;
+  if (planResult?.kind === "reply") {
+    typing.cleanup();
+    return { kind: "reply", reply: planResult.reply };
+  }
+  if (planResult?.kind === "continue") {
+    ctx.Body = planResult.body;
+    ctx.BodyForAgent = planResult.body;
+    sessionCtx.Body = planResult.body;
+    sessionCtx.BodyForAgent = planResult.body;
+    sessionCtx.BodyStripped = planResult.body;
+    cleanedBody = planResult.body;
+  }
+
+  const continueResult = allowTextCommands
+    ? handleContinueCommand({ command, sessionEntry, storePath })
+    : null;
+  if (continueResult?.kind === "reply") {
+    typing.cleanup();
+    return { kind: "reply", reply: continueResult.reply };
+  }
+  if (continueResult?.kind === "continue") {
+    ctx.Body = continueResult.body;
+    ctx.BodyForAgent = continueResult.body;
+    sessionCtx.Body = continueResult.body;
+    sessionCtx.BodyForAgent = continueResult.body;
+    sessionCtx.BodyStripped = continueResult.body;
+    cleanedBody = continueResult.body;
+  }
+
   const sendInlineReply = async (reply?: ReplyPayload) => {
     if (!reply) {
       return;
@@ -380,5 +418,6 @@ export async function handleInlineActions(params: {
     kind: "continue",
     directives,
     abortedLastRun,
+    prefill: continueResult?.kind === "continue" ? continueResult.prefill : undefined,
   };
 }
@@ -1,5 +1,6 @@
 import crypto from "node:crypto";
 import type { ExecToolDefaults } from "../../agents/bash-tools.js";
+import type { AssistantPrefill } from "../../agents/prefill.js";
 import type { OpenClawConfig } from "../../config/config.js";
 import type { MsgContext, TemplateContext } from "../templating.js";
 import type { GetReplyOptions, ReplyPayload } from "../types.js";
@@ -8,6 +9,8 @@ import type { InlineDirectives } from "./directive-handling.js";
 import type { createModelSelectionState } from "./model-selection.js";
 import type { TypingController } from "./typing.js";
 import { resolveSessionAuthProfileOverride } from "../../agents/auth-profiles/session-override.js";
+import { buildPersonaSystemPrompt, loadPersona } from "../../agents/personas.js";
+import { expandPromptTemplateVars } from "../../agents/prompt-template-vars.js";
 import {
   abortEmbeddedPiRun,
   isEmbeddedPiRunActive,
@@ -24,6 +27,7 @@ import {
 import { logVerbose } from "../../globals.js";
 import { clearCommandLane, getQueueSize } from "../../process/command-queue.js";
 import { normalizeMainKey } from "../../routing/session-key.js";
+import { buildPinnedMessagesContext } from "../../sessions/pinned-messages.js";
 import { isReasoningTagProvider } from "../../utils/provider-utils.js";
 import { hasControlCommand } from "../command-detection.js";
 import { buildInboundMediaNote } from "../media-note.js";
@@ -106,6 +110,8 @@ type RunPreparedReplyParams = {
   storePath?: string;
   workspaceDir: string;
   abortedLastRun: boolean;
+  /** Seed for the assistant turn (`/continue`). */
+  prefill?: AssistantPrefill;
 };
 
 export async function runPreparedReply(
@@ -186,7 +192,24 @@ export async function runPreparedReply(
   const inboundMetaPrompt = buildInboundMetaSystemPrompt(
     isNewSession ? sessionCtx : { ...sessionCtx, ThreadStarterBody: undefined },
   );
-  const extraSystemPrompt = [inboundMetaPrompt, groupIntro, groupSystemPrompt]
+  const persona = sessionEntry?.persona ? await loadPersona(sessionEntry.persona) : null;
+  if (sessionEntry?.persona && !persona) {
+    logVerbose(`persona "${sessionEntry.persona}" not found; running without it`);
+  }
+  // Template variables are filled in only for configured prompt text; inbound metadata
+  // carries user content and must not be able to read `{{env:…}}`.
+  const expandConfigured = (text: string) =>
+    expandPromptTemplateVars(text, {
+      cwd: workspaceDir,
+      model: `${provider}/${model}`,
+      timezone: cfg.agents?.defaults?.userTimezone,
+    });
+  const extraSystemPrompt = [
+    persona ? expandConfigured(buildPersonaSystemPrompt(persona)) : "",
+    inboundMetaPrompt,
+    groupIntro,
+    expandConfigured(groupSystemPrompt),
+  ]
     .filter(Boolean)
     .join("\n\n");
   const baseBody = sessionCtx.BodyStripped ?? sessionCtx.Body ?? "";
@@ -427,6 +450,12 @@ export async function runPreparedReply(
         defaultLevel: resolvedElevatedLevel ?? "off",
       },
       timeoutMs,
+      planMode: sessionEntry?.planMode || undefined,
+      disabledTools: sessionEntry?.disabledTools?.length ? sessionEntry.disabledTools : undefined,
+      prefill: params.prefill,
+      pinnedContext: buildPinnedMessagesContext(sessionEntry?.pinnedMessages) || undefined,
+      streamParams:
+        persona?.temperature !== undefined ? { temperature: persona.temperature } : undefined,
       blockReplyBreak: resolvedBlockStreamingBreak,
       ownerNumbers: command.ownerList.length > 0 ? command.ownerList : undefined,
       extraSystemPrompt: extraSystemPrompt || undefined,
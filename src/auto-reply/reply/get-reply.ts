@@ -337,5 +337,6 @@ export async function getReplyFromConfig(
     storePath,
     workspaceDir,
     abortedLastRun,
+    prefill: inlineActionResult.prefill,
   });
 }
@@ -88,6 +88,17 @@ describe("shouldRunMemoryFlush", () => {
     ).toBe(true);
   });
 
+  it("moves the threshold down with a compaction threshold percent", () => {
+    const params = {
+      entry: { totalTokens: 59_000 },
+      contextWindowTokens: 100_000,
+      reserveTokensFloor: 5_000,
+      softThresholdTokens: 2_000,
+    };
+    expect(shouldRunMemoryFlush(params)).toBe(false);
+    expect(shouldRunMemoryFlush({ ...params, compactionThresholdPercent: 60 })).toBe(true);
+  });
+
   it("skips when already flushed for current compaction count", () => {
     expect(
       shouldRunMemoryFlush({
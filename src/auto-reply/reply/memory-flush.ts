@@ -1,7 +1,11 @@
 import type { OpenClawConfig } from "../../config/config.js";
 import { lookupContextTokens } from "../../agents/context.js";
 import { DEFAULT_CONTEXT_TOKENS } from "../../agents/defaults.js";
-import { DEFAULT_PI_COMPACTION_RESERVE_TOKENS_FLOOR } from "../../agents/pi-settings.js";
+import {
+  applyCompactionThresholdPercent,
+  DEFAULT_PI_COMPACTION_RESERVE_TOKENS_FLOOR,
+  resolveCompactionThresholdPercent,
+} from "../../agents/pi-settings.js";
 import { resolveFreshSessionTotalTokens, type SessionEntry } from "../../config/sessions.js";
 import { SILENT_REPLY_TOKEN } from "../tokens.js";
 
@@ -26,6 +30,7 @@ export type MemoryFlushSettings = {
   prompt: string;
   systemPrompt: string;
   reserveTokensFloor: number;
+  compactionThresholdPercent?: number;
 };
 
 const normalizeNonNegativeInt = (value: unknown): number | null => {
@@ -56,6 +61,7 @@ export function resolveMemoryFlushSettings(cfg?: OpenClawConfig): MemoryFlushSet
     prompt: ensureNoReplyHint(prompt),
     systemPrompt: ensureNoReplyHint(systemPrompt),
     reserveTokensFloor,
+    compactionThresholdPercent: resolveCompactionThresholdPercent(cfg),
   };
 }
 
@@ -82,6 +88,7 @@ export function shouldRunMemoryFlush(params: {
   >;
   contextWindowTokens: number;
   reserveTokensFloor: number;
+  compactionThresholdPercent?: number;
   softThresholdTokens: number;
 }): boolean {
   const totalTokens = resolveFreshSessionTotalTokens(params.entry);
@@ -89,7 +96,11 @@ export function shouldRunMemoryFlush(params: {
     return false;
   }
   const contextWindow = Math.max(1, Math.floor(params.contextWindowTokens));
-  const reserveTokens = Math.max(0, Math.floor(params.reserveTokensFloor));
+  const reserveTokens = applyCompactionThresholdPercent({
+    reserveTokens: Math.max(0, Math.floor(params.reserveTokensFloor)),
+    contextWindowTokens: contextWindow,
+    thresholdPercent: params.compactionThresholdPercent,
+  });
   const softThreshold = Math.max(0, Math.floor(params.softThresholdTokens));
   const threshold = Math.max(0, contextWindow - reserveTokens - softThreshold);
   if (threshold <= 0) {
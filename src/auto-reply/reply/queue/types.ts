@@ -1,5 +1,7 @@
 import type { ExecToolDefaults } from "../../../agents/bash-tools.js";
+import type { AssistantPrefill } from "../../../agents/prefill.js";
 import type { SkillSnapshot } from "../../../agents/skills.js";
+import type { AgentStreamParams } from "../../../commands/agent/types.js";
 import type { OpenClawConfig } from "../../../config/config.js";
 import type { SessionEntry } from "../../../config/sessions.js";
 import type { OriginatingChannelType } from "../../templating.js";
@@ -74,6 +76,16 @@ export type FollowupRun = {
       defaultLevel: ElevatedLevel;
     };
     timeoutMs: number;
+    /** Session is in plan mode (/plan): read-only tools only. */
+    planMode?: boolean;
+    /** Tools turned off for the session (/tools disable). */
+    disabledTools?: string[];
+    /** Seed for the assistant turn (`/continue`). */
+    prefill?: AssistantPrefill;
+    /** Pinned messages (/pin), sent with each model request but not stored in the transcript. */
+    pinnedContext?: string;
+    /** Provider stream params for the run (e.g. a `/persona` temperature). */
+    streamParams?: AgentStreamParams;
     blockReplyBreak: "text_end" | "message_end";
     ownerNumbers?: string[];
     extraSystemPrompt?: string;
@@ -0,0 +1,91 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import type { SessionEntry } from "../../config/sessions.js";
+
+const generateSessionTitle = vi.fn();
+
+vi.mock("../../agents/session-title.js", async (importOriginal) => ({
+  ...(await importOriginal<typeof import("../../agents/session-title.js")>()),
+  generateSessionTitle: (...args: unknown[]) => generateSessionTitle(...args),
+}));
+
+const { resetSessionAutoTitleForTests, scheduleSessionAutoTitle } =
+  await import("./session-auto-title.js");
+
+describe("scheduleSessionAutoTitle", () => {
+  let dir: string;
+  let storePath: string;
+  const sessionKey = "agent:main:main";
+  const cfg = { session: { autoTitle: { enabled: true } } } as OpenClawConfig;
+
+  beforeEach(async () => {
+    resetSessionAutoTitleForTests();
+    generateSessionTitle.mockReset();
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-autotitle-"));
+    storePath = path.join(dir, "sessions.json");
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  const seed = async (entry: SessionEntry) => {
+    const sessionStore = { [sessionKey]: entry };
+    await fs.writeFile(storePath, JSON.stringify(sessionStore));
+    return sessionStore;
+  };
+
+  it("stores a generated title once per session", async () => {
+    const entry: SessionEntry = { sessionId: "s1", updatedAt: 0 };
+    const sessionStore = await seed(entry);
+    generateSessionTitle.mockResolvedValue("Fix flaky auth test");
+
+    const params = {
+      cfg,
+      sessionEntry: entry,
+      sessionStore,
+      sessionKey,
+      storePath,
+      provider: "anthropic",
+      model: "claude-opus-4-5",
+      userText: "the auth test keeps failing on CI",
+      assistantText: "Looking at it.",
+    };
+    await scheduleSessionAutoTitle(params);
+    expect(scheduleSessionAutoTitle(params)).toBeUndefined();
+
+    expect(generateSessionTitle).toHaveBeenCalledTimes(1);
+    const stored = JSON.parse(await fs.readFile(storePath, "utf8"));
+    expect(stored[sessionKey]).toMatchObject({
+      title: "Fix flaky auth test",
+      titleSource: "auto",
+    });
+    expect(sessionStore[sessionKey].title).toBe("Fix flaky auth test");
+  });
+
+  it("does nothing when disabled or already titled", async () => {
+    const entry: SessionEntry = { sessionId: "s2", updatedAt: 0, title: "Mine" };
+    const sessionStore = await seed(entry);
+    const base = {
+      sessionEntry: entry,
+      sessionStore,
+      sessionKey,
+      storePath,
+      provider: "anthropic",
+      model: "claude-opus-4-5",
+      userText: "hi",
+    };
+    expect(scheduleSessionAutoTitle({ ...base, cfg })).toBeUndefined();
+    expect(
+      scheduleSessionAutoTitle({
+        ...base,
+        cfg: {} as OpenClawConfig,
+        sessionEntry: { sessionId: "s3", updatedAt: 0 },
+      }),
+    ).toBeUndefined();
+    expect(generateSessionTitle).not.toHaveBeenCalled();
+  });
+});
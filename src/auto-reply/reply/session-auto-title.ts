@@ -0,0 +1,70 @@
+import type { OpenClawConfig } from "../../config/config.js";
+import type { SessionEntry } from "../../config/sessions.js";
+import { generateSessionTitle, isSessionAutoTitleEnabled } from "../../agents/session-title.js";
+import { updateSessionStoreEntry } from "../../config/sessions.js";
+import { logVerbose } from "../../globals.js";
+
+// One attempt per session id per process, so a failing title model is not retried every turn.
+const attemptedSessionIds = new Set<string>();
+
+/**
+ * Fire-and-forget title generation after an exchange in an untitled session. Never blocks or
+ * fails the reply; user-set titles are never replaced.
+ */
+export function scheduleSessionAutoTitle(params: {
+  cfg: OpenClawConfig;
+  sessionEntry?: SessionEntry;
+  sessionStore?: Record<string, SessionEntry>;
+  sessionKey?: string;
+  storePath?: string;
+  provider: string;
+  model: string;
+  agentDir?: string;
+  userText: string;
+  assistantText?: string;
+}): Promise<void> | undefined {
+  const { sessionEntry, sessionKey, storePath } = params;
+  if (!isSessionAutoTitleEnabled(params.cfg) || !sessionEntry || !sessionKey || !storePath) {
+    return undefined;
+  }
+  if (sessionEntry.title || !params.userText.trim()) {
+    return undefined;
+  }
+  if (attemptedSessionIds.has(sessionEntry.sessionId)) {
+    return undefined;
+  }
+  attemptedSessionIds.add(sessionEntry.sessionId);
+  const sessionId = sessionEntry.sessionId;
+  return generateSessionTitle({
+    cfg: params.cfg,
+    provider: params.provider,
+    model: params.model,
+    agentDir: params.agentDir,
+    userText: params.userText,
+    assistantText: params.assistantText,
+  })
+    .then(async (title) => {
+      if (!title) {
+        return;
+      }
+      const updated = await updateSessionStoreEntry({
+        storePath,
+        sessionKey,
+        update: async (entry) =>
+          entry.title || entry.sessionId !== sessionId
+            ? null
+            : { title, titleSource: "auto" },
+      });
+      if (updated?.title === title && params.sessionStore?.[sessionKey]) {
+        params.sessionStore[sessionKey].title = title;
+        params.sessionStore[sessionKey].titleSource = "auto";
+      }
+    })
+    .catch((err) => {
+      logVerbose(`session auto-title failed for ${sessionKey}: ${String(err)}`);
+    });
+}
+
+export function resetSessionAutoTitleForTests(): void {
+  attemptedSessionIds.clear();
+}
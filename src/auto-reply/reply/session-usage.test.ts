@@ -117,4 +117,38 @@ describe("persistSessionUsageUpdate", () => {
     expect(stored[sessionKey].totalTokens).toBe(250_000);
     expect(stored[sessionKey].totalTokensFresh).toBe(true);
   });
+
+  it("accumulates usage totals across runs in the session store", async () => {
+    const tmp = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-usage-"));
+    const storePath = path.join(tmp, "sessions.json");
+    const sessionKey = "main";
+    await seedSessionStore({
+      storePath,
+      sessionKey,
+      entry: { sessionId: "s1", updatedAt: Date.now() },
+    });
+
+    for (const input of [1_000, 3_000]) {
+      await persistSessionUsageUpdate({
+        storePath,
+        sessionKey,
+        usage: { input, output: 100 },
+        modelUsed: "opus",
+        providerUsed: "anthropic",
+      });
+    }
+
+    const stored = JSON.parse(await fs.readFile(storePath, "utf-8"));
+    expect(stored[sessionKey].inputTokens).toBe(3_000);
+    expect(stored[sessionKey].usageTotals).toMatchObject({
+      input: 4_000,
+      output: 200,
+      totalTokens: 4_200,
+      runs: 2,
+      runsWithoutCost: 2,
+    });
+    expect(stored[sessionKey].modelHistory).toMatchObject([
+      { provider: "anthropic", model: "opus", runs: 2 },
+    ]);
+  });
 });
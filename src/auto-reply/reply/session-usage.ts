@@ -1,3 +1,4 @@
+import type { OpenClawConfig } from "../../config/config.js";
 import { setCliSessionId } from "../../agents/cli-session.js";
 import {
   deriveSessionTotalTokens,
@@ -10,6 +11,7 @@ import {
   updateSessionStoreEntry,
 } from "../../config/sessions.js";
 import { logVerbose } from "../../globals.js";
+import { buildSessionUsagePatch } from "../../sessions/usage-totals.js";
 
 export async function persistSessionUsageUpdate(params: {
   storePath?: string;
@@ -29,6 +31,8 @@ export async function persistSessionUsageUpdate(params: {
   systemPromptReport?: SessionSystemPromptReport;
   cliSessionId?: string;
   logLabel?: string;
+  /** Used to price the run from the model's cost config for the session's cumulative totals. */
+  config?: OpenClawConfig;
 }): Promise<void> {
   const { storePath, sessionKey } = params;
   if (!storePath || !sessionKey) {
@@ -55,6 +59,8 @@ export async function persistSessionUsageUpdate(params: {
           // (tool-use loops, compaction retries), overstating actual context.
           // `lastCallUsage` reflects only the final API call — the true context.
           const usageForContext = params.lastCallUsage ?? params.usage;
+          const providerUsed = params.providerUsed ?? entry.modelProvider;
+          const modelUsed = params.modelUsed ?? entry.model;
           const totalTokens = hasFreshContextSnapshot
             ? deriveSessionTotalTokens({
                 usage: usageForContext,
@@ -68,10 +74,16 @@ export async function persistSessionUsageUpdate(params: {
             // Missing a last-call snapshot means context utilization is stale/unknown.
             totalTokens,
             totalTokensFresh: typeof totalTokens === "number",
-            modelProvider: params.providerUsed ?? entry.modelProvider,
-            model: params.modelUsed ?? entry.model,
+            modelProvider: providerUsed,
+            model: modelUsed,
             contextTokens: resolvedContextTokens,
             systemPromptReport: params.systemPromptReport ?? entry.systemPromptReport,
+            ...buildSessionUsagePatch(entry, {
+              usage: params.usage,
+              provider: providerUsed,
+              model: modelUsed,
+              config: params.config,
+            }),
             updatedAt: Date.now(),
           };
           const cliProvider = params.providerUsed ?? entry.modelProvider;
@@ -99,11 +111,14 @@ export async function persistSessionUsageUpdate(params: {
         storePath,
         sessionKey,
         update: async (entry) => {
+          const providerUsed = params.providerUsed ?? entry.modelProvider;
+          const modelUsed = params.modelUsed ?? entry.model;
           const patch: Partial<SessionEntry> = {
-            modelProvider: params.providerUsed ?? entry.modelProvider,
-            model: params.modelUsed ?? entry.model,
+            modelProvider: providerUsed,
+            model: modelUsed,
             contextTokens: params.contextTokensUsed ?? entry.contextTokens,
             systemPromptReport: params.systemPromptReport ?? entry.systemPromptReport,
+            ...buildSessionUsagePatch(entry, { provider: providerUsed, model: modelUsed }),
             updatedAt: Date.now(),
           };
           const cliProvider = params.providerUsed ?? entry.modelProvider;
@@ -6,6 +6,7 @@ import type { OpenClawConfig } from "../../config/config.js";
 import type { TtsAutoMode } from "../../config/types.tts.js";
 import type { MsgContext, TemplateContext } from "../templating.js";
 import { resolveSessionAgentId } from "../../agents/agent-scope.js";
+import { clearToolResultCacheSession } from "../../agents/pi-tools.result-cache.js";
 import { normalizeChatType } from "../../channels/chat-type.js";
 import {
   DEFAULT_RESET_TRIGGERS,
@@ -351,6 +352,12 @@ export async function initSessionState(params: {
     );
   }
   if (isNewSession) {
+    sessionEntry.createdAt = Date.now();
+    sessionEntry.usageTotals = undefined;
+    sessionEntry.modelHistory = undefined;
+    sessionEntry.title = undefined;
+    sessionEntry.titleSource = undefined;
+    sessionEntry.tags = undefined;
     sessionEntry.compactionCount = 0;
     sessionEntry.memoryFlushCompactionCount = undefined;
     sessionEntry.memoryFlushAt = undefined;
@@ -360,6 +367,9 @@ export async function initSessionState(params: {
     sessionEntry.inputTokens = undefined;
     sessionEntry.outputTokens = undefined;
     sessionEntry.contextTokens = undefined;
+    // Task lists and pins belong to the previous conversation.
+    sessionEntry.todos = undefined;
+    sessionEntry.pinnedMessages = undefined;
   }
   // Preserve per-session overrides while resetting compaction state on /new.
   sessionStore[sessionKey] = { ...sessionStore[sessionKey], ...sessionEntry };
@@ -383,6 +393,7 @@ export async function initSessionState(params: {
 
   // Archive old transcript so it doesn't accumulate on disk (#14869).
   if (previousSessionEntry?.sessionId) {
+    clearToolResultCacheSession(sessionKey);
     archiveSessionTranscripts({
       sessionId: previousSessionEntry.sessionId,
       storePath,
@@ -0,0 +1,89 @@
+import type { Command } from "commander";
+import {
+  batchCancelCommand,
+  batchResultsCommand,
+  batchStatusCommand,
+  batchSubmitCommand,
+} from "../commands/batch.js";
+import { defaultRuntime } from "../runtime.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { theme } from "../terminal/theme.js";
+import { runCommandWithRuntime } from "./cli-utils.js";
+import { formatHelpExamples } from "./help-format.js";
+
+export function registerBatchCli(program: Command) {
+  const batch = program
+    .command("batch")
+    .description("Run many prompts through Anthropic Message Batches (half price, async)")
+    .addHelpText(
+      "after",
+      () =>
+        `\n${theme.muted("Docs:")} ${formatDocsLink("/cli/batch", "docs.openclaw.ai/cli/batch")}\n`,
+    )
+    .action(() => {
+      batch.help({ error: true });
+    });
+
+  batch
+    .command("submit")
+    .description("Submit a JSONL file of prompts, wait for the batch, and write replies as JSONL")
+    .argument("<input>", 'JSONL file, one {"id","prompt","system?","maxTokens?"} per line')
+    .option("--model <model>", "Anthropic model or alias (default: the agent's model)")
+    .option("--agent <id>", "Agent whose default model is used")
+    .option("--max-tokens <n>", "Max output tokens per prompt (default: 4096)")
+    .option("--system <text>", "System prompt for lines without their own `system`")
+    .option("--out <file>", "Results file (default: <input>.results.jsonl)")
+    .option("--poll-interval <seconds>", "Seconds between status checks (default: 30)")
+    .option("--no-wait", "Return after submitting; fetch later with `batch results`")
+    .option("--json", "Output JSON", false)
+    .addHelpText(
+      "after",
+      () =>
+        `
+${theme.heading("Examples:")}
+${formatHelpExamples([
+  ["openclaw batch submit prompts.jsonl --model sonnet", "Wait and write prompts.results.jsonl."],
+  ["openclaw batch submit prompts.jsonl --no-wait", "Submit only; prints the batch id."],
+])}`,
+    )
+    .action(async (input: string, opts) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await batchSubmitCommand(input, opts, defaultRuntime);
+      });
+    });
+
+  batch
+    .command("status")
+    .description("Show a batch's processing state and request counts")
+    .argument("<id>", "Batch id (msgbatch_…)")
+    .option("--json", "Output JSON", false)
+    .action(async (id: string, opts) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await batchStatusCommand(id, opts, defaultRuntime);
+      });
+    });
+
+  batch
+    .command("results")
+    .description("Download the replies of an ended batch as JSONL")
+    .argument("<id>", "Batch id (msgbatch_…)")
+    .option("--out <file>", "Results file (default: <id>.results.jsonl)")
+    .option("--model <model>", "Model id the batch used, for the cost estimate")
+    .option("--json", "Output JSON", false)
+    .action(async (id: string, opts) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await batchResultsCommand(id, opts, defaultRuntime);
+      });
+    });
+
+  batch
+    .command("cancel")
+    .description("Cancel a batch that is still processing")
+    .argument("<id>", "Batch id (msgbatch_…)")
+    .option("--json", "Output JSON", false)
+    .action(async (id: string, opts) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await batchCancelCommand(id, opts, defaultRuntime);
+      });
+    });
+}
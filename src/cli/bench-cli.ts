@@ -0,0 +1,42 @@
+import type { Command } from "commander";
+import { benchCommand } from "../commands/bench.js";
+import { defaultRuntime } from "../runtime.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { theme } from "../terminal/theme.js";
+import { runCommandWithRuntime } from "./cli-utils.js";
+import { formatHelpExamples } from "./help-format.js";
+
+export function registerBenchCli(program: Command) {
+  program
+    .command("bench")
+    .description("Run one prompt against several models and compare latency, tokens, and cost")
+    .requiredOption("--models <list>", "Models or aliases, comma-separated (e.g. sonnet,gpt)")
+    .option("--prompt <text>", "Prompt text")
+    .option("--prompt-file <path>", "Read the prompt from a file")
+    .option("--runs <n>", "Runs per model (default: 1, max: 20)")
+    .option("--out <dir>", "Directory for outputs and results.json (default: ./bench-<time>)")
+    .option("--agent <id>", "Agent whose default model resolves bare model names")
+    .option("--timeout <seconds>", "Per-request timeout (default: agents.defaults.council)")
+    .option("--json", "Output JSON", false)
+    .addHelpText(
+      "after",
+      () =>
+        `
+${theme.heading("Examples:")}
+${formatHelpExamples([
+  [
+    "openclaw bench --models sonnet,gpt --prompt-file p.md --runs 3",
+    "Three runs per model; outputs saved under ./bench-<time>/.",
+  ],
+  ['openclaw bench --models opus,gemini --prompt "Summarize RFC 9110" --json', "JSON results."],
+])}
+
+${theme.muted("Docs:")} ${formatDocsLink("/cli/bench", "docs.openclaw.ai/cli/bench")}
+`,
+    )
+    .action(async (opts) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await benchCommand(opts, defaultRuntime);
+      });
+    });
+}
@@ -428,4 +428,22 @@ describe("cron cli", () => {
     expect(patch?.patch?.delivery?.mode).toBe("announce");
     expect(patch?.patch?.delivery?.bestEffort).toBe(false);
   });
+
+  it("patches continueSession without touching delivery", async () => {
+    callGatewayFromCli.mockClear();
+
+    const program = buildProgram();
+
+    await program.parseAsync(["cron", "edit", "job-1", "--no-continue-session"], {
+      from: "user",
+    });
+
+    const updateCall = callGatewayFromCli.mock.calls.find((call) => call[0] === "cron.update");
+    const patch = updateCall?.[2] as {
+      patch?: { payload?: { kind?: string; continueSession?: boolean }; delivery?: unknown };
+    };
+
+    expect(patch?.patch?.payload).toEqual({ kind: "agentTurn", continueSession: false });
+    expect(patch?.patch?.delivery).toBeUndefined();
+  });
 });
@@ -0,0 +1,43 @@
+import type { Command } from "commander";
+import { evalCommand } from "../commands/eval.js";
+import { defaultRuntime } from "../runtime.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { theme } from "../terminal/theme.js";
+import { runCommandWithRuntime } from "./cli-utils.js";
+import { createDefaultDeps } from "./deps.js";
+import { formatHelpExamples } from "./help-format.js";
+
+export function registerEvalCli(program: Command) {
+  program
+    .command("eval")
+    .description("Run a YAML suite of prompts and check the replies (exit 1 on any failure)")
+    .argument("<suite>", "Path to the eval suite (YAML)")
+    .option("--agent <id>", "Agent to run the cases with (default: suite `agent` or default agent)")
+    .option("--judge <model>", "Model for `judge` assertions (default: suite `judge` or agent model)")
+    .option("--filter <text>", "Only run cases whose name contains this text")
+    .option("--timeout <seconds>", "Per-case agent timeout")
+    .option("--out <file>", "Also write the JSON report to this file")
+    .option("--json", "Output JSON", false)
+    .addHelpText(
+      "after",
+      () =>
+        `
+${theme.heading("Examples:")}
+${formatHelpExamples([
+  ["openclaw eval evals/basics.yaml", "Run every case; exit 1 if any fails."],
+  ["openclaw eval evals/basics.yaml --filter review --judge opus", "Subset with a stronger judge."],
+  ["openclaw eval evals/basics.yaml --json --out report.json", "JSON report for CI."],
+])}
+
+${theme.muted("Docs:")} ${formatDocsLink("/cli/eval", "docs.openclaw.ai/cli/eval")}
+`,
+    )
+    .action(async (suite: string, opts) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const result = await evalCommand(suite, opts, defaultRuntime, createDefaultDeps());
+        if (!result.ok) {
+          defaultRuntime.exit(1);
+        }
+      });
+    });
+}
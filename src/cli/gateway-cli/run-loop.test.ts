@@ -72,6 +72,9 @@ describe("runGatewayLoop", () => {
     const beforeSigint = new Set(
       process.listeners("SIGINT") as Array<(...args: unknown[]) => void>,
     );
+    const beforeSighup = new Set(
+      process.listeners("SIGHUP") as Array<(...args: unknown[]) => void>,
+    );
     const beforeSigusr1 = new Set(
       process.listeners("SIGUSR1") as Array<(...args: unknown[]) => void>,
     );
@@ -117,6 +120,7 @@ describe("runGatewayLoop", () => {
     } finally {
       removeNewSignalListeners("SIGTERM", beforeSigterm);
       removeNewSignalListeners("SIGINT", beforeSigint);
+      removeNewSignalListeners("SIGHUP", beforeSighup);
       removeNewSignalListeners("SIGUSR1", beforeSigusr1);
     }
   });
@@ -1,5 +1,6 @@
 import type { startGatewayServer } from "../../gateway/server.js";
 import type { defaultRuntime } from "../../runtime.js";
+import { flushAgentRunsForShutdown } from "../../agents/run-shutdown.js";
 import { acquireGatewayLock } from "../../infra/gateway-lock.js";
 import {
   consumeGatewaySigusr1RestartAuthorization,
@@ -30,13 +31,14 @@ export async function runGatewayLoop(params: {
   const cleanupSignals = () => {
     process.removeListener("SIGTERM", onSigterm);
     process.removeListener("SIGINT", onSigint);
+    process.removeListener("SIGHUP", onSighup);
     process.removeListener("SIGUSR1", onSigusr1);
   };
 
   const DRAIN_TIMEOUT_MS = 30_000;
   const SHUTDOWN_TIMEOUT_MS = 5_000;
 
-  const request = (action: GatewayRunSignalAction, signal: string) => {
+  const request = (action: GatewayRunSignalAction, signal: NodeJS.Signals) => {
     if (shuttingDown) {
       gatewayLog.info(`received ${signal} during shutdown; ignoring`);
       return;
@@ -70,6 +72,10 @@ export async function runGatewayLoop(params: {
               gatewayLog.warn("drain timeout reached; proceeding with restart");
             }
           }
+        } else {
+          // Abort in-flight turns so their partial replies are saved, and reap exec children
+          // (the systemd unit uses KillMode=process, so nothing else will).
+          await flushAgentRunsForShutdown();
         }
 
         await server?.close({
@@ -100,6 +106,10 @@ export async function runGatewayLoop(params: {
     gatewayLog.info("signal SIGINT received");
     request("stop", "SIGINT");
   };
+  const onSighup = () => {
+    gatewayLog.info("signal SIGHUP received");
+    request("stop", "SIGHUP");
+  };
   const onSigusr1 = () => {
     gatewayLog.info("signal SIGUSR1 received");
     const authorized = consumeGatewaySigusr1RestartAuthorization();
@@ -115,6 +125,7 @@ export async function runGatewayLoop(params: {
 
   process.on("SIGTERM", onSigterm);
   process.on("SIGINT", onSigint);
+  process.on("SIGHUP", onSighup);
   process.on("SIGUSR1", onSigusr1);
 
   try {
@@ -129,7 +140,7 @@ export async function runGatewayLoop(params: {
     });
 
     // Keep process alive; SIGUSR1 triggers an in-process restart (no supervisor required).
-    // SIGTERM/SIGINT still exit after a graceful shutdown.
+    // SIGTERM/SIGINT/SIGHUP still exit after a graceful shutdown.
     // eslint-disable-next-line no-constant-condition
     while (true) {
       onIteration();
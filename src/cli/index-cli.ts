@@ -0,0 +1,38 @@
+import type { Command } from "commander";
+import { codebaseIndexCommand } from "../commands/codebase-index.js";
+import { defaultRuntime } from "../runtime.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { theme } from "../terminal/theme.js";
+import { runCommandWithRuntime } from "./cli-utils.js";
+import { formatHelpExamples } from "./help-format.js";
+
+export function registerIndexCli(program: Command) {
+  program
+    .command("index")
+    .description("Embed a project's source files for semantic code search (codebase_search)")
+    .argument("[dir]", "Project directory (default: the git root of the current directory)")
+    .option("--agent <id>", "Agent whose memorySearch embedding settings to use")
+    .option("--force", "Re-embed every file instead of only changed ones", false)
+    .option("--status", "Show the index for this project without updating it", false)
+    .option("--max-file-kb <n>", "Skip files larger than this (default: 256)")
+    .option("--json", "Output JSON", false)
+    .addHelpText(
+      "after",
+      () =>
+        `
+${theme.heading("Examples:")}
+${formatHelpExamples([
+  ["openclaw index", "Index (or refresh) the current repository."],
+  ["openclaw index ~/src/app --force", "Rebuild the index from scratch."],
+  ["openclaw index --status", "Show file/chunk counts and when it was last updated."],
+])}
+
+${theme.muted("Docs:")} ${formatDocsLink("/cli/index-codebase", "docs.openclaw.ai/cli/index-codebase")}
+`,
+    )
+    .action(async (dir: string | undefined, opts) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await codebaseIndexCommand(dir, opts, defaultRuntime);
+      });
+    });
+}
@@ -0,0 +1,50 @@
+import path from "node:path";
+import { describe, expect, it } from "vitest";
+import { applyCliLogEnv, parseCliLogArgs } from "./log-flags.js";
+
+describe("parseCliLogArgs", () => {
+  it("strips global log flags before the command", () => {
+    const res = parseCliLogArgs([
+      "node",
+      "openclaw",
+      "--log-file",
+      "/tmp/cli.log",
+      "--log-level=DEBUG",
+      "gateway",
+      "--verbose",
+    ]);
+    if (!res.ok) {
+      throw new Error(res.error);
+    }
+    expect(res.file).toBe("/tmp/cli.log");
+    expect(res.level).toBe("debug");
+    expect(res.argv).toEqual(["node", "openclaw", "gateway", "--verbose"]);
+  });
+
+  it("leaves flags after the command alone", () => {
+    const res = parseCliLogArgs(["node", "openclaw", "logs", "--log-file", "x.log"]);
+    if (!res.ok) {
+      throw new Error(res.error);
+    }
+    expect(res.file).toBeNull();
+    expect(res.argv).toEqual(["node", "openclaw", "logs", "--log-file", "x.log"]);
+  });
+
+  it("rejects missing values and unknown levels", () => {
+    expect(parseCliLogArgs(["node", "openclaw", "--log-file"])).toEqual({
+      ok: false,
+      error: "--log-file requires a value",
+    });
+    const res = parseCliLogArgs(["node", "openclaw", "--log-level", "loud", "status"]);
+    expect(res.ok).toBe(false);
+  });
+});
+
+describe("applyCliLogEnv", () => {
+  it("exports an absolute file path and level", () => {
+    const env: Record<string, string | undefined> = {};
+    applyCliLogEnv({ file: "logs/cli.log", level: "warn", env });
+    expect(env.OPENCLAW_LOG_FILE).toBe(path.resolve("logs/cli.log"));
+    expect(env.OPENCLAW_LOG_LEVEL).toBe("warn");
+  });
+});
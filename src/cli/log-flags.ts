@@ -0,0 +1,87 @@
+import path from "node:path";
+import { expandHomePrefix } from "../infra/home-dir.js";
+import { ALLOWED_LOG_LEVELS, type LogLevel } from "../logging/levels.js";
+
+export type CliLogParseResult =
+  | { ok: true; file: string | null; level: LogLevel | null; argv: string[] }
+  | { ok: false; error: string };
+
+/**
+ * Strips the global `--log-file <path>` / `--log-level <level>` flags (before the command name)
+ * so they apply to the file logger from the very first line, like `--profile`.
+ */
+export function parseCliLogArgs(argv: string[]): CliLogParseResult {
+  if (argv.length < 2) {
+    return { ok: true, file: null, level: null, argv };
+  }
+
+  const out: string[] = argv.slice(0, 2);
+  let file: string | null = null;
+  let level: LogLevel | null = null;
+  let sawCommand = false;
+
+  const args = argv.slice(2);
+  for (let i = 0; i < args.length; i += 1) {
+    const arg = args[i];
+    if (arg === undefined) {
+      continue;
+    }
+    if (sawCommand) {
+      out.push(arg);
+      continue;
+    }
+
+    const flag = ["--log-file", "--log-level"].find(
+      (name) => arg === name || arg.startsWith(`${name}=`),
+    );
+    if (flag) {
+      let value: string;
+      if (arg.includes("=")) {
+        value = arg.slice(arg.indexOf("=") + 1).trim();
+      } else {
+        value = (args[i + 1] ?? "").trim();
+        i += 1;
+      }
+      if (!value) {
+        return { ok: false, error: `${flag} requires a value` };
+      }
+      if (flag === "--log-file") {
+        file = value;
+        continue;
+      }
+      const normalized = value.toLowerCase();
+      if (!(ALLOWED_LOG_LEVELS as readonly string[]).includes(normalized)) {
+        return {
+          ok: false,
+          error: `Invalid --log-level (use ${ALLOWED_LOG_LEVELS.join(", ")})`,
+        };
+      }
+      level = normalized as LogLevel;
+      continue;
+    }
+
+    if (!arg.startsWith("-")) {
+      sawCommand = true;
+    }
+    out.push(arg);
+  }
+
+  return { ok: true, file, level, argv: out };
+}
+
+/** Passes the flags to the logger (and to child processes) via env. */
+export function applyCliLogEnv(params: {
+  file: string | null;
+  level: LogLevel | null;
+  env?: Record<string, string | undefined>;
+}) {
+  const env = params.env ?? (process.env as Record<string, string | undefined>);
+  if (params.file) {
+    env.OPENCLAW_LOG_FILE = path.resolve(
+      expandHomePrefix(params.file, { env: env as NodeJS.ProcessEnv }),
+    );
+  }
+  if (params.level) {
+    env.OPENCLAW_LOG_LEVEL = params.level;
+  }
+}
@@ -24,6 +24,7 @@ import {
   modelsSetCommand,
   modelsSetImageCommand,
   modelsStatusCommand,
+  modelsUpdateCommand,
 } from "../commands/models.js";
 import { defaultRuntime } from "../runtime.js";
 import { formatDocsLink } from "../terminal/links.js";
@@ -275,6 +276,18 @@ export function registerModelsCli(program: Command) {
       });
     });
 
+  models
+    .command("update")
+    .description("Download model pricing and context windows from the LiteLLM registry")
+    .option("--url <url>", "Registry JSON URL (LiteLLM format)")
+    .option("--yes", "Save without prompting", false)
+    .option("--json", "Output JSON", false)
+    .action(async (opts) => {
+      await runModelsCommand(async () => {
+        await modelsUpdateCommand(opts, defaultRuntime);
+      });
+    });
+
   models.action(async (opts) => {
     await runModelsCommand(async () => {
       await modelsStatusCommand(
@@ -89,6 +89,7 @@ const coreEntries: CoreCliEntry[] = [
     commands: [
       { name: "agent", description: "Agent commands" },
       { name: "agents", description: "Manage isolated agents" },
+      { name: "run", description: "Headless agent run for CI" },
     ],
     register: async ({ program, ctx }) => {
       const mod = await import("./register.agent.js");
@@ -45,6 +45,14 @@ export function configureProgramHelp(program: Command, ctx: ProgramContext) {
     );
 
   program.option("--no-color", "Disable ANSI colors", false);
+  program.option(
+    "--log-file <path>",
+    "Write file logs to this path for this run (rotated by size; see logging.maxFileBytes)",
+  );
+  program.option(
+    "--log-level <level>",
+    "File log level for this run, independent of terminal output (silent|error|warn|info|debug|trace)",
+  );
 
   program.configureHelp({
     // sort options and subcommands alphabetically
@@ -7,6 +7,7 @@ import {
   agentsListCommand,
   agentsSetIdentityCommand,
 } from "../../commands/agents.js";
+import { headlessRunCommand } from "../../commands/headless-run.js";
 import { setVerbose } from "../../globals.js";
 import { defaultRuntime } from "../../runtime.js";
 import { formatDocsLink } from "../../terminal/links.js";
@@ -39,12 +40,44 @@ export function registerAgentCommands(program: Command, args: { agentChannelOpti
       "Run the embedded agent locally (requires model provider API keys in your shell)",
       false,
     )
+    .option("--persona <name>", "System prompt preset from ~/.openclaw/prompts/<name>.md")
+    .option("--plan", "Plan only: read-only tools, reply with a plan (--local)", false)
+    .option("--no-memory", "Leave out MEMORY.md, project memory, and memory tools (--local)")
+    .option("--with-diff", "Attach uncommitted changes (git diff HEAD) to the message", false)
+    .option("--with-staged", "Attach staged changes (git diff --cached) to the message", false)
+    .option("--with-log <n>", "Attach the last n commits (git log) to the message")
+    .option(
+      "--stop <sequence>",
+      "Stop generating at this string (repeatable, --local)",
+      collectOption,
+      [],
+    )
+    .option("--prefill <text>", "Start the reply with this text, e.g. '```diff' (implies --local)")
+    .option(
+      "--response-schema <file>",
+      "Reply with JSON matching this JSON schema file; prints only the JSON (implies --local)",
+    )
+    .option(
+      "--compare <models>",
+      "Ask these models (comma-separated refs/aliases) the same message and print each answer",
+    )
+    .option("--judge <model>", "With --compare: model that synthesizes the answers")
     .option("--deliver", "Send the agent's reply back to the selected channel", false)
     .option("--json", "Output result as JSON", false)
+    .option("--force", "Run even if another local run holds this session (--local)", false)
     .option(
       "--timeout <seconds>",
       "Override agent command timeout (seconds, default 600 or config value)",
     )
+    .option("--max-turns <n>", "Ask the model to wrap up after this many model turns (--local)")
+    .option(
+      "--max-duration <seconds>",
+      "Ask the model to wrap up after this many seconds (--local)",
+    )
+    .option(
+      "--max-cost-usd <usd>",
+      "Ask the model to wrap up after this estimated spend (--local)",
+    )
     .addHelpText(
       "after",
       () =>
@@ -66,6 +99,22 @@ ${formatHelpExamples([
     'openclaw agent --agent ops --message "Generate report" --deliver --reply-channel slack --reply-to "#reports"',
     "Send reply to a different channel/target.",
   ],
+  [
+    'openclaw agent --agent ops --message "Review the last commit" --persona reviewer',
+    "Use a saved system prompt preset.",
+  ],
+  [
+    'openclaw agent --agent ops --message "Triage this alert" --response-schema triage.json',
+    "Print schema-validated JSON.",
+  ],
+  [
+    'openclaw agent --message "Is this migration safe?" --compare gpt,opus --judge opus',
+    "Compare two models and synthesize.",
+  ],
+  [
+    'openclaw agent --agent ops --message "Why does this fail?" --with-diff --with-log 5',
+    "Send current changes and recent commits along.",
+  ],
 ])}
 
 ${theme.muted("Docs:")} ${formatDocsLink("/cli/agent", "docs.openclaw.ai/cli/agent")}`,
@@ -80,6 +129,52 @@ ${theme.muted("Docs:")} ${formatDocsLink("/cli/agent", "docs.openclaw.ai/cli/age
       });
     });
 
+  program
+    .command("run")
+    .description("Run the agent once, non-interactively, for CI (exit code reflects the outcome)")
+    .option("--task <file>", "File with the task prompt (Markdown or text)")
+    .option("-m, --message <text>", "Task prompt inline (instead of --task)")
+    .option("--agent <id>", "Agent id (default: ci.agent, else the default agent)")
+    .option("--thinking <level>", "Thinking level: off | minimal | low | medium | high")
+    .option("--max-turns <n>", "Model-turn limit (default: ci.maxTurns)")
+    .option("--max-duration <seconds>", "Wall-clock limit (default: ci.maxDurationSeconds)")
+    .option("--max-cost-usd <usd>", "Estimated cost limit (default: ci.maxCostUsd)")
+    .option(
+      "--timeout <seconds>",
+      "Hard timeout for the run (default: agents.defaults.timeoutSeconds)",
+    )
+    .option("--output-format <format>", "text | json | jsonl (jsonl streams agent events)", "text")
+    .option("--transcript <path>", "Copy the session transcript (JSONL) here when done")
+    .option("--success <regex>", "Exit 0 only if the final reply matches this pattern")
+    .addHelpText(
+      "after",
+      () =>
+        `
+${theme.heading("Examples:")}
+${formatHelpExamples([
+  ["openclaw run --task task.md --max-turns 30", "Run a task; exit 1 on error or limit."],
+  [
+    "openclaw run --task task.md --output-format jsonl --transcript out/run.jsonl",
+    "Stream events as JSONL and keep the transcript.",
+  ],
+  [
+    'openclaw run -m "Fix the failing test" --success "^DONE"',
+    "Require the reply to start with DONE.",
+  ],
+])}
+
+${theme.muted("Docs:")} ${formatDocsLink("/cli/run", "docs.openclaw.ai/cli/run")}`,
+    )
+    .action(async (opts) => {
+      const deps = createDefaultDeps();
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const outcome = await headlessRunCommand(opts, defaultRuntime, deps);
+        if (!outcome.ok) {
+          defaultRuntime.exit(1);
+        }
+      });
+    });
+
   const agents = program
     .command("agents")
     .description("Manage isolated agents (workspaces + auth + routing)")
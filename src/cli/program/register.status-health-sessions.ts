@@ -1,5 +1,12 @@
 import type { Command } from "commander";
 import { healthCommand } from "../../commands/health.js";
+import { sessionsCopyCommand } from "../../commands/sessions.commands.copy.js";
+import { sessionsDeleteCommand } from "../../commands/sessions.commands.delete.js";
+import { sessionsForkCommand } from "../../commands/sessions.commands.fork.js";
+import { sessionsImportCommand } from "../../commands/sessions.commands.import.js";
+import { sessionsPruneCommand } from "../../commands/sessions.commands.prune.js";
+import { sessionsRenameCommand } from "../../commands/sessions.commands.rename.js";
+import { sessionsShowCommand } from "../../commands/sessions.commands.show.js";
 import { sessionsCommand } from "../../commands/sessions.js";
 import { statusCommand } from "../../commands/status.js";
 import { setVerbose } from "../../globals.js";
@@ -108,21 +115,27 @@ export function registerStatusHealthSessionsCommands(program: Command) {
       });
     });
 
-  program
+  const sessions = program
     .command("sessions")
-    .description("List stored conversation sessions")
+    .description("List and manage stored conversation sessions")
     .option("--json", "Output as JSON", false)
     .option("--verbose", "Verbose logging", false)
     .option("--store <path>", "Path to session store (default: resolved from config)")
     .option("--active <minutes>", "Only show sessions updated within the past N minutes")
+    .option("--global", "List sessions from every agent, not just the current workspace's", false)
     .addHelpText(
       "after",
       () =>
         `\n${theme.heading("Examples:")}\n${formatHelpExamples([
           ["openclaw sessions", "List all sessions."],
           ["openclaw sessions --active 120", "Only last 2 hours."],
+          ["openclaw sessions --global", "Sessions from every agent."],
           ["openclaw sessions --json", "Machine-readable output."],
           ["openclaw sessions --store ./tmp/sessions.json", "Use a specific session store."],
+          ["openclaw sessions show main --limit 20", "Print the last 20 transcript messages."],
+          ["openclaw sessions delete --all-older-than 30d", "Delete sessions idle for 30 days."],
+          ["openclaw sessions import ./conversations.json", "Import a ChatGPT export."],
+          ["openclaw sessions prune --dry-run", "Preview what session maintenance would remove."],
         ])}\n\n${theme.muted(
           "Shows token usage per session when the agent reports it; set agents.defaults.contextTokens to cap the window and show %.",
         )}`,
@@ -131,16 +144,182 @@ export function registerStatusHealthSessionsCommands(program: Command) {
       "after",
       () =>
         `\n${theme.muted("Docs:")} ${formatDocsLink("/cli/sessions", "docs.openclaw.ai/cli/sessions")}\n`,
-    )
-    .action(async (opts) => {
-      setVerbose(Boolean(opts.verbose));
-      await sessionsCommand(
-        {
-          json: Boolean(opts.json),
-          store: opts.store as string | undefined,
-          active: opts.active as string | undefined,
-        },
-        defaultRuntime,
-      );
+    );
+
+  // --json and --store live on the parent so they work before or after the subcommand name.
+  const parentOpts = (cmd: Command) =>
+    (cmd.parent?.opts?.() ?? {}) as {
+      json?: boolean;
+      store?: string;
+      global?: boolean;
+      verbose?: boolean;
+    };
+
+  sessions
+    .command("show <name>")
+    .description("Pretty-print a session transcript (name: key, id, title, or label)")
+    .option("--limit <n>", "Only show the last N messages")
+    .option("--full", "Do not truncate tool output", false)
+    .action(async (name, opts, cmd) => {
+      const parent = parentOpts(cmd);
+      setVerbose(Boolean(parent.verbose));
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await sessionsShowCommand(
+          {
+            name: String(name),
+            store: parent.store,
+            json: Boolean(parent.json),
+            limit: opts.limit as string | undefined,
+            full: Boolean(opts.full),
+          },
+          defaultRuntime,
+        );
+      });
+    });
+
+  sessions
+    .command("delete [name]")
+    .description("Delete a session (its transcript is archived, not erased)")
+    .option("--all-older-than <duration>", "Delete every session idle longer than this (e.g. 30d)")
+    .option("--keep-transcript", "Leave transcript files in place", false)
+    .option("--force", "Skip confirmation", false)
+    .action(async (name, opts, cmd) => {
+      const parent = parentOpts(cmd);
+      setVerbose(Boolean(parent.verbose));
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await sessionsDeleteCommand(
+          {
+            name: typeof name === "string" ? name : undefined,
+            allOlderThan: opts.allOlderThan as string | undefined,
+            store: parent.store,
+            force: Boolean(opts.force),
+            keepTranscript: Boolean(opts.keepTranscript),
+            json: Boolean(parent.json),
+          },
+          defaultRuntime,
+        );
+      });
+    });
+
+  sessions
+    .command("rename <name> <title...>")
+    .description("Set a session's title")
+    .action(async (name, title, _opts, cmd) => {
+      const parent = parentOpts(cmd);
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await sessionsRenameCommand(
+          {
+            name: String(name),
+            title: (title as string[]).join(" "),
+            store: parent.store,
+            json: Boolean(parent.json),
+          },
+          defaultRuntime,
+        );
+      });
+    });
+
+  sessions
+    .command("copy <name> <newKey>")
+    .description("Copy a session and its transcript to a new session key")
+    .option("--title <title>", "Title for the copy (default: original title + (copy))")
+    .action(async (name, newKey, opts, cmd) => {
+      const parent = parentOpts(cmd);
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await sessionsCopyCommand(
+          {
+            name: String(name),
+            newKey: String(newKey),
+            title: opts.title as string | undefined,
+            store: parent.store,
+            json: Boolean(parent.json),
+          },
+          defaultRuntime,
+        );
+      });
+    });
+
+  sessions
+    .command("fork <name> <newKey>")
+    .description("Branch a session's active history into a new session key")
+    .option("--rewind <turns>", "Leave out the last N turns (user messages and what followed)")
+    .option("--title <title>", "Title for the fork (default: original title + (fork))")
+    .action(async (name, newKey, opts, cmd) => {
+      const parent = parentOpts(cmd);
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await sessionsForkCommand(
+          {
+            name: String(name),
+            newKey: String(newKey),
+            title: opts.title as string | undefined,
+            rewind: opts.rewind as string | undefined,
+            store: parent.store,
+            json: Boolean(parent.json),
+          },
+          defaultRuntime,
+        );
+      });
+    });
+
+  sessions
+    .command("import <file>")
+    .description("Import a Claude Code session log or ChatGPT export as new sessions")
+    .option("--format <format>", "auto | claude-code | openai", "auto")
+    .option("--key <key>", "Session key for the import (single conversation only)")
+    .option("--title <title>", "Title for the import (default: the source title)")
+    .action(async (file, opts, cmd) => {
+      const parent = parentOpts(cmd);
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await sessionsImportCommand(
+          {
+            file: String(file),
+            format: opts.format as string | undefined,
+            key: opts.key as string | undefined,
+            title: opts.title as string | undefined,
+            store: parent.store,
+            json: Boolean(parent.json),
+          },
+          defaultRuntime,
+        );
+      });
     });
+
+  sessions
+    .command("prune")
+    .description("Remove old sessions per session.maintenance and archive their transcripts")
+    .option("--dry-run", "List what would be removed without changing anything", false)
+    .option("--older-than <duration>", "Prune sessions idle longer than this (default: pruneAfter)")
+    .option("--max-entries <n>", "Keep at most N sessions per store (default: maxEntries)")
+    .option("--transcripts <mode>", "archive | delete | keep (default: maintenance.transcripts)")
+    .action(async (opts, cmd) => {
+      const parent = parentOpts(cmd);
+      setVerbose(Boolean(parent.verbose));
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await sessionsPruneCommand(
+          {
+            dryRun: Boolean(opts.dryRun),
+            olderThan: opts.olderThan as string | undefined,
+            maxEntries: opts.maxEntries as string | undefined,
+            transcripts: opts.transcripts as string | undefined,
+            store: parent.store,
+            global: Boolean(parent.global),
+            json: Boolean(parent.json),
+          },
+          defaultRuntime,
+        );
+      });
+    });
+
+  sessions.action(async (opts) => {
+    setVerbose(Boolean(opts.verbose));
+    await sessionsCommand(
+      {
+        json: Boolean(opts.json),
+        store: opts.store as string | undefined,
+        active: opts.active as string | undefined,
+        global: Boolean(opts.global),
+      },
+      defaultRuntime,
+    );
+  });
 }
@@ -80,6 +80,54 @@ const entries: SubCliEntry[] = [
       mod.registerModelsCli(program);
     },
   },
+  {
+    name: "usage",
+    description: "Token and cost reports",
+    register: async (program) => {
+      const mod = await import("../usage-cli.js");
+      mod.registerUsageCli(program);
+    },
+  },
+  {
+    name: "bench",
+    description: "Compare models on one prompt",
+    register: async (program) => {
+      const mod = await import("../bench-cli.js");
+      mod.registerBenchCli(program);
+    },
+  },
+  {
+    name: "eval",
+    description: "Run prompt regression suites",
+    register: async (program) => {
+      const mod = await import("../eval-cli.js");
+      mod.registerEvalCli(program);
+    },
+  },
+  {
+    name: "index",
+    description: "Embed source files for codebase_search",
+    register: async (program) => {
+      const mod = await import("../index-cli.js");
+      mod.registerIndexCli(program);
+    },
+  },
+  {
+    name: "review",
+    description: "Review a diff with the model",
+    register: async (program) => {
+      const mod = await import("../review-cli.js");
+      mod.registerReviewCli(program);
+    },
+  },
+  {
+    name: "batch",
+    description: "Anthropic Message Batches",
+    register: async (program) => {
+      const mod = await import("../batch-cli.js");
+      mod.registerBatchCli(program);
+    },
+  },
   {
     name: "approvals",
     description: "Exec approvals",
@@ -20,6 +20,12 @@ describe("program routes", () => {
     await expect(route?.run(["node", "openclaw", "sessions", "--store"])).resolves.toBe(false);
   });
 
+  it("leaves sessions subcommands to commander", () => {
+    expect(findRoutedCommand(["sessions", "show"])).toBeNull();
+    expect(findRoutedCommand(["sessions", "delete"])).toBeNull();
+    expect(findRoutedCommand(["sessions", "./store.json"])).not.toBeNull();
+  });
+
   it("does not match unknown routes", () => {
     expect(findRoutedCommand(["definitely-not-real"])).toBeNull();
   });
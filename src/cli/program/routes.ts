@@ -42,10 +42,22 @@ const routeStatus: RouteSpec = {
   },
 };
 
+const SESSIONS_SUBCOMMANDS = new Set([
+  "show",
+  "delete",
+  "rename",
+  "copy",
+  "fork",
+  "import",
+  "prune",
+]);
+
 const routeSessions: RouteSpec = {
-  match: (path) => path[0] === "sessions",
+  // Subcommands go through commander; only the plain listing takes the fast path.
+  match: (path) => path[0] === "sessions" && !SESSIONS_SUBCOMMANDS.has(path[1] ?? ""),
   run: async (argv) => {
     const json = hasFlag(argv, "--json");
+    const global = hasFlag(argv, "--global");
     const store = getFlagValue(argv, "--store");
     if (store === null) {
       return false;
@@ -55,7 +67,7 @@ const routeSessions: RouteSpec = {
       return false;
     }
     const { sessionsCommand } = await import("../../commands/sessions.js");
-    await sessionsCommand({ json, store, active }, defaultRuntime);
+    await sessionsCommand({ json, store, active, global }, defaultRuntime);
     return true;
   },
 };
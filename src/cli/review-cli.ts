@@ -0,0 +1,39 @@
+import type { Command } from "commander";
+import { reviewCommand } from "../commands/review.js";
+import { defaultRuntime } from "../runtime.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { theme } from "../terminal/theme.js";
+import { runCommandWithRuntime } from "./cli-utils.js";
+import { formatHelpExamples } from "./help-format.js";
+
+export function registerReviewCli(program: Command) {
+  program
+    .command("review")
+    .description("Review a diff with the model and list findings by file and severity")
+    .argument("[ref]", "Ref or range to review (e.g. main, HEAD~3, a1b2c3..HEAD)")
+    .option("--staged", "Review staged changes only", false)
+    .option("--pr <number>", "Review a GitHub pull request (uses the gh CLI)")
+    .option("--agent <id>", "Agent whose model settings to use")
+    .option("--model <ref>", "Model to review with (provider/model or alias)")
+    .option("--fail-on <severity>", "Exit 1 on findings this severe: critical|major|minor|nit")
+    .option("--json", "Output JSON", false)
+    .addHelpText(
+      "after",
+      () =>
+        `
+${theme.heading("Examples:")}
+${formatHelpExamples([
+  ["openclaw review", "Review uncommitted changes against HEAD."],
+  ["openclaw review main", "Review this branch's changes since it left main."],
+  ["openclaw review --pr 123 --fail-on major", "Gate CI on major or critical findings."],
+])}
+
+${theme.muted("Docs:")} ${formatDocsLink("/cli/review", "docs.openclaw.ai/cli/review")}
+`,
+    )
+    .action(async (ref: string | undefined, opts) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await reviewCommand(ref, opts, defaultRuntime);
+      });
+    });
+}
@@ -1,4 +1,5 @@
 import type { Command } from "commander";
+import { tuiHistoryCommand } from "../commands/tui-history.js";
 import { defaultRuntime } from "../runtime.js";
 import { formatDocsLink } from "../terminal/links.js";
 import { theme } from "../terminal/theme.js";
@@ -6,7 +7,7 @@ import { runTui } from "../tui/tui.js";
 import { parseTimeoutMs } from "./parse-timeout.js";
 
 export function registerTuiCli(program: Command) {
-  program
+  const tui = program
     .command("tui")
     .description("Open a terminal UI connected to the Gateway")
     .option("--url <url>", "Gateway WebSocket URL (defaults to gateway.remote.url when configured)")
@@ -18,6 +19,8 @@ export function registerTuiCli(program: Command) {
     .option("--message <text>", "Send an initial message after connecting")
     .option("--timeout-ms <ms>", "Agent timeout in ms (defaults to agents.defaults.timeoutSeconds)")
     .option("--history-limit <n>", "History entries to load", "200")
+    .option("--stream", "Stream replies as they are generated (default)")
+    .option("--no-stream", "Show each reply only once it is complete")
     .addHelpText(
       "after",
       () => `\n${theme.muted("Docs:")} ${formatDocsLink("/cli/tui", "docs.openclaw.ai/cli/tui")}\n`,
@@ -41,6 +44,30 @@ export function registerTuiCli(program: Command) {
           message: opts.message as string | undefined,
           timeoutMs,
           historyLimit: Number.isNaN(historyLimit) ? undefined : historyLimit,
+          stream: opts.stream as boolean | undefined,
+        });
+      } catch (err) {
+        defaultRuntime.error(String(err));
+        defaultRuntime.exit(1);
+      }
+    });
+
+  tui
+    .command("history")
+    .description("List or clear the TUI input history (shared by all sessions)")
+    .option("--limit <n>", "Show the last N entries", "20")
+    .option("--session <key>", "Only entries typed in this session")
+    .option("--clear", "Delete the history", false)
+    .option("--json", "Output JSON", false)
+    .action(async (opts, cmd: Command) => {
+      try {
+        // `--session` is also a `tui` option, so commander may hand it to the parent.
+        const parentSession = (cmd.parent?.opts?.() ?? {}).session as string | undefined;
+        await tuiHistoryCommand({
+          limit: opts.limit as string | undefined,
+          session: (opts.session as string | undefined) ?? parentSession,
+          clear: Boolean(opts.clear),
+          json: Boolean(opts.json),
         });
       } catch (err) {
         defaultRuntime.error(String(err));
@@ -0,0 +1,45 @@
+import type { Command } from "commander";
+import { usageReportCommand } from "../commands/usage-report.js";
+import { defaultRuntime } from "../runtime.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { theme } from "../terminal/theme.js";
+import { runCommandWithRuntime } from "./cli-utils.js";
+import { formatHelpExamples } from "./help-format.js";
+
+export function registerUsageCli(program: Command) {
+  const usage = program
+    .command("usage")
+    .description("Token and cost accounting from session transcripts")
+    .addHelpText(
+      "after",
+      () =>
+        `\n${theme.muted("Docs:")} ${formatDocsLink("/cli/usage", "docs.openclaw.ai/cli/usage")}\n`,
+    )
+    .action(() => {
+      usage.help({ error: true });
+    });
+
+  usage
+    .command("report")
+    .description("Summarize tokens and cost by model, day, or session")
+    .option("--since <when>", "Duration back from now (7d, 12h) or a date (2026-10-01)", "7d")
+    .option("--by <group>", "Group rows by model|day|session", "model")
+    .option("--agent <id>", "Agent id (default: the default agent)")
+    .option("--json", "Output JSON", false)
+    .addHelpText(
+      "after",
+      () =>
+        `
+${theme.heading("Examples:")}
+${formatHelpExamples([
+  ["openclaw usage report", "Last 7 days by model."],
+  ["openclaw usage report --since 30d --by day", "Daily totals for the last month."],
+  ["openclaw usage report --by session --json", "Per-session totals as JSON."],
+])}`,
+    )
+    .action(async (opts) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await usageReportCommand(opts, defaultRuntime);
+      });
+    });
+}
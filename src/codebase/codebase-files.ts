@@ -0,0 +1,87 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import { walkTree } from "../agents/tools/fs-walk.js";
+
+export const DEFAULT_CODEBASE_MAX_FILE_BYTES = 256 * 1024;
+
+/** Text files worth embedding; binaries, lockfiles, and generated bundles are left out. */
+const CODE_EXTENSIONS = new Set([
+  ".c",
+  ".cc",
+  ".cjs",
+  ".cpp",
+  ".cs",
+  ".css",
+  ".go",
+  ".h",
+  ".hpp",
+  ".html",
+  ".java",
+  ".js",
+  ".jsx",
+  ".kt",
+  ".lua",
+  ".md",
+  ".mjs",
+  ".php",
+  ".py",
+  ".rb",
+  ".rs",
+  ".scala",
+  ".sh",
+  ".sql",
+  ".svelte",
+  ".swift",
+  ".toml",
+  ".ts",
+  ".tsx",
+  ".vue",
+  ".yaml",
+  ".yml",
+  ".zig",
+]);
+
+const SKIPPED_FILE_SUFFIXES = [".min.js", ".min.css", ".d.ts.map", ".map"];
+
+const SKIP_DIRS = [".git", "node_modules", "dist", "build", "target", "vendor", ".venv", "venv"];
+
+export type CodebaseFile = {
+  /** Root-relative, `/`-separated. */
+  relPath: string;
+  absPath: string;
+  mtimeMs: number;
+  size: number;
+};
+
+export function isIndexableCodePath(relPath: string): boolean {
+  const lower = relPath.toLowerCase();
+  if (SKIPPED_FILE_SUFFIXES.some((suffix) => lower.endsWith(suffix))) {
+    return false;
+  }
+  return CODE_EXTENSIONS.has(path.extname(lower));
+}
+
+/** Source files under `root`, honoring .gitignore and skipping build output and oversized files. */
+export async function listCodebaseFiles(
+  root: string,
+  opts: { maxFileBytes?: number; signal?: AbortSignal } = {},
+): Promise<CodebaseFile[]> {
+  const maxFileBytes = opts.maxFileBytes ?? DEFAULT_CODEBASE_MAX_FILE_BYTES;
+  const files: CodebaseFile[] = [];
+  for await (const entry of walkTree({ root, skipDirs: SKIP_DIRS, signal: opts.signal })) {
+    if (!entry.dirent.isFile() || !isIndexableCodePath(entry.relPath)) {
+      continue;
+    }
+    const stat = await fs.stat(entry.path).catch(() => null);
+    if (!stat || stat.size === 0 || stat.size > maxFileBytes) {
+      continue;
+    }
+    files.push({
+      relPath: entry.relPath,
+      absPath: entry.path,
+      mtimeMs: Math.floor(stat.mtimeMs),
+      size: stat.size,
+    });
+  }
+  return files.toSorted((a, b) => a.relPath.localeCompare(b.relPath));
+}
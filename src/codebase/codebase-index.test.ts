@@ -0,0 +1,106 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+
+const VOCAB = ["retry", "webhook", "parse", "config", "render"];
+const embed = (text: string) => VOCAB.map((word) => text.toLowerCase().split(word).length - 1);
+const embedBatch = vi.fn(async (texts: string[]) => texts.map(embed));
+
+vi.mock("../memory/embeddings.js", () => ({
+  createEmbeddingProvider: async () => ({
+    provider: {
+      id: "mock",
+      model: "bag-of-words",
+      embedQuery: async (text: string) => embed(text),
+      embedBatch,
+    },
+  }),
+}));
+
+import { listCodebaseFiles } from "./codebase-files.js";
+import { CodebaseIndex, hasCodebaseIndex } from "./codebase-index.js";
+
+describe("codebase index", () => {
+  let root: string;
+  let stateDir: string;
+  let env: NodeJS.ProcessEnv;
+
+  beforeEach(async () => {
+    root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-codebase-"));
+    stateDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-codebase-state-"));
+    env = { OPENCLAW_STATE_DIR: stateDir };
+    embedBatch.mockClear();
+    await fs.mkdir(path.join(root, "src"));
+    await fs.mkdir(path.join(root, "node_modules", "dep"), { recursive: true });
+    await fs.writeFile(path.join(root, ".gitignore"), "generated/\n");
+    await fs.mkdir(path.join(root, "generated"));
+    await fs.writeFile(path.join(root, "src", "webhook.ts"), "export function retryWebhook() {}\n");
+    await fs.writeFile(path.join(root, "src", "config.ts"), "export function parseConfig() {}\n");
+    await fs.writeFile(path.join(root, "src", "logo.png"), "not code");
+    await fs.writeFile(path.join(root, "src", "app.min.js"), "render()");
+    await fs.writeFile(path.join(root, "generated", "api.ts"), "webhook webhook");
+    await fs.writeFile(path.join(root, "node_modules", "dep", "index.js"), "retry");
+  });
+
+  afterEach(async () => {
+    await fs.rm(root, { recursive: true, force: true });
+    await fs.rm(stateDir, { recursive: true, force: true });
+  });
+
+  const open = () => CodebaseIndex.get({ cfg: {}, agentId: "main", root, env });
+
+  it("lists source files, honoring .gitignore and skipping deps and minified bundles", async () => {
+    const files = await listCodebaseFiles(root);
+    expect(files.map((file) => file.relPath)).toEqual(["src/config.ts", "src/webhook.ts"]);
+  });
+
+  it("indexes, searches, and only re-embeds what changed", async () => {
+    const index = await open();
+    try {
+      expect(hasCodebaseIndex(root, env)).toBe(true);
+      expect(await index.sync()).toEqual({
+        files: 2,
+        indexed: 2,
+        unchanged: 0,
+        removed: 0,
+        pending: 0,
+        chunks: 2,
+      });
+
+      const results = await index.search("where is the webhook retry?", { maxResults: 1 });
+      expect(results.map((result) => result.path)).toEqual(["src/webhook.ts"]);
+      expect(results[0]).toMatchObject({ startLine: 1, endLine: 2 });
+
+      embedBatch.mockClear();
+      expect((await index.sync()).indexed).toBe(0);
+      expect(embedBatch).not.toHaveBeenCalled();
+
+      const configPath = path.join(root, "src", "config.ts");
+      await fs.writeFile(configPath, "export function renderConfig() {}\n");
+      const later = new Date(Date.now() + 10_000);
+      await fs.utimes(configPath, later, later);
+      await fs.rm(path.join(root, "src", "webhook.ts"));
+
+      const synced = await index.sync();
+      expect(synced).toMatchObject({ files: 1, indexed: 1, removed: 1, chunks: 1 });
+      expect(embedBatch).toHaveBeenCalledTimes(1);
+      expect((await index.search("render", { pathPrefix: "src" }))[0]?.snippet).toContain(
+        "renderConfig",
+      );
+      expect(await index.search("render", { pathPrefix: "lib" })).toEqual([]);
+    } finally {
+      index.close();
+    }
+  });
+
+  it("leaves changed files beyond maxFiles pending", async () => {
+    const index = await open();
+    try {
+      expect(await index.sync({ maxFiles: 1 })).toMatchObject({ indexed: 1, pending: 1 });
+      expect(await index.sync({ maxFiles: 1 })).toMatchObject({ indexed: 1, pending: 0 });
+    } finally {
+      index.close();
+    }
+  });
+});
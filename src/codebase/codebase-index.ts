@@ -0,0 +1,406 @@
+import type { DatabaseSync } from "node:sqlite";
+import fsSync from "node:fs";
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { OpenClawConfig } from "../config/config.js";
+import type { EmbeddingProvider } from "../memory/embeddings.js";
+import { resolveAgentDir } from "../agents/agent-scope.js";
+import { resolveProjectStateKey } from "../agents/memory-file.js";
+import { resolveMemorySearchConfig } from "../agents/memory-search.js";
+import { resolveStateDir } from "../config/paths.js";
+import { enforceEmbeddingMaxInputTokens } from "../memory/embedding-chunk-limits.js";
+import { createEmbeddingProvider } from "../memory/embeddings.js";
+import {
+  chunkMarkdown,
+  cosineSimilarity,
+  ensureDir,
+  hashText,
+  type MemoryChunk,
+  parseEmbedding,
+} from "../memory/internal.js";
+import { requireNodeSqlite } from "../memory/sqlite.js";
+import { type CodebaseFile, listCodebaseFiles } from "./codebase-files.js";
+
+const SCHEMA_VERSION = 1;
+/** Smaller than memory chunks: a function or two per hit reads better than half a file. */
+const CODE_CHUNKING = { tokens: 300, overlap: 40 };
+const EMBED_BATCH_SIZE = 64;
+const SNIPPET_MAX_CHARS = 1200;
+const DEFAULT_MAX_RESULTS = 8;
+
+const INDEX_CACHE = new Map<string, CodebaseIndex>();
+
+export type CodebaseSearchResult = {
+  path: string;
+  startLine: number;
+  endLine: number;
+  score: number;
+  snippet: string;
+};
+
+export type CodebaseSyncResult = {
+  files: number;
+  indexed: number;
+  unchanged: number;
+  removed: number;
+  /** Changed files left for a later sync because of `maxFiles`. */
+  pending: number;
+  chunks: number;
+};
+
+export type CodebaseSyncOptions = {
+  /** Drop everything and re-embed all files. */
+  force?: boolean;
+  /** Re-embed at most this many changed files; the rest are reported as `pending`. */
+  maxFiles?: number;
+  maxFileBytes?: number;
+  signal?: AbortSignal;
+  onProgress?: (update: { completed: number; total: number; path: string }) => void;
+};
+
+export type CodebaseIndexStatus = {
+  root: string;
+  dbPath: string;
+  provider: string;
+  model: string;
+  files: number;
+  chunks: number;
+  indexedAt?: number;
+};
+
+/** `<stateDir>/codebase/<name>-<hash>.sqlite`: one index per project root. */
+export function resolveCodebaseIndexPath(
+  root: string,
+  env: NodeJS.ProcessEnv = process.env,
+): string {
+  return path.join(resolveStateDir(env), "codebase", `${resolveProjectStateKey(root)}.sqlite`);
+}
+
+export function hasCodebaseIndex(root: string, env: NodeJS.ProcessEnv = process.env): boolean {
+  return fsSync.existsSync(resolveCodebaseIndexPath(root, env));
+}
+
+function ensureCodebaseSchema(db: DatabaseSync) {
+  db.exec(`
+    CREATE TABLE IF NOT EXISTS meta (
+      key TEXT PRIMARY KEY,
+      value TEXT NOT NULL
+    );
+  `);
+  db.exec(`
+    CREATE TABLE IF NOT EXISTS files (
+      path TEXT PRIMARY KEY,
+      hash TEXT NOT NULL,
+      mtime INTEGER NOT NULL,
+      size INTEGER NOT NULL
+    );
+  `);
+  db.exec(`
+    CREATE TABLE IF NOT EXISTS chunks (
+      path TEXT NOT NULL,
+      start_line INTEGER NOT NULL,
+      end_line INTEGER NOT NULL,
+      text TEXT NOT NULL,
+      embedding TEXT NOT NULL
+    );
+  `);
+  db.exec(`CREATE INDEX IF NOT EXISTS idx_chunks_path ON chunks(path);`);
+}
+
+type PendingFile = {
+  file: CodebaseFile;
+  hash: string;
+  chunks: MemoryChunk[];
+};
+
+/**
+ * Embedding index over a project's source files, stored in SQLite under the state dir. Syncs are
+ * incremental: files whose mtime and size are unchanged are skipped, edited files are re-chunked
+ * and re-embedded, and deleted or newly ignored files are dropped. Uses the agent's
+ * `memorySearch` embedding provider; switching provider or model rebuilds the index.
+ */
+export class CodebaseIndex {
+  private syncing: Promise<CodebaseSyncResult> | null = null;
+
+  private constructor(
+    readonly root: string,
+    readonly dbPath: string,
+    private readonly cacheKey: string,
+    private readonly db: DatabaseSync,
+    private readonly provider: EmbeddingProvider,
+  ) {}
+
+  static async get(params: {
+    cfg: OpenClawConfig;
+    agentId: string;
+    root: string;
+    env?: NodeJS.ProcessEnv;
+  }): Promise<CodebaseIndex> {
+    const root = path.resolve(params.root);
+    const dbPath = resolveCodebaseIndexPath(root, params.env);
+    const cacheKey = `${params.agentId}\0${dbPath}`;
+    const cached = INDEX_CACHE.get(cacheKey);
+    if (cached) {
+      return cached;
+    }
+    const settings = resolveMemorySearchConfig(params.cfg, params.agentId);
+    if (!settings) {
+      throw new Error(
+        "codebase index needs an embedding provider; memorySearch is disabled for this agent",
+      );
+    }
+    const { provider } = await createEmbeddingProvider({
+      config: params.cfg,
+      agentDir: resolveAgentDir(params.cfg, params.agentId),
+      provider: settings.provider,
+      remote: settings.remote,
+      model: settings.model,
+      fallback: settings.fallback,
+      local: settings.local,
+    });
+    ensureDir(path.dirname(dbPath));
+    const { DatabaseSync } = requireNodeSqlite();
+    const db = new DatabaseSync(dbPath);
+    ensureCodebaseSchema(db);
+    const index = new CodebaseIndex(root, dbPath, cacheKey, db, provider);
+    index.resetOnFingerprintChange();
+    INDEX_CACHE.set(cacheKey, index);
+    return index;
+  }
+
+  private readMeta(key: string): string | undefined {
+    const row = this.db.prepare("SELECT value FROM meta WHERE key = ?").get(key) as
+      | { value: string }
+      | undefined;
+    return row?.value;
+  }
+
+  private writeMeta(key: string, value: string) {
+    this.db
+      .prepare(
+        "INSERT INTO meta (key, value) VALUES (?, ?) ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+      )
+      .run(key, value);
+  }
+
+  private resetOnFingerprintChange() {
+    const fingerprint = JSON.stringify({
+      version: SCHEMA_VERSION,
+      provider: this.provider.id,
+      model: this.provider.model,
+      chunking: CODE_CHUNKING,
+    });
+    if (this.readMeta("fingerprint") !== fingerprint) {
+      this.clear();
+      this.writeMeta("fingerprint", fingerprint);
+    }
+  }
+
+  private clear() {
+    this.db.exec("DELETE FROM chunks; DELETE FROM files;");
+    this.db.prepare("DELETE FROM meta WHERE key = 'indexed_at'").run();
+  }
+
+  status(): CodebaseIndexStatus {
+    const count = (table: "files" | "chunks") =>
+      (this.db.prepare(`SELECT COUNT(*) AS n FROM ${table}`).get() as { n: number }).n;
+    const indexedAt = Number(this.readMeta("indexed_at"));
+    return {
+      root: this.root,
+      dbPath: this.dbPath,
+      provider: this.provider.id,
+      model: this.provider.model,
+      files: count("files"),
+      chunks: count("chunks"),
+      indexedAt: Number.isFinite(indexedAt) && indexedAt > 0 ? indexedAt : undefined,
+    };
+  }
+
+  /** Concurrent callers share one in-flight sync. */
+  async sync(opts: CodebaseSyncOptions = {}): Promise<CodebaseSyncResult> {
+    if (this.syncing) {
+      return await this.syncing;
+    }
+    this.syncing = this.runSync(opts);
+    try {
+      return await this.syncing;
+    } finally {
+      this.syncing = null;
+    }
+  }
+
+  private async runSync(opts: CodebaseSyncOptions): Promise<CodebaseSyncResult> {
+    if (opts.force) {
+      this.clear();
+    }
+    const files = await listCodebaseFiles(this.root, {
+      maxFileBytes: opts.maxFileBytes,
+      signal: opts.signal,
+    });
+    const known = new Map(
+      (
+        this.db.prepare("SELECT path, hash, mtime, size FROM files").all() as Array<{
+          path: string;
+          hash: string;
+          mtime: number;
+          size: number;
+        }>
+      ).map((row) => [row.path, row]),
+    );
+
+    const present = new Set(files.map((file) => file.relPath));
+    const removed = [...known.keys()].filter((relPath) => !present.has(relPath));
+    if (removed.length > 0) {
+      const deleteChunks = this.db.prepare("DELETE FROM chunks WHERE path = ?");
+      const deleteFile = this.db.prepare("DELETE FROM files WHERE path = ?");
+      this.db.exec("BEGIN");
+      try {
+        for (const relPath of removed) {
+          deleteChunks.run(relPath);
+          deleteFile.run(relPath);
+        }
+        this.db.exec("COMMIT");
+      } catch (err) {
+        this.db.exec("ROLLBACK");
+        throw err;
+      }
+    }
+
+    const changed = files.filter((file) => {
+      const row = known.get(file.relPath);
+      return !row || row.mtime !== file.mtimeMs || row.size !== file.size;
+    });
+    const limit = opts.maxFiles ?? changed.length;
+    const batch = changed.slice(0, limit);
+    let indexed = 0;
+    let unchanged = files.length - changed.length;
+    let queue: PendingFile[] = [];
+    let queuedChunks = 0;
+
+    const touchFile = this.db.prepare("UPDATE files SET mtime = ?, size = ? WHERE path = ?");
+    const flush = async () => {
+      if (queue.length === 0) {
+        return;
+      }
+      const texts = queue.flatMap((item) =>
+        item.chunks.map((chunk) => `${item.file.relPath}\n${chunk.text}`),
+      );
+      const vectors = texts.length > 0 ? await this.provider.embedBatch(texts) : [];
+      const deleteChunks = this.db.prepare("DELETE FROM chunks WHERE path = ?");
+      const insertChunk = this.db.prepare(
+        "INSERT INTO chunks (path, start_line, end_line, text, embedding) VALUES (?, ?, ?, ?, ?)",
+      );
+      const upsertFile = this.db.prepare(
+        "INSERT INTO files (path, hash, mtime, size) VALUES (?, ?, ?, ?) " +
+          "ON CONFLICT(path) DO UPDATE SET hash = excluded.hash, mtime = excluded.mtime, size = excluded.size",
+      );
+      let offset = 0;
+      this.db.exec("BEGIN");
+      try {
+        for (const item of queue) {
+          deleteChunks.run(item.file.relPath);
+          for (const chunk of item.chunks) {
+            const vector = vectors[offset] ?? [];
+            offset += 1;
+            insertChunk.run(
+              item.file.relPath,
+              chunk.startLine,
+              chunk.endLine,
+              chunk.text,
+              JSON.stringify(vector),
+            );
+          }
+          upsertFile.run(item.file.relPath, item.hash, item.file.mtimeMs, item.file.size);
+        }
+        this.db.exec("COMMIT");
+      } catch (err) {
+        this.db.exec("ROLLBACK");
+        throw err;
+      }
+      indexed += queue.length;
+      queue = [];
+      queuedChunks = 0;
+    };
+
+    for (const [i, file] of batch.entries()) {
+      if (opts.signal?.aborted) {
+        break;
+      }
+      opts.onProgress?.({ completed: i, total: batch.length, path: file.relPath });
+      const content = await fs.readFile(file.absPath, "utf-8").catch(() => null);
+      if (content === null) {
+        continue;
+      }
+      const hash = hashText(content);
+      if (known.get(file.relPath)?.hash === hash) {
+        // Touched but not edited (checkout, formatter no-op): just remember the new mtime.
+        touchFile.run(file.mtimeMs, file.size, file.relPath);
+        unchanged += 1;
+        continue;
+      }
+      const chunks = enforceEmbeddingMaxInputTokens(
+        this.provider,
+        chunkMarkdown(content, CODE_CHUNKING).filter((chunk) => chunk.text.trim()),
+      );
+      queue.push({ file, hash, chunks });
+      queuedChunks += chunks.length;
+      if (queuedChunks >= EMBED_BATCH_SIZE) {
+        await flush();
+      }
+    }
+    await flush();
+    opts.onProgress?.({ completed: batch.length, total: batch.length, path: "" });
+    this.writeMeta("indexed_at", String(Date.now()));
+
+    return {
+      files: files.length,
+      indexed,
+      unchanged,
+      removed: removed.length,
+      pending: changed.length - batch.length,
+      chunks: this.status().chunks,
+    };
+  }
+
+  async search(
+    query: string,
+    opts: { maxResults?: number; pathPrefix?: string } = {},
+  ): Promise<CodebaseSearchResult[]> {
+    const trimmed = query.trim();
+    if (!trimmed) {
+      return [];
+    }
+    const queryVector = await this.provider.embedQuery(trimmed);
+    const prefix = opts.pathPrefix?.replace(/^\.\/+/, "").replace(/\/+$/, "");
+    const rows = (
+      prefix
+        ? this.db
+            .prepare(
+              "SELECT path, start_line, end_line, text, embedding FROM chunks WHERE path = ? OR path LIKE ? ESCAPE '\\'",
+            )
+            .all(prefix, `${prefix.replace(/[\\%_]/g, "\\$&")}/%`)
+        : this.db.prepare("SELECT path, start_line, end_line, text, embedding FROM chunks").all()
+    ) as Array<{
+      path: string;
+      start_line: number;
+      end_line: number;
+      text: string;
+      embedding: string;
+    }>;
+    return rows
+      .map((row) => ({
+        path: row.path,
+        startLine: row.start_line,
+        endLine: row.end_line,
+        score: cosineSimilarity(queryVector, parseEmbedding(row.embedding)),
+        snippet: row.text.slice(0, SNIPPET_MAX_CHARS),
+      }))
+      .toSorted((a, b) => b.score - a.score)
+      .slice(0, Math.max(1, opts.maxResults ?? DEFAULT_MAX_RESULTS));
+  }
+
+  close() {
+    INDEX_CACHE.delete(this.cacheKey);
+    this.db.close();
+  }
+}
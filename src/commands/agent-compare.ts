@@ -0,0 +1,67 @@
+import type { RuntimeEnv } from "../runtime.js";
+import {
+  formatCouncilAnswer,
+  parseCouncilModelList,
+  prepareCouncil,
+  resolveCouncilTimeoutMs,
+  runCouncil,
+} from "../agents/council.js";
+import { loadConfig } from "../config/config.js";
+
+export type AgentCompareOpts = {
+  message: string;
+  compare: string;
+  judge?: string;
+  agent?: string;
+  timeout?: string;
+  json?: boolean;
+};
+
+/** `openclaw agent --compare a,b`: one prompt, several models, answers printed as they finish. */
+export async function agentCompareCommand(opts: AgentCompareOpts, runtime: RuntimeEnv) {
+  const message = (opts.message ?? "").trim();
+  if (!message) {
+    throw new Error("Message (--message) is required");
+  }
+  const cfg = loadConfig();
+  const timeoutSeconds =
+    opts.timeout !== undefined ? Number.parseInt(String(opts.timeout), 10) : undefined;
+  if (timeoutSeconds !== undefined && (Number.isNaN(timeoutSeconds) || timeoutSeconds <= 0)) {
+    throw new Error("--timeout must be a positive integer (seconds)");
+  }
+
+  const council = await prepareCouncil({
+    cfg,
+    models: parseCouncilModelList(opts.compare),
+    judge: opts.judge,
+    agentId: opts.agent,
+  });
+  for (const error of council.errors) {
+    runtime.error(`--compare: ${error}`);
+  }
+  if (council.members.length < 2) {
+    throw new Error("--compare needs at least two usable models (e.g. --compare gpt,opus).");
+  }
+
+  const result = await runCouncil({
+    cfg,
+    prompt: message,
+    members: council.members,
+    judge: council.judge,
+    timeoutMs: resolveCouncilTimeoutMs(cfg, timeoutSeconds),
+    onAnswer: opts.json
+      ? undefined
+      : (answer) => {
+          runtime.log(`${formatCouncilAnswer(answer)}\n`);
+        },
+  });
+  if (opts.json) {
+    runtime.log(JSON.stringify(result, null, 2));
+  } else if (result.synthesis) {
+    runtime.log(formatCouncilAnswer(result.synthesis, `Synthesis by ${result.synthesis.key}`));
+  }
+  if (result.answers.every((answer) => answer.error)) {
+    throw new Error("None of the --compare models answered.");
+  }
+  return result;
+}
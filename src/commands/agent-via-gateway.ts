@@ -1,6 +1,14 @@
 import type { CliDeps } from "../cli/deps.js";
 import type { RuntimeEnv } from "../runtime.js";
 import { listAgentIds } from "../agents/agent-scope.js";
+import {
+  buildGitContext,
+  hasGitContextRequest,
+  parseGitContextLogCount,
+} from "../agents/git-context.js";
+import { buildPersonaSystemPrompt, requirePersona } from "../agents/personas.js";
+import { expandPromptTemplateVars } from "../agents/prompt-template-vars.js";
+import { installAgentShutdownHandlers } from "../agents/run-shutdown.js";
 import { DEFAULT_CHAT_CHANNEL } from "../channels/registry.js";
 import { formatCliCommand } from "../cli/command-format.js";
 import { withProgress } from "../cli/progress.js";
@@ -12,6 +20,7 @@ import {
   GATEWAY_CLIENT_NAMES,
   normalizeMessageChannel,
 } from "../utils/message-channel.js";
+import { agentCompareCommand } from "./agent-compare.js";
 import { agentCommand } from "./agent.js";
 import { resolveSessionKeyForRequest } from "./agent/session.js";
 
@@ -40,6 +49,20 @@ export type AgentCliOpts = {
   verbose?: string;
   json?: boolean;
   timeout?: string;
+  maxTurns?: string;
+  maxDuration?: string;
+  maxCostUsd?: string;
+  plan?: boolean;
+  memory?: boolean;
+  withDiff?: boolean;
+  withStaged?: boolean;
+  withLog?: string;
+  responseSchema?: string;
+  prefill?: string;
+  persona?: string;
+  stop?: string[];
+  compare?: string;
+  judge?: string;
   deliver?: boolean;
   channel?: string;
   replyTo?: string;
@@ -50,6 +73,7 @@ export type AgentCliOpts = {
   runId?: string;
   extraSystemPrompt?: string;
   local?: boolean;
+  force?: boolean;
 };
 
 function parseTimeoutSeconds(opts: { cfg: ReturnType<typeof loadConfig>; timeout?: string }) {
@@ -104,6 +128,21 @@ export async function agentViaGatewayCommand(opts: AgentCliOpts, runtime: Runtim
     }
   }
   const timeoutSeconds = parseTimeoutSeconds({ cfg, timeout: opts.timeout });
+  const persona = opts.persona ? await requirePersona(opts.persona) : undefined;
+  if (persona && (persona.model || persona.temperature !== undefined)) {
+    runtime.log(
+      `Persona "${persona.name}": model/temperature apply to --local runs; the Gateway uses the session model.`,
+    );
+  }
+  // The persona file is local config, so its template variables are filled in here.
+  const personaPrompt = persona
+    ? expandPromptTemplateVars(buildPersonaSystemPrompt(persona), {
+        cwd: process.cwd(),
+        timezone: cfg.agents?.defaults?.userTimezone,
+      })
+    : undefined;
+  const extraSystemPrompt =
+    [personaPrompt, opts.extraSystemPrompt].filter(Boolean).join("\n\n") || undefined;
   const gatewayTimeoutMs = Math.max(10_000, (timeoutSeconds + 30) * 1000);
 
   const sessionKey = resolveSessionKeyForRequest({
@@ -139,7 +178,7 @@ export async function agentViaGatewayCommand(opts: AgentCliOpts, runtime: Runtim
           replyAccountId: opts.replyAccount,
           timeout: timeoutSeconds,
           lane: opts.lane,
-          extraSystemPrompt: opts.extraSystemPrompt,
+          extraSystemPrompt,
           idempotencyKey,
         },
         expectFinal: true,
@@ -172,20 +211,74 @@ export async function agentViaGatewayCommand(opts: AgentCliOpts, runtime: Runtim
   return response;
 }
 
-export async function agentCliCommand(opts: AgentCliOpts, runtime: RuntimeEnv, deps?: CliDeps) {
+/** `--with-diff`, `--with-staged`, `--with-log <n>`: append git output from the current repo. */
+async function attachGitContext(opts: AgentCliOpts, runtime: RuntimeEnv): Promise<AgentCliOpts> {
+  const log =
+    opts.withLog !== undefined ? parseGitContextLogCount(opts.withLog, "--with-log") : undefined;
+  const request = { diff: opts.withDiff === true, staged: opts.withStaged === true, log };
+  if (!hasGitContextRequest(request)) {
+    return opts;
+  }
+  const context = await buildGitContext(process.cwd(), request);
+  if (context.truncated && !opts.json) {
+    runtime.log("Git context was truncated; ask about fewer changes or stage a subset.");
+  }
+  return { ...opts, message: `${opts.message.trim()}\n\n${context.text}` };
+}
+
+export async function agentCliCommand(cliOpts: AgentCliOpts, runtime: RuntimeEnv, deps?: CliDeps) {
+  const opts = await attachGitContext(cliOpts, runtime);
   const localOpts = {
     ...opts,
     agentId: opts.agent,
     replyAccountId: opts.replyAccount,
+    // Another local run on the same session would interleave transcript writes; refuse instead.
+    exclusiveSession: true,
   };
-  if (opts.local === true) {
-    return await agentCommand(localOpts, runtime, deps);
+  if (opts.compare) {
+    return await agentCompareCommand({ ...opts, compare: opts.compare }, runtime);
+  }
+  // Schema-constrained and prefilled replies need the embedded runner (provider payload hooks).
+  if (opts.local === true || opts.responseSchema || opts.prefill) {
+    return await runLocalAgentCommand(localOpts, runtime, deps);
   }
 
+  if (opts.maxTurns || opts.maxDuration || opts.maxCostUsd) {
+    runtime.log(
+      "--max-turns/--max-duration/--max-cost-usd apply to --local runs; the Gateway uses agents.defaults limits.",
+    );
+  }
+  if (opts.stop && opts.stop.length > 0) {
+    runtime.log(
+      "--stop applies to --local runs; the Gateway uses agents.defaults.models[...].params.stopSequences.",
+    );
+  }
+  if (opts.plan) {
+    runtime.log("--plan applies to --local runs; over the Gateway, send /plan in the session.");
+  }
+  if (opts.memory === false) {
+    runtime.log(
+      "--no-memory applies to --local runs; over the Gateway, send /tools disable group:memory.",
+    );
+  }
   try {
     return await agentViaGatewayCommand(opts, runtime);
   } catch (err) {
     runtime.error?.(`Gateway agent failed; falling back to embedded: ${String(err)}`);
-    return await agentCommand(localOpts, runtime, deps);
+    return await runLocalAgentCommand(localOpts, runtime, deps);
+  }
+}
+
+async function runLocalAgentCommand(
+  opts: Parameters<typeof agentCommand>[0],
+  runtime: RuntimeEnv,
+  deps?: CliDeps,
+) {
+  // Closing the terminal mid-run should still persist the partial turn and reap tool processes.
+  const dispose = installAgentShutdownHandlers({ exit: (code) => runtime.exit(code) });
+  try {
+    return await agentCommand(opts, runtime, deps);
+  } finally {
+    dispose();
   }
 }
@@ -138,6 +138,34 @@ describe("agentCommand", () => {
     });
   });
 
+  it("refuses a session another process is running unless forced", async () => {
+    await withTempHome(async (home) => {
+      const store = path.join(home, "sessions.json");
+      mockConfig(home, store);
+
+      await agentCommand({ message: "first", to: "+1444", exclusiveSession: true }, runtime);
+      const sessionFile = vi.mocked(runEmbeddedPiAgent).mock.calls.at(-1)?.[0]?.sessionFile ?? "";
+      const lockPath = `${sessionFile}.lock`;
+      expect(fs.existsSync(lockPath)).toBe(false);
+
+      fs.writeFileSync(
+        lockPath,
+        JSON.stringify({ pid: process.ppid, createdAt: new Date().toISOString() }),
+      );
+      await expect(
+        agentCommand({ message: "second", to: "+1444", exclusiveSession: true }, runtime),
+      ).rejects.toThrow(`is in use by another openclaw process (pid ${process.ppid})`);
+      expect(runEmbeddedPiAgent).toHaveBeenCalledTimes(1);
+
+      await agentCommand(
+        { message: "third", to: "+1444", exclusiveSession: true, force: true },
+        runtime,
+      );
+      expect(runEmbeddedPiAgent).toHaveBeenCalledTimes(2);
+      expect(fs.existsSync(lockPath)).toBe(false);
+    });
+  });
+
   it("does not duplicate agent events from embedded runs", async () => {
     await withTempHome(async (home) => {
       const store = path.join(home, "sessions.json");
@@ -21,9 +21,20 @@ import {
   resolveConfiguredModelRef,
   resolveThinkingDefault,
 } from "../agents/model-selection.js";
+import { buildPersonaSystemPrompt, requirePersona } from "../agents/personas.js";
+import { expandPromptTemplateVars } from "../agents/prompt-template-vars.js";
 import { runEmbeddedPiAgent } from "../agents/pi-embedded.js";
+import { formatRunTimings } from "../agents/run-timings.js";
 import { buildWorkspaceSkillSnapshot } from "../agents/skills.js";
 import { getSkillsSnapshotVersion } from "../agents/skills/refresh.js";
+import { checkSpendBudget, formatSpendBudgetNotice } from "../agents/spend-budget.js";
+import {
+  buildStructuredOutputSystemPrompt,
+  loadResponseSchema,
+  runWithStructuredOutput,
+  type StructuredOutputRequest,
+  type StructuredOutputResult,
+} from "../agents/structured-output.js";
 import { resolveAgentTimeoutMs } from "../agents/timeout.js";
 import { ensureAgentWorkspace } from "../agents/workspace.js";
 import {
@@ -49,6 +60,7 @@ import {
   emitAgentEvent,
   registerAgentRunContext,
 } from "../infra/agent-events.js";
+import { normalizeOutboundPayloadsForJson } from "../infra/outbound/payloads.js";
 import { getRemoteSkillEligibility } from "../infra/skills-remote.js";
 import { normalizeAgentId } from "../routing/session-key.js";
 import { defaultRuntime, type RuntimeEnv } from "../runtime.js";
@@ -58,9 +70,25 @@ import { resolveSendPolicy } from "../sessions/send-policy.js";
 import { resolveMessageChannel } from "../utils/message-channel.js";
 import { deliverAgentCommandResult } from "./agent/delivery.js";
 import { resolveAgentRunContext } from "./agent/run-context.js";
+import { acquireExclusiveSessionLock } from "./agent/session-lock.js";
 import { updateSessionStoreAfterAgentRun } from "./agent/session-store.js";
 import { resolveSession } from "./agent/session.js";
 
+function parsePositiveFlag(
+  value: string | undefined,
+  flag: string,
+  opts?: { integer?: boolean },
+): number | undefined {
+  if (value === undefined) {
+    return undefined;
+  }
+  const parsed = Number(String(value).trim());
+  if (!Number.isFinite(parsed) || parsed <= 0 || (opts?.integer && !Number.isInteger(parsed))) {
+    throw new Error(`${flag} must be a positive ${opts?.integer ? "integer" : "number"}`);
+  }
+  return parsed;
+}
+
 export async function agentCommand(
   opts: AgentCommandOpts,
   runtime: RuntimeEnv = defaultRuntime,
@@ -135,6 +163,23 @@ export async function agentCommand(
     cfg,
     overrideSeconds: timeoutSecondsRaw,
   });
+  const runLimits = {
+    maxTurns: parsePositiveFlag(opts.maxTurns, "--max-turns", { integer: true }),
+    maxDurationSeconds: parsePositiveFlag(opts.maxDuration, "--max-duration", { integer: true }),
+    maxCostUsd: parsePositiveFlag(opts.maxCostUsd, "--max-cost-usd"),
+  };
+  const persona = opts.persona ? await requirePersona(opts.persona) : undefined;
+  const streamParams =
+    persona?.temperature !== undefined || (opts.stop && opts.stop.length > 0)
+      ? {
+          temperature: persona?.temperature,
+          ...opts.streamParams,
+          ...(opts.stop && opts.stop.length > 0 ? { stopSequences: opts.stop } : {}),
+        }
+      : opts.streamParams;
+  const responseSchema = opts.responseSchema
+    ? await loadResponseSchema(opts.responseSchema)
+    : undefined;
 
   const sessionResolution = resolveSession({
     cfg,
@@ -157,6 +202,22 @@ export async function agentCommand(
   let sessionEntry = resolvedSessionEntry;
   const runId = opts.runId?.trim() || sessionId;
 
+  const budget = await checkSpendBudget({
+    cfg,
+    agentId: sessionAgentId,
+    sessionId,
+    sessionCostUsd: sessionEntry?.usageTotals?.costUsd,
+  });
+  const budgetNotice = formatSpendBudgetNotice(budget);
+  if (budget?.level === "exceeded" && sessionEntry?.budgetOverride !== budget.worst?.periodKey) {
+    // Nobody is around to confirm here; chat sessions can opt in with `/budget continue`.
+    throw new Error(`${budgetNotice}. Refusing to start a new run.`);
+  }
+  if (budgetNotice) {
+    runtime.error(budgetNotice);
+  }
+  let sessionLock: Awaited<ReturnType<typeof acquireExclusiveSessionLock>> | undefined;
+
   try {
     if (opts.deliver === true) {
       const sendPolicy = resolveSendPolicy({
@@ -231,7 +292,7 @@ export async function agentCommand(
       });
     }
 
-    const agentModelPrimary = resolveAgentModelPrimary(cfg, sessionAgentId);
+    const agentModelPrimary = persona?.model ?? resolveAgentModelPrimary(cfg, sessionAgentId);
     const cfgForModelSelection = agentModelPrimary
       ? {
           ...cfg,
@@ -368,6 +429,13 @@ export async function agentCommand(
     const sessionFile = resolveSessionFilePath(sessionId, sessionEntry, {
       agentId: sessionAgentId,
     });
+    if (opts.exclusiveSession) {
+      sessionLock = await acquireExclusiveSessionLock({
+        sessionFile,
+        sessionLabel: sessionKey ?? sessionId,
+        force: opts.force,
+      });
+    }
 
     const startedAt = Date.now();
     let lifecycleEnded = false;
@@ -375,6 +443,8 @@ export async function agentCommand(
     let result: Awaited<ReturnType<typeof runEmbeddedPiAgent>>;
     let fallbackProvider = provider;
     let fallbackModel = model;
+    let structuredResult: StructuredOutputResult | undefined;
+    let structuredAttempts = 0;
     try {
       const runContext = resolveAgentRunContext(opts);
       const messageChannel = resolveMessageChannel(
@@ -382,89 +452,129 @@ export async function agentCommand(
         opts.replyChannel ?? opts.channel,
       );
       const spawnedBy = opts.spawnedBy ?? sessionEntry?.spawnedBy;
-      const fallbackResult = await runWithModelFallback({
-        cfg,
-        provider,
-        model,
-        agentDir,
-        fallbacksOverride: resolveAgentModelFallbacksOverride(cfg, sessionAgentId),
-        run: (providerOverride, modelOverride) => {
-          if (isCliProvider(providerOverride, cfg)) {
-            const cliSessionId = getCliSessionId(sessionEntry, providerOverride);
-            return runCliAgent({
+      const extraSystemPrompt =
+        [
+          persona
+            ? expandPromptTemplateVars(buildPersonaSystemPrompt(persona), {
+                cwd: workspaceDir,
+                model: `${provider}/${model}`,
+                timezone: cfg.agents?.defaults?.userTimezone,
+              })
+            : undefined,
+          opts.extraSystemPrompt,
+          responseSchema ? buildStructuredOutputSystemPrompt(responseSchema) : undefined,
+        ]
+          .filter(Boolean)
+          .join("\n\n") || undefined;
+      const runTurn = (prompt: string, structuredOutput?: StructuredOutputRequest) =>
+        runWithModelFallback({
+          cfg,
+          provider,
+          model,
+          agentDir,
+          fallbacksOverride: resolveAgentModelFallbacksOverride(cfg, sessionAgentId),
+          run: (providerOverride, modelOverride) => {
+            if (isCliProvider(providerOverride, cfg)) {
+              const cliSessionId = getCliSessionId(sessionEntry, providerOverride);
+              return runCliAgent({
+                sessionId,
+                sessionKey,
+                agentId: sessionAgentId,
+                sessionFile,
+                workspaceDir,
+                config: cfg,
+                prompt,
+                provider: providerOverride,
+                model: modelOverride,
+                thinkLevel: resolvedThinkLevel,
+                timeoutMs,
+                runId,
+                extraSystemPrompt,
+                cliSessionId,
+                images: opts.images,
+                streamParams,
+              });
+            }
+            const authProfileId =
+              providerOverride === provider ? sessionEntry?.authProfileOverride : undefined;
+            return runEmbeddedPiAgent({
               sessionId,
               sessionKey,
               agentId: sessionAgentId,
+              messageChannel,
+              agentAccountId: runContext.accountId,
+              messageTo: opts.replyTo ?? opts.to,
+              messageThreadId: opts.threadId,
+              groupId: runContext.groupId,
+              groupChannel: runContext.groupChannel,
+              groupSpace: runContext.groupSpace,
+              spawnedBy,
+              currentChannelId: runContext.currentChannelId,
+              currentThreadTs: runContext.currentThreadTs,
+              replyToMode: runContext.replyToMode,
+              hasRepliedRef: runContext.hasRepliedRef,
+              senderIsOwner: true,
               sessionFile,
               workspaceDir,
               config: cfg,
-              prompt: body,
+              skillsSnapshot,
+              prompt,
+              images: opts.images,
+              clientTools: opts.clientTools,
               provider: providerOverride,
               model: modelOverride,
+              authProfileId,
+              authProfileIdSource: authProfileId
+                ? sessionEntry?.authProfileOverrideSource
+                : undefined,
               thinkLevel: resolvedThinkLevel,
+              verboseLevel: resolvedVerboseLevel,
+              execOverrides: opts.execOverrides,
               timeoutMs,
+              runLimits,
+              planMode: opts.plan === true || sessionEntry?.planMode === true,
+              disabledTools: sessionEntry?.disabledTools,
+              disableMemory: opts.memory === false,
+              structuredOutput,
+              prefill: opts.prefill ? { text: opts.prefill, echo: true } : undefined,
               runId,
-              extraSystemPrompt: opts.extraSystemPrompt,
-              cliSessionId,
-              images: opts.images,
-              streamParams: opts.streamParams,
+              lane: opts.lane,
+              abortSignal: opts.abortSignal,
+              extraSystemPrompt,
+              inputProvenance: opts.inputProvenance,
+              streamParams,
+              agentDir,
+              onAgentEvent: (evt) => {
+                // Track lifecycle end for fallback emission below.
+                if (
+                  evt.stream === "lifecycle" &&
+                  typeof evt.data?.phase === "string" &&
+                  (evt.data.phase === "end" || evt.data.phase === "error")
+                ) {
+                  lifecycleEnded = true;
+                }
+              },
             });
-          }
-          const authProfileId =
-            providerOverride === provider ? sessionEntry?.authProfileOverride : undefined;
-          return runEmbeddedPiAgent({
-            sessionId,
-            sessionKey,
-            agentId: sessionAgentId,
-            messageChannel,
-            agentAccountId: runContext.accountId,
-            messageTo: opts.replyTo ?? opts.to,
-            messageThreadId: opts.threadId,
-            groupId: runContext.groupId,
-            groupChannel: runContext.groupChannel,
-            groupSpace: runContext.groupSpace,
-            spawnedBy,
-            currentChannelId: runContext.currentChannelId,
-            currentThreadTs: runContext.currentThreadTs,
-            replyToMode: runContext.replyToMode,
-            hasRepliedRef: runContext.hasRepliedRef,
-            senderIsOwner: true,
-            sessionFile,
-            workspaceDir,
-            config: cfg,
-            skillsSnapshot,
-            prompt: body,
-            images: opts.images,
-            clientTools: opts.clientTools,
-            provider: providerOverride,
-            model: modelOverride,
-            authProfileId,
-            authProfileIdSource: authProfileId
-              ? sessionEntry?.authProfileOverrideSource
-              : undefined,
-            thinkLevel: resolvedThinkLevel,
-            verboseLevel: resolvedVerboseLevel,
-            timeoutMs,
-            runId,
-            lane: opts.lane,
-            abortSignal: opts.abortSignal,
-            extraSystemPrompt: opts.extraSystemPrompt,
-            inputProvenance: opts.inputProvenance,
-            streamParams: opts.streamParams,
-            agentDir,
-            onAgentEvent: (evt) => {
-              // Track lifecycle end for fallback emission below.
-              if (
-                evt.stream === "lifecycle" &&
-                typeof evt.data?.phase === "string" &&
-                (evt.data.phase === "end" || evt.data.phase === "error")
-              ) {
-                lifecycleEnded = true;
-              }
-            },
-          });
-        },
-      });
+          },
+        });
+      let fallbackResult: Awaited<ReturnType<typeof runTurn>>;
+      if (responseSchema) {
+        const structured = await runWithStructuredOutput({
+          schema: responseSchema,
+          prompt: body,
+          run: runTurn,
+          replyText: (outcome) =>
+            (outcome.result.payloads ?? [])
+              .map((payload) => payload.text ?? "")
+              .filter(Boolean)
+              .join("\n"),
+        });
+        fallbackResult = structured.outcome;
+        structuredResult = structured.output;
+        structuredAttempts = structured.attempts;
+      } else {
+        fallbackResult = await runTurn(body);
+      }
       result = fallbackResult.result;
       fallbackProvider = fallbackResult.provider;
       fallbackModel = fallbackResult.model;
@@ -513,6 +623,23 @@ export async function agentCommand(
       });
     }
 
+    if (structuredResult) {
+      if (!structuredResult.ok) {
+        throw new Error(
+          `Reply did not match --response-schema after ${structuredAttempts} attempt(s): ${structuredResult.error}`,
+        );
+      }
+      // Only the JSON goes to stdout so the output can be piped straight into other tools.
+      const json = JSON.stringify(structuredResult.value, null, 2);
+      runtime.log(json);
+      return { payloads: normalizeOutboundPayloadsForJson([{ text: json }]), meta: result.meta };
+    }
+
+    const verboseEnabled = resolvedVerboseLevel !== undefined && resolvedVerboseLevel !== "off";
+    if (result.meta.timings && verboseEnabled && !opts.json) {
+      runtime.error(formatRunTimings(result.meta.timings, result.meta.durationMs));
+    }
+
     const payloads = result.payloads ?? [];
     return await deliverAgentCommandResult({
       cfg,
@@ -524,6 +651,7 @@ export async function agentCommand(
       payloads,
     });
   } finally {
+    await sessionLock?.release();
     clearAgentRunContext(runId);
   }
 }
@@ -0,0 +1,32 @@
+import { acquireSessionWriteLock, SessionLockedError } from "../../agents/session-write-lock.js";
+
+/** Long enough for a run that is just finishing to let go; short enough to feel immediate. */
+const SESSION_IN_USE_WAIT_MS = 1_000;
+
+/**
+ * Holds the transcript write lock for a whole CLI run. The embedded runner takes the same lock per
+ * attempt (re-entrant within a process), so holding it here keeps a second process out between
+ * attempts and turns the runner's timeout into an immediate, readable error.
+ */
+export async function acquireExclusiveSessionLock(params: {
+  sessionFile: string;
+  sessionLabel: string;
+  force?: boolean;
+}): Promise<{ release: () => Promise<void> }> {
+  try {
+    return await acquireSessionWriteLock({
+      sessionFile: params.sessionFile,
+      timeoutMs: SESSION_IN_USE_WAIT_MS,
+      force: params.force,
+    });
+  } catch (err) {
+    if (!(err instanceof SessionLockedError)) {
+      throw err;
+    }
+    const owner = err.ownerPid ? ` (pid ${err.ownerPid})` : "";
+    throw new Error(
+      `Session ${params.sessionLabel} is in use by another openclaw process${owner}. Wait for it to finish, or re-run with --force to take it over.`,
+      { cause: err },
+    );
+  }
+}
@@ -5,6 +5,7 @@ import { DEFAULT_CONTEXT_TOKENS } from "../../agents/defaults.js";
 import { isCliProvider } from "../../agents/model-selection.js";
 import { deriveSessionTotalTokens, hasNonzeroUsage } from "../../agents/usage.js";
 import { type SessionEntry, updateSessionStore } from "../../config/sessions.js";
+import { buildSessionUsagePatch } from "../../sessions/usage-totals.js";
 
 type RunResult = Awaited<
   ReturnType<(typeof import("../../agents/pi-embedded.js"))["runEmbeddedPiAgent"]>
@@ -48,9 +49,12 @@ export async function updateSessionStoreAfterAgentRun(params: {
     sessionId,
     updatedAt: Date.now(),
   };
+  // Cumulative usage belongs to one sessionId; a fresh id starts its own totals.
+  const startsNewSession = sessionStore[sessionKey]?.sessionId !== sessionId;
   const next: SessionEntry = {
     ...entry,
     sessionId,
+    createdAt: startsNewSession ? Date.now() : entry.createdAt,
     updatedAt: Date.now(),
     modelProvider: providerUsed,
     model: modelUsed,
@@ -77,6 +81,15 @@ export async function updateSessionStoreAfterAgentRun(params: {
     next.totalTokens = totalTokens;
     next.totalTokensFresh = true;
   }
+  Object.assign(
+    next,
+    buildSessionUsagePatch(startsNewSession ? {} : entry, {
+      usage: hasNonzeroUsage(usage) ? usage : undefined,
+      provider: providerUsed,
+      model: modelUsed,
+      config: cfg,
+    }),
+  );
   if (compactionsThisRun > 0) {
     next.compactionCount = (entry.compactionCount ?? 0) + compactionsThisRun;
   }
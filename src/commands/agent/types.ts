@@ -1,3 +1,4 @@
+import type { ExecToolDefaults } from "../../agents/bash-tools.js";
 import type { ClientToolDefinition } from "../../agents/pi-embedded-runner/run/params.js";
 import type { ChannelOutboundTargetMode } from "../../channels/plugins/types.js";
 import type { InputProvenance } from "../../sessions/input-provenance.js";
@@ -13,6 +14,8 @@ export type AgentStreamParams = {
   /** Provider stream params override (best-effort). */
   temperature?: number;
   maxTokens?: number;
+  /** Stop generating when the model emits one of these strings. */
+  stopSequences?: string[];
 };
 
 export type AgentRunContext = {
@@ -43,6 +46,24 @@ export type AgentCommandOpts = {
   verbose?: string;
   json?: boolean;
   timeout?: string;
+  /** Per-run limits (embedded runs); override `agents.defaults.max*`. */
+  maxTurns?: string;
+  maxDuration?: string;
+  maxCostUsd?: string;
+  /** Plan mode for this run: read-only tools, reply with a plan. */
+  plan?: boolean;
+  /** `--no-memory` sets this to false: no MEMORY.md/project memory and no memory tools. */
+  memory?: boolean;
+  /** Path to a JSON schema; the reply is validated against it and printed as bare JSON. */
+  responseSchema?: string;
+  /** Text the reply must start with (assistant prefill). */
+  prefill?: string;
+  /** System prompt preset from `~/.openclaw/prompts/<name>.md`. */
+  persona?: string;
+  /** Stop sequences for this run (`--stop`, repeatable). */
+  stop?: string[];
+  /** Exec tool policy for this run (e.g. `openclaw run` never prompts for approvals). */
+  execOverrides?: Pick<ExecToolDefaults, "host" | "security" | "ask" | "node">;
   deliver?: boolean;
   /** Override delivery target (separate from session routing). */
   replyTo?: string;
@@ -76,4 +97,8 @@ export type AgentCommandOpts = {
   inputProvenance?: InputProvenance;
   /** Per-call stream param overrides (best-effort). */
   streamParams?: AgentStreamParams;
+  /** Fail with "session in use" when another process is running this session (local CLI runs). */
+  exclusiveSession?: boolean;
+  /** With `exclusiveSession`: take the session over even if another process holds it. */
+  force?: boolean;
 };
@@ -0,0 +1,247 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { RuntimeEnv } from "../runtime.js";
+import {
+  ANTHROPIC_BATCH_DISCOUNT,
+  type AnthropicBatch,
+  type AnthropicBatchClient,
+  type BatchResultLine,
+  buildBatchRequests,
+  createAnthropicBatchClient,
+  formatBatchCounts,
+  parseBatchInput,
+  waitForAnthropicBatch,
+} from "../agents/anthropic-batch.js";
+import { resolveCouncilMembers } from "../agents/council.js";
+import { requireApiKey, resolveApiKeyForProvider } from "../agents/model-auth.js";
+import { resolveDefaultModelForAgent } from "../agents/model-selection.js";
+import { formatCliCommand } from "../cli/command-format.js";
+import { loadConfig, type OpenClawConfig } from "../config/config.js";
+import { theme } from "../terminal/theme.js";
+import { estimateUsageCost, formatUsd, resolveModelCostConfig } from "../utils/usage-format.js";
+
+const DEFAULT_MAX_TOKENS = 4096;
+const DEFAULT_POLL_SECONDS = 30;
+
+export type BatchSubmitOpts = {
+  model?: string;
+  agent?: string;
+  maxTokens?: string;
+  system?: string;
+  out?: string;
+  pollInterval?: string;
+  wait?: boolean;
+  json?: boolean;
+};
+
+export type BatchResultsOpts = {
+  out?: string;
+  model?: string;
+  json?: boolean;
+};
+
+function parsePositiveInt(raw: string | undefined, flag: string): number | undefined {
+  if (raw === undefined) {
+    return undefined;
+  }
+  const value = Number.parseInt(String(raw), 10);
+  if (Number.isNaN(value) || value <= 0) {
+    throw new Error(`${flag} must be a positive integer`);
+  }
+  return value;
+}
+
+async function resolveBatchModel(params: {
+  cfg: OpenClawConfig;
+  raw?: string;
+  agentId?: string;
+}): Promise<string> {
+  let ref = resolveDefaultModelForAgent({ cfg: params.cfg, agentId: params.agentId });
+  if (params.raw) {
+    const { members, errors } = await resolveCouncilMembers({
+      cfg: params.cfg,
+      models: [params.raw],
+      agentId: params.agentId,
+    });
+    const member = members[0];
+    if (!member) {
+      throw new Error(`--model: ${errors.join("; ") || params.raw}`);
+    }
+    ref = member.ref;
+  }
+  if (ref.provider !== "anthropic") {
+    throw new Error(
+      `Message Batches are an Anthropic API; ${ref.provider}/${ref.model} can't be batched (e.g. --model sonnet).`,
+    );
+  }
+  return ref.model;
+}
+
+async function createBatchClient(cfg: OpenClawConfig): Promise<AnthropicBatchClient> {
+  const auth = await resolveApiKeyForProvider({ provider: "anthropic", cfg });
+  if (auth.mode === "oauth" || auth.mode === "token") {
+    throw new Error(
+      `The Message Batches API needs an Anthropic API key, not a subscription token. Set ANTHROPIC_API_KEY or add a key with \`${formatCliCommand("openclaw configure")}\`.`,
+    );
+  }
+  return createAnthropicBatchClient({
+    apiKey: requireApiKey(auth, "anthropic"),
+    baseUrl: cfg.models?.providers?.anthropic?.baseUrl,
+  });
+}
+
+/** Batch cost at the discounted rate, or undefined when the model has no cost config. */
+export function estimateBatchCostUsd(params: {
+  cfg?: OpenClawConfig;
+  model?: string;
+  results: BatchResultLine[];
+}): number | undefined {
+  if (!params.model) {
+    return undefined;
+  }
+  const cost = resolveModelCostConfig({
+    provider: "anthropic",
+    model: params.model,
+    config: params.cfg,
+  });
+  let total: number | undefined;
+  for (const result of params.results) {
+    const value = estimateUsageCost({ usage: result.usage, cost });
+    if (value !== undefined) {
+      total = (total ?? 0) + value * ANTHROPIC_BATCH_DISCOUNT;
+    }
+  }
+  return total;
+}
+
+async function writeBatchResults(file: string, results: BatchResultLine[]) {
+  await fs.mkdir(path.dirname(file), { recursive: true });
+  await fs.writeFile(file, results.map((result) => JSON.stringify(result)).join("\n") + "\n");
+}
+
+async function fetchAndWriteResults(params: {
+  cfg: OpenClawConfig;
+  client: AnthropicBatchClient;
+  batch: AnthropicBatch;
+  out: string;
+  model?: string;
+  runtime: RuntimeEnv;
+  json?: boolean;
+}) {
+  const results = await params.client.results(params.batch);
+  await writeBatchResults(params.out, results);
+  const costUsd = estimateBatchCostUsd({ cfg: params.cfg, model: params.model, results });
+  const failed = results.filter((result) => result.status !== "succeeded").length;
+  if (params.json) {
+    params.runtime.log(
+      JSON.stringify({ batch: params.batch, out: params.out, failed, costUsd }, null, 2),
+    );
+  } else {
+    params.runtime.log(formatBatchCounts(params.batch));
+    const cost = costUsd !== undefined ? ` · ~${formatUsd(costUsd)} (batch rate)` : "";
+    params.runtime.log(theme.muted(`Results written to ${params.out}${cost}`));
+  }
+  return { batch: params.batch, results, costUsd };
+}
+
+/**
+ * `openclaw batch submit`: sends every prompt of a JSONL file as one Anthropic Message Batch,
+ * then (unless `--no-wait`) polls until it ends and writes the replies to `--out`.
+ */
+export async function batchSubmitCommand(
+  inputPath: string,
+  opts: BatchSubmitOpts,
+  runtime: RuntimeEnv,
+) {
+  const items = parseBatchInput(await fs.readFile(path.resolve(inputPath), "utf8"));
+  const cfg = loadConfig();
+  const model = await resolveBatchModel({ cfg, raw: opts.model?.trim(), agentId: opts.agent });
+  const maxTokens = parsePositiveInt(opts.maxTokens, "--max-tokens") ?? DEFAULT_MAX_TOKENS;
+  const intervalMs =
+    (parsePositiveInt(opts.pollInterval, "--poll-interval") ?? DEFAULT_POLL_SECONDS) * 1000;
+  const client = await createBatchClient(cfg);
+
+  const batch = await client.create(
+    buildBatchRequests(items, { model, maxTokens, system: opts.system?.trim() || undefined }),
+  );
+  if (opts.wait === false) {
+    if (opts.json) {
+      runtime.log(JSON.stringify({ batch, model }, null, 2));
+    } else {
+      runtime.log(`Submitted ${items.length} prompts to ${model}: ${batch.id}`);
+      runtime.log(
+        theme.muted(
+          `Fetch the replies later with ${formatCliCommand(`openclaw batch results ${batch.id} --model ${model}`)}`,
+        ),
+      );
+    }
+    return { batch };
+  }
+
+  if (!opts.json) {
+    runtime.log(theme.muted(`Submitted ${items.length} prompts to ${model}: ${batch.id}`));
+  }
+  const ended = await waitForAnthropicBatch({
+    client,
+    batchId: batch.id,
+    intervalMs,
+    onProgress: opts.json ? undefined : (next) => runtime.log(theme.muted(formatBatchCounts(next))),
+  });
+  const out = path.resolve(
+    opts.out ?? `${path.basename(inputPath, path.extname(inputPath))}.results.jsonl`,
+  );
+  return await fetchAndWriteResults({
+    cfg,
+    client,
+    batch: ended,
+    out,
+    model,
+    runtime,
+    json: opts.json,
+  });
+}
+
+/** `openclaw batch status <id>`: processing state and request counts. */
+export async function batchStatusCommand(
+  batchId: string,
+  opts: { json?: boolean },
+  runtime: RuntimeEnv,
+) {
+  const client = await createBatchClient(loadConfig());
+  const batch = await client.get(batchId);
+  runtime.log(opts.json ? JSON.stringify(batch, null, 2) : formatBatchCounts(batch));
+  return batch;
+}
+
+/** `openclaw batch results <id>`: downloads the replies of an ended batch to JSONL. */
+export async function batchResultsCommand(
+  batchId: string,
+  opts: BatchResultsOpts,
+  runtime: RuntimeEnv,
+) {
+  const cfg = loadConfig();
+  const client = await createBatchClient(cfg);
+  const batch = await client.get(batchId);
+  const out = path.resolve(opts.out ?? `${batchId}.results.jsonl`);
+  return await fetchAndWriteResults({
+    cfg,
+    client,
+    batch,
+    out,
+    model: opts.model?.trim() || undefined,
+    runtime,
+    json: opts.json,
+  });
+}
+
+/** `openclaw batch cancel <id>`: stops processing; finished replies stay downloadable. */
+export async function batchCancelCommand(
+  batchId: string,
+  opts: { json?: boolean },
+  runtime: RuntimeEnv,
+) {
+  const client = await createBatchClient(loadConfig());
+  const batch = await client.cancel(batchId);
+  runtime.log(opts.json ? JSON.stringify(batch, null, 2) : formatBatchCounts(batch));
+  return batch;
+}
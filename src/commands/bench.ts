@@ -0,0 +1,156 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { RuntimeEnv } from "../runtime.js";
+import { type BenchModelSummary, type BenchRun, runBench, summarizeBench } from "../agents/bench.js";
+import {
+  parseCouncilModelList,
+  resolveCouncilMembers,
+  resolveCouncilTimeoutMs,
+} from "../agents/council.js";
+import { loadConfig } from "../config/config.js";
+import { renderTable } from "../terminal/table.js";
+import { theme } from "../terminal/theme.js";
+import { formatTokenCount, formatUsd } from "../utils/usage-format.js";
+
+const MAX_RUNS = 20;
+
+export type BenchOpts = {
+  models: string;
+  prompt?: string;
+  promptFile?: string;
+  runs?: string;
+  out?: string;
+  agent?: string;
+  timeout?: string;
+  json?: boolean;
+};
+
+function formatMs(value?: number): string {
+  return typeof value === "number" ? `${(value / 1000).toFixed(2)}s` : "-";
+}
+
+export function formatBenchSummary(summary: BenchModelSummary[], width: number): string {
+  return renderTable({
+    width,
+    columns: [
+      { key: "Model", header: "Model", flex: true, minWidth: 16 },
+      { key: "Ok", header: "Ok", align: "right" },
+      { key: "Latency", header: "Latency", align: "right" },
+      { key: "TTFT", header: "TTFT", align: "right" },
+      { key: "Output", header: "Out tok", align: "right" },
+      { key: "Rate", header: "Tok/s", align: "right" },
+      { key: "Cost", header: "Cost", align: "right" },
+    ],
+    rows: summary.map((row) => ({
+      Model: row.key,
+      Ok: `${row.ok}/${row.runs}`,
+      Latency: formatMs(row.avgLatencyMs),
+      TTFT: formatMs(row.avgTtftMs),
+      Output:
+        row.avgOutputTokens !== undefined ? formatTokenCount(Math.round(row.avgOutputTokens)) : "-",
+      Rate: row.tokensPerSecond !== undefined ? row.tokensPerSecond.toFixed(1) : "-",
+      Cost: formatUsd(row.totalCostUsd) ?? "-",
+    })),
+  }).trimEnd();
+}
+
+/** File name for one run's output, e.g. `anthropic_claude-opus-4-6.run2.md`. */
+export function benchOutputFileName(run: Pick<BenchRun, "key" | "run">): string {
+  return `${run.key.replace(/[^a-zA-Z0-9._-]+/g, "_")}.run${run.run}.md`;
+}
+
+async function writeBenchOutputs(params: {
+  dir: string;
+  prompt: string;
+  runs: BenchRun[];
+  summary: BenchModelSummary[];
+}) {
+  await fs.mkdir(params.dir, { recursive: true });
+  await fs.writeFile(path.join(params.dir, "prompt.md"), `${params.prompt}\n`, "utf8");
+  for (const run of params.runs) {
+    const body = run.error ? `<!-- failed: ${run.error} -->\n` : `${run.text ?? ""}\n`;
+    await fs.writeFile(path.join(params.dir, benchOutputFileName(run)), body, "utf8");
+  }
+  await fs.writeFile(
+    path.join(params.dir, "results.json"),
+    `${JSON.stringify({ summary: params.summary, runs: params.runs }, null, 2)}\n`,
+    "utf8",
+  );
+}
+
+function parsePositiveInt(raw: string | undefined, flag: string): number | undefined {
+  if (raw === undefined) {
+    return undefined;
+  }
+  const value = Number.parseInt(String(raw), 10);
+  if (Number.isNaN(value) || value <= 0) {
+    throw new Error(`${flag} must be a positive integer`);
+  }
+  return value;
+}
+
+/** `openclaw bench`: the same prompt against several models, timed, with outputs saved side by side. */
+export async function benchCommand(opts: BenchOpts, runtime: RuntimeEnv) {
+  if (opts.prompt && opts.promptFile) {
+    throw new Error("Use either --prompt or --prompt-file, not both.");
+  }
+  const prompt = (
+    opts.promptFile ? await fs.readFile(path.resolve(opts.promptFile), "utf8") : (opts.prompt ?? "")
+  ).trim();
+  if (!prompt) {
+    throw new Error("A prompt is required (--prompt or --prompt-file).");
+  }
+  const runs = parsePositiveInt(opts.runs, "--runs") ?? 1;
+  if (runs > MAX_RUNS) {
+    throw new Error(`--runs is capped at ${MAX_RUNS}`);
+  }
+  const timeoutSeconds = parsePositiveInt(opts.timeout, "--timeout");
+
+  const cfg = loadConfig();
+  const { members, errors } = await resolveCouncilMembers({
+    cfg,
+    models: parseCouncilModelList(opts.models),
+    agentId: opts.agent,
+  });
+  for (const error of errors) {
+    runtime.error(`--models: ${error}`);
+  }
+  if (members.length === 0) {
+    throw new Error("No usable models (e.g. --models sonnet,gpt).");
+  }
+
+  const results = await runBench({
+    cfg,
+    members,
+    prompt,
+    runs,
+    timeoutMs: resolveCouncilTimeoutMs(cfg, timeoutSeconds),
+    onRun: opts.json
+      ? undefined
+      : (run) => {
+          const status = run.error
+            ? theme.error(`failed: ${run.error}`)
+            : run.cached
+              ? "cached"
+              : formatMs(run.latencyMs);
+          runtime.log(theme.muted(`${run.key} #${run.run}: ${status}`));
+        },
+  });
+  const summary = summarizeBench(results);
+  const outDir = path.resolve(
+    opts.out ?? `bench-${new Date().toISOString().replace(/[:.]/g, "-")}`,
+  );
+  await writeBenchOutputs({ dir: outDir, prompt, runs: results, summary });
+
+  if (opts.json) {
+    runtime.log(JSON.stringify({ outDir, summary, runs: results }, null, 2));
+  } else {
+    runtime.log("");
+    runtime.log(formatBenchSummary(summary, Math.max(60, (process.stdout.columns ?? 120) - 1)));
+    runtime.log(theme.muted(`Outputs saved to ${outDir}`));
+  }
+  if (results.every((run) => run.error)) {
+    throw new Error("Every bench run failed.");
+  }
+  return { outDir, summary, runs: results };
+}
@@ -0,0 +1,88 @@
+import path from "node:path";
+import type { RuntimeEnv } from "../runtime.js";
+import { resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { findGitRoot } from "../agents/system-prompt-params.js";
+import { withProgressTotals } from "../cli/progress.js";
+import { CodebaseIndex, hasCodebaseIndex } from "../codebase/codebase-index.js";
+import { loadConfig } from "../config/config.js";
+import { formatRelativeTimestamp } from "../infra/format-time/format-relative.ts";
+import { theme } from "../terminal/theme.js";
+import { shortenHomePath } from "../utils.js";
+
+export type CodebaseIndexOpts = {
+  agent?: string;
+  force?: boolean;
+  status?: boolean;
+  maxFileKb?: string;
+  json?: boolean;
+};
+
+/** `openclaw index [dir]`: build or refresh the embedding index behind `codebase_search`. */
+export async function codebaseIndexCommand(
+  dir: string | undefined,
+  opts: CodebaseIndexOpts,
+  runtime: RuntimeEnv,
+) {
+  const target = path.resolve(dir ?? process.cwd());
+  // Index whole repositories so the tool finds the same index from any subdirectory.
+  const root = findGitRoot(target) ?? target;
+  let maxFileBytes: number | undefined;
+  if (opts.maxFileKb !== undefined) {
+    const kb = Number.parseInt(opts.maxFileKb, 10);
+    if (Number.isNaN(kb) || kb <= 0) {
+      throw new Error("--max-file-kb must be a positive integer");
+    }
+    maxFileBytes = kb * 1024;
+  }
+
+  if (opts.status && !hasCodebaseIndex(root)) {
+    if (opts.json) {
+      runtime.log(JSON.stringify({ root, indexed: false }, null, 2));
+    } else {
+      runtime.log(`No index for ${shortenHomePath(root)}; run \`openclaw index\` to build one.`);
+    }
+    return;
+  }
+
+  const cfg = loadConfig();
+  const agentId = opts.agent?.trim() || resolveDefaultAgentId(cfg);
+  const index = await CodebaseIndex.get({ cfg, agentId, root });
+  try {
+    if (opts.status) {
+      const status = index.status();
+      if (opts.json) {
+        runtime.log(JSON.stringify({ indexed: true, ...status }, null, 2));
+        return;
+      }
+      runtime.log(`${theme.heading("Codebase index")} ${shortenHomePath(root)}`);
+      runtime.log(`Files: ${status.files} · Chunks: ${status.chunks}`);
+      runtime.log(`Embeddings: ${status.provider}/${status.model}`);
+      if (status.indexedAt) {
+        runtime.log(`Updated: ${formatRelativeTimestamp(status.indexedAt)}`);
+      }
+      runtime.log(theme.muted(`Store: ${shortenHomePath(status.dbPath)}`));
+      return;
+    }
+
+    const result = await withProgressTotals(
+      { label: "Indexing codebase…", total: 0, enabled: !opts.json },
+      async (update) =>
+        await index.sync({
+          force: opts.force,
+          maxFileBytes,
+          onProgress: ({ completed, total, path: relPath }) =>
+            update({ completed, total, label: relPath ? `Embedding ${relPath}` : undefined }),
+        }),
+    );
+    if (opts.json) {
+      runtime.log(JSON.stringify({ root, ...result }, null, 2));
+      return;
+    }
+    runtime.log(
+      `Indexed ${shortenHomePath(root)}: ${result.files} files (${result.indexed} embedded, ${result.unchanged} unchanged, ${result.removed} removed), ${result.chunks} chunks.`,
+    );
+    runtime.log(theme.muted("The codebase_search tool is now available to agents working here."));
+  } finally {
+    index.close();
+  }
+}
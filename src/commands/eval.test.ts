@@ -0,0 +1,82 @@
+import { describe, expect, it } from "vitest";
+import { checkEvalAssertion, parseEvalJudgeVerdict, parseEvalSuite } from "./eval.js";
+
+const SUITE = `
+judge: sonnet
+cases:
+  - name: greeting
+    prompt: Say hello
+    assert:
+      - contains: hello
+      - not-contains: sorry
+      - regex: "^hello"
+  - prompt: Return the user as JSON
+    assert:
+      - schema:
+          type: object
+          required: [name]
+          properties:
+            name: { type: string }
+      - judge: The name is plausible
+`;
+
+describe("parseEvalSuite", () => {
+  it("parses cases and assertions", async () => {
+    const suite = await parseEvalSuite(SUITE, "/tmp");
+    expect(suite.judge).toBe("sonnet");
+    expect(suite.cases.map((entry) => entry.name)).toEqual(["greeting", "case-2"]);
+    expect(suite.cases[0]?.assert.map((assertion) => assertion.type)).toEqual([
+      "contains",
+      "not-contains",
+      "regex",
+    ]);
+    expect(suite.cases[1]?.assert.map((assertion) => assertion.type)).toEqual(["schema", "judge"]);
+  });
+
+  it("rejects unknown assertions and missing prompts", async () => {
+    await expect(
+      parseEvalSuite("cases:\n  - prompt: hi\n    assert:\n      - equals: hi\n", "/tmp"),
+    ).rejects.toThrow(/expected exactly one of/);
+    await expect(parseEvalSuite("cases:\n  - name: a\n", "/tmp")).rejects.toThrow(
+      /case "a".prompt must be a non-empty string/,
+    );
+    await expect(parseEvalSuite("cases: []\n", "/tmp")).rejects.toThrow(/at least one/);
+  });
+});
+
+describe("checkEvalAssertion", () => {
+  it("checks text, regex, and schema assertions", async () => {
+    const suite = await parseEvalSuite(SUITE, "/tmp");
+    const [contains, notContains, regex] = suite.cases[0]?.assert ?? [];
+    const [schema] = suite.cases[1]?.assert ?? [];
+    if (!contains || !notContains || !regex || !schema) {
+      throw new Error("expected parsed assertions");
+    }
+    expect(await checkEvalAssertion(contains, "hello there")).toMatchObject({ ok: true });
+    expect(await checkEvalAssertion(notContains, "sorry, hello")).toMatchObject({ ok: false });
+    expect(await checkEvalAssertion(regex, "well, hello")).toMatchObject({ ok: false });
+    expect(await checkEvalAssertion(schema, '```json\n{"name":"Ada"}\n```')).toMatchObject({
+      ok: true,
+    });
+    expect(await checkEvalAssertion(schema, '{"age":3}')).toMatchObject({ ok: false });
+  });
+
+  it("fails judge assertions without a judge", async () => {
+    const result = await checkEvalAssertion({ type: "judge", criteria: "is polite" }, "hi");
+    expect(result).toMatchObject({ ok: false, detail: "no judge model available" });
+  });
+});
+
+describe("parseEvalJudgeVerdict", () => {
+  it("reads PASS/FAIL from the first line", () => {
+    expect(parseEvalJudgeVerdict("PASS\nIt greets the user.")).toEqual({
+      ok: true,
+      reason: "It greets the user.",
+    });
+    expect(parseEvalJudgeVerdict("**FAIL**: no greeting")).toEqual({
+      ok: false,
+      reason: "no greeting",
+    });
+    expect(parseEvalJudgeVerdict("Maybe")).toMatchObject({ ok: false });
+  });
+});
@@ -0,0 +1,450 @@
+import crypto from "node:crypto";
+import fs from "node:fs/promises";
+import path from "node:path";
+import YAML from "yaml";
+import type { CliDeps } from "../cli/deps.js";
+import type { RuntimeEnv } from "../runtime.js";
+import { listAgentIds, resolveDefaultAgentId } from "../agents/agent-scope.js";
+import {
+  askModel,
+  type CouncilMember,
+  resolveCouncilMembers,
+  resolveCouncilTimeoutMs,
+} from "../agents/council.js";
+import { resolveDefaultModelForAgent } from "../agents/model-selection.js";
+import {
+  compileResponseSchema,
+  loadResponseSchema,
+  parseStructuredOutput,
+  type ResponseSchema,
+} from "../agents/structured-output.js";
+import { formatCliCommand } from "../cli/command-format.js";
+import { loadConfig } from "../config/config.js";
+import { normalizeAgentId } from "../routing/session-key.js";
+import { renderTable } from "../terminal/table.js";
+import { theme } from "../terminal/theme.js";
+import { estimateUsageCost, formatUsd, resolveModelCostConfig } from "../utils/usage-format.js";
+import { agentCommand } from "./agent.js";
+
+export type EvalAssertion =
+  | { type: "contains"; value: string }
+  | { type: "not-contains"; value: string }
+  | { type: "regex"; pattern: RegExp }
+  | { type: "schema"; schema: ResponseSchema }
+  | { type: "judge"; criteria: string };
+
+export type EvalCase = {
+  name: string;
+  prompt: string;
+  assert: EvalAssertion[];
+};
+
+export type EvalSuite = {
+  agent?: string;
+  judge?: string;
+  cases: EvalCase[];
+};
+
+export type EvalAssertionResult = {
+  type: EvalAssertion["type"];
+  ok: boolean;
+  detail?: string;
+};
+
+export type EvalCaseResult = {
+  name: string;
+  ok: boolean;
+  error?: string;
+  text?: string;
+  durationMs: number;
+  costUsd?: number;
+  assertions: EvalAssertionResult[];
+};
+
+export type EvalOpts = {
+  agent?: string;
+  judge?: string;
+  filter?: string;
+  timeout?: string;
+  out?: string;
+  json?: boolean;
+};
+
+const ASSERTION_TYPES = ["contains", "not-contains", "regex", "schema", "judge"] as const;
+
+function describeCase(index: number, name?: string): string {
+  return name ? `case "${name}"` : `case #${index + 1}`;
+}
+
+function readString(value: unknown, where: string): string {
+  if (typeof value !== "string" || !value.trim()) {
+    throw new Error(`${where} must be a non-empty string`);
+  }
+  return value;
+}
+
+async function parseAssertion(
+  raw: unknown,
+  where: string,
+  baseDir: string,
+): Promise<EvalAssertion> {
+  if (!raw || typeof raw !== "object" || Array.isArray(raw)) {
+    throw new Error(`${where}: each assertion must be a mapping like \`contains: text\``);
+  }
+  const keys = Object.keys(raw);
+  const type = keys[0] as (typeof ASSERTION_TYPES)[number];
+  if (keys.length !== 1 || !(ASSERTION_TYPES as readonly string[]).includes(type)) {
+    throw new Error(`${where}: expected exactly one of ${ASSERTION_TYPES.join(", ")}`);
+  }
+  const value = (raw as Record<string, unknown>)[type];
+  switch (type) {
+    case "contains":
+    case "not-contains":
+      return { type, value: readString(value, `${where}.${type}`) };
+    case "regex": {
+      const source = readString(value, `${where}.regex`);
+      try {
+        return { type, pattern: new RegExp(source, "m") };
+      } catch (err) {
+        throw new Error(`${where}.regex: ${String(err)}`, { cause: err });
+      }
+    }
+    case "schema": {
+      if (typeof value === "string") {
+        return { type, schema: await loadResponseSchema(path.resolve(baseDir, value)) };
+      }
+      if (!value || typeof value !== "object" || Array.isArray(value)) {
+        throw new Error(`${where}.schema must be a JSON schema object or a path to one`);
+      }
+      return { type, schema: compileResponseSchema(value as Record<string, unknown>) };
+    }
+    case "judge":
+      return { type, criteria: readString(value, `${where}.judge`) };
+  }
+}
+
+/**
+ * Parses a YAML eval suite. Schema assertions may point at a JSON file, resolved against
+ * `baseDir` (the suite's directory).
+ */
+export async function parseEvalSuite(raw: string, baseDir: string): Promise<EvalSuite> {
+  let doc: unknown;
+  try {
+    doc = YAML.parse(raw);
+  } catch (err) {
+    throw new Error(`Eval suite is not valid YAML: ${String(err)}`, { cause: err });
+  }
+  if (!doc || typeof doc !== "object" || Array.isArray(doc)) {
+    throw new Error("Eval suite must be a mapping with a `cases` list");
+  }
+  const record = doc as Record<string, unknown>;
+  if (!Array.isArray(record.cases) || record.cases.length === 0) {
+    throw new Error("Eval suite needs at least one entry under `cases`");
+  }
+  const names = new Set<string>();
+  const cases: EvalCase[] = [];
+  for (const [index, entry] of record.cases.entries()) {
+    if (!entry || typeof entry !== "object" || Array.isArray(entry)) {
+      throw new Error(`${describeCase(index)} must be a mapping`);
+    }
+    const item = entry as Record<string, unknown>;
+    const name = typeof item.name === "string" && item.name.trim() ? item.name.trim() : undefined;
+    const where = describeCase(index, name);
+    const caseName = name ?? `case-${index + 1}`;
+    if (names.has(caseName)) {
+      throw new Error(`${where}: duplicate case name`);
+    }
+    names.add(caseName);
+    const prompt = readString(item.prompt, `${where}.prompt`).trim();
+    const rawAsserts = item.assert === undefined ? [] : item.assert;
+    if (!Array.isArray(rawAsserts)) {
+      throw new Error(`${where}.assert must be a list`);
+    }
+    const assert: EvalAssertion[] = [];
+    for (const [assertIndex, rawAssert] of rawAsserts.entries()) {
+      assert.push(await parseAssertion(rawAssert, `${where}.assert[${assertIndex}]`, baseDir));
+    }
+    cases.push({ name: caseName, prompt, assert });
+  }
+  return {
+    agent: typeof record.agent === "string" ? record.agent.trim() || undefined : undefined,
+    judge: typeof record.judge === "string" ? record.judge.trim() || undefined : undefined,
+    cases,
+  };
+}
+
+function preview(text: string, max = 80): string {
+  const flat = text.replace(/\s+/g, " ").trim();
+  return flat.length > max ? `${flat.slice(0, max - 1)}…` : flat;
+}
+
+/** Checks a reply against one assertion; judge assertions go through `judge`. */
+export async function checkEvalAssertion(
+  assertion: EvalAssertion,
+  text: string,
+  judge?: (criteria: string, text: string) => Promise<EvalAssertionResult>,
+): Promise<EvalAssertionResult> {
+  switch (assertion.type) {
+    case "contains":
+      return text.includes(assertion.value)
+        ? { type: assertion.type, ok: true }
+        : { type: assertion.type, ok: false, detail: `missing "${preview(assertion.value)}"` };
+    case "not-contains":
+      return text.includes(assertion.value)
+        ? { type: assertion.type, ok: false, detail: `found "${preview(assertion.value)}"` }
+        : { type: assertion.type, ok: true };
+    case "regex":
+      return assertion.pattern.test(text)
+        ? { type: assertion.type, ok: true }
+        : { type: assertion.type, ok: false, detail: `no match for ${String(assertion.pattern)}` };
+    case "schema": {
+      const parsed = parseStructuredOutput(text, assertion.schema);
+      return parsed.ok
+        ? { type: assertion.type, ok: true }
+        : { type: assertion.type, ok: false, detail: parsed.error };
+    }
+    case "judge":
+      if (!judge) {
+        return { type: assertion.type, ok: false, detail: "no judge model available" };
+      }
+      return judge(assertion.criteria, text);
+  }
+}
+
+export function buildEvalJudgePrompt(params: {
+  prompt: string;
+  criteria: string;
+  text: string;
+}): string {
+  return [
+    "You are grading an AI assistant's reply for an automated test.",
+    "Decide whether the reply meets the criteria. Ignore style unless the criteria mention it.",
+    "",
+    "## Prompt",
+    params.prompt,
+    "",
+    "## Reply",
+    params.text || "(empty)",
+    "",
+    "## Criteria",
+    params.criteria,
+    "",
+    "Answer with PASS or FAIL on the first line, then one sentence explaining why.",
+  ].join("\n");
+}
+
+/** Reads the judge's verdict from the first line; anything other than PASS fails. */
+export function parseEvalJudgeVerdict(reply: string): { ok: boolean; reason?: string } {
+  const [first = "", ...rest] = reply.trim().split("\n");
+  const verdict = first
+    .replace(/[*_`#:.]/g, " ")
+    .trim()
+    .split(/\s+/)[0]
+    ?.toUpperCase();
+  const reason = [first.replace(/^\W*(pass|fail)\W*/i, ""), ...rest].join(" ").trim();
+  return { ok: verdict === "PASS", reason: reason || undefined };
+}
+
+export function formatEvalReport(results: EvalCaseResult[], width: number): string {
+  return renderTable({
+    width,
+    columns: [
+      { key: "Case", header: "Case", flex: true, minWidth: 16 },
+      { key: "Result", header: "Result" },
+      { key: "Time", header: "Time", align: "right" },
+      { key: "Cost", header: "Cost", align: "right" },
+      { key: "Detail", header: "Detail", flex: true, minWidth: 20 },
+    ],
+    rows: results.map((result) => ({
+      Case: result.name,
+      Result: result.ok ? theme.success("pass") : theme.error("fail"),
+      Time: `${(result.durationMs / 1000).toFixed(1)}s`,
+      Cost: formatUsd(result.costUsd) ?? "-",
+      Detail:
+        result.error ??
+        result.assertions
+          .filter((assertion) => !assertion.ok)
+          .map((assertion) => `${assertion.type}: ${assertion.detail ?? "failed"}`)
+          .join("; "),
+    })),
+  }).trimEnd();
+}
+
+async function resolveJudgeMember(params: {
+  cfg: ReturnType<typeof loadConfig>;
+  raw?: string;
+  agentId: string;
+}): Promise<CouncilMember> {
+  if (!params.raw) {
+    const ref = resolveDefaultModelForAgent({ cfg: params.cfg, agentId: params.agentId });
+    return { key: `${ref.provider}/${ref.model}`, ref };
+  }
+  const { members, errors } = await resolveCouncilMembers({
+    cfg: params.cfg,
+    models: [params.raw],
+    agentId: params.agentId,
+  });
+  const member = members[0];
+  if (!member) {
+    throw new Error(`Judge model: ${errors.join("; ") || params.raw}`);
+  }
+  return member;
+}
+
+/**
+ * `openclaw eval`: runs each case of a YAML suite through the agent in a fresh session and checks
+ * the reply against its assertions. Fails (exit 1) when any case fails.
+ */
+export async function evalCommand(
+  suitePath: string,
+  opts: EvalOpts,
+  runtime: RuntimeEnv,
+  deps?: CliDeps,
+): Promise<{ ok: boolean; results: EvalCaseResult[] }> {
+  const resolvedSuite = path.resolve(suitePath);
+  const suite = await parseEvalSuite(
+    await fs.readFile(resolvedSuite, "utf8"),
+    path.dirname(resolvedSuite),
+  );
+  const filter = opts.filter?.trim();
+  const cases = filter ? suite.cases.filter((entry) => entry.name.includes(filter)) : suite.cases;
+  if (cases.length === 0) {
+    throw new Error(`No cases match --filter "${filter}"`);
+  }
+
+  const cfg = loadConfig();
+  const agentIdRaw = opts.agent?.trim() || suite.agent;
+  const agentId = agentIdRaw ? normalizeAgentId(agentIdRaw) : resolveDefaultAgentId(cfg);
+  if (agentIdRaw && !listAgentIds(cfg).includes(agentId)) {
+    throw new Error(
+      `Unknown agent id "${agentIdRaw}". Use "${formatCliCommand("openclaw agents list")}" to see configured agents.`,
+    );
+  }
+  const needsJudge = cases.some((entry) => entry.assert.some((a) => a.type === "judge"));
+  const judgeMember = needsJudge
+    ? await resolveJudgeMember({ cfg, raw: opts.judge?.trim() || suite.judge, agentId })
+    : undefined;
+  const judgeTimeoutMs = resolveCouncilTimeoutMs(cfg);
+
+  // Replies are graded here, not printed by the agent command.
+  const quietRuntime: RuntimeEnv = { ...runtime, log: () => {} };
+  const results: EvalCaseResult[] = [];
+  for (const entry of cases) {
+    const startedAt = Date.now();
+    const sessionId = crypto.randomUUID();
+    let result: EvalCaseResult;
+    try {
+      const run = await agentCommand(
+        {
+          message: entry.prompt,
+          agentId,
+          sessionKey: `agent:${agentId}:eval:${sessionId.slice(0, 8)}`,
+          sessionId,
+          timeout: opts.timeout,
+          // Nobody is around to approve commands; the exec policy alone decides.
+          execOverrides: { ask: "off" },
+          exclusiveSession: true,
+        },
+        quietRuntime,
+        deps,
+      );
+      const text = (run?.payloads ?? [])
+        .map((payload) => payload.text ?? "")
+        .filter(Boolean)
+        .join("\n\n");
+      const agentMeta = run?.meta?.agentMeta;
+      const costUsd = agentMeta
+        ? estimateUsageCost({
+            usage: agentMeta.usage,
+            cost: resolveModelCostConfig({
+              provider: agentMeta.provider,
+              model: agentMeta.model,
+              config: cfg,
+            }),
+          })
+        : undefined;
+      const judge = judgeMember
+        ? async (criteria: string, reply: string): Promise<EvalAssertionResult> => {
+            const controller = new AbortController();
+            const timer = setTimeout(() => controller.abort(), judgeTimeoutMs);
+            try {
+              const verdict = parseEvalJudgeVerdict(
+                await askModel({
+                  cfg,
+                  member: judgeMember,
+                  prompt: buildEvalJudgePrompt({ prompt: entry.prompt, criteria, text: reply }),
+                  signal: controller.signal,
+                }),
+              );
+              return {
+                type: "judge",
+                ok: verdict.ok,
+                detail: verdict.ok ? undefined : verdict.reason,
+              };
+            } catch (err) {
+              return { type: "judge", ok: false, detail: `judge error: ${String(err)}` };
+            } finally {
+              clearTimeout(timer);
+            }
+          }
+        : undefined;
+      const assertions: EvalAssertionResult[] = [];
+      for (const assertion of entry.assert) {
+        assertions.push(await checkEvalAssertion(assertion, text, judge));
+      }
+      const runError = run?.meta?.error?.message ?? (run?.meta?.aborted ? "run aborted" : undefined);
+      result = {
+        name: entry.name,
+        ok: !runError && assertions.every((assertion) => assertion.ok),
+        error: runError,
+        text,
+        durationMs: Date.now() - startedAt,
+        costUsd,
+        assertions,
+      };
+    } catch (err) {
+      result = {
+        name: entry.name,
+        ok: false,
+        error: err instanceof Error ? err.message : String(err),
+        durationMs: Date.now() - startedAt,
+        assertions: [],
+      };
+    }
+    results.push(result);
+    if (!opts.json) {
+      const status = result.ok ? "pass" : theme.error("fail");
+      runtime.log(
+        theme.muted(`${entry.name}: ${status} (${(result.durationMs / 1000).toFixed(1)}s)`),
+      );
+    }
+  }
+
+  const passed = results.filter((result) => result.ok).length;
+  const costs = results.map((result) => result.costUsd).filter((cost) => typeof cost === "number");
+  const totalCostUsd = costs.length > 0 ? costs.reduce((sum, cost) => sum + cost, 0) : undefined;
+  const report = {
+    suite: resolvedSuite,
+    agentId,
+    judge: judgeMember?.key,
+    passed,
+    failed: results.length - passed,
+    totalCostUsd,
+    results,
+  };
+  if (opts.out) {
+    const outPath = path.resolve(opts.out);
+    await fs.mkdir(path.dirname(outPath), { recursive: true });
+    await fs.writeFile(outPath, `${JSON.stringify(report, null, 2)}\n`, "utf8");
+  }
+  if (opts.json) {
+    runtime.log(JSON.stringify(report, null, 2));
+  } else {
+    runtime.log("");
+    runtime.log(formatEvalReport(results, Math.max(60, (process.stdout.columns ?? 120) - 1)));
+    const cost = formatUsd(totalCostUsd);
+    const line = `${passed}/${results.length} passed${cost ? ` · ${cost}` : ""}`;
+    runtime.log(passed === results.length ? theme.success(line) : theme.error(line));
+  }
+  return { ok: passed === results.length, results };
+}
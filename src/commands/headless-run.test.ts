@@ -0,0 +1,38 @@
+import { describe, expect, it } from "vitest";
+import { evaluateRunOutcome } from "./headless-run.js";
+
+describe("openclaw run outcome", () => {
+  it("succeeds when the run completes cleanly", () => {
+    expect(evaluateRunOutcome({ finalText: "All tests pass." })).toEqual({
+      ok: true,
+      status: "success",
+    });
+  });
+
+  it("fails on errors, aborts, and reached limits", () => {
+    expect(evaluateRunOutcome({ error: new Error("boom"), finalText: "" })).toMatchObject({
+      ok: false,
+      status: "error",
+      reason: "Error: boom",
+    });
+    expect(evaluateRunOutcome({ aborted: true, finalText: "partial" })).toMatchObject({
+      ok: false,
+      reason: "run aborted",
+    });
+    expect(
+      evaluateRunOutcome({
+        limitReason: "turn limit reached (30/30 model turns)",
+        finalText: "Summary of progress",
+      }),
+    ).toMatchObject({ ok: false, status: "failed", reason: expect.stringContaining("turn limit") });
+  });
+
+  it("applies the --success pattern to the final reply", () => {
+    const success = /^DONE\b/m;
+    expect(evaluateRunOutcome({ finalText: "Fixed it.\nDONE", success }).ok).toBe(true);
+    expect(evaluateRunOutcome({ finalText: "Could not fix it.", success })).toMatchObject({
+      ok: false,
+      reason: expect.stringContaining("--success"),
+    });
+  });
+});
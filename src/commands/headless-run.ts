@@ -0,0 +1,255 @@
+import crypto from "node:crypto";
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { CliDeps } from "../cli/deps.js";
+import type { RuntimeEnv } from "../runtime.js";
+import { listAgentIds, resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { installAgentShutdownHandlers } from "../agents/run-shutdown.js";
+import { formatCliCommand } from "../cli/command-format.js";
+import { loadConfig } from "../config/config.js";
+import {
+  loadSessionStore,
+  resolveSessionFilePath,
+  resolveSessionFilePathOptions,
+  resolveStorePath,
+} from "../config/sessions.js";
+import { type AgentEventPayload, onAgentEvent } from "../infra/agent-events.js";
+import { normalizeAgentId } from "../routing/session-key.js";
+import { resolveUserPath } from "../utils.js";
+import { agentCommand } from "./agent.js";
+
+export const RUN_OUTPUT_FORMATS = ["text", "json", "jsonl"] as const;
+
+export type RunOutputFormat = (typeof RUN_OUTPUT_FORMATS)[number];
+
+export type HeadlessRunOpts = {
+  task?: string;
+  message?: string;
+  agent?: string;
+  thinking?: string;
+  maxTurns?: string;
+  maxDuration?: string;
+  maxCostUsd?: string;
+  timeout?: string;
+  outputFormat?: string;
+  transcript?: string;
+  success?: string;
+};
+
+export type RunOutcome = {
+  ok: boolean;
+  status: "success" | "failed" | "error";
+  reason?: string;
+};
+
+/**
+ * Decides the exit status of a headless run: it fails on errors, aborts, and reached run limits,
+ * and, when a `--success` pattern is given, unless the final reply matches it.
+ */
+export function evaluateRunOutcome(params: {
+  error?: unknown;
+  aborted?: boolean;
+  runError?: string;
+  limitReason?: string;
+  finalText: string;
+  success?: RegExp;
+}): RunOutcome {
+  if (params.error !== undefined) {
+    return { ok: false, status: "error", reason: String(params.error) };
+  }
+  if (params.aborted) {
+    return { ok: false, status: "failed", reason: "run aborted" };
+  }
+  if (params.runError) {
+    return { ok: false, status: "failed", reason: params.runError };
+  }
+  if (params.limitReason) {
+    return { ok: false, status: "failed", reason: params.limitReason };
+  }
+  if (params.success && !params.success.test(params.finalText)) {
+    return {
+      ok: false,
+      status: "failed",
+      reason: `reply did not match --success ${String(params.success)}`,
+    };
+  }
+  return { ok: true, status: "success" };
+}
+
+function parseOutputFormat(raw?: string): RunOutputFormat {
+  const value = (raw ?? "text").trim().toLowerCase();
+  if (!(RUN_OUTPUT_FORMATS as readonly string[]).includes(value)) {
+    throw new Error(`--output-format must be one of: ${RUN_OUTPUT_FORMATS.join(", ")}`);
+  }
+  return value as RunOutputFormat;
+}
+
+function parseSuccessPattern(raw?: string): RegExp | undefined {
+  if (!raw?.trim()) {
+    return undefined;
+  }
+  try {
+    return new RegExp(raw, "m");
+  } catch (err) {
+    throw new Error(`Invalid --success pattern: ${String(err)}`, { cause: err });
+  }
+}
+
+async function readTask(opts: HeadlessRunOpts): Promise<string> {
+  if (opts.task && opts.message) {
+    throw new Error("Pass either --task or --message, not both");
+  }
+  const body = opts.task
+    ? await fs.readFile(resolveUserPath(opts.task), "utf-8")
+    : (opts.message ?? "");
+  if (!body.trim()) {
+    throw new Error(opts.task ? `Task file ${opts.task} is empty` : "Pass --task <file>");
+  }
+  return body.trim();
+}
+
+async function copyTranscript(params: {
+  cfg: ReturnType<typeof loadConfig>;
+  agentId: string;
+  sessionKey: string;
+  sessionId: string;
+  dest: string;
+}): Promise<string | undefined> {
+  const storePath = resolveStorePath(params.cfg.session?.store, { agentId: params.agentId });
+  const entry = loadSessionStore(storePath)[params.sessionKey];
+  const source = resolveSessionFilePath(
+    entry?.sessionId ?? params.sessionId,
+    entry,
+    resolveSessionFilePathOptions({ agentId: params.agentId, storePath }),
+  );
+  const dest = resolveUserPath(params.dest);
+  try {
+    await fs.mkdir(path.dirname(dest), { recursive: true });
+    await fs.copyFile(source, dest);
+    return dest;
+  } catch {
+    return undefined;
+  }
+}
+
+/**
+ * `openclaw run`: one non-interactive agent run for CI. It always runs embedded in a fresh
+ * session, uses the `ci` config profile, and exits 1 unless the run completes cleanly.
+ */
+export async function headlessRunCommand(
+  opts: HeadlessRunOpts,
+  runtime: RuntimeEnv,
+  deps?: CliDeps,
+): Promise<RunOutcome> {
+  const format = parseOutputFormat(opts.outputFormat);
+  const success = parseSuccessPattern(opts.success);
+  const message = await readTask(opts);
+  const cfg = loadConfig();
+  const ci = cfg.ci ?? {};
+
+  const agentIdRaw = opts.agent?.trim() || ci.agent?.trim();
+  const agentId = agentIdRaw ? normalizeAgentId(agentIdRaw) : resolveDefaultAgentId(cfg);
+  if (agentIdRaw && !listAgentIds(cfg).includes(agentId)) {
+    throw new Error(
+      `Unknown agent id "${agentIdRaw}". Use "${formatCliCommand("openclaw agents list")}" to see configured agents.`,
+    );
+  }
+
+  const sessionId = crypto.randomUUID();
+  const sessionKey = `agent:${agentId}:run:${sessionId.slice(0, 8)}`;
+  const startedAt = Date.now();
+  let limitReason: string | undefined;
+
+  const emit = (line: Record<string, unknown>) => runtime.log(JSON.stringify(line));
+  const unsubscribe = onAgentEvent((evt: AgentEventPayload) => {
+    if (evt.sessionKey !== sessionKey) {
+      return;
+    }
+    const details = (evt.data.result as { details?: Record<string, unknown> } | undefined)
+      ?.details;
+    if (evt.stream === "tool" && details?.runLimitReached && !limitReason) {
+      limitReason = typeof details.reason === "string" ? details.reason : "run limit reached";
+    }
+    if (format === "jsonl") {
+      emit({ type: "event", ts: evt.ts, stream: evt.stream, data: evt.data });
+    }
+  });
+  // The reply is printed below in the chosen format, not by the agent command itself.
+  const quietRuntime: RuntimeEnv = { ...runtime, log: () => {} };
+  const dispose = installAgentShutdownHandlers({ exit: (code) => runtime.exit(code) });
+
+  let error: unknown;
+  let result: Awaited<ReturnType<typeof agentCommand>> | undefined;
+  try {
+    result = await agentCommand(
+      {
+        message,
+        agentId,
+        sessionKey,
+        sessionId,
+        thinking: opts.thinking,
+        timeout: opts.timeout,
+        maxTurns: opts.maxTurns ?? (ci.maxTurns !== undefined ? String(ci.maxTurns) : undefined),
+        maxDuration:
+          opts.maxDuration ??
+          (ci.maxDurationSeconds !== undefined ? String(ci.maxDurationSeconds) : undefined),
+        maxCostUsd:
+          opts.maxCostUsd ?? (ci.maxCostUsd !== undefined ? String(ci.maxCostUsd) : undefined),
+        // Nobody is around to approve commands; the exec policy alone decides.
+        execOverrides: { ask: "off", security: ci.execSecurity },
+        exclusiveSession: true,
+      },
+      quietRuntime,
+      deps,
+    );
+  } catch (err) {
+    error = err;
+  } finally {
+    unsubscribe();
+    dispose();
+  }
+
+  const finalText = (result?.payloads ?? [])
+    .map((payload) => payload.text ?? "")
+    .filter(Boolean)
+    .join("\n\n");
+  const meta = result?.meta;
+  const outcome = evaluateRunOutcome({
+    error,
+    aborted: meta?.aborted,
+    runError: meta?.error?.message,
+    limitReason,
+    finalText,
+    success,
+  });
+  const transcript = opts.transcript
+    ? await copyTranscript({ cfg, agentId, sessionKey, sessionId, dest: opts.transcript })
+    : undefined;
+  if (opts.transcript && !transcript) {
+    runtime.error(`warning: no transcript to copy to ${opts.transcript}`);
+  }
+
+  const summary = {
+    ok: outcome.ok,
+    status: outcome.status,
+    reason: outcome.reason,
+    sessionKey,
+    sessionId,
+    durationMs: Date.now() - startedAt,
+    text: finalText,
+    transcript,
+  };
+  if (format === "text") {
+    if (finalText) {
+      runtime.log(finalText);
+    }
+    runtime.error(
+      outcome.ok ? `run: success (${sessionKey})` : `run: ${outcome.status}: ${outcome.reason}`,
+    );
+  } else if (format === "json") {
+    runtime.log(JSON.stringify(summary, null, 2));
+  } else {
+    emit({ type: "result", ...summary });
+  }
+  return outcome;
+}
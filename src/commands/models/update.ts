@@ -0,0 +1,88 @@
+import { cancel, confirm, isCancel } from "@clack/prompts";
+import type { RuntimeEnv } from "../../runtime.js";
+import {
+  convertLiteLlmRegistry,
+  diffModelMetadata,
+  LITELLM_MODEL_REGISTRY_URL,
+  loadModelMetadataRegistry,
+  writeModelMetadataRegistry,
+} from "../../agents/model-metadata-registry.js";
+import { stylePromptMessage, stylePromptTitle } from "../../terminal/prompt-style.js";
+import { theme } from "../../terminal/theme.js";
+import { fetchWithTimeout } from "../../utils/fetch-timeout.js";
+
+const FETCH_TIMEOUT_MS = 30_000;
+const PREVIEW_LIMIT = 8;
+
+export type ModelsUpdateOpts = {
+  url?: string;
+  yes?: boolean;
+  json?: boolean;
+};
+
+function formatPreview(label: string, keys: string[]): string | undefined {
+  if (keys.length === 0) {
+    return undefined;
+  }
+  const shown = keys.slice(0, PREVIEW_LIMIT).join(", ");
+  const more = keys.length > PREVIEW_LIMIT ? `, … +${keys.length - PREVIEW_LIMIT}` : "";
+  return `${label} (${keys.length}): ${shown}${more}`;
+}
+
+/**
+ * `openclaw models update`: downloads community model metadata (pricing, context windows),
+ * shows what changes, and caches it for cost estimates and context lookups.
+ */
+export async function modelsUpdateCommand(opts: ModelsUpdateOpts, runtime: RuntimeEnv) {
+  const url = opts.url?.trim() || LITELLM_MODEL_REGISTRY_URL;
+  const res = await fetchWithTimeout(url, { method: "GET" }, FETCH_TIMEOUT_MS);
+  if (!res.ok) {
+    throw new Error(`Model registry download failed: HTTP ${res.status} (${url})`);
+  }
+  const registry = convertLiteLlmRegistry(await res.json(), {
+    source: url,
+    fetchedAt: new Date().toISOString(),
+  });
+  const total = Object.keys(registry.models).length;
+  if (total === 0) {
+    throw new Error(`No chat models found in ${url}`);
+  }
+  const diff = diffModelMetadata(loadModelMetadataRegistry(), registry);
+
+  if (!opts.json) {
+    runtime.log(
+      `Fetched ${total} models: ${diff.added.length} new, ${diff.changed.length} changed, ${diff.removed.length} removed.`,
+    );
+    for (const line of [
+      formatPreview("New", diff.added),
+      formatPreview("Changed", diff.changed),
+      formatPreview("Removed", diff.removed),
+    ]) {
+      if (line) {
+        runtime.log(theme.muted(line));
+      }
+    }
+  }
+  const unchanged = diff.added.length + diff.changed.length + diff.removed.length === 0;
+  if (!unchanged && !opts.yes) {
+    if (!process.stdin.isTTY || opts.json) {
+      throw new Error("Non-interactive update: pass --yes to save the model metadata.");
+    }
+    const ok = await confirm({ message: stylePromptMessage("Save this model metadata?") });
+    if (isCancel(ok) || !ok) {
+      cancel(stylePromptTitle("Model update cancelled.") ?? "Model update cancelled.");
+      runtime.exit(0);
+      return;
+    }
+  }
+
+  const file = await writeModelMetadataRegistry(registry);
+  if (opts.json) {
+    runtime.log(JSON.stringify({ source: url, file, total, ...diff }, null, 2));
+  } else {
+    runtime.log(
+      theme.muted(`Saved to ${file}. Prices set in models.providers still take priority.`),
+    );
+  }
+  return { file, total, diff };
+}
@@ -0,0 +1,126 @@
+import path from "node:path";
+import type { RuntimeEnv } from "../runtime.js";
+import { resolveDefaultAgentId } from "../agents/agent-scope.js";
+import {
+  formatReviewFindings,
+  REVIEW_SEVERITIES,
+  type ReviewSeverity,
+  type ReviewTarget,
+  runCodeReview,
+  severityAtLeast,
+} from "../agents/code-review.js";
+import { lookupContextTokens } from "../agents/context.js";
+import {
+  buildModelAliasIndex,
+  resolveDefaultModelForAgent,
+  resolveModelRefFromString,
+} from "../agents/model-selection.js";
+import { findGitRoot } from "../agents/system-prompt-params.js";
+import { withProgressTotals } from "../cli/progress.js";
+import { loadConfig } from "../config/config.js";
+
+export type ReviewOpts = {
+  staged?: boolean;
+  pr?: string;
+  agent?: string;
+  model?: string;
+  failOn?: string;
+  json?: boolean;
+};
+
+function resolveTarget(ref: string | undefined, opts: ReviewOpts): ReviewTarget {
+  const picked = [ref !== undefined, opts.staged === true, opts.pr !== undefined].filter(Boolean);
+  if (picked.length > 1) {
+    throw new Error("Pass only one of [ref], --staged, or --pr");
+  }
+  if (opts.pr !== undefined) {
+    const number = Number(opts.pr.replace(/^#/, ""));
+    if (!Number.isInteger(number) || number <= 0) {
+      throw new Error("--pr must be a pull request number");
+    }
+    return { kind: "pr", number };
+  }
+  if (opts.staged) {
+    return { kind: "staged" };
+  }
+  if (ref !== undefined) {
+    if (!ref.trim() || ref.startsWith("-")) {
+      throw new Error(`Invalid ref: ${ref}`);
+    }
+    return { kind: "ref", ref: ref.trim() };
+  }
+  return { kind: "worktree" };
+}
+
+/**
+ * `openclaw review [ref]`: the `/review` chat command for terminals and CI. With `--fail-on`, exits
+ * 1 when any finding is at least that severe or part of the diff could not be reviewed.
+ */
+export async function reviewCommand(
+  ref: string | undefined,
+  opts: ReviewOpts,
+  runtime: RuntimeEnv,
+) {
+  const target = resolveTarget(ref, opts);
+  const failOn = opts.failOn?.trim().toLowerCase();
+  if (failOn && !REVIEW_SEVERITIES.includes(failOn as ReviewSeverity)) {
+    throw new Error(`--fail-on must be one of ${REVIEW_SEVERITIES.join(", ")}`);
+  }
+  const cwd = path.resolve(process.cwd());
+  const root = findGitRoot(cwd);
+  if (!root) {
+    throw new Error(`Not inside a git repository: ${cwd}`);
+  }
+
+  const cfg = loadConfig();
+  const agentId = opts.agent?.trim() || resolveDefaultAgentId(cfg);
+  const defaultRef = resolveDefaultModelForAgent({ cfg, agentId });
+  const modelRef = opts.model?.trim()
+    ? resolveModelRefFromString({
+        raw: opts.model,
+        defaultProvider: defaultRef.provider,
+        aliasIndex: buildModelAliasIndex({ cfg, defaultProvider: defaultRef.provider }),
+      })?.ref
+    : defaultRef;
+  if (!modelRef) {
+    throw new Error(`Unknown model: ${opts.model}`);
+  }
+
+  const result = await withProgressTotals(
+    { label: "Reviewing…", total: 0, enabled: !opts.json },
+    async (update) =>
+      await runCodeReview({
+        cfg,
+        provider: modelRef.provider,
+        model: modelRef.model,
+        root,
+        target,
+        contextTokens: cfg.agents?.defaults?.contextTokens ?? lookupContextTokens(modelRef.model),
+        onProgress: ({ completed, total }) =>
+          update({
+            completed,
+            total,
+            label:
+              total > 1 && completed < total
+                ? `Reviewing part ${completed + 1} of ${total}…`
+                : undefined,
+          }),
+      }),
+  );
+  if (opts.json) {
+    const model = `${modelRef.provider}/${modelRef.model}`;
+    runtime.log(JSON.stringify({ root, model, ...result }, null, 2));
+  } else {
+    runtime.log(formatReviewFindings(result));
+  }
+  if (!failOn) {
+    return;
+  }
+  // A gate should not pass on a review that did not cover the whole diff.
+  const blocking = result.findings.some((finding) =>
+    severityAtLeast(finding.severity, failOn as ReviewSeverity),
+  );
+  if (blocking || result.errors.length > 0) {
+    runtime.exit(1);
+  }
+}
@@ -0,0 +1,137 @@
+import type { OpenClawConfig } from "../config/config.js";
+import type { RuntimeEnv } from "../runtime.js";
+import {
+  listAgentIds,
+  resolveAgentIdForWorkspacePath,
+  resolveDefaultAgentId,
+} from "../agents/agent-scope.js";
+import { loadConfig } from "../config/config.js";
+import { loadSessionStore, resolveStorePath, type SessionEntry } from "../config/sessions.js";
+import { resolveAgentIdFromSessionKey } from "../routing/session-key.js";
+
+/** Session id prefixes shorter than this are too likely to collide to be useful. */
+const MIN_SESSION_ID_PREFIX = 6;
+
+export type SessionsStoreContext = {
+  cfg: OpenClawConfig;
+  storePath: string;
+  store: Record<string, SessionEntry>;
+};
+
+export type SessionsScope = {
+  /** Agent whose sessions are listed; undefined with `--global`. */
+  agentId?: string;
+  /** True when `agentId` came from the workspace containing the current directory. */
+  fromWorkspace: boolean;
+  storePaths: string[];
+  /** Set when several agents share one store file, so rows must be filtered by key. */
+  filterAgentId?: string;
+};
+
+/**
+ * Sessions are namespaced per agent, and each agent has a workspace. Inside a workspace the
+ * commands default to that agent's sessions; elsewhere they use the default agent. `--global`
+ * spans every agent, and an explicit `--store` is used as-is.
+ */
+export function resolveSessionsScope(
+  cfg: OpenClawConfig,
+  opts: { store?: string; global?: boolean; cwd?: string },
+): SessionsScope {
+  const agentIds = listAgentIds(cfg);
+  if (opts.global && !opts.store) {
+    const storePaths = agentIds.map((id) => resolveStorePath(cfg.session?.store, { agentId: id }));
+    return { fromWorkspace: false, storePaths: [...new Set(storePaths)] };
+  }
+  const workspaceAgentId = resolveAgentIdForWorkspacePath(cfg, opts.cwd ?? process.cwd());
+  const agentId = workspaceAgentId ?? resolveDefaultAgentId(cfg);
+  const template = opts.store ?? cfg.session?.store;
+  const storePath = resolveStorePath(template, { agentId });
+  const shared =
+    !opts.store &&
+    agentIds.some(
+      (id) => id !== agentId && resolveStorePath(template, { agentId: id }) === storePath,
+    );
+  return {
+    agentId,
+    fromWorkspace: Boolean(workspaceAgentId),
+    storePaths: [storePath],
+    filterAgentId: shared ? agentId : undefined,
+  };
+}
+
+/** Loads and merges the scope's stores; keys carry the agent id, so they do not collide. */
+export function loadScopedSessionStore(scope: SessionsScope): Record<string, SessionEntry> {
+  const merged: Record<string, SessionEntry> = {};
+  for (const storePath of scope.storePaths) {
+    for (const [key, entry] of Object.entries(loadSessionStore(storePath))) {
+      if (!scope.filterAgentId || resolveAgentIdFromSessionKey(key) === scope.filterAgentId) {
+        merged[key] = entry;
+      }
+    }
+  }
+  return merged;
+}
+
+export function loadSessionsStoreContext(opts: { store?: string }): SessionsStoreContext {
+  const cfg = loadConfig();
+  const scope = resolveSessionsScope(cfg, { store: opts.store });
+  const storePath = scope.storePaths[0];
+  return { cfg, storePath, store: loadScopedSessionStore(scope) };
+}
+
+export type ResolvedSessionMatch =
+  | { ok: true; key: string; entry: SessionEntry }
+  | { ok: false; error: string };
+
+/**
+ * Finds a session by key, key suffix (`main` for `agent:main:main`), session id (or a prefix of at
+ * least six characters), title, or label. The first rule that matches wins; ties are an error.
+ */
+export function resolveSessionByName(
+  store: Record<string, SessionEntry>,
+  rawName: string,
+): ResolvedSessionMatch {
+  const name = rawName.trim();
+  if (!name) {
+    return { ok: false, error: "Session name is required." };
+  }
+  if (store[name]) {
+    return { ok: true, key: name, entry: store[name] };
+  }
+  const lower = name.toLowerCase();
+  const entries = Object.entries(store);
+  const rules: Array<(key: string, entry: SessionEntry) => boolean> = [
+    (key) => key.toLowerCase() === lower || key.toLowerCase().endsWith(`:${lower}`),
+    (_key, entry) => entry.sessionId === name,
+    (_key, entry) =>
+      name.length >= MIN_SESSION_ID_PREFIX && entry.sessionId?.startsWith(name) === true,
+    (_key, entry) =>
+      entry.title?.toLowerCase() === lower || entry.label?.toLowerCase() === lower,
+  ];
+  for (const rule of rules) {
+    const matches = entries.filter(([key, entry]) => entry && rule(key, entry));
+    if (matches.length === 1) {
+      const [key, entry] = matches[0];
+      return { ok: true, key, entry };
+    }
+    if (matches.length > 1) {
+      const keys = matches.map(([key]) => `  ${key}`).join("\n");
+      return { ok: false, error: `"${name}" matches several sessions:\n${keys}` };
+    }
+  }
+  return { ok: false, error: `Session "${name}" not found.` };
+}
+
+export function requireSession(
+  ctx: SessionsStoreContext,
+  name: string,
+  runtime: RuntimeEnv,
+): { key: string; entry: SessionEntry } | null {
+  const resolved = resolveSessionByName(ctx.store, name);
+  if (!resolved.ok) {
+    runtime.error(resolved.error);
+    runtime.exit(1);
+    return null;
+  }
+  return resolved;
+}
@@ -0,0 +1,101 @@
+import crypto from "node:crypto";
+import fs from "node:fs";
+import path from "node:path";
+import type { RuntimeEnv } from "../runtime.js";
+import { updateSessionStore } from "../config/sessions.js";
+import { resolveSessionTranscriptCandidates } from "../gateway/session-utils.fs.js";
+import { defaultRuntime } from "../runtime.js";
+import { buildForkedSessionEntry } from "../sessions/session-fork.js";
+import { parseSessionTitle } from "../sessions/session-title.js";
+import { loadSessionsStoreContext, requireSession } from "./sessions.command-shared.js";
+
+type SessionsCopyOptions = {
+  name: string;
+  newKey: string;
+  title?: string;
+  store?: string;
+  json?: boolean;
+};
+
+/**
+ * Writes a copy of a transcript under a new session id. Only the header line carries the id, so
+ * every other line is copied as-is.
+ */
+export function copySessionTranscript(params: {
+  sourceFile: string;
+  sessionId: string;
+}): string {
+  const lines = fs.readFileSync(params.sourceFile, "utf-8").split(/\r?\n/);
+  const headerIndex = lines.findIndex((line) => line.trim());
+  if (headerIndex >= 0) {
+    try {
+      const header = JSON.parse(lines[headerIndex]) as Record<string, unknown>;
+      if (header?.type === "session") {
+        lines[headerIndex] = JSON.stringify({
+          ...header,
+          id: params.sessionId,
+          parentSession: params.sourceFile,
+        });
+      }
+    } catch {
+      // Leave a malformed header alone; SessionManager repairs it on open.
+    }
+  }
+  const targetFile = path.join(path.dirname(params.sourceFile), `${params.sessionId}.jsonl`);
+  fs.writeFileSync(targetFile, lines.join("\n"), { encoding: "utf-8", flag: "wx" });
+  return targetFile;
+}
+
+export async function sessionsCopyCommand(
+  opts: SessionsCopyOptions,
+  runtime: RuntimeEnv = defaultRuntime,
+) {
+  const ctx = loadSessionsStoreContext(opts);
+  const session = requireSession(ctx, opts.name, runtime);
+  if (!session) {
+    return;
+  }
+  const newKey = opts.newKey.trim();
+  if (!newKey) {
+    runtime.error("Target session key is required.");
+    runtime.exit(1);
+    return;
+  }
+  if (ctx.store[newKey]) {
+    runtime.error(`Session "${newKey}" already exists.`);
+    runtime.exit(1);
+    return;
+  }
+  let title = session.entry.title ? `${session.entry.title} (copy)` : undefined;
+  if (opts.title !== undefined) {
+    const parsed = parseSessionTitle(opts.title);
+    if (!parsed.ok) {
+      runtime.error(parsed.error);
+      runtime.exit(1);
+      return;
+    }
+    title = parsed.title;
+  }
+
+  const { entry } = session;
+  const sessionId = crypto.randomUUID();
+  const sourceFile = resolveSessionTranscriptCandidates(
+    entry.sessionId,
+    ctx.storePath,
+    entry.sessionFile,
+  ).find((candidate) => fs.existsSync(candidate));
+  const sessionFile = sourceFile ? copySessionTranscript({ sourceFile, sessionId }) : undefined;
+
+  const copy = buildForkedSessionEntry(entry, { sessionId, sessionFile, title });
+  await updateSessionStore(ctx.storePath, (store) => {
+    store[newKey] = copy;
+  });
+
+  if (opts.json) {
+    runtime.log(
+      JSON.stringify({ from: session.key, key: newKey, sessionId, sessionFile }, null, 2),
+    );
+    return;
+  }
+  runtime.log(`Copied ${session.key} → ${newKey}${sourceFile ? "" : " (no transcript found)"}`);
+}
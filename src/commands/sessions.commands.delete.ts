@@ -0,0 +1,127 @@
+import type { SessionEntry } from "../config/sessions.js";
+import type { RuntimeEnv } from "../runtime.js";
+import { parseDurationMs } from "../cli/parse-duration.js";
+import { resolveMainSessionKey, updateSessionStore } from "../config/sessions.js";
+import { archiveSessionTranscripts } from "../gateway/session-utils.fs.js";
+import { defaultRuntime } from "../runtime.js";
+import { createClackPrompter } from "../wizard/clack-prompter.js";
+import { loadSessionsStoreContext, requireSession } from "./sessions.command-shared.js";
+
+type SessionsDeleteOptions = {
+  name?: string;
+  allOlderThan?: string;
+  store?: string;
+  force?: boolean;
+  keepTranscript?: boolean;
+  json?: boolean;
+};
+
+/** Sessions idle for longer than `olderThanMs`, never including the main session. */
+export function selectSessionsOlderThan(params: {
+  store: Record<string, SessionEntry>;
+  olderThanMs: number;
+  mainKey: string;
+  now?: number;
+}): string[] {
+  const cutoff = (params.now ?? Date.now()) - params.olderThanMs;
+  return Object.entries(params.store)
+    .filter(([key, entry]) => key !== params.mainKey && (entry?.updatedAt ?? 0) < cutoff)
+    .map(([key]) => key);
+}
+
+export async function sessionsDeleteCommand(
+  opts: SessionsDeleteOptions,
+  runtime: RuntimeEnv = defaultRuntime,
+) {
+  if (Boolean(opts.name) === Boolean(opts.allOlderThan)) {
+    runtime.error("Pass a session name or --all-older-than <duration>, not both.");
+    runtime.exit(1);
+    return;
+  }
+  const ctx = loadSessionsStoreContext(opts);
+  const mainKey = resolveMainSessionKey(ctx.cfg);
+
+  let keys: string[];
+  if (opts.allOlderThan) {
+    let olderThanMs: number;
+    try {
+      olderThanMs = parseDurationMs(opts.allOlderThan, { defaultUnit: "d" });
+    } catch {
+      runtime.error("--all-older-than must be a duration like 30d, 12h, or 90m");
+      runtime.exit(1);
+      return;
+    }
+    keys = selectSessionsOlderThan({ store: ctx.store, olderThanMs, mainKey });
+  } else {
+    const session = requireSession(ctx, opts.name ?? "", runtime);
+    if (!session) {
+      return;
+    }
+    if (session.key === mainKey) {
+      runtime.error(`Cannot delete the main session (${mainKey}).`);
+      runtime.exit(1);
+      return;
+    }
+    keys = [session.key];
+  }
+
+  if (keys.length === 0) {
+    runtime.log(
+      opts.json ? JSON.stringify({ deleted: [], archived: [] }) : "No sessions to delete.",
+    );
+    return;
+  }
+
+  if (!opts.force) {
+    if (!process.stdin.isTTY) {
+      runtime.error("Non-interactive session. Re-run with --force.");
+      runtime.exit(1);
+      return;
+    }
+    const prompter = createClackPrompter();
+    const confirmed = await prompter.confirm({
+      message:
+        keys.length === 1
+          ? `Delete session "${keys[0]}"?`
+          : `Delete ${keys.length} sessions idle for more than ${opts.allOlderThan}?`,
+      initialValue: false,
+    });
+    if (!confirmed) {
+      runtime.log("Cancelled.");
+      return;
+    }
+  }
+
+  const removed: Array<{ key: string; entry: SessionEntry }> = [];
+  await updateSessionStore(ctx.storePath, (store) => {
+    for (const key of keys) {
+      const entry = store[key];
+      if (entry) {
+        removed.push({ key, entry });
+        delete store[key];
+      }
+    }
+  });
+
+  const archived = opts.keepTranscript
+    ? []
+    : removed.flatMap(({ entry }) =>
+        archiveSessionTranscripts({
+          sessionId: entry.sessionId,
+          storePath: ctx.storePath,
+          sessionFile: entry.sessionFile,
+          reason: "deleted",
+        }),
+      );
+
+  if (opts.json) {
+    runtime.log(JSON.stringify({ deleted: removed.map(({ key }) => key), archived }, null, 2));
+    return;
+  }
+  for (const { key } of removed) {
+    runtime.log(`Deleted session: ${key}`);
+  }
+  if (archived.length > 0) {
+    runtime.log(`Archived ${archived.length} transcript file(s).`);
+  }
+}
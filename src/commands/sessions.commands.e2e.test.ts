@@ -0,0 +1,286 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import zlib from "node:zlib";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import type { SessionEntry } from "../config/sessions.js";
+
+process.env.FORCE_COLOR = "0";
+
+vi.mock("../config/config.js", async (importOriginal) => {
+  const actual = await importOriginal<typeof import("../config/config.js")>();
+  return { ...actual, loadConfig: () => ({}) };
+});
+
+import {
+  loadScopedSessionStore,
+  resolveSessionByName,
+  resolveSessionsScope,
+} from "./sessions.command-shared.js";
+import { sessionsCopyCommand } from "./sessions.commands.copy.js";
+import { sessionsDeleteCommand } from "./sessions.commands.delete.js";
+import { sessionsImportCommand } from "./sessions.commands.import.js";
+import { sessionsPruneCommand } from "./sessions.commands.prune.js";
+import { sessionsRenameCommand } from "./sessions.commands.rename.js";
+import { sessionsShowCommand } from "./sessions.commands.show.js";
+
+const makeRuntime = () => {
+  const logs: string[] = [];
+  return {
+    runtime: {
+      log: (msg: unknown) => logs.push(String(msg)),
+      error: (msg: unknown) => {
+        throw new Error(String(msg));
+      },
+      exit: (code: number) => {
+        throw new Error(`exit ${code}`);
+      },
+    },
+    logs,
+  } as const;
+};
+
+const DAY = 24 * 60 * 60_000;
+
+describe("sessions subcommands", () => {
+  let dir: string;
+  let store: string;
+
+  const readStore = () =>
+    JSON.parse(fs.readFileSync(store, "utf-8")) as Record<string, SessionEntry>;
+
+  const writeTranscript = (sessionId: string, messages: unknown[]) => {
+    const lines = [
+      { type: "session", version: 3, id: sessionId, timestamp: "2026-01-01T00:00:00.000Z" },
+      ...messages.map((message, i) => ({ type: "message", id: `m${i}`, message })),
+    ];
+    fs.writeFileSync(
+      path.join(dir, `${sessionId}.jsonl`),
+      `${lines.map((line) => JSON.stringify(line)).join("\n")}\n`,
+    );
+  };
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-sessions-cli-"));
+    store = path.join(dir, "sessions.json");
+    const now = Date.now();
+    fs.writeFileSync(
+      store,
+      JSON.stringify({
+        "agent:main:main": { sessionId: "aaaaaaaa-main", updatedAt: now - 60 * DAY },
+        "agent:main:discord:channel:1": {
+          sessionId: "bbbbbbbb-old",
+          updatedAt: now - 45 * DAY,
+          title: "Fix flaky auth test",
+        },
+        "agent:main:discord:channel:2": { sessionId: "cccccccc-new", updatedAt: now - DAY },
+      }),
+    );
+    writeTranscript("bbbbbbbb-old", [
+      { role: "user", content: [{ type: "text", text: "auth test fails on CI" }], timestamp: 1 },
+      {
+        role: "assistant",
+        content: [
+          { type: "text", text: "Checking the logs." },
+          { type: "toolCall", id: "c1", name: "read", arguments: { path: "ci.log" } },
+        ],
+        timestamp: 2,
+      },
+      {
+        role: "toolResult",
+        toolCallId: "c1",
+        toolName: "read",
+        content: [{ type: "text", text: "x".repeat(1_000) }],
+        timestamp: 3,
+      },
+    ]);
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it("resolves sessions by key, key suffix, id prefix, and title", () => {
+    const entries = readStore();
+    const keyOf = (name: string) => {
+      const resolved = resolveSessionByName(entries, name);
+      return resolved.ok ? resolved.key : resolved.error;
+    };
+    expect(keyOf("agent:main:main")).toBe("agent:main:main");
+    expect(keyOf("main")).toBe("agent:main:main");
+    expect(keyOf("bbbbbbbb")).toBe("agent:main:discord:channel:1");
+    expect(keyOf("fix flaky auth test")).toBe("agent:main:discord:channel:1");
+    expect(keyOf("bbb")).toBe('Session "bbb" not found.');
+
+    entries["agent:ops:main"] = { sessionId: "dddddddd", updatedAt: 0 };
+    expect(keyOf("main")).toContain("matches several sessions");
+  });
+
+  it("scopes sessions to the agent whose workspace contains the current directory", () => {
+    const code = path.join(dir, "code");
+    const cfg: OpenClawConfig = {
+      agents: {
+        list: [
+          { id: "main", default: true, workspace: path.join(code, "web") },
+          { id: "api", workspace: path.join(code, "api") },
+        ],
+      },
+      session: { store: path.join(dir, "{agentId}", "sessions.json") },
+    };
+
+    const scoped = resolveSessionsScope(cfg, { cwd: path.join(code, "api", "src") });
+    expect(scoped).toMatchObject({ agentId: "api", fromWorkspace: true });
+    expect(scoped.storePaths).toEqual([path.join(dir, "api", "sessions.json")]);
+
+    const outside = resolveSessionsScope(cfg, { cwd: os.tmpdir() });
+    expect(outside).toMatchObject({ agentId: "main", fromWorkspace: false });
+
+    const global = resolveSessionsScope(cfg, { global: true, cwd: path.join(code, "api") });
+    expect(global.agentId).toBeUndefined();
+    expect(global.storePaths).toHaveLength(2);
+
+    // With one shared store file, rows are filtered by the agent id in the key.
+    const shared = resolveSessionsScope(
+      { ...cfg, session: { store } },
+      { cwd: path.join(code, "api") },
+    );
+    fs.writeFileSync(
+      store,
+      JSON.stringify({
+        "agent:main:main": { sessionId: "m", updatedAt: 1 },
+        "agent:api:main": { sessionId: "a", updatedAt: 1 },
+      }),
+    );
+    expect(Object.keys(loadScopedSessionStore(shared))).toEqual(["agent:api:main"]);
+  });
+
+  it("prints the transcript with tool output truncated", async () => {
+    const { runtime, logs } = makeRuntime();
+    await sessionsShowCommand({ name: "Fix flaky auth test", store }, runtime);
+
+    const output = logs.join("\n");
+    expect(output).toContain("Session: agent:main:discord:channel:1");
+    expect(output).toContain("Title: Fix flaky auth test");
+    expect(output).toContain("  auth test fails on CI");
+    expect(output).toContain('  → read {"path":"ci.log"}');
+    expect(output).toContain("(+600 chars)");
+
+    const limited = makeRuntime();
+    await sessionsShowCommand({ name: "bbbbbbbb-old", store, limit: "1" }, limited.runtime);
+    expect(limited.logs.join("\n")).toContain("(showing last 1 of 3 messages)");
+  });
+
+  it("deletes idle sessions but never the main session", async () => {
+    const { runtime } = makeRuntime();
+    await sessionsDeleteCommand({ allOlderThan: "30d", store, force: true }, runtime);
+
+    expect(Object.keys(readStore()).toSorted()).toEqual([
+      "agent:main:discord:channel:2",
+      "agent:main:main",
+    ]);
+    const files = fs.readdirSync(dir);
+    expect(files.some((file) => file.startsWith("bbbbbbbb-old.jsonl.deleted."))).toBe(true);
+
+    await expect(
+      sessionsDeleteCommand({ name: "main", store, force: true }, runtime),
+    ).rejects.toThrow("Cannot delete the main session");
+  });
+
+  it("previews a prune with --dry-run, then gzips the pruned transcripts", async () => {
+    const { runtime, logs } = makeRuntime();
+    await sessionsPruneCommand({ store, dryRun: true }, runtime);
+    expect(logs[0]).toBe(`${store}: would remove 1 session(s) and archive 1 transcript file(s).`);
+    expect(logs[1]).toMatch(/^ {2}agent:main:discord:channel:1 \(/);
+    expect(readStore()["agent:main:discord:channel:1"]).toBeDefined();
+
+    await sessionsPruneCommand({ store }, runtime);
+    expect(Object.keys(readStore()).toSorted()).toEqual([
+      "agent:main:discord:channel:2",
+      "agent:main:main",
+    ]);
+    expect(fs.existsSync(path.join(dir, "bbbbbbbb-old.jsonl"))).toBe(false);
+    const archived = zlib
+      .gunzipSync(fs.readFileSync(path.join(dir, "archive", "bbbbbbbb-old.jsonl.gz")))
+      .toString("utf-8");
+    expect(archived).toContain("auth test fails on CI");
+  });
+
+  it("renames and copies sessions", async () => {
+    const { runtime } = makeRuntime();
+    await sessionsRenameCommand({ name: "channel:2", title: "  Release notes  ", store }, runtime);
+    expect(readStore()["agent:main:discord:channel:2"]).toMatchObject({
+      title: "Release notes",
+      titleSource: "user",
+    });
+
+    await sessionsCopyCommand(
+      { name: "agent:main:discord:channel:1", newKey: "agent:main:experiment", store },
+      runtime,
+    );
+    const copy = readStore()["agent:main:experiment"];
+    expect(copy.title).toBe("Fix flaky auth test (copy)");
+    expect(copy.sessionId).not.toBe("bbbbbbbb-old");
+    const header = JSON.parse(fs.readFileSync(copy.sessionFile ?? "", "utf-8").split("\n")[0]);
+    expect(header.id).toBe(copy.sessionId);
+
+    const shown = makeRuntime();
+    await sessionsShowCommand({ name: "agent:main:experiment", store }, shown.runtime);
+    expect(shown.logs.join("\n")).toContain("auth test fails on CI");
+
+    await expect(
+      sessionsCopyCommand({ name: "main", newKey: "agent:main:experiment", store }, runtime),
+    ).rejects.toThrow("already exists");
+  });
+
+  it("imports a Claude Code session log as a new titled session", async () => {
+    const source = path.join(dir, "claude-code.jsonl");
+    fs.writeFileSync(
+      source,
+      [
+        { type: "summary", summary: "Port the parser" },
+        {
+          type: "user",
+          sessionId: "cc-1",
+          timestamp: "2026-03-01T10:00:00.000Z",
+          message: { role: "user", content: "port the parser to rust" },
+        },
+        {
+          type: "assistant",
+          sessionId: "cc-1",
+          timestamp: "2026-03-01T10:00:01.000Z",
+          message: {
+            id: "msg_1",
+            role: "assistant",
+            model: "claude-sonnet-4-5",
+            content: [{ type: "text", text: "Starting with the lexer." }],
+          },
+        },
+      ]
+        .map((line) => JSON.stringify(line))
+        .join("\n"),
+    );
+
+    const { runtime, logs } = makeRuntime();
+    await sessionsImportCommand({ file: source, store }, runtime);
+    expect(logs.join("\n")).toContain('"Port the parser" (2 messages)');
+
+    const [key, entry] =
+      Object.entries(readStore()).find(([candidate]) => candidate.includes(":import:")) ?? [];
+    expect(key).toMatch(/^agent:main:import:/);
+    expect(entry).toMatchObject({
+      title: "Port the parser",
+      modelProvider: "anthropic",
+      model: "claude-sonnet-4-5",
+      createdAt: Date.parse("2026-03-01T10:00:00.000Z"),
+    });
+
+    const shown = makeRuntime();
+    await sessionsShowCommand({ name: "Port the parser", store }, shown.runtime);
+    expect(shown.logs.join("\n")).toContain("  Starting with the lexer.");
+
+    await expect(
+      sessionsImportCommand({ file: source, store, key: "agent:main:main" }, runtime),
+    ).rejects.toThrow("already exists");
+  });
+});
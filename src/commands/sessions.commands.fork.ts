@@ -0,0 +1,104 @@
+import fs from "node:fs";
+import type { RuntimeEnv } from "../runtime.js";
+import { updateSessionStore } from "../config/sessions.js";
+import { resolveSessionTranscriptCandidates } from "../gateway/session-utils.fs.js";
+import { defaultRuntime } from "../runtime.js";
+import { branchSessionTranscript, buildForkedSessionEntry } from "../sessions/session-fork.js";
+import { parseSessionTitle } from "../sessions/session-title.js";
+import { loadSessionsStoreContext, requireSession } from "./sessions.command-shared.js";
+
+type SessionsForkOptions = {
+  name: string;
+  newKey: string;
+  title?: string;
+  rewind?: string;
+  store?: string;
+  json?: boolean;
+};
+
+export async function sessionsForkCommand(
+  opts: SessionsForkOptions,
+  runtime: RuntimeEnv = defaultRuntime,
+) {
+  let rewindTurns = 0;
+  if (opts.rewind !== undefined) {
+    rewindTurns = Number.parseInt(String(opts.rewind), 10);
+    if (!Number.isFinite(rewindTurns) || rewindTurns <= 0) {
+      runtime.error("--rewind must be a positive integer (turns)");
+      runtime.exit(1);
+      return;
+    }
+  }
+  const ctx = loadSessionsStoreContext(opts);
+  const session = requireSession(ctx, opts.name, runtime);
+  if (!session) {
+    return;
+  }
+  const newKey = opts.newKey.trim();
+  if (!newKey) {
+    runtime.error("Target session key is required.");
+    runtime.exit(1);
+    return;
+  }
+  if (ctx.store[newKey]) {
+    runtime.error(`Session "${newKey}" already exists.`);
+    runtime.exit(1);
+    return;
+  }
+  let title = session.entry.title ? `${session.entry.title} (fork)` : undefined;
+  if (opts.title !== undefined) {
+    const parsed = parseSessionTitle(opts.title);
+    if (!parsed.ok) {
+      runtime.error(parsed.error);
+      runtime.exit(1);
+      return;
+    }
+    title = parsed.title;
+  }
+
+  const { entry } = session;
+  const sourceFile = resolveSessionTranscriptCandidates(
+    entry.sessionId,
+    ctx.storePath,
+    entry.sessionFile,
+  ).find((candidate) => fs.existsSync(candidate));
+  if (!sourceFile) {
+    runtime.error(`Session "${session.key}" has no transcript to fork.`);
+    runtime.exit(1);
+    return;
+  }
+  const branch = branchSessionTranscript({ sessionFile: sourceFile, rewindTurns });
+  if (!branch.ok) {
+    runtime.error(branch.error);
+    runtime.exit(1);
+    return;
+  }
+  const fork = buildForkedSessionEntry(entry, {
+    sessionId: branch.sessionId,
+    sessionFile: branch.sessionFile,
+    title,
+    rewound: rewindTurns > 0,
+  });
+  await updateSessionStore(ctx.storePath, (store) => {
+    store[newKey] = fork;
+  });
+
+  if (opts.json) {
+    runtime.log(
+      JSON.stringify(
+        {
+          from: session.key,
+          key: newKey,
+          sessionId: branch.sessionId,
+          sessionFile: branch.sessionFile,
+          rewoundTurns: rewindTurns,
+        },
+        null,
+        2,
+      ),
+    );
+    return;
+  }
+  const rewound = rewindTurns > 0 ? ` (without the last ${rewindTurns} turn(s))` : "";
+  runtime.log(`Forked ${session.key} → ${newKey}${rewound}`);
+}
@@ -0,0 +1,183 @@
+import { CURRENT_SESSION_VERSION, SessionManager } from "@mariozechner/pi-coding-agent";
+import crypto from "node:crypto";
+import fs from "node:fs";
+import path from "node:path";
+import type { SessionEntry } from "../config/sessions.js";
+import type { RuntimeEnv } from "../runtime.js";
+import type {
+  ImportedConversation,
+  TranscriptImportFormat,
+} from "../sessions/transcript-import.js";
+import { resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { updateSessionStore } from "../config/sessions.js";
+import { defaultRuntime } from "../runtime.js";
+import { parseSessionTitle, SESSION_TITLE_MAX_LENGTH } from "../sessions/session-title.js";
+import {
+  convertClaudeCodeTranscript,
+  convertOpenAiExport,
+  detectTranscriptImportFormat,
+} from "../sessions/transcript-import.js";
+import { resolveUserPath } from "../utils.js";
+import { loadSessionsStoreContext } from "./sessions.command-shared.js";
+
+const IMPORT_FORMATS = ["auto", "claude-code", "openai"] as const;
+
+type SessionsImportOptions = {
+  file: string;
+  format?: string;
+  key?: string;
+  title?: string;
+  store?: string;
+  json?: boolean;
+};
+
+type ImportedSession = {
+  key: string;
+  sessionId: string;
+  sessionFile: string;
+  title?: string;
+  messages: number;
+};
+
+/** Writes converted messages to a fresh transcript next to the session store. */
+export function writeImportedTranscript(params: {
+  conversation: ImportedConversation;
+  sessionsDir: string;
+  sessionId: string;
+  sourceFile: string;
+}): string {
+  const sessionFile = path.join(params.sessionsDir, `${params.sessionId}.jsonl`);
+  fs.mkdirSync(params.sessionsDir, { recursive: true });
+  const header = {
+    type: "session",
+    version: CURRENT_SESSION_VERSION,
+    id: params.sessionId,
+    timestamp: new Date(params.conversation.createdAt ?? Date.now()).toISOString(),
+    cwd: process.cwd(),
+    importedFrom: params.sourceFile,
+  };
+  fs.writeFileSync(sessionFile, `${JSON.stringify(header)}\n`, { encoding: "utf-8", flag: "wx" });
+  const sessionManager = SessionManager.open(sessionFile);
+  for (const message of params.conversation.messages) {
+    sessionManager.appendMessage(message);
+  }
+  return sessionFile;
+}
+
+export async function sessionsImportCommand(
+  opts: SessionsImportOptions,
+  runtime: RuntimeEnv = defaultRuntime,
+) {
+  const format = (opts.format ?? "auto").trim().toLowerCase();
+  if (!IMPORT_FORMATS.includes(format as (typeof IMPORT_FORMATS)[number])) {
+    runtime.error(`--format must be one of: ${IMPORT_FORMATS.join(", ")}`);
+    runtime.exit(1);
+    return;
+  }
+  const sourceFile = resolveUserPath(opts.file);
+  let raw: string;
+  try {
+    raw = fs.readFileSync(sourceFile, "utf-8");
+  } catch (err) {
+    runtime.error(`Cannot read ${sourceFile}: ${String(err)}`);
+    runtime.exit(1);
+    return;
+  }
+  const resolvedFormat: TranscriptImportFormat | undefined =
+    format === "auto" ? detectTranscriptImportFormat(raw) : (format as TranscriptImportFormat);
+  if (!resolvedFormat) {
+    runtime.error(
+      "Unrecognized file. Pass --format claude-code (JSONL session log) or --format openai (ChatGPT conversations.json).",
+    );
+    runtime.exit(1);
+    return;
+  }
+
+  let conversations: ImportedConversation[];
+  try {
+    conversations =
+      resolvedFormat === "openai"
+        ? convertOpenAiExport(raw)
+        : [convertClaudeCodeTranscript(raw)].filter((item) => item.messages.length > 0);
+  } catch (err) {
+    runtime.error(`Failed to parse ${sourceFile} as ${resolvedFormat}: ${String(err)}`);
+    runtime.exit(1);
+    return;
+  }
+  if (conversations.length === 0) {
+    runtime.error(`No messages found in ${sourceFile}.`);
+    runtime.exit(1);
+    return;
+  }
+  if (conversations.length > 1 && (opts.key || opts.title)) {
+    runtime.error(
+      `${sourceFile} holds ${conversations.length} conversations; --key and --title need a single one.`,
+    );
+    runtime.exit(1);
+    return;
+  }
+
+  let titleOverride: string | undefined;
+  if (opts.title !== undefined) {
+    const parsed = parseSessionTitle(opts.title);
+    if (!parsed.ok) {
+      runtime.error(parsed.error);
+      runtime.exit(1);
+      return;
+    }
+    titleOverride = parsed.title;
+  }
+
+  const ctx = loadSessionsStoreContext(opts);
+  const keyOverride = opts.key?.trim();
+  if (keyOverride && ctx.store[keyOverride]) {
+    runtime.error(`Session "${keyOverride}" already exists.`);
+    runtime.exit(1);
+    return;
+  }
+
+  const agentId = resolveDefaultAgentId(ctx.cfg);
+  const sessionsDir = path.dirname(ctx.storePath);
+  const imported: ImportedSession[] = [];
+  const entries: Record<string, SessionEntry> = {};
+  for (const conversation of conversations) {
+    const sessionId = crypto.randomUUID();
+    const key = keyOverride || `agent:${agentId}:import:${sessionId.slice(0, 8)}`;
+    const sessionFile = writeImportedTranscript({
+      conversation,
+      sessionsDir,
+      sessionId,
+      sourceFile,
+    });
+    // Source titles are clipped rather than rejected; they were never written for this limit.
+    const parsedTitle = conversation.title
+      ? parseSessionTitle(conversation.title.trim().slice(0, SESSION_TITLE_MAX_LENGTH))
+      : undefined;
+    const title = titleOverride ?? (parsedTitle?.ok ? parsedTitle.title : undefined);
+    const now = Date.now();
+    entries[key] = {
+      sessionId,
+      sessionFile,
+      createdAt: conversation.createdAt ?? now,
+      updatedAt: now,
+      chatType: "direct",
+      title,
+      titleSource: title ? "user" : undefined,
+      modelProvider: conversation.model ? conversation.provider : undefined,
+      model: conversation.model,
+    };
+    imported.push({ key, sessionId, sessionFile, title, messages: conversation.messages.length });
+  }
+  await updateSessionStore(ctx.storePath, (store) => {
+    Object.assign(store, entries);
+  });
+
+  if (opts.json) {
+    runtime.log(JSON.stringify({ format: resolvedFormat, imported }, null, 2));
+    return;
+  }
+  for (const session of imported) {
+    const title = session.title ? ` "${session.title}"` : "";
+    runtime.log(`Imported ${session.key}${title} (${session.messages} messages)`);
+  }
+}
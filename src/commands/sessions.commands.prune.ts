@@ -0,0 +1,121 @@
+import type { SessionTranscriptRetention } from "../config/types.base.js";
+import type { RuntimeEnv } from "../runtime.js";
+import { parseDurationMs } from "../cli/parse-duration.js";
+import { loadConfig } from "../config/config.js";
+import { loadSessionStore, resolveMaintenanceConfig } from "../config/sessions.js";
+import { formatTimeAgo } from "../infra/format-time/format-relative.ts";
+import { defaultRuntime } from "../runtime.js";
+import {
+  applySessionPrune,
+  planSessionPrune,
+  resolveProtectedSessionKeys,
+  type SessionPrunePlan,
+  type SessionPruneResult,
+} from "../sessions/session-prune.js";
+import { resolveSessionsScope } from "./sessions.command-shared.js";
+
+const TRANSCRIPT_RETENTIONS: SessionTranscriptRetention[] = ["archive", "delete", "keep"];
+
+type SessionsPruneOptions = {
+  olderThan?: string;
+  maxEntries?: string;
+  transcripts?: string;
+  dryRun?: boolean;
+  store?: string;
+  global?: boolean;
+  json?: boolean;
+};
+
+/**
+ * Runs the `session.maintenance` prune pass on demand, whatever the configured mode. Flags
+ * override the configured age, count, and transcript handling for this run only.
+ */
+export async function sessionsPruneCommand(
+  opts: SessionsPruneOptions,
+  runtime: RuntimeEnv = defaultRuntime,
+) {
+  const maintenance = resolveMaintenanceConfig();
+  let pruneAfterMs = maintenance.pruneAfterMs;
+  if (opts.olderThan !== undefined) {
+    try {
+      pruneAfterMs = parseDurationMs(opts.olderThan, { defaultUnit: "d" });
+    } catch {
+      runtime.error("--older-than must be a duration like 30d, 12h, or 90m");
+      runtime.exit(1);
+      return;
+    }
+  }
+  let maxEntries = maintenance.maxEntries;
+  if (opts.maxEntries !== undefined) {
+    maxEntries = Number.parseInt(String(opts.maxEntries), 10);
+    if (!Number.isFinite(maxEntries) || maxEntries <= 0) {
+      runtime.error("--max-entries must be a positive integer");
+      runtime.exit(1);
+      return;
+    }
+  }
+  let transcripts = maintenance.transcripts;
+  if (opts.transcripts !== undefined) {
+    if (!TRANSCRIPT_RETENTIONS.includes(opts.transcripts as SessionTranscriptRetention)) {
+      runtime.error(`--transcripts must be one of: ${TRANSCRIPT_RETENTIONS.join(", ")}`);
+      runtime.exit(1);
+      return;
+    }
+    transcripts = opts.transcripts as SessionTranscriptRetention;
+  }
+
+  const cfg = loadConfig();
+  const scope = resolveSessionsScope(cfg, { store: opts.store, global: opts.global });
+  const protectedKeys = resolveProtectedSessionKeys(cfg);
+  const plans: SessionPrunePlan[] = scope.storePaths.map((storePath) =>
+    planSessionPrune({
+      storePath,
+      store: loadSessionStore(storePath, { skipCache: true }),
+      pruneAfterMs,
+      maxEntries,
+      protectedKeys,
+    }),
+  );
+
+  if (opts.dryRun) {
+    if (opts.json) {
+      runtime.log(JSON.stringify({ dryRun: true, transcripts, stores: plans }, null, 2));
+      return;
+    }
+    for (const plan of plans) {
+      const files =
+        plan.sessions.reduce((sum, session) => sum + session.transcripts.length, 0) +
+        plan.orphanTranscripts.length;
+      runtime.log(
+        `${plan.storePath}: would remove ${plan.sessions.length} session(s) and ${transcripts} ` +
+          `${files} transcript file(s).`,
+      );
+      for (const session of plan.sessions) {
+        const age = session.updatedAt ? formatTimeAgo(Date.now() - session.updatedAt) : "unknown";
+        runtime.log(`  ${session.key} (${age})`);
+      }
+      for (const file of plan.orphanTranscripts) {
+        runtime.log(`  ${file} (no session)`);
+      }
+    }
+    return;
+  }
+
+  const results: SessionPruneResult[] = [];
+  for (const plan of plans) {
+    results.push(await applySessionPrune(plan, transcripts));
+  }
+  if (opts.json) {
+    runtime.log(JSON.stringify({ dryRun: false, transcripts, stores: results }, null, 2));
+    return;
+  }
+  for (const result of results) {
+    const handled =
+      transcripts === "delete"
+        ? `deleted ${result.deleted.length} transcript file(s)`
+        : transcripts === "archive"
+          ? `archived ${result.archived.length} transcript file(s)`
+          : "kept transcripts";
+    runtime.log(`${result.storePath}: removed ${result.removed.length} session(s), ${handled}.`);
+  }
+}
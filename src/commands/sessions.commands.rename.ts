@@ -0,0 +1,43 @@
+import type { RuntimeEnv } from "../runtime.js";
+import { updateSessionStore } from "../config/sessions.js";
+import { defaultRuntime } from "../runtime.js";
+import { parseSessionTitle } from "../sessions/session-title.js";
+import { loadSessionsStoreContext, requireSession } from "./sessions.command-shared.js";
+
+type SessionsRenameOptions = {
+  name: string;
+  title: string;
+  store?: string;
+  json?: boolean;
+};
+
+/** Sets the session title; the session key stays the same so routing is unaffected. */
+export async function sessionsRenameCommand(
+  opts: SessionsRenameOptions,
+  runtime: RuntimeEnv = defaultRuntime,
+) {
+  const ctx = loadSessionsStoreContext(opts);
+  const session = requireSession(ctx, opts.name, runtime);
+  if (!session) {
+    return;
+  }
+  const parsed = parseSessionTitle(opts.title);
+  if (!parsed.ok) {
+    runtime.error(parsed.error);
+    runtime.exit(1);
+    return;
+  }
+  await updateSessionStore(ctx.storePath, (store) => {
+    const entry = store[session.key];
+    if (entry) {
+      entry.title = parsed.title;
+      entry.titleSource = "user";
+      entry.updatedAt = Date.now();
+    }
+  });
+  if (opts.json) {
+    runtime.log(JSON.stringify({ key: session.key, title: parsed.title }, null, 2));
+    return;
+  }
+  runtime.log(`Renamed ${session.key}: ${parsed.title}`);
+}
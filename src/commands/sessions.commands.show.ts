@@ -0,0 +1,199 @@
+import fs from "node:fs";
+import type { SessionEntry } from "../config/sessions.js";
+import type { RuntimeEnv } from "../runtime.js";
+import {
+  readSessionMessages,
+  resolveSessionTranscriptCandidates,
+} from "../gateway/session-utils.fs.js";
+import { formatUtcTimestamp, formatZonedTimestamp } from "../infra/format-time/format-datetime.ts";
+import { formatTimeAgo } from "../infra/format-time/format-relative.ts";
+import { defaultRuntime } from "../runtime.js";
+import { isRich, theme } from "../terminal/theme.js";
+import { formatTokenCount, formatUsd } from "../utils/usage-format.js";
+import { loadSessionsStoreContext, requireSession } from "./sessions.command-shared.js";
+
+const TOOL_TEXT_PREVIEW_CHARS = 400;
+const TOOL_ARGS_PREVIEW_CHARS = 160;
+
+type SessionsShowOptions = {
+  name: string;
+  store?: string;
+  json?: boolean;
+  limit?: string;
+  full?: boolean;
+};
+
+type TranscriptBlock = {
+  type?: unknown;
+  text?: unknown;
+  thinking?: unknown;
+  name?: unknown;
+  arguments?: unknown;
+};
+
+type TranscriptMessage = {
+  role?: unknown;
+  content?: unknown;
+  timestamp?: unknown;
+  toolName?: unknown;
+  isError?: unknown;
+  __openclaw?: { kind?: unknown };
+};
+
+function clip(text: string, max: number, full: boolean): string {
+  if (full || text.length <= max) {
+    return text;
+  }
+  return `${text.slice(0, max)}… (+${text.length - max} chars)`;
+}
+
+function indent(text: string): string {
+  return text
+    .split("\n")
+    .map((line) => `  ${line}`)
+    .join("\n");
+}
+
+function formatWhen(timestamp: unknown): string {
+  const ms =
+    typeof timestamp === "number"
+      ? timestamp
+      : typeof timestamp === "string"
+        ? Date.parse(timestamp)
+        : Number.NaN;
+  if (!Number.isFinite(ms)) {
+    return "";
+  }
+  const date = new Date(ms);
+  return formatZonedTimestamp(date) ?? formatUtcTimestamp(date);
+}
+
+function blocksOf(message: TranscriptMessage): TranscriptBlock[] {
+  if (typeof message.content === "string") {
+    return [{ type: "text", text: message.content }];
+  }
+  return Array.isArray(message.content) ? (message.content as TranscriptBlock[]) : [];
+}
+
+/** Renders one transcript message as a heading line plus indented body. */
+export function formatTranscriptMessage(
+  raw: unknown,
+  opts: { full?: boolean; rich?: boolean } = {},
+): string {
+  const message = (raw ?? {}) as TranscriptMessage;
+  const full = opts.full === true;
+  const rich = opts.rich === true;
+  const role = typeof message.role === "string" ? message.role : "unknown";
+  const when = formatWhen(message.timestamp);
+  if (message.__openclaw?.kind === "compaction") {
+    const label = when ? `── compacted ${when} ──` : "── compacted ──";
+    return rich ? theme.muted(label) : label;
+  }
+
+  const tool = typeof message.toolName === "string" ? ` ${message.toolName}` : "";
+  const error = message.isError === true ? " (error)" : "";
+  const headingRaw = `${when ? `[${when}] ` : ""}${role}${tool}${error}`;
+  const heading = rich
+    ? role === "user"
+      ? theme.accent(headingRaw)
+      : role === "assistant"
+        ? theme.success(headingRaw)
+        : theme.muted(headingRaw)
+    : headingRaw;
+
+  const body: string[] = [];
+  for (const block of blocksOf(message)) {
+    if (!block || typeof block !== "object") {
+      continue;
+    }
+    if (block.type === "text" && typeof block.text === "string" && block.text.trim()) {
+      const text = block.text.trim();
+      body.push(role === "toolResult" ? clip(text, TOOL_TEXT_PREVIEW_CHARS, full) : text);
+    } else if (block.type === "thinking" && typeof block.thinking === "string") {
+      const thinking = `(thinking) ${clip(block.thinking.trim(), TOOL_TEXT_PREVIEW_CHARS, full)}`;
+      body.push(rich ? theme.muted(thinking) : thinking);
+    } else if (block.type === "toolCall") {
+      const name = typeof block.name === "string" ? block.name : "tool";
+      const args = clip(JSON.stringify(block.arguments ?? {}), TOOL_ARGS_PREVIEW_CHARS, full);
+      body.push(`→ ${name} ${args}`);
+    } else if (block.type === "image") {
+      body.push("[image]");
+    }
+  }
+  return body.length > 0 ? `${heading}\n${indent(body.join("\n"))}` : heading;
+}
+
+function formatHeader(key: string, entry: SessionEntry, transcript: string | undefined): string[] {
+  const lines = [`Session: ${key}`];
+  if (entry.title) {
+    lines.push(`Title: ${entry.title}`);
+  }
+  if (entry.tags?.length) {
+    lines.push(`Tags: ${entry.tags.map((tag) => `#${tag}`).join(" ")}`);
+  }
+  const times = [
+    entry.createdAt ? `created ${formatTimeAgo(Date.now() - entry.createdAt)}` : null,
+    entry.updatedAt ? `updated ${formatTimeAgo(Date.now() - entry.updatedAt)}` : null,
+  ].filter(Boolean);
+  lines.push(`Id: ${entry.sessionId}${times.length ? ` · ${times.join(" · ")}` : ""}`);
+  if (entry.model) {
+    lines.push(`Model: ${entry.modelProvider ? `${entry.modelProvider}/` : ""}${entry.model}`);
+  }
+  const totals = entry.usageTotals;
+  if (totals) {
+    const cost = formatUsd(totals.costUsd);
+    const tokens = formatTokenCount(totals.totalTokens);
+    const usage = `Usage: ${tokens} tokens over ${totals.runs} run(s)`;
+    lines.push(cost ? `${usage} · ${cost}` : usage);
+  }
+  lines.push(`Transcript: ${transcript ?? "(missing)"}`);
+  return lines;
+}
+
+export async function sessionsShowCommand(
+  opts: SessionsShowOptions,
+  runtime: RuntimeEnv = defaultRuntime,
+) {
+  const ctx = loadSessionsStoreContext(opts);
+  const session = requireSession(ctx, opts.name, runtime);
+  if (!session) {
+    return;
+  }
+  let limit: number | undefined;
+  if (opts.limit !== undefined) {
+    limit = Number.parseInt(String(opts.limit), 10);
+    if (!Number.isFinite(limit) || limit <= 0) {
+      runtime.error("--limit must be a positive integer");
+      runtime.exit(1);
+      return;
+    }
+  }
+
+  const { key, entry } = session;
+  const all = readSessionMessages(entry.sessionId, ctx.storePath, entry.sessionFile);
+  const messages = limit ? all.slice(-limit) : all;
+  const transcript = resolveSessionTranscriptCandidates(
+    entry.sessionId,
+    ctx.storePath,
+    entry.sessionFile,
+  ).find((candidate) => fs.existsSync(candidate));
+
+  if (opts.json) {
+    runtime.log(JSON.stringify({ key, entry, transcript: transcript ?? null, messages }, null, 2));
+    return;
+  }
+
+  const rich = isRich();
+  const header = formatHeader(key, entry, transcript);
+  runtime.log(rich ? header.map((line) => theme.heading(line)).join("\n") : header.join("\n"));
+  if (messages.length === 0) {
+    runtime.log("\nNo messages.");
+    return;
+  }
+  if (messages.length < all.length) {
+    runtime.log(`\n(showing last ${messages.length} of ${all.length} messages)`);
+  }
+  for (const message of messages) {
+    runtime.log(`\n${formatTranscriptMessage(message, { full: opts.full, rich })}`);
+  }
+}
@@ -86,6 +86,57 @@ describe("sessionsCommand", () => {
     expect(row).toContain("pi:opus");
   });
 
+  it("shows cumulative session cost from persisted usage totals", async () => {
+    const store = writeStore({
+      "+15555550123": {
+        sessionId: "abc123",
+        updatedAt: Date.now() - 60_000,
+        usageTotals: {
+          input: 40_000,
+          output: 2_000,
+          cacheRead: 0,
+          cacheWrite: 0,
+          totalTokens: 42_000,
+          costUsd: 1.234,
+          runs: 3,
+        },
+      },
+      "+15555550999": {
+        sessionId: "legacy",
+        updatedAt: Date.now() - 120_000,
+      },
+    });
+
+    const { runtime, logs } = makeRuntime();
+    await sessionsCommand({ store }, runtime);
+
+    fs.rmSync(store);
+
+    expect(logs.find((line) => line.includes("Tokens (ctx %"))).toContain("Cost");
+    expect(logs.find((line) => line.includes("+15555550123"))).toContain("$1.23");
+    expect(logs.find((line) => line.includes("+15555550999"))).not.toContain("$");
+  });
+
+  it("shows session titles and tags", async () => {
+    const store = writeStore({
+      "agent:main:main": {
+        sessionId: "abc123",
+        updatedAt: Date.now() - 60_000,
+        title: "Fix flaky auth test",
+        tags: ["ci", "auth"],
+      },
+    });
+
+    const { runtime, logs } = makeRuntime();
+    await sessionsCommand({ store }, runtime);
+
+    fs.rmSync(store);
+
+    const row = logs.find((line) => line.includes("agent:main:main")) ?? "";
+    expect(row).toContain("Fix flaky auth test");
+    expect(row).toContain("#ci #auth");
+  });
+
   it("shows placeholder rows when tokens are missing", async () => {
     const store = writeStore({
       "discord:group:demo": {
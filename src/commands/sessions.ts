@@ -4,14 +4,15 @@ import { DEFAULT_CONTEXT_TOKENS, DEFAULT_MODEL, DEFAULT_PROVIDER } from "../agen
 import { resolveConfiguredModelRef } from "../agents/model-selection.js";
 import { loadConfig } from "../config/config.js";
 import {
-  loadSessionStore,
   resolveFreshSessionTotalTokens,
-  resolveStorePath,
   type SessionEntry,
+  type SessionUsageTotals,
 } from "../config/sessions.js";
 import { info } from "../globals.js";
 import { formatTimeAgo } from "../infra/format-time/format-relative.ts";
 import { isRich, theme } from "../terminal/theme.js";
+import { formatUsd } from "../utils/usage-format.js";
+import { loadScopedSessionStore, resolveSessionsScope } from "./sessions.command-shared.js";
 
 type SessionRow = {
   key: string;
@@ -19,6 +20,8 @@ type SessionRow = {
   updatedAt: number | null;
   ageMs: number | null;
   sessionId?: string;
+  title?: string;
+  tags?: string[];
   systemSent?: boolean;
   abortedLastRun?: boolean;
   thinkingLevel?: string;
@@ -33,13 +36,17 @@ type SessionRow = {
   totalTokensFresh?: boolean;
   model?: string;
   contextTokens?: number;
+  createdAt?: number;
+  usageTotals?: SessionUsageTotals;
 };
 
 const KIND_PAD = 6;
 const KEY_PAD = 26;
+const TITLE_PAD = 24;
 const AGE_PAD = 9;
 const MODEL_PAD = 14;
 const TOKENS_PAD = 20;
+const COST_PAD = 9;
 
 const formatKTokens = (value: number) => `${(value / 1000).toFixed(value >= 10_000 ? 0 : 1)}k`;
 
@@ -51,6 +58,14 @@ const truncateKey = (key: string) => {
   return `${key.slice(0, head)}...${key.slice(-6)}`;
 };
 
+const formatTitleCell = (title: string | undefined, rich: boolean) => {
+  const raw = title ?? "-";
+  const label = (raw.length > TITLE_PAD ? `${raw.slice(0, TITLE_PAD - 1)}…` : raw).padEnd(
+    TITLE_PAD,
+  );
+  return rich ? (title ? theme.info(label) : theme.muted(label)) : label;
+};
+
 const colorByPct = (label: string, pct: number | null, rich: boolean) => {
   if (!rich || pct === null) {
     return label;
@@ -85,6 +100,12 @@ const formatTokensCell = (
   return colorByPct(padded, pct, rich);
 };
 
+const formatCostCell = (totals: SessionUsageTotals | undefined, rich: boolean) => {
+  const cost = formatUsd(totals?.costUsd);
+  const label = (cost ? `${cost}${totals?.runsWithoutCost ? "+" : ""}` : "-").padEnd(COST_PAD);
+  return rich ? theme.muted(label) : label;
+};
+
 const formatKindCell = (kind: SessionRow["kind"], rich: boolean) => {
   const label = kind.padEnd(KIND_PAD);
   if (!rich) {
@@ -121,6 +142,7 @@ const formatFlagsCell = (row: SessionRow, rich: boolean) => {
     row.elevatedLevel ? `elev:${row.elevatedLevel}` : null,
     row.responseUsage ? `usage:${row.responseUsage}` : null,
     row.groupActivation ? `activation:${row.groupActivation}` : null,
+    ...(row.tags ?? []).map((tag) => `#${tag}`),
     row.systemSent ? "system" : null,
     row.abortedLastRun ? "aborted" : null,
     row.sessionId ? `id:${row.sessionId}` : null,
@@ -155,6 +177,8 @@ function toRows(store: Record<string, SessionEntry>): SessionRow[] {
         updatedAt,
         ageMs: updatedAt ? Date.now() - updatedAt : null,
         sessionId: entry?.sessionId,
+        title: entry?.title,
+        tags: entry?.tags,
         systemSent: entry?.systemSent,
         abortedLastRun: entry?.abortedLastRun,
         thinkingLevel: entry?.thinkingLevel,
@@ -169,13 +193,15 @@ function toRows(store: Record<string, SessionEntry>): SessionRow[] {
         totalTokensFresh: entry?.totalTokensFresh,
         model: entry?.model,
         contextTokens: entry?.contextTokens,
+        createdAt: entry?.createdAt,
+        usageTotals: entry?.usageTotals,
       } satisfies SessionRow;
     })
     .toSorted((a, b) => (b.updatedAt ?? 0) - (a.updatedAt ?? 0));
 }
 
 export async function sessionsCommand(
-  opts: { json?: boolean; store?: string; active?: string },
+  opts: { json?: boolean; store?: string; active?: string; global?: boolean },
   runtime: RuntimeEnv,
 ) {
   const cfg = loadConfig();
@@ -189,8 +215,9 @@ export async function sessionsCommand(
     lookupContextTokens(resolved.model) ??
     DEFAULT_CONTEXT_TOKENS;
   const configModel = resolved.model ?? DEFAULT_MODEL;
-  const storePath = resolveStorePath(opts.store ?? cfg.session?.store);
-  const store = loadSessionStore(storePath);
+  const scope = resolveSessionsScope(cfg, { store: opts.store, global: opts.global });
+  const storePath = scope.storePaths[0];
+  const store = loadScopedSessionStore(scope);
 
   let activeMinutes: number | undefined;
   if (opts.active !== undefined) {
@@ -218,6 +245,8 @@ export async function sessionsCommand(
       JSON.stringify(
         {
           path: storePath,
+          ...(scope.storePaths.length > 1 ? { paths: scope.storePaths } : {}),
+          agentId: scope.agentId ?? null,
           count: rows.length,
           activeMinutes: activeMinutes ?? null,
           sessions: rows.map((r) => ({
@@ -237,7 +266,12 @@ export async function sessionsCommand(
     return;
   }
 
-  runtime.log(info(`Session store: ${storePath}`));
+  for (const storeFile of scope.storePaths) {
+    runtime.log(info(`Session store: ${storeFile}`));
+  }
+  if (scope.fromWorkspace) {
+    runtime.log(info(`Agent: ${scope.agentId} (from workspace; --global lists every agent)`));
+  }
   runtime.log(info(`Sessions listed: ${rows.length}`));
   if (activeMinutes) {
     runtime.log(info(`Filtered to last ${activeMinutes} minute(s)`));
@@ -251,9 +285,11 @@ export async function sessionsCommand(
   const header = [
     "Kind".padEnd(KIND_PAD),
     "Key".padEnd(KEY_PAD),
+    "Title".padEnd(TITLE_PAD),
     "Age".padEnd(AGE_PAD),
     "Model".padEnd(MODEL_PAD),
     "Tokens (ctx %)".padEnd(TOKENS_PAD),
+    "Cost".padEnd(COST_PAD),
     "Flags",
   ].join(" ");
 
@@ -270,9 +306,11 @@ export async function sessionsCommand(
     const line = [
       formatKindCell(row.kind, rich),
       keyCell,
+      formatTitleCell(row.title, rich),
       formatAgeCell(row.updatedAt, rich),
       formatModelCell(model, rich),
       formatTokensCell(total, contextTokens ?? null, rich),
+      formatCostCell(row.usageTotals, rich),
       formatFlagsCell(row, rich),
     ].join(" ");
 
@@ -0,0 +1,53 @@
+import type { RuntimeEnv } from "../runtime.js";
+import { formatRelativeTimestamp } from "../infra/format-time/format-relative.ts";
+import { defaultRuntime } from "../runtime.js";
+import {
+  clearTuiHistory,
+  loadTuiHistory,
+  resolveTuiHistoryPath,
+} from "../tui/tui-history-store.js";
+
+type TuiHistoryOptions = {
+  limit?: string;
+  session?: string;
+  clear?: boolean;
+  json?: boolean;
+};
+
+/** Lists (newest last) or clears the TUI input history shared by every session. */
+export async function tuiHistoryCommand(
+  opts: TuiHistoryOptions,
+  runtime: RuntimeEnv = defaultRuntime,
+) {
+  const filePath = resolveTuiHistoryPath();
+  if (opts.clear) {
+    const removed = await clearTuiHistory(filePath);
+    if (opts.json) {
+      runtime.log(JSON.stringify({ path: filePath, cleared: removed }, null, 2));
+      return;
+    }
+    runtime.log(`Cleared ${removed} history entr${removed === 1 ? "y" : "ies"} (${filePath}).`);
+    return;
+  }
+
+  const limit = opts.limit === undefined ? 20 : Number.parseInt(String(opts.limit), 10);
+  if (!Number.isFinite(limit) || limit <= 0) {
+    runtime.error("--limit must be a positive integer");
+    runtime.exit(1);
+    return;
+  }
+  const entries = await loadTuiHistory(filePath, { limit, session: opts.session?.trim() });
+  if (opts.json) {
+    runtime.log(JSON.stringify({ path: filePath, entries }, null, 2));
+    return;
+  }
+  if (entries.length === 0) {
+    runtime.log(`No TUI history yet (${filePath}).`);
+    return;
+  }
+  for (const entry of entries) {
+    const when = formatRelativeTimestamp(entry.ts, { dateFallback: true });
+    const text = entry.text.replace(/\s+/g, " ").trim();
+    runtime.log(`${when}  ${entry.session ?? "-"}  ${text}`);
+  }
+}
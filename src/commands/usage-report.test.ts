@@ -0,0 +1,21 @@
+import { describe, expect, it } from "vitest";
+import { resolveUsageReportStart } from "./usage-report.js";
+
+describe("resolveUsageReportStart", () => {
+  const now = Date.parse("2026-10-16T12:00:00Z");
+
+  it("treats durations as time back from now", () => {
+    expect(resolveUsageReportStart("7d", now)).toBe(now - 7 * 86_400_000);
+    expect(resolveUsageReportStart("12h", now)).toBe(now - 12 * 3_600_000);
+  });
+
+  it("accepts dates and timestamps", () => {
+    expect(resolveUsageReportStart("2026-10-01T00:00:00Z", now)).toBe(
+      Date.parse("2026-10-01T00:00:00Z"),
+    );
+  });
+
+  it("rejects anything else", () => {
+    expect(() => resolveUsageReportStart("last week", now)).toThrow(/--since/);
+  });
+});
@@ -0,0 +1,116 @@
+import type { RuntimeEnv } from "../runtime.js";
+import { listAgentIds, resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { parseDurationMs } from "../cli/parse-duration.js";
+import { loadConfig } from "../config/config.js";
+import {
+  type CostUsageTotals,
+  loadUsageReport,
+  type UsageReport,
+  type UsageReportGroupBy,
+} from "../infra/session-cost-usage.js";
+import { normalizeAgentId } from "../routing/session-key.js";
+import { renderTable } from "../terminal/table.js";
+import { theme } from "../terminal/theme.js";
+import { formatTokenCount, formatUsd } from "../utils/usage-format.js";
+
+const GROUP_BY: readonly UsageReportGroupBy[] = ["model", "day", "session"];
+
+export type UsageReportOpts = {
+  since?: string;
+  by?: string;
+  agent?: string;
+  json?: boolean;
+};
+
+/** `--since` takes a duration back from now (`7d`, `12h`) or a date/time (`2026-10-01`). */
+export function resolveUsageReportStart(raw: string, nowMs: number): number {
+  const trimmed = raw.trim();
+  if (/^\d+(?:\.\d+)?(?:ms|s|m|h|d)$/i.test(trimmed)) {
+    return nowMs - parseDurationMs(trimmed);
+  }
+  const parsed = Date.parse(trimmed);
+  if (Number.isNaN(parsed)) {
+    throw new Error(`--since must be a duration like 7d or a date like 2026-10-01 (got ${raw})`);
+  }
+  return parsed;
+}
+
+function parseGroupBy(raw?: string): UsageReportGroupBy {
+  const value = (raw ?? "model").trim().toLowerCase();
+  if (!(GROUP_BY as readonly string[]).includes(value)) {
+    throw new Error(`--by must be one of: ${GROUP_BY.join(", ")}`);
+  }
+  return value as UsageReportGroupBy;
+}
+
+function formatCost(totals: CostUsageTotals): string {
+  const cost = formatUsd(totals.totalCost) ?? "$0.00";
+  return totals.missingCostEntries > 0 ? `${cost}*` : cost;
+}
+
+function toRow(key: string, totals: CostUsageTotals): Record<string, string> {
+  return {
+    Key: key,
+    Input: formatTokenCount(totals.input),
+    Output: formatTokenCount(totals.output),
+    Cache: formatTokenCount(totals.cacheRead + totals.cacheWrite),
+    Total: formatTokenCount(totals.totalTokens),
+    Cost: formatCost(totals),
+  };
+}
+
+export function formatUsageReport(report: UsageReport, width: number): string {
+  const header = { model: "Model", day: "Day", session: "Session" }[report.groupBy];
+  const since = new Date(report.startMs).toLocaleString();
+  const lines = [theme.heading(`Usage since ${since} by ${report.groupBy}`)];
+  if (report.rows.length === 0) {
+    lines.push(theme.muted("No usage recorded in this period."));
+    return lines.join("\n");
+  }
+  lines.push(
+    renderTable({
+      width,
+      columns: [
+        { key: "Key", header, flex: true, minWidth: 12 },
+        { key: "Input", header: "Input", align: "right" },
+        { key: "Output", header: "Output", align: "right" },
+        { key: "Cache", header: "Cache", align: "right" },
+        { key: "Total", header: "Total", align: "right" },
+        { key: "Cost", header: "Cost", align: "right" },
+      ],
+      rows: [
+        ...report.rows.map((row) => toRow(row.key, row)),
+        toRow("Total", report.totals),
+      ],
+    }).trimEnd(),
+  );
+  if (report.totals.missingCostEntries > 0) {
+    lines.push(
+      theme.muted(
+        `* ${report.totals.missingCostEntries} response(s) had no cost data (no pricing for the model).`,
+      ),
+    );
+  }
+  return lines.join("\n");
+}
+
+/** `openclaw usage report`: token and cost totals from the agent's session transcripts. */
+export async function usageReportCommand(opts: UsageReportOpts, runtime: RuntimeEnv) {
+  const cfg = loadConfig();
+  const groupBy = parseGroupBy(opts.by);
+  const now = Date.now();
+  const startMs = resolveUsageReportStart(opts.since ?? "7d", now);
+
+  const agentIdRaw = opts.agent?.trim();
+  const agentId = agentIdRaw ? normalizeAgentId(agentIdRaw) : resolveDefaultAgentId(cfg);
+  if (agentIdRaw && !listAgentIds(cfg).includes(agentId)) {
+    throw new Error(`Unknown agent id "${agentIdRaw}".`);
+  }
+
+  const report = await loadUsageReport({ startMs, endMs: now, groupBy, config: cfg, agentId });
+  if (opts.json) {
+    runtime.log(JSON.stringify({ agentId, ...report }, null, 2));
+    return;
+  }
+  runtime.log(formatUsageReport(report, Math.max(60, (process.stdout.columns ?? 120) - 1)));
+}
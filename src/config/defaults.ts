@@ -1,5 +1,5 @@
 import type { OpenClawConfig } from "./types.js";
-import type { ModelDefinitionConfig } from "./types.models.js";
+import type { ModelApi, ModelDefinitionConfig } from "./types.models.js";
 import { DEFAULT_CONTEXT_TOKENS } from "../agents/defaults.js";
 import { parseModelRef } from "../agents/model-selection.js";
 import { DEFAULT_AGENT_MAX_CONCURRENT, DEFAULT_SUBAGENT_MAX_CONCURRENT } from "./agent-limits.js";
@@ -41,15 +41,31 @@ function isPositiveNumber(value: unknown): value is number {
   return typeof value === "number" && Number.isFinite(value) && value > 0;
 }
 
+// Anthropic prompt caching: cache writes cost 1.25x the input price and cache reads 0.1x.
+const ANTHROPIC_CACHE_WRITE_MULTIPLIER = 1.25;
+const ANTHROPIC_CACHE_READ_MULTIPLIER = 0.1;
+
+/**
+ * Fills missing cost fields. Unset cache prices are derived from the input price rather than left
+ * at 0 (which would make cached tokens free): Anthropic-style APIs use their cache multipliers,
+ * everything else prices cached tokens like regular input.
+ */
 function resolveModelCost(
   raw?: Partial<ModelDefinitionConfig["cost"]>,
+  api?: ModelApi,
 ): ModelDefinitionConfig["cost"] {
+  const input = typeof raw?.input === "number" ? raw.input : DEFAULT_MODEL_COST.input;
+  const anthropic = api === "anthropic-messages";
+  const derive = (multiplier: number) => Number((input * (anthropic ? multiplier : 1)).toFixed(6));
   return {
-    input: typeof raw?.input === "number" ? raw.input : DEFAULT_MODEL_COST.input,
+    input,
     output: typeof raw?.output === "number" ? raw.output : DEFAULT_MODEL_COST.output,
-    cacheRead: typeof raw?.cacheRead === "number" ? raw.cacheRead : DEFAULT_MODEL_COST.cacheRead,
+    cacheRead:
+      typeof raw?.cacheRead === "number" ? raw.cacheRead : derive(ANTHROPIC_CACHE_READ_MULTIPLIER),
     cacheWrite:
-      typeof raw?.cacheWrite === "number" ? raw.cacheWrite : DEFAULT_MODEL_COST.cacheWrite,
+      typeof raw?.cacheWrite === "number"
+        ? raw.cacheWrite
+        : derive(ANTHROPIC_CACHE_WRITE_MULTIPLIER),
   };
 }
 
@@ -196,7 +212,11 @@ export function applyModelDefaults(cfg: OpenClawConfig): OpenClawConfig {
           modelMutated = true;
         }
 
-        const cost = resolveModelCost(raw.cost);
+        const api =
+          raw.api ??
+          provider.api ??
+          (providerId === "anthropic" ? ("anthropic-messages" as const) : undefined);
+        const cost = resolveModelCost(raw.cost, api);
         const costMutated =
           !raw.cost ||
           raw.cost.input !== cost.input ||
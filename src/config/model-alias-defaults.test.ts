@@ -81,6 +81,41 @@ describe("applyModelDefaults", () => {
     expect(model?.maxTokens).toBe(8192);
   });
 
+  it("derives missing cache prices from the input price", () => {
+    const cfg = {
+      models: {
+        providers: {
+          anthropic: {
+            baseUrl: "https://api.anthropic.com",
+            models: [{ id: "claude-sonnet-4-5", name: "Sonnet", cost: { input: 3, output: 15 } }],
+          },
+          myproxy: {
+            baseUrl: "https://proxy.example/v1",
+            api: "openai-completions",
+            models: [
+              { id: "gpt-5.2", name: "GPT-5.2", cost: { input: 2, output: 8, cacheRead: 0.2 } },
+            ],
+          },
+        },
+      },
+    } as unknown as OpenClawConfig;
+
+    const next = applyModelDefaults(cfg);
+
+    expect(next.models?.providers?.anthropic?.models?.[0]?.cost).toEqual({
+      input: 3,
+      output: 15,
+      cacheRead: 0.3,
+      cacheWrite: 3.75,
+    });
+    expect(next.models?.providers?.myproxy?.models?.[0]?.cost).toEqual({
+      input: 2,
+      output: 8,
+      cacheRead: 0.2,
+      cacheWrite: 2,
+    });
+  });
+
   it("clamps maxTokens to contextWindow", () => {
     const cfg = {
       models: {
@@ -6,6 +6,7 @@ import {
   resolveDefaultConfigCandidates,
   resolveConfigPathCandidate,
   resolveConfigPath,
+  resolveDefaultStateDir,
   resolveOAuthDir,
   resolveOAuthPath,
   resolveStateDir,
@@ -107,6 +108,30 @@ describe("state + config path candidates", () => {
     }
   });
 
+  it.skipIf(process.platform === "win32")("ignores LOCALAPPDATA outside Windows", () => {
+    const env = { LOCALAPPDATA: "/appdata" } as NodeJS.ProcessEnv;
+    expect(resolveDefaultStateDir(env, () => "/home/test")).toBe(
+      path.join(path.resolve("/home/test"), ".openclaw"),
+    );
+  });
+
+  it.skipIf(process.platform !== "win32")(
+    "uses LOCALAPPDATA on Windows unless ~/.openclaw already exists",
+    async () => {
+      const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-state-win-"));
+      try {
+        const home = path.join(root, "home");
+        const env = { LOCALAPPDATA: path.join(root, "appdata") } as NodeJS.ProcessEnv;
+        expect(resolveStateDir(env, () => home)).toBe(path.join(root, "appdata", "openclaw"));
+
+        await fs.mkdir(path.join(home, ".openclaw"), { recursive: true });
+        expect(resolveStateDir(env, () => home)).toBe(path.join(home, ".openclaw"));
+      } finally {
+        await fs.rm(root, { recursive: true, force: true });
+      }
+    },
+  );
+
   it("CONFIG_PATH prefers existing config when present", async () => {
     const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-config-"));
     try {
@@ -52,10 +52,31 @@ export function resolveNewStateDir(homedir: () => string = resolveDefaultHomeDir
   return newStateDir(homedir);
 }
 
+/**
+ * State directory to use when no override is set, ignoring legacy dirs.
+ * Default: ~/.openclaw; on Windows %LOCALAPPDATA%\openclaw unless ~/.openclaw already exists
+ * or OPENCLAW_HOME pins a home directory.
+ */
+export function resolveDefaultStateDir(
+  env: NodeJS.ProcessEnv = process.env,
+  homedir: () => string = envHomedir(env),
+): string {
+  const homeDir = newStateDir(() => resolveRequiredHomeDir(env, homedir));
+  const localAppData = env.LOCALAPPDATA?.trim();
+  if (process.platform !== "win32" || !localAppData || env.OPENCLAW_HOME?.trim()) {
+    return homeDir;
+  }
+  const platformDir = path.join(localAppData, "openclaw");
+  if (!fs.existsSync(platformDir) && fs.existsSync(homeDir)) {
+    return homeDir;
+  }
+  return platformDir;
+}
+
 /**
  * State directory for mutable data (sessions, logs, caches).
  * Can be overridden via OPENCLAW_STATE_DIR.
- * Default: ~/.openclaw
+ * Default: ~/.openclaw (see resolveDefaultStateDir for Windows)
  */
 export function resolveStateDir(
   env: NodeJS.ProcessEnv = process.env,
@@ -66,7 +87,7 @@ export function resolveStateDir(
   if (override) {
     return resolveUserPath(override, env, effectiveHomedir);
   }
-  const newDir = newStateDir(effectiveHomedir);
+  const newDir = resolveDefaultStateDir(env, effectiveHomedir);
   const legacyDirs = legacyStateDirs(effectiveHomedir);
   const hasNew = fs.existsSync(newDir);
   if (hasNew) {
@@ -206,7 +227,11 @@ export function resolveDefaultConfigCandidates(
     candidates.push(...LEGACY_CONFIG_FILENAMES.map((name) => path.join(resolved, name)));
   }
 
-  const defaultDirs = [newStateDir(effectiveHomedir), ...legacyStateDirs(effectiveHomedir)];
+  const defaultDirs = new Set([
+    resolveDefaultStateDir(env, effectiveHomedir),
+    newStateDir(effectiveHomedir),
+    ...legacyStateDirs(effectiveHomedir),
+  ]);
   for (const dir of defaultDirs) {
     candidates.push(path.join(dir, CONFIG_FILENAME));
     candidates.push(...LEGACY_CONFIG_FILENAMES.map((name) => path.join(dir, name)));
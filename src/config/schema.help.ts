@@ -5,6 +5,23 @@ export const FIELD_HELP: Record<string, string> = {
   "meta.lastTouchedAt": "ISO timestamp of the last config write (auto-set).",
   "update.channel": 'Update channel for git + npm installs ("stable", "beta", or "dev").',
   "update.checkOnStart": "Check for npm updates when the gateway starts (default: true).",
+  "ci.agent": "Agent used by `openclaw run` (its workspace, model, and tool policy apply).",
+  "ci.execSecurity":
+    'Exec security for `openclaw run` ("deny", "allowlist", or "full"). Runs never prompt for approvals, so commands outside the policy are refused.',
+  "ci.maxTurns": "Default model-turn limit for `openclaw run` (overridden by --max-turns).",
+  "ci.maxDurationSeconds": "Default wall-clock limit for `openclaw run` in seconds.",
+  "ci.maxCostUsd": "Default estimated cost limit for `openclaw run` in USD.",
+  "logging.maxFileBytes":
+    "Rotate the log file once it reaches this many bytes; the old file becomes `<file>.1` (default: 10 MB).",
+  "logging.maxFiles": "Rotated log copies to keep next to the log file (default: 5; 0 truncates).",
+  "ui.tui.editMode":
+    'Editor keys in `openclaw tui`: "emacs" (default) or "vi" (Esc enters normal mode).',
+  "ui.tui.keybindings":
+    'Extra TUI keys, e.g. { "alt+c": "/compact", "f5": "/new", "alt+x": "abort" }. Overrides built-in keys.',
+  "ui.tui.stream":
+    "Stream replies in `openclaw tui` as they are generated (default: true). Set false to render each reply once it is complete; `--stream`/`--no-stream` override per run.",
+  "ui.tui.statusline":
+    'Bottom status line in `openclaw tui`, refreshed after each turn: true for all segments, or { segments: ["model", "context", "cost", "branch", "tools"] } to pick and order them.',
   "gateway.remote.url": "Remote Gateway WebSocket URL (ws:// or wss://).",
   "gateway.remote.tlsFingerprint":
     "Expected sha256 TLS fingerprint for the remote gateway (pin to avoid MITM).",
@@ -34,6 +51,8 @@ export const FIELD_HELP: Record<string, string> = {
     "DANGEROUS. Disable Control UI device identity checks (token/password only).",
   "gateway.http.endpoints.chatCompletions.enabled":
     "Enable the OpenAI-compatible `POST /v1/chat/completions` endpoint (default: false).",
+  "gateway.http.endpoints.events.enabled":
+    "Serve the live agent event stream as Server-Sent Events on `GET /v1/events` (default: false).",
   "gateway.reload.mode": 'Hot reload strategy for config changes ("hybrid" recommended).',
   "gateway.reload.debounceMs": "Debounce window (ms) before applying config changes.",
   "gateway.nodes.browser.mode":
@@ -62,6 +81,24 @@ export const FIELD_HELP: Record<string, string> = {
     'Optional allowlist of model ids (e.g. "gpt-5.2" or "openai/gpt-5.2").',
   "tools.exec.notifyOnExit":
     "When true (default), backgrounded exec sessions enqueue a system event and request a heartbeat on exit.",
+  "tools.exec.trackCwd":
+    "When true (default), exec remembers directory changes from `cd` and uses them as the default workdir for later calls.",
+  "tools.exec.env":
+    "Env vars injected into exec subprocesses on the gateway and sandbox hosts. Applied after scrubbing, so listed keys are always passed through.",
+  "tools.exec.scrubEnv":
+    'Env var names removed from the inherited gateway env before exec runs; `*` wildcards allowed. Defaults to provider API keys and gateway credentials (e.g. "*_API_KEY"); set [] to inherit everything.',
+  "tools.exec.sandbox.backend":
+    'OS-level sandbox for exec commands that run on the gateway host: "off" (default), "auto", "bwrap" (Linux), or "sandbox-exec" (macOS). Commands can read the filesystem except $HOME and the state dir, and only write to the workspace, temp dirs, and allowWrite paths.',
+  "tools.exec.sandbox.network":
+    "Allow network access from OS-sandboxed exec commands (default: false).",
+  "tools.exec.sandbox.allowRead":
+    "Paths under $HOME or the state dir that OS-sandboxed exec commands may still read (e.g. toolchains like ~/.cargo).",
+  "tools.exec.sandbox.allowWrite":
+    "Extra paths OS-sandboxed exec commands may write to, in addition to the workspace and temp dirs.",
+  "tools.exec.persistentShell.enabled":
+    "Keep one long-lived shell per session so cd, exports, and activated virtualenvs persist across exec calls (default: false).",
+  "tools.exec.persistentShell.idleMs":
+    "Close persistent shells after this many ms without exec calls (default: 900000).",
   "tools.exec.pathPrepend": "Directories to prepend to PATH for exec runs (gateway/sandbox).",
   "tools.exec.safeBins":
     "Allow stdin-only safe binaries to run without explicit allowlist entries.",
@@ -96,10 +133,76 @@ export const FIELD_HELP: Record<string, string> = {
     "Hard cap for web_fetch maxChars (applies to config and tool calls).",
   "tools.web.fetch.timeoutSeconds": "Timeout in seconds for web_fetch requests.",
   "tools.web.fetch.cacheTtlMinutes": "Cache TTL in minutes for web_fetch results.",
+  "tools.web.fetch.diskCache":
+    "Persist web_fetch results on disk so they survive restarts; expired entries are revalidated with ETag/Last-Modified (default: true).",
   "tools.web.fetch.maxRedirects": "Maximum redirects allowed for web_fetch (default: 3).",
+  "tools.web.fetch.urlAllowlist":
+    "Hostnames web_fetch may fetch (exact or `*.example.com`); applies to redirects too. Empty allows any public host.",
+  "tools.web.fetch.urlDenylist":
+    "Hostnames web_fetch must never fetch (exact or `*.example.com`); takes precedence over the allowlist.",
+  "tools.web.fetch.allowPrivateNetwork":
+    "Allow web_fetch to reach private, loopback, and link-local addresses (default: false). Leave off unless you trust every URL the agent may see.",
   "tools.web.fetch.userAgent": "Override User-Agent header for web_fetch requests.",
   "tools.web.fetch.readability":
     "Use Readability to extract main content from HTML (fallbacks to basic HTML cleanup).",
+  "tools.web.http.enabled":
+    "Enable the http_request tool for API calls with custom methods, headers, and bodies (default: false).",
+  "tools.web.http.timeoutSeconds": "Timeout in seconds for http_request calls (default: 30).",
+  "tools.web.http.maxChars": "Max response body characters returned by http_request (default: 20000).",
+  "tools.web.http.maxRedirects":
+    "Maximum redirects followed by http_request for GET/HEAD without credentials (default: 3). Other requests never follow redirects.",
+  "tools.web.http.urlAllowlist":
+    "Hostnames http_request may call (exact or `*.example.com`). Empty allows any public host.",
+  "tools.web.http.urlDenylist":
+    "Hostnames http_request must never call (exact or `*.example.com`); takes precedence over the allowlist.",
+  "tools.web.http.allowPrivateNetwork":
+    "Allow http_request to reach private, loopback, and link-local addresses such as internal services (default: false).",
+  "tools.web.http.userAgent": "Override User-Agent header for http_request calls.",
+  "tools.web.http.credentials":
+    "Headers injected into http_request calls per host (keyed by hostname or `*.example.com`), e.g. Authorization tokens. The model never sees these values and cannot override them.",
+  "tools.runCode.enabled":
+    "Enable the run_code tool for Python/JavaScript snippets with time and memory limits (default: false).",
+  "tools.runCode.timeoutSeconds":
+    "Maximum run time per run_code call in seconds (default: 30); the model may only lower it.",
+  "tools.runCode.memoryMb": "Memory limit per run_code call in MB (default: 2048).",
+  "tools.runCode.maxOutputChars":
+    "Max characters of stdout/stderr returned by run_code (default: 20000).",
+  "tools.runCode.python":
+    "Python interpreter used by run_code on the gateway host (default: python3, or python on Windows).",
+  "tools.runCode.sandbox.backend":
+    'OS sandbox for run_code on the gateway host: "auto" (default), "off", "bwrap" (Linux), or "sandbox-exec" (macOS). Without one, run_code refuses to run unless set to "off". Sandboxed sessions run inside their container.',
+  "tools.runCode.sandbox.network": "Allow network access from run_code snippets (default: false).",
+  "tools.resultCache.enabled":
+    "Answer repeated identical read/grep/find/ls/web_fetch calls in a run with a pointer to the earlier result instead of re-running them (default: true). Reads are keyed by file mtime; directory searches reset after any write/edit/exec.",
+  "tools.loopDetection.enabled":
+    "Stop tool-call loops: refuse a call that repeats the previous one verbatim too often, or that alternates with another failing call, and tell the model to change approach (default: true).",
+  "tools.loopDetection.maxRepeats":
+    "Identical consecutive tool calls allowed before the next one is refused (default: 3; `process` polling is exempt).",
+  "tools.openapi":
+    "OpenAPI 3.x documents (path or URL, JSON or YAML) turned into one tool per operation. Each entry: spec, baseUrl (default: first server), name (tool prefix), operations (operationId allowlist), auth (bearer/basic/apiKey), timeoutSeconds.",
+  "tools.external.enabled":
+    "Expose executables from the external tools dir as agent tools (default: true). Each declares its schema via `--schema` and handles calls as JSON on stdin/stdout; not offered to sandboxed sessions.",
+  "tools.external.dir":
+    "Directory scanned for external tool executables (default: ~/.openclaw/tools).",
+  "tools.external.timeoutSeconds":
+    "Timeout for a single external tool call in seconds (default: 60).",
+  "tools.wasm.enabled": "Load the WASM tools listed in tools.wasm.tools (default: true).",
+  "tools.wasm.runtime": 'wasmtime binary used to run WASM tools (default: "wasmtime" on PATH).',
+  "tools.wasm.timeoutSeconds": "Timeout for a single WASM tool call in seconds (default: 60).",
+  "tools.wasm.tools":
+    "WASI modules/components exposed as tools. Each declares its schema via `--schema` and sees only the granted dirs (`workspaceAccess`, `dirs`); network is off unless `network: true`.",
+  "tools.github.enabled":
+    "Enable the gh tool for GitHub issues and pull requests over the REST API (default: false).",
+  "tools.github.token":
+    "GitHub API token for the gh tool (fallback: GH_TOKEN/GITHUB_TOKEN env var, then the gh CLI keyring entry).",
+  "tools.github.keyring":
+    "Read the token stored by `gh auth login` from the OS keyring when no token is configured (default: true).",
+  "tools.github.apiBaseUrl":
+    "GitHub REST API base URL (default: https://api.github.com). Set to https://<host>/api/v3 for GitHub Enterprise.",
+  "tools.github.defaultRepo": 'Repository ("owner/name") the gh tool uses when a call omits repo.',
+  "tools.github.timeoutSeconds": "Timeout in seconds for gh tool API requests (default: 30).",
+  "tools.github.maxChars":
+    "Max characters of PR diffs and issue bodies returned by the gh tool (default: 30000).",
   "tools.web.fetch.firecrawl.enabled": "Enable Firecrawl fallback for web_fetch (if configured).",
   "tools.web.fetch.firecrawl.apiKey": "Firecrawl API key (fallback: FIRECRAWL_API_KEY env var).",
   "tools.web.fetch.firecrawl.baseUrl":
@@ -136,6 +239,14 @@ export const FIELD_HELP: Record<string, string> = {
   "auth.cooldowns.failureWindowHours": "Failure window (hours) for backoff counters (default: 24).",
   "agents.defaults.bootstrapMaxChars":
     "Max characters of each workspace bootstrap file injected into the system prompt before truncation (default: 20000).",
+  "agents.defaults.workspaceMap.enabled":
+    "Include a .gitignore-aware directory tree of the workspace in the system prompt (default: false).",
+  "agents.defaults.workspaceMap.maxDepth":
+    "Levels of the workspace tree to include; 1 lists top-level entries only (default: 2, max: 5).",
+  "agents.defaults.workspaceMap.maxEntries":
+    "Maximum entries in the workspace tree before it is cut off (default: 200).",
+  "agents.defaults.bootstrapTotalMaxChars":
+    "Max characters across all injected Project Context files combined; later files are truncated or omitted once it is used up (default: 60000).",
   "agents.defaults.repoRoot":
     "Optional repository root shown in the system prompt runtime line (overrides auto-detect).",
   "agents.defaults.envelopeTimezone":
@@ -143,6 +254,36 @@ export const FIELD_HELP: Record<string, string> = {
   "agents.defaults.envelopeTimestamp":
     'Include absolute timestamps in message envelopes ("on" or "off").',
   "agents.defaults.envelopeElapsed": 'Include elapsed time in message envelopes ("on" or "off").',
+  "agents.defaults.maxTurns":
+    "Model turns per run; once reached, further tool calls are refused and the model is told to reply with a final summary.",
+  "agents.defaults.maxDurationSeconds":
+    "Wall-clock seconds per run before the model is told to wrap up (timeoutSeconds remains the hard stop).",
+  "agents.defaults.maxCostUsd":
+    "Estimated model spend (USD) per run before the model is told to wrap up; needs model cost data.",
+  "agents.defaults.budget.sessionUsd":
+    "Spend limit (USD) for one session; past it, chat asks for /budget continue and `openclaw agent` refuses new runs.",
+  "agents.defaults.budget.dailyUsd":
+    "Spend limit (USD) per local calendar day across the agent's sessions, summed from transcripts.",
+  "agents.defaults.budget.monthlyUsd":
+    "Spend limit (USD) per local calendar month across the agent's sessions, summed from transcripts.",
+  "agents.defaults.budget.warnPercent":
+    "Percent of a budget at which a warning is appended to the reply (default: 80).",
+  "agents.defaults.responseCache.enabled":
+    "Replay stored replies for byte-identical model requests (same model, system prompt, messages, tools, and options) instead of calling the provider. Meant for development loops such as `openclaw eval` reruns; also enabled by OPENCLAW_RESPONSE_CACHE=1.",
+  "agents.defaults.responseCache.ttlSeconds":
+    "How long a stored reply can be replayed, in seconds (default: 86400).",
+  "agents.defaults.responseCache.dir":
+    "Directory for stored replies (default: ~/.openclaw/cache/responses).",
+  "agents.defaults.responseCache.maxEntries":
+    "Most stored replies to keep; expired replies are deleted first, then the oldest (default: 2000).",
+  "agents.defaults.responseCache.maxMb":
+    "Most disk space stored replies may use, in MB (default: 200).",
+  "agents.defaults.council.models":
+    "Models (provider/model or alias) that /council asks the same prompt concurrently (max 6).",
+  "agents.defaults.council.judge":
+    "Optional model that compares the council answers and writes a synthesized reply.",
+  "agents.defaults.council.timeoutSeconds":
+    "Seconds each council model (and the judge) may take before its answer is reported as timed out (default: 120).",
   "agents.defaults.models": "Configured model catalog (keys are full provider/model IDs).",
   "agents.defaults.memorySearch":
     "Vector search over MEMORY.md and memory/*.md (per-agent overrides supported).",
@@ -187,11 +328,23 @@ export const FIELD_HELP: Record<string, string> = {
     "Weight for BM25 text relevance when merging results (0-1).",
   "agents.defaults.memorySearch.query.hybrid.candidateMultiplier":
     "Multiplier for candidate pool size (default: 4).",
+  "agents.defaults.memorySearch.recall.enabled":
+    "Search memory with every prompt and prepend the most relevant snippets (default: false).",
+  "agents.defaults.memorySearch.recall.maxResults":
+    "Max memory snippets injected per prompt (default: 3).",
+  "agents.defaults.memorySearch.recall.minScore":
+    "Minimum relevance score for injected snippets (default: query.minScore).",
+  "agents.defaults.memorySearch.recall.maxChars":
+    "Cap on characters injected across all recalled snippets (default: 2000).",
+  "agents.defaults.memorySearch.recall.timeoutMs":
+    "Skip recall for a prompt when the search takes longer than this (default: 1500).",
   "agents.defaults.memorySearch.cache.enabled":
     "Cache chunk embeddings in SQLite to speed up reindexing and frequent updates (default: true).",
   memory: "Memory backend configuration (global).",
   "memory.backend": 'Memory backend ("builtin" for OpenClaw embeddings, "qmd" for QMD sidecar).',
   "memory.citations": 'Default citation behavior ("auto", "on", or "off").',
+  "memory.maxChars":
+    "Size cap for MEMORY.md when the agent writes facts via memory_write (default: agents.defaults.bootstrapMaxChars, 20000).",
   "memory.qmd.command": "Path to the qmd binary (default: resolves from PATH).",
   "memory.qmd.includeDefaultMemory":
     "Whether to automatically index MEMORY.md + memory/**/*.md (default: true).",
@@ -315,6 +468,12 @@ export const FIELD_HELP: Record<string, string> = {
     'Override native skill commands for Slack (bool or "auto").',
   "session.agentToAgent.maxPingPongTurns":
     "Max reply-back turns between requester and target (0–5).",
+  "session.autoTitle.enabled":
+    "Generate a short session title with one extra model call after the first exchange (default: false).",
+  "session.autoTitle.model":
+    "Model for title generation (provider/model or alias). Defaults to the session's model; a small, cheap model is recommended.",
+  "session.maintenance.transcripts":
+    'Transcripts of pruned sessions: "archive" gzips them into sessions/archive/, "delete" removes them, "keep" leaves them (default: "archive").',
   "channels.telegram.customCommands":
     "Additional Telegram bot menu commands (merged with native; conflicts ignored).",
   "messages.ackReaction": "Emoji reaction used to acknowledge inbound messages (empty disables).",
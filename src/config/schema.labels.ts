@@ -5,6 +5,13 @@ export const FIELD_LABELS: Record<string, string> = {
   "meta.lastTouchedAt": "Config Last Touched At",
   "update.channel": "Update Channel",
   "update.checkOnStart": "Update Check on Start",
+  "ci.agent": "CI Run Agent",
+  "ci.execSecurity": "CI Run Exec Security",
+  "ci.maxTurns": "CI Run Max Turns",
+  "ci.maxDurationSeconds": "CI Run Max Duration (sec)",
+  "ci.maxCostUsd": "CI Run Max Cost (USD)",
+  "logging.maxFileBytes": "Log File Max Size (bytes)",
+  "logging.maxFiles": "Rotated Log Files Kept",
   "diagnostics.enabled": "Diagnostics Enabled",
   "diagnostics.flags": "Diagnostics Flags",
   "diagnostics.otel.enabled": "OpenTelemetry Enabled",
@@ -78,6 +85,14 @@ export const FIELD_LABELS: Record<string, string> = {
   "tools.exec.security": "Exec Security",
   "tools.exec.ask": "Exec Ask",
   "tools.exec.node": "Exec Node Binding",
+  "tools.exec.trackCwd": "Exec Track Working Directory",
+  "tools.exec.env": "Exec Environment",
+  "tools.exec.scrubEnv": "Exec Scrubbed Env Vars",
+  "tools.exec.sandbox.backend": "Exec OS Sandbox Backend",
+  "tools.exec.sandbox.network": "Exec OS Sandbox Network",
+  "tools.exec.sandbox.allowWrite": "Exec OS Sandbox Writable Paths",
+  "tools.exec.persistentShell.enabled": "Exec Persistent Shell",
+  "tools.exec.persistentShell.idleMs": "Exec Persistent Shell Idle Timeout (ms)",
   "tools.exec.pathPrepend": "Exec PATH Prepend",
   "tools.exec.safeBins": "Exec Safe Bins",
   "tools.message.allowCrossContextSend": "Allow Cross-Context Messaging",
@@ -97,14 +112,53 @@ export const FIELD_LABELS: Record<string, string> = {
   "tools.web.fetch.maxChars": "Web Fetch Max Chars",
   "tools.web.fetch.timeoutSeconds": "Web Fetch Timeout (sec)",
   "tools.web.fetch.cacheTtlMinutes": "Web Fetch Cache TTL (min)",
+  "tools.web.fetch.diskCache": "Web Fetch Disk Cache",
   "tools.web.fetch.maxRedirects": "Web Fetch Max Redirects",
+  "tools.web.fetch.urlAllowlist": "Web Fetch URL Allowlist",
+  "tools.web.fetch.urlDenylist": "Web Fetch URL Denylist",
+  "tools.web.fetch.allowPrivateNetwork": "Web Fetch Allow Private Network",
   "tools.web.fetch.userAgent": "Web Fetch User-Agent",
+  "tools.web.http.enabled": "Enable HTTP Request Tool",
+  "tools.web.http.timeoutSeconds": "HTTP Request Timeout (sec)",
+  "tools.web.http.maxChars": "HTTP Request Max Chars",
+  "tools.web.http.maxRedirects": "HTTP Request Max Redirects",
+  "tools.web.http.urlAllowlist": "HTTP Request URL Allowlist",
+  "tools.web.http.urlDenylist": "HTTP Request URL Denylist",
+  "tools.web.http.allowPrivateNetwork": "HTTP Request Allow Private Network",
+  "tools.web.http.userAgent": "HTTP Request User-Agent",
+  "tools.web.http.credentials": "HTTP Request Credentials",
+  "tools.runCode.enabled": "Enable Run Code Tool",
+  "tools.runCode.timeoutSeconds": "Run Code Timeout (sec)",
+  "tools.runCode.memoryMb": "Run Code Memory Limit (MB)",
+  "tools.runCode.maxOutputChars": "Run Code Max Output Chars",
+  "tools.runCode.python": "Run Code Python Interpreter",
+  "tools.runCode.sandbox.backend": "Run Code OS Sandbox Backend",
+  "tools.runCode.sandbox.network": "Run Code Network Access",
+  "tools.resultCache.enabled": "Enable Tool Result Cache",
+  "tools.loopDetection.enabled": "Enable Tool Loop Detection",
+  "tools.loopDetection.maxRepeats": "Tool Loop Max Repeats",
+  "tools.openapi": "OpenAPI Tools",
+  "tools.external.enabled": "Enable External Tools",
+  "tools.external.dir": "External Tools Directory",
+  "tools.external.timeoutSeconds": "External Tool Timeout (sec)",
+  "tools.wasm.enabled": "Enable WASM Tools",
+  "tools.wasm.runtime": "WASM Runtime Binary",
+  "tools.wasm.timeoutSeconds": "WASM Tool Timeout (sec)",
+  "tools.wasm.tools": "WASM Tools",
+  "tools.github.enabled": "Enable GitHub Tool",
+  "tools.github.token": "GitHub Token",
+  "tools.github.keyring": "GitHub Keyring Lookup",
+  "tools.github.apiBaseUrl": "GitHub API Base URL",
+  "tools.github.defaultRepo": "GitHub Default Repo",
+  "tools.github.timeoutSeconds": "GitHub Timeout (sec)",
+  "tools.github.maxChars": "GitHub Max Chars",
   "gateway.controlUi.basePath": "Control UI Base Path",
   "gateway.controlUi.root": "Control UI Assets Root",
   "gateway.controlUi.allowedOrigins": "Control UI Allowed Origins",
   "gateway.controlUi.allowInsecureAuth": "Allow Insecure Control UI Auth",
   "gateway.controlUi.dangerouslyDisableDeviceAuth": "Dangerously Disable Control UI Device Auth",
   "gateway.http.endpoints.chatCompletions.enabled": "OpenAI Chat Completions Endpoint",
+  "gateway.http.endpoints.events.enabled": "Agent Events SSE Endpoint",
   "gateway.reload.mode": "Config Reload Mode",
   "gateway.reload.debounceMs": "Config Reload Debounce (ms)",
   "gateway.nodes.browser.mode": "Gateway Node Browser Mode",
@@ -118,9 +172,28 @@ export const FIELD_LABELS: Record<string, string> = {
   "agents.defaults.workspace": "Workspace",
   "agents.defaults.repoRoot": "Repo Root",
   "agents.defaults.bootstrapMaxChars": "Bootstrap Max Chars",
+  "agents.defaults.bootstrapTotalMaxChars": "Bootstrap Total Max Chars",
+  "agents.defaults.workspaceMap.enabled": "Workspace Map",
+  "agents.defaults.workspaceMap.maxDepth": "Workspace Map Depth",
+  "agents.defaults.workspaceMap.maxEntries": "Workspace Map Max Entries",
   "agents.defaults.envelopeTimezone": "Envelope Timezone",
   "agents.defaults.envelopeTimestamp": "Envelope Timestamp",
   "agents.defaults.envelopeElapsed": "Envelope Elapsed",
+  "agents.defaults.maxTurns": "Max Turns per Run",
+  "agents.defaults.maxDurationSeconds": "Max Run Duration (sec)",
+  "agents.defaults.maxCostUsd": "Max Cost per Run (USD)",
+  "agents.defaults.budget.sessionUsd": "Session Budget (USD)",
+  "agents.defaults.budget.dailyUsd": "Daily Budget (USD)",
+  "agents.defaults.budget.monthlyUsd": "Monthly Budget (USD)",
+  "agents.defaults.budget.warnPercent": "Budget Warning Threshold (%)",
+  "agents.defaults.responseCache.enabled": "Enable Response Cache",
+  "agents.defaults.responseCache.ttlSeconds": "Response Cache TTL (s)",
+  "agents.defaults.responseCache.dir": "Response Cache Directory",
+  "agents.defaults.responseCache.maxEntries": "Response Cache Max Entries",
+  "agents.defaults.responseCache.maxMb": "Response Cache Max Size (MB)",
+  "agents.defaults.council.models": "Council Models",
+  "agents.defaults.council.judge": "Council Judge Model",
+  "agents.defaults.council.timeoutSeconds": "Council Timeout (sec)",
   "agents.defaults.memorySearch": "Memory Search",
   "agents.defaults.memorySearch.enabled": "Enable Memory Search",
   "agents.defaults.memorySearch.sources": "Memory Search Sources",
@@ -153,11 +226,17 @@ export const FIELD_LABELS: Record<string, string> = {
   "agents.defaults.memorySearch.query.hybrid.textWeight": "Memory Search Text Weight",
   "agents.defaults.memorySearch.query.hybrid.candidateMultiplier":
     "Memory Search Hybrid Candidate Multiplier",
+  "agents.defaults.memorySearch.recall.enabled": "Memory Recall",
+  "agents.defaults.memorySearch.recall.maxResults": "Memory Recall Max Results",
+  "agents.defaults.memorySearch.recall.minScore": "Memory Recall Min Score",
+  "agents.defaults.memorySearch.recall.maxChars": "Memory Recall Max Chars",
+  "agents.defaults.memorySearch.recall.timeoutMs": "Memory Recall Timeout (ms)",
   "agents.defaults.memorySearch.cache.enabled": "Memory Search Embedding Cache",
   "agents.defaults.memorySearch.cache.maxEntries": "Memory Search Embedding Cache Max Entries",
   memory: "Memory",
   "memory.backend": "Memory Backend",
   "memory.citations": "Memory Citations Mode",
+  "memory.maxChars": "Memory File Size Cap",
   "memory.qmd.command": "QMD Binary",
   "memory.qmd.includeDefaultMemory": "QMD Include Default Memory",
   "memory.qmd.paths": "QMD Extra Paths",
@@ -208,6 +287,10 @@ export const FIELD_LABELS: Record<string, string> = {
   "ui.seamColor": "Accent Color",
   "ui.assistant.name": "Assistant Name",
   "ui.assistant.avatar": "Assistant Avatar",
+  "ui.tui.editMode": "TUI Edit Mode",
+  "ui.tui.keybindings": "TUI Key Bindings",
+  "ui.tui.stream": "TUI Stream Replies",
+  "ui.tui.statusline": "TUI Status Line",
   "browser.evaluateEnabled": "Browser Evaluate Enabled",
   "browser.snapshotDefaults": "Browser Snapshot Defaults",
   "browser.snapshotDefaults.mode": "Browser Snapshot Mode",
@@ -215,6 +298,9 @@ export const FIELD_LABELS: Record<string, string> = {
   "browser.remoteCdpHandshakeTimeoutMs": "Remote CDP Handshake Timeout (ms)",
   "session.dmScope": "DM Session Scope",
   "session.agentToAgent.maxPingPongTurns": "Agent-to-Agent Ping-Pong Turns",
+  "session.autoTitle.enabled": "Auto Session Titles",
+  "session.autoTitle.model": "Auto Title Model",
+  "session.maintenance.transcripts": "Pruned Session Transcripts",
   "messages.ackReaction": "Ack Reaction Emoji",
   "messages.ackReactionScope": "Ack Reaction Scope",
   "messages.inbound.debounceMs": "Inbound Message Debounce (ms)",
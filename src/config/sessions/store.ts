@@ -2,7 +2,11 @@ import crypto from "node:crypto";
 import fs from "node:fs";
 import path from "node:path";
 import type { MsgContext } from "../../auto-reply/templating.js";
-import type { SessionMaintenanceConfig, SessionMaintenanceMode } from "../types.base.js";
+import type {
+  SessionMaintenanceConfig,
+  SessionMaintenanceMode,
+  SessionTranscriptRetention,
+} from "../types.base.js";
 import { acquireSessionWriteLock } from "../../agents/session-write-lock.js";
 import { parseByteSize } from "../../cli/parse-bytes.js";
 import { parseDurationMs } from "../../cli/parse-duration.js";
@@ -232,6 +236,7 @@ const DEFAULT_SESSION_PRUNE_AFTER_MS = 30 * 24 * 60 * 60 * 1000;
 const DEFAULT_SESSION_MAX_ENTRIES = 500;
 const DEFAULT_SESSION_ROTATE_BYTES = 10_485_760; // 10 MB
 const DEFAULT_SESSION_MAINTENANCE_MODE: SessionMaintenanceMode = "warn";
+const DEFAULT_SESSION_TRANSCRIPT_RETENTION: SessionTranscriptRetention = "archive";
 
 export type SessionMaintenanceWarning = {
   activeSessionKey: string;
@@ -243,11 +248,12 @@ export type SessionMaintenanceWarning = {
   wouldCap: boolean;
 };
 
-type ResolvedSessionMaintenanceConfig = {
+export type ResolvedSessionMaintenanceConfig = {
   mode: SessionMaintenanceMode;
   pruneAfterMs: number;
   maxEntries: number;
   rotateBytes: number;
+  transcripts: SessionTranscriptRetention;
 };
 
 function resolvePruneAfterMs(maintenance?: SessionMaintenanceConfig): number {
@@ -290,6 +296,7 @@ export function resolveMaintenanceConfig(): ResolvedSessionMaintenanceConfig {
     pruneAfterMs: resolvePruneAfterMs(maintenance),
     maxEntries: maintenance?.maxEntries ?? DEFAULT_SESSION_MAX_ENTRIES,
     rotateBytes: resolveRotateBytes(maintenance),
+    transcripts: maintenance?.transcripts ?? DEFAULT_SESSION_TRANSCRIPT_RETENTION,
   };
 }
 
@@ -22,6 +22,44 @@ export type SessionOrigin = {
   threadId?: string | number;
 };
 
+export type SessionTodoStatus = "pending" | "in_progress" | "completed";
+
+export type SessionTodoItem = {
+  content: string;
+  status: SessionTodoStatus;
+  /** Present-tense label shown while the item is in progress (e.g. "Running tests"). */
+  activeForm?: string;
+};
+
+export type SessionPinnedMessage = {
+  text: string;
+  /** Who wrote the pinned text. */
+  role: "user" | "assistant";
+  pinnedAt: number;
+};
+
+/** Usage summed over every run of the session (unlike inputTokens/outputTokens, which are per run). */
+export type SessionUsageTotals = {
+  input: number;
+  output: number;
+  cacheRead: number;
+  cacheWrite: number;
+  totalTokens: number;
+  /** Estimated USD cost; only runs whose model has cost config contribute. */
+  costUsd?: number;
+  runs: number;
+  /** Runs counted in the token totals but missing from costUsd (no model cost config). */
+  runsWithoutCost?: number;
+};
+
+export type SessionModelUse = {
+  provider?: string;
+  model: string;
+  firstUsedAt: number;
+  lastUsedAt: number;
+  runs: number;
+};
+
 export type SessionEntry = {
   /**
    * Last delivered heartbeat payload (used to suppress duplicate heartbeat notifications).
@@ -31,6 +69,8 @@ export type SessionEntry = {
   /** Timestamp (ms) when lastHeartbeatText was delivered. */
   lastHeartbeatSentAt?: number;
   sessionId: string;
+  /** When the current sessionId started (absent for sessions created before this was tracked). */
+  createdAt?: number;
   updatedAt: number;
   sessionFile?: string;
   /** Parent session key that spawned this session (used for sandbox session-tool scoping). */
@@ -48,6 +88,12 @@ export type SessionEntry = {
   execAsk?: string;
   execNode?: string;
   responseUsage?: "on" | "off" | "tokens" | "full";
+  /** Plan mode (/plan): runs get read-only tools until the plan is approved. */
+  planMode?: boolean;
+  /** Tools withheld from this session's runs (`/tools disable`), as normalized tool names. */
+  disabledTools?: string[];
+  /** Active system prompt preset (`/persona`), by name. */
+  persona?: string;
   providerOverride?: string;
   modelOverride?: string;
   authProfileOverride?: string;
@@ -80,12 +126,25 @@ export type SessionEntry = {
   model?: string;
   contextTokens?: number;
   compactionCount?: number;
+  /** Cumulative usage and cost for the current sessionId; survives restarts. */
+  usageTotals?: SessionUsageTotals;
+  /** Budget period (`/budget continue`) the user chose to keep spending past. */
+  budgetOverride?: string;
+  /** Budget period the last near-limit warning was shown for. */
+  budgetWarned?: string;
+  /** Models that served runs in this session, in first-use order. */
+  modelHistory?: SessionModelUse[];
   memoryFlushAt?: number;
   memoryFlushCompactionCount?: number;
   cliSessionIds?: Record<string, string>;
   claudeCliSessionId?: string;
   label?: string;
   displayName?: string;
+  /** Human-readable session title (`/title`, or generated after the first exchange). */
+  title?: string;
+  /** "auto" titles may be regenerated; "user" titles are never overwritten. */
+  titleSource?: "auto" | "user";
+  tags?: string[];
   channel?: string;
   groupId?: string;
   subject?: string;
@@ -99,6 +158,10 @@ export type SessionEntry = {
   lastThreadId?: string | number;
   skillsSnapshot?: SessionSkillSnapshot;
   systemPromptReport?: SessionSystemPromptReport;
+  /** Task list maintained by the agent via todo_write (shown by /todos). */
+  todos?: SessionTodoItem[];
+  /** Messages pinned via /pin; re-sent verbatim with every run so compaction never drops them. */
+  pinnedMessages?: SessionPinnedMessage[];
 };
 
 export function mergeSessionEntry(
@@ -155,6 +218,7 @@ export type SessionSystemPromptReport = {
   model?: string;
   workspaceDir?: string;
   bootstrapMaxChars?: number;
+  bootstrapTotalMaxChars?: number;
   sandbox?: {
     mode?: string;
     sandboxed?: boolean;
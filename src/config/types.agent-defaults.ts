@@ -93,6 +93,39 @@ export type CliBackendConfig = {
   serialize?: boolean;
 };
 
+export type AgentCouncilConfig = {
+  /** Models (provider/model or alias) asked concurrently. */
+  models?: string[];
+  /** Model that synthesizes the answers (optional). */
+  judge?: string;
+  /** Per-model timeout in seconds (default: 120). */
+  timeoutSeconds?: number;
+};
+
+export type AgentSpendBudgetConfig = {
+  /** Spend limit (USD) for a single session. */
+  sessionUsd?: number;
+  /** Spend limit (USD) per local calendar day, per agent. */
+  dailyUsd?: number;
+  /** Spend limit (USD) per local calendar month, per agent. */
+  monthlyUsd?: number;
+  /** Percent of a limit at which replies start carrying a warning (default: 80). */
+  warnPercent?: number;
+};
+
+export type AgentResponseCacheConfig = {
+  /** Serve byte-identical model requests from disk (default: false). */
+  enabled?: boolean;
+  /** How long a stored reply stays valid, in seconds (default: 86400). */
+  ttlSeconds?: number;
+  /** Cache directory (default: ~/.openclaw/cache/responses). */
+  dir?: string;
+  /** Most stored replies kept; the oldest are deleted first (default: 2000). */
+  maxEntries?: number;
+  /** Most disk space used by stored replies, in MB (default: 200). */
+  maxMb?: number;
+};
+
 export type AgentDefaultsConfig = {
   /** Primary model and fallbacks (provider/model). */
   model?: AgentModelListConfig;
@@ -108,6 +141,10 @@ export type AgentDefaultsConfig = {
   skipBootstrap?: boolean;
   /** Max chars for injected bootstrap files before truncation (default: 20000). */
   bootstrapMaxChars?: number;
+  /** Max chars across all injected context files combined (default: 60000). */
+  bootstrapTotalMaxChars?: number;
+  /** Directory tree of the workspace in the system prompt's Workspace section (default: off). */
+  workspaceMap?: AgentWorkspaceMapConfig;
   /** Optional IANA timezone for the user (used in system prompt; defaults to host timezone). */
   userTimezone?: string;
   /** Time format in system prompt: auto (OS preference), 12-hour, or 24-hour. */
@@ -158,6 +195,18 @@ export type AgentDefaultsConfig = {
   /** Human-like delay between block replies. */
   humanDelay?: HumanDelayConfig;
   timeoutSeconds?: number;
+  /** Model turns per run before tool calls are refused and the model is asked to wrap up. */
+  maxTurns?: number;
+  /** Wall-clock seconds per run before the model is asked to wrap up */
+  maxDurationSeconds?: number;
+  /** Estimated model spend (USD) per run before the model is asked to wrap up. */
+  maxCostUsd?: number;
+  /** Spend budgets across runs; warns near the limit and stops new runs past it. */
+  budget?: AgentSpendBudgetConfig;
+  /** Development cache that replays identical model requests instead of calling the provider. */
+  responseCache?: AgentResponseCacheConfig;
+  /** Models asked side by side by `/council` and `openclaw agent --compare`. */
+  council?: AgentCouncilConfig;
   /** Max inbound media size in MB for agent-visible attachments (text note or future image attach). */
   mediaMaxMb?: number;
   typingIntervalSeconds?: number;
@@ -252,8 +301,34 @@ export type AgentCompactionConfig = {
   reserveTokensFloor?: number;
   /** Max share of context window for history during safeguard pruning (0.1–0.9, default 0.5). */
   maxHistoryShare?: number;
+  /** Compact once context reaches this percent of the window (10–95). */
+  thresholdPercent?: number;
+  /** Recent context (tokens) kept verbatim after compaction (Pi default: 20000). */
+  keepRecentTokens?: number;
+  /** Summarizer model (provider/model or alias) for safeguard mode. Default: session model. */
+  model?: string;
   /** Pre-compaction memory flush (agentic turn). Default: enabled. */
   memoryFlush?: AgentCompactionMemoryFlushConfig;
+  /** Replace large, stale tool results with placeholders before summarizing. Default: enabled. */
+  pruneToolResults?: AgentCompactionPruneToolResultsConfig;
+};
+
+export type AgentCompactionPruneToolResultsConfig = {
+  /** Enable tool result pruning ahead of compaction (default: true). */
+  enabled?: boolean;
+  /** Keep tool results from the last N assistant turns (default: 3). */
+  keepLastAssistants?: number;
+  /** Only prune tool results with at least this many characters (default: 2000). */
+  minChars?: number;
+};
+
+export type AgentWorkspaceMapConfig = {
+  /** Include the workspace tree in the system prompt (default: false). */
+  enabled?: boolean;
+  /** Levels to descend; 1 lists only top-level entries (default: 2, max: 5). */
+  maxDepth?: number;
+  /** Maximum entries listed before the tree is cut off (default: 200). */
+  maxEntries?: number;
 };
 
 export type AgentCompactionMemoryFlushConfig = {
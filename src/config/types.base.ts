@@ -101,10 +101,21 @@ export type SessionConfig = {
   };
   /** Automatic session store maintenance (pruning, capping, file rotation). */
   maintenance?: SessionMaintenanceConfig;
+  /** Generate a short session title after the first exchange. */
+  autoTitle?: SessionAutoTitleConfig;
+};
+
+export type SessionAutoTitleConfig = {
+  /** Enable automatic titles (default: false). */
+  enabled?: boolean;
+  /** Model used for the title call (provider/model or alias). Default: the session's model. */
+  model?: string;
 };
 
 export type SessionMaintenanceMode = "enforce" | "warn";
 
+export type SessionTranscriptRetention = "archive" | "delete" | "keep";
+
 export type SessionMaintenanceConfig = {
   /** Whether to enforce maintenance or warn only. Default: "warn". */
   mode?: SessionMaintenanceMode;
@@ -116,11 +127,20 @@ export type SessionMaintenanceConfig = {
   maxEntries?: number;
   /** Rotate sessions.json when it exceeds this size (e.g. "10mb"). Default: 10mb. */
   rotateBytes?: number | string;
+  /**
+   * What happens to the transcripts of pruned sessions: gzip them into `sessions/archive/`,
+   * delete them, or leave them in place. Default: "archive".
+   */
+  transcripts?: SessionTranscriptRetention;
 };
 
 export type LoggingConfig = {
   level?: "silent" | "fatal" | "error" | "warn" | "info" | "debug" | "trace";
   file?: string;
+  /** Rotate the log file once it reaches this size (bytes). Default: 10 MB. */
+  maxFileBytes?: number;
+  /** Rotated copies kept next to the log file (`.1` is the newest). Default: 5. */
+  maxFiles?: number;
   consoleLevel?: "silent" | "fatal" | "error" | "warn" | "info" | "debug" | "trace";
   consoleStyle?: "pretty" | "compact" | "json";
   /** Redact sensitive tokens in tool summaries. Default: "tools". */
@@ -246,9 +246,18 @@ export type GatewayHttpResponsesImagesConfig = {
   timeoutMs?: number;
 };
 
+export type GatewayHttpEventsConfig = {
+  /**
+   * If true, the Gateway serves the live agent event stream as SSE on `GET /v1/events`.
+   * Default: false when absent.
+   */
+  enabled?: boolean;
+};
+
 export type GatewayHttpEndpointsConfig = {
   chatCompletions?: GatewayHttpChatCompletionsConfig;
   responses?: GatewayHttpResponsesConfig;
+  events?: GatewayHttpEventsConfig;
 };
 
 export type GatewayHttpConfig = {
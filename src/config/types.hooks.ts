@@ -113,6 +113,26 @@ export type InternalHooksConfig = {
   installs?: Record<string, HookInstallRecord>;
 };
 
+export type ShellHookEntry = {
+  /** Shell command to run; receives the event as JSON on stdin. */
+  command: string;
+  /** Tool names the hook applies to (tool events only). Omit or include "*" for all tools. */
+  tools?: string[];
+  /** Kill the command after this many milliseconds (default: 30000). */
+  timeoutMs?: number;
+};
+
+export type ShellHooksConfig = {
+  /** Before a tool runs. Exit 2 denies the call; `{"params":{...}}` on stdout rewrites arguments. */
+  preToolUse?: ShellHookEntry[];
+  /** After a tool finishes (output ignored). */
+  postToolUse?: ShellHookEntry[];
+  /** Before the agent handles a prompt; stdout is prepended to the prompt as context. */
+  userPromptSubmit?: ShellHookEntry[];
+  /** When a session is replaced by /new or /reset (output ignored). */
+  sessionEnd?: ShellHookEntry[];
+};
+
 export type HooksConfig = {
   enabled?: boolean;
   path?: string;
@@ -144,4 +164,6 @@ export type HooksConfig = {
   gmail?: HooksGmailConfig;
   /** Internal agent event hooks */
   internal?: InternalHooksConfig;
+  /** Shell commands run on tool/prompt/session lifecycle events */
+  shell?: ShellHooksConfig;
 };
@@ -7,6 +7,8 @@ export type MemoryQmdSearchMode = "query" | "search" | "vsearch";
 export type MemoryConfig = {
   backend?: MemoryBackend;
   citations?: MemoryCitationsMode;
+  /** Size cap for MEMORY.md when written via memory_write (default: bootstrapMaxChars). */
+  maxChars?: number;
   qmd?: MemoryQmdConfig;
 };
 
@@ -64,6 +64,17 @@ export type OpenClawConfig = {
     /** Check for updates on gateway start (npm installs only). */
     checkOnStart?: boolean;
   };
+  /** Profile for headless `openclaw run` (CI). Runs never wait for exec approvals. */
+  ci?: {
+    /** Agent whose workspace, model, and tool policy the run uses (default: the default agent). */
+    agent?: string;
+    /** Exec security for runs (default: tools.exec.security); approvals are not prompted. */
+    execSecurity?: "deny" | "allowlist" | "full";
+    /** Default limits for runs; `--max-turns` and friends override them. */
+    maxTurns?: number;
+    maxDurationSeconds?: number;
+    maxCostUsd?: number;
+  };
   browser?: BrowserConfig;
   ui?: {
     /** Accent color for OpenClaw UI chrome (hex). */
@@ -74,6 +85,22 @@ export type OpenClawConfig = {
       /** Assistant avatar (emoji, short text, or image URL/data URI). */
       avatar?: string;
     };
+    tui?: {
+      /** Editor key scheme for the terminal UI (default: "emacs"). */
+      editMode?: "emacs" | "vi";
+      /** Key (e.g. "alt+c", "ctrl+k", "f5") -> "abort" or a slash command such as "/compact". */
+      keybindings?: Record<string, string>;
+      /** Stream replies as they arrive (default: true); false renders each reply once complete. */
+      stream?: boolean;
+      /** Bottom status line (off by default); `true` shows every segment. */
+      statusline?:
+        | boolean
+        | {
+            enabled?: boolean;
+            /** Segments in display order (default: all). */
+            segments?: Array<"model" | "context" | "cost" | "branch" | "tools">;
+          };
+    };
   };
   skills?: SkillsConfig;
   plugins?: PluginsConfig;
@@ -0,0 +1,61 @@
+export type ExecToolConfig = {
+  /** Exec host routing (default: sandbox). */
+  host?: "sandbox" | "gateway" | "node";
+  /** Exec security mode (default: deny). */
+  security?: "deny" | "allowlist" | "full";
+  /** Exec ask mode (default: on-miss). */
+  ask?: "off" | "on-miss" | "always";
+  /** Default node binding for exec.host=node (node id/name). */
+  node?: string;
+  /** Directories to prepend to PATH when running exec (gateway/sandbox). */
+  pathPrepend?: string[];
+  /** Safe stdin-only binaries that can run without allowlist entries. */
+  safeBins?: string[];
+  /** Default time (ms) before an exec command auto-backgrounds. */
+  backgroundMs?: number;
+  /** Default timeout (seconds) before auto-killing exec commands. */
+  timeoutSec?: number;
+  /** Emit a running notice (ms) when approval-backed exec runs long (default: 10000, 0 = off). */
+  approvalRunningNoticeMs?: number;
+  /** How long to keep finished sessions in memory (ms). */
+  cleanupMs?: number;
+  /** Emit a system event and heartbeat when a backgrounded exec exits. */
+  notifyOnExit?: boolean;
+  /** Carry `cd` from earlier exec calls forward as the default workdir (default: true). */
+  trackCwd?: boolean;
+  /** Extra env vars injected into exec subprocesses (gateway + sandbox hosts). */
+  env?: Record<string, string>;
+  /**
+   * Env var names (`*` wildcards) removed from the inherited gateway env before exec runs.
+   * Replaces the default list of provider API keys and gateway credentials; [] disables scrubbing.
+   */
+  scrubEnv?: string[];
+  /** OS-level sandbox for commands that run directly on the gateway host. */
+  sandbox?: {
+    /** off (default), auto (use whatever is available), bwrap (Linux), sandbox-exec (macOS). */
+    backend?: "off" | "auto" | "bwrap" | "sandbox-exec";
+    /** Allow network access from sandboxed commands (default: false). */
+    network?: boolean;
+    /** Paths under $HOME or the state dir that stay readable (both are hidden otherwise). */
+    allowRead?: string[];
+    /** Extra writable paths besides the workspace and temp dirs. */
+    allowWrite?: string[];
+  };
+  /** Keep one long-lived shell per session so cwd/exports persist across exec calls. */
+  persistentShell?: {
+    /** Enable the persistent shell (default: false). */
+    enabled?: boolean;
+    /** Close idle persistent shells after this many ms (default: 900000). */
+    idleMs?: number;
+  };
+  /** apply_patch subtool configuration (experimental). */
+  applyPatch?: {
+    /** Enable apply_patch for OpenAI models (default: false). */
+    enabled?: boolean;
+    /**
+     * Optional allowlist of model ids that can use apply_patch.
+     * Accepts either raw ids (e.g. "gpt-5.2") or full ids (e.g. "openai/gpt-5.2").
+     */
+    allowModels?: string[];
+  };
+};
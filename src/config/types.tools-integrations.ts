@@ -0,0 +1,117 @@
+/** `http_request` tool configuration (`tools.web.http`). */
+export type HttpRequestToolConfig = {
+  /** Enable the http_request tool (default: false). */
+  enabled?: boolean;
+  /** Timeout in seconds for requests (default: 30). */
+  timeoutSeconds?: number;
+  /** Max response body characters returned to the model (default: 20000). */
+  maxChars?: number;
+  /** Maximum redirects followed for GET/HEAD requests without credentials (default: 3). */
+  maxRedirects?: number;
+  /** Only request these hostnames (supports `*.example.com`); empty allows any public host. */
+  urlAllowlist?: string[];
+  /** Never request these hostnames (supports `*.example.com`); wins over the allowlist. */
+  urlDenylist?: string[];
+  /** Allow requests to private/loopback/link-local addresses (default: false). */
+  allowPrivateNetwork?: boolean;
+  /** Override User-Agent header. */
+  userAgent?: string;
+  /**
+   * Headers injected for matching hosts, keyed by hostname or `*.example.com` pattern,
+   * e.g. `{ "api.github.com": { Authorization: "Bearer ..." } }`. Overrides model-supplied
+   * headers of the same name.
+   */
+  credentials?: Record<string, Record<string, string>>;
+};
+
+/** `run_code` tool configuration (`tools.runCode`). */
+export type RunCodeToolConfig = {
+  /** Enable the run_code tool (default: false). */
+  enabled?: boolean;
+  /** Maximum run time per call in seconds (default: 30). */
+  timeoutSeconds?: number;
+  /** Memory limit per run in MB (default: 2048). */
+  memoryMb?: number;
+  /** Max characters of stdout/stderr returned to the model (default: 20000). */
+  maxOutputChars?: number;
+  /** Python interpreter on the gateway host (default: python3, or python on Windows). */
+  python?: string;
+  /** Isolation for runs on the gateway host (sandboxed sessions run in their container). */
+  sandbox?: {
+    /** auto (default), off (run unisolated), bwrap (Linux), or sandbox-exec (macOS). */
+    backend?: "off" | "auto" | "bwrap" | "sandbox-exec";
+    /** Allow network access from snippets (default: false). */
+    network?: boolean;
+  };
+};
+
+/** One OpenAPI source in `tools.openapi`. */
+export type OpenApiToolSourceConfig = {
+  /** Path or http(s) URL of an OpenAPI 3.x document (JSON or YAML). */
+  spec: string;
+  /** Base URL for requests (default: the spec's first `servers` entry). */
+  baseUrl?: string;
+  /** Tool name prefix (default: derived from the spec title). */
+  name?: string;
+  /** Only expose these operationIds (default: all operations). */
+  operations?: string[];
+  /** Credentials added to every request. */
+  auth?:
+    | { type: "bearer"; token: string }
+    | { type: "basic"; username: string; password: string }
+    | { type: "apiKey"; in: "header" | "query"; name: string; value: string };
+  /** Request timeout in seconds (default: 30). */
+  timeoutSeconds?: number;
+};
+
+/** External executable tools (`tools.external`). */
+export type ExternalToolsConfig = {
+  /** Discover external tools (default: true). */
+  enabled?: boolean;
+  /** Directory scanned for tool executables (default: ~/.openclaw/tools). */
+  dir?: string;
+  /** Timeout for a single external tool call in seconds (default: 60). */
+  timeoutSeconds?: number;
+};
+
+/** WASI tools (`tools.wasm`). */
+export type WasmToolsConfig = {
+  /** Load configured WASM tools (default: true). */
+  enabled?: boolean;
+  /** wasmtime binary (default: "wasmtime" on PATH). */
+  runtime?: string;
+  /** Timeout for a single WASM tool call in seconds (default: 60). */
+  timeoutSeconds?: number;
+  tools?: Array<{
+    /** Path to the .wasm module or component. */
+    module: string;
+    /** Override the tool name declared by `--schema`. */
+    name?: string;
+    /** Override the tool description declared by `--schema`. */
+    description?: string;
+    /** Map the agent workspace into the guest at /workspace (default: false). */
+    workspaceAccess?: boolean;
+    /** Extra host directories visible to the guest at the same path. */
+    dirs?: string[];
+    /** Allow outbound network access (default: false). */
+    network?: boolean;
+  }>;
+};
+
+/** `gh` tool configuration (`tools.github`). */
+export type GithubToolConfig = {
+  /** Enable the gh tool (default: false). */
+  enabled?: boolean;
+  /** API token (defaults to GH_TOKEN/GITHUB_TOKEN, then the gh CLI keyring entry). */
+  token?: string;
+  /** Look up the gh CLI token in the OS keyring when no token is configured (default: true). */
+  keyring?: boolean;
+  /** REST API base URL (default: https://api.github.com; set for GitHub Enterprise). */
+  apiBaseUrl?: string;
+  /** Repository ("owner/name") used when a call omits `repo`. */
+  defaultRepo?: string;
+  /** Timeout in seconds for API requests (default: 30). */
+  timeoutSeconds?: number;
+  /** Max characters returned for diffs and issue bodies (default: 30000). */
+  maxChars?: number;
+};
@@ -1,5 +1,14 @@
 import type { ChatType } from "../channels/chat-type.js";
 import type { AgentElevatedAllowFromConfig, SessionSendPolicyAction } from "./types.base.js";
+import type { ExecToolConfig } from "./types.tools-exec.js";
+import type {
+  ExternalToolsConfig,
+  GithubToolConfig,
+  HttpRequestToolConfig,
+  OpenApiToolSourceConfig,
+  RunCodeToolConfig,
+  WasmToolsConfig,
+} from "./types.tools-integrations.js";
 
 export type MediaUnderstandingScopeMatch = {
   channel?: string;
@@ -160,41 +169,6 @@ export type GroupToolPolicyConfig = {
 
 export type GroupToolPolicyBySenderConfig = Record<string, GroupToolPolicyConfig>;
 
-export type ExecToolConfig = {
-  /** Exec host routing (default: sandbox). */
-  host?: "sandbox" | "gateway" | "node";
-  /** Exec security mode (default: deny). */
-  security?: "deny" | "allowlist" | "full";
-  /** Exec ask mode (default: on-miss). */
-  ask?: "off" | "on-miss" | "always";
-  /** Default node binding for exec.host=node (node id/name). */
-  node?: string;
-  /** Directories to prepend to PATH when running exec (gateway/sandbox). */
-  pathPrepend?: string[];
-  /** Safe stdin-only binaries that can run without allowlist entries. */
-  safeBins?: string[];
-  /** Default time (ms) before an exec command auto-backgrounds. */
-  backgroundMs?: number;
-  /** Default timeout (seconds) before auto-killing exec commands. */
-  timeoutSec?: number;
-  /** Emit a running notice (ms) when approval-backed exec runs long (default: 10000, 0 = off). */
-  approvalRunningNoticeMs?: number;
-  /** How long to keep finished sessions in memory (ms). */
-  cleanupMs?: number;
-  /** Emit a system event and heartbeat when a backgrounded exec exits. */
-  notifyOnExit?: boolean;
-  /** apply_patch subtool configuration (experimental). */
-  applyPatch?: {
-    /** Enable apply_patch for OpenAI models (default: false). */
-    enabled?: boolean;
-    /**
-     * Optional allowlist of model ids that can use apply_patch.
-     * Accepts either raw ids (e.g. "gpt-5.2") or full ids (e.g. "openai/gpt-5.2").
-     */
-    allowModels?: string[];
-  };
-};
-
 export type AgentToolsConfig = {
   /** Base tool profile applied before allow/deny lists. */
   profile?: ToolProfileId;
@@ -314,6 +288,19 @@ export type MemorySearchConfig = {
       candidateMultiplier?: number;
     };
   };
+  /** Automatic retrieval: search memory with each prompt and prepend the top hits. */
+  recall?: {
+    /** Enable per-prompt recall (default: false). */
+    enabled?: boolean;
+    /** Max snippets injected per prompt (default: 3). */
+    maxResults?: number;
+    /** Minimum score for a snippet to be injected (default: query.minScore). */
+    minScore?: number;
+    /** Cap on injected characters across all snippets (default: 2000). */
+    maxChars?: number;
+    /** Give up on recall after this long so a slow embedding provider never blocks a run (default: 1500). */
+    timeoutMs?: number;
+  };
   /** Index cache behavior. */
   cache?: {
     /** Cache chunk embeddings in SQLite (default: true). */
@@ -376,8 +363,16 @@ export type ToolsConfig = {
       timeoutSeconds?: number;
       /** Cache TTL in minutes for fetched content. */
       cacheTtlMinutes?: number;
+      /** Persist cached responses on disk and revalidate them via ETag (default: true). */
+      diskCache?: boolean;
       /** Maximum number of redirects to follow (default: 3). */
       maxRedirects?: number;
+      /** Only fetch these hostnames (supports `*.example.com`); empty allows any public host. */
+      urlAllowlist?: string[];
+      /** Never fetch these hostnames (supports `*.example.com`); wins over the allowlist. */
+      urlDenylist?: string[];
+      /** Allow fetching private/loopback/link-local addresses (default: false). */
+      allowPrivateNetwork?: boolean;
       /** Override User-Agent header for fetch requests. */
       userAgent?: string;
       /** Use Readability to extract main content (default: true). */
@@ -397,9 +392,32 @@ export type ToolsConfig = {
         timeoutSeconds?: number;
       };
     };
+    http?: HttpRequestToolConfig;
   };
   media?: MediaToolsConfig;
   links?: LinkToolsConfig;
+  /** Code interpreter (`run_code`) tool configuration. */
+  runCode?: RunCodeToolConfig;
+  /** Reuse identical read/grep/find/ls/web_fetch results within a run. */
+  resultCache?: {
+    /** Enable tool result caching (default: true). */
+    enabled?: boolean;
+  };
+  /** Refuse repeated identical or alternating failing tool calls within a run. */
+  loopDetection?: {
+    /** Enable tool-call loop detection (default: true). */
+    enabled?: boolean;
+    /** Identical consecutive calls allowed before the next one is refused (default: 3). */
+    maxRepeats?: number;
+  };
+  /** REST APIs exposed as tools, one per OpenAPI operation. */
+  openapi?: OpenApiToolSourceConfig[];
+  /** Executables exposed as agent tools over a JSON stdin/stdout protocol. */
+  external?: ExternalToolsConfig;
+  /** WASI tools run under wasmtime with explicit filesystem/network grants. */
+  wasm?: WasmToolsConfig;
+  /** GitHub (`gh`) tool configuration. */
+  github?: GithubToolConfig;
   /** Message tool configuration. */
   message?: {
     /**
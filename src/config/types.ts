@@ -28,5 +28,7 @@ export * from "./types.slack.js";
 export * from "./types.telegram.js";
 export * from "./types.tts.js";
 export * from "./types.tools.js";
+export * from "./types.tools-exec.js";
+export * from "./types.tools-integrations.js";
 export * from "./types.whatsapp.js";
 export * from "./types.memory.js";
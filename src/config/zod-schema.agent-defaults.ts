@@ -47,6 +47,15 @@ export const AgentDefaultsSchema = z
     repoRoot: z.string().optional(),
     skipBootstrap: z.boolean().optional(),
     bootstrapMaxChars: z.number().int().positive().optional(),
+    bootstrapTotalMaxChars: z.number().int().positive().optional(),
+    workspaceMap: z
+      .object({
+        enabled: z.boolean().optional(),
+        maxDepth: z.number().int().min(1).max(5).optional(),
+        maxEntries: z.number().int().positive().optional(),
+      })
+      .strict()
+      .optional(),
     userTimezone: z.string().optional(),
     timeFormat: z.union([z.literal("auto"), z.literal("12"), z.literal("24")]).optional(),
     envelopeTimezone: z.string().optional(),
@@ -93,6 +102,9 @@ export const AgentDefaultsSchema = z
         mode: z.union([z.literal("default"), z.literal("safeguard")]).optional(),
         reserveTokensFloor: z.number().int().nonnegative().optional(),
         maxHistoryShare: z.number().min(0.1).max(0.9).optional(),
+        thresholdPercent: z.number().min(10).max(95).optional(),
+        keepRecentTokens: z.number().int().positive().optional(),
+        model: z.string().optional(),
         memoryFlush: z
           .object({
             enabled: z.boolean().optional(),
@@ -102,6 +114,14 @@ export const AgentDefaultsSchema = z
           })
           .strict()
           .optional(),
+        pruneToolResults: z
+          .object({
+            enabled: z.boolean().optional(),
+            keepLastAssistants: z.number().int().nonnegative().optional(),
+            minChars: z.number().int().nonnegative().optional(),
+          })
+          .strict()
+          .optional(),
       })
       .strict()
       .optional(),
@@ -125,6 +145,36 @@ export const AgentDefaultsSchema = z
     blockStreamingCoalesce: BlockStreamingCoalesceSchema.optional(),
     humanDelay: HumanDelaySchema.optional(),
     timeoutSeconds: z.number().int().positive().optional(),
+    maxTurns: z.number().int().positive().optional(),
+    maxDurationSeconds: z.number().int().positive().optional(),
+    maxCostUsd: z.number().positive().optional(),
+    budget: z
+      .object({
+        sessionUsd: z.number().positive().optional(),
+        dailyUsd: z.number().positive().optional(),
+        monthlyUsd: z.number().positive().optional(),
+        warnPercent: z.number().min(1).max(100).optional(),
+      })
+      .strict()
+      .optional(),
+    responseCache: z
+      .object({
+        enabled: z.boolean().optional(),
+        ttlSeconds: z.number().int().positive().optional(),
+        dir: z.string().optional(),
+        maxEntries: z.number().int().positive().optional(),
+        maxMb: z.number().positive().optional(),
+      })
+      .strict()
+      .optional(),
+    council: z
+      .object({
+        models: z.array(z.string()).optional(),
+        judge: z.string().optional(),
+        timeoutSeconds: z.number().int().positive().optional(),
+      })
+      .strict()
+      .optional(),
     mediaMaxMb: z.number().positive().optional(),
     typingIntervalSeconds: z.number().int().positive().optional(),
     typingMode: z
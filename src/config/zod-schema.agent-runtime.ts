@@ -205,16 +205,69 @@ export const ToolsWebFetchSchema = z
     maxCharsCap: z.number().int().positive().optional(),
     timeoutSeconds: z.number().int().positive().optional(),
     cacheTtlMinutes: z.number().nonnegative().optional(),
+    diskCache: z.boolean().optional(),
     maxRedirects: z.number().int().nonnegative().optional(),
+    urlAllowlist: z.array(z.string()).optional(),
+    urlDenylist: z.array(z.string()).optional(),
+    allowPrivateNetwork: z.boolean().optional(),
     userAgent: z.string().optional(),
   })
   .strict()
   .optional();
 
+export const ToolsWebHttpSchema = z
+  .object({
+    enabled: z.boolean().optional(),
+    timeoutSeconds: z.number().int().positive().optional(),
+    maxChars: z.number().int().positive().optional(),
+    maxRedirects: z.number().int().nonnegative().optional(),
+    urlAllowlist: z.array(z.string()).optional(),
+    urlDenylist: z.array(z.string()).optional(),
+    allowPrivateNetwork: z.boolean().optional(),
+    userAgent: z.string().optional(),
+    credentials: z
+      .record(z.string(), z.record(z.string(), z.string().register(sensitive)))
+      .optional(),
+  })
+  .strict()
+  .optional();
+
+export const ToolsRunCodeSchema = z
+  .object({
+    enabled: z.boolean().optional(),
+    timeoutSeconds: z.number().int().positive().optional(),
+    memoryMb: z.number().int().positive().optional(),
+    maxOutputChars: z.number().int().positive().optional(),
+    python: z.string().optional(),
+    sandbox: z
+      .object({
+        backend: z.enum(["off", "auto", "bwrap", "sandbox-exec"]).optional(),
+        network: z.boolean().optional(),
+      })
+      .strict()
+      .optional(),
+  })
+  .strict()
+  .optional();
+
+export const ToolsGitHubSchema = z
+  .object({
+    enabled: z.boolean().optional(),
+    token: z.string().optional().register(sensitive),
+    keyring: z.boolean().optional(),
+    apiBaseUrl: z.string().optional(),
+    defaultRepo: z.string().optional(),
+    timeoutSeconds: z.number().int().positive().optional(),
+    maxChars: z.number().int().positive().optional(),
+  })
+  .strict()
+  .optional();
+
 export const ToolsWebSchema = z
   .object({
     search: ToolsWebSearchSchema,
     fetch: ToolsWebFetchSchema,
+    http: ToolsWebHttpSchema,
   })
   .strict()
   .optional();
@@ -288,6 +341,25 @@ export const AgentToolsSchema = z
         approvalRunningNoticeMs: z.number().int().nonnegative().optional(),
         cleanupMs: z.number().int().positive().optional(),
         notifyOnExit: z.boolean().optional(),
+        trackCwd: z.boolean().optional(),
+        env: z.record(z.string(), z.string()).optional(),
+        scrubEnv: z.array(z.string()).optional(),
+        sandbox: z
+          .object({
+            backend: z.enum(["off", "auto", "bwrap", "sandbox-exec"]).optional(),
+            network: z.boolean().optional(),
+            allowRead: z.array(z.string()).optional(),
+            allowWrite: z.array(z.string()).optional(),
+          })
+          .strict()
+          .optional(),
+        persistentShell: z
+          .object({
+            enabled: z.boolean().optional(),
+            idleMs: z.number().int().positive().optional(),
+          })
+          .strict()
+          .optional(),
         applyPatch: z
           .object({
             enabled: z.boolean().optional(),
@@ -420,6 +492,16 @@ export const MemorySearchSchema = z
       })
       .strict()
       .optional(),
+    recall: z
+      .object({
+        enabled: z.boolean().optional(),
+        maxResults: z.number().int().positive().optional(),
+        minScore: z.number().min(0).max(1).optional(),
+        maxChars: z.number().int().positive().optional(),
+        timeoutMs: z.number().int().positive().optional(),
+      })
+      .strict()
+      .optional(),
     cache: z
       .object({
         enabled: z.boolean().optional(),
@@ -486,6 +568,92 @@ export const ToolsSchema = z
     web: ToolsWebSchema,
     media: ToolsMediaSchema,
     links: ToolsLinksSchema,
+    runCode: ToolsRunCodeSchema,
+    resultCache: z
+      .object({
+        enabled: z.boolean().optional(),
+      })
+      .strict()
+      .optional(),
+    loopDetection: z
+      .object({
+        enabled: z.boolean().optional(),
+        maxRepeats: z.number().int().positive().optional(),
+      })
+      .strict()
+      .optional(),
+    openapi: z
+      .array(
+        z
+          .object({
+            spec: z.string().min(1),
+            baseUrl: z.string().optional(),
+            name: z.string().optional(),
+            operations: z.array(z.string()).optional(),
+            auth: z
+              .union([
+                z
+                  .object({
+                    type: z.literal("bearer"),
+                    token: z.string().register(sensitive),
+                  })
+                  .strict(),
+                z
+                  .object({
+                    type: z.literal("basic"),
+                    username: z.string(),
+                    password: z.string().register(sensitive),
+                  })
+                  .strict(),
+                z
+                  .object({
+                    type: z.literal("apiKey"),
+                    in: z.union([z.literal("header"), z.literal("query")]),
+                    name: z.string().min(1),
+                    value: z.string().register(sensitive),
+                  })
+                  .strict(),
+              ])
+              .optional(),
+            timeoutSeconds: z.number().int().positive().optional(),
+          })
+          .strict(),
+      )
+      .optional(),
+    external: z
+      .object({
+        enabled: z.boolean().optional(),
+        dir: z.string().optional(),
+        timeoutSeconds: z.number().int().positive().optional(),
+      })
+      .strict()
+      .optional(),
+    wasm: z
+      .object({
+        enabled: z.boolean().optional(),
+        runtime: z.string().optional(),
+        timeoutSeconds: z.number().int().positive().optional(),
+        tools: z
+          .array(
+            z
+              .object({
+                module: z.string().min(1),
+                name: z
+                  .string()
+                  .regex(/^[a-zA-Z][a-zA-Z0-9_-]{0,63}$/)
+                  .optional(),
+                description: z.string().optional(),
+                workspaceAccess: z.boolean().optional(),
+                dirs: z.array(z.string()).optional(),
+                network: z.boolean().optional(),
+              })
+              .strict(),
+          )
+          .optional(),
+      })
+      .strict()
+      .optional(),
+    github: ToolsGitHubSchema,
     message: z
       .object({
         allowCrossContextSend: z.boolean().optional(),
@@ -539,6 +707,25 @@ export const ToolsSchema = z
         timeoutSec: z.number().int().positive().optional(),
         cleanupMs: z.number().int().positive().optional(),
         notifyOnExit: z.boolean().optional(),
+        trackCwd: z.boolean().optional(),
+        env: z.record(z.string(), z.string()).optional(),
+        scrubEnv: z.array(z.string()).optional(),
+        sandbox: z
+          .object({
+            backend: z.enum(["off", "auto", "bwrap", "sandbox-exec"]).optional(),
+            network: z.boolean().optional(),
+            allowRead: z.array(z.string()).optional(),
+            allowWrite: z.array(z.string()).optional(),
+          })
+          .strict()
+          .optional(),
+        persistentShell: z
+          .object({
+            enabled: z.boolean().optional(),
+            idleMs: z.number().int().positive().optional(),
+          })
+          .strict()
+          .optional(),
         applyPatch: z
           .object({
             enabled: z.boolean().optional(),
@@ -122,6 +122,24 @@ export const InternalHooksSchema = z
   .strict()
   .optional();
 
+const ShellHookEntrySchema = z
+  .object({
+    command: z.string().min(1),
+    tools: z.array(z.string()).optional(),
+    timeoutMs: z.number().int().positive().optional(),
+  })
+  .strict();
+
+export const ShellHooksSchema = z
+  .object({
+    preToolUse: z.array(ShellHookEntrySchema).optional(),
+    postToolUse: z.array(ShellHookEntrySchema).optional(),
+    userPromptSubmit: z.array(ShellHookEntrySchema).optional(),
+    sessionEnd: z.array(ShellHookEntrySchema).optional(),
+  })
+  .strict()
+  .optional();
+
 export const HooksGmailSchema = z
   .object({
     account: z.string().optional(),
@@ -73,6 +73,7 @@ export const SessionSchema = z
         pruneDays: z.number().int().positive().optional(),
         maxEntries: z.number().int().positive().optional(),
         rotateBytes: z.union([z.string(), z.number()]).optional(),
+        transcripts: z.enum(["archive", "delete", "keep"]).optional(),
       })
       .strict()
       .superRefine((val, ctx) => {
@@ -100,6 +101,13 @@ export const SessionSchema = z
         }
       })
       .optional(),
+    autoTitle: z
+      .object({
+        enabled: z.boolean().optional(),
+        model: z.string().optional(),
+      })
+      .strict()
+      .optional(),
   })
   .strict()
   .optional();
@@ -3,7 +3,12 @@ import { ToolsSchema } from "./zod-schema.agent-runtime.js";
 import { AgentsSchema, AudioSchema, BindingsSchema, BroadcastSchema } from "./zod-schema.agents.js";
 import { ApprovalsSchema } from "./zod-schema.approvals.js";
 import { HexColorSchema, ModelsConfigSchema } from "./zod-schema.core.js";
-import { HookMappingSchema, HooksGmailSchema, InternalHooksSchema } from "./zod-schema.hooks.js";
+import {
+  HookMappingSchema,
+  HooksGmailSchema,
+  InternalHooksSchema,
+  ShellHooksSchema,
+} from "./zod-schema.hooks.js";
 import { ChannelsSchema } from "./zod-schema.providers.js";
 import { sensitive } from "./zod-schema.sensitive.js";
 import {
@@ -20,6 +25,12 @@ const BrowserSnapshotDefaultsSchema = z
   .strict()
   .optional();
 
+const TUI_NAMED_KEYS =
+  "enter|tab|space|backspace|delete|home|end|pageup|pagedown|up|down|left|right";
+const TUI_KEY_PATTERN = new RegExp(
+  `^(?:(?:ctrl|alt|shift)\\+)*(?:[a-z0-9]|f(?:[1-9]|1[0-2])|${TUI_NAMED_KEYS})$`,
+);
+
 const NodeHostSchema = z
   .object({
     browserProxy: z
@@ -88,6 +99,7 @@ const MemorySchema = z
   .object({
     backend: z.union([z.literal("builtin"), z.literal("qmd")]).optional(),
     citations: z.union([z.literal("auto"), z.literal("on"), z.literal("off")]).optional(),
+    maxChars: z.number().int().positive().optional(),
     qmd: MemoryQmdSchema.optional(),
   })
   .strict()
@@ -172,6 +184,8 @@ export const OpenClawSchema = z
           ])
           .optional(),
         file: z.string().optional(),
+        maxFileBytes: z.number().int().positive().optional(),
+        maxFiles: z.number().int().min(0).optional(),
         consoleLevel: z
           .union([
             z.literal("silent"),
@@ -198,6 +212,18 @@ export const OpenClawSchema = z
       })
       .strict()
       .optional(),
+    ci: z
+      .object({
+        agent: z.string().optional(),
+        execSecurity: z
+          .union([z.literal("deny"), z.literal("allowlist"), z.literal("full")])
+          .optional(),
+        maxTurns: z.number().int().positive().optional(),
+        maxDurationSeconds: z.number().int().positive().optional(),
+        maxCostUsd: z.number().positive().optional(),
+      })
+      .strict()
+      .optional(),
     browser: z
       .object({
         enabled: z.boolean().optional(),
@@ -243,6 +269,44 @@ export const OpenClawSchema = z
           })
           .strict()
           .optional(),
+        tui: z
+          .object({
+            editMode: z.union([z.literal("emacs"), z.literal("vi")]).optional(),
+            keybindings: z
+              .record(
+                z.string().regex(TUI_KEY_PATTERN, "Expected a key such as ctrl+r, alt+c, or f5"),
+                z
+                  .string()
+                  .refine((action) => action === "abort" || action.startsWith("/"), {
+                    message: 'Expected "abort" or a slash command such as "/compact"',
+                  }),
+              )
+              .optional(),
+            stream: z.boolean().optional(),
+            statusline: z
+              .union([
+                z.boolean(),
+                z
+                  .object({
+                    enabled: z.boolean().optional(),
+                    segments: z
+                      .array(
+                        z.union([
+                          z.literal("model"),
+                          z.literal("context"),
+                          z.literal("cost"),
+                          z.literal("branch"),
+                          z.literal("tools"),
+                        ]),
+                      )
+                      .optional(),
+                  })
+                  .strict(),
+              ])
+              .optional(),
+          })
+          .strict()
+          .optional(),
       })
       .strict()
       .optional(),
@@ -314,6 +378,7 @@ export const OpenClawSchema = z
         mappings: z.array(HookMappingSchema).optional(),
         gmail: HooksGmailSchema,
         internal: InternalHooksSchema,
+        shell: ShellHooksSchema,
       })
       .strict()
       .optional(),
@@ -484,6 +549,12 @@ export const OpenClawSchema = z
                   })
                   .strict()
                   .optional(),
+                events: z
+                  .object({
+                    enabled: z.boolean().optional(),
+                  })
+                  .strict()
+                  .optional(),
                 responses: z
                   .object({
                     enabled: z.boolean().optional(),
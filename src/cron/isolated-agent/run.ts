@@ -221,16 +221,21 @@ export async function runCronIsolatedAgentTurn(params: {
     model = resolvedOverride.ref.model;
   }
   const now = Date.now();
+  const continueSession =
+    params.job.payload.kind === "agentTurn" && params.job.payload.continueSession === true;
   const cronSession = resolveCronSession({
     cfg: params.cfg,
     sessionKey: agentSessionKey,
     agentId,
     nowMs: now,
+    continueSession,
   });
   const runSessionId = cronSession.sessionEntry.sessionId;
-  const runSessionKey = baseSessionKey.startsWith("cron:")
-    ? `${agentSessionKey}:run:${runSessionId}`
-    : agentSessionKey;
+  // Continued jobs append every run to the job session, so there is no per-run copy to reap.
+  const runSessionKey =
+    baseSessionKey.startsWith("cron:") && !continueSession
+      ? `${agentSessionKey}:run:${runSessionId}`
+      : agentSessionKey;
   const persistSessionEntry = async () => {
     cronSession.store[agentSessionKey] = cronSession.sessionEntry;
     if (runSessionKey !== agentSessionKey) {
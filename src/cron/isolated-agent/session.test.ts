@@ -70,4 +70,44 @@ describe("resolveCronSession", () => {
     expect(result.sessionEntry.providerOverride).toBeUndefined();
     expect(result.sessionEntry.model).toBeUndefined();
   });
+
+  it("reuses the stored session when continueSession is set", () => {
+    vi.mocked(loadSessionStore).mockReturnValue({
+      "agent:main:cron:test-job": {
+        sessionId: "old-session-id",
+        updatedAt: 1000,
+        systemSent: true,
+        totalTokens: 1200,
+      },
+    });
+
+    const result = resolveCronSession({
+      cfg: {} as OpenClawConfig,
+      sessionKey: "agent:main:cron:test-job",
+      agentId: "main",
+      nowMs: 2000,
+      continueSession: true,
+    });
+
+    expect(result.isNewSession).toBe(false);
+    expect(result.systemSent).toBe(true);
+    expect(result.sessionEntry.sessionId).toBe("old-session-id");
+    expect(result.sessionEntry.totalTokens).toBe(1200);
+    expect(result.sessionEntry.updatedAt).toBe(2000);
+  });
+
+  it("starts fresh with continueSession when nothing is stored yet", () => {
+    vi.mocked(loadSessionStore).mockReturnValue({});
+
+    const result = resolveCronSession({
+      cfg: {} as OpenClawConfig,
+      sessionKey: "agent:main:cron:new-job",
+      agentId: "main",
+      nowMs: Date.now(),
+      continueSession: true,
+    });
+
+    expect(result.isNewSession).toBe(true);
+    expect(result.sessionEntry.sessionId).toBeTruthy();
+  });
 });
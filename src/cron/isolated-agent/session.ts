@@ -7,6 +7,8 @@ export function resolveCronSession(params: {
   sessionKey: string;
   nowMs: number;
   agentId: string;
+  /** Reuse the stored session (history included) instead of starting a fresh one. */
+  continueSession?: boolean;
 }) {
   const sessionCfg = params.cfg.session;
   const storePath = resolveStorePath(sessionCfg?.store, {
@@ -14,6 +16,16 @@ export function resolveCronSession(params: {
   });
   const store = loadSessionStore(storePath);
   const entry = store[params.sessionKey];
+  if (params.continueSession && entry?.sessionId) {
+    const sessionEntry: SessionEntry = { ...entry, updatedAt: params.nowMs };
+    return {
+      storePath,
+      store,
+      sessionEntry,
+      systemSent: entry.systemSent ?? false,
+      isNewSession: false,
+    };
+  }
   const sessionId = crypto.randomUUID();
   const systemSent = false;
   const sessionEntry: SessionEntry = {
@@ -123,6 +123,9 @@ function coercePayload(payload: UnknownRecord) {
       delete next.timeoutSeconds;
     }
   }
+  if ("continueSession" in next && typeof next.continueSession !== "boolean") {
+    delete next.continueSession;
+  }
   if (
     "allowUnsafeExternalContent" in next &&
     typeof next.allowUnsafeExternalContent !== "boolean"
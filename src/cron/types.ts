@@ -30,6 +30,8 @@ export type CronPayload =
       model?: string;
       thinking?: string;
       timeoutSeconds?: number;
+      /** Keep one session across runs so each run sees the previous results. */
+      continueSession?: boolean;
       allowUnsafeExternalContent?: boolean;
       deliver?: boolean;
       channel?: CronMessageChannel;
@@ -45,6 +47,7 @@ export type CronPayloadPatch =
       model?: string;
       thinking?: string;
       timeoutSeconds?: number;
+      continueSession?: boolean;
       allowUnsafeExternalContent?: boolean;
       deliver?: boolean;
       channel?: CronMessageChannel;
@@ -1,6 +1,7 @@
 #!/usr/bin/env node
 import { spawn } from "node:child_process";
 import process from "node:process";
+import { applyCliLogEnv, parseCliLogArgs } from "./cli/log-flags.js";
 import { applyCliProfileEnv, parseCliProfileArgs } from "./cli/profile.js";
 import { shouldSkipRespawnForArgv } from "./cli/respawn-policy.js";
 import { normalizeWindowsArgv } from "./cli/windows-argv.js";
@@ -96,6 +97,14 @@ if (!ensureExperimentalWarningSuppressed()) {
     process.argv = parsed.argv;
   }
 
+  const logArgs = parseCliLogArgs(process.argv);
+  if (!logArgs.ok) {
+    console.error(`[openclaw] ${logArgs.error}`);
+    process.exit(2);
+  }
+  applyCliLogEnv(logArgs);
+  process.argv = logArgs.argv;
+
   import("./cli/run-main.js")
     .then(({ runCli }) => runCli(process.argv))
     .catch((error) => {
@@ -0,0 +1,127 @@
+import type { AddressInfo } from "node:net";
+import { createServer } from "node:http";
+import { afterAll, beforeAll, describe, expect, it, vi } from "vitest";
+import { emitAgentEvent } from "../infra/agent-events.js";
+
+vi.mock("./auth.js", () => ({
+  authorizeGatewayConnect: async (params: { connectAuth?: { token?: string } }) =>
+    params.connectAuth?.token === "secret" ? { ok: true } : { ok: false },
+}));
+
+const { handleAgentEventsHttpRequest, matchesAgentEventFilter, parseAgentEventFilter } =
+  await import("./agent-events-http.js");
+
+let port = 0;
+let server: ReturnType<typeof createServer> | undefined;
+
+beforeAll(async () => {
+  server = createServer((req, res) => {
+    void handleAgentEventsHttpRequest(req, res, {
+      auth: { mode: "token", token: "secret", allowTailscale: false },
+    }).then((handled) => {
+      if (!handled) {
+        res.statusCode = 404;
+        res.end("not found");
+      }
+    });
+  });
+  await new Promise<void>((resolve) => server?.listen(0, "127.0.0.1", resolve));
+  port = (server?.address() as AddressInfo).port;
+});
+
+afterAll(async () => {
+  await new Promise<void>((resolve) => server?.close(() => resolve()));
+});
+
+describe("agent event filter", () => {
+  it("parses session, run, and stream filters", () => {
+    const filter = parseAgentEventFilter(
+      new URLSearchParams("sessionKey=agent:main:main&streams=assistant, tool"),
+    );
+    expect(filter.sessionKey).toBe("agent:main:main");
+    expect(filter.runId).toBeUndefined();
+    expect([...(filter.streams ?? [])]).toEqual(["assistant", "tool"]);
+  });
+
+  it("matches only events that pass every filter", () => {
+    const evt = {
+      runId: "run-1",
+      seq: 1,
+      stream: "tool",
+      ts: 0,
+      data: {},
+      sessionKey: "agent:main:main",
+    };
+    expect(matchesAgentEventFilter(evt, {})).toBe(true);
+    expect(matchesAgentEventFilter(evt, { sessionKey: "agent:main:main", runId: "run-1" })).toBe(
+      true,
+    );
+    expect(matchesAgentEventFilter(evt, { sessionKey: "agent:other:main" })).toBe(false);
+    expect(matchesAgentEventFilter(evt, { agentId: "main" })).toBe(true);
+    expect(matchesAgentEventFilter(evt, { agentId: "other" })).toBe(false);
+    expect(matchesAgentEventFilter({ ...evt, sessionKey: undefined }, { agentId: "main" })).toBe(
+      false,
+    );
+    expect(matchesAgentEventFilter(evt, { streams: new Set(["assistant"]) })).toBe(false);
+  });
+});
+
+describe("GET /v1/events", () => {
+  it("rejects unauthenticated requests and other methods", async () => {
+    const unauthorized = await fetch(`http://127.0.0.1:${port}/v1/events`);
+    expect(unauthorized.status).toBe(401);
+    await unauthorized.text();
+
+    const post = await fetch(`http://127.0.0.1:${port}/v1/events`, {
+      method: "POST",
+      headers: { authorization: "Bearer secret" },
+    });
+    expect(post.status).toBe(405);
+    await post.text();
+
+    const unscoped = await fetch(`http://127.0.0.1:${port}/v1/events?runId=run-sse`, {
+      headers: { authorization: "Bearer secret" },
+    });
+    expect(unscoped.status).toBe(400);
+    await unscoped.text();
+  });
+
+  it("streams matching agent events as SSE", async () => {
+    const controller = new AbortController();
+    const res = await fetch(`http://127.0.0.1:${port}/v1/events?agentId=main&runId=run-sse`, {
+      headers: { authorization: "Bearer secret" },
+      signal: controller.signal,
+    });
+    expect(res.status).toBe(200);
+    expect(res.headers.get("content-type")).toContain("text/event-stream");
+
+    const reader = res.body?.getReader();
+    if (!reader) {
+      throw new Error("expected a response body");
+    }
+    const decoder = new TextDecoder();
+    let text = decoder.decode((await reader.read()).value);
+    expect(text).toContain(": connected");
+
+    const sessionKey = "agent:main:main";
+    const emit = (runId: string, delta: string) =>
+      emitAgentEvent({ runId, stream: "assistant", data: { delta }, sessionKey });
+    emit("run-other", "skip");
+    emit("run-sse", "hello");
+    while (!text.includes("hello")) {
+      text += decoder.decode((await reader.read()).value);
+    }
+    controller.abort();
+
+    expect(text).toContain("event: agent");
+    expect(text).not.toContain("skip");
+    const data = text
+      .split("\n")
+      .find((line) => line.startsWith("data: ") && line.includes("hello"));
+    expect(JSON.parse(data?.slice("data: ".length) ?? "{}")).toMatchObject({
+      runId: "run-sse",
+      stream: "assistant",
+      data: { delta: "hello" },
+    });
+  });
+});
@@ -0,0 +1,150 @@
+import type { IncomingMessage, ServerResponse } from "node:http";
+import type { AuthRateLimiter } from "./auth-rate-limit.js";
+import { type AgentEventPayload, onAgentEvent } from "../infra/agent-events.js";
+import { normalizeAgentId, parseAgentSessionKey } from "../routing/session-key.js";
+import { authorizeGatewayConnect, type ResolvedGatewayAuth } from "./auth.js";
+import {
+  sendGatewayAuthFailure,
+  sendInvalidRequest,
+  sendMethodNotAllowed,
+  setSseHeaders,
+} from "./http-common.js";
+import { getBearerToken, resolveAgentIdFromHeader } from "./http-utils.js";
+
+type AgentEventsHttpOptions = {
+  auth: ResolvedGatewayAuth;
+  trustedProxies?: string[];
+  rateLimiter?: AuthRateLimiter;
+  keepaliveMs?: number;
+  maxBufferedBytes?: number;
+};
+
+const DEFAULT_KEEPALIVE_MS = 15_000;
+/** A client this far behind is dropped instead of buffering its backlog in the gateway. */
+const DEFAULT_MAX_BUFFERED_BYTES = 1024 * 1024;
+
+export type AgentEventFilter = {
+  sessionKey?: string;
+  agentId?: string;
+  runId?: string;
+  streams?: Set<string>;
+};
+
+export function parseAgentEventFilter(
+  params: URLSearchParams,
+  headerAgentId?: string,
+): AgentEventFilter {
+  const sessionKey = params.get("sessionKey")?.trim() || undefined;
+  const rawAgentId = params.get("agentId")?.trim();
+  const agentId = rawAgentId ? normalizeAgentId(rawAgentId) : headerAgentId;
+  const runId = params.get("runId")?.trim() || undefined;
+  const streams = (params.get("streams") ?? "")
+    .split(",")
+    .map((entry) => entry.trim())
+    .filter(Boolean);
+  return {
+    sessionKey,
+    agentId,
+    runId,
+    streams: streams.length > 0 ? new Set(streams) : undefined,
+  };
+}
+
+export function matchesAgentEventFilter(evt: AgentEventPayload, filter: AgentEventFilter): boolean {
+  if (filter.sessionKey && evt.sessionKey !== filter.sessionKey) {
+    return false;
+  }
+  if (filter.agentId) {
+    const parsed = parseAgentSessionKey(evt.sessionKey);
+    if (!parsed || normalizeAgentId(parsed.agentId) !== filter.agentId) {
+      return false;
+    }
+  }
+  if (filter.runId && evt.runId !== filter.runId) {
+    return false;
+  }
+  if (filter.streams && !filter.streams.has(evt.stream)) {
+    return false;
+  }
+  return true;
+}
+
+/**
+ * `GET /v1/events`: the live agent event stream (assistant deltas, tool calls/results,
+ * lifecycle, compaction) as Server-Sent Events, for custom frontends that cannot speak the
+ * Gateway WebSocket protocol. Scoped to one session or agent; clients that stop reading are
+ * disconnected.
+ */
+export async function handleAgentEventsHttpRequest(
+  req: IncomingMessage,
+  res: ServerResponse,
+  opts: AgentEventsHttpOptions,
+): Promise<boolean> {
+  const url = new URL(req.url ?? "/", `http://${req.headers.host || "localhost"}`);
+  if (url.pathname !== "/v1/events") {
+    return false;
+  }
+
+  if (req.method !== "GET") {
+    sendMethodNotAllowed(res, "GET");
+    return true;
+  }
+
+  const token = getBearerToken(req);
+  const authResult = await authorizeGatewayConnect({
+    auth: opts.auth,
+    connectAuth: { token, password: token },
+    req,
+    trustedProxies: opts.trustedProxies,
+    rateLimiter: opts.rateLimiter,
+  });
+  if (!authResult.ok) {
+    sendGatewayAuthFailure(res, authResult);
+    return true;
+  }
+
+  const filter = parseAgentEventFilter(url.searchParams, resolveAgentIdFromHeader(req));
+  if (!filter.sessionKey && !filter.agentId) {
+    sendInvalidRequest(res, "sessionKey or agentId is required");
+    return true;
+  }
+  setSseHeaders(res);
+
+  const maxBufferedBytes = opts.maxBufferedBytes ?? DEFAULT_MAX_BUFFERED_BYTES;
+  let closed = false;
+  const close = () => {
+    if (closed) {
+      return;
+    }
+    closed = true;
+    clearInterval(keepalive);
+    unsubscribe();
+    res.destroy();
+  };
+  const send = (chunk: string) => {
+    if (closed) {
+      return;
+    }
+    res.write(chunk);
+    if (res.writableLength > maxBufferedBytes) {
+      close();
+    }
+  };
+
+  const unsubscribe = onAgentEvent((evt) => {
+    if (matchesAgentEventFilter(evt, filter)) {
+      send(`event: agent\ndata: ${JSON.stringify(evt)}\n\n`);
+    }
+  });
+  // Comment lines keep proxies from timing out idle streams.
+  const keepalive = setInterval(
+    () => send(": keepalive\n\n"),
+    opts.keepaliveMs ?? DEFAULT_KEEPALIVE_MS,
+  );
+  keepalive.unref?.();
+
+  req.on("close", close);
+  res.on("error", close);
+  send(": connected\n\n");
+  return true;
+}
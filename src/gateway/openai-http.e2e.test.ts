@@ -344,6 +344,29 @@ describe("OpenAI-compatible HTTP API (e2e)", () => {
     }
   });
 
+  it("lists configured agents on /v1/models", async () => {
+    testState.agentsConfig = { list: [{ id: "main", default: true }, { id: "beta" }] };
+    try {
+      const unauthorized = await fetch(`http://127.0.0.1:${enabledPort}/v1/models`);
+      expect(unauthorized.status).toBe(401);
+      await unauthorized.text();
+
+      const res = await fetch(`http://127.0.0.1:${enabledPort}/v1/models`, {
+        headers: { authorization: "Bearer secret" },
+      });
+      expect(res.status).toBe(200);
+      const json = (await res.json()) as { object?: string; data?: Array<{ id?: string }> };
+      expect(json.object).toBe("list");
+      expect(json.data?.map((model) => model.id)).toEqual([
+        "openclaw",
+        "openclaw:main",
+        "openclaw:beta",
+      ]);
+    } finally {
+      testState.agentsConfig = undefined;
+    }
+  });
+
   it("returns 429 for repeated failed auth when gateway.auth.rateLimit is configured", async () => {
     const { startGatewayServer } = await import("./server.js");
     testState.gatewayAuth = {
@@ -1,8 +1,11 @@
 import type { IncomingMessage, ServerResponse } from "node:http";
 import { randomUUID } from "node:crypto";
 import type { AuthRateLimiter } from "./auth-rate-limit.js";
+import { listAgentIds } from "../agents/agent-scope.js";
+import { resolveStopSequences } from "../agents/pi-embedded-runner/extra-params.js";
 import { createDefaultDeps } from "../cli/deps.js";
 import { agentCommand } from "../commands/agent.js";
+import { loadConfig } from "../config/config.js";
 import { emitAgentEvent, onAgentEvent } from "../infra/agent-events.js";
 import { logWarn } from "../logger.js";
 import { defaultRuntime } from "../runtime.js";
@@ -39,6 +42,7 @@ type OpenAiChatCompletionRequest = {
   stream?: unknown;
   messages?: unknown;
   user?: unknown;
+  stop?: unknown;
 };
 
 function writeSse(res: ServerResponse, data: unknown) {
@@ -139,6 +143,20 @@ function resolveOpenAiSessionKey(params: {
   return resolveSessionKey({ ...params, prefix: "openai" });
 }
 
+/**
+ * Each agent is exposed as an `openclaw:<agentId>` model (routed by `resolveAgentIdFromModel`),
+ * so clients that pick models from `/v1/models` can target a specific agent.
+ */
+function listOpenAiModels() {
+  const created = Math.floor(Date.now() / 1000);
+  return ["openclaw", ...listAgentIds(loadConfig()).map((id) => `openclaw:${id}`)].map((id) => ({
+    id,
+    object: "model",
+    created,
+    owned_by: "openclaw",
+  }));
+}
+
 function coerceRequest(val: unknown): OpenAiChatCompletionRequest {
   if (!val || typeof val !== "object") {
     return {};
@@ -152,12 +170,13 @@ export async function handleOpenAiHttpRequest(
   opts: OpenAiHttpOptions,
 ): Promise<boolean> {
   const url = new URL(req.url ?? "/", `http://${req.headers.host || "localhost"}`);
-  if (url.pathname !== "/v1/chat/completions") {
+  const isModels = url.pathname === "/v1/models";
+  if (url.pathname !== "/v1/chat/completions" && !isModels) {
     return false;
   }
 
-  if (req.method !== "POST") {
-    sendMethodNotAllowed(res);
+  if (req.method !== (isModels ? "GET" : "POST")) {
+    sendMethodNotAllowed(res, isModels ? "GET" : "POST");
     return true;
   }
 
@@ -174,6 +193,11 @@ export async function handleOpenAiHttpRequest(
     return true;
   }
 
+  if (isModels) {
+    sendJson(res, 200, { object: "list", data: listOpenAiModels() });
+    return true;
+  }
+
   const body = await readJsonBodyOrError(req, res, opts.maxBodyBytes ?? 1024 * 1024);
   if (body === undefined) {
     return true;
@@ -199,6 +223,8 @@ export async function handleOpenAiHttpRequest(
 
   const runId = `chatcmpl_${randomUUID()}`;
   const deps = createDefaultDeps();
+  const stopSequences = resolveStopSequences({ stopSequences: payload.stop });
+  const streamParams = stopSequences ? { stopSequences } : undefined;
 
   if (!stream) {
     try {
@@ -206,6 +232,7 @@ export async function handleOpenAiHttpRequest(
         {
           message: prompt.message,
           extraSystemPrompt: prompt.extraSystemPrompt,
+          streamParams,
           sessionKey,
           runId,
           deliver: false,
@@ -320,6 +347,7 @@ export async function handleOpenAiHttpRequest(
         {
           message: prompt.message,
           extraSystemPrompt: prompt.extraSystemPrompt,
+          streamParams,
           sessionKey,
           runId,
           deliver: false,
@@ -21,6 +21,7 @@ import {
 import { loadConfig } from "../config/config.js";
 import { safeEqualSecret } from "../security/secret-equal.js";
 import { handleSlackHttpRequest } from "../slack/http/index.js";
+import { handleAgentEventsHttpRequest } from "./agent-events-http.js";
 import {
   authorizeGatewayConnect,
   isLocalDirectRequest,
@@ -543,6 +544,17 @@ export function createGatewayHttpServer(opts: {
           return;
         }
       }
+      if (configSnapshot.gateway?.http?.endpoints?.events?.enabled) {
+        if (
+          await handleAgentEventsHttpRequest(req, res, {
+            auth: resolvedAuth,
+            trustedProxies,
+            rateLimiter,
+          })
+        ) {
+          return;
+        }
+      }
       if (canvasHost) {
         if (isCanvasPath(requestPath)) {
           const ok = await authorizeCanvasRequest({
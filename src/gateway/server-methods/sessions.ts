@@ -3,6 +3,7 @@ import fs from "node:fs";
 import type { GatewayRequestHandlers } from "./types.js";
 import { resolveDefaultAgentId } from "../../agents/agent-scope.js";
 import { abortEmbeddedPiRun, waitForEmbeddedPiRunEnd } from "../../agents/pi-embedded.js";
+import { clearToolResultCacheSession } from "../../agents/pi-tools.result-cache.js";
 import { stopSubagentsForRequester } from "../../auto-reply/reply/abort.js";
 import { clearSessionQueues } from "../../auto-reply/reply/queue.js";
 import { loadConfig } from "../../config/config.js";
@@ -313,6 +314,7 @@ export const sessionsHandlers: GatewayRequestHandlers = {
       store[primaryKey] = nextEntry;
       return nextEntry;
     });
+    clearToolResultCacheSession(target.canonicalKey);
     // Archive old transcript so it doesn't accumulate on disk (#14869).
     archiveSessionTranscriptsForSession({
       sessionId: oldSessionId,
@@ -366,6 +368,9 @@ export const sessionsHandlers: GatewayRequestHandlers = {
       queueKeys.add(sessionId);
     }
     clearSessionQueues([...queueKeys]);
+    for (const queueKey of queueKeys) {
+      clearToolResultCacheSession(queueKey);
+    }
     stopSubagentsForRequester({ cfg, requesterSessionKey: target.canonicalKey });
     if (sessionId) {
       abortEmbeddedPiRun(sessionId);
@@ -0,0 +1,42 @@
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveSessionsScope } from "../commands/sessions.command-shared.js";
+import { loadSessionStore, resolveMaintenanceConfig } from "../config/sessions.js";
+import {
+  applySessionPrune,
+  planSessionPrune,
+  resolveProtectedSessionKeys,
+} from "../sessions/session-prune.js";
+
+/**
+ * With `session.maintenance.mode: "enforce"`, prunes every agent's session store once at startup
+ * and archives or deletes the transcripts left behind, including ones pruned on earlier saves.
+ */
+export async function pruneGatewaySessionStores(params: {
+  cfg: OpenClawConfig;
+  log: { info?: (msg: string) => void; warn: (msg: string) => void };
+}): Promise<void> {
+  const maintenance = resolveMaintenanceConfig();
+  if (maintenance.mode !== "enforce") {
+    return;
+  }
+  const { storePaths } = resolveSessionsScope(params.cfg, { global: true });
+  const protectedKeys = resolveProtectedSessionKeys(params.cfg);
+  for (const storePath of storePaths) {
+    const plan = planSessionPrune({
+      storePath,
+      store: loadSessionStore(storePath, { skipCache: true }),
+      pruneAfterMs: maintenance.pruneAfterMs,
+      maxEntries: maintenance.maxEntries,
+      protectedKeys,
+    });
+    if (plan.sessions.length === 0 && plan.orphanTranscripts.length === 0) {
+      continue;
+    }
+    const result = await applySessionPrune(plan, maintenance.transcripts);
+    params.log.info?.(
+      `session maintenance: removed ${result.removed.length} session(s), archived ` +
+        `${result.archived.length} and deleted ${result.deleted.length} transcript(s) ` +
+        `in ${storePath}`,
+    );
+  }
+}
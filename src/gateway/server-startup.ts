@@ -8,6 +8,7 @@ import {
   resolveConfiguredModelRef,
   resolveHooksGmailModel,
 } from "../agents/model-selection.js";
+import { preloadOpenApiSpecs } from "../agents/tools/openapi-spec.js";
 import { startGmailWatcher } from "../hooks/gmail-watcher.js";
 import {
   clearInternalHooks,
@@ -23,6 +24,7 @@ import {
   shouldWakeFromRestartSentinel,
 } from "./server-restart-sentinel.js";
 import { startGatewayMemoryBackend } from "./server-startup-memory.js";
+import { pruneGatewaySessionStores } from "./server-startup-sessions.js";
 
 export async function startGatewaySidecars(params: {
   cfg: ReturnType<typeof loadConfig>;
@@ -155,6 +157,16 @@ export async function startGatewaySidecars(params: {
     params.log.warn(`qmd memory startup initialization failed: ${String(err)}`);
   });
 
+  void pruneGatewaySessionStores({ cfg: params.cfg, log: params.log }).catch((err) => {
+    params.log.warn(`session maintenance on startup failed: ${String(err)}`);
+  });
+
+  if (params.cfg.tools?.openapi?.length) {
+    void preloadOpenApiSpecs(params.cfg).catch((err) => {
+      params.log.warn(`openapi spec preload failed: ${String(err)}`);
+    });
+  }
+
   if (shouldWakeFromRestartSentinel()) {
     setTimeout(() => {
       void scheduleRestartSentinelWake({ deps: params.deps });
@@ -233,6 +233,16 @@ describe("deriveSessionTitle", () => {
     expect(deriveSessionTitle(entry)).toBe("My Custom Session");
   });
 
+  test("prefers an explicit session title over displayName", () => {
+    const entry = {
+      sessionId: "abc123",
+      updatedAt: Date.now(),
+      title: "Fix flaky auth test",
+      displayName: "My Custom Session",
+    } as SessionEntry;
+    expect(deriveSessionTitle(entry)).toBe("Fix flaky auth test");
+  });
+
   test("falls back to subject when displayName is missing", () => {
     const entry = {
       sessionId: "abc123",
@@ -162,6 +162,10 @@ export function deriveSessionTitle(
     return undefined;
   }
 
+  if (entry.title?.trim()) {
+    return entry.title.trim();
+  }
+
   if (entry.displayName?.trim()) {
     return entry.displayName.trim();
   }
@@ -762,6 +766,8 @@ export function listSessionsFromStore(params: {
         kind: classifySessionKey(key, entry),
         label: entry?.label,
         displayName,
+        title: entry?.title,
+        tags: entry?.tags,
         channel,
         subject,
         groupChannel,
@@ -785,6 +791,8 @@ export function listSessionsFromStore(params: {
         modelProvider,
         model,
         contextTokens: entry?.contextTokens,
+        costUsd: entry?.usageTotals?.costUsd,
+        disabledTools: entry?.disabledTools,
         deliveryContext: deliveryFields.deliveryContext,
         lastChannel: deliveryFields.lastChannel ?? entry?.lastChannel,
         lastTo: deliveryFields.lastTo ?? entry?.lastTo,
@@ -795,7 +803,15 @@ export function listSessionsFromStore(params: {
 
   if (search) {
     sessions = sessions.filter((s) => {
-      const fields = [s.displayName, s.label, s.subject, s.sessionId, s.key];
+      const fields = [
+        s.displayName,
+        s.label,
+        s.title,
+        s.subject,
+        s.sessionId,
+        s.key,
+        ...(s.tags ?? []),
+      ];
       return fields.some((f) => typeof f === "string" && f.toLowerCase().includes(search));
     });
   }
@@ -13,6 +13,9 @@ export type GatewaySessionRow = {
   kind: "direct" | "group" | "global" | "unknown";
   label?: string;
   displayName?: string;
+  /** Session title from `/title` or auto-titling. */
+  title?: string;
+  tags?: string[];
   derivedTitle?: string;
   lastMessagePreview?: string;
   channel?: string;
@@ -38,6 +41,10 @@ export type GatewaySessionRow = {
   modelProvider?: string;
   model?: string;
   contextTokens?: number;
+  /** Cumulative estimated cost of the current sessionId (from usageTotals). */
+  costUsd?: number;
+  /** Tools turned off with `/tools disable`. */
+  disabledTools?: string[];
   deliveryContext?: DeliveryContext;
   lastChannel?: SessionEntry["lastChannel"];
   lastTo?: string;
@@ -0,0 +1,94 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import type { PluginRegistry } from "../plugins/registry.js";
+import { createHookRunner } from "../plugins/hooks.js";
+import { registerShellHooks, resolvePreToolUseOutcome, runShellHook } from "./shell-hooks.js";
+
+const isWindows = process.platform === "win32";
+
+describe("shell hooks", () => {
+  let dir: string;
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-shell-hooks-"));
+  });
+
+  afterEach(async () => {
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  it("maps exit codes and stdout to pre_tool_use decisions", () => {
+    const entry = { command: "guard" };
+    const base = { stdout: "", stderr: "", timedOut: false };
+    expect(resolvePreToolUseOutcome(entry, { ...base, code: 2, stderr: "no rm -rf\n" })).toEqual({
+      block: true,
+      blockReason: "no rm -rf",
+    });
+    expect(
+      resolvePreToolUseOutcome(entry, {
+        ...base,
+        code: 0,
+        stdout: '{"decision":"deny","reason":"readonly"}',
+      }),
+    ).toEqual({ block: true, blockReason: "readonly" });
+    expect(
+      resolvePreToolUseOutcome(entry, { ...base, code: 0, stdout: '{"params":{"command":"ls"}}' }),
+    ).toEqual({ params: { command: "ls" } });
+    expect(resolvePreToolUseOutcome(entry, { ...base, code: 1, stderr: "boom" })).toBeUndefined();
+    expect(resolvePreToolUseOutcome(entry, { ...base, code: 0, stdout: "ok" })).toBeUndefined();
+  });
+
+  it.skipIf(isWindows)("passes the event as JSON on stdin", async () => {
+    const outcome = await runShellHook(
+      { command: 'cat; printf "%s" "$OPENCLAW_HOOK_EVENT" >&2' },
+      { event: "post_tool_use", toolName: "read" },
+      { cwd: dir },
+    );
+    expect(outcome.code).toBe(0);
+    expect(JSON.parse(outcome.stdout)).toEqual({ event: "post_tool_use", toolName: "read" });
+    expect(outcome.stderr).toBe("post_tool_use");
+  });
+
+  it.skipIf(isWindows)("registers config hooks on the plugin hook points", async () => {
+    const registry = { typedHooks: [], hooks: [] } as unknown as PluginRegistry;
+    const cfg = {
+      agents: { defaults: { workspace: dir } },
+      hooks: {
+        shell: {
+          preToolUse: [
+            { command: "echo 'exec is disabled' >&2; exit 2", tools: ["exec"] },
+            { command: 'echo \'{"params":{"path":"safe.txt"}}\'', tools: ["read"] },
+          ],
+          userPromptSubmit: [{ command: "echo branch: main" }],
+        },
+      },
+    } as OpenClawConfig;
+
+    expect(registerShellHooks(registry, cfg)).toBe(2);
+    const runner = createHookRunner(registry);
+
+    const denied = await runner.runBeforeToolCall(
+      { toolName: "exec", params: { command: "rm -rf /" } },
+      { toolName: "exec" },
+    );
+    expect(denied).toEqual({ block: true, blockReason: "exec is disabled" });
+
+    const rewritten = await runner.runBeforeToolCall(
+      { toolName: "read", params: { path: "secret.txt", limit: 5 } },
+      { toolName: "read" },
+    );
+    expect(rewritten).toMatchObject({ params: { path: "safe.txt", limit: 5 } });
+
+    const untouched = await runner.runBeforeToolCall(
+      { toolName: "ls", params: { path: "." } },
+      { toolName: "ls" },
+    );
+    expect(untouched?.block).toBeFalsy();
+
+    const start = await runner.runBeforeAgentStart({ prompt: "hi" }, { workspaceDir: dir });
+    expect(start?.prependContext).toBe("branch: main");
+  });
+});
@@ -0,0 +1,264 @@
+import fs from "node:fs";
+import type { OpenClawConfig } from "../config/config.js";
+import type { ShellHookEntry, ShellHooksConfig } from "../config/types.hooks.js";
+import type { PluginRegistry } from "../plugins/registry.js";
+import type {
+  PluginHookBeforeToolCallResult,
+  PluginHookHandlerMap,
+  PluginHookName,
+  PluginHookRegistration,
+} from "../plugins/types.js";
+import { resolveAgentWorkspaceDir, resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { getShellConfig } from "../agents/shell-utils.js";
+import { normalizeToolName } from "../agents/tool-policy.js";
+import { createSubsystemLogger } from "../logging/subsystem.js";
+import { runCommandWithTimeout } from "../process/exec.js";
+import { isPlainObject } from "../utils.js";
+
+const log = createSubsystemLogger("hooks/shell");
+
+export const SHELL_HOOKS_PLUGIN_ID = "shell-hooks";
+export const DEFAULT_SHELL_HOOK_TIMEOUT_MS = 30_000;
+/** Exit code a hook uses to deny the tool call; stderr becomes the reason shown to the model. */
+export const SHELL_HOOK_DENY_EXIT_CODE = 2;
+
+export type ShellHookEvent =
+  | "pre_tool_use"
+  | "post_tool_use"
+  | "user_prompt_submit"
+  | "session_end";
+
+export type ShellHookOutcome = {
+  code: number | null;
+  stdout: string;
+  stderr: string;
+  timedOut: boolean;
+};
+
+export async function runShellHook(
+  entry: ShellHookEntry,
+  payload: { event: ShellHookEvent } & Record<string, unknown>,
+  opts?: { cwd?: string },
+): Promise<ShellHookOutcome> {
+  const { shell, args } = getShellConfig();
+  const timeoutMs = entry.timeoutMs ?? DEFAULT_SHELL_HOOK_TIMEOUT_MS;
+  const result = await runCommandWithTimeout([shell, ...args, entry.command], {
+    timeoutMs,
+    cwd: opts?.cwd,
+    input: JSON.stringify(payload),
+    env: { OPENCLAW_HOOK_EVENT: payload.event },
+  });
+  // runCommandWithTimeout only kills the child when the timeout fires.
+  return {
+    code: result.code,
+    stdout: result.stdout,
+    stderr: result.stderr,
+    timedOut: result.killed,
+  };
+}
+
+function matchesTool(entry: ShellHookEntry, toolName: string): boolean {
+  if (!entry.tools || entry.tools.length === 0) {
+    return true;
+  }
+  return entry.tools.some((name) => name === "*" || normalizeToolName(name) === toolName);
+}
+
+function parseJsonObject(stdout: string): Record<string, unknown> | undefined {
+  const trimmed = stdout.trim();
+  if (!trimmed.startsWith("{")) {
+    return undefined;
+  }
+  try {
+    const parsed: unknown = JSON.parse(trimmed);
+    return isPlainObject(parsed) ? parsed : undefined;
+  } catch {
+    return undefined;
+  }
+}
+
+function describeFailure(entry: ShellHookEntry, outcome: ShellHookOutcome): string {
+  const reason = outcome.timedOut ? "timed out" : `exited with code ${String(outcome.code)}`;
+  const stderr = outcome.stderr.trim();
+  return `${entry.command} ${reason}${stderr ? `: ${stderr.slice(0, 500)}` : ""}`;
+}
+
+/**
+ * Interprets a pre_tool_use hook run. Exit 2 (or `{"decision":"deny"}` on stdout) blocks the call;
+ * exit 0 with `{"params":{...}}` on stdout replaces the listed arguments. Any other exit code is
+ * logged and the call proceeds.
+ */
+export function resolvePreToolUseOutcome(
+  entry: ShellHookEntry,
+  outcome: ShellHookOutcome,
+): PluginHookBeforeToolCallResult | undefined {
+  if (outcome.code === SHELL_HOOK_DENY_EXIT_CODE) {
+    return {
+      block: true,
+      blockReason: outcome.stderr.trim() || `Tool call denied by hook: ${entry.command}`,
+    };
+  }
+  if (outcome.code !== 0) {
+    log.warn(`pre_tool_use hook failed: ${describeFailure(entry, outcome)}`);
+    return undefined;
+  }
+  const output = parseJsonObject(outcome.stdout);
+  if (!output) {
+    return undefined;
+  }
+  const decision = typeof output.decision === "string" ? output.decision.toLowerCase() : "";
+  if (decision === "deny" || decision === "block") {
+    const reason = typeof output.reason === "string" ? output.reason.trim() : "";
+    return { block: true, blockReason: reason || `Tool call denied by hook: ${entry.command}` };
+  }
+  return isPlainObject(output.params) ? { params: output.params } : undefined;
+}
+
+function resolveHookCwd(cfg: OpenClawConfig, agentId?: string, workspaceDir?: string) {
+  const dir = workspaceDir ?? resolveAgentWorkspaceDir(cfg, agentId ?? resolveDefaultAgentId(cfg));
+  return fs.existsSync(dir) ? dir : undefined;
+}
+
+function buildHandlers(
+  hooks: ShellHooksConfig,
+  cfg: OpenClawConfig,
+): Partial<{ [K in PluginHookName]: PluginHookHandlerMap[K] }> {
+  const handlers: Partial<{ [K in PluginHookName]: PluginHookHandlerMap[K] }> = {};
+  const preToolUse = hooks.preToolUse ?? [];
+  const postToolUse = hooks.postToolUse ?? [];
+  const userPromptSubmit = hooks.userPromptSubmit ?? [];
+  const sessionEnd = hooks.sessionEnd ?? [];
+
+  if (preToolUse.length > 0) {
+    handlers.before_tool_call = async (event, ctx) => {
+      const toolName = normalizeToolName(event.toolName);
+      let params = event.params;
+      let modified = false;
+      for (const entry of preToolUse.filter((candidate) => matchesTool(candidate, toolName))) {
+        const outcome = await runShellHook(
+          entry,
+          {
+            event: "pre_tool_use",
+            toolName,
+            params,
+            agentId: ctx.agentId,
+            sessionKey: ctx.sessionKey,
+          },
+          { cwd: resolveHookCwd(cfg, ctx.agentId) },
+        );
+        const result = resolvePreToolUseOutcome(entry, outcome);
+        if (result?.block) {
+          return result;
+        }
+        if (result?.params) {
+          params = { ...params, ...result.params };
+          modified = true;
+        }
+      }
+      return modified ? { params } : undefined;
+    };
+  }
+
+  if (postToolUse.length > 0) {
+    handlers.after_tool_call = async (event, ctx) => {
+      const toolName = normalizeToolName(event.toolName);
+      for (const entry of postToolUse.filter((candidate) => matchesTool(candidate, toolName))) {
+        const outcome = await runShellHook(
+          entry,
+          {
+            event: "post_tool_use",
+            toolName,
+            params: event.params,
+            result: event.result,
+            error: event.error,
+            durationMs: event.durationMs,
+            agentId: ctx.agentId,
+            sessionKey: ctx.sessionKey,
+          },
+          { cwd: resolveHookCwd(cfg, ctx.agentId) },
+        );
+        if (outcome.code !== 0) {
+          log.warn(`post_tool_use hook failed: ${describeFailure(entry, outcome)}`);
+        }
+      }
+    };
+  }
+
+  if (userPromptSubmit.length > 0) {
+    handlers.before_agent_start = async (event, ctx) => {
+      const context: string[] = [];
+      for (const entry of userPromptSubmit) {
+        const outcome = await runShellHook(
+          entry,
+          {
+            event: "user_prompt_submit",
+            prompt: event.prompt,
+            agentId: ctx.agentId,
+            sessionKey: ctx.sessionKey,
+            sessionId: ctx.sessionId,
+          },
+          { cwd: resolveHookCwd(cfg, ctx.agentId, ctx.workspaceDir) },
+        );
+        if (outcome.code !== 0) {
+          log.warn(`user_prompt_submit hook failed: ${describeFailure(entry, outcome)}`);
+          continue;
+        }
+        // Plain stdout is added to the prompt as extra context.
+        const text = outcome.stdout.trim();
+        if (text) {
+          context.push(text);
+        }
+      }
+      return context.length > 0 ? { prependContext: context.join("\n\n") } : undefined;
+    };
+  }
+
+  if (sessionEnd.length > 0) {
+    handlers.session_end = async (event, ctx) => {
+      for (const entry of sessionEnd) {
+        const outcome = await runShellHook(
+          entry,
+          {
+            event: "session_end",
+            sessionId: event.sessionId,
+            messageCount: event.messageCount,
+            durationMs: event.durationMs,
+            agentId: ctx.agentId,
+          },
+          { cwd: resolveHookCwd(cfg, ctx.agentId) },
+        );
+        if (outcome.code !== 0) {
+          log.warn(`session_end hook failed: ${describeFailure(entry, outcome)}`);
+        }
+      }
+    };
+  }
+
+  return handlers;
+}
+
+/**
+ * Registers `hooks.shell` commands as typed hooks on the plugin registry, so they run at the same
+ * points as plugin `before_tool_call`/`after_tool_call`/`before_agent_start`/`session_end` hooks.
+ */
+export function registerShellHooks(registry: PluginRegistry, cfg: OpenClawConfig): number {
+  const hooks = cfg.hooks?.shell;
+  if (!hooks) {
+    return 0;
+  }
+  const handlers = buildHandlers(hooks, cfg);
+  let count = 0;
+  for (const [hookName, handler] of Object.entries(handlers)) {
+    registry.typedHooks.push({
+      pluginId: SHELL_HOOKS_PLUGIN_ID,
+      hookName,
+      handler,
+      source: "config:hooks.shell",
+    } as PluginHookRegistration);
+    count += 1;
+  }
+  if (count > 0) {
+    log.debug(`registered ${count} shell hook handler(s)`);
+  }
+  return count;
+}
@@ -23,3 +23,16 @@ export async function copyToClipboard(value: string): Promise<boolean> {
   }
   return false;
 }
+
+/**
+ * OSC 52 asks the terminal emulator itself to set the clipboard, so it reaches the local machine
+ * even when the process runs over SSH. Inside tmux it has to be wrapped in a DCS passthrough.
+ */
+export function buildOsc52Sequence(value: string, env: NodeJS.ProcessEnv = process.env): string {
+  const osc = `\x1b]52;c;${Buffer.from(value, "utf-8").toString("base64")}\x07`;
+  return env.TMUX ? `\x1bPtmux;${osc.replaceAll("\x1b", "\x1b\x1b")}\x1b\\` : osc;
+}
+
+export function isRemoteTerminal(env: NodeJS.ProcessEnv = process.env): boolean {
+  return Boolean(env.SSH_TTY || env.SSH_CONNECTION || env.SSH_CLIENT);
+}
@@ -9,6 +9,8 @@ type DiagnosticBaseEvent = {
 
 export type DiagnosticUsageEvent = DiagnosticBaseEvent & {
   type: "model.usage";
+  /** Set when the usage covers one agent run; request and tool spans nest under it. */
+  runId?: string;
   sessionKey?: string;
   sessionId?: string;
   channel?: string;
@@ -30,6 +32,36 @@ export type DiagnosticUsageEvent = DiagnosticBaseEvent & {
   durationMs?: number;
 };
 
+/** One provider round-trip inside an agent run (assistant message start to end). */
+export type DiagnosticModelRequestEvent = DiagnosticBaseEvent & {
+  type: "model.request";
+  runId: string;
+  sessionKey?: string;
+  provider?: string;
+  model?: string;
+  usage: {
+    input?: number;
+    output?: number;
+    cacheRead?: number;
+    cacheWrite?: number;
+    total?: number;
+  };
+  stopReason?: string;
+  durationMs?: number;
+  error?: string;
+};
+
+export type DiagnosticToolExecutionEvent = DiagnosticBaseEvent & {
+  type: "tool.execution";
+  runId: string;
+  sessionKey?: string;
+  toolName: string;
+  toolCallId: string;
+  durationMs?: number;
+  outcome: "ok" | "error";
+  error?: string;
+};
+
 export type DiagnosticWebhookReceivedEvent = DiagnosticBaseEvent & {
   type: "webhook.received";
   channel: string;
@@ -129,6 +161,8 @@ export type DiagnosticHeartbeatEvent = DiagnosticBaseEvent & {
 
 export type DiagnosticEventPayload =
   | DiagnosticUsageEvent
+  | DiagnosticModelRequestEvent
+  | DiagnosticToolExecutionEvent
   | DiagnosticWebhookReceivedEvent
   | DiagnosticWebhookProcessedEvent
   | DiagnosticWebhookErrorEvent
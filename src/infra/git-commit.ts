@@ -126,3 +126,18 @@ export const resolveCommitHash = (options: { cwd?: string; env?: NodeJS.ProcessE
     return cachedCommit;
   }
 };
+
+/** Current branch of the repo containing `cwd`; null when detached or not in a git checkout. */
+export const resolveGitBranch = (cwd: string = process.cwd()) => {
+  try {
+    const headPath = resolveGitHead(cwd);
+    if (!headPath) {
+      return null;
+    }
+    const head = fs.readFileSync(headPath, "utf-8").trim();
+    const match = head.match(/^ref:\s*refs\/heads\/(.+)$/i);
+    return match?.[1]?.trim() || null;
+  } catch {
+    return null;
+  }
+};
@@ -102,4 +102,31 @@ describe("ssrf pinning", () => {
       }),
     ).rejects.toThrow(/allowlist/i);
   });
+
+  it("blocks denylisted hostnames even when allowlisted", async () => {
+    const lookup = vi.fn(async () => [{ address: "93.184.216.34", family: 4 }]);
+    const policy = {
+      hostnameAllowlist: ["*.example.com"],
+      hostnameDenylist: ["internal.example.com", "*.corp.example.com"],
+    };
+
+    await expect(
+      resolvePinnedHostnameWithPolicy("internal.example.com", { lookupFn: lookup, policy }),
+    ).rejects.toThrow(/denylisted/i);
+    await expect(
+      resolvePinnedHostnameWithPolicy("git.corp.example.com", { lookupFn: lookup, policy }),
+    ).rejects.toThrow(/denylisted/i);
+    expect(lookup).not.toHaveBeenCalled();
+
+    await expect(
+      resolvePinnedHostnameWithPolicy("docs.example.com", { lookupFn: lookup, policy }),
+    ).resolves.toMatchObject({ hostname: "docs.example.com" });
+  });
+
+  it("rejects DNS results in reserved and multicast ranges", async () => {
+    for (const address of ["198.18.0.1", "192.0.0.8", "224.0.0.1", "255.255.255.255"]) {
+      const lookup = vi.fn(async () => [{ address, family: 4 }]);
+      await expect(resolvePinnedHostname("example.com", lookup)).rejects.toThrow(/private/i);
+    }
+  });
 });
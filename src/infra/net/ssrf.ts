@@ -21,6 +21,8 @@ export type SsrFPolicy = {
   allowPrivateNetwork?: boolean;
   allowedHostnames?: string[];
   hostnameAllowlist?: string[];
+  /** Hostnames (or `*.example.com` patterns) that are always blocked, even when allowlisted. */
+  hostnameDenylist?: string[];
 };
 
 const PRIVATE_IPV6_PREFIXES = ["fe80:", "fec0:", "fc", "fd"];
@@ -54,7 +56,7 @@ function normalizeHostnameAllowlist(values?: string[]): string[] {
   );
 }
 
-function isHostnameAllowedByPattern(hostname: string, pattern: string): boolean {
+export function isHostnameAllowedByPattern(hostname: string, pattern: string): boolean {
   if (pattern.startsWith("*.")) {
     const suffix = pattern.slice(2);
     if (!suffix || hostname === suffix) {
@@ -72,6 +74,10 @@ function matchesHostnameAllowlist(hostname: string, allowlist: string[]): boolea
   return allowlist.some((pattern) => isHostnameAllowedByPattern(hostname, pattern));
 }
 
+function matchesHostnameDenylist(hostname: string, denylist: string[]): boolean {
+  return denylist.some((pattern) => isHostnameAllowedByPattern(hostname, pattern));
+}
+
 function parseIpv4(address: string): number[] | null {
   const parts = address.split(".");
   if (parts.length !== 4) {
@@ -138,6 +144,17 @@ function isPrivateIpv4(parts: number[]): boolean {
   if (octet1 === 100 && octet2 >= 64 && octet2 <= 127) {
     return true;
   }
+  // IETF protocol assignments (192.0.0.0/24) and benchmarking (198.18.0.0/15).
+  if (octet1 === 192 && octet2 === 0 && parts[2] === 0) {
+    return true;
+  }
+  if (octet1 === 198 && (octet2 === 18 || octet2 === 19)) {
+    return true;
+  }
+  // Multicast, reserved, and broadcast (224.0.0.0 and above).
+  if (octet1 >= 224) {
+    return true;
+  }
   return false;
 }
 
@@ -250,20 +267,26 @@ export type PinnedHostname = {
   lookup: typeof dnsLookupCb;
 };
 
-export async function resolvePinnedHostnameWithPolicy(
-  hostname: string,
-  params: { lookupFn?: LookupFn; policy?: SsrFPolicy } = {},
-): Promise<PinnedHostname> {
+/**
+ * The checks that need no DNS: deny/allow lists, blocked hostnames, and private IP literals.
+ * Returns the normalized hostname; throws `SsrFBlockedError` when the policy rejects it.
+ */
+export function assertHostnameAllowedByPolicy(hostname: string, policy?: SsrFPolicy): string {
   const normalized = normalizeHostname(hostname);
   if (!normalized) {
     throw new Error("Invalid hostname");
   }
 
-  const allowPrivateNetwork = Boolean(params.policy?.allowPrivateNetwork);
-  const allowedHostnames = normalizeHostnameSet(params.policy?.allowedHostnames);
-  const hostnameAllowlist = normalizeHostnameAllowlist(params.policy?.hostnameAllowlist);
+  const allowPrivateNetwork = Boolean(policy?.allowPrivateNetwork);
+  const allowedHostnames = normalizeHostnameSet(policy?.allowedHostnames);
+  const hostnameAllowlist = normalizeHostnameAllowlist(policy?.hostnameAllowlist);
+  const hostnameDenylist = normalizeHostnameAllowlist(policy?.hostnameDenylist);
   const isExplicitAllowed = allowedHostnames.has(normalized);
 
+  if (matchesHostnameDenylist(normalized, hostnameDenylist)) {
+    throw new SsrFBlockedError(`Blocked hostname (denylisted): ${hostname}`);
+  }
+
   if (!matchesHostnameAllowlist(normalized, hostnameAllowlist)) {
     throw new SsrFBlockedError(`Blocked hostname (not in allowlist): ${hostname}`);
   }
@@ -277,6 +300,16 @@ export async function resolvePinnedHostnameWithPolicy(
       throw new SsrFBlockedError("Blocked: private/internal IP address");
     }
   }
+  return normalized;
+}
+
+export async function resolvePinnedHostnameWithPolicy(
+  hostname: string,
+  params: { lookupFn?: LookupFn; policy?: SsrFPolicy } = {},
+): Promise<PinnedHostname> {
+  const normalized = assertHostnameAllowedByPolicy(hostname, params.policy);
+  const allowPrivateNetwork = Boolean(params.policy?.allowPrivateNetwork);
+  const isExplicitAllowed = normalizeHostnameSet(params.policy?.allowedHostnames).has(normalized);
 
   const lookupFn = params.lookupFn ?? dnsLookup;
   const results = await lookupFn(normalized, { all: true });
@@ -9,6 +9,7 @@ import {
   loadSessionCostSummary,
   loadSessionLogs,
   loadSessionUsageTimeSeries,
+  loadUsageReport,
 } from "./session-cost-usage.js";
 
 describe("session cost usage", () => {
@@ -114,6 +115,55 @@ describe("session cost usage", () => {
     }
   });
 
+  it("groups a usage report by model and by session", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-usage-report-"));
+    const sessionsDir = path.join(root, "agents", "main", "sessions");
+    await fs.mkdir(sessionsDir, { recursive: true });
+    const now = Date.now();
+    const assistant = (model: string, tokens: number, cost: number, ts = now) => ({
+      type: "message",
+      timestamp: new Date(ts).toISOString(),
+      message: {
+        role: "assistant",
+        provider: "openai",
+        model,
+        usage: { input: tokens, output: 0, totalTokens: tokens, cost: { total: cost } },
+      },
+    });
+    const write = (name: string, lines: unknown[]) =>
+      fs.writeFile(
+        path.join(sessionsDir, `${name}.jsonl`),
+        lines.map((line) => JSON.stringify(line)).join("\n"),
+        "utf-8",
+      );
+    await write("sess-a", [assistant("gpt-5.2", 100, 0.1), assistant("gpt-5-mini", 50, 0.01)]);
+    await write("sess-b", [
+      assistant("gpt-5.2", 200, 0.2),
+      assistant("gpt-5.2", 999, 9, now - 10 * 24 * 60 * 60 * 1000),
+    ]);
+
+    const originalState = process.env.OPENCLAW_STATE_DIR;
+    process.env.OPENCLAW_STATE_DIR = root;
+    try {
+      const range = { startMs: now - 7 * 24 * 60 * 60 * 1000, endMs: now + 1000 };
+      const byModel = await loadUsageReport({ ...range, groupBy: "model" });
+      expect(byModel.rows.map((row) => [row.key, row.totalTokens])).toEqual([
+        ["openai/gpt-5.2", 300],
+        ["openai/gpt-5-mini", 50],
+      ]);
+      expect(byModel.totals.totalCost).toBeCloseTo(0.31, 5);
+
+      const bySession = await loadUsageReport({ ...range, groupBy: "session" });
+      expect(bySession.rows.map((row) => row.key)).toEqual(["sess-b", "sess-a"]);
+    } finally {
+      if (originalState === undefined) {
+        delete process.env.OPENCLAW_STATE_DIR;
+      } else {
+        process.env.OPENCLAW_STATE_DIR = originalState;
+      }
+    }
+  });
+
   it("summarizes a single session file", async () => {
     const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cost-session-"));
     const sessionFile = path.join(root, "session.jsonl");
@@ -23,6 +23,8 @@ import type {
   SessionToolUsage,
   SessionUsageTimePoint,
   SessionUsageTimeSeries,
+  UsageReport,
+  UsageReportGroupBy,
 } from "./session-cost-usage.types.js";
 import { normalizeUsage } from "../agents/usage.js";
 import {
@@ -49,6 +51,9 @@ export type {
   SessionToolUsage,
   SessionUsageTimePoint,
   SessionUsageTimeSeries,
+  UsageReport,
+  UsageReportGroupBy,
+  UsageReportRow,
 } from "./session-cost-usage.types.js";
 
 const emptyTotals = (): CostUsageTotals => ({
@@ -272,6 +277,32 @@ async function scanUsageFile(params: {
   });
 }
 
+async function listTranscriptFilesSince(
+  agentId: string | undefined,
+  sinceTime: number,
+): Promise<string[]> {
+  const sessionsDir = resolveSessionTranscriptsDirForAgent(agentId);
+  const entries = await fs.promises.readdir(sessionsDir, { withFileTypes: true }).catch(() => []);
+  return (
+    await Promise.all(
+      entries
+        .filter((entry) => entry.isFile() && entry.name.endsWith(".jsonl"))
+        .map(async (entry) => {
+          const filePath = path.join(sessionsDir, entry.name);
+          const stats = await fs.promises.stat(filePath).catch(() => null);
+          if (!stats) {
+            return null;
+          }
+          // Include file if it was modified after our start time
+          if (stats.mtimeMs < sinceTime) {
+            return null;
+          }
+          return filePath;
+        }),
+    )
+  ).filter((filePath): filePath is string => Boolean(filePath));
+}
+
 export async function loadCostUsageSummary(params?: {
   startMs?: number;
   endMs?: number;
@@ -298,27 +329,7 @@ export async function loadCostUsageSummary(params?: {
   const dailyMap = new Map<string, CostUsageTotals>();
   const totals = emptyTotals();
 
-  const sessionsDir = resolveSessionTranscriptsDirForAgent(params?.agentId);
-  const entries = await fs.promises.readdir(sessionsDir, { withFileTypes: true }).catch(() => []);
-  const files = (
-    await Promise.all(
-      entries
-        .filter((entry) => entry.isFile() && entry.name.endsWith(".jsonl"))
-        .map(async (entry) => {
-          const filePath = path.join(sessionsDir, entry.name);
-          const stats = await fs.promises.stat(filePath).catch(() => null);
-          if (!stats) {
-            return null;
-          }
-          // Include file if it was modified after our start time
-          if (stats.mtimeMs < sinceTime) {
-            return null;
-          }
-          return filePath;
-        }),
-    )
-  ).filter((filePath): filePath is string => Boolean(filePath));
-
+  const files = await listTranscriptFilesSince(params?.agentId, sinceTime);
   for (const filePath of files) {
     await scanUsageFile({
       filePath,
@@ -363,6 +374,66 @@ export async function loadCostUsageSummary(params?: {
   };
 }
 
+/**
+ * Usage totals between `startMs` and `endMs`, grouped by model (`provider/model`), local day, or
+ * session id (transcript file name). Rows are sorted by cost, then tokens; days sort by date.
+ */
+export async function loadUsageReport(params: {
+  startMs: number;
+  endMs: number;
+  groupBy: UsageReportGroupBy;
+  config?: OpenClawConfig;
+  agentId?: string;
+}): Promise<UsageReport> {
+  const groups = new Map<string, CostUsageTotals>();
+  const totals = emptyTotals();
+
+  const files = await listTranscriptFilesSince(params.agentId, params.startMs);
+  for (const filePath of files) {
+    const sessionId = path.basename(filePath, ".jsonl");
+    await scanUsageFile({
+      filePath,
+      config: params.config,
+      onEntry: (entry) => {
+        const ts = entry.timestamp?.getTime();
+        if (!ts || ts < params.startMs || ts > params.endMs) {
+          return;
+        }
+        const key =
+          params.groupBy === "day"
+            ? formatDayKey(entry.timestamp ?? new Date(ts))
+            : params.groupBy === "session"
+              ? sessionId
+              : entry.provider && entry.model
+                ? `${entry.provider}/${entry.model}`
+                : (entry.model ?? "unknown");
+        const bucket = groups.get(key) ?? emptyTotals();
+        for (const target of [bucket, totals]) {
+          applyUsageTotals(target, entry.usage);
+          if (entry.costBreakdown?.total !== undefined) {
+            applyCostBreakdown(target, entry.costBreakdown);
+          } else {
+            applyCostTotal(target, entry.costTotal);
+          }
+        }
+        groups.set(key, bucket);
+      },
+    });
+  }
+
+  const rows = Array.from(groups.entries()).map(([key, bucket]) => Object.assign({ key }, bucket));
+  return {
+    startMs: params.startMs,
+    endMs: params.endMs,
+    groupBy: params.groupBy,
+    rows:
+      params.groupBy === "day"
+        ? rows.toSorted((a, b) => a.key.localeCompare(b.key))
+        : rows.toSorted((a, b) => b.totalCost - a.totalCost || b.totalTokens - a.totalTokens),
+    totals,
+  };
+}
+
 /**
  * Scan all transcript files to discover sessions not in the session store.
  * Returns basic metadata for each discovered session.
@@ -58,6 +58,20 @@ export type CostUsageSummary = {
   totals: CostUsageTotals;
 };
 
+export type UsageReportGroupBy = "model" | "day" | "session";
+
+export type UsageReportRow = CostUsageTotals & {
+  key: string;
+};
+
+export type UsageReport = {
+  startMs: number;
+  endMs: number;
+  groupBy: UsageReportGroupBy;
+  rows: UsageReportRow[];
+  totals: CostUsageTotals;
+};
+
 export type SessionDailyUsage = {
   date: string; // YYYY-MM-DD
   tokens: number;
@@ -86,6 +86,22 @@ describe("logger helpers", () => {
 
     cleanup(todayPath);
   });
+
+  it("rotates the log file once it reaches maxFileBytes", () => {
+    const logPath = pathForTest();
+    setLoggerOverride({ level: "info", file: logPath, maxFileBytes: 200, maxFiles: 2 });
+    fs.writeFileSync(logPath, "x".repeat(190));
+    fs.writeFileSync(`${logPath}.2`, "oldest");
+
+    logInfo("after-rotate");
+
+    expect(fs.readFileSync(logPath, "utf-8")).toContain("after-rotate");
+    expect(fs.readFileSync(`${logPath}.1`, "utf-8")).toBe("x".repeat(190));
+    expect(fs.existsSync(`${logPath}.2`)).toBe(false);
+    for (const file of [logPath, `${logPath}.1`, `${logPath}.2`]) {
+      cleanup(file);
+    }
+  });
 });
 
 function pathForTest() {
@@ -15,12 +15,16 @@ export const DEFAULT_LOG_FILE = path.join(DEFAULT_LOG_DIR, "openclaw.log"); // l
 const LOG_PREFIX = "openclaw";
 const LOG_SUFFIX = ".log";
 const MAX_LOG_AGE_MS = 24 * 60 * 60 * 1000; // 24h
+const DEFAULT_MAX_FILE_BYTES = 10 * 1024 * 1024;
+const DEFAULT_MAX_FILES = 5;
 
 const requireConfig = createRequire(import.meta.url);
 
 export type LoggerSettings = {
   level?: LogLevel;
   file?: string;
+  maxFileBytes?: number;
+  maxFiles?: number;
   consoleLevel?: LogLevel;
   consoleStyle?: ConsoleStyle;
 };
@@ -30,6 +34,8 @@ type LogObj = { date?: Date } & Record<string, unknown>;
 type ResolvedSettings = {
   level: LogLevel;
   file: string;
+  maxFileBytes: number;
+  maxFiles: number;
 };
 export type LoggerResolvedSettings = ResolvedSettings;
 export type LogTransportRecord = Record<string, unknown>;
@@ -51,8 +57,8 @@ function attachExternalTransport(logger: TsLogger<LogObj>, transport: LogTranspo
 }
 
 function resolveSettings(): ResolvedSettings {
-  let cfg: OpenClawConfig["logging"] | undefined =
-    (loggingState.overrideSettings as LoggerSettings | null) ?? readLoggingConfig();
+  const override = loggingState.overrideSettings as LoggerSettings | null;
+  let cfg: OpenClawConfig["logging"] | undefined = override ?? readLoggingConfig();
   if (!cfg) {
     try {
       const loaded = requireConfig("../config/config.js") as {
@@ -65,16 +71,59 @@ function resolveSettings(): ResolvedSettings {
   }
   const defaultLevel =
     process.env.VITEST === "true" && process.env.OPENCLAW_TEST_FILE_LOG !== "1" ? "silent" : "info";
-  const level = normalizeLogLevel(cfg?.level, defaultLevel);
-  const file = cfg?.file ?? defaultRollingPathForToday();
-  return { level, file };
+  // `--log-level` / `--log-file` (see cli/log-flags.ts) win over config for this process.
+  const envLevel = override ? undefined : process.env.OPENCLAW_LOG_LEVEL?.trim();
+  const envFile = override ? undefined : process.env.OPENCLAW_LOG_FILE?.trim();
+  const level = normalizeLogLevel(envLevel || cfg?.level, defaultLevel);
+  const file = envFile || cfg?.file || defaultRollingPathForToday();
+  const maxFileBytes =
+    typeof cfg?.maxFileBytes === "number" && cfg.maxFileBytes > 0
+      ? cfg.maxFileBytes
+      : DEFAULT_MAX_FILE_BYTES;
+  const maxFiles =
+    typeof cfg?.maxFiles === "number" && cfg.maxFiles >= 0
+      ? Math.floor(cfg.maxFiles)
+      : DEFAULT_MAX_FILES;
+  return { level, file, maxFileBytes, maxFiles };
 }
 
 function settingsChanged(a: ResolvedSettings | null, b: ResolvedSettings) {
   if (!a) {
     return true;
   }
-  return a.level !== b.level || a.file !== b.file;
+  return (
+    a.level !== b.level ||
+    a.file !== b.file ||
+    a.maxFileBytes !== b.maxFileBytes ||
+    a.maxFiles !== b.maxFiles
+  );
+}
+
+/** Shift `file` → `file.1` → … → `file.<maxFiles>`, dropping the oldest copy. */
+export function rotateLogFile(file: string, maxFiles: number): void {
+  try {
+    if (maxFiles <= 0) {
+      fs.truncateSync(file, 0);
+      return;
+    }
+    fs.rmSync(`${file}.${maxFiles}`, { force: true });
+    for (let index = maxFiles - 1; index >= 1; index -= 1) {
+      if (fs.existsSync(`${file}.${index}`)) {
+        fs.renameSync(`${file}.${index}`, `${file}.${index + 1}`);
+      }
+    }
+    fs.renameSync(file, `${file}.1`);
+  } catch {
+    // never block on logging failures
+  }
+}
+
+function readFileSize(file: string): number {
+  try {
+    return fs.statSync(file).size;
+  } catch {
+    return 0;
+  }
 }
 
 export function isFileLogLevelEnabled(level: LogLevel): boolean {
@@ -100,11 +149,21 @@ function buildLogger(settings: ResolvedSettings): TsLogger<LogObj> {
     type: "hidden", // no ansi formatting
   });
 
+  let fileBytes = readFileSize(settings.file);
   logger.attachTransport((logObj: LogObj) => {
     try {
       const time = logObj.date?.toISOString?.() ?? new Date().toISOString();
-      const line = JSON.stringify({ ...logObj, time });
-      fs.appendFileSync(settings.file, `${line}\n`, { encoding: "utf8" });
+      const line = `${JSON.stringify({ ...logObj, time })}\n`;
+      if (fileBytes > 0 && fileBytes + Buffer.byteLength(line) > settings.maxFileBytes) {
+        // Re-check on disk: another process (gateway + CLI) may already have rotated it.
+        fileBytes = readFileSize(settings.file);
+        if (fileBytes + Buffer.byteLength(line) > settings.maxFileBytes) {
+          rotateLogFile(settings.file, settings.maxFiles);
+          fileBytes = 0;
+        }
+      }
+      fs.appendFileSync(settings.file, line, { encoding: "utf8" });
+      fileBytes += Buffer.byteLength(line);
     } catch {
       // never block on logging failures
     }
@@ -233,7 +292,8 @@ function pruneOldRollingLogs(dir: string): void {
       if (!entry.isFile()) {
         continue;
       }
-      if (!entry.name.startsWith(`${LOG_PREFIX}-`) || !entry.name.endsWith(LOG_SUFFIX)) {
+      const isLogFile = entry.name.endsWith(LOG_SUFFIX) || /\.log\.\d+$/.test(entry.name);
+      if (!entry.name.startsWith(`${LOG_PREFIX}-`) || !isLogFile) {
         continue;
       }
       const fullPath = path.join(dir, entry.name);
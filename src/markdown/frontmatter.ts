@@ -155,3 +155,17 @@ export function parseFrontmatterBlock(content: string): ParsedFrontmatter {
   }
   return merged;
 }
+
+/** The document body after a leading `---` front-matter block (or the whole text if none). */
+export function stripFrontmatterBlock(content: string): string {
+  const normalized = content.replace(/\r\n?/g, "\n");
+  if (!normalized.startsWith("---")) {
+    return normalized;
+  }
+  const endIndex = normalized.indexOf("\n---", 3);
+  if (endIndex === -1) {
+    return normalized;
+  }
+  const afterFence = normalized.indexOf("\n", endIndex + 4);
+  return afterFence === -1 ? "" : normalized.slice(afterFence + 1);
+}
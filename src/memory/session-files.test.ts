@@ -84,4 +84,24 @@ describe("buildSessionEntry", () => {
     expect(entry).not.toBeNull();
     expect(entry!.lineMap).toEqual([3, 5]);
   });
+
+  it("indexes compaction summaries alongside messages", async () => {
+    const jsonlLines = [
+      JSON.stringify({ type: "message", message: { role: "user", content: "Start" } }),
+      JSON.stringify({
+        type: "compaction",
+        summary: "Migrated the billing  service\nto Postgres 16.",
+        firstKeptEntryId: "e2",
+      }),
+      JSON.stringify({ type: "compaction", summary: "" }),
+    ];
+    const filePath = path.join(tmpDir, "compacted.jsonl");
+    await fs.writeFile(filePath, jsonlLines.join("\n"));
+
+    const entry = await buildSessionEntry(filePath);
+    expect(entry!.content).toBe(
+      "User: Start\nSummary: Migrated the billing service to Postgres 16.",
+    );
+    expect(entry!.lineMap).toEqual([1, 2]);
+  });
 });
@@ -89,11 +89,21 @@ export async function buildSessionEntry(absPath: string): Promise<SessionFileEnt
       } catch {
         continue;
       }
-      if (
-        !record ||
-        typeof record !== "object" ||
-        (record as { type?: unknown }).type !== "message"
-      ) {
+      if (!record || typeof record !== "object") {
+        continue;
+      }
+      const recordType = (record as { type?: unknown }).type;
+      if (recordType === "compaction") {
+        // Compaction summaries condense everything before them; index them so long sessions
+        // stay searchable after their early turns drop out of the live context.
+        const summary = extractSessionText((record as { summary?: unknown }).summary);
+        if (summary) {
+          collected.push(`Summary: ${redactSensitiveText(summary, { mode: "tools" })}`);
+          lineMap.push(jsonlIdx + 1);
+        }
+        continue;
+      }
+      if (recordType !== "message") {
         continue;
       }
       const message = (record as { message?: unknown }).message as
@@ -268,10 +268,12 @@ export type {
   DiagnosticLaneEnqueueEvent,
   DiagnosticMessageProcessedEvent,
   DiagnosticMessageQueuedEvent,
+  DiagnosticModelRequestEvent,
   DiagnosticRunAttemptEvent,
   DiagnosticSessionState,
   DiagnosticSessionStateEvent,
   DiagnosticSessionStuckEvent,
+  DiagnosticToolExecutionEvent,
   DiagnosticUsageEvent,
   DiagnosticWebhookErrorEvent,
   DiagnosticWebhookProcessedEvent,
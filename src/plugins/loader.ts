@@ -10,6 +10,7 @@ import type {
   PluginDiagnostic,
   PluginLogger,
 } from "./types.js";
+import { registerShellHooks } from "../hooks/shell-hooks.js";
 import { createSubsystemLogger } from "../logging/subsystem.js";
 import { resolveUserPath } from "../utils.js";
 import { clearPluginCommands } from "./commands.js";
@@ -108,9 +109,11 @@ const resolvePluginSdkAccountIdAlias = (): string | null => {
 function buildCacheKey(params: {
   workspaceDir?: string;
   plugins: NormalizedPluginsConfig;
+  shellHooks?: unknown;
 }): string {
   const workspaceKey = params.workspaceDir ? resolveUserPath(params.workspaceDir) : "";
-  return `${workspaceKey}::${JSON.stringify(params.plugins)}`;
+  const shellHooksKey = params.shellHooks ? `::${JSON.stringify(params.shellHooks)}` : "";
+  return `${workspaceKey}::${JSON.stringify(params.plugins)}${shellHooksKey}`;
 }
 
 function validatePluginConfig(params: {
@@ -208,6 +211,7 @@ export function loadOpenClawPlugins(options: PluginLoadOptions = {}): PluginRegi
   const cacheKey = buildCacheKey({
     workspaceDir: options.workspaceDir,
     plugins: normalized,
+    shellHooks: cfg.hooks?.shell,
   });
   const cacheEnabled = options.cache !== false;
   if (cacheEnabled) {
@@ -484,6 +488,8 @@ export function loadOpenClawPlugins(options: PluginLoadOptions = {}): PluginRegi
     });
   }
 
+  registerShellHooks(registry, cfg);
+
   if (cacheEnabled) {
     registryCache.set(cacheKey, registry);
   }
@@ -0,0 +1,43 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import { afterEach, describe, expect, it } from "vitest";
+import { createTestRegistry } from "../test-utils/channel-plugins.js";
+import { resolvePluginProviderStreamFn } from "./providers.js";
+import { setActivePluginRegistry } from "./runtime.js";
+
+const stream = (() => {
+  throw new Error("not called");
+}) as unknown as StreamFn;
+
+describe("resolvePluginProviderStreamFn", () => {
+  afterEach(() => {
+    setActivePluginRegistry(createTestRegistry());
+  });
+
+  it("returns the stream registered for a provider id or alias", () => {
+    setActivePluginRegistry({
+      ...createTestRegistry(),
+      providers: [
+        {
+          pluginId: "acme",
+          source: "test",
+          provider: { id: "acme", label: "Acme", aliases: ["acme-ai"], auth: [], stream },
+        },
+      ],
+    });
+
+    expect(resolvePluginProviderStreamFn("acme")).toBe(stream);
+    expect(resolvePluginProviderStreamFn("ACME-AI")).toBe(stream);
+    expect(resolvePluginProviderStreamFn("openai")).toBeUndefined();
+  });
+
+  it("ignores providers that only register auth", () => {
+    setActivePluginRegistry({
+      ...createTestRegistry(),
+      providers: [
+        { pluginId: "acme", source: "test", provider: { id: "acme", label: "Acme", auth: [] } },
+      ],
+    });
+
+    expect(resolvePluginProviderStreamFn("acme")).toBeUndefined();
+  });
+});
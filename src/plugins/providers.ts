@@ -1,6 +1,9 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
 import type { ProviderPlugin } from "./types.js";
+import { normalizeProviderId } from "../agents/model-selection.js";
 import { createSubsystemLogger } from "../logging/subsystem.js";
 import { loadOpenClawPlugins, type PluginLoadOptions } from "./loader.js";
+import { getActivePluginRegistry } from "./runtime.js";
 
 const log = createSubsystemLogger("plugins");
 
@@ -21,3 +24,12 @@ export function resolvePluginProviders(params: {
 
   return registry.providers.map((entry) => entry.provider);
 }
+
+/** Stream function registered by a loaded provider plugin for `provider` (id or alias), if any. */
+export function resolvePluginProviderStreamFn(provider: string): StreamFn | undefined {
+  const target = normalizeProviderId(provider);
+  const entry = getActivePluginRegistry()?.providers.find(({ provider: plugin }) =>
+    [plugin.id, ...(plugin.aliases ?? [])].some((id) => normalizeProviderId(id) === target),
+  );
+  return entry?.provider.stream;
+}
@@ -84,6 +84,9 @@ type ResizeToJpeg = typeof import("../../media/image-ops.js").resizeToJpeg;
 type CreateMemoryGetTool = typeof import("../../agents/tools/memory-tool.js").createMemoryGetTool;
 type CreateMemorySearchTool =
   typeof import("../../agents/tools/memory-tool.js").createMemorySearchTool;
+type CreateMemoryReadTool = typeof import("../../agents/tools/memory-tool.js").createMemoryReadTool;
+type CreateMemoryWriteTool =
+  typeof import("../../agents/tools/memory-tool.js").createMemoryWriteTool;
 type RegisterMemoryCli = typeof import("../../cli/memory-cli.js").registerMemoryCli;
 type DiscordMessageActions =
   typeof import("../../channels/plugins/actions/discord.js").discordMessageActions;
@@ -201,6 +204,8 @@ export type PluginRuntime = {
   tools: {
     createMemoryGetTool: CreateMemoryGetTool;
     createMemorySearchTool: CreateMemorySearchTool;
+    createMemoryReadTool: CreateMemoryReadTool;
+    createMemoryWriteTool: CreateMemoryWriteTool;
     registerMemoryCli: RegisterMemoryCli;
   };
   channel: {
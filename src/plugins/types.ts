@@ -1,4 +1,4 @@
-import type { AgentMessage } from "@mariozechner/pi-agent-core";
+import type { AgentMessage, StreamFn } from "@mariozechner/pi-agent-core";
 import type { Command } from "commander";
 import type { IncomingMessage, ServerResponse } from "node:http";
 import type { AuthProfileCredential, OAuthCredential } from "../agents/auth-profiles/types.js";
@@ -122,6 +122,11 @@ export type ProviderPlugin = {
   auth: ProviderAuthMethod[];
   formatApiKey?: (cred: AuthProfileCredential) => string;
   refreshOAuth?: (cred: OAuthCredential) => Promise<OAuthCredential>;
+  /**
+   * Custom model transport. When set, agent runs on this provider's models call it instead of
+   * the built-in API client selected by the model's `api`.
+   */
+  stream?: StreamFn;
 };
 
 export type OpenClawPluginGatewayMethod = {
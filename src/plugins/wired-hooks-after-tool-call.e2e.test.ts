@@ -2,6 +2,7 @@
  * Test: after_tool_call hook wiring (pi-embedded-subscribe.handlers.tools.ts)
  */
 import { beforeEach, describe, expect, it, vi } from "vitest";
+import { createRunTimingsTracker } from "../agents/run-timings.js";
 
 const hookMocks = vi.hoisted(() => ({
   runner: {
@@ -55,6 +56,7 @@ describe("after_tool_call hook wiring", () => {
         messagingToolSentTextsNormalized: [] as string[],
         messagingToolSentTargets: [] as unknown[],
         blockBuffer: "",
+        timings: createRunTimingsTracker(),
       },
       log: { debug: vi.fn(), warn: vi.fn() },
       flushBlockReplyBuffer: vi.fn(),
@@ -120,6 +122,7 @@ describe("after_tool_call hook wiring", () => {
         messagingToolSentTextsNormalized: [] as string[],
         messagingToolSentTargets: [] as unknown[],
         blockBuffer: "",
+        timings: createRunTimingsTracker(),
       },
       log: { debug: vi.fn(), warn: vi.fn() },
       flushBlockReplyBuffer: vi.fn(),
@@ -175,6 +178,7 @@ describe("after_tool_call hook wiring", () => {
         messagingToolSentTexts: [] as string[],
         messagingToolSentTextsNormalized: [] as string[],
         messagingToolSentTargets: [] as unknown[],
+        timings: createRunTimingsTracker(),
       },
       log: { debug: vi.fn(), warn: vi.fn() },
       shouldEmitToolResult: () => false,
@@ -138,6 +138,8 @@ export async function runCommandWithTimeout(
     }, timeoutMs);
 
     if (hasInput && child.stdin) {
+      // A child that exits without reading stdin must not surface EPIPE as an uncaught error.
+      child.stdin.on("error", () => {});
       child.stdin.write(input ?? "");
       child.stdin.end();
     }
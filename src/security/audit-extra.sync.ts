@@ -332,6 +332,14 @@ function isWebFetchEnabled(cfg: OpenClawConfig): boolean {
   return true;
 }
 
+function isHttpRequestEnabled(cfg: OpenClawConfig): boolean {
+  return cfg.tools?.web?.http?.enabled === true;
+}
+
+function isGhToolEnabled(cfg: OpenClawConfig): boolean {
+  return cfg.tools?.github?.enabled === true;
+}
+
 function isBrowserEnabled(cfg: OpenClawConfig): boolean {
   try {
     return resolveBrowserConfig(cfg.browser, cfg).enabled;
@@ -853,6 +861,16 @@ export function collectSmallModelRiskFindings(params: {
         exposed.push("web_fetch");
       }
     }
+    if (isHttpRequestEnabled(params.cfg)) {
+      if (isToolAllowedByPolicies("http_request", policies)) {
+        exposed.push("http_request");
+      }
+    }
+    if (isGhToolEnabled(params.cfg)) {
+      if (isToolAllowedByPolicies("gh", policies)) {
+        exposed.push("gh");
+      }
+    }
     if (isBrowserEnabled(params.cfg)) {
       if (isToolAllowedByPolicies("browser", policies)) {
         exposed.push("browser");
@@ -0,0 +1,88 @@
+import { CURRENT_SESSION_VERSION, SessionManager } from "@mariozechner/pi-coding-agent";
+import crypto from "node:crypto";
+import fs from "node:fs";
+import path from "node:path";
+import type { SessionEntry } from "../config/sessions.js";
+
+export type SessionBranchResult =
+  | { ok: true; sessionId: string; sessionFile: string }
+  | { ok: false; error: string };
+
+/**
+ * Writes a new transcript holding the active branch of `sessionFile`, optionally cut back so the
+ * last `rewindTurns` user messages (and everything after them) are left out. The source file is
+ * not modified; the new header points back at it through `parentSession`.
+ */
+export function branchSessionTranscript(params: {
+  sessionFile: string;
+  rewindTurns?: number;
+}): SessionBranchResult {
+  if (!fs.existsSync(params.sessionFile)) {
+    return { ok: false, error: "This session has no transcript yet." };
+  }
+  const manager = SessionManager.open(params.sessionFile);
+  let leafId = manager.getLeafId();
+  const rewindTurns = params.rewindTurns ?? 0;
+  if (rewindTurns > 0) {
+    const userEntries = manager
+      .getBranch()
+      .filter((entry) => entry.type === "message" && entry.message.role === "user");
+    if (rewindTurns > userEntries.length) {
+      return { ok: false, error: `Only ${userEntries.length} turn(s) to rewind.` };
+    }
+    leafId = userEntries[userEntries.length - rewindTurns].parentId;
+  }
+  if (leafId) {
+    const sessionFile = manager.createBranchedSession(leafId);
+    const sessionId = manager.getSessionId();
+    if (sessionFile && sessionId) {
+      return { ok: true, sessionId, sessionFile };
+    }
+  }
+
+  // Nothing to keep (empty transcript, or rewound past the first message): start empty.
+  const sessionId = crypto.randomUUID();
+  const timestamp = new Date().toISOString();
+  const sessionFile = path.join(
+    path.dirname(params.sessionFile),
+    `${timestamp.replace(/[:.]/g, "-")}_${sessionId}.jsonl`,
+  );
+  const header = {
+    type: "session",
+    version: CURRENT_SESSION_VERSION,
+    id: sessionId,
+    timestamp,
+    cwd: manager.getCwd(),
+    parentSession: params.sessionFile,
+  };
+  fs.writeFileSync(sessionFile, `${JSON.stringify(header)}\n`, "utf-8");
+  return { ok: true, sessionId, sessionFile };
+}
+
+/**
+ * Store entry for a fork of `entry` under a new session id. Settings, pins, and usage carry over;
+ * CLI backend conversation ids do not, since those backends only know the original history.
+ */
+export function buildForkedSessionEntry(
+  entry: SessionEntry,
+  params: { sessionId: string; sessionFile?: string; title?: string; rewound?: boolean },
+): SessionEntry {
+  const now = Date.now();
+  const next: SessionEntry = {
+    ...entry,
+    sessionId: params.sessionId,
+    sessionFile: params.sessionFile,
+    createdAt: now,
+    updatedAt: now,
+    title: params.title,
+    titleSource:
+      params.title === entry.title ? entry.titleSource : params.title ? "user" : undefined,
+  };
+  if (params.rewound) {
+    // The last reported prompt size covers turns that are no longer in the transcript.
+    next.totalTokensFresh = false;
+  }
+  delete next.cliSessionIds;
+  delete next.claudeCliSessionId;
+  return next;
+}
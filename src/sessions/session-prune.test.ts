@@ -0,0 +1,89 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { SessionEntry } from "../config/sessions.js";
+
+vi.mock("../config/config.js", async (importOriginal) => {
+  const actual = await importOriginal<typeof import("../config/config.js")>();
+  return { ...actual, loadConfig: () => ({}) };
+});
+
+import { loadSessionStore, saveSessionStore } from "../config/sessions.js";
+import { applySessionPrune, planSessionPrune } from "./session-prune.js";
+
+const DAY = 24 * 60 * 60_000;
+
+describe("session prune", () => {
+  let dir: string;
+  let storePath: string;
+  let store: Record<string, SessionEntry>;
+
+  const touch = (name: string, ageMs: number) => {
+    const file = path.join(dir, name);
+    fs.writeFileSync(file, "{}\n");
+    const time = new Date(Date.now() - ageMs);
+    fs.utimesSync(file, time, time);
+    return file;
+  };
+
+  beforeEach(async () => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-session-prune-"));
+    storePath = path.join(dir, "sessions.json");
+    const now = Date.now();
+    store = {
+      "agent:main:main": { sessionId: "main-id", updatedAt: now - 90 * DAY },
+      stale: { sessionId: "stale-id", updatedAt: now - 40 * DAY },
+      recent: { sessionId: "recent-id", updatedAt: now - DAY },
+      newest: { sessionId: "newest-id", updatedAt: now },
+    };
+    await saveSessionStore(storePath, store, { skipMaintenance: true });
+    for (const id of ["main-id", "stale-id", "recent-id", "newest-id"]) {
+      touch(`${id}.jsonl`, 0);
+    }
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it("plans stale and over-cap sessions plus stale orphans, never main sessions", () => {
+    const orphan = touch("gone-id.jsonl.deleted.2026-01-01T00-00-00.000Z", 40 * DAY);
+    touch("fresh-orphan.jsonl", DAY);
+
+    const plan = planSessionPrune({
+      storePath,
+      store,
+      pruneAfterMs: 30 * DAY,
+      maxEntries: 1,
+      protectedKeys: ["agent:main:main"],
+    });
+
+    expect(plan.sessions.map((session) => session.key).toSorted()).toEqual(["recent", "stale"]);
+    expect(plan.sessions.find((session) => session.key === "stale")?.transcripts).toEqual([
+      path.join(dir, "stale-id.jsonl"),
+    ]);
+    expect(plan.orphanTranscripts).toEqual([orphan]);
+  });
+
+  it("deletes transcripts and skips sessions used since the plan was made", async () => {
+    const plan = planSessionPrune({ storePath, store, pruneAfterMs: 30 * DAY, maxEntries: 2 });
+    await saveSessionStore(
+      storePath,
+      { ...store, stale: { ...store.stale, updatedAt: Date.now() } },
+      { skipMaintenance: true },
+    );
+
+    const result = await applySessionPrune(plan, "delete");
+
+    expect(result.removed).toEqual(["agent:main:main"]);
+    expect(result.deleted).toEqual([path.join(dir, "main-id.jsonl")]);
+    expect(Object.keys(loadSessionStore(storePath, { skipCache: true })).toSorted()).toEqual([
+      "newest",
+      "recent",
+      "stale",
+    ]);
+    expect(fs.existsSync(path.join(dir, "stale-id.jsonl"))).toBe(true);
+    expect(fs.existsSync(path.join(dir, "archive"))).toBe(false);
+  });
+});
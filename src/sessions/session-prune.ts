@@ -0,0 +1,192 @@
+import fs from "node:fs";
+import path from "node:path";
+import { pipeline } from "node:stream/promises";
+import zlib from "node:zlib";
+import type { OpenClawConfig } from "../config/config.js";
+import type { SessionTranscriptRetention } from "../config/types.base.js";
+import { listAgentIds } from "../agents/agent-scope.js";
+import {
+  capEntryCount,
+  pruneStaleEntries,
+  resolveAgentMainSessionKey,
+  resolveMainSessionKey,
+  updateSessionStore,
+  type SessionEntry,
+} from "../config/sessions.js";
+import { resolveSessionTranscriptCandidates } from "../gateway/session-utils.fs.js";
+
+/** Live transcripts plus the `.deleted.*` / `.reset.*` copies left by delete and reset. */
+const TRANSCRIPT_FILE_RE = /\.jsonl(?:\.(?:deleted|reset)\..+)?$/;
+
+export type PrunedSession = {
+  key: string;
+  sessionId: string;
+  updatedAt?: number;
+  transcripts: string[];
+};
+
+export type SessionPrunePlan = {
+  storePath: string;
+  /** Entries idle for longer than `pruneAfterMs`, or beyond the newest `maxEntries`. */
+  sessions: PrunedSession[];
+  /** Transcripts in the sessions directory that no entry refers to and are just as stale. */
+  orphanTranscripts: string[];
+};
+
+export type SessionPruneResult = {
+  storePath: string;
+  removed: string[];
+  archived: string[];
+  deleted: string[];
+};
+
+/** Main sessions are never pruned, whatever their age. */
+export function resolveProtectedSessionKeys(cfg: OpenClawConfig): Set<string> {
+  return new Set([
+    resolveMainSessionKey(cfg),
+    ...listAgentIds(cfg).map((agentId) => resolveAgentMainSessionKey({ cfg, agentId })),
+  ]);
+}
+
+/**
+ * Works out what a prune pass would remove, using the same age and count rules as
+ * `session.maintenance` enforcement on save. Nothing is changed on disk.
+ */
+export function planSessionPrune(params: {
+  storePath: string;
+  store: Record<string, SessionEntry>;
+  pruneAfterMs: number;
+  maxEntries: number;
+  protectedKeys?: Iterable<string>;
+}): SessionPrunePlan {
+  const protectedKeys = new Set(params.protectedKeys);
+  const kept = { ...params.store };
+  pruneStaleEntries(kept, params.pruneAfterMs, { log: false });
+  capEntryCount(kept, params.maxEntries, { log: false });
+  for (const key of protectedKeys) {
+    if (params.store[key]) {
+      kept[key] = params.store[key];
+    }
+  }
+
+  const keptIds = Object.values(kept)
+    .map((entry) => entry?.sessionId)
+    .filter((id): id is string => Boolean(id));
+  const keptFiles = new Set(
+    Object.values(kept)
+      .map((entry) => entry?.sessionFile && path.basename(entry.sessionFile))
+      .filter(Boolean),
+  );
+  const sessions: PrunedSession[] = [];
+  const planned = new Set<string>();
+  for (const [key, entry] of Object.entries(params.store)) {
+    if (kept[key] || !entry?.sessionId) {
+      continue;
+    }
+    // Another entry may still point at the same transcript (e.g. an alias key).
+    const shared = keptIds.includes(entry.sessionId);
+    const transcripts = shared
+      ? []
+      : resolveSessionTranscriptCandidates(
+          entry.sessionId,
+          params.storePath,
+          entry.sessionFile,
+        ).filter((candidate) => !planned.has(candidate) && fs.existsSync(candidate));
+    for (const transcript of transcripts) {
+      planned.add(transcript);
+    }
+    sessions.push({ key, sessionId: entry.sessionId, updatedAt: entry.updatedAt, transcripts });
+  }
+
+  const sessionsDir = path.dirname(params.storePath);
+  const cutoffMs = Date.now() - params.pruneAfterMs;
+  const orphanTranscripts: string[] = [];
+  let names: string[] = [];
+  try {
+    names = fs.readdirSync(sessionsDir);
+  } catch {
+    // No sessions directory yet.
+  }
+  for (const name of names.toSorted()) {
+    if (
+      !TRANSCRIPT_FILE_RE.test(name) ||
+      keptFiles.has(name) ||
+      keptIds.some((id) => name.includes(id))
+    ) {
+      continue;
+    }
+    const file = path.join(sessionsDir, name);
+    if (planned.has(file)) {
+      continue;
+    }
+    try {
+      const stat = fs.statSync(file);
+      if (stat.isFile() && stat.mtimeMs < cutoffMs) {
+        orphanTranscripts.push(file);
+      }
+    } catch {
+      // Removed while scanning.
+    }
+  }
+
+  return { storePath: params.storePath, sessions, orphanTranscripts };
+}
+
+/** Gzips `file` into an `archive/` folder next to it and removes the original. */
+export async function archiveTranscriptFile(file: string): Promise<string> {
+  const archiveDir = path.join(path.dirname(file), "archive");
+  await fs.promises.mkdir(archiveDir, { recursive: true });
+  let target = path.join(archiveDir, `${path.basename(file)}.gz`);
+  if (fs.existsSync(target)) {
+    target = path.join(archiveDir, `${path.basename(file)}.${Date.now()}.gz`);
+  }
+  await pipeline(fs.createReadStream(file), zlib.createGzip(), fs.createWriteStream(target));
+  await fs.promises.rm(file, { force: true });
+  return target;
+}
+
+/**
+ * Removes the planned entries (skipping any that were used since the plan was made), then
+ * archives, deletes, or keeps their transcripts and the orphaned ones.
+ */
+export async function applySessionPrune(
+  plan: SessionPrunePlan,
+  retention: SessionTranscriptRetention,
+): Promise<SessionPruneResult> {
+  const removed: PrunedSession[] = [];
+  if (plan.sessions.length > 0) {
+    await updateSessionStore(plan.storePath, (store) => {
+      for (const session of plan.sessions) {
+        const entry = store[session.key];
+        if (entry?.sessionId === session.sessionId && entry.updatedAt === session.updatedAt) {
+          delete store[session.key];
+          removed.push(session);
+        }
+      }
+    });
+  }
+
+  const result: SessionPruneResult = {
+    storePath: plan.storePath,
+    removed: removed.map((session) => session.key),
+    archived: [],
+    deleted: [],
+  };
+  if (retention === "keep") {
+    return result;
+  }
+  const files = [...removed.flatMap((session) => session.transcripts), ...plan.orphanTranscripts];
+  for (const file of files) {
+    try {
+      if (retention === "delete") {
+        await fs.promises.rm(file);
+        result.deleted.push(file);
+      } else {
+        result.archived.push(await archiveTranscriptFile(file));
+      }
+    } catch {
+      // Best-effort: the file may have been removed in the meantime.
+    }
+  }
+  return result;
+}
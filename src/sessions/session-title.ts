@@ -0,0 +1,63 @@
+export const SESSION_TITLE_MAX_LENGTH = 80;
+export const SESSION_TAG_MAX_LENGTH = 32;
+export const MAX_SESSION_TAGS = 10;
+
+export type ParsedSessionTitle = { ok: true; title: string } | { ok: false; error: string };
+
+export function parseSessionTitle(raw: unknown): ParsedSessionTitle {
+  if (typeof raw !== "string") {
+    return { ok: false, error: "invalid title: must be a string" };
+  }
+  const trimmed = raw.replace(/\s+/g, " ").trim();
+  if (!trimmed) {
+    return { ok: false, error: "invalid title: empty" };
+  }
+  if (trimmed.length > SESSION_TITLE_MAX_LENGTH) {
+    return {
+      ok: false,
+      error: `invalid title: too long (max ${SESSION_TITLE_MAX_LENGTH})`,
+    };
+  }
+  return { ok: true, title: trimmed };
+}
+
+/** Lowercases, strips a leading `#`, drops duplicates and empties, and caps the count. */
+export function normalizeSessionTags(raw: string[]): string[] {
+  const tags: string[] = [];
+  for (const item of raw) {
+    const tag = item
+      .trim()
+      .replace(/^#+/, "")
+      .toLowerCase()
+      .replace(/[^\p{L}\p{N}_.-]+/gu, "-")
+      .replace(/^-+|-+$/g, "")
+      .slice(0, SESSION_TAG_MAX_LENGTH);
+    if (tag && !tags.includes(tag)) {
+      tags.push(tag);
+    }
+  }
+  return tags.slice(0, MAX_SESSION_TAGS);
+}
+
+/** Cleans a model-generated title: first line, no quotes or trailing period, length-capped. */
+export function cleanGeneratedTitle(text: string): string | undefined {
+  const line = text
+    .split("\n")
+    .map((item) => item.trim())
+    .find(Boolean);
+  if (!line) {
+    return undefined;
+  }
+  const cleaned = line
+    .replace(/^title:\s*/i, "")
+    .replace(/^["'`*]+|["'`*]+$/g, "")
+    .replace(/\.+$/, "")
+    .replace(/\s+/g, " ")
+    .trim();
+  if (!cleaned) {
+    return undefined;
+  }
+  return cleaned.length > SESSION_TITLE_MAX_LENGTH
+    ? `${cleaned.slice(0, SESSION_TITLE_MAX_LENGTH - 1).trimEnd()}…`
+    : cleaned;
+}
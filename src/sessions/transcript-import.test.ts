@@ -0,0 +1,199 @@
+import { describe, expect, it } from "vitest";
+import {
+  convertClaudeCodeTranscript,
+  convertOpenAiExport,
+  detectTranscriptImportFormat,
+} from "./transcript-import.js";
+
+const jsonl = (lines: unknown[]) => lines.map((line) => JSON.stringify(line)).join("\n");
+
+const CLAUDE_CODE_LOG = jsonl([
+  { type: "summary", summary: "Fix flaky auth test", leafUuid: "u4" },
+  {
+    type: "user",
+    sessionId: "cc-1",
+    timestamp: "2026-03-01T10:00:00.000Z",
+    message: { role: "user", content: "why does the auth test fail?" },
+  },
+  {
+    type: "user",
+    isMeta: true,
+    sessionId: "cc-1",
+    timestamp: "2026-03-01T10:00:01.000Z",
+    message: { role: "user", content: "<local-command-stdout></local-command-stdout>" },
+  },
+  {
+    type: "assistant",
+    sessionId: "cc-1",
+    timestamp: "2026-03-01T10:00:02.000Z",
+    message: {
+      id: "msg_1",
+      role: "assistant",
+      model: "claude-sonnet-4-5",
+      content: [{ type: "thinking", thinking: "look at the log", signature: "sig" }],
+    },
+  },
+  {
+    type: "assistant",
+    sessionId: "cc-1",
+    timestamp: "2026-03-01T10:00:03.000Z",
+    message: {
+      id: "msg_1",
+      role: "assistant",
+      model: "claude-sonnet-4-5",
+      content: [
+        { type: "text", text: "Reading the CI log." },
+        { type: "tool_use", id: "toolu_1", name: "Read", input: { file_path: "ci.log" } },
+      ],
+    },
+  },
+  {
+    type: "user",
+    sessionId: "cc-1",
+    timestamp: "2026-03-01T10:00:04.000Z",
+    message: {
+      role: "user",
+      content: [{ type: "tool_result", tool_use_id: "toolu_1", content: "timeout after 5s" }],
+    },
+  },
+  {
+    type: "assistant",
+    isSidechain: true,
+    sessionId: "cc-1",
+    message: { id: "msg_side", role: "assistant", content: [{ type: "text", text: "sub" }] },
+  },
+  {
+    type: "assistant",
+    sessionId: "cc-1",
+    timestamp: "2026-03-01T10:00:05.000Z",
+    message: {
+      id: "msg_2",
+      role: "assistant",
+      model: "claude-sonnet-4-5",
+      content: [{ type: "text", text: "The test times out; raise the limit." }],
+    },
+  },
+]);
+
+const OPENAI_EXPORT = JSON.stringify([
+  {
+    title: "Trip ideas",
+    create_time: 1_767_225_600,
+    conversation_id: "conv-1",
+    current_node: "n3",
+    mapping: {
+      root: { id: "root", parent: null, message: null },
+      n0: {
+        id: "n0",
+        parent: "root",
+        message: {
+          author: { role: "system" },
+          content: { content_type: "text", parts: [""] },
+          metadata: { is_visually_hidden_from_conversation: true },
+        },
+      },
+      n1: {
+        id: "n1",
+        parent: "n0",
+        message: {
+          author: { role: "user" },
+          create_time: 1_767_225_601,
+          content: { content_type: "text", parts: ["Where should I go in May?"] },
+        },
+      },
+      n2old: {
+        id: "n2old",
+        parent: "n1",
+        message: {
+          author: { role: "assistant" },
+          content: { content_type: "text", parts: ["Regenerated away"] },
+        },
+      },
+      n2: {
+        id: "n2",
+        parent: "n1",
+        message: {
+          author: { role: "assistant" },
+          create_time: 1_767_225_602,
+          content: { content_type: "text", parts: ["Lisbon is lovely in May."] },
+          metadata: { model_slug: "gpt-4o" },
+        },
+      },
+      n3: {
+        id: "n3",
+        parent: "n2",
+        message: {
+          author: { role: "user" },
+          content: { content_type: "text", parts: ["Thanks!"] },
+        },
+      },
+    },
+  },
+  { title: "Empty", current_node: "x", mapping: { x: { id: "x", parent: null } } },
+]);
+
+describe("transcript import", () => {
+  it("detects the source format", () => {
+    expect(detectTranscriptImportFormat(CLAUDE_CODE_LOG)).toBe("claude-code");
+    expect(detectTranscriptImportFormat(OPENAI_EXPORT)).toBe("openai");
+    expect(detectTranscriptImportFormat('{"hello":"world"}')).toBeUndefined();
+    expect(detectTranscriptImportFormat("")).toBeUndefined();
+  });
+
+  it("converts Claude Code logs, merging split responses and pairing tool results", () => {
+    const conversation = convertClaudeCodeTranscript(CLAUDE_CODE_LOG);
+    expect(conversation).toMatchObject({
+      sourceId: "cc-1",
+      title: "Fix flaky auth test",
+      model: "claude-sonnet-4-5",
+      createdAt: Date.parse("2026-03-01T10:00:00.000Z"),
+    });
+    expect(conversation.messages.map((message) => message.role)).toEqual([
+      "user",
+      "assistant",
+      "toolResult",
+      "assistant",
+    ]);
+    const [, call, result] = conversation.messages;
+    expect(call).toMatchObject({
+      stopReason: "toolUse",
+      provider: "anthropic",
+      content: [
+        { type: "text", text: "Reading the CI log." },
+        { type: "toolCall", id: "toolu_1", name: "Read", arguments: { file_path: "ci.log" } },
+      ],
+    });
+    expect(result).toMatchObject({
+      toolCallId: "toolu_1",
+      toolName: "Read",
+      isError: false,
+      content: [{ type: "text", text: "timeout after 5s" }],
+    });
+  });
+
+  it("converts the current branch of each ChatGPT conversation", () => {
+    const conversations = convertOpenAiExport(OPENAI_EXPORT);
+    expect(conversations).toHaveLength(1);
+    const [conversation] = conversations;
+    expect(conversation).toMatchObject({
+      sourceId: "conv-1",
+      title: "Trip ideas",
+      model: "gpt-4o",
+      createdAt: 1_767_225_600_000,
+    });
+    expect(
+      conversation.messages.map((message) => [
+        message.role,
+        (message.content[0] as { text: string }).text,
+      ]),
+    ).toEqual([
+      ["user", "Where should I go in May?"],
+      ["assistant", "Lisbon is lovely in May."],
+      ["user", "Thanks!"],
+    ]);
+    expect(conversation.messages[1]).toMatchObject({
+      provider: "openai",
+      timestamp: 1_767_225_602_000,
+    });
+  });
+});
@@ -0,0 +1,335 @@
+import type {
+  AssistantMessage,
+  ImageContent,
+  TextContent,
+  ToolCall,
+  ToolResultMessage,
+  UserMessage,
+} from "@mariozechner/pi-ai";
+
+export type TranscriptImportFormat = "claude-code" | "openai";
+
+export type ImportedMessage = UserMessage | AssistantMessage | ToolResultMessage;
+
+export type ImportedConversation = {
+  /** Id of the conversation in the source tool, when it has one. */
+  sourceId?: string;
+  title?: string;
+  createdAt?: number;
+  provider?: string;
+  model?: string;
+  messages: ImportedMessage[];
+};
+
+type RawRecord = Record<string, unknown>;
+
+const ZERO_USAGE: AssistantMessage["usage"] = {
+  input: 0,
+  output: 0,
+  cacheRead: 0,
+  cacheWrite: 0,
+  totalTokens: 0,
+  cost: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0, total: 0 },
+};
+
+function asRecord(value: unknown): RawRecord | undefined {
+  return value && typeof value === "object" && !Array.isArray(value)
+    ? (value as RawRecord)
+    : undefined;
+}
+
+function asString(value: unknown): string | undefined {
+  return typeof value === "string" ? value : undefined;
+}
+
+function parseTimestamp(value: unknown, fallback: number): number {
+  if (typeof value === "number" && Number.isFinite(value)) {
+    // OpenAI exports use seconds; anything below 1e12 is not a millisecond epoch.
+    return value < 1e12 ? Math.round(value * 1000) : value;
+  }
+  if (typeof value === "string") {
+    const parsed = Date.parse(value);
+    if (Number.isFinite(parsed)) {
+      return parsed;
+    }
+  }
+  return fallback;
+}
+
+function assistantMessage(params: {
+  content: AssistantMessage["content"];
+  provider: string;
+  model: string;
+  api: AssistantMessage["api"];
+  timestamp: number;
+}): AssistantMessage {
+  const hasToolCall = params.content.some((block) => block.type === "toolCall");
+  return {
+    role: "assistant",
+    content: params.content,
+    api: params.api,
+    provider: params.provider,
+    model: params.model,
+    usage: ZERO_USAGE,
+    stopReason: hasToolCall ? "toolUse" : "stop",
+    timestamp: params.timestamp,
+  };
+}
+
+/** Detects the source format from file contents; undefined when it matches neither. */
+export function detectTranscriptImportFormat(raw: string): TranscriptImportFormat | undefined {
+  const trimmed = raw.trim();
+  if (!trimmed) {
+    return undefined;
+  }
+  try {
+    const parsed = JSON.parse(trimmed) as unknown;
+    const first = Array.isArray(parsed) ? parsed[0] : parsed;
+    if (asRecord(first)?.mapping) {
+      return "openai";
+    }
+  } catch {
+    // Not a single JSON document; JSONL is checked below.
+  }
+  const firstLine = trimmed.split(/\r?\n/, 1)[0] ?? "";
+  try {
+    const record = asRecord(JSON.parse(firstLine));
+    return record && ("sessionId" in record || "type" in record) ? "claude-code" : undefined;
+  } catch {
+    return undefined;
+  }
+}
+
+// --- Claude Code (~/.claude/projects/<project>/<session>.jsonl) ---
+
+function claudeImageBlock(block: RawRecord): ImageContent | undefined {
+  const source = asRecord(block.source);
+  const data = asString(source?.data);
+  const mimeType = asString(source?.media_type);
+  return source?.type === "base64" && data && mimeType
+    ? { type: "image", data, mimeType }
+    : undefined;
+}
+
+function claudeToolResultContent(value: unknown): Array<TextContent | ImageContent> {
+  if (typeof value === "string") {
+    return [{ type: "text", text: value }];
+  }
+  if (!Array.isArray(value)) {
+    return [];
+  }
+  const content: Array<TextContent | ImageContent> = [];
+  for (const item of value) {
+    const block = asRecord(item);
+    if (block?.type === "text" && typeof block.text === "string") {
+      content.push({ type: "text", text: block.text });
+    } else if (block?.type === "image") {
+      const image = claudeImageBlock(block);
+      if (image) {
+        content.push(image);
+      }
+    }
+  }
+  return content;
+}
+
+/**
+ * Converts a Claude Code session log. Each API response is split across several lines that share
+ * `message.id`; those are merged back into one assistant message. Thinking blocks are dropped
+ * because their signatures cannot be replayed, and sidechain (subagent) lines are skipped.
+ */
+export function convertClaudeCodeTranscript(raw: string): ImportedConversation {
+  const messages: ImportedMessage[] = [];
+  const toolNames = new Map<string, string>();
+  let sourceId: string | undefined;
+  let title: string | undefined;
+  let model: string | undefined;
+  let createdAt: number | undefined;
+  let lastAssistantId: string | undefined;
+
+  for (const line of raw.split(/\r?\n/)) {
+    if (!line.trim()) {
+      continue;
+    }
+    let record: RawRecord | undefined;
+    try {
+      record = asRecord(JSON.parse(line));
+    } catch {
+      continue;
+    }
+    if (!record) {
+      continue;
+    }
+    if (record.type === "summary") {
+      title ??= asString(record.summary);
+      continue;
+    }
+    if ((record.type !== "user" && record.type !== "assistant") || record.isSidechain === true) {
+      continue;
+    }
+    const message = asRecord(record.message);
+    if (!message) {
+      continue;
+    }
+    sourceId ??= asString(record.sessionId);
+    const timestamp = parseTimestamp(record.timestamp, Date.now());
+    createdAt ??= timestamp;
+    const blocks =
+      typeof message.content === "string"
+        ? [{ type: "text", text: message.content }]
+        : Array.isArray(message.content)
+          ? message.content.map(asRecord).filter((block): block is RawRecord => Boolean(block))
+          : [];
+
+    if (record.type === "assistant") {
+      const content: AssistantMessage["content"] = [];
+      for (const block of blocks) {
+        if (block.type === "text" && typeof block.text === "string" && block.text.trim()) {
+          content.push({ type: "text", text: block.text });
+        } else if (block.type === "tool_use" && typeof block.id === "string") {
+          const name = asString(block.name) ?? "tool";
+          toolNames.set(block.id, name);
+          const call: ToolCall = {
+            type: "toolCall",
+            id: block.id,
+            name,
+            arguments: asRecord(block.input) ?? {},
+          };
+          content.push(call);
+        }
+      }
+      if (content.length === 0) {
+        continue;
+      }
+      model = asString(message.model) ?? model;
+      const messageId = asString(message.id);
+      const previous = messages.at(-1);
+      if (messageId && messageId === lastAssistantId && previous?.role === "assistant") {
+        previous.content.push(...content);
+        previous.stopReason = previous.content.some((block) => block.type === "toolCall")
+          ? "toolUse"
+          : "stop";
+        continue;
+      }
+      lastAssistantId = messageId;
+      messages.push(
+        assistantMessage({
+          content,
+          provider: "anthropic",
+          model: model ?? "claude-code",
+          api: "anthropic-messages",
+          timestamp,
+        }),
+      );
+      continue;
+    }
+
+    lastAssistantId = undefined;
+    const userContent: Array<TextContent | ImageContent> = [];
+    for (const block of blocks) {
+      if (block.type === "tool_result" && typeof block.tool_use_id === "string") {
+        messages.push({
+          role: "toolResult",
+          toolCallId: block.tool_use_id,
+          toolName: toolNames.get(block.tool_use_id) ?? "tool",
+          content: claudeToolResultContent(block.content),
+          isError: block.is_error === true,
+          timestamp,
+        });
+      } else if (block.type === "text" && typeof block.text === "string" && block.text.trim()) {
+        userContent.push({ type: "text", text: block.text });
+      } else if (block.type === "image") {
+        const image = claudeImageBlock(block);
+        if (image) {
+          userContent.push(image);
+        }
+      }
+    }
+    // Local command echoes and caveats are metadata, not conversation.
+    if (userContent.length > 0 && record.isMeta !== true) {
+      messages.push({ role: "user", content: userContent, timestamp });
+    }
+  }
+
+  return { sourceId, title, createdAt, provider: "anthropic", model, messages };
+}
+
+// --- OpenAI (ChatGPT data export `conversations.json`) ---
+
+function openAiText(content: RawRecord | undefined): string {
+  if (!content) {
+    return "";
+  }
+  const parts = Array.isArray(content.parts) ? content.parts : [];
+  const texts = parts.filter((part): part is string => typeof part === "string");
+  if (texts.length > 0) {
+    return texts.join("\n").trim();
+  }
+  return asString(content.text)?.trim() ?? "";
+}
+
+/**
+ * Converts one conversation from a ChatGPT export. The export stores a tree of edits and
+ * regenerations; only the branch ending at `current_node` (what the user last saw) is imported.
+ */
+export function convertOpenAiConversation(raw: unknown): ImportedConversation {
+  const conversation = asRecord(raw) ?? {};
+  const mapping = asRecord(conversation.mapping) ?? {};
+  const path: RawRecord[] = [];
+  const seen = new Set<string>();
+  let nodeId = asString(conversation.current_node);
+  while (nodeId && !seen.has(nodeId)) {
+    seen.add(nodeId);
+    const node = asRecord(mapping[nodeId]);
+    if (!node) {
+      break;
+    }
+    path.push(node);
+    nodeId = asString(node.parent);
+  }
+  path.reverse();
+
+  const createdAt = parseTimestamp(conversation.create_time, Date.now());
+  const messages: ImportedMessage[] = [];
+  let model: string | undefined;
+  for (const node of path) {
+    const message = asRecord(node.message);
+    const role = asString(asRecord(message?.author)?.role);
+    const text = openAiText(asRecord(message?.content));
+    if (!message || !text || asRecord(message.metadata)?.is_visually_hidden_from_conversation) {
+      continue;
+    }
+    const timestamp = parseTimestamp(message.create_time, createdAt);
+    if (role === "user") {
+      messages.push({ role: "user", content: [{ type: "text", text }], timestamp });
+    } else if (role === "assistant") {
+      model = asString(asRecord(message.metadata)?.model_slug) ?? model;
+      messages.push(
+        assistantMessage({
+          content: [{ type: "text", text }],
+          provider: "openai",
+          model: model ?? "chatgpt",
+          api: "openai-responses",
+          timestamp,
+        }),
+      );
+    }
+    // System prompts and tool (browsing/code) messages have no equivalent here; skip them.
+  }
+
+  return {
+    sourceId: asString(conversation.conversation_id) ?? asString(conversation.id),
+    title: asString(conversation.title),
+    createdAt,
+    provider: "openai",
+    model,
+    messages,
+  };
+}
+
+/** An export holds one conversation object or an array of them. */
+export function convertOpenAiExport(raw: string): ImportedConversation[] {
+  const parsed = JSON.parse(raw) as unknown;
+  const items = Array.isArray(parsed) ? parsed : [parsed];
+  return items.map(convertOpenAiConversation).filter((item) => item.messages.length > 0);
+}
@@ -0,0 +1,73 @@
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { buildSessionUsagePatch, recordSessionModelUse } from "./usage-totals.js";
+
+const cfg = {
+  models: {
+    providers: {
+      anthropic: {
+        models: [
+          { id: "opus", cost: { input: 15, output: 75, cacheRead: 1.5, cacheWrite: 18.75 } },
+        ],
+      },
+    },
+  },
+} as unknown as OpenClawConfig;
+
+describe("buildSessionUsagePatch", () => {
+  it("accumulates tokens and cost across runs", () => {
+    const first = buildSessionUsagePatch(
+      {},
+      {
+        usage: { input: 1_000_000, output: 10_000, cacheRead: 0, cacheWrite: 0 },
+        provider: "anthropic",
+        model: "opus",
+        config: cfg,
+        now: 1,
+      },
+    );
+    const second = buildSessionUsagePatch(first, {
+      usage: { input: 2_000, output: 100, cacheRead: 10_000 },
+      provider: "openai",
+      model: "gpt-5",
+      config: cfg,
+      now: 2,
+    });
+
+    expect(second.usageTotals).toEqual({
+      input: 1_002_000,
+      output: 10_100,
+      cacheRead: 10_000,
+      cacheWrite: 0,
+      totalTokens: 1_010_000 + 12_100,
+      costUsd: 15 + 0.75,
+      runs: 2,
+      runsWithoutCost: 1,
+    });
+    expect(second.modelHistory?.map((item) => `${item.provider}/${item.model}`)).toEqual([
+      "anthropic/opus",
+      "openai/gpt-5",
+    ]);
+  });
+
+  it("keeps totals untouched when a run reports no usage", () => {
+    const patch = buildSessionUsagePatch({}, { provider: "anthropic", model: "opus", now: 5 });
+    expect(patch.usageTotals).toBeUndefined();
+    expect(patch.modelHistory).toEqual([
+      { provider: "anthropic", model: "opus", firstUsedAt: 5, lastUsedAt: 5, runs: 1 },
+    ]);
+  });
+});
+
+describe("recordSessionModelUse", () => {
+  it("bumps repeat models in place", () => {
+    const history = recordSessionModelUse(
+      recordSessionModelUse(undefined, { provider: "anthropic", model: "opus", now: 1 }),
+      { provider: "anthropic", model: "opus", now: 9 },
+    );
+    expect(history).toEqual([
+      { provider: "anthropic", model: "opus", firstUsedAt: 1, lastUsedAt: 9, runs: 2 },
+    ]);
+    expect(recordSessionModelUse(history, { model: " " })).toBe(history);
+  });
+});
@@ -0,0 +1,103 @@
+import type { NormalizedUsage } from "../agents/usage.js";
+import type { OpenClawConfig } from "../config/config.js";
+import type { SessionEntry, SessionModelUse, SessionUsageTotals } from "../config/sessions.js";
+import { estimateUsageCost, resolveModelCostConfig } from "../utils/usage-format.js";
+
+/** Oldest entries are dropped once a session has switched models this many times. */
+export const MAX_SESSION_MODEL_HISTORY = 20;
+
+const finite = (value: number | undefined): number =>
+  typeof value === "number" && Number.isFinite(value) && value > 0 ? value : 0;
+
+export function recordSessionModelUse(
+  history: SessionModelUse[] | undefined,
+  params: { provider?: string; model?: string; now?: number },
+): SessionModelUse[] | undefined {
+  const model = params.model?.trim();
+  if (!model) {
+    return history;
+  }
+  const provider = params.provider?.trim() || undefined;
+  const now = params.now ?? Date.now();
+  const list = history ?? [];
+  const index = list.findIndex((item) => item.model === model && item.provider === provider);
+  if (index >= 0) {
+    const existing = list[index];
+    return list.map((item, i) =>
+      i === index ? { ...existing, lastUsedAt: now, runs: existing.runs + 1 } : item,
+    );
+  }
+  const next = [...list, { provider, model, firstUsedAt: now, lastUsedAt: now, runs: 1 }];
+  return next.length > MAX_SESSION_MODEL_HISTORY
+    ? next.slice(next.length - MAX_SESSION_MODEL_HISTORY)
+    : next;
+}
+
+/**
+ * Adds one run's usage to the session totals. Cost is estimated from the model's configured
+ * pricing; runs without pricing still count toward tokens and are tallied in runsWithoutCost.
+ */
+export function addSessionUsage(
+  totals: SessionUsageTotals | undefined,
+  params: { usage: NormalizedUsage; provider?: string; model?: string; config?: OpenClawConfig },
+): SessionUsageTotals {
+  const input = finite(params.usage.input);
+  const output = finite(params.usage.output);
+  const cacheRead = finite(params.usage.cacheRead);
+  const cacheWrite = finite(params.usage.cacheWrite);
+  const runTotal = finite(params.usage.total) || input + output + cacheRead + cacheWrite;
+  const cost = estimateUsageCost({
+    usage: params.usage,
+    cost: resolveModelCostConfig({
+      provider: params.provider,
+      model: params.model,
+      config: params.config,
+    }),
+  });
+  const next: SessionUsageTotals = {
+    input: (totals?.input ?? 0) + input,
+    output: (totals?.output ?? 0) + output,
+    cacheRead: (totals?.cacheRead ?? 0) + cacheRead,
+    cacheWrite: (totals?.cacheWrite ?? 0) + cacheWrite,
+    totalTokens: (totals?.totalTokens ?? 0) + runTotal,
+    runs: (totals?.runs ?? 0) + 1,
+  };
+  if (cost !== undefined) {
+    next.costUsd = (totals?.costUsd ?? 0) + cost;
+  } else if (totals?.costUsd !== undefined) {
+    next.costUsd = totals.costUsd;
+  }
+  const runsWithoutCost = (totals?.runsWithoutCost ?? 0) + (cost === undefined ? 1 : 0);
+  if (runsWithoutCost > 0) {
+    next.runsWithoutCost = runsWithoutCost;
+  }
+  return next;
+}
+
+/** Patch that folds one run into the entry's cumulative usage and model history. */
+export function buildSessionUsagePatch(
+  entry: Pick<SessionEntry, "usageTotals" | "modelHistory">,
+  params: {
+    usage?: NormalizedUsage;
+    provider?: string;
+    model?: string;
+    config?: OpenClawConfig;
+    now?: number;
+  },
+): Pick<SessionEntry, "usageTotals" | "modelHistory"> {
+  return {
+    usageTotals: params.usage
+      ? addSessionUsage(entry.usageTotals, {
+          usage: params.usage,
+          provider: params.provider,
+          model: params.model,
+          config: params.config,
+        })
+      : entry.usageTotals,
+    modelHistory: recordSessionModelUse(entry.modelHistory, {
+      provider: params.provider,
+      model: params.model,
+      now: params.now,
+    }),
+  };
+}
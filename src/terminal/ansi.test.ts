@@ -0,0 +1,30 @@
+import { describe, expect, it } from "vitest";
+import { createTerminalSanitizer, sanitizeTerminalText } from "./ansi.js";
+
+describe("sanitizeTerminalText", () => {
+  it("strips CSI, OSC, and charset escapes", () => {
+    expect(
+      sanitizeTerminalText(
+        "\x1b[31mred\x1b[0m \x1b]0;title\x07\x1b]8;;https://x\x1b\\link\x1b]8;;\x1b\\ \x1b(Bok",
+      ),
+    ).toBe("red link ok");
+  });
+
+  it("drops control characters but keeps whitespace", () => {
+    expect(sanitizeTerminalText("a\x07b\x7fc\u009b2Jd\ttab\r\n")).toBe("abcd\ttab\r\n");
+  });
+});
+
+describe("createTerminalSanitizer", () => {
+  it("holds escape sequences split across chunks", () => {
+    const sanitizer = createTerminalSanitizer();
+    const out = [
+      sanitizer.push("abc\x1b[3"),
+      sanitizer.push("1mred\x1b"),
+      sanitizer.push("]0;ti"),
+      sanitizer.push("tle\x07done"),
+      sanitizer.flush(),
+    ];
+    expect(out).toEqual(["abc", "red", "", "done", ""]);
+  });
+});
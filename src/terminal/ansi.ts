@@ -12,3 +12,63 @@ export function stripAnsi(input: string): string {
 export function visibleWidth(input: string): number {
   return Array.from(stripAnsi(input)).length;
 }
+
+// Every escape sequence a terminal would act on: CSI (7-bit and C1), OSC, DCS/SOS/PM/APC
+// strings, charset selection, and two-byte ESC commands.
+const TERMINAL_ESCAPE_PATTERN = [
+  "\\x1b\\[[0-?]*[ -/]*[@-~]",
+  "\\x9b[0-?]*[ -/]*[@-~]",
+  "\\x1b\\][\\s\\S]*?(?:\\x07|\\x1b\\\\|\\x9c)",
+  "\\x1b[PX^_][\\s\\S]*?(?:\\x1b\\\\|\\x9c)",
+  "\\x1b[()*+\\-./][\\s\\S]",
+  "\\x1b[ -/]*[0-~]",
+].join("|");
+// An escape sequence cut off at the end of a stream chunk.
+const PARTIAL_ESCAPE_PATTERN = [
+  "\\x1b[\\]PX^_](?:[^\\x07\\x1b\\x9c]|\\x1b(?!\\\\))*$",
+  "\\x1b(?:\\[[0-?]*[ -/]*|[ -/]*)$",
+  "\\x9b[0-?]*[ -/]*$",
+].join("|");
+
+const TERMINAL_ESCAPE_REGEX = new RegExp(TERMINAL_ESCAPE_PATTERN, "g");
+const PARTIAL_ESCAPE_REGEX = new RegExp(PARTIAL_ESCAPE_PATTERN);
+// C0 controls except tab/newline/carriage return, DEL, and C1 controls.
+const CONTROL_CHAR_REGEX = /[\x00-\x08\x0b\x0c\x0e-\x1f\x7f-\x9f]/g;
+
+export function stripTerminalEscapes(input: string): string {
+  return input.replace(TERMINAL_ESCAPE_REGEX, "");
+}
+
+/**
+ * Remove terminal escape sequences and control characters so untrusted output cannot
+ * drive the user's terminal (cursor moves, title changes, hyperlinks, clipboard writes).
+ */
+export function sanitizeTerminalText(input: string): string {
+  return stripTerminalEscapes(input).replace(CONTROL_CHAR_REGEX, "");
+}
+
+/**
+ * Streaming variant of sanitizeTerminalText: holds back an escape sequence split across
+ * chunks until the rest arrives. Call flush() at end of stream.
+ */
+export function createTerminalSanitizer() {
+  let pending = "";
+  return {
+    push(chunk: string): string {
+      const text = pending + chunk;
+      const partial = PARTIAL_ESCAPE_REGEX.exec(text);
+      // Cap held-back data so an unterminated OSC/DCS string cannot stall output forever.
+      if (partial && partial[0].length > 0 && partial[0].length < 4096) {
+        pending = partial[0];
+        return sanitizeTerminalText(text.slice(0, partial.index));
+      }
+      pending = "";
+      return sanitizeTerminalText(text);
+    },
+    flush(): string {
+      const rest = pending;
+      pending = "";
+      return sanitizeTerminalText(rest);
+    },
+  };
+}
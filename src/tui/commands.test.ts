@@ -1,5 +1,6 @@
 import { describe, expect, it } from "vitest";
-import { getSlashCommands, parseCommand } from "./commands.js";
+import type { OpenClawConfig } from "../config/types.js";
+import { buildModelCompletionItems, getSlashCommands, parseCommand } from "./commands.js";
 
 describe("tui slash commands", () => {
   it("treats /elev as an alias for /elevated", () => {
@@ -18,4 +19,38 @@ describe("tui slash commands", () => {
     expect(commands.some((command) => command.name === "context")).toBe(true);
     expect(commands.some((command) => command.name === "commands")).toBe(true);
   });
+
+  it("completes /model with aliases and catalog models, and /session from live sources", () => {
+    const cfg = {
+      agents: { defaults: { models: { "openai/gpt-5.2": { alias: "gpt" } } } },
+    } as OpenClawConfig;
+    const models = buildModelCompletionItems(cfg, [
+      { provider: "openai", id: "gpt-5.2", name: "GPT-5.2" },
+      { provider: "anthropic", id: "claude-opus-4-6", name: "claude-opus-4-6" },
+    ]);
+    let sessions = [{ value: "agent:main:main", label: "agent:main:main" }];
+    const commands = getSlashCommands({
+      cfg,
+      sources: { models: () => models, sessions: () => sessions },
+    });
+    const complete = (name: string, prefix: string) =>
+      commands
+        .find((command) => command.name === name)
+        ?.getArgumentCompletions?.(prefix)
+        ?.map((item) => item.value) ?? null;
+
+    expect(models[0]).toEqual({ value: "gpt", label: "gpt", description: "openai/gpt-5.2" });
+    expect(complete("model", "")).toEqual(["gpt", "openai/gpt-5.2", "anthropic/claude-opus-4-6"]);
+    expect(complete("model", "anth")).toEqual(["anthropic/claude-opus-4-6"]);
+    expect(complete("model", "5.2")).toEqual(["gpt", "openai/gpt-5.2"]);
+    expect(complete("session", "agent:")).toEqual(["agent:main:main"]);
+
+    sessions = [
+      { value: "agent:main:work", label: "agent:main:work", description: "Release notes" },
+    ];
+    expect(complete("session", "release")).toEqual(["agent:main:work"]);
+    expect(complete("session", "nope")).toBeNull();
+    expect(complete("session", "sw")).toEqual(["switch"]);
+    expect(complete("session", "switch release")).toEqual(["switch agent:main:work"]);
+  });
 });
@@ -1,5 +1,7 @@
-import type { SlashCommand } from "@mariozechner/pi-tui";
+import type { AutocompleteItem, SlashCommand } from "@mariozechner/pi-tui";
 import type { OpenClawConfig } from "../config/types.js";
+import { DEFAULT_PROVIDER } from "../agents/defaults.js";
+import { buildModelAliasIndex, modelKey } from "../agents/model-selection.js";
 import { listChatCommands, listChatCommandsForConfig } from "../auto-reply/commands-registry.js";
 import { formatThinkingLevels, listThinkingLevelLabels } from "../auto-reply/thinking.js";
 
@@ -8,18 +10,75 @@ const REASONING_LEVELS = ["on", "off"];
 const ELEVATED_LEVELS = ["on", "off", "ask", "full"];
 const ACTIVATION_LEVELS = ["mention", "always"];
 const USAGE_FOOTER_LEVELS = ["off", "tokens", "full"];
+const SESSION_SUBCOMMANDS: AutocompleteItem[] = [
+  { value: "list", label: "list", description: "List this agent's sessions" },
+  { value: "switch", label: "switch", description: "Switch by key, label, or title" },
+  { value: "new", label: "new", description: "Start a new named session" },
+];
 
 export type ParsedCommand = {
   name: string;
   args: string;
 };
 
+/** Live argument lists; read on every keystroke, so the TUI can refresh them in place. */
+export type SlashArgumentSources = {
+  models?: () => AutocompleteItem[];
+  sessions?: () => AutocompleteItem[];
+  agents?: () => AutocompleteItem[];
+};
+
 export type SlashCommandOptions = {
   cfg?: OpenClawConfig;
   provider?: string;
   model?: string;
+  sources?: SlashArgumentSources;
+  /** User-defined template commands; built-in names take precedence. */
+  customCommands?: Array<{ name: string; description?: string }>;
 };
 
+/** Prefix match on the value; once something is typed, also substring match label and hint. */
+function completeFrom(items: AutocompleteItem[] | undefined, prefix: string) {
+  const needle = prefix.trim().toLowerCase();
+  const matches = (items ?? []).filter(
+    (item) =>
+      item.value.toLowerCase().startsWith(needle) ||
+      (needle.length > 0 &&
+        [item.label, item.description].some((text) => text?.toLowerCase().includes(needle))),
+  );
+  return matches.length > 0 ? matches : null;
+}
+
+/** Configured aliases first (described by their target), then every catalog model. */
+export function buildModelCompletionItems(
+  cfg: OpenClawConfig | undefined,
+  models: Array<{ provider: string; id: string; name?: string }>,
+): AutocompleteItem[] {
+  const items: AutocompleteItem[] = [];
+  const seen = new Set<string>();
+  const push = (item: AutocompleteItem) => {
+    if (!seen.has(item.value)) {
+      seen.add(item.value);
+      items.push(item);
+    }
+  };
+  if (cfg) {
+    const { byAlias } = buildModelAliasIndex({ cfg, defaultProvider: DEFAULT_PROVIDER });
+    for (const { alias, ref } of byAlias.values()) {
+      push({ value: alias, label: alias, description: modelKey(ref.provider, ref.model) });
+    }
+  }
+  for (const model of models) {
+    const key = modelKey(model.provider, model.id);
+    push({
+      value: key,
+      label: key,
+      description: model.name && model.name !== model.id ? model.name : undefined,
+    });
+  }
+  return items;
+}
+
 const COMMAND_ALIASES: Record<string, string> = {
   elev: "elevated",
 };
@@ -39,16 +98,33 @@ export function parseCommand(input: string): ParsedCommand {
 
 export function getSlashCommands(options: SlashCommandOptions = {}): SlashCommand[] {
   const thinkLevels = listThinkingLevelLabels(options.provider, options.model);
+  const sources = options.sources ?? {};
   const commands: SlashCommand[] = [
     { name: "help", description: "Show slash command help" },
     { name: "status", description: "Show gateway status summary" },
-    { name: "agent", description: "Switch agent (or open picker)" },
+    {
+      name: "agent",
+      description: "Switch agent (or open picker)",
+      getArgumentCompletions: (prefix) => completeFrom(sources.agents?.(), prefix),
+    },
     { name: "agents", description: "Open agent picker" },
-    { name: "session", description: "Switch session (or open picker)" },
+    {
+      name: "session",
+      description: "Switch, list, or create sessions (or open picker)",
+      getArgumentCompletions: (prefix) => {
+        const target = prefix.match(/^switch\s+(.*)$/i);
+        if (target) {
+          const items = completeFrom(sources.sessions?.(), target[1]);
+          return items?.map((item) => ({ ...item, value: `switch ${item.value}` })) ?? null;
+        }
+        return completeFrom([...SESSION_SUBCOMMANDS, ...(sources.sessions?.() ?? [])], prefix);
+      },
+    },
     { name: "sessions", description: "Open session picker" },
     {
       name: "model",
       description: "Set model (or open picker)",
+      getArgumentCompletions: (prefix) => completeFrom(sources.models?.(), prefix),
     },
     { name: "models", description: "Open model picker" },
     {
@@ -114,14 +190,55 @@ export function getSlashCommands(options: SlashCommandOptions = {}): SlashComman
         })),
     },
     { name: "abort", description: "Abort active run" },
+    {
+      name: "copy",
+      description: "Copy the last response (or its last code block) to the clipboard",
+      getArgumentCompletions: (prefix) =>
+        "code".startsWith(prefix.toLowerCase()) ? [{ value: "code", label: "code" }] : [],
+    },
     { name: "new", description: "Reset the session" },
     { name: "reset", description: "Reset the session" },
-    { name: "settings", description: "Open settings" },
+    {
+      name: "multiline",
+      description: "Toggle multi-line input (Enter adds a line, Alt+Enter sends)",
+      getArgumentCompletions: (prefix) =>
+        ["on", "off"]
+          .filter((v) => v.startsWith(prefix.toLowerCase()))
+          .map((value) => ({ value, label: value })),
+    },
+    {
+      name: "memory",
+      description: "Show, add to, edit ($EDITOR), or clear the agent's memory",
+      getArgumentCompletions: (prefix) =>
+        ["show", "add", "edit", "clear"]
+          .filter((v) => v.startsWith(prefix.toLowerCase()))
+          .map((value) => ({ value, label: value })),
+    },
+    {
+      name: "diff-context",
+      description: "Send git diff/staged/log output with the next message",
+      getArgumentCompletions: (prefix) =>
+        ["diff", "staged", "log", "off"]
+          .filter((v) => v.startsWith(prefix.toLowerCase()))
+          .map((value) => ({ value, label: value })),
+    },
+    {
+      name: "settings",
+      description: "Show session settings (display: TUI display options)",
+      getArgumentCompletions: (prefix) =>
+        "display".startsWith(prefix.toLowerCase()) ? [{ value: "display", label: "display" }] : [],
+    },
     { name: "exit", description: "Exit the TUI" },
     { name: "quit", description: "Exit the TUI" },
   ];
 
   const seen = new Set(commands.map((command) => command.name));
+  for (const custom of options.customCommands ?? []) {
+    if (!seen.has(custom.name)) {
+      seen.add(custom.name);
+      commands.push({ name: custom.name, description: custom.description ?? "Custom command" });
+    }
+  }
   const gatewayCommands = options.cfg ? listChatCommandsForConfig(options.cfg) : listChatCommands();
   for (const command of gatewayCommands) {
     const aliases = command.textAliases.length > 0 ? command.textAliases : [`/${command.key}`];
@@ -140,13 +257,13 @@ export function getSlashCommands(options: SlashCommandOptions = {}): SlashComman
 
 export function helpText(options: SlashCommandOptions = {}): string {
   const thinkLevels = formatThinkingLevels(options.provider, options.model, "|");
-  return [
+  const lines = [
     "Slash commands:",
     "/help",
     "/commands",
     "/status",
     "/agent <id> (or /agents)",
-    "/session <key> (or /sessions)",
+    "/session [list | switch <name> | new <name>] (or /sessions)",
     "/model <provider/model> (or /models)",
     `/think <${thinkLevels}>`,
     "/verbose <on|off>",
@@ -157,7 +274,15 @@ export function helpText(options: SlashCommandOptions = {}): string {
     "/activation <mention|always>",
     "/new or /reset",
     "/abort",
-    "/settings",
+    "/copy [code]",
+    "/multiline [on|off]",
+    "/memory [show | add <fact> | edit | clear]",
+    "/diff-context [diff] [staged] [log <n>] | off",
+    "/settings [display]",
     "/exit",
-  ].join("\n");
+  ];
+  const custom = (options.customCommands ?? []).map((command) =>
+    command.description ? `/${command.name} - ${command.description}` : `/${command.name}`,
+  );
+  return [...lines, ...(custom.length > 0 ? ["", "Custom commands:", ...custom] : [])].join("\n");
 }
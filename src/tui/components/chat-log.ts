@@ -8,9 +8,11 @@ export class ChatLog extends Container {
   private toolById = new Map<string, ToolExecutionComponent>();
   private streamingRuns = new Map<string, AssistantMessageComponent>();
   private toolsExpanded = false;
+  private lastAssistantText: string | null = null;
 
   clearAll() {
     this.clear();
+    this.lastAssistantText = null;
     this.toolById.clear();
     this.streamingRuns.clear();
   }
@@ -46,6 +48,7 @@ export class ChatLog extends Container {
   }
 
   finalizeAssistant(text: string, runId?: string) {
+    this.lastAssistantText = text;
     const effectiveRunId = this.resolveRunId(runId);
     const existing = this.streamingRuns.get(effectiveRunId);
     if (existing) {
@@ -56,6 +59,11 @@ export class ChatLog extends Container {
     this.addChild(new AssistantMessageComponent(text));
   }
 
+  /** Text of the most recent finished assistant reply, as shown (used by /copy). */
+  getLastAssistantText(): string | null {
+    return this.lastAssistantText;
+  }
+
   dropAssistant(runId?: string) {
     const effectiveRunId = this.resolveRunId(runId);
     const existing = this.streamingRuns.get(effectiveRunId);
@@ -66,6 +74,20 @@ export class ChatLog extends Container {
     this.streamingRuns.delete(effectiveRunId);
   }
 
+  /** Shows a tool call while the model is still writing its arguments. */
+  previewToolArgs(toolCallId: string, toolName: string, args: unknown) {
+    const existing = this.toolById.get(toolCallId);
+    if (existing) {
+      existing.setArgs(args, { composing: true });
+      return existing;
+    }
+    const component = new ToolExecutionComponent(toolName, args, { composing: true });
+    component.setExpanded(this.toolsExpanded);
+    this.toolById.set(toolCallId, component);
+    this.addChild(component);
+    return component;
+  }
+
   startTool(toolCallId: string, toolName: string, args: unknown) {
     const existing = this.toolById.get(toolCallId);
     if (existing) {
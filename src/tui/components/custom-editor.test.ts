@@ -0,0 +1,63 @@
+import { describe, expect, it, vi } from "vitest";
+import { editorTheme } from "../theme/theme.js";
+import { CustomEditor, isUnbracketedPaste } from "./custom-editor.js";
+
+const createEditor = () => new CustomEditor({ requestRender: vi.fn() } as never, editorTheme);
+
+const type = (editor: CustomEditor, keys: string) => {
+  for (const key of keys) {
+    editor.handleInput(key);
+  }
+};
+
+describe("isUnbracketedPaste", () => {
+  it("detects multi-line chunks that arrive without paste markers", () => {
+    expect(isUnbracketedPaste("Error: boom\r    at main (a.ts:1)\r")).toBe(true);
+    expect(isUnbracketedPaste("line one\nline two")).toBe(true);
+  });
+
+  it("leaves keys, typed text, and bracketed pastes alone", () => {
+    expect(isUnbracketedPaste("\r")).toBe(false);
+    expect(isUnbracketedPaste("hello\r")).toBe(false);
+    expect(isUnbracketedPaste("\x1b[13;2u")).toBe(false);
+    expect(isUnbracketedPaste("\x1b[200~a\nb\x1b[201~")).toBe(false);
+  });
+});
+
+describe("CustomEditor vi mode", () => {
+  it("switches modes with Esc and edits from normal mode", () => {
+    const editor = createEditor();
+    const modes: string[] = [];
+    editor.editMode = "vi";
+    editor.onViModeChange = (mode) => modes.push(mode);
+    const onEscape = vi.fn();
+    editor.onEscape = onEscape;
+
+    type(editor, "hello");
+    editor.handleInput("\x1b");
+    expect(editor.getViMode()).toBe("normal");
+    type(editor, "0xA!");
+    expect(editor.getText()).toBe("ello!");
+    expect(editor.getViMode()).toBe("insert");
+
+    editor.handleInput("\x1b");
+    type(editor, "zdd");
+    expect(editor.getText()).toBe("");
+    editor.handleInput("\x1b");
+    expect(onEscape).toHaveBeenCalledTimes(1);
+    expect(modes).toEqual(["normal", "insert", "normal"]);
+  });
+
+  it("runs configured key bindings before built-in keys", () => {
+    const editor = createEditor();
+    const compact = vi.fn();
+    const onCtrlG = vi.fn();
+    editor.onCtrlG = onCtrlG;
+    editor.setKeyBindings({ "ctrl+g": compact });
+
+    editor.handleInput("\x07");
+
+    expect(compact).toHaveBeenCalledTimes(1);
+    expect(onCtrlG).not.toHaveBeenCalled();
+  });
+});
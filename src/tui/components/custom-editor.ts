@@ -1,4 +1,21 @@
-import { Editor, Key, matchesKey } from "@mariozechner/pi-tui";
+import { Editor, Key, type KeyId, matchesKey } from "@mariozechner/pi-tui";
+import { type ViMode, ViModeHandler } from "./vi-mode.js";
+
+export type { ViMode } from "./vi-mode.js";
+
+const PASTE_START = "\x1b[200~";
+const PASTE_END = "\x1b[201~";
+
+export type EditMode = "emacs" | "vi";
+
+export function isUnbracketedPaste(data: string): boolean {
+  return (
+    data.length > 1 &&
+    !data.startsWith("\x1b") &&
+    !data.includes(PASTE_START) &&
+    /[\r\n]./.test(data)
+  );
+}
 
 export class CustomEditor extends Editor {
   onEscape?: () => void;
@@ -8,11 +25,43 @@ export class CustomEditor extends Editor {
   onCtrlL?: () => void;
   onCtrlO?: () => void;
   onCtrlP?: () => void;
+  onCtrlR?: () => void;
   onCtrlT?: () => void;
   onShiftTab?: () => void;
   onAltEnter?: () => void;
+  onViModeChange?: (mode: ViMode) => void;
+  editMode: EditMode = "emacs";
+  private keyBindings: Array<{ key: string; run: () => void }> = [];
+  private readonly vi = new ViModeHandler({
+    send: (data) => super.handleInput(data),
+    isShowingAutocomplete: () => this.isShowingAutocomplete(),
+    onModeChange: (mode) => this.onViModeChange?.(mode),
+  });
+
+  /** User key bindings (pi-tui key ids such as `ctrl+r`); checked before the built-in keys. */
+  setKeyBindings(bindings: Record<string, () => void>): void {
+    this.keyBindings = Object.entries(bindings).map(([key, run]) => ({ key, run }));
+  }
+
+  getViMode(): ViMode {
+    return this.vi.getMode();
+  }
 
   handleInput(data: string): void {
+    // Terminals without bracketed paste deliver a paste as one chunk with raw newlines; route it
+    // through the editor's paste path so each line does not submit on its own.
+    if (isUnbracketedPaste(data)) {
+      super.handleInput(`${PASTE_START}${data}${PASTE_END}`);
+      return;
+    }
+    const binding = this.keyBindings.find((entry) => matchesKey(data, entry.key as KeyId));
+    if (binding) {
+      binding.run();
+      return;
+    }
+    if (this.editMode === "vi" && this.vi.handleInput(data)) {
+      return;
+    }
     if (matchesKey(data, Key.alt("enter")) && this.onAltEnter) {
       this.onAltEnter();
       return;
@@ -29,6 +78,10 @@ export class CustomEditor extends Editor {
       this.onCtrlP();
       return;
     }
+    if (matchesKey(data, Key.ctrl("r")) && this.onCtrlR) {
+      this.onCtrlR();
+      return;
+    }
     if (matchesKey(data, Key.ctrl("g")) && this.onCtrlG) {
       this.onCtrlG();
       return;
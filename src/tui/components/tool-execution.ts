@@ -16,6 +16,10 @@ type ToolResult = {
 };
 
 const PREVIEW_LINES = 12;
+// Quick commands finish before this; longer runs get a live (dimmed) output tail.
+const LIVE_OUTPUT_DELAY_MS = 2_000;
+// Arguments still being written show their latest characters.
+const COMPOSING_ARGS_CHARS = 240;
 
 function formatArgs(toolName: string, args: unknown): string {
   const display = resolveToolDisplay({ name: toolName, args });
@@ -56,33 +60,40 @@ export class ToolExecutionComponent extends Container {
   private header: Text;
   private argsLine: Text;
   private output: Markdown;
+  private liveOutput: Text;
+  private startedAt = Date.now();
   private toolName: string;
   private args: unknown;
   private result?: ToolResult;
   private expanded = false;
   private isError = false;
   private isPartial = true;
+  private composing = false;
 
-  constructor(toolName: string, args: unknown) {
+  constructor(toolName: string, args: unknown, opts?: { composing?: boolean }) {
     super();
     this.toolName = toolName;
     this.args = args;
+    this.composing = Boolean(opts?.composing);
     this.box = new Box(1, 1, (line) => theme.toolPendingBg(line));
     this.header = new Text("", 0, 0);
     this.argsLine = new Text("", 0, 0);
     this.output = new Markdown("", 0, 0, markdownTheme, {
       color: (line) => theme.toolOutput(line),
     });
+    this.liveOutput = new Text("", 0, 0);
     this.addChild(new Spacer(1));
     this.addChild(this.box);
     this.box.addChild(this.header);
     this.box.addChild(this.argsLine);
     this.box.addChild(this.output);
+    this.box.addChild(this.liveOutput);
     this.refresh();
   }
 
-  setArgs(args: unknown) {
+  setArgs(args: unknown, opts?: { composing?: boolean }) {
     this.args = args;
+    this.composing = Boolean(opts?.composing);
     this.refresh();
   }
 
@@ -116,14 +127,27 @@ export class ToolExecutionComponent extends Container {
       name: this.toolName,
       args: this.args,
     });
-    const title = `${display.emoji} ${display.label}${this.isPartial ? " (running)" : ""}`;
+    const status = this.composing ? " (composing)" : this.isPartial ? " (running)" : "";
+    const title = `${display.emoji} ${display.label}${status}`;
     this.header.setText(theme.toolTitle(theme.bold(title)));
 
-    const argLine = formatArgs(this.toolName, this.args);
+    let argLine = formatArgs(this.toolName, this.args);
+    if (this.composing && argLine.length > COMPOSING_ARGS_CHARS) {
+      argLine = `…${argLine.slice(-COMPOSING_ARGS_CHARS)}`;
+    }
     this.argsLine.setText(argLine ? theme.dim(argLine) : theme.dim(" "));
 
     const raw = extractText(this.result);
-    const text = raw || (this.isPartial ? "…" : "");
+    if (this.isPartial) {
+      const live = Date.now() - this.startedAt >= LIVE_OUTPUT_DELAY_MS ? raw : "";
+      const lines = live ? live.split("\n") : [];
+      const tailLines = this.expanded ? lines : lines.slice(-PREVIEW_LINES);
+      this.output.setText(live ? "" : "…");
+      this.liveOutput.setText(tailLines.length > 0 ? theme.dim(tailLines.join("\n")) : "");
+      return;
+    }
+    this.liveOutput.setText("");
+    const text = raw;
     if (!this.expanded && text) {
       const lines = text.split("\n");
       const preview =
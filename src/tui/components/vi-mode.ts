@@ -0,0 +1,122 @@
+import { Key, matchesKey } from "@mariozechner/pi-tui";
+
+export type ViMode = "insert" | "normal";
+
+// Vi normal-mode keys are replayed as the editor's own (emacs-style) key sequences.
+const LEFT = "\x1b[D";
+const RIGHT = "\x1b[C";
+const UP = "\x1b[A";
+const DOWN = "\x1b[B";
+const DELETE = "\x1b[3~";
+const LINE_START = "\x01";
+const LINE_END = "\x05";
+const KILL_TO_END = "\x0b";
+const WORD_LEFT = "\x1b[1;5D";
+const WORD_RIGHT = "\x1b[1;5C";
+
+const VI_MOTIONS: Record<string, string[]> = {
+  h: [LEFT],
+  l: [RIGHT],
+  j: [DOWN],
+  k: [UP],
+  w: [WORD_RIGHT],
+  b: [WORD_LEFT],
+  "0": [LINE_START],
+  $: [LINE_END],
+  x: [DELETE],
+  D: [KILL_TO_END],
+};
+
+/** Normal-mode keys that switch to insert mode, with the keys to replay first. */
+const VI_INSERTS: Record<string, string[]> = {
+  i: [],
+  a: [RIGHT],
+  A: [LINE_END],
+  I: [LINE_START],
+  C: [KILL_TO_END],
+  S: [LINE_START, KILL_TO_END],
+};
+
+export type ViEditorHost = {
+  /** Feeds keys straight to the editor, skipping vi handling. */
+  send: (data: string) => void;
+  isShowingAutocomplete: () => boolean;
+  onModeChange?: (mode: ViMode) => void;
+};
+
+/** Vi edit mode for the input editor: tracks insert/normal mode and translates normal-mode keys. */
+export class ViModeHandler {
+  private mode: ViMode = "insert";
+  private pending: string | null = null;
+
+  constructor(private readonly host: ViEditorHost) {}
+
+  getMode(): ViMode {
+    return this.mode;
+  }
+
+  private setMode(mode: ViMode): void {
+    this.pending = null;
+    if (this.mode !== mode) {
+      this.mode = mode;
+      this.host.onModeChange?.(mode);
+    }
+  }
+
+  private replay(keys: string[]): void {
+    for (const key of keys) {
+      this.host.send(key);
+    }
+  }
+
+  /**
+   * Returns true when the key was consumed. Esc leaves insert mode (a second Esc is not consumed).
+   * Normal mode covers motions, `x`, `D`, `dd`/`cc`, the insert commands, and Enter to send;
+   * control keys pass through.
+   */
+  handleInput(data: string): boolean {
+    if (this.mode === "insert") {
+      if (matchesKey(data, Key.escape) && !this.host.isShowingAutocomplete()) {
+        this.setMode("normal");
+        return true;
+      }
+      return false;
+    }
+    if (matchesKey(data, Key.enter)) {
+      this.setMode("insert");
+      this.host.send(data);
+      return true;
+    }
+    if (data.length !== 1 || data < " ") {
+      this.pending = null;
+      return false;
+    }
+    if (this.pending) {
+      const operator = this.pending;
+      this.pending = null;
+      if (data === operator) {
+        this.replay([LINE_START, KILL_TO_END]);
+        if (operator === "c") {
+          this.setMode("insert");
+        }
+      }
+      return true;
+    }
+    if (data === "d" || data === "c") {
+      this.pending = data;
+      return true;
+    }
+    const motion = VI_MOTIONS[data];
+    if (motion) {
+      this.replay(motion);
+      return true;
+    }
+    const insert = VI_INSERTS[data];
+    if (insert) {
+      this.replay(insert);
+      this.setMode("insert");
+    }
+    // Other printable keys are ignored in normal mode rather than typed.
+    return true;
+  }
+}
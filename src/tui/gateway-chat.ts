@@ -70,6 +70,8 @@ export type GatewaySessionList = {
     lastAccountId?: string;
     derivedTitle?: string;
     lastMessagePreview?: string;
+    costUsd?: number;
+    disabledTools?: string[];
   }>;
 };
 
@@ -45,4 +45,52 @@ describe("tui command handlers", () => {
     );
     expect(requestRender).toHaveBeenCalled();
   });
+
+  it("creates a labeled session with /session new and switches to it", async () => {
+    const addSystem = vi.fn();
+    const setSession = vi.fn();
+    const listSessions = vi.fn().mockResolvedValue({
+      sessions: [{ key: "agent:main:main" }, { key: "agent:main:bug-42" }],
+    });
+    const patchSession = vi.fn().mockResolvedValue({});
+
+    const { handleCommand } = createCommandHandlers({
+      client: { listSessions, patchSession } as never,
+      chatLog: { addUser: vi.fn(), addSystem } as never,
+      tui: { requestRender: vi.fn() } as never,
+      opts: {},
+      state: {
+        currentSessionKey: "agent:main:main",
+        currentAgentId: "main",
+        activeChatRunId: "run-1",
+        sessionInfo: {},
+      } as never,
+      deliverDefault: false,
+      openOverlay: vi.fn(),
+      closeOverlay: vi.fn(),
+      refreshSessionInfo: vi.fn(),
+      loadHistory: vi.fn(),
+      setSession,
+      refreshAgents: vi.fn(),
+      abortActive: vi.fn(),
+      setActivityStatus: vi.fn(),
+      formatSessionKey: (key: string) => key.replace(/^agent:main:/, ""),
+      applySessionInfoFromPatch: vi.fn(),
+      noteLocalRunId: vi.fn(),
+    });
+
+    await handleCommand("/session new Release notes");
+    expect(patchSession).toHaveBeenCalledWith({
+      key: "agent:main:release-notes",
+      label: "Release notes",
+    });
+    expect(setSession).toHaveBeenCalledWith("agent:main:release-notes");
+    expect(addSystem).toHaveBeenCalledWith("run in main continues in the background");
+
+    await handleCommand("/session new bug-42");
+    expect(addSystem).toHaveBeenLastCalledWith(
+      "session bug-42 already exists; use /session switch bug-42",
+    );
+    expect(patchSession).toHaveBeenCalledTimes(1);
+  });
 });
@@ -22,6 +22,9 @@ import {
   createSearchableSelectList,
   createSettingsList,
 } from "./components/selectors.js";
+import { type CustomCommand, createCustomCommandRunner } from "./tui-custom-commands.js";
+import { createLocalCommandHandlers } from "./tui-local-commands.js";
+import { createSessionCommandHandler } from "./tui-session-command.js";
 import { formatStatusSummary } from "./tui-status-summary.js";
 
 type CommandHandlerContext = {
@@ -43,6 +46,11 @@ type CommandHandlerContext = {
   applySessionInfoFromPatch: (result: SessionsPatchResult) => void;
   noteLocalRunId: (runId: string) => void;
   forgetLocalRunId?: (runId: string) => void;
+  /** Drains `!!cmd` output queued for the next message. */
+  takeSharedContext?: () => string[];
+  getCustomCommands?: () => CustomCommand[];
+  /** Local path of the current agent's MEMORY.md; null when the workspace is not on this host. */
+  resolveMemoryFilePath?: () => Promise<string | null>;
 };
 
 export function createCommandHandlers(context: CommandHandlerContext) {
@@ -67,6 +75,22 @@ export function createCommandHandlers(context: CommandHandlerContext) {
     forgetLocalRunId,
   } = context;
 
+  const localCommands = createLocalCommandHandlers({
+    chatLog,
+    tui,
+    state,
+    forward: (raw) => sendMessage(raw),
+    takeSharedContext: context.takeSharedContext,
+    resolveMemoryFilePath: context.resolveMemoryFilePath,
+  });
+  const runCustomCommand = createCustomCommandRunner({
+    client,
+    chatLog,
+    state,
+    applySessionInfoFromPatch,
+    sendMessage: (text, options) => sendMessage(text, options),
+  });
+
   const setAgent = async (id: string) => {
     state.currentAgentId = normalizeAgentId(id);
     await setSession("");
@@ -237,6 +261,15 @@ export function createCommandHandlers(context: CommandHandlerContext) {
     tui.requestRender();
   };
 
+  const handleSessionCommand = createSessionCommandHandler({
+    client,
+    chatLog,
+    state,
+    setSession,
+    openSessionSelector,
+    formatSessionKey,
+  });
+
   const handleCommand = async (raw: string) => {
     const { name, args } = parseCommand(raw);
     if (!name) {
@@ -248,6 +281,7 @@ export function createCommandHandlers(context: CommandHandlerContext) {
           helpText({
             provider: state.sessionInfo.modelProvider,
             model: state.sessionInfo.model,
+            customCommands: context.getCustomCommands?.(),
           }),
         );
         break;
@@ -281,11 +315,7 @@ export function createCommandHandlers(context: CommandHandlerContext) {
         await openAgentSelector();
         break;
       case "session":
-        if (!args) {
-          await openSessionSelector();
-        } else {
-          await setSession(args);
-        }
+        await handleSessionCommand(args);
         break;
       case "sessions":
         await openSessionSelector();
@@ -446,8 +476,25 @@ export function createCommandHandlers(context: CommandHandlerContext) {
       case "abort":
         await abortActive();
         break;
+      case "copy":
+        await localCommands.copy(args);
+        break;
+      case "multiline":
+        localCommands.multiline(args);
+        break;
+      case "memory":
+        await localCommands.memory(raw, args);
+        break;
+      case "diff-context":
+        await localCommands.diffContext(args);
+        break;
       case "settings":
-        openSettings();
+        // Bare /settings shows what the gateway stored on the session; display toggles stay local.
+        if (args.trim().toLowerCase() === "display") {
+          openSettings();
+        } else {
+          await sendMessage(raw);
+        }
         break;
       case "exit":
       case "quit":
@@ -455,16 +502,26 @@ export function createCommandHandlers(context: CommandHandlerContext) {
         tui.stop();
         process.exit(0);
         break;
-      default:
+      default: {
+        const custom = context.getCustomCommands?.().find((command) => command.name === name);
+        if (custom) {
+          await runCustomCommand(custom, raw, args);
+          break;
+        }
         await sendMessage(raw);
         break;
+      }
     }
     tui.requestRender();
   };
 
-  const sendMessage = async (text: string) => {
+  const sendMessage = async (
+    text: string,
+    options: { display?: string; thinking?: string } = {},
+  ) => {
     try {
-      chatLog.addUser(text);
+      chatLog.addUser(options.display ?? text);
+      const message = text.startsWith("/") ? text : await localCommands.attachLocalContext(text);
       tui.requestRender();
       const runId = randomUUID();
       noteLocalRunId(runId);
@@ -472,8 +529,8 @@ export function createCommandHandlers(context: CommandHandlerContext) {
       setActivityStatus("sending");
       await client.sendChat({
         sessionKey: state.currentSessionKey,
-        message: text,
-        thinking: opts.thinking,
+        message,
+        thinking: options.thinking ?? opts.thinking,
         deliver: deliverDefault,
         timeoutMs: opts.timeoutMs,
         runId,
@@ -0,0 +1,54 @@
+import type { AutocompleteItem } from "@mariozechner/pi-tui";
+import type { OpenClawConfig } from "../config/types.js";
+import type { GatewayChatClient } from "./gateway-chat.js";
+import type { AgentSummary } from "./tui-types.js";
+import { buildModelCompletionItems, type SlashArgumentSources } from "./commands.js";
+
+/**
+ * Argument completions for `/model`, `/session`, and `/agent`. Models and sessions come from the
+ * gateway and are refreshed on connect; until then, models are the configured aliases.
+ */
+export function createCompletionSources(params: {
+  config: OpenClawConfig;
+  client: GatewayChatClient;
+  getAgents: () => AgentSummary[];
+  getAgentId: () => string;
+}) {
+  let models: AutocompleteItem[] = buildModelCompletionItems(params.config, []);
+  let sessions: AutocompleteItem[] = [];
+
+  const sources: SlashArgumentSources = {
+    models: () => models,
+    sessions: () => sessions,
+    agents: () =>
+      params.getAgents().map((agent) => ({
+        value: agent.id,
+        label: agent.id,
+        description: agent.name && agent.name !== agent.id ? agent.name : undefined,
+      })),
+  };
+
+  const refresh = async () => {
+    try {
+      models = buildModelCompletionItems(params.config, await params.client.listModels());
+    } catch {
+      // Keep configured aliases only.
+    }
+    try {
+      const result = await params.client.listSessions({
+        includeGlobal: false,
+        includeUnknown: false,
+        includeDerivedTitles: true,
+        agentId: params.getAgentId(),
+      });
+      sessions = result.sessions.map((session) => {
+        const title = session.derivedTitle ?? session.displayName;
+        return { value: session.key, label: session.key, description: title || undefined };
+      });
+    } catch {
+      // Session completion is best-effort.
+    }
+  };
+
+  return { sources, refresh };
+}
@@ -0,0 +1,46 @@
+import { describe, expect, it, vi } from "vitest";
+import { copyToTerminalClipboard, extractLastCodeBlock, parseCopyArgs } from "./tui-copy.js";
+
+describe("tui /copy", () => {
+  it("parses arguments", () => {
+    expect(parseCopyArgs("")).toBe("response");
+    expect(parseCopyArgs(" CODE ")).toBe("code");
+    expect(parseCopyArgs("all")).toBeNull();
+  });
+
+  it("extracts the last fenced code block", () => {
+    const text = [
+      "First:",
+      "```ts",
+      "const a = 1;",
+      "```",
+      "Then:",
+      "~~~bash",
+      "echo one",
+      "echo two",
+      "~~~",
+    ].join("\n");
+    expect(extractLastCodeBlock(text)).toBe("echo one\necho two");
+    expect(extractLastCodeBlock("no code here")).toBeNull();
+    expect(extractLastCodeBlock("````md\n```js\nx\n```\n````")).toBe("```js\nx\n```");
+  });
+
+  it("uses the system clipboard locally and OSC 52 over SSH or as a fallback", async () => {
+    const write = vi.fn();
+    const copySystem = vi.fn().mockResolvedValue(true);
+    await expect(copyToTerminalClipboard("hi", { env: {}, copySystem, write })).resolves.toBe(
+      "system",
+    );
+    expect(write).not.toHaveBeenCalled();
+
+    await expect(
+      copyToTerminalClipboard("hi", { env: { SSH_TTY: "/dev/pts/1" }, copySystem, write }),
+    ).resolves.toBe("osc52");
+    expect(copySystem).toHaveBeenCalledTimes(1);
+    expect(write).toHaveBeenLastCalledWith("\x1b]52;c;aGk=\x07");
+
+    copySystem.mockResolvedValue(false);
+    await copyToTerminalClipboard("hi", { env: { TMUX: "/tmp/tmux" }, copySystem, write });
+    expect(write).toHaveBeenLastCalledWith("\x1bPtmux;\x1b\x1b]52;c;aGk=\x07\x1b\\");
+  });
+});
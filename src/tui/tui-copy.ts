@@ -0,0 +1,44 @@
+import { buildOsc52Sequence, copyToClipboard, isRemoteTerminal } from "../infra/clipboard.js";
+
+export type CopyTarget = "response" | "code";
+
+export type ClipboardMethod = "system" | "osc52";
+
+export function parseCopyArgs(args: string): CopyTarget | null {
+  const normalized = args.trim().toLowerCase();
+  if (!normalized) {
+    return "response";
+  }
+  return normalized === "code" ? "code" : null;
+}
+
+/** Body of the last fenced (``` or ~~~) code block in `text`, without the fences. */
+export function extractLastCodeBlock(text: string): string | null {
+  const fence = /^[ \t]*(`{3,}|~{3,})[^\n]*\n([\s\S]*?)^[ \t]*\1[ \t]*$/gm;
+  let last: string | null = null;
+  for (const match of text.matchAll(fence)) {
+    last = match[2].replace(/\n$/, "");
+  }
+  return last;
+}
+
+/**
+ * Over SSH the system clipboard belongs to the remote host, so go straight to OSC 52. Locally,
+ * use the platform clipboard tool and fall back to OSC 52 when none is available.
+ */
+export async function copyToTerminalClipboard(
+  text: string,
+  deps: {
+    env?: NodeJS.ProcessEnv;
+    copySystem?: (value: string) => Promise<boolean>;
+    write?: (data: string) => void;
+  } = {},
+): Promise<ClipboardMethod> {
+  const env = deps.env ?? process.env;
+  if (!isRemoteTerminal(env) && (await (deps.copySystem ?? copyToClipboard)(text))) {
+    return "system";
+  }
+  const write = deps.write ?? ((data: string) => process.stdout.write(data));
+  write(buildOsc52Sequence(text, env));
+  return "osc52";
+}
@@ -0,0 +1,77 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import { getSlashCommands } from "./commands.js";
+import { expandCustomCommand, loadCustomCommands } from "./tui-custom-commands.js";
+
+describe("tui custom commands", () => {
+  let root: string;
+  let userDir: string;
+  let projectDir: string;
+
+  beforeEach(() => {
+    root = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-tui-commands-"));
+    userDir = path.join(root, "user");
+    projectDir = path.join(root, "project");
+    fs.mkdirSync(userDir);
+    fs.mkdirSync(projectDir);
+  });
+
+  afterEach(() => {
+    fs.rmSync(root, { recursive: true, force: true });
+  });
+
+  it("loads templates with front-matter; project commands override user ones", async () => {
+    fs.writeFileSync(path.join(userDir, "review.md"), "Review the staged diff.\n");
+    fs.writeFileSync(path.join(userDir, "fix-issue.md"), "Fix it.\n");
+    fs.writeFileSync(
+      path.join(projectDir, "Fix-Issue.md"),
+      [
+        "---",
+        "description: Fix a GitHub issue",
+        "model: anthropic/claude-opus-4-5",
+        "thinking: high",
+        "---",
+        "Fix issue #$1 following CONTRIBUTING.md.",
+        "",
+      ].join("\n"),
+    );
+    fs.writeFileSync(path.join(projectDir, "empty.md"), "---\ndescription: nothing\n---\n");
+    fs.writeFileSync(path.join(projectDir, "notes.txt"), "not a command\n");
+
+    const commands = await loadCustomCommands([userDir, projectDir, path.join(root, "missing")]);
+
+    expect(commands.map((command) => command.name)).toEqual(["fix-issue", "review"]);
+    expect(commands[0]).toMatchObject({
+      description: "Fix a GitHub issue",
+      model: "anthropic/claude-opus-4-5",
+      thinking: "high",
+      template: "Fix issue #$1 following CONTRIBUTING.md.",
+      path: path.join(projectDir, "Fix-Issue.md"),
+    });
+  });
+
+  it("substitutes $ARGUMENTS and positional arguments", () => {
+    expect(expandCustomCommand("Fix #$1 in $2.", '123 "src/main.rs"')).toBe(
+      "Fix #123 in src/main.rs.",
+    );
+    expect(expandCustomCommand("Explain: $ARGUMENTS ($3)", " why is  it slow ")).toBe(
+      "Explain: why is  it slow (it)",
+    );
+    expect(expandCustomCommand("Missing: [$2]", "one")).toBe("Missing: []");
+    expect(expandCustomCommand("Review the diff.", "focus on errors")).toBe(
+      "Review the diff.\n\nfocus on errors",
+    );
+  });
+
+  it("adds custom commands to completion without shadowing built-ins", () => {
+    const commands = getSlashCommands({
+      customCommands: [{ name: "fix-issue", description: "Fix a GitHub issue" }, { name: "help" }],
+    });
+    expect(commands.filter((command) => command.name === "help")).toHaveLength(1);
+    expect(commands.find((command) => command.name === "fix-issue")?.description).toBe(
+      "Fix a GitHub issue",
+    );
+  });
+});
@@ -0,0 +1,153 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { SessionsPatchResult } from "../gateway/protocol/index.js";
+import type { ChatLog } from "./components/chat-log.js";
+import type { GatewayChatClient } from "./gateway-chat.js";
+import type { TuiStateAccess } from "./tui-types.js";
+import { expandPromptTemplateVars } from "../agents/prompt-template-vars.js";
+import { resolveStateDir } from "../config/paths.js";
+import { parseFrontmatterBlock, stripFrontmatterBlock } from "../markdown/frontmatter.js";
+
+/** A prompt template from `<dir>/commands/<name>.md`, run as `/<name> [args]`. */
+export type CustomCommand = {
+  name: string;
+  description?: string;
+  /** `provider/model` or alias to switch the session to before sending. */
+  model?: string;
+  /** Thinking level for this message only. */
+  thinking?: string;
+  template: string;
+  path: string;
+};
+
+const COMMAND_NAME_RE = /^[a-z0-9][a-z0-9_-]{0,63}$/;
+
+/** User commands first, then project commands, which win on name clashes. */
+export function resolveCustomCommandDirs(
+  cwd: string = process.cwd(),
+  env: NodeJS.ProcessEnv = process.env,
+): string[] {
+  return [path.join(resolveStateDir(env), "commands"), path.join(cwd, ".openclaw", "commands")];
+}
+
+export function parseCustomCommandFile(
+  name: string,
+  content: string,
+  filePath: string,
+): CustomCommand {
+  const frontmatter = parseFrontmatterBlock(content);
+  return {
+    name,
+    description: frontmatter.description?.trim() || undefined,
+    model: frontmatter.model?.trim() || undefined,
+    thinking: frontmatter.thinking?.trim() || undefined,
+    template: stripFrontmatterBlock(content).trim(),
+    path: filePath,
+  };
+}
+
+async function listCommandsInDir(dir: string): Promise<CustomCommand[]> {
+  let entries: string[];
+  try {
+    entries = await fs.readdir(dir);
+  } catch {
+    return [];
+  }
+  const commands: CustomCommand[] = [];
+  for (const entry of entries.toSorted()) {
+    const name = entry.toLowerCase().replace(/\.md$/, "");
+    if (!entry.endsWith(".md") || !COMMAND_NAME_RE.test(name)) {
+      continue;
+    }
+    const filePath = path.join(dir, entry);
+    try {
+      const command = parseCustomCommandFile(name, await fs.readFile(filePath, "utf8"), filePath);
+      if (command.template) {
+        commands.push(command);
+      }
+    } catch {
+      // Unreadable files are skipped; the rest still load.
+    }
+  }
+  return commands;
+}
+
+export async function loadCustomCommands(
+  dirs: string[] = resolveCustomCommandDirs(),
+): Promise<CustomCommand[]> {
+  const byName = new Map<string, CustomCommand>();
+  for (const dir of dirs) {
+    for (const command of await listCommandsInDir(dir)) {
+      byName.set(command.name, command);
+    }
+  }
+  return [...byName.values()].toSorted((a, b) => a.name.localeCompare(b.name));
+}
+
+/** Whitespace-separated arguments; single or double quotes keep spaces together. */
+export function splitCommandArgs(args: string): string[] {
+  return Array.from(
+    args.matchAll(/"([^"]*)"|'([^']*)'|(\S+)/g),
+    (match) => match[1] ?? match[2] ?? match[3],
+  );
+}
+
+/**
+ * Fills `$ARGUMENTS` with the whole argument string and `$1..$n` with positional arguments
+ * (missing ones become empty). Templates without placeholders get the arguments appended, so
+ * nothing typed is silently dropped.
+ */
+export function expandCustomCommand(template: string, args: string): string {
+  const trimmed = args.trim();
+  const positional = splitCommandArgs(trimmed);
+  let used = false;
+  const expanded = template.replace(/\$(ARGUMENTS\b|\d+)/g, (_match, key: string) => {
+    used = true;
+    return key === "ARGUMENTS" ? trimmed : (positional[Number(key) - 1] ?? "");
+  });
+  return used || !trimmed ? expanded : `${expanded}\n\n${trimmed}`;
+}
+
+/**
+ * Runs a custom command: switches the session to the command's model first when it names one,
+ * then sends the expanded template (shown in the log as the typed command).
+ */
+export function createCustomCommandRunner(context: {
+  client: GatewayChatClient;
+  chatLog: ChatLog;
+  state: TuiStateAccess;
+  applySessionInfoFromPatch: (result: SessionsPatchResult) => void;
+  sendMessage: (text: string, options: { display?: string; thinking?: string }) => Promise<void>;
+}) {
+  const { client, chatLog, state } = context;
+  const currentModelRef = () => {
+    const { model, modelProvider } = state.sessionInfo;
+    return model ? (modelProvider ? `${modelProvider}/${model}` : model) : undefined;
+  };
+
+  return async (command: CustomCommand, raw: string, args: string) => {
+    const current = [state.sessionInfo.model, currentModelRef()];
+    if (command.model && !current.includes(command.model)) {
+      try {
+        const result = await client.patchSession({
+          key: state.currentSessionKey,
+          model: command.model,
+        });
+        chatLog.addSystem(`model set to ${command.model} (/${command.name})`);
+        context.applySessionInfoFromPatch(result);
+      } catch (err) {
+        chatLog.addSystem(`model set failed: ${String(err)}`);
+        return;
+      }
+    }
+    // Read the model again: the patch above may have just switched it.
+    const template = expandPromptTemplateVars(command.template, {
+      cwd: process.cwd(),
+      model: currentModelRef(),
+    });
+    await context.sendMessage(expandCustomCommand(template, args), {
+      display: raw,
+      thinking: command.thinking,
+    });
+  };
+}
@@ -6,6 +6,7 @@ import { createEventHandlers } from "./tui-event-handlers.js";
 
 type MockChatLog = Pick<
   ChatLog,
+  | "previewToolArgs"
   | "startTool"
   | "updateToolResult"
   | "addSystem"
@@ -32,6 +33,7 @@ describe("tui-event-handlers: handleAgentEvent", () => {
     autoMessageSent: false,
     toolsExpanded: false,
     showThinking: false,
+    multilineMode: false,
     connectionStatus: "connected",
     activityStatus: "idle",
     statusTimeout: null,
@@ -41,6 +43,7 @@ describe("tui-event-handlers: handleAgentEvent", () => {
 
   const makeContext = (state: TuiStateAccess) => {
     const chatLog: MockChatLog = {
+      previewToolArgs: vi.fn(),
       startTool: vi.fn(),
       updateToolResult: vi.fn(),
       addSystem: vi.fn(),
@@ -103,6 +106,27 @@ describe("tui-event-handlers: handleAgentEvent", () => {
     expect(tui.requestRender).toHaveBeenCalledTimes(1);
   });
 
+  it("previews tool args while the tool call is still streaming", () => {
+    const state = makeState({ activeChatRunId: "run-1" });
+    const { chatLog, tui, setActivityStatus } = makeContext(state);
+    const { handleAgentEvent } = createEventHandlers({
+      chatLog,
+      tui,
+      state,
+      setActivityStatus,
+    });
+
+    handleAgentEvent({
+      runId: "run-1",
+      stream: "tool",
+      data: { phase: "args", toolCallId: "tc1", name: "exec", args: { command: "rm -" } },
+    });
+
+    expect(chatLog.previewToolArgs).toHaveBeenCalledWith("tc1", "exec", { command: "rm -" });
+    expect(chatLog.startTool).not.toHaveBeenCalled();
+    expect(tui.requestRender).toHaveBeenCalledTimes(1);
+  });
+
   it("ignores tool events when runId does not match activeChatRunId", () => {
     const state = makeState({ activeChatRunId: "run-1" });
     const { chatLog, tui, setActivityStatus } = makeContext(state);
@@ -340,6 +364,31 @@ describe("tui-event-handlers: handleAgentEvent", () => {
     );
   });
 
+  it("streams partial exec output when verbose is on (non-full)", () => {
+    const state = makeState({
+      activeChatRunId: "run-123",
+      sessionInfo: { verboseLevel: "on" },
+    });
+    const { chatLog, tui, setActivityStatus } = makeContext(state);
+    const { handleAgentEvent } = createEventHandlers({
+      chatLog,
+      tui,
+      state,
+      setActivityStatus,
+    });
+
+    const partialResult = { content: [{ type: "text", text: "running tests..." }] };
+    handleAgentEvent({
+      runId: "run-123",
+      stream: "tool",
+      data: { phase: "update", toolCallId: "tc-exec", name: "exec", partialResult },
+    });
+
+    expect(chatLog.updateToolResult).toHaveBeenCalledWith("tc-exec", partialResult, {
+      partial: true,
+    });
+  });
+
   it("refreshes history after a non-local chat final", () => {
     const state = makeState({ activeChatRunId: null });
     const { chatLog, tui, setActivityStatus, loadHistory, isLocalRunId, forgetLocalRunId } =
@@ -392,4 +441,61 @@ describe("tui-event-handlers: handleAgentEvent", () => {
     expect(chatLog.dropAssistant).toHaveBeenCalledWith("run-silent");
     expect(chatLog.finalizeAssistant).not.toHaveBeenCalled();
   });
+
+  it("keeps the partial reply when a run is aborted", () => {
+    const state = makeState({ activeChatRunId: "run-abort" });
+    const { chatLog, tui, setActivityStatus } = makeContext(state);
+    const { handleChatEvent } = createEventHandlers({
+      chatLog,
+      tui,
+      state,
+      setActivityStatus,
+    });
+
+    handleChatEvent({
+      runId: "run-abort",
+      sessionKey: state.currentSessionKey,
+      state: "delta",
+      message: { content: "partial answer" },
+    });
+    handleChatEvent({
+      runId: "run-abort",
+      sessionKey: state.currentSessionKey,
+      state: "aborted",
+    });
+
+    expect(chatLog.finalizeAssistant).toHaveBeenCalledWith("partial answer", "run-abort");
+    expect(chatLog.dropAssistant).not.toHaveBeenCalled();
+    expect(chatLog.addSystem).toHaveBeenCalledWith("run aborted");
+    expect(state.activeChatRunId).toBeNull();
+  });
+
+  it("renders only the final reply in no-stream mode", () => {
+    const state = makeState({ activeChatRunId: "run-buffered" });
+    const { chatLog, tui, setActivityStatus } = makeContext(state);
+    const { handleChatEvent } = createEventHandlers({
+      chatLog,
+      tui,
+      state,
+      setActivityStatus,
+      isBuffered: () => true,
+    });
+
+    handleChatEvent({
+      runId: "run-buffered",
+      sessionKey: state.currentSessionKey,
+      state: "delta",
+      message: { content: "partial" },
+    });
+    expect(chatLog.updateAssistant).not.toHaveBeenCalled();
+    expect(setActivityStatus).toHaveBeenCalledWith("buffering");
+
+    handleChatEvent({
+      runId: "run-buffered",
+      sessionKey: state.currentSessionKey,
+      state: "final",
+      message: { role: "assistant", content: [{ type: "text", text: "partial and the rest" }] },
+    });
+    expect(chatLog.finalizeAssistant).toHaveBeenCalledWith("partial and the rest", "run-buffered");
+  });
 });
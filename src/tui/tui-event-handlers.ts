@@ -4,6 +4,8 @@ import type { AgentEvent, ChatEvent, TuiStateAccess } from "./tui-types.js";
 import { asString, extractTextFromMessage, isCommandMessage } from "./tui-formatters.js";
 import { TuiStreamAssembler } from "./tui-stream-assembler.js";
 
+const LIVE_OUTPUT_TOOLS = new Set(["exec", "bash"]);
+
 type EventHandlerContext = {
   chatLog: ChatLog;
   tui: TUI;
@@ -14,6 +16,8 @@ type EventHandlerContext = {
   isLocalRunId?: (runId: string) => boolean;
   forgetLocalRunId?: (runId: string) => void;
   clearLocalRunIds?: () => void;
+  /** No-stream mode: collect deltas and render the reply once it is final. */
+  isBuffered?: () => boolean;
 };
 
 export function createEventHandlers(context: EventHandlerContext) {
@@ -27,6 +31,7 @@ export function createEventHandlers(context: EventHandlerContext) {
     isLocalRunId,
     forgetLocalRunId,
     clearLocalRunIds,
+    isBuffered,
   } = context;
   const finalizedRuns = new Map<string, number>();
   const sessionRuns = new Map<string, number>();
@@ -105,6 +110,10 @@ export function createEventHandlers(context: EventHandlerContext) {
       if (!displayText) {
         return;
       }
+      if (isBuffered?.()) {
+        setActivityStatus("buffering");
+        return;
+      }
       chatLog.updateAssistant(displayText, evt.runId);
       setActivityStatus("streaming");
     }
@@ -162,9 +171,15 @@ export function createEventHandlers(context: EventHandlerContext) {
       void refreshSessionInfo?.();
     }
     if (evt.state === "aborted") {
+      // Keep the reply streamed before the abort; late deltas for the run are ignored.
+      const partialText = streamAssembler.takePartial(evt.runId);
+      if (partialText.trim()) {
+        chatLog.finalizeAssistant(partialText, evt.runId);
+      } else {
+        chatLog.dropAssistant(evt.runId);
+      }
       chatLog.addSystem("run aborted");
-      streamAssembler.drop(evt.runId);
-      sessionRuns.delete(evt.runId);
+      noteFinalizedRun(evt.runId);
       state.activeChatRunId = null;
       setActivityStatus("aborted");
       void refreshSessionInfo?.();
@@ -218,10 +233,13 @@ export function createEventHandlers(context: EventHandlerContext) {
       if (!toolCallId) {
         return;
       }
-      if (phase === "start") {
+      if (phase === "args") {
+        chatLog.previewToolArgs(toolCallId, toolName, data.args);
+      } else if (phase === "start") {
         chatLog.startTool(toolCallId, toolName, data.args);
       } else if (phase === "update") {
-        if (!allowToolOutput) {
+        // Shell output streams live (dimmed tail) even when final tool output is hidden.
+        if (!allowToolOutput && !LIVE_OUTPUT_TOOLS.has(toolName)) {
           return;
         }
         chatLog.updateToolResult(toolCallId, data.partialResult, {
@@ -0,0 +1,64 @@
+import { EventEmitter } from "node:events";
+import { describe, expect, it, vi } from "vitest";
+import { openInExternalEditor, resolveExternalEditor } from "./tui-external-editor.js";
+
+describe("resolveExternalEditor", () => {
+  it("prefers VISUAL, then EDITOR, then the platform default", () => {
+    expect(resolveExternalEditor({ VISUAL: "code --wait", EDITOR: "nano" }, "linux")).toBe(
+      "code --wait",
+    );
+    expect(resolveExternalEditor({ EDITOR: " nano " }, "linux")).toBe("nano");
+    expect(resolveExternalEditor({}, "linux")).toBe("vi");
+    expect(resolveExternalEditor({}, "win32")).toBe("notepad");
+  });
+});
+
+describe("openInExternalEditor", () => {
+  it("suspends the TUI while the editor runs and quotes the path", async () => {
+    const calls: string[] = [];
+    const tui = {
+      stop: () => calls.push("stop"),
+      start: () => calls.push("start"),
+      requestRender: vi.fn(),
+    };
+    const spawnCommand = vi.fn((command: string) => {
+      calls.push(command);
+      const child = new EventEmitter();
+      queueMicrotask(() => child.emit("close", 0));
+      return child;
+    });
+
+    const result = await openInExternalEditor({
+      filePath: "/tmp/it's here/MEMORY.md",
+      tui,
+      env: { EDITOR: "nano" },
+      platform: "linux",
+      spawnCommand: spawnCommand as never,
+    });
+
+    expect(result).toEqual({ ok: true, editor: "nano" });
+    expect(calls).toEqual(["stop", "nano '/tmp/it'\\''s here/MEMORY.md'", "start"]);
+    expect(spawnCommand.mock.calls[0]?.[1]).toMatchObject({ shell: true, stdio: "inherit" });
+    expect(tui.requestRender).toHaveBeenCalled();
+  });
+
+  it("reports a non-zero exit and still restores the TUI", async () => {
+    const tui = { stop: vi.fn(), start: vi.fn(), requestRender: vi.fn() };
+    const spawnCommand = vi.fn(() => {
+      const child = new EventEmitter();
+      queueMicrotask(() => child.emit("close", 1));
+      return child;
+    });
+
+    const result = await openInExternalEditor({
+      filePath: "/tmp/MEMORY.md",
+      tui,
+      env: {},
+      platform: "linux",
+      spawnCommand: spawnCommand as never,
+    });
+
+    expect(result).toEqual({ ok: false, editor: "vi", error: "exited with code 1" });
+    expect(tui.start).toHaveBeenCalledTimes(1);
+  });
+});
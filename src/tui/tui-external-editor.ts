@@ -0,0 +1,64 @@
+import { spawn } from "node:child_process";
+
+type EditorTui = {
+  stop: () => void;
+  start: () => void;
+  requestRender: () => void;
+};
+
+/** `$VISUAL`, then `$EDITOR`, then the platform default. May include arguments (`code --wait`). */
+export function resolveExternalEditor(
+  env: NodeJS.ProcessEnv = process.env,
+  platform: NodeJS.Platform = process.platform,
+): string {
+  const configured = env.VISUAL?.trim() || env.EDITOR?.trim();
+  if (configured) {
+    return configured;
+  }
+  return platform === "win32" ? "notepad" : "vi";
+}
+
+function quoteShellArg(value: string, platform: NodeJS.Platform): string {
+  if (platform === "win32") {
+    return `"${value.replaceAll('"', '""')}"`;
+  }
+  return `'${value.replaceAll("'", "'\\''")}'`;
+}
+
+/**
+ * Hands the terminal to an external editor for `filePath` and restores the TUI once it exits.
+ * The editor command runs through the shell so `$EDITOR` values with flags keep working.
+ */
+export async function openInExternalEditor(params: {
+  filePath: string;
+  tui: EditorTui;
+  env?: NodeJS.ProcessEnv;
+  platform?: NodeJS.Platform;
+  spawnCommand?: typeof spawn;
+}): Promise<{ ok: true; editor: string } | { ok: false; editor: string; error: string }> {
+  const env = params.env ?? process.env;
+  const platform = params.platform ?? process.platform;
+  const editor = resolveExternalEditor(env, platform);
+  const spawnCommand = params.spawnCommand ?? spawn;
+  params.tui.stop();
+  try {
+    const exitCode = await new Promise<number | null>((resolve, reject) => {
+      const child = spawnCommand(`${editor} ${quoteShellArg(params.filePath, platform)}`, {
+        shell: true,
+        stdio: "inherit",
+        env,
+      });
+      child.once("error", reject);
+      child.once("close", (code) => resolve(code));
+    });
+    if (exitCode !== 0) {
+      return { ok: false, editor, error: `exited with code ${exitCode ?? "null"}` };
+    }
+    return { ok: true, editor };
+  } catch (err) {
+    return { ok: false, editor, error: String(err) };
+  } finally {
+    params.tui.start();
+    params.tui.requestRender();
+  }
+}
@@ -0,0 +1,88 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import {
+  expandFileMentions,
+  FileMentionAutocompleteProvider,
+  listFileMentionCompletions,
+} from "./tui-file-mentions.js";
+
+describe("tui @file mentions", () => {
+  let cwd: string;
+
+  beforeEach(() => {
+    cwd = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-tui-mentions-"));
+    fs.mkdirSync(path.join(cwd, "src"));
+    fs.writeFileSync(path.join(cwd, "src", "main.rs"), 'fn main() {\n    println!("hi");\n}\n');
+    fs.writeFileSync(path.join(cwd, "src", "lib.rs"), "pub mod parser;\n");
+    fs.writeFileSync(path.join(cwd, "notes.txt"), "a\nb\nc\nd\n");
+    fs.writeFileSync(path.join(cwd, "logo.png"), Buffer.from([0x89, 0x50, 0x00, 0x01]));
+    fs.writeFileSync(path.join(cwd, ".env"), "SECRET=1\n");
+  });
+
+  afterEach(() => {
+    fs.rmSync(cwd, { recursive: true, force: true });
+  });
+
+  it("attaches mentioned files with line numbers and leaves other @tokens alone", async () => {
+    const result = await expandFileMentions("ask @alice why @src/main.rs, @logo.png fail", {
+      cwd,
+    });
+
+    expect(result.attached).toEqual([{ path: "src/main.rs", lines: 3, truncated: false }]);
+    expect(result.skipped).toEqual([{ path: "logo.png", reason: "binary file" }]);
+    expect(result.text).toBe(
+      [
+        "ask @alice why @src/main.rs, @logo.png fail",
+        "",
+        '<file path="src/main.rs">',
+        "1\tfn main() {",
+        '2\t    println!("hi");',
+        "3\t}",
+        "</file>",
+      ].join("\n"),
+    );
+  });
+
+  it("caps long files", async () => {
+    const result = await expandFileMentions("@notes.txt", { cwd, maxLines: 2 });
+    expect(result.attached).toEqual([{ path: "notes.txt", lines: 2, truncated: true }]);
+    expect(result.text).toContain("1\ta\n2\tb\n[truncated: showing lines 1-2 of 4]\n</file>");
+
+    // Only the first `maxBytes` are read, so the total line count is unknown.
+    const head = await expandFileMentions("@notes.txt", { cwd, maxBytes: 6 });
+    expect(head.attached).toEqual([{ path: "notes.txt", lines: 1, truncated: true }]);
+    expect(head.text).toContain("1\ta\n[truncated: showing lines 1-1]\n</file>");
+  });
+
+  it("completes paths, directories first, hiding dotfiles unless asked", () => {
+    expect(listFileMentionCompletions("", cwd).map((item) => item.value)).toEqual([
+      "@src/",
+      "@logo.png",
+      "@notes.txt",
+    ]);
+    expect(listFileMentionCompletions("src/m", cwd)).toEqual([
+      { value: "@src/main.rs", label: "main.rs" },
+    ]);
+    expect(listFileMentionCompletions(".e", cwd).map((item) => item.value)).toEqual(["@.env"]);
+  });
+
+  it("offers and applies @ completions in the editor", () => {
+    const provider = new FileMentionAutocompleteProvider([], cwd);
+    const line = "explain @src/ma";
+    const suggestions = provider.getSuggestions([line], 0, line.length);
+    expect(suggestions?.prefix).toBe("@src/ma");
+    expect(suggestions?.items.map((item) => item.value)).toEqual(["@src/main.rs"]);
+
+    const applied = provider.applyCompletion(
+      [line],
+      0,
+      line.length,
+      suggestions!.items[0],
+      suggestions!.prefix,
+    );
+    expect(applied.lines).toEqual(["explain @src/main.rs "]);
+    expect(applied.cursorCol).toBe("explain @src/main.rs ".length);
+  });
+});
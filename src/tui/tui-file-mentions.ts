@@ -0,0 +1,240 @@
+import { type AutocompleteItem, CombinedAutocompleteProvider } from "@mariozechner/pi-tui";
+import fs from "node:fs";
+import path from "node:path";
+
+/** Per-file caps for `@path` attachments; longer files are cut with a note. */
+export const FILE_MENTION_MAX_LINES = 2_000;
+export const FILE_MENTION_MAX_BYTES = 100_000;
+
+const MAX_COMPLETIONS = 50;
+const TRAILING_PUNCTUATION = /[.,;:!?)\]}'"]+$/;
+
+export type AttachedFile = {
+  path: string;
+  lines: number;
+  truncated: boolean;
+};
+
+export type FileMentionExpansion = {
+  text: string;
+  attached: AttachedFile[];
+  skipped: Array<{ path: string; reason: string }>;
+};
+
+/** `@path` tokens at the start of the input or after whitespace; `@"a b.txt"` allows spaces. */
+function findMentions(text: string): string[] {
+  return Array.from(
+    text.matchAll(/(?:^|\s)@(?:"([^"\n]+)"|(\S+))/g),
+    (match) => match[1] ?? match[2],
+  );
+}
+
+function resolveMentionFile(mention: string, cwd: string): string | null {
+  const candidates = [mention, mention.replace(TRAILING_PUNCTUATION, "")];
+  for (const candidate of candidates) {
+    if (!candidate) {
+      continue;
+    }
+    const resolved = path.resolve(cwd, candidate);
+    try {
+      if (fs.statSync(resolved).isFile()) {
+        return resolved;
+      }
+    } catch {
+      // Not a file path (e.g. a @handle); leave the text alone.
+    }
+  }
+  return null;
+}
+
+/** Reads at most `maxBytes` of `file`; `complete` is false when the file is longer. */
+async function readFileHead(
+  file: string,
+  maxBytes: number,
+): Promise<{ buffer: Buffer; complete: boolean }> {
+  const handle = await fs.promises.open(file, "r");
+  try {
+    // One extra byte tells a file of exactly `maxBytes` apart from a longer one.
+    const buffer = Buffer.alloc(maxBytes + 1);
+    let length = 0;
+    while (length < buffer.length) {
+      const { bytesRead } = await handle.read(buffer, length, buffer.length - length, length);
+      if (bytesRead === 0) {
+        break;
+      }
+      length += bytesRead;
+    }
+    return { buffer: buffer.subarray(0, Math.min(length, maxBytes)), complete: length <= maxBytes };
+  } finally {
+    await handle.close();
+  }
+}
+
+/**
+ * Line-numbers `content` within the caps. `partial` marks content cut from a longer file, whose
+ * total line count is unknown.
+ */
+export function formatAttachedFile(
+  displayPath: string,
+  content: string,
+  limits: { maxLines?: number; maxBytes?: number; partial?: boolean } = {},
+): { block: string; lines: number; truncated: boolean } {
+  const maxLines = limits.maxLines ?? FILE_MENTION_MAX_LINES;
+  const maxBytes = limits.maxBytes ?? FILE_MENTION_MAX_BYTES;
+  const allLines = content.replace(/\r?\n$/, "").split(/\r?\n/);
+  const numbered: string[] = [];
+  let bytes = 0;
+  const width = String(Math.min(allLines.length, maxLines)).length;
+  for (const [index, line] of allLines.entries()) {
+    const formatted = `${String(index + 1).padStart(width)}\t${line}`;
+    bytes += Buffer.byteLength(formatted, "utf-8") + 1;
+    if (index >= maxLines || bytes > maxBytes) {
+      break;
+    }
+    numbered.push(formatted);
+  }
+  const truncated = Boolean(limits.partial) || numbered.length < allLines.length;
+  const total = limits.partial ? "" : ` of ${allLines.length}`;
+  const note = truncated ? `\n[truncated: showing lines 1-${numbered.length}${total}]` : "";
+  return {
+    block: `<file path="${displayPath}">\n${numbered.join("\n")}${note}\n</file>`,
+    lines: numbered.length,
+    truncated,
+  };
+}
+
+/**
+ * Appends the contents of every `@path` that names a readable text file, line-numbered and
+ * size-capped, so the model sees them without a round of read tool calls. Mentions that are not
+ * files (handles, directories, typos) are left as typed.
+ */
+export async function expandFileMentions(
+  text: string,
+  opts: { cwd: string; maxLines?: number; maxBytes?: number },
+): Promise<FileMentionExpansion> {
+  const attached: AttachedFile[] = [];
+  const skipped: FileMentionExpansion["skipped"] = [];
+  const blocks: string[] = [];
+  const seen = new Set<string>();
+  for (const mention of findMentions(text)) {
+    const file = resolveMentionFile(mention, opts.cwd);
+    if (!file || seen.has(file)) {
+      continue;
+    }
+    seen.add(file);
+    const displayPath = path.relative(opts.cwd, file) || path.basename(file);
+    let head: { buffer: Buffer; complete: boolean };
+    try {
+      head = await readFileHead(file, opts.maxBytes ?? FILE_MENTION_MAX_BYTES);
+    } catch (err) {
+      skipped.push({ path: displayPath, reason: String(err) });
+      continue;
+    }
+    if (head.buffer.subarray(0, 8_192).includes(0)) {
+      skipped.push({ path: displayPath, reason: "binary file" });
+      continue;
+    }
+    let content = head.buffer.toString("utf-8");
+    if (!head.complete) {
+      // Drop the line (and any multi-byte character) the byte cap cut in half.
+      const lastNewline = content.lastIndexOf("\n");
+      content = lastNewline >= 0 ? content.slice(0, lastNewline + 1) : content;
+    }
+    const formatted = formatAttachedFile(displayPath, content, {
+      ...opts,
+      partial: !head.complete,
+    });
+    blocks.push(formatted.block);
+    attached.push({ path: displayPath, lines: formatted.lines, truncated: formatted.truncated });
+  }
+  return {
+    text: blocks.length > 0 ? `${text}\n\n${blocks.join("\n\n")}` : text,
+    attached,
+    skipped,
+  };
+}
+
+/** Completes `@partial/path` from the filesystem: directories first, dotfiles only on request. */
+export function listFileMentionCompletions(partial: string, cwd: string): AutocompleteItem[] {
+  const slash = partial.lastIndexOf("/");
+  const dirPart = slash >= 0 ? partial.slice(0, slash + 1) : "";
+  const namePart = (slash >= 0 ? partial.slice(slash + 1) : partial).toLowerCase();
+  let entries: fs.Dirent[];
+  try {
+    entries = fs.readdirSync(path.resolve(cwd, dirPart || "."), { withFileTypes: true });
+  } catch {
+    return [];
+  }
+  return entries
+    .filter(
+      (entry) =>
+        entry.name.toLowerCase().startsWith(namePart) &&
+        (namePart.startsWith(".") || !entry.name.startsWith(".")),
+    )
+    .toSorted(
+      (a, b) =>
+        Number(b.isDirectory()) - Number(a.isDirectory()) || a.name.localeCompare(b.name),
+    )
+    .slice(0, MAX_COMPLETIONS)
+    .map((entry) => {
+      const suffix = entry.isDirectory() ? "/" : "";
+      return { value: `@${dirPart}${entry.name}${suffix}`, label: `${entry.name}${suffix}` };
+    });
+}
+
+function matchMentionPrefix(line: string, cursorCol: number): string | null {
+  const match = line.slice(0, cursorCol).match(/(?:^|\s)(@[^\s"]*)$/);
+  return match ? match[1] : null;
+}
+
+/** Slash-command completion from pi-tui, plus `@path` completion (as you type and on Tab). */
+export class FileMentionAutocompleteProvider extends CombinedAutocompleteProvider {
+  private readonly mentionBasePath: string;
+
+  constructor(...args: ConstructorParameters<typeof CombinedAutocompleteProvider>) {
+    super(...args);
+    this.mentionBasePath = args[1] ?? process.cwd();
+  }
+
+  private getMentionSuggestions(lines: string[], cursorLine: number, cursorCol: number) {
+    const prefix = matchMentionPrefix(lines[cursorLine] ?? "", cursorCol);
+    if (!prefix) {
+      return null;
+    }
+    const items = listFileMentionCompletions(prefix.slice(1), this.mentionBasePath);
+    return items.length > 0 ? { items, prefix } : null;
+  }
+
+  getSuggestions(lines: string[], cursorLine: number, cursorCol: number) {
+    return (
+      this.getMentionSuggestions(lines, cursorLine, cursorCol) ??
+      super.getSuggestions(lines, cursorLine, cursorCol)
+    );
+  }
+
+  getForceFileSuggestions(lines: string[], cursorLine: number, cursorCol: number) {
+    return (
+      this.getMentionSuggestions(lines, cursorLine, cursorCol) ??
+      super.getForceFileSuggestions(lines, cursorLine, cursorCol)
+    );
+  }
+
+  applyCompletion(
+    lines: string[],
+    cursorLine: number,
+    cursorCol: number,
+    item: AutocompleteItem,
+    prefix: string,
+  ) {
+    if (!prefix.startsWith("@") || !item.value.startsWith("@")) {
+      return super.applyCompletion(lines, cursorLine, cursorCol, item, prefix);
+    }
+    const line = lines[cursorLine] ?? "";
+    const start = cursorCol - prefix.length;
+    // Files get a trailing space; directories stay open so Tab can keep descending.
+    const value = item.value.endsWith("/") ? item.value : `${item.value} `;
+    const next = [...lines];
+    next[cursorLine] = `${line.slice(0, start)}${value}${line.slice(cursorCol)}`;
+    return { lines: next, cursorLine, cursorCol: start + value.length };
+  }
+}
@@ -0,0 +1,82 @@
+import type { Component, TUI } from "@mariozechner/pi-tui";
+import type { CustomEditor } from "./components/custom-editor.js";
+import { formatRelativeTimestamp } from "../infra/format-time/format-relative.ts";
+import { createFilterableSelectList } from "./components/selectors.js";
+import {
+  appendTuiHistory,
+  listDistinctHistory,
+  loadTuiHistory,
+  type TuiHistoryEntry,
+} from "./tui-history-store.js";
+
+type HistorySearchContext = {
+  tui: TUI;
+  editor: CustomEditor;
+  openOverlay: (component: Component) => void;
+  closeOverlay: () => void;
+  setActivityStatus: (text: string) => void;
+  formatSessionKey: (key: string) => string;
+};
+
+/**
+ * Input history shared across TUI runs and sessions: loads it into the editor so Up/Down reach
+ * earlier runs, records submitted input, and opens the Ctrl+R search picker.
+ */
+export function createHistorySearch(context: HistorySearchContext) {
+  const { tui, editor, openOverlay, closeOverlay } = context;
+  let entries: TuiHistoryEntry[] = [];
+
+  const load = () => {
+    void loadTuiHistory()
+      .then((loaded) => {
+        entries = [...loaded, ...entries];
+        for (const entry of loaded) {
+          editor.addToHistory(entry.text);
+        }
+      })
+      .catch(() => {});
+  };
+
+  const record = (text: string, session: string) => {
+    const entry = { text, ts: Date.now(), session };
+    if (entries.at(-1)?.text !== text) {
+      entries.push(entry);
+    }
+    void appendTuiHistory(entry).catch(() => {
+      // History is best-effort; a read-only state dir should not break input.
+    });
+  };
+
+  const open = () => {
+    const distinct = listDistinctHistory(entries);
+    if (distinct.length === 0) {
+      context.setActivityStatus("no input history yet");
+      tui.requestRender();
+      return;
+    }
+    const items = distinct.map((entry, index) => {
+      const time = formatRelativeTimestamp(entry.ts, { dateFallback: true, fallback: "" });
+      const session = entry.session ? context.formatSessionKey(entry.session) : "";
+      return {
+        value: String(index),
+        label: entry.text.replace(/\s+/g, " ").trim(),
+        description: [time, session].filter(Boolean).join(" · "),
+        searchText: entry.text,
+      };
+    });
+    const selector = createFilterableSelectList(items, 9);
+    selector.onSelect = (item) => {
+      closeOverlay();
+      editor.setText(distinct[Number(item.value)]?.text ?? "");
+      tui.requestRender();
+    };
+    selector.onCancel = () => {
+      closeOverlay();
+      tui.requestRender();
+    };
+    openOverlay(selector);
+    tui.requestRender();
+  };
+
+  return { load, record, open };
+}
@@ -0,0 +1,61 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import {
+  appendTuiHistory,
+  clearTuiHistory,
+  listDistinctHistory,
+  loadTuiHistory,
+} from "./tui-history-store.js";
+
+describe("tui history store", () => {
+  let dir: string;
+  let filePath: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-tui-history-"));
+    filePath = path.join(dir, "tui", "history.jsonl");
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it("appends entries with session and time, dropping consecutive duplicates", async () => {
+    await expect(
+      appendTuiHistory({ text: "hi", ts: 1, session: "main" }, filePath),
+    ).resolves.toBe(true);
+    await expect(
+      appendTuiHistory({ text: "hi", ts: 2, session: "work" }, filePath),
+    ).resolves.toBe(false);
+    await appendTuiHistory({ text: "/status", ts: 3, session: "work" }, filePath);
+    await appendTuiHistory({ text: "hi", ts: 4, session: "work" }, filePath);
+    fs.appendFileSync(filePath, "not json\n");
+
+    expect(await loadTuiHistory(filePath)).toEqual([
+      { text: "hi", ts: 1, session: "main" },
+      { text: "/status", ts: 3, session: "work" },
+      { text: "hi", ts: 4, session: "work" },
+    ]);
+    expect((await loadTuiHistory(filePath, { limit: 1 })).map((entry) => entry.ts)).toEqual([4]);
+    expect(
+      (await loadTuiHistory(filePath, { session: "work" })).map((entry) => entry.text),
+    ).toEqual(["/status", "hi"]);
+  });
+
+  it("lists distinct texts newest first and clears the file", async () => {
+    for (const [ts, text] of ["a", "b", "a", "c"].entries()) {
+      await appendTuiHistory({ text, ts }, filePath);
+    }
+    const distinct = listDistinctHistory(await loadTuiHistory(filePath));
+    expect(distinct.map((entry) => [entry.text, entry.ts])).toEqual([
+      ["c", 3],
+      ["a", 2],
+      ["b", 1],
+    ]);
+
+    await expect(clearTuiHistory(filePath)).resolves.toBe(4);
+    expect(await loadTuiHistory(filePath)).toEqual([]);
+  });
+});
@@ -0,0 +1,100 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import { resolveStateDir } from "../config/paths.js";
+
+/** One submitted input line, kept across TUI runs and sessions. */
+export type TuiHistoryEntry = {
+  text: string;
+  /** Epoch ms when it was submitted. */
+  ts: number;
+  /** Session key it was submitted in. */
+  session?: string;
+};
+
+/** Entries kept on disk; the file is compacted to this size once it grows past twice that. */
+export const TUI_HISTORY_MAX_ENTRIES = 1_000;
+
+export function resolveTuiHistoryPath(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "tui", "history.jsonl");
+}
+
+function parseEntry(line: string): TuiHistoryEntry | null {
+  try {
+    const parsed = JSON.parse(line) as Partial<TuiHistoryEntry>;
+    if (typeof parsed.text !== "string" || !parsed.text || typeof parsed.ts !== "number") {
+      return null;
+    }
+    const session = typeof parsed.session === "string" ? parsed.session : undefined;
+    return { text: parsed.text, ts: parsed.ts, session };
+  } catch {
+    return null;
+  }
+}
+
+async function readAllEntries(filePath: string): Promise<TuiHistoryEntry[]> {
+  let raw: string;
+  try {
+    raw = await fs.readFile(filePath, "utf8");
+  } catch {
+    return [];
+  }
+  return raw
+    .split("\n")
+    .map((line) => (line.trim() ? parseEntry(line) : null))
+    .filter((entry): entry is TuiHistoryEntry => Boolean(entry));
+}
+
+/** Oldest first; `limit` keeps the newest entries. */
+export async function loadTuiHistory(
+  filePath: string = resolveTuiHistoryPath(),
+  opts: { limit?: number; session?: string } = {},
+): Promise<TuiHistoryEntry[]> {
+  const entries = (await readAllEntries(filePath)).filter(
+    (entry) => !opts.session || entry.session === opts.session,
+  );
+  const limit = opts.limit ?? TUI_HISTORY_MAX_ENTRIES;
+  return limit > 0 ? entries.slice(-limit) : entries;
+}
+
+/**
+ * Appends an entry unless it repeats the newest one (consecutive duplicates are dropped, even
+ * across sessions). Returns false when it was skipped.
+ */
+export async function appendTuiHistory(
+  entry: TuiHistoryEntry,
+  filePath: string = resolveTuiHistoryPath(),
+): Promise<boolean> {
+  const entries = await readAllEntries(filePath);
+  if (entries.at(-1)?.text === entry.text) {
+    return false;
+  }
+  await fs.mkdir(path.dirname(filePath), { recursive: true });
+  if (entries.length + 1 > TUI_HISTORY_MAX_ENTRIES * 2) {
+    const kept = [...entries, entry].slice(-TUI_HISTORY_MAX_ENTRIES);
+    const tmp = `${filePath}.${process.pid}.tmp`;
+    await fs.writeFile(tmp, kept.map((item) => `${JSON.stringify(item)}\n`).join(""), "utf8");
+    await fs.rename(tmp, filePath);
+    return true;
+  }
+  await fs.appendFile(filePath, `${JSON.stringify(entry)}\n`, "utf8");
+  return true;
+}
+
+export async function clearTuiHistory(filePath: string = resolveTuiHistoryPath()): Promise<number> {
+  const count = (await readAllEntries(filePath)).length;
+  await fs.rm(filePath, { force: true });
+  return count;
+}
+
+/** Newest first, one row per distinct text (its latest use), for the Ctrl+R picker. */
+export function listDistinctHistory(entries: TuiHistoryEntry[]): TuiHistoryEntry[] {
+  const seen = new Set<string>();
+  const result: TuiHistoryEntry[] = [];
+  for (const entry of entries.toReversed()) {
+    if (!seen.has(entry.text)) {
+      seen.add(entry.text);
+      result.push(entry);
+    }
+  }
+  return result;
+}
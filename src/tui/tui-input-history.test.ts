@@ -156,4 +156,21 @@ describe("createEditorSubmitHandler", () => {
 
     expect(sendMessage).toHaveBeenCalledWith("!");
   });
+
+  it("records submitted lines, including bang lines, for persistent history", () => {
+    const recordHistory = vi.fn();
+    const handler = createEditorSubmitHandler({
+      editor: { setText: vi.fn(), addToHistory: vi.fn() },
+      handleCommand: vi.fn(),
+      sendMessage: vi.fn(),
+      handleBangLine: vi.fn(),
+      recordHistory,
+    });
+
+    handler("  hello  ");
+    handler("!git status");
+    handler("   ");
+
+    expect(recordHistory.mock.calls).toEqual([["hello"], ["!git status"]]);
+  });
 });
@@ -0,0 +1,20 @@
+/**
+ * Editor handlers for `ui.tui.keybindings`: each key id (e.g. `alt+c`, `f5`) runs `abort` or the
+ * configured slash command.
+ */
+export function buildKeyBindingHandlers(
+  keybindings: Record<string, string> | undefined,
+  actions: {
+    abort: () => Promise<void> | void;
+    runCommand: (command: string) => Promise<void> | void;
+  },
+): Record<string, () => void> {
+  return Object.fromEntries(
+    Object.entries(keybindings ?? {}).map(([key, action]) => [
+      key.toLowerCase(),
+      () => {
+        void (action === "abort" ? actions.abort() : actions.runCommand(action));
+      },
+    ]),
+  );
+}
@@ -0,0 +1,137 @@
+import type { TUI } from "@mariozechner/pi-tui";
+import type { ChatLog } from "./components/chat-log.js";
+import type { TuiStateAccess } from "./tui-types.js";
+import { buildGitContext, type GitContext, parseGitContextArgs } from "../agents/git-context.js";
+import { copyToTerminalClipboard, extractLastCodeBlock, parseCopyArgs } from "./tui-copy.js";
+import { openInExternalEditor } from "./tui-external-editor.js";
+import { expandFileMentions } from "./tui-file-mentions.js";
+
+type LocalCommandContext = {
+  chatLog: ChatLog;
+  tui: TUI;
+  state: TuiStateAccess;
+  /** Sends a command on to the gateway when the terminal cannot handle it. */
+  forward: (raw: string) => Promise<void>;
+  /** Drains `!!cmd` output queued for the next message. */
+  takeSharedContext?: () => string[];
+  /** Local path of the current agent's MEMORY.md; null when the workspace is not on this host. */
+  resolveMemoryFilePath?: () => Promise<string | null>;
+};
+
+/**
+ * Commands the terminal answers itself (clipboard, editor, the local repository), plus the local
+ * context that rides along with the next message: `@file` mentions, shared `!!` output, and
+ * `/diff-context`.
+ */
+export function createLocalCommandHandlers(context: LocalCommandContext) {
+  const { chatLog, tui, state } = context;
+
+  /** `/diff-context` output waiting to go out with the next message. */
+  let pendingGitContext: GitContext | null = null;
+
+  const copy = async (args: string) => {
+    const target = parseCopyArgs(args);
+    if (!target) {
+      chatLog.addSystem("usage: /copy [code]");
+      return;
+    }
+    const last = chatLog.getLastAssistantText();
+    if (!last) {
+      chatLog.addSystem("nothing to copy yet");
+      return;
+    }
+    const text = target === "code" ? extractLastCodeBlock(last) : last;
+    if (text === null) {
+      chatLog.addSystem("no code block in the last response");
+      return;
+    }
+    try {
+      const method = await copyToTerminalClipboard(text);
+      const what = target === "code" ? "code block" : "last response";
+      chatLog.addSystem(
+        method === "osc52"
+          ? `${what} sent to the terminal clipboard (OSC 52)`
+          : `${what} copied to clipboard`,
+      );
+    } catch (err) {
+      chatLog.addSystem(`copy failed: ${String(err)}`);
+    }
+  };
+
+  const multiline = (args: string) => {
+    const mode = args.trim().toLowerCase();
+    if (mode && mode !== "on" && mode !== "off") {
+      chatLog.addSystem("usage: /multiline [on|off]");
+      return;
+    }
+    state.multilineMode = mode ? mode === "on" : !state.multilineMode;
+    chatLog.addSystem(
+      state.multilineMode
+        ? "multiline on: Enter adds a line, Alt+Enter sends"
+        : "multiline off: Enter sends (end a line with \\ to continue it)",
+    );
+  };
+
+  const memory = async (raw: string, args: string) => {
+    // Only `edit` needs the local terminal; the gateway handles the rest (and replies with the
+    // file paths for `edit` when the agent workspace lives on another host).
+    const filePath =
+      args.trim().toLowerCase() === "edit" ? await context.resolveMemoryFilePath?.() : null;
+    if (!filePath) {
+      await context.forward(raw);
+      return;
+    }
+    const result = await openInExternalEditor({ filePath, tui });
+    chatLog.addSystem(
+      result.ok
+        ? `memory: closed ${result.editor} (${filePath})`
+        : `memory: ${result.editor} failed: ${result.error}`,
+    );
+  };
+
+  const diffContext = async (args: string) => {
+    // Read from the repo this terminal is in, which may not be the gateway's workspace.
+    if (["off", "clear"].includes(args.trim().toLowerCase())) {
+      chatLog.addSystem(
+        pendingGitContext ? "diff-context: dropped" : "diff-context: nothing attached",
+      );
+      pendingGitContext = null;
+      return;
+    }
+    try {
+      pendingGitContext = await buildGitContext(process.cwd(), parseGitContextArgs(args));
+      const cut = pendingGitContext.truncated ? ", truncated" : "";
+      chatLog.addSystem(
+        `diff-context: ${pendingGitContext.summary.join(", ")}${cut} will be sent with your next message`,
+      );
+    } catch (err) {
+      chatLog.addSystem(`diff-context: ${err instanceof Error ? err.message : String(err)}`);
+    }
+  };
+
+  /** Appends the pending local context to an outgoing message and notes each part in the log. */
+  const attachLocalContext = async (text: string): Promise<string> => {
+    const expanded = await expandFileMentions(text, { cwd: process.cwd() });
+    let message = expanded.text;
+    for (const file of expanded.attached) {
+      const cut = file.truncated ? ", truncated" : "";
+      chatLog.addSystem(`attached ${file.path} (${file.lines} lines${cut})`);
+    }
+    for (const file of expanded.skipped) {
+      chatLog.addSystem(`not attached ${file.path}: ${file.reason}`);
+    }
+    const shared = context.takeSharedContext?.() ?? [];
+    if (shared.length > 0) {
+      message = `${message}\n\n${shared.join("\n\n")}`;
+      chatLog.addSystem(`sent ${shared.length} local command output(s)`);
+    }
+    if (pendingGitContext) {
+      message = `${message}\n\n${pendingGitContext.text}`;
+      chatLog.addSystem(`sent git context (${pendingGitContext.summary.join(", ")})`);
+      pendingGitContext = null;
+    }
+    return message;
+  };
+
+  return { copy, multiline, memory, diffContext, attachLocalContext };
+}
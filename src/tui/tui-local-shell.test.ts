@@ -1,5 +1,6 @@
+import { EventEmitter } from "node:events";
 import { describe, expect, it, vi } from "vitest";
-import { createLocalShellRunner } from "./tui-local-shell.js";
+import { createLocalShellRunner, formatSharedShellOutput } from "./tui-local-shell.js";
 
 const createSelector = () => {
   const selector = {
@@ -50,4 +51,61 @@ describe("createLocalShellRunner", () => {
     expect(createSelectorSpy).toHaveBeenCalledTimes(1);
     expect(spawnCommand).not.toHaveBeenCalled();
   });
+
+  it("queues !! output as context for the next message", async () => {
+    const messages: string[] = [];
+    const shared: string[] = [];
+    let lastSelector: ReturnType<typeof createSelector> | null = null;
+    const spawnCommand = vi.fn(() => {
+      const child = Object.assign(new EventEmitter(), {
+        stdout: new EventEmitter(),
+        stderr: new EventEmitter(),
+      });
+      setImmediate(() => {
+        child.stdout.emit("data", Buffer.from("M src/main.rs\n"));
+        child.emit("close", 0, null);
+      });
+      return child;
+    });
+
+    const { runLocalShellLine } = createLocalShellRunner({
+      chatLog: { addSystem: (line: string) => messages.push(line) },
+      tui: { requestRender: vi.fn() },
+      openOverlay: vi.fn(),
+      closeOverlay: vi.fn(),
+      createSelector: () => {
+        lastSelector = createSelector();
+        return lastSelector;
+      },
+      spawnCommand: spawnCommand as never,
+      onShare: (context) => shared.push(context),
+    });
+
+    const run = runLocalShellLine("!!git status --short");
+    lastSelector?.onSelect?.({ value: "yes", label: "Yes" });
+    await run;
+    await runLocalShellLine("!git status --short");
+
+    expect(spawnCommand).toHaveBeenCalledTimes(2);
+    expect(spawnCommand.mock.calls[0]).toEqual(expect.arrayContaining(["git status --short"]));
+    expect(messages).toContain("[local] M src/main.rs");
+    expect(shared).toEqual([
+      [
+        "Output of a command I ran locally (exit 0):",
+        "```console",
+        "$ git status --short",
+        "M src/main.rs",
+        "```",
+      ].join("\n"),
+    ]);
+  });
+
+  it("fences shared output past any backticks it contains", () => {
+    const block = formatSharedShellOutput({
+      command: "cat README.md",
+      output: "```sh\nls\n```",
+      exitLabel: "exit 0",
+    });
+    expect(block).toContain("````console\n$ cat README.md\n```sh\nls\n```\n````");
+  });
 });
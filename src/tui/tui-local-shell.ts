@@ -22,8 +22,27 @@ type LocalShellDeps = {
   getCwd?: () => string;
   env?: NodeJS.ProcessEnv;
   maxOutputChars?: number;
+  /** Receives the command and output of `!!cmd` runs, to be sent with the next message. */
+  onShare?: (context: string) => void;
 };
 
+/** Context block for `!!cmd`; the fence is longer than any backtick run in the output. */
+export function formatSharedShellOutput(params: {
+  command: string;
+  output: string;
+  exitLabel: string;
+}): string {
+  const longestRun = Math.max(0, ...(params.output.match(/`+/g) ?? []).map((run) => run.length));
+  const fence = "`".repeat(Math.max(3, longestRun + 1));
+  const body = params.output ? `${params.output}\n` : "";
+  return [
+    `Output of a command I ran locally (${params.exitLabel}):`,
+    `${fence}console`,
+    `$ ${params.command}`,
+    `${body}${fence}`,
+  ].join("\n");
+}
+
 export function createLocalShellRunner(deps: LocalShellDeps) {
   let localExecAsked = false;
   let localExecAllowed = false;
@@ -79,7 +98,9 @@ export function createLocalShellRunner(deps: LocalShellDeps) {
   };
 
   const runLocalShellLine = async (line: string) => {
-    const cmd = line.slice(1);
+    // `!!cmd` also shares the command and its output with the agent on the next message.
+    const share = line.startsWith("!!");
+    const cmd = line.slice(share ? 2 : 1);
     // NOTE: A lone '!' is handled by the submit handler as a normal message.
     // Keep this guard anyway in case this is called directly.
     if (cmd === "") {
@@ -126,9 +147,12 @@ export function createLocalShellRunner(deps: LocalShellDeps) {
             deps.chatLog.addSystem(`[local] ${line}`);
           }
         }
-        deps.chatLog.addSystem(
-          `[local] exit ${code ?? "?"}${signal ? ` (signal ${String(signal)})` : ""}`,
-        );
+        const exitLabel = `exit ${code ?? "?"}${signal ? ` (signal ${String(signal)})` : ""}`;
+        deps.chatLog.addSystem(`[local] ${exitLabel}`);
+        if (share && deps.onShare) {
+          deps.onShare(formatSharedShellOutput({ command: cmd, output: combined, exitLabel }));
+          deps.chatLog.addSystem("[local] output will be sent with your next message");
+        }
         deps.tui.requestRender();
         resolve();
       });
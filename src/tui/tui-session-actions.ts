@@ -48,6 +48,8 @@ type SessionInfoEntry = {
   responseUsage?: "on" | "off" | "tokens" | "full";
   updatedAt?: number | null;
   displayName?: string;
+  costUsd?: number;
+  disabledTools?: string[];
 };
 
 export function createSessionActions(context: SessionActionContext) {
@@ -207,6 +209,12 @@ export function createSessionActions(context: SessionActionContext) {
     if (entry?.displayName !== undefined) {
       next.displayName = entry.displayName;
     }
+    if (entry && "costUsd" in entry) {
+      next.costUsd = entry.costUsd;
+    }
+    if (entry && "disabledTools" in entry) {
+      next.disabledTools = entry.disabledTools;
+    }
     if (entry?.updatedAt !== undefined) {
       next.updatedAt = entry.updatedAt;
     }
@@ -256,7 +264,8 @@ export function createSessionActions(context: SessionActionContext) {
         updateHeader();
       }
       applySessionInfo({
-        entry,
+        // List rows omit cost/tools when unset; clear them rather than keep the previous session's.
+        entry: entry ? { costUsd: undefined, disabledTools: undefined, ...entry } : entry,
         defaults: result.defaults,
       });
     } catch (err) {
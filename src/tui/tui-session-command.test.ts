@@ -0,0 +1,44 @@
+import { describe, expect, it } from "vitest";
+import {
+  matchSessionsByName,
+  parseSessionCommand,
+  slugifySessionName,
+} from "./tui-session-command.js";
+
+describe("tui /session", () => {
+  it("parses subcommands and keeps bare keys as switches", () => {
+    expect(parseSessionCommand("")).toEqual({ action: "picker" });
+    expect(parseSessionCommand("list")).toEqual({ action: "list" });
+    expect(parseSessionCommand("switch Release notes")).toEqual({
+      action: "switch",
+      name: "Release notes",
+    });
+    expect(parseSessionCommand("new Bug 42")).toEqual({ action: "new", name: "Bug 42" });
+    expect(parseSessionCommand("work")).toEqual({ action: "switch", name: "work" });
+    expect(parseSessionCommand("new")).toBeNull();
+  });
+
+  it("slugifies new session names", () => {
+    expect(slugifySessionName(" Bug #42: parser ")).toBe("bug-42-parser");
+    expect(slugifySessionName("!!!")).toBe("");
+  });
+
+  it("matches keys before labels and titles", () => {
+    const format = (key: string) => key.replace(/^agent:main:/, "");
+    const sessions = [
+      { key: "agent:main:main", derivedTitle: "work" },
+      { key: "agent:main:work" },
+      { key: "agent:main:bug-42", label: "Bug 42" },
+      { key: "agent:main:notes", derivedTitle: "Notes" },
+      { key: "agent:main:notes-2", displayName: "notes" },
+    ];
+    expect(matchSessionsByName(sessions, "work", format).map((s) => s.key)).toEqual([
+      "agent:main:work",
+    ]);
+    expect(matchSessionsByName(sessions, "bug 42", format).map((s) => s.key)).toEqual([
+      "agent:main:bug-42",
+    ]);
+    expect(matchSessionsByName(sessions, "NOTES", format)).toHaveLength(2);
+    expect(matchSessionsByName(sessions, "missing", format)).toEqual([]);
+  });
+});
@@ -0,0 +1,166 @@
+import type { ChatLog } from "./components/chat-log.js";
+import type { GatewayChatClient, GatewaySessionList } from "./gateway-chat.js";
+import type { TuiStateAccess } from "./tui-types.js";
+import { formatRelativeTimestamp } from "../infra/format-time/format-relative.ts";
+
+export type SessionCommand =
+  | { action: "picker" }
+  | { action: "list" }
+  | { action: "switch"; name: string }
+  | { action: "new"; name: string };
+
+export const SESSION_COMMAND_USAGE = "usage: /session [list | switch <name> | new <name>]";
+
+/** `/session <key>` without a subcommand keeps working as a switch. */
+export function parseSessionCommand(args: string): SessionCommand | null {
+  const trimmed = args.trim();
+  if (!trimmed) {
+    return { action: "picker" };
+  }
+  const [first = "", ...rest] = trimmed.split(/\s+/);
+  const name = rest.join(" ");
+  switch (first.toLowerCase()) {
+    case "list":
+    case "ls":
+      return rest.length === 0 ? { action: "list" } : null;
+    case "switch":
+      return name ? { action: "switch", name } : null;
+    case "new":
+      return name ? { action: "new", name } : null;
+    default:
+      return { action: "switch", name: trimmed };
+  }
+}
+
+/** Session key segment for `/session new <name>`: lowercase, with runs of other chars as `-`. */
+export function slugifySessionName(name: string): string {
+  return name
+    .trim()
+    .toLowerCase()
+    .replace(/[^a-z0-9_-]+/g, "-")
+    .replace(/^-+|-+$/g, "")
+    .slice(0, 64);
+}
+
+type SessionRow = GatewaySessionList["sessions"][number];
+
+/**
+ * Finds sessions for a name typed after `/session switch`: an exact key (full or without the
+ * `agent:<id>:` prefix) wins; otherwise labels and titles match case-insensitively.
+ */
+export function matchSessionsByName(
+  sessions: SessionRow[],
+  name: string,
+  formatSessionKey: (key: string) => string,
+): SessionRow[] {
+  const exact = sessions.filter(
+    (session) => session.key === name || formatSessionKey(session.key) === name,
+  );
+  if (exact.length > 0) {
+    return exact;
+  }
+  const needle = name.toLowerCase();
+  return sessions.filter((session) =>
+    [session.label, session.displayName, session.derivedTitle].some(
+      (text) => text?.toLowerCase() === needle,
+    ),
+  );
+}
+
+/** Runs `/session`: the picker, `list`, `switch <name>`, and `new <name>`. */
+export function createSessionCommandHandler(context: {
+  client: GatewayChatClient;
+  chatLog: ChatLog;
+  state: TuiStateAccess;
+  setSession: (key: string) => Promise<void>;
+  openSessionSelector: () => Promise<void>;
+  formatSessionKey: (key: string) => string;
+}) {
+  const { client, chatLog, state, formatSessionKey } = context;
+
+  const switchSession = async (key: string) => {
+    if (state.activeChatRunId) {
+      // The run keeps going on the gateway; its reply lands in that session's history.
+      const current = formatSessionKey(state.currentSessionKey);
+      chatLog.addSystem(`run in ${current} continues in the background`);
+    }
+    await context.setSession(key);
+  };
+
+  return async (args: string) => {
+    const parsed = parseSessionCommand(args);
+    if (!parsed) {
+      chatLog.addSystem(SESSION_COMMAND_USAGE);
+      return;
+    }
+    if (parsed.action === "picker") {
+      await context.openSessionSelector();
+      return;
+    }
+    let sessions: GatewaySessionList["sessions"];
+    try {
+      sessions = (
+        await client.listSessions({
+          includeGlobal: false,
+          includeUnknown: false,
+          includeDerivedTitles: true,
+          agentId: state.currentAgentId,
+        })
+      ).sessions;
+    } catch (err) {
+      chatLog.addSystem(`sessions list failed: ${String(err)}`);
+      return;
+    }
+
+    if (parsed.action === "list") {
+      if (sessions.length === 0) {
+        chatLog.addSystem("no sessions yet");
+        return;
+      }
+      for (const session of sessions) {
+        const marker = session.key === state.currentSessionKey ? "*" : " ";
+        const title = session.label ?? session.derivedTitle ?? session.displayName;
+        const time = session.updatedAt
+          ? formatRelativeTimestamp(session.updatedAt, { dateFallback: true, fallback: "" })
+          : "";
+        const details = [formatSessionKey(session.key), title, time].filter(Boolean).join("  ");
+        chatLog.addSystem(`${marker} ${details}`);
+      }
+      return;
+    }
+
+    if (parsed.action === "switch") {
+      const matches = matchSessionsByName(sessions, parsed.name, formatSessionKey);
+      if (matches.length > 1) {
+        const keys = matches.map((session) => formatSessionKey(session.key)).join(", ");
+        chatLog.addSystem(`"${parsed.name}" matches several sessions: ${keys}`);
+        return;
+      }
+      // Unknown names switch to that key, which starts a new session on the first message.
+      await switchSession(matches[0]?.key ?? parsed.name);
+      return;
+    }
+
+    const slug = slugifySessionName(parsed.name);
+    if (!slug) {
+      chatLog.addSystem(SESSION_COMMAND_USAGE);
+      return;
+    }
+    const key = `agent:${state.currentAgentId}:${slug}`;
+    if (sessions.some((session) => session.key === key)) {
+      chatLog.addSystem(`session ${slug} already exists; use /session switch ${slug}`);
+      return;
+    }
+    try {
+      await client.patchSession({
+        key,
+        label: parsed.name === slug ? undefined : parsed.name,
+      });
+    } catch (err) {
+      chatLog.addSystem(`session create failed: ${String(err)}`);
+      return;
+    }
+    await switchSession(key);
+    chatLog.addSystem(`new session ${slug}`);
+  };
+}
@@ -0,0 +1,45 @@
+import { describe, expect, it } from "vitest";
+import { formatStatusline, resolveStatuslineSegments } from "./tui-statusline.js";
+
+describe("tui statusline", () => {
+  it("is off unless configured", () => {
+    expect(resolveStatuslineSegments(undefined)).toBeNull();
+    expect(resolveStatuslineSegments(false)).toBeNull();
+    expect(resolveStatuslineSegments({ enabled: false, segments: ["model"] })).toBeNull();
+    expect(resolveStatuslineSegments(true)).toEqual([
+      "model",
+      "context",
+      "cost",
+      "branch",
+      "tools",
+    ]);
+    expect(resolveStatuslineSegments({ segments: ["cost", "model"] })).toEqual(["cost", "model"]);
+  });
+
+  it("renders segments in the configured order", () => {
+    const line = formatStatusline({
+      segments: ["model", "context", "cost", "branch", "tools"],
+      sessionInfo: {
+        modelProvider: "anthropic",
+        model: "claude-sonnet-4-5",
+        totalTokens: 50_000,
+        contextTokens: 200_000,
+        costUsd: 0.4213,
+        disabledTools: ["exec"],
+      },
+      branch: "main",
+    });
+    expect(line).toBe(
+      "anthropic/claude-sonnet-4-5 · ctx 25% · cost $0.42 · git main · tools 1 off",
+    );
+  });
+
+  it("drops unknown model and branch and marks unknown usage", () => {
+    const line = formatStatusline({
+      segments: ["model", "context", "cost", "branch", "tools"],
+      sessionInfo: {},
+      branch: null,
+    });
+    expect(line).toBe("ctx ? · cost ? · tools on");
+  });
+});
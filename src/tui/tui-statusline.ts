@@ -0,0 +1,68 @@
+import type { SessionInfo } from "./tui-types.js";
+import { formatUsd } from "../utils/usage-format.js";
+
+export const STATUSLINE_SEGMENTS = ["model", "context", "cost", "branch", "tools"] as const;
+
+export type StatuslineSegment = (typeof STATUSLINE_SEGMENTS)[number];
+
+export type StatuslineConfig = {
+  enabled?: boolean;
+  segments?: StatuslineSegment[];
+};
+
+/** Segments to render, or null when the status line is off (it is opt-in). */
+export function resolveStatuslineSegments(
+  config?: StatuslineConfig | boolean,
+): StatuslineSegment[] | null {
+  if (config === undefined || config === false) {
+    return null;
+  }
+  if (config === true) {
+    return [...STATUSLINE_SEGMENTS];
+  }
+  if (config.enabled === false) {
+    return null;
+  }
+  return config.segments?.length ? config.segments : [...STATUSLINE_SEGMENTS];
+}
+
+function formatSegment(
+  segment: StatuslineSegment,
+  info: SessionInfo,
+  branch: string | null,
+): string | null {
+  switch (segment) {
+    case "model":
+      if (!info.model) {
+        return null;
+      }
+      return info.modelProvider ? `${info.modelProvider}/${info.model}` : info.model;
+    case "context": {
+      const total = info.totalTokens;
+      const context = info.contextTokens;
+      if (typeof total !== "number" || typeof context !== "number" || context <= 0) {
+        return "ctx ?";
+      }
+      return `ctx ${Math.min(999, Math.round((total / context) * 100))}%`;
+    }
+    case "cost":
+      return `cost ${formatUsd(info.costUsd) ?? "?"}`;
+    case "branch":
+      return branch ? `git ${branch}` : null;
+    case "tools": {
+      const disabled = info.disabledTools?.length ?? 0;
+      return disabled > 0 ? `tools ${disabled} off` : "tools on";
+    }
+  }
+}
+
+export function formatStatusline(params: {
+  segments: StatuslineSegment[];
+  sessionInfo: SessionInfo;
+  branch: string | null;
+}): string {
+  return params.segments
+    .map((segment) => formatSegment(segment, params.sessionInfo, params.branch))
+    .filter(Boolean)
+    .join(" · ");
+}
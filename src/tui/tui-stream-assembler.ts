@@ -152,6 +152,13 @@ export class TuiStreamAssembler {
     return finalText;
   }
 
+  /** Text streamed so far for a run that ends without a final message (e.g. aborted). */
+  takePartial(runId: string): string {
+    const text = this.runs.get(runId)?.displayText ?? "";
+    this.runs.delete(runId);
+    return text;
+  }
+
   drop(runId: string) {
     this.runs.delete(runId);
   }
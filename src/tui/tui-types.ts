@@ -8,6 +8,8 @@ export type TuiOptions = {
   timeoutMs?: number;
   historyLimit?: number;
   message?: string;
+  /** false renders each reply once complete instead of streaming it (default: ui.tui.stream). */
+  stream?: boolean;
 };
 
 export type ChatEvent = {
@@ -37,6 +39,9 @@ export type SessionInfo = {
   responseUsage?: "on" | "off" | "tokens" | "full";
   updatedAt?: number | null;
   displayName?: string;
+  /** Cumulative estimated session cost; absent when no run had model cost config. */
+  costUsd?: number;
+  disabledTools?: string[];
 };
 
 export type SessionScope = "per-sender" | "global";
@@ -100,6 +105,8 @@ export type TuiStateAccess = {
   autoMessageSent: boolean;
   toolsExpanded: boolean;
   showThinking: boolean;
+  /** When on, Enter adds a line and Alt+Enter sends (toggled with /multiline). */
+  multilineMode: boolean;
   connectionStatus: string;
   activityStatus: string;
   statusTimeout: ReturnType<typeof setTimeout> | null;
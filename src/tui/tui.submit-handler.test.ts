@@ -93,4 +93,48 @@ describe("createEditorSubmitHandler", () => {
     expect(sendMessage).toHaveBeenCalledWith("hello");
     expect(editor.addToHistory).toHaveBeenCalledWith("hello");
   });
+
+  it("continues on a new line after a trailing backslash", () => {
+    const editor = { setText: vi.fn(), addToHistory: vi.fn() };
+    const sendMessage = vi.fn();
+
+    const onSubmit = createEditorSubmitHandler({
+      editor,
+      handleCommand: vi.fn(),
+      sendMessage,
+      handleBangLine: vi.fn(),
+    });
+
+    onSubmit("first line \\");
+    expect(editor.setText).toHaveBeenLastCalledWith("first line \n");
+    expect(sendMessage).not.toHaveBeenCalled();
+
+    onSubmit("ends with an escaped backslash \\\\");
+    expect(sendMessage).toHaveBeenCalledWith("ends with an escaped backslash \\\\");
+  });
+
+  it("adds lines in multiline mode until sent explicitly", () => {
+    const editor = { setText: vi.fn(), addToHistory: vi.fn() };
+    const handleCommand = vi.fn();
+    const sendMessage = vi.fn();
+
+    const onSubmit = createEditorSubmitHandler({
+      editor,
+      handleCommand,
+      sendMessage,
+      handleBangLine: vi.fn(),
+      isMultiline: () => true,
+    });
+
+    onSubmit("Traceback:\n  File x");
+    expect(editor.setText).toHaveBeenLastCalledWith("Traceback:\n  File x\n");
+    expect(sendMessage).not.toHaveBeenCalled();
+
+    onSubmit("/multiline");
+    expect(handleCommand).toHaveBeenCalledWith("/multiline");
+
+    onSubmit("Traceback:\n  File x", { send: true });
+    expect(sendMessage).toHaveBeenCalledWith("Traceback:\n  File x");
+  });
 });
+
@@ -1,11 +1,11 @@
 import {
-  CombinedAutocompleteProvider,
   Container,
   Loader,
   ProcessTerminal,
   Text,
   TUI,
 } from "@mariozechner/pi-tui";
+import fs from "node:fs";
 import type {
   AgentSummary,
   SessionInfo,
@@ -13,8 +13,10 @@ import type {
   TuiOptions,
   TuiStateAccess,
 } from "./tui-types.js";
-import { resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { resolveAgentWorkspaceDir, resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { readMemoryFile } from "../agents/memory-file.js";
 import { loadConfig } from "../config/config.js";
+import { resolveGitBranch } from "../infra/git-commit.js";
 import {
   buildAgentMainSessionKey,
   normalizeAgentId,
@@ -27,16 +29,32 @@ import { CustomEditor } from "./components/custom-editor.js";
 import { GatewayChatClient } from "./gateway-chat.js";
 import { editorTheme, theme } from "./theme/theme.js";
 import { createCommandHandlers } from "./tui-command-handlers.js";
+import { createCompletionSources } from "./tui-completion-sources.js";
+import {
+  type CustomCommand,
+  loadCustomCommands,
+  resolveCustomCommandDirs,
+} from "./tui-custom-commands.js";
 import { createEventHandlers } from "./tui-event-handlers.js";
+import { FileMentionAutocompleteProvider } from "./tui-file-mentions.js";
 import { formatTokens } from "./tui-formatters.js";
+import { createHistorySearch } from "./tui-history-search.js";
+import { buildKeyBindingHandlers } from "./tui-keybindings.js";
 import { createLocalShellRunner } from "./tui-local-shell.js";
 import { createOverlayHandlers } from "./tui-overlays.js";
 import { createSessionActions } from "./tui-session-actions.js";
+import { formatStatusline, resolveStatuslineSegments } from "./tui-statusline.js";
 import { buildWaitingStatusMessage, defaultWaitingPhrases } from "./tui-waiting.js";
 
 export { resolveFinalAssistantText } from "./tui-formatters.js";
 export type { TuiOptions } from "./tui-types.js";
 
+/** A trailing backslash (not an escaped one) continues the input on a new line. */
+function endsWithContinuation(text: string): boolean {
+  const match = text.match(/\\+$/);
+  return Boolean(match && match[0].length % 2 === 1);
+}
+
 export function createEditorSubmitHandler(params: {
   editor: {
     setText: (value: string) => void;
@@ -45,10 +63,32 @@ export function createEditorSubmitHandler(params: {
   handleCommand: (value: string) => Promise<void> | void;
   sendMessage: (value: string) => Promise<void> | void;
   handleBangLine: (value: string) => Promise<void> | void;
+  isMultiline?: () => boolean;
+  /** Persists submitted input beyond the editor's in-memory history. */
+  recordHistory?: (value: string) => void;
 }) {
-  return (text: string) => {
+  const remember = (value: string) => {
+    params.editor.addToHistory(value);
+    params.recordHistory?.(value);
+  };
+  return (text: string, opts: { send?: boolean } = {}) => {
     const raw = text;
     const value = raw.trim();
+
+    if (!opts.send) {
+      // `line \` + Enter keeps editing on a new line instead of sending.
+      if (endsWithContinuation(raw.trimEnd())) {
+        params.editor.setText(`${raw.trimEnd().slice(0, -1)}\n`);
+        return;
+      }
+      // Multiline mode: Enter adds a line (Alt+Enter sends). A lone slash command still runs,
+      // so /multiline can switch it back off.
+      const loneCommand = value.startsWith("/") && !value.includes("\n");
+      if (params.isMultiline?.() && value && !loneCommand) {
+        params.editor.setText(`${raw}\n`);
+        return;
+      }
+    }
     params.editor.setText("");
 
     // Keep previous behavior: ignore empty/whitespace-only submissions.
@@ -60,13 +100,13 @@ export function createEditorSubmitHandler(params: {
     // IMPORTANT: use the raw (untrimmed) text so leading spaces do NOT trigger.
     // Per requirement: a lone '!' should be treated as a normal message.
     if (raw.startsWith("!") && raw !== "!") {
-      params.editor.addToHistory(raw);
+      remember(raw);
       void params.handleBangLine(raw);
       return;
     }
 
     // Enable built-in editor prompt history navigation (up/down).
-    params.editor.addToHistory(value);
+    remember(value);
 
     if (value.startsWith("/")) {
       void params.handleCommand(value);
@@ -95,6 +135,8 @@ export async function runTui(opts: TuiOptions) {
   let wasDisconnected = false;
   let toolsExpanded = false;
   let showThinking = false;
+  let multilineMode = false;
+  const streamReplies = opts.stream ?? config.ui?.tui?.stream ?? true;
   const localRunIds = new Set<string>();
 
   const deliverDefault = opts.deliver ?? false;
@@ -200,6 +242,13 @@ export async function runTui(opts: TuiOptions) {
     set showThinking(value) {
       showThinking = value;
     },
+    get multilineMode() {
+      return multilineMode;
+    },
+    set multilineMode(value) {
+      multilineMode = value;
+      updateFooter();
+    },
     get connectionStatus() {
       return connectionStatus;
     },
@@ -259,22 +308,43 @@ export async function runTui(opts: TuiOptions) {
   const header = new Text("", 1, 0);
   const statusContainer = new Container();
   const footer = new Text("", 1, 0);
+  const statuslineSegments = resolveStatuslineSegments(config.ui?.tui?.statusline);
+  const statusline = statuslineSegments ? new Text("", 1, 0) : null;
   const chatLog = new ChatLog();
   const editor = new CustomEditor(tui, editorTheme);
+  editor.editMode = config.ui?.tui?.editMode ?? "emacs";
   const root = new Container();
   root.addChild(header);
   root.addChild(chatLog);
   root.addChild(statusContainer);
   root.addChild(footer);
   root.addChild(editor);
+  if (statusline) {
+    root.addChild(statusline);
+  }
+
+  const completions = createCompletionSources({
+    config,
+    client,
+    getAgents: () => agents,
+    getAgentId: () => currentAgentId,
+  });
+
+  let customCommands: CustomCommand[] = [];
+  const refreshCustomCommands = async () => {
+    customCommands = await loadCustomCommands(resolveCustomCommandDirs(process.cwd()));
+    updateAutocompleteProvider();
+  };
 
   const updateAutocompleteProvider = () => {
     editor.setAutocompleteProvider(
-      new CombinedAutocompleteProvider(
+      new FileMentionAutocompleteProvider(
         getSlashCommands({
           cfg: config,
           provider: sessionInfo.modelProvider,
           model: sessionInfo.model,
+          sources: completions.sources,
+          customCommands,
         }),
         process.cwd(),
       ),
@@ -329,7 +399,7 @@ export async function runTui(opts: TuiOptions) {
     );
   };
 
-  const busyStates = new Set(["sending", "waiting", "streaming", "running"]);
+  const busyStates = new Set(["sending", "waiting", "streaming", "buffering", "running"]);
   let statusText: Text | null = null;
   let statusLoader: Loader | null = null;
 
@@ -517,9 +587,22 @@ export async function runTui(opts: TuiOptions) {
       think !== "off" ? `think ${think}` : null,
       verbose !== "off" ? `verbose ${verbose}` : null,
       reasoningLabel,
+      multilineMode ? "multiline" : null,
+      editor.editMode === "vi" && editor.getViMode() === "normal" ? "vi normal" : null,
       tokens,
     ].filter(Boolean);
     footer.setText(theme.dim(footerParts.join(" | ")));
+    if (statusline && statuslineSegments) {
+      statusline.setText(
+        theme.accentSoft(
+          formatStatusline({
+            segments: statuslineSegments,
+            sessionInfo,
+            branch: statuslineSegments.includes("branch") ? resolveGitBranch(process.cwd()) : null,
+          }),
+        ),
+      );
+    }
   };
 
   const { openOverlay, closeOverlay } = createOverlayHandlers(tui, editor);
@@ -567,8 +650,10 @@ export async function runTui(opts: TuiOptions) {
     isLocalRunId,
     forgetLocalRunId,
     clearLocalRunIds,
+    isBuffered: () => !streamReplies,
   });
 
+  let sharedShellContext: string[] = [];
   const { handleCommand, sendMessage, openModelSelector, openAgentSelector, openSessionSelector } =
     createCommandHandlers({
       client,
@@ -589,6 +674,19 @@ export async function runTui(opts: TuiOptions) {
       formatSessionKey,
       noteLocalRunId,
       forgetLocalRunId,
+      getCustomCommands: () => customCommands,
+      resolveMemoryFilePath: async () => {
+        const workspaceDir = resolveAgentWorkspaceDir(config, currentAgentId);
+        if (!fs.existsSync(workspaceDir)) {
+          return null;
+        }
+        return (await readMemoryFile(workspaceDir)).path;
+      },
+      takeSharedContext: () => {
+        const shared = sharedShellContext;
+        sharedShellContext = [];
+        return shared;
+      },
     });
 
   const { runLocalShellLine } = createLocalShellRunner({
@@ -596,14 +694,35 @@ export async function runTui(opts: TuiOptions) {
     tui,
     openOverlay,
     closeOverlay,
+    onShare: (context) => {
+      sharedShellContext.push(context);
+    },
   });
   updateAutocompleteProvider();
-  editor.onSubmit = createEditorSubmitHandler({
+  // Input history from earlier TUI runs (all sessions), so Up/Down and Ctrl+R reach it.
+  const historySearch = createHistorySearch({
+    tui,
+    editor,
+    openOverlay,
+    closeOverlay,
+    setActivityStatus,
+    formatSessionKey,
+  });
+  historySearch.load();
+
+  const submitHandler = createEditorSubmitHandler({
     editor,
     handleCommand,
     sendMessage,
     handleBangLine: runLocalShellLine,
+    isMultiline: () => multilineMode,
+    recordHistory: (text) => historySearch.record(text, currentSessionKey),
   });
+  editor.onSubmit = (text) => submitHandler(text);
+  editor.onAltEnter = () => {
+    submitHandler(editor.getText(), { send: true });
+    tui.requestRender();
+  };
 
   editor.onEscape = () => {
     void abortActive();
@@ -622,6 +741,13 @@ export async function runTui(opts: TuiOptions) {
       process.exit(0);
     }
     lastCtrlCAt = now;
+    if (state.activeChatRunId) {
+      // First press stops the in-flight turn (like Esc) and returns to the prompt.
+      void abortActive();
+      setActivityStatus("aborting (press ctrl+c again to exit)");
+      tui.requestRender();
+      return;
+    }
     setActivityStatus("press ctrl+c again to exit");
     tui.requestRender();
   };
@@ -649,6 +775,19 @@ export async function runTui(opts: TuiOptions) {
     showThinking = !showThinking;
     void loadHistory();
   };
+  editor.onCtrlR = () => {
+    historySearch.open();
+  };
+  editor.onViModeChange = () => {
+    updateFooter();
+    tui.requestRender();
+  };
+  editor.setKeyBindings(
+    buildKeyBindingHandlers(config.ui?.tui?.keybindings, {
+      abort: abortActive,
+      runCommand: handleCommand,
+    }),
+  );
 
   client.onEvent = (evt) => {
     if (evt.event === "chat") {
@@ -668,6 +807,8 @@ export async function runTui(opts: TuiOptions) {
       await refreshAgents();
       updateHeader();
       await loadHistory();
+      void completions.refresh();
+      void refreshCustomCommands();
       setConnectionStatus(reconnected ? "gateway reconnected" : "gateway connected", 4000);
       tui.requestRender();
       if (!autoMessageSent && autoMessage) {
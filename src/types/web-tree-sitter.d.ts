@@ -0,0 +1,31 @@
+declare module "web-tree-sitter" {
+  export type Point = {
+    row: number;
+    column: number;
+  };
+
+  export type Node = {
+    type: string;
+    text: string;
+    startPosition: Point;
+    endPosition: Point;
+    namedChildren: Array<Node | null>;
+    childForFieldName(fieldName: string): Node | null;
+  };
+
+  export type Tree = {
+    rootNode: Node;
+    delete(): void;
+  };
+
+  export class Language {
+    static load(input: string | Uint8Array): Promise<Language>;
+  }
+
+  export class Parser {
+    static init(options?: Record<string, unknown>): Promise<void>;
+    setLanguage(language: Language | null): this;
+    parse(input: string): Tree | null;
+    delete(): void;
+  }
+}
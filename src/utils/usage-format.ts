@@ -1,5 +1,6 @@
 import type { NormalizedUsage } from "../agents/usage.js";
 import type { OpenClawConfig } from "../config/config.js";
+import { lookupModelMetadata } from "../agents/model-metadata-registry.js";
 
 export type ModelCostConfig = {
   input: number;
@@ -55,7 +56,8 @@ export function resolveModelCostConfig(params: {
   }
   const providers = params.config?.models?.providers ?? {};
   const entry = providers[provider]?.models?.find((item) => item.id === model);
-  return entry?.cost;
+  // Configured prices win; `openclaw models update` fills in the rest.
+  return entry?.cost ?? lookupModelMetadata({ provider, model })?.cost;
 }
 
 const toNumber = (value: number | undefined): number =>
@@ -13,7 +13,7 @@ import {
   resizeToJpeg,
 } from "../media/image-ops.js";
 import { detectMime, extensionForMime } from "../media/mime.js";
-import { resolveUserPath } from "../utils.js";
+import { resolveConfigDir, resolveUserPath } from "../utils.js";
 
 export type WebMediaResult = {
   buffer: Buffer;
@@ -32,12 +32,8 @@ type WebMediaOptions = {
 };
 
 function getDefaultLocalRoots(): string[] {
-  const home = os.homedir();
-  return [
-    os.tmpdir(),
-    path.join(home, ".openclaw", "media"),
-    path.join(home, ".openclaw", "agents"),
-  ];
+  const stateDir = resolveConfigDir();
+  return [os.tmpdir(), path.join(stateDir, "media"), path.join(stateDir, "agents")];
 }
 
 async function assertLocalMediaAllowed(
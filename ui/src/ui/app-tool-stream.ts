@@ -240,7 +240,8 @@ export function handleAgentEvent(host: ToolStreamHost, payload?: AgentEventPaylo
   }
   const name = typeof data.name === "string" ? data.name : "tool";
   const phase = typeof data.phase === "string" ? data.phase : "";
-  const args = phase === "start" ? data.args : undefined;
+  // "args" previews arguments the model is still streaming; "start" carries the final args.
+  const args = phase === "start" || phase === "args" ? data.args : undefined;
   const output =
     phase === "update"
       ? formatToolOutput(data.partialResult)